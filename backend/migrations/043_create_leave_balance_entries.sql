@@ -0,0 +1,18 @@
+CREATE TABLE leave_balance_entries (
+    id               TEXT PRIMARY KEY,
+    user_id          TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    leave_type       TEXT NOT NULL,
+    entry_type       TEXT NOT NULL
+        CHECK (entry_type IN ('grant', 'consumption', 'reversal', 'adjustment')),
+    days             DOUBLE PRECISION NOT NULL,
+    leave_request_id TEXT REFERENCES leave_requests(id) ON DELETE SET NULL,
+    effective_date   DATE NOT NULL,
+    note             TEXT,
+    created_by       TEXT REFERENCES users(id) ON DELETE SET NULL,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+CREATE INDEX idx_leave_balance_entries_user_type
+    ON leave_balance_entries(user_id, leave_type);
+CREATE UNIQUE INDEX idx_leave_balance_entries_request_entry
+    ON leave_balance_entries(leave_request_id, entry_type)
+    WHERE leave_request_id IS NOT NULL;
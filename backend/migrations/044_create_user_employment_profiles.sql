@@ -0,0 +1,32 @@
+CREATE TABLE user_employment_profiles (
+    user_id                TEXT PRIMARY KEY REFERENCES users(id) ON DELETE CASCADE,
+    hire_date              DATE NOT NULL,
+    employment_type        TEXT NOT NULL
+        CHECK (employment_type IN ('full_time', 'part_time')),
+    weekly_scheduled_days  SMALLINT NOT NULL DEFAULT 5
+        CHECK (weekly_scheduled_days BETWEEN 0 AND 7),
+    weekly_scheduled_hours DOUBLE PRECISION NOT NULL DEFAULT 40
+        CHECK (weekly_scheduled_hours >= 0 AND weekly_scheduled_hours <= 168),
+    created_at             TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at             TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+ALTER TABLE leave_balance_entries
+    ADD COLUMN expires_on DATE,
+    ADD COLUMN statutory_grant BOOLEAN NOT NULL DEFAULT FALSE,
+    ADD COLUMN source_entry_id TEXT REFERENCES leave_balance_entries(id) ON DELETE CASCADE;
+
+ALTER TABLE leave_balance_entries DROP CONSTRAINT leave_balance_entries_entry_type_check;
+ALTER TABLE leave_balance_entries ADD CONSTRAINT leave_balance_entries_entry_type_check
+    CHECK (entry_type IN ('grant', 'consumption', 'reversal', 'adjustment', 'expiration'));
+
+-- One statutory grant per user, leave type and grant date keeps the accrual batch idempotent.
+CREATE UNIQUE INDEX idx_leave_balance_entries_statutory_grant
+    ON leave_balance_entries(user_id, leave_type, effective_date)
+    WHERE entry_type = 'grant' AND statutory_grant;
+CREATE UNIQUE INDEX idx_leave_balance_entries_expiration
+    ON leave_balance_entries(source_entry_id)
+    WHERE entry_type = 'expiration';
+CREATE INDEX idx_leave_balance_entries_expires_on
+    ON leave_balance_entries(expires_on)
+    WHERE expires_on IS NOT NULL;
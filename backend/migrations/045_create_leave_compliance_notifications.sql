@@ -0,0 +1,12 @@
+-- Deadline reminders sent for the five-day mandatory annual leave rule, one per grant and threshold.
+CREATE TABLE leave_compliance_notifications (
+    id                   TEXT PRIMARY KEY,
+    grant_entry_id       TEXT NOT NULL REFERENCES leave_balance_entries(id) ON DELETE CASCADE,
+    user_id              TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    days_before_deadline INTEGER NOT NULL,
+    sent_at              TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (grant_entry_id, days_before_deadline)
+);
+
+CREATE INDEX idx_leave_compliance_notifications_user_id
+    ON leave_compliance_notifications(user_id);
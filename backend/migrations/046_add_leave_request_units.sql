@@ -0,0 +1,17 @@
+ALTER TABLE leave_requests
+    ADD COLUMN unit TEXT NOT NULL DEFAULT 'full'
+        CHECK (unit IN ('full', 'half_am', 'half_pm', 'hours')),
+    ADD COLUMN hours DOUBLE PRECISION
+        CHECK (hours IS NULL OR (hours > 0 AND hours < 24));
+
+-- Partial units cover a single day; only hourly leave carries an hour count.
+ALTER TABLE leave_requests ADD CONSTRAINT leave_requests_unit_shape_check
+    CHECK (
+        (unit = 'full' AND hours IS NULL)
+        OR (unit IN ('half_am', 'half_pm') AND hours IS NULL AND start_date = end_date)
+        OR (unit = 'hours' AND hours IS NOT NULL AND start_date = end_date)
+    );
+
+ALTER TABLE archived_leave_requests
+    ADD COLUMN unit TEXT NOT NULL DEFAULT 'full',
+    ADD COLUMN hours DOUBLE PRECISION;
@@ -0,0 +1,37 @@
+CREATE TABLE leave_types (
+    code                TEXT PRIMARY KEY
+        CHECK (code ~ '^[a-z][a-z0-9_]{0,49}$'),
+    name                TEXT NOT NULL,
+    paid                BOOLEAN NOT NULL DEFAULT TRUE,
+    balance_tracked     BOOLEAN NOT NULL DEFAULT FALSE,
+    requires_attachment BOOLEAN NOT NULL DEFAULT FALSE,
+    is_active           BOOLEAN NOT NULL DEFAULT TRUE,
+    sort_order          INTEGER NOT NULL DEFAULT 0,
+    created_at          TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at          TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+INSERT INTO leave_types (code, name, paid, balance_tracked, requires_attachment, sort_order) VALUES
+    ('annual',   '年次有給休暇', TRUE,  TRUE,  FALSE, 10),
+    ('sick',     '病気休暇',     FALSE, FALSE, FALSE, 20),
+    ('personal', '私用休暇',     FALSE, FALSE, FALSE, 30),
+    ('other',    'その他',       FALSE, FALSE, FALSE, 40);
+
+-- Keep any historical codes valid, but hidden from new requests.
+INSERT INTO leave_types (code, name, is_active, sort_order)
+SELECT DISTINCT leave_type, leave_type, FALSE, 1000
+FROM (
+    SELECT leave_type FROM leave_requests
+    UNION
+    SELECT leave_type FROM leave_balance_entries
+) used
+WHERE leave_type ~ '^[a-z][a-z0-9_]{0,49}$'
+ON CONFLICT (code) DO NOTHING;
+
+ALTER TABLE leave_requests
+    ADD CONSTRAINT leave_requests_leave_type_fkey
+    FOREIGN KEY (leave_type) REFERENCES leave_types(code);
+
+ALTER TABLE leave_balance_entries
+    ADD CONSTRAINT leave_balance_entries_leave_type_fkey
+    FOREIGN KEY (leave_type) REFERENCES leave_types(code);
@@ -0,0 +1,37 @@
+CREATE TABLE work_schedules (
+    id                 TEXT PRIMARY KEY,
+    name               TEXT NOT NULL UNIQUE,
+    start_time         TIME NOT NULL,
+    end_time           TIME NOT NULL,
+    break_minutes      INTEGER NOT NULL DEFAULT 60 CHECK (break_minutes >= 0),
+    -- 0 = Sunday, matching weekly_holidays.weekday.
+    working_weekdays   SMALLINT[] NOT NULL DEFAULT '{1,2,3,4,5}'
+        CHECK (working_weekdays <@ '{0,1,2,3,4,5,6}'::SMALLINT[]),
+    late_grace_minutes INTEGER NOT NULL DEFAULT 0
+        CHECK (late_grace_minutes BETWEEN 0 AND 240),
+    created_at         TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at         TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK (end_time > start_time)
+);
+
+-- A schedule applies to either one user or a whole department. When several
+-- assignments cover a day, user assignments win over department ones and the
+-- most recent effective_from wins among those.
+CREATE TABLE work_schedule_assignments (
+    id             TEXT PRIMARY KEY,
+    schedule_id    TEXT NOT NULL REFERENCES work_schedules(id) ON DELETE RESTRICT,
+    user_id        TEXT REFERENCES users(id) ON DELETE CASCADE,
+    department_id  TEXT REFERENCES departments(id) ON DELETE CASCADE,
+    effective_from DATE NOT NULL,
+    effective_to   DATE,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    CHECK ((user_id IS NULL) <> (department_id IS NULL)),
+    CHECK (effective_to IS NULL OR effective_to >= effective_from)
+);
+
+CREATE INDEX idx_work_schedule_assignments_user
+    ON work_schedule_assignments(user_id, effective_from)
+    WHERE user_id IS NOT NULL;
+CREATE INDEX idx_work_schedule_assignments_department
+    ON work_schedule_assignments(department_id, effective_from)
+    WHERE department_id IS NOT NULL;
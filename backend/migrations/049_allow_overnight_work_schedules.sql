@@ -0,0 +1,5 @@
+-- An end time at or before the start time now means the shift ends on the
+-- following day; only zero-length shifts remain invalid.
+ALTER TABLE work_schedules DROP CONSTRAINT work_schedules_check;
+ALTER TABLE work_schedules
+    ADD CONSTRAINT work_schedules_time_span_check CHECK (end_time <> start_time);
@@ -0,0 +1,14 @@
+-- Outcome of the forgotten clock-out sweep for an attendance record that was
+-- left open. One row per record so repeated sweeps never act on it twice.
+CREATE TABLE attendance_open_record_sweeps (
+    attendance_id TEXT PRIMARY KEY REFERENCES attendance(id) ON DELETE CASCADE,
+    user_id       TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    action        TEXT NOT NULL CHECK (action IN ('auto_closed', 'needs_correction')),
+    -- Clock-out the sweep recorded; NULL when the record was only flagged.
+    closed_at     TIMESTAMP,
+    notified      BOOLEAN NOT NULL DEFAULT FALSE,
+    created_at    TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_attendance_open_record_sweeps_created
+    ON attendance_open_record_sweeps(created_at DESC);
@@ -0,0 +1,43 @@
+-- Article 36 agreement overtime limits. The row without a department applies
+-- company-wide; a department row replaces it for users in that department.
+-- Without a company row the statutory defaults below apply.
+CREATE TABLE overtime_limits (
+    id                          TEXT PRIMARY KEY,
+    department_id               TEXT UNIQUE REFERENCES departments(id) ON DELETE CASCADE,
+    monthly_limit_hours         DOUBLE PRECISION NOT NULL DEFAULT 45
+        CHECK (monthly_limit_hours > 0),
+    annual_limit_hours          DOUBLE PRECISION NOT NULL DEFAULT 360
+        CHECK (annual_limit_hours > 0),
+    special_clause_enabled      BOOLEAN NOT NULL DEFAULT FALSE,
+    special_annual_limit_hours  DOUBLE PRECISION NOT NULL DEFAULT 720
+        CHECK (special_annual_limit_hours > 0),
+    special_monthly_cap_hours   DOUBLE PRECISION NOT NULL DEFAULT 100
+        CHECK (special_monthly_cap_hours > 0),
+    special_average_limit_hours DOUBLE PRECISION NOT NULL DEFAULT 80
+        CHECK (special_average_limit_hours > 0),
+    special_months_per_year     INTEGER NOT NULL DEFAULT 6
+        CHECK (special_months_per_year BETWEEN 0 AND 12),
+    -- Share of a limit from which employees and managers are warned.
+    warning_ratio               DOUBLE PRECISION NOT NULL DEFAULT 0.8
+        CHECK (warning_ratio > 0 AND warning_ratio <= 1),
+    -- First month of the agreement year.
+    year_start_month            INTEGER NOT NULL DEFAULT 4
+        CHECK (year_start_month BETWEEN 1 AND 12),
+    created_at                  TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at                  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_overtime_limits_company
+    ON overtime_limits ((department_id IS NULL))
+    WHERE department_id IS NULL;
+
+-- Warnings sent per user, month, limit and level so each goes out once.
+CREATE TABLE overtime_limit_notifications (
+    id         TEXT PRIMARY KEY,
+    user_id    TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    month      DATE NOT NULL,
+    limit_kind TEXT NOT NULL,
+    level      TEXT NOT NULL CHECK (level IN ('approaching', 'exceeded')),
+    sent_at    TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, month, limit_kind, level)
+);
@@ -0,0 +1,13 @@
+-- Worked hours of a completed attendance record split by premium pay category.
+-- Regular, overtime and the holiday columns add up to the worked hours;
+-- late-night hours overlap them. Rows are rebuilt per statutory week whenever
+-- an attendance record of that week changes.
+CREATE TABLE attendance_work_time (
+    attendance_id               TEXT PRIMARY KEY REFERENCES attendance(id) ON DELETE CASCADE,
+    regular_hours               DOUBLE PRECISION NOT NULL DEFAULT 0,
+    statutory_overtime_hours    DOUBLE PRECISION NOT NULL DEFAULT 0,
+    late_night_hours            DOUBLE PRECISION NOT NULL DEFAULT 0,
+    legal_holiday_hours         DOUBLE PRECISION NOT NULL DEFAULT 0,
+    non_statutory_holiday_hours DOUBLE PRECISION NOT NULL DEFAULT 0,
+    updated_at                  TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
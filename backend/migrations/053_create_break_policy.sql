@@ -0,0 +1,28 @@
+-- Breaks inserted by the break policy to cover a missing statutory break.
+ALTER TABLE break_records ADD COLUMN is_automatic BOOLEAN NOT NULL DEFAULT FALSE;
+ALTER TABLE archived_break_records ADD COLUMN is_automatic BOOLEAN NOT NULL DEFAULT FALSE;
+
+-- Company-wide break policy; a single row. Without it the statutory minimums are
+-- only flagged.
+CREATE TABLE break_policy (
+    id                       BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    mode                     TEXT NOT NULL DEFAULT 'flag' CHECK (mode IN ('flag', 'deduct')),
+    first_threshold_minutes  INTEGER NOT NULL DEFAULT 360 CHECK (first_threshold_minutes > 0),
+    first_break_minutes      INTEGER NOT NULL DEFAULT 45 CHECK (first_break_minutes >= 0),
+    second_threshold_minutes INTEGER NOT NULL DEFAULT 480
+        CHECK (second_threshold_minutes >= first_threshold_minutes),
+    second_break_minutes     INTEGER NOT NULL DEFAULT 60
+        CHECK (second_break_minutes >= first_break_minutes),
+    updated_at               TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Days whose recorded breaks fell short of the policy when they were clocked out.
+CREATE TABLE attendance_break_violations (
+    attendance_id          TEXT PRIMARY KEY REFERENCES attendance(id) ON DELETE CASCADE,
+    -- Worked minutes before any automatic deduction.
+    worked_minutes         INTEGER NOT NULL,
+    recorded_break_minutes INTEGER NOT NULL,
+    required_break_minutes INTEGER NOT NULL,
+    auto_deducted          BOOLEAN NOT NULL DEFAULT FALSE,
+    detected_at            TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
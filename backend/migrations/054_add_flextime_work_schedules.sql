@@ -0,0 +1,16 @@
+-- Flextime schedules judge total hours over a settlement period of one to three
+-- months instead of daily start times. start_time/end_time still give the
+-- standard day that required hours are counted in.
+ALTER TABLE work_schedules
+    ADD COLUMN kind TEXT NOT NULL DEFAULT 'fixed' CHECK (kind IN ('fixed', 'flextime')),
+    ADD COLUMN core_start_time TIME,
+    ADD COLUMN core_end_time TIME,
+    ADD COLUMN settlement_months INTEGER NOT NULL DEFAULT 1
+        CHECK (settlement_months BETWEEN 1 AND 3),
+    -- Month in which settlement periods are counted from.
+    ADD COLUMN settlement_start_month INTEGER NOT NULL DEFAULT 1
+        CHECK (settlement_start_month BETWEEN 1 AND 12),
+    ADD CONSTRAINT work_schedules_core_time_check CHECK (
+        (core_start_time IS NULL AND core_end_time IS NULL)
+        OR (core_start_time IS NOT NULL AND core_end_time > core_start_time)
+    );
@@ -0,0 +1,14 @@
+-- Months closed for payroll. The row without a department closes the month
+-- company-wide; a department row closes it for users in that department.
+-- Re-opening deletes the row; the audit log keeps the history.
+CREATE TABLE period_closings (
+    id            TEXT PRIMARY KEY,
+    -- First day of the closed month.
+    period_month  DATE NOT NULL CHECK (EXTRACT(DAY FROM period_month) = 1),
+    department_id TEXT REFERENCES departments(id) ON DELETE CASCADE,
+    closed_by     TEXT REFERENCES users(id) ON DELETE SET NULL,
+    closed_at     TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE UNIQUE INDEX idx_period_closings_scope
+    ON period_closings (period_month, COALESCE(department_id, ''));
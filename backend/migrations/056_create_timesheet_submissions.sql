@@ -0,0 +1,20 @@
+-- Monthly timesheets an employee submits as final for manager sign-off.
+-- A month without a row is still a draft; a returned timesheet goes back to
+-- the employee and can be submitted again.
+CREATE TABLE timesheet_submissions (
+    id               TEXT PRIMARY KEY,
+    user_id          TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    -- First day of the submitted month.
+    period_month     DATE NOT NULL CHECK (EXTRACT(DAY FROM period_month) = 1),
+    status           TEXT NOT NULL CHECK (status IN ('submitted', 'approved', 'returned')),
+    submitted_at     TIMESTAMPTZ NOT NULL,
+    decided_by       TEXT REFERENCES users(id) ON DELETE SET NULL,
+    decided_at       TIMESTAMPTZ,
+    decision_comment TEXT,
+    created_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at       TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    UNIQUE (user_id, period_month)
+);
+
+CREATE INDEX idx_timesheet_submissions_status
+    ON timesheet_submissions (status, period_month);
@@ -0,0 +1,21 @@
+-- Code that identifies the employee in the payroll system.
+ALTER TABLE user_employment_profiles ADD COLUMN employee_code TEXT UNIQUE;
+
+-- Admin-defined CSV layouts for payroll systems. Columns are an ordered JSON
+-- array of {"field": ..., "header": ...} picked from the export field catalog.
+CREATE TABLE export_templates (
+    id             TEXT PRIMARY KEY,
+    name           TEXT NOT NULL UNIQUE,
+    granularity    TEXT NOT NULL CHECK (granularity IN ('daily', 'monthly')),
+    encoding       TEXT NOT NULL DEFAULT 'utf8'
+        CHECK (encoding IN ('utf8', 'utf8_bom', 'shift_jis')),
+    delimiter      TEXT NOT NULL DEFAULT 'comma'
+        CHECK (delimiter IN ('comma', 'tab', 'semicolon')),
+    -- strftime patterns for the date and month fields.
+    date_format    TEXT NOT NULL DEFAULT '%Y-%m-%d',
+    month_format   TEXT NOT NULL DEFAULT '%Y-%m',
+    include_header BOOLEAN NOT NULL DEFAULT TRUE,
+    columns        JSONB NOT NULL,
+    created_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
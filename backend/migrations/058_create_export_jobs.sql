@@ -0,0 +1,27 @@
+-- Exports too large to build within a request. A worker claims queued jobs,
+-- streams the rows into a file in the export store and records progress;
+-- the requester downloads the result through a short-lived signed link.
+CREATE TABLE export_jobs (
+    id           TEXT PRIMARY KEY,
+    requested_by TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    kind         TEXT NOT NULL CHECK (kind IN ('attendance', 'audit_logs')),
+    -- Filters resolved when the job was queued, including the requester's scope.
+    parameters   JSONB NOT NULL,
+    status       TEXT NOT NULL DEFAULT 'queued'
+                 CHECK (status IN ('queued', 'running', 'completed', 'failed')),
+    rows_written BIGINT NOT NULL DEFAULT 0,
+    total_rows   BIGINT,
+    -- Object key in the export store once the file is complete.
+    storage_key  TEXT,
+    filename     TEXT,
+    content_type TEXT,
+    size_bytes   BIGINT,
+    error        TEXT,
+    created_at   TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    started_at   TIMESTAMPTZ,
+    finished_at  TIMESTAMPTZ,
+    updated_at   TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_export_jobs_queue ON export_jobs (created_at) WHERE status = 'queued';
+CREATE INDEX idx_export_jobs_requested_by ON export_jobs (requested_by, created_at DESC);
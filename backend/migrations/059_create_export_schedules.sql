@@ -0,0 +1,27 @@
+-- Recurring attendance exports delivered without anyone asking for them, such
+-- as last month's payroll file on the 1st. The runner picks up schedules whose
+-- next_run_at has passed, exports the period relative to that run time and
+-- delivers the file to the destination.
+CREATE TABLE export_schedules (
+    id              TEXT PRIMARY KEY,
+    name            TEXT NOT NULL UNIQUE,
+    -- Five-field cron expression evaluated in the application time zone.
+    cron_expression TEXT NOT NULL,
+    -- Standard attendance layout when NULL.
+    template_id     TEXT REFERENCES export_templates(id) ON DELETE RESTRICT,
+    username        TEXT,
+    period          TEXT NOT NULL
+                    CHECK (period IN ('previous_day', 'previous_week', 'previous_month')),
+    -- {"type": "local" | "s3" | "email", ...}
+    destination     JSONB NOT NULL,
+    enabled         BOOLEAN NOT NULL DEFAULT TRUE,
+    next_run_at     TIMESTAMPTZ NOT NULL,
+    last_run_at     TIMESTAMPTZ,
+    last_run_status TEXT CHECK (last_run_status IN ('success', 'failure')),
+    last_run_error  TEXT,
+    created_by      TEXT REFERENCES users(id) ON DELETE SET NULL,
+    created_at      TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    updated_at      TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+CREATE INDEX idx_export_schedules_due ON export_schedules (next_run_at) WHERE enabled;
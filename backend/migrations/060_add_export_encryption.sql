@@ -0,0 +1,10 @@
+-- Exports can be encrypted with age, for a recipient's public key or a one-time
+-- passphrase. A queued job keeps the public key, or the passphrase encrypted with
+-- the PII key until its worker has used it.
+ALTER TABLE export_jobs
+    ADD COLUMN encryption     TEXT CHECK (encryption IN ('age', 'passphrase')),
+    ADD COLUMN encryption_key TEXT;
+
+-- Scheduled exports run unattended, so they can only be encrypted for a public key.
+ALTER TABLE export_schedules
+    ADD COLUMN encryption_recipient TEXT;
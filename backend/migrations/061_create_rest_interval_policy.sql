@@ -0,0 +1,24 @@
+-- Company-wide rest required between one shift's clock-out and the next clock-in; a
+-- single row. Without it 11 hours of rest are required and shortfalls only warned about.
+CREATE TABLE rest_interval_policy (
+    id                   BOOLEAN PRIMARY KEY DEFAULT TRUE CHECK (id),
+    mode                 TEXT NOT NULL DEFAULT 'warn' CHECK (mode IN ('warn', 'block')),
+    minimum_rest_minutes INTEGER NOT NULL DEFAULT 660 CHECK (minimum_rest_minutes > 0),
+    updated_at           TIMESTAMPTZ NOT NULL DEFAULT NOW()
+);
+
+-- Clock-ins that came too soon after the previous clock-out, including blocked attempts.
+CREATE TABLE rest_interval_violations (
+    user_id               TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    date                  DATE NOT NULL,
+    previous_clock_out    TIMESTAMP NOT NULL,
+    clock_in              TIMESTAMP NOT NULL,
+    rest_minutes          INTEGER NOT NULL,
+    required_rest_minutes INTEGER NOT NULL,
+    -- Whether the clock-in was refused.
+    blocked               BOOLEAN NOT NULL DEFAULT FALSE,
+    detected_at           TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, date)
+);
+
+CREATE INDEX idx_rest_interval_violations_date ON rest_interval_violations (date);
@@ -0,0 +1,9 @@
+-- Runs of consecutive worked days whose managers have been alerted; one alert per run.
+CREATE TABLE consecutive_workday_notifications (
+    user_id     TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    start_date  DATE NOT NULL,
+    -- Length of the run when the alert went out.
+    days        INTEGER NOT NULL,
+    notified_at TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, start_date)
+);
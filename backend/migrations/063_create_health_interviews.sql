@@ -0,0 +1,18 @@
+-- Physician interviews offered to employees over the long-working-hours threshold; one row
+-- per employee and month.
+CREATE TABLE health_interviews (
+    user_id        TEXT NOT NULL REFERENCES users(id) ON DELETE CASCADE,
+    year           INTEGER NOT NULL,
+    month          INTEGER NOT NULL CHECK (month BETWEEN 1 AND 12),
+    -- Overtime of the month when the interview was last recorded.
+    overtime_hours DOUBLE PRECISION NOT NULL,
+    status         TEXT NOT NULL CHECK (status IN ('offered', 'completed', 'declined')),
+    notes          TEXT,
+    offered_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    completed_at   TIMESTAMPTZ,
+    updated_by     TEXT REFERENCES users(id) ON DELETE SET NULL,
+    updated_at     TIMESTAMPTZ NOT NULL DEFAULT NOW(),
+    PRIMARY KEY (user_id, year, month)
+);
+
+CREATE INDEX idx_health_interviews_month ON health_interviews (year, month);
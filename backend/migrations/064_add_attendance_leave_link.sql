@@ -0,0 +1,8 @@
+-- Days without a clock-in are filled in by absence detection, either as 'absent' or,
+-- when approved leave covers the day, as 'leave' pointing at that request.
+ALTER TABLE attendance
+    ADD COLUMN leave_request_id TEXT REFERENCES leave_requests(id) ON DELETE SET NULL;
+
+CREATE INDEX idx_attendance_leave_request
+    ON attendance(leave_request_id)
+    WHERE leave_request_id IS NOT NULL;
@@ -0,0 +1,2 @@
+-- Leave requests cannot carry attachments yet, so the flag had nothing to enforce.
+ALTER TABLE leave_types DROP COLUMN requires_attachment;
@@ -0,0 +1,30 @@
+use chrono::{Duration, NaiveDate};
+use timekeeper_backend::{
+    config::Config, db::connection::create_pool, services::absence::AbsenceDetector, utils::time,
+};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let config = Config::load()?;
+    let pool = create_pool(&config.database_url).await?;
+    // Runs after midnight, so the last complete day is yesterday.
+    let date = match std::env::args().nth(1) {
+        Some(raw) => NaiveDate::parse_from_str(&raw, "%Y-%m-%d")?,
+        None => time::today_local(&config.time_zone) - Duration::days(1),
+    };
+
+    let summary = AbsenceDetector::new(pool)
+        .detect(date)
+        .await
+        .map_err(|err| anyhow::anyhow!("absence detection failed: {err:?}"))?;
+    tracing::info!(
+        %date,
+        absent = summary.absent,
+        leave = summary.leave,
+        "Absence detection completed"
+    );
+
+    Ok(())
+}
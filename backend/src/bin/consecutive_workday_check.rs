@@ -0,0 +1,25 @@
+use chrono::{Duration, NaiveDate};
+use timekeeper_backend::{
+    config::Config, db::connection::create_pool, services::rest_day::RestDayService, utils::time,
+};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let config = Config::load()?;
+    let pool = create_pool(&config.database_url).await?;
+    // Runs after midnight, so the last complete day is yesterday.
+    let as_of = match std::env::args().nth(1) {
+        Some(raw) => NaiveDate::parse_from_str(&raw, "%Y-%m-%d")?,
+        None => time::today_local(&config.time_zone) - Duration::days(1),
+    };
+
+    let sent = RestDayService::new(pool)
+        .send_alerts(as_of, &config)
+        .await
+        .map_err(|err| anyhow::anyhow!("consecutive workday check failed: {err:?}"))?;
+    tracing::info!(%as_of, sent, "Consecutive workday check completed");
+
+    Ok(())
+}
@@ -0,0 +1,48 @@
+use std::time::Duration;
+
+use timekeeper_backend::{
+    config::Config,
+    db::connection::create_pool,
+    services::{export_job::work_once, export_storage::ExportStorage},
+};
+
+/// Upper bound for the pause after consecutive failures (e.g. the database is down).
+const MAX_BACKOFF: Duration = Duration::from_secs(60);
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let run_once = std::env::args().skip(1).any(|arg| arg == "--once");
+    let config = Config::load()?;
+    let pool = create_pool(&config.database_url).await?;
+    let storage = ExportStorage::from_config(&config).await;
+
+    let mut backoff = Duration::from_secs(1);
+    loop {
+        match work_once(&pool, &storage, &config).await {
+            Ok(Some(job)) => {
+                backoff = Duration::from_secs(1);
+                tracing::info!(
+                    job_id = %job.id,
+                    status = ?job.status,
+                    rows = job.rows_written,
+                    "Processed export job"
+                );
+            }
+            Ok(None) if run_once => break,
+            Ok(None) => {
+                backoff = Duration::from_secs(1);
+                tokio::time::sleep(Duration::from_secs(1)).await;
+            }
+            Err(err) if run_once => return Err(err),
+            Err(err) => {
+                tracing::error!(error = ?err, retry_in = ?backoff, "Export job worker failed");
+                tokio::time::sleep(backoff).await;
+                backoff = (backoff * 2).min(MAX_BACKOFF);
+            }
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+use std::time::Duration;
+
+use chrono::Utc;
+use timekeeper_backend::{
+    config::Config, db::connection::create_pool, services::audit_log::AuditLogService,
+    services::export_schedule::run_due_schedules,
+};
+
+/// How often due schedules are checked; cron expressions have minute resolution.
+const POLL_INTERVAL: Duration = Duration::from_secs(30);
+/// Upper bound for the pause after consecutive failures (e.g. the database is down).
+const MAX_BACKOFF: Duration = Duration::from_secs(300);
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let run_once = std::env::args().skip(1).any(|arg| arg == "--once");
+    let config = Config::load()?;
+    let pool = create_pool(&config.database_url).await?;
+    let audit = AuditLogService::new(pool.clone());
+
+    let mut delay = POLL_INTERVAL;
+    loop {
+        match run_due_schedules(&pool, &config, &audit, Utc::now()).await {
+            Ok(summary) => {
+                delay = POLL_INTERVAL;
+                if summary.succeeded + summary.failed > 0 {
+                    tracing::info!(
+                        succeeded = summary.succeeded,
+                        failed = summary.failed,
+                        "Ran due export schedules"
+                    );
+                }
+            }
+            Err(err) if run_once => return Err(err),
+            Err(err) => {
+                delay = (delay * 2).min(MAX_BACKOFF);
+                tracing::error!(error = ?err, retry_in = ?delay, "Export schedule run failed");
+            }
+        }
+        if run_once {
+            break;
+        }
+        tokio::time::sleep(delay).await;
+    }
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+use timekeeper_backend::{
+    config::Config, db::connection::create_pool,
+    services::attendance_closure::ForgottenClockOutSweeper, utils::time,
+};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let config = Config::load()?;
+    let pool = create_pool(&config.database_url).await?;
+    let now = time::now_in_timezone(&config.time_zone).naive_local();
+
+    let summary = ForgottenClockOutSweeper::new(pool)
+        .sweep(now, &config)
+        .await
+        .map_err(|err| anyhow::anyhow!("forgotten clock-out sweep failed: {err:?}"))?;
+    tracing::info!(
+        auto_closed = summary.auto_closed,
+        flagged = summary.flagged,
+        breaks_ended = summary.breaks_ended,
+        "Forgotten clock-out sweep completed"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+use chrono::NaiveDate;
+use timekeeper_backend::{
+    config::Config, db::connection::create_pool, services::leave_accrual::LeaveAccrualService,
+    utils::time,
+};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let config = Config::load()?;
+    let pool = create_pool(&config.database_url).await?;
+    // An explicit date allows backfilling grants that a missed run did not book.
+    let as_of = match std::env::args().nth(1) {
+        Some(raw) => NaiveDate::parse_from_str(&raw, "%Y-%m-%d")?,
+        None => time::today_local(&config.time_zone),
+    };
+
+    let summary = LeaveAccrualService::new(pool)
+        .run(as_of)
+        .await
+        .map_err(|err| anyhow::anyhow!("leave accrual failed: {err:?}"))?;
+    tracing::info!(
+        %as_of,
+        granted = summary.granted,
+        expired = summary.expired,
+        "Leave accrual completed"
+    );
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+use chrono::NaiveDate;
+use timekeeper_backend::{
+    config::Config, db::connection::create_pool,
+    services::leave_compliance::LeaveComplianceService, utils::time,
+};
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::fmt::init();
+
+    let config = Config::load()?;
+    let pool = create_pool(&config.database_url).await?;
+    let as_of = match std::env::args().nth(1) {
+        Some(raw) => NaiveDate::parse_from_str(&raw, "%Y-%m-%d")?,
+        None => time::today_local(&config.time_zone),
+    };
+
+    let sent = LeaveComplianceService::new(pool)
+        .send_deadline_reminders(as_of, &config)
+        .await
+        .map_err(|err| anyhow::anyhow!("mandatory leave reminders failed: {err:?}"))?;
+    tracing::info!(%as_of, sent, "Mandatory leave reminders completed");
+
+    Ok(())
+}
@@ -1,7 +1,9 @@
 use anyhow::anyhow;
 use chrono::{DateTime, Duration as ChronoDuration, Utc};
 use chrono_tz::Tz;
+use hmac::{Hmac, Mac};
 use serde::{Deserialize, Serialize};
+use sha2::Sha256;
 use std::env;
 
 use crate::utils::cookies::SameSite;
@@ -26,6 +28,28 @@ pub struct Config {
     pub cookie_same_site: SameSite,
     pub cors_allow_origins: Vec<String>,
     pub time_zone: Tz,
+    /// Local hour at which one business day ends and the next begins.
+    pub attendance_day_change_hour: u32,
+    /// Hours past the scheduled end of a shift before an open record counts as forgotten.
+    pub forgotten_clock_out_cutoff_hours: i64,
+    /// Close forgotten records at the scheduled end instead of flagging them for correction.
+    pub forgotten_clock_out_auto_close: bool,
+    /// Days in a row an employee may work before the stretch is reported as missing rest.
+    pub consecutive_workday_limit: i64,
+    /// TrueType font embedded in PDF reports. It must cover Japanese glyphs.
+    pub report_font_path: Option<String>,
+    /// Directory for finished export files when no S3 bucket is configured.
+    pub export_storage_dir: String,
+    /// S3 bucket for finished export files; the local directory is used when unset.
+    pub export_s3_bucket: Option<String>,
+    /// Endpoint of an S3-compatible store such as MinIO; AWS S3 when unset.
+    pub export_s3_endpoint: Option<String>,
+    /// Lifetime of export download links.
+    pub export_download_ttl_seconds: u64,
+    /// Key that signs local export download links, kept apart from the session token key.
+    pub export_download_secret: String,
+    /// Require users other than system administrators to encrypt the exports they download.
+    pub export_encryption_required: bool,
     pub mfa_issuer: String,
     pub rate_limit_ip_max_requests: u32,
     pub rate_limit_ip_window_seconds: u64,
@@ -159,6 +183,65 @@ impl Config {
             .parse()
             .map_err(|_| anyhow!("Invalid APP_TIMEZONE value: {}", time_zone_name))?;
 
+        let attendance_day_change_hour = env::var("ATTENDANCE_DAY_CHANGE_HOUR")
+            .unwrap_or_else(|_| "0".to_string())
+            .parse::<u32>()
+            .unwrap_or(0);
+        if attendance_day_change_hour > 23 {
+            return Err(anyhow!(
+                "ATTENDANCE_DAY_CHANGE_HOUR must be between 0 and 23, got {}",
+                attendance_day_change_hour
+            ));
+        }
+
+        let forgotten_clock_out_cutoff_hours = env::var("FORGOTTEN_CLOCK_OUT_CUTOFF_HOURS")
+            .unwrap_or_else(|_| "4".to_string())
+            .parse::<i64>()
+            .unwrap_or(4)
+            .max(0);
+
+        let forgotten_clock_out_auto_close = env::var("FORGOTTEN_CLOCK_OUT_AUTO_CLOSE")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false);
+
+        let consecutive_workday_limit = env::var("CONSECUTIVE_WORKDAY_LIMIT")
+            .unwrap_or_else(|_| "6".to_string())
+            .parse::<i64>()
+            .unwrap_or(6)
+            .max(1);
+
+        let report_font_path = env::var("REPORT_FONT_PATH").ok().filter(|p| !p.is_empty());
+
+        let export_storage_dir =
+            env::var("EXPORT_STORAGE_DIR").unwrap_or_else(|_| "exports".to_string());
+        let export_s3_bucket = env::var("EXPORT_S3_BUCKET").ok().filter(|b| !b.is_empty());
+        let export_s3_endpoint = env::var("EXPORT_S3_ENDPOINT")
+            .ok()
+            .filter(|e| !e.is_empty());
+        let export_download_ttl_seconds = env::var("EXPORT_DOWNLOAD_TTL_SECONDS")
+            .unwrap_or_else(|_| "300".to_string())
+            .parse::<u64>()
+            .unwrap_or(300)
+            .max(1);
+        let export_download_secret = match env::var("EXPORT_DOWNLOAD_SECRET")
+            .ok()
+            .filter(|s| !s.is_empty())
+        {
+            Some(secret) if secret.len() < 32 => {
+                return Err(anyhow!(
+                    "EXPORT_DOWNLOAD_SECRET must be at least 32 characters long (current length: {})",
+                    secret.len()
+                ));
+            }
+            Some(secret) => secret,
+            None => derive_export_download_secret(&jwt_secret),
+        };
+        let export_encryption_required = env::var("EXPORT_ENCRYPTION_REQUIRED")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse()
+            .unwrap_or(false);
+
         let mfa_issuer = env::var("MFA_ISSUER").unwrap_or_else(|_| "Timekeeper".to_string());
 
         let rate_limit_ip_max_requests = env::var("RATE_LIMIT_IP_MAX_REQUESTS")
@@ -280,6 +363,17 @@ impl Config {
             cookie_same_site,
             cors_allow_origins,
             time_zone,
+            attendance_day_change_hour,
+            forgotten_clock_out_cutoff_hours,
+            forgotten_clock_out_auto_close,
+            consecutive_workday_limit,
+            report_font_path,
+            export_storage_dir,
+            export_s3_bucket,
+            export_s3_endpoint,
+            export_download_ttl_seconds,
+            export_download_secret,
+            export_encryption_required,
             mfa_issuer,
             rate_limit_ip_max_requests,
             rate_limit_ip_window_seconds,
@@ -326,6 +420,14 @@ impl Config {
     }
 }
 
+/// Export link key derived from the JWT secret, for deployments without EXPORT_DOWNLOAD_SECRET.
+fn derive_export_download_secret(jwt_secret: &str) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(jwt_secret.as_bytes())
+        .expect("HMAC accepts keys of any length");
+    mac.update(b"timekeeper/export-download-link/v1");
+    hex::encode(mac.finalize().into_bytes())
+}
+
 fn parse_same_site(raw: String) -> anyhow::Result<SameSite> {
     match raw.to_ascii_lowercase().as_str() {
         "lax" => Ok(SameSite::Lax),
@@ -387,6 +489,17 @@ mod tests {
             cookie_same_site: SameSite::Lax,
             cors_allow_origins: Vec::new(),
             time_zone: UTC,
+            attendance_day_change_hour: 0,
+            forgotten_clock_out_cutoff_hours: 4,
+            forgotten_clock_out_auto_close: false,
+            consecutive_workday_limit: 6,
+            report_font_path: None,
+            export_storage_dir: "exports".into(),
+            export_s3_bucket: None,
+            export_s3_endpoint: None,
+            export_download_ttl_seconds: 300,
+            export_download_secret: "test-export-download-secret-32-chars".to_string(),
+            export_encryption_required: false,
             mfa_issuer: "Timekeeper".to_string(),
             rate_limit_ip_max_requests: 15,
             rate_limit_ip_window_seconds: 900,
@@ -478,6 +591,70 @@ mod tests {
         restore_env(&keys, original);
     }
 
+    #[test]
+    fn config_validates_attendance_day_change_hour() {
+        let _guard = env_guard();
+        let keys = [
+            "JWT_SECRET",
+            "AWS_KMS_KEY_ID",
+            "AWS_AUDIT_LOG_BUCKET",
+            "ATTENDANCE_DAY_CHANGE_HOUR",
+        ];
+        let original = snapshot_env(&keys);
+
+        env::set_var("JWT_SECRET", "a_secure_token_that_is_long_enough_123");
+        set_optional_aws_env();
+        env::remove_var("ATTENDANCE_DAY_CHANGE_HOUR");
+        let config = Config::load().expect("load config");
+        assert_eq!(config.attendance_day_change_hour, 0);
+
+        env::set_var("ATTENDANCE_DAY_CHANGE_HOUR", "5");
+        let config = Config::load().expect("load config");
+        assert_eq!(config.attendance_day_change_hour, 5);
+
+        env::set_var("ATTENDANCE_DAY_CHANGE_HOUR", "24");
+        assert!(Config::load().is_err());
+
+        restore_env(&keys, original);
+    }
+
+    #[test]
+    fn config_keeps_export_download_links_off_the_jwt_secret() {
+        let _guard = env_guard();
+        let keys = [
+            "JWT_SECRET",
+            "AWS_KMS_KEY_ID",
+            "AWS_AUDIT_LOG_BUCKET",
+            "EXPORT_DOWNLOAD_SECRET",
+        ];
+        let original = snapshot_env(&keys);
+
+        env::set_var("JWT_SECRET", "a_secure_token_that_is_long_enough_123");
+        set_optional_aws_env();
+        env::remove_var("EXPORT_DOWNLOAD_SECRET");
+        let derived = Config::load().expect("load config").export_download_secret;
+        assert_ne!(derived, "a_secure_token_that_is_long_enough_123");
+        assert_eq!(
+            derived,
+            Config::load().expect("load config").export_download_secret
+        );
+
+        env::set_var(
+            "EXPORT_DOWNLOAD_SECRET",
+            "an_export_link_secret_that_is_long_1",
+        );
+        let config = Config::load().expect("load config");
+        assert_eq!(
+            config.export_download_secret,
+            "an_export_link_secret_that_is_long_1"
+        );
+
+        env::set_var("EXPORT_DOWNLOAD_SECRET", "short");
+        assert!(Config::load().is_err());
+
+        restore_env(&keys, original);
+    }
+
     #[test]
     fn config_aws_kms_key_id_is_optional() {
         let _guard = env_guard();
@@ -7,7 +7,7 @@ use crate::{
             AdminAttendanceUpsert, AdminBreakItem, AdminHolidayListQuery, AdminHolidayListResponse,
             AdminRequestListPageInfo, AdminRequestListResponse, AdminSessionResponse,
             ApprovePayload, ArchivedUserResponse, AuditLogExportQuery, AuditLogListQuery,
-            AuditLogListResponse, AuditLogResponse, DecisionPayload, ExportQuery, RejectPayload,
+            AuditLogListResponse, DecisionPayload, ExportDownloadQuery, ExportQuery, RejectPayload,
             RequestListQuery, SubjectRequestListQuery, SubjectRequestListResponse,
         },
         attendance::{AttendanceExportQuery, AttendanceQuery, AttendanceStatusResponse},
@@ -26,25 +26,67 @@ use crate::{
             DecisionPayload as AttendanceCorrectionDecisionPayload,
             UpdateAttendanceCorrectionRequest,
         },
+        attendance_sweep::{OpenRecordSweep, OpenRecordSweepAction},
+        audit_log::AuditLogResponse,
+        break_policy::{BreakPolicy, BreakPolicyMode, BreakPolicyPayload, BreakViolation},
         break_record::{ActiveBreakResponse, BreakRecordResponse},
         consent_log::{ConsentLogResponse, RecordConsentPayload},
+        export_encryption::{ExportEncryptionMethod, ExportEncryptionQuery},
+        export_job::{
+            CreateExportJobPayload, ExportDownloadResponse, ExportJob, ExportJobKind,
+            ExportJobStatus,
+        },
+        export_schedule::{
+            CreateExportSchedulePayload, ExportDestination, ExportPeriod, ExportRunStatus,
+            ExportSchedule, UpdateExportSchedulePayload,
+        },
+        export_template::{
+            CreateExportTemplatePayload, ExportColumn, ExportDelimiter, ExportEncoding,
+            ExportField, ExportFieldInfo, ExportGranularity, ExportTemplate,
+            UpdateExportTemplatePayload,
+        },
+        health_interview::{
+            HealthInterview, HealthInterviewPayload, HealthInterviewStatus, LongHoursEmployee,
+        },
         holiday::{
             AdminHolidayKind, AdminHolidayListItem, CreateHolidayPayload,
             CreateWeeklyHolidayPayload, GoogleHolidayCandidate, HolidayResponse,
             WeeklyHolidayResponse,
         },
         holiday_exception::{CreateHolidayExceptionPayload, HolidayExceptionResponse},
-        leave_request::{CreateLeaveRequest, LeaveRequestResponse, LeaveType},
+        leave_request::{CreateLeaveRequest, LeaveRequestResponse, LeaveType, LeaveUnit},
+        leave_type::{CreateLeaveTypePayload, LeaveTypeDefinition, UpdateLeaveTypePayload},
+        overtime::{DailyOvertime, OvertimeDayStatus, OvertimeReconciliation},
+        overtime_limit::{
+            OvertimeLimit, OvertimeLimitCheck, OvertimeLimitKind, OvertimeLimitLevel,
+            OvertimeLimitPayload, OvertimeLimitStatus,
+        },
         overtime_request::{CreateOvertimeRequest, OvertimeRequestResponse},
         password_reset::{RequestPasswordResetPayload, ResetPasswordPayload},
+        period_closing::{ClosePeriodPayload, PeriodClosing},
         request::RequestStatus,
+        rest_day::ConsecutiveWorkdayStreak,
+        rest_interval::{
+            RestIntervalMode, RestIntervalPolicy, RestIntervalPolicyPayload, RestIntervalViolation,
+            RestShortfall,
+        },
         subject_request::{
             CreateDataSubjectRequest, DataSubjectRequestResponse, DataSubjectRequestType,
         },
+        timesheet::{
+            TimesheetBlockers, TimesheetDecisionPayload, TimesheetMonthPayload,
+            TimesheetMonthStatus, TimesheetStatus, TimesheetSubmission,
+            TimesheetSubmissionListItem,
+        },
         user::{
             ChangePasswordRequest, CreateUser, LoginRequest, LoginResponse, MfaCodeRequest,
             MfaSetupResponse, MfaStatusResponse, UpdateProfile, UpdateUser, UserResponse,
         },
+        work_schedule::{
+            CreateWorkScheduleAssignmentPayload, CreateWorkSchedulePayload, FlextimeBalance,
+            UpdateWorkSchedulePayload, WorkRuleKind, WorkSchedule, WorkScheduleAssignment,
+        },
+        work_time::WorkTimeBreakdown,
         PaginationQuery,
     },
 };
@@ -92,6 +134,7 @@ struct RequestCancellationResponse {
         my_attendance_summary_doc,
         attendance_breaks_doc,
         export_attendance_doc,
+        my_attendance_report_doc,
         create_attendance_correction_doc,
         list_my_attendance_corrections_doc,
         get_my_attendance_correction_doc,
@@ -134,6 +177,7 @@ struct RequestCancellationResponse {
         admin_create_weekly_holiday_doc,
         admin_delete_weekly_holiday_doc,
         admin_export_doc,
+        admin_department_attendance_report_doc,
         admin_list_audit_logs_doc,
         admin_get_audit_log_doc,
         admin_export_audit_logs_doc,
@@ -177,6 +221,7 @@ struct RequestCancellationResponse {
             BreakEndRequest,
             AttendanceResponse,
             AttendanceSummary,
+            WorkTimeBreakdown,
             AttendanceStatusResponse,
             BreakRecordResponse,
             ActiveBreakResponse,
@@ -189,12 +234,58 @@ struct RequestCancellationResponse {
             AttendanceCorrectionSnapshot,
             CorrectionBreakItem,
             AttendanceCorrectionDecisionPayload,
+            OpenRecordSweep,
+            OpenRecordSweepAction,
+            // work schedules
+            WorkSchedule,
+            WorkRuleKind,
+            FlextimeBalance,
+            CreateWorkSchedulePayload,
+            UpdateWorkSchedulePayload,
+            WorkScheduleAssignment,
+            CreateWorkScheduleAssignmentPayload,
             // requests
             CreateLeaveRequest,
             LeaveRequestResponse,
             LeaveType,
+            LeaveUnit,
+            LeaveTypeDefinition,
+            CreateLeaveTypePayload,
+            UpdateLeaveTypePayload,
             CreateOvertimeRequest,
             OvertimeRequestResponse,
+            OvertimeReconciliation,
+            DailyOvertime,
+            OvertimeDayStatus,
+            OvertimeLimit,
+            OvertimeLimitPayload,
+            OvertimeLimitStatus,
+            OvertimeLimitCheck,
+            OvertimeLimitKind,
+            OvertimeLimitLevel,
+            BreakPolicy,
+            BreakPolicyMode,
+            BreakPolicyPayload,
+            BreakViolation,
+            RestIntervalMode,
+            RestIntervalPolicy,
+            RestIntervalPolicyPayload,
+            RestIntervalViolation,
+            RestShortfall,
+            ConsecutiveWorkdayStreak,
+            HealthInterviewStatus,
+            HealthInterview,
+            HealthInterviewPayload,
+            LongHoursEmployee,
+            PeriodClosing,
+            ClosePeriodPayload,
+            TimesheetStatus,
+            TimesheetSubmission,
+            TimesheetSubmissionListItem,
+            TimesheetBlockers,
+            TimesheetMonthStatus,
+            TimesheetMonthPayload,
+            TimesheetDecisionPayload,
             RequestStatus,
             CreateDataSubjectRequest,
             DataSubjectRequestResponse,
@@ -230,6 +321,29 @@ struct RequestCancellationResponse {
             AdminHolidayListItem,
             AdminSessionResponse,
             ExportQuery,
+            ExportTemplate,
+            ExportGranularity,
+            ExportEncoding,
+            ExportDelimiter,
+            ExportField,
+            ExportColumn,
+            ExportFieldInfo,
+            CreateExportTemplatePayload,
+            UpdateExportTemplatePayload,
+            ExportJob,
+            ExportJobKind,
+            ExportJobStatus,
+            CreateExportJobPayload,
+            ExportDownloadResponse,
+            ExportDownloadQuery,
+            ExportEncryptionMethod,
+            ExportEncryptionQuery,
+            ExportSchedule,
+            ExportPeriod,
+            ExportDestination,
+            ExportRunStatus,
+            CreateExportSchedulePayload,
+            UpdateExportSchedulePayload,
             AuditLogListQuery,
             AuditLogListResponse,
             AuditLogResponse,
@@ -491,6 +605,17 @@ fn attendance_breaks_doc() {}
 )]
 fn export_attendance_doc() {}
 
+#[utoipa::path(
+    get,
+    path = "/api/attendance/me/report",
+    params(AttendanceQuery),
+    responses(
+        (status = 200, description = "出勤簿 PDF", content_type = "application/pdf", body = Vec<u8>)
+    ),
+    tag = "Attendance"
+)]
+fn my_attendance_report_doc() {}
+
 #[utoipa::path(
     post,
     path = "/api/attendance-corrections",
@@ -886,12 +1011,27 @@ fn admin_delete_weekly_holiday_doc() {}
 #[utoipa::path(
     get,
     path = "/api/admin/export",
-    params(ExportQuery),
-    responses((status = 200, body = serde_json::Value)),
+    params(ExportQuery, ExportEncryptionQuery),
+    responses((
+        status = 200,
+        body = serde_json::Value,
+        description = "`csv_data` と `filename` を含む JSON。`template` 指定時はテンプレートの形式の CSV ファイル。`encryption` 指定時は age で暗号化され、生成したパスフレーズは `passphrase` と `X-Export-Passphrase` ヘッダーで一度だけ返す"
+    )),
     tag = "Admin"
 )]
 fn admin_export_doc() {}
 
+#[utoipa::path(
+    get,
+    path = "/api/admin/departments/{id}/attendance-report",
+    params(("id" = String, Path, description = "Department ID"), AttendanceQuery),
+    responses(
+        (status = 200, description = "所属従業員ごとの出勤簿 PDF", content_type = "application/pdf", body = Vec<u8>)
+    ),
+    tag = "Admin"
+)]
+fn admin_department_attendance_report_doc() {}
+
 #[utoipa::path(
     get,
     path = "/api/admin/audit-logs",
@@ -913,8 +1053,12 @@ fn admin_get_audit_log_doc() {}
 #[utoipa::path(
     get,
     path = "/api/admin/audit-logs/export",
-    params(AuditLogExportQuery),
-    responses((status = 200, body = [AuditLogResponse])),
+    params(AuditLogExportQuery, ExportEncryptionQuery),
+    responses((
+        status = 200,
+        body = [AuditLogResponse],
+        description = "`encryption` 指定時は age で暗号化したファイル。パスフレーズは `X-Export-Passphrase` ヘッダーで一度だけ返す"
+    )),
     tag = "Admin"
 )]
 fn admin_export_audit_logs_doc() {}
@@ -1125,6 +1269,7 @@ mod tests {
             my_attendance_summary_doc,
             attendance_breaks_doc,
             export_attendance_doc,
+            my_attendance_report_doc,
             create_attendance_correction_doc,
             list_my_attendance_corrections_doc,
             get_my_attendance_correction_doc,
@@ -1167,6 +1312,7 @@ mod tests {
             admin_create_weekly_holiday_doc,
             admin_delete_weekly_holiday_doc,
             admin_export_doc,
+            admin_department_attendance_report_doc,
             admin_list_audit_logs_doc,
             admin_get_audit_log_doc,
             admin_export_audit_logs_doc,
@@ -11,14 +11,22 @@ use std::str::FromStr;
 use utoipa::ToSchema;
 
 use crate::repositories::attendance::{AttendanceRepository, AttendanceRepositoryTrait};
+use crate::repositories::attendance_sweep;
 use crate::repositories::break_record::BreakRecordRepository;
-use crate::repositories::repository::Repository;
-use crate::repositories::transaction;
+use crate::repositories::{leave_attendance, repository::Repository, transaction, work_time};
+use crate::services::{
+    attendance_closure, break_policy::BreakPolicyService, period_closing::PeriodClosingService,
+    work_schedule::WorkScheduleService, work_time::WorkTimeService,
+};
 use crate::state::AppState;
 use crate::{
     handlers::attendance::recalculate_total_hours,
     handlers::attendance_utils::{get_break_records, get_break_records_map},
-    models::{attendance::AttendanceResponse, user::User},
+    models::{
+        attendance::AttendanceResponse,
+        attendance_sweep::{OpenRecordSweep, OpenRecordSweepQuery},
+        user::User,
+    },
     utils::{encryption::decrypt_pii, time},
 };
 
@@ -43,10 +51,13 @@ pub async fn get_all_attendance(
 
     let attendance_ids: Vec<AttendanceId> = attendances.iter().map(|a| a.id).collect();
     let mut break_map = get_break_records_map(state.read_pool(), &attendance_ids).await?;
+    let work_time_map =
+        work_time::find_by_attendance_ids(state.read_pool(), &attendance_ids).await?;
 
     let mut data = Vec::new();
     for attendance in attendances {
         let break_records = break_map.remove(&attendance.id).unwrap_or_default();
+        let work_time = work_time_map.get(&attendance.id).copied();
         let response = AttendanceResponse {
             id: attendance.id,
             user_id: attendance.user_id,
@@ -56,6 +67,8 @@ pub async fn get_all_attendance(
             status: attendance.status,
             total_work_hours: attendance.total_work_hours,
             break_records,
+            work_time,
+            rest_interval_warning: None,
         };
         data.push(response);
     }
@@ -112,16 +125,24 @@ pub async fn upsert_attendance(
         None => None,
     };
 
-    let mut tx = transaction::begin_transaction(&state.write_pool).await?;
-
     // Parse and validate user_id
     let user_id_typed = UserId::from_str(&user_id)
         .map_err(|_| AppError::BadRequest("Invalid user_id format".into()))?;
+    PeriodClosingService::new(state.write_pool.clone())
+        .ensure_open(user_id_typed, date)
+        .await?;
+    let expectation = WorkScheduleService::new(state.write_pool.clone())
+        .day_expectation(user_id_typed, date)
+        .await?;
+
+    let mut tx = transaction::begin_transaction(&state.write_pool).await?;
 
     let attendance_repo = AttendanceRepository::new();
     let break_repo = BreakRecordRepository::new();
 
-    // ensure unique per user/date: delete existing and reinsert (basic upsert)
+    // ensure unique per user/date: delete existing and reinsert (basic upsert),
+    // keeping the day's link to approved leave
+    let leave_link = leave_attendance::find_leave_link(&mut *tx, user_id_typed, date).await?;
     attendance_repo
         .delete_by_user_and_date(&mut tx, user_id_typed, date)
         .await?;
@@ -134,7 +155,6 @@ pub async fn upsert_attendance(
     att.clock_in_time = Some(cin);
     att.clock_out_time = cout;
 
-    let mut total_break_minutes: i64 = 0;
     let mut pending_breaks: Vec<crate::models::break_record::BreakRecord> = Vec::new();
 
     if let Some(bks) = breaks {
@@ -161,22 +181,33 @@ pub async fn upsert_attendance(
                 let d = duration.num_minutes().max(0);
                 br.duration_minutes = Some(d as i32);
                 br.updated_at = now_utc;
-                total_break_minutes += d;
             }
             pending_breaks.push(br);
         }
     }
 
-    att.calculate_work_hours(total_break_minutes);
-
     attendance_repo.create_in_transaction(&mut tx, &att).await?;
 
+    if let Some(leave_request_id) = leave_link {
+        leave_attendance::mark_leave_day(&mut *tx, user_id_typed, date, leave_request_id).await?;
+    }
+
     // insert breaks
     for br in pending_breaks {
         break_repo.create_in_transaction(&mut tx, &br).await?;
     }
 
+    let break_minutes = BreakPolicyService::new(state.write_pool.clone())
+        .enforce(&mut tx, &att, time::now_utc(&state.config.time_zone))
+        .await?;
+    att.calculate_work_hours(break_minutes);
+    att.apply_day_expectation(&expectation);
+    attendance_repo.update_in_transaction(&mut tx, &att).await?;
+
     transaction::commit_transaction(tx).await?;
+    let work_time = WorkTimeService::new(state.write_pool.clone())
+        .refresh_week(att.user_id, att.date)
+        .await?;
 
     let breaks = get_break_records(&state.write_pool, att.id).await?;
     Ok(Json(AttendanceResponse {
@@ -188,6 +219,8 @@ pub async fn upsert_attendance(
         status: att.status,
         total_work_hours: att.total_work_hours,
         break_records: breaks,
+        work_time: work_time.get(&att.id).copied(),
+        rest_interval_warning: None,
     }))
 }
 
@@ -211,6 +244,27 @@ pub async fn list_active_breaks(
     Ok(Json(active_breaks))
 }
 
+// Admin: records swept for a forgotten clock-out
+pub async fn list_forgotten_clock_outs(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(pagination): Query<PaginationQuery>,
+    Query(query): Query<OpenRecordSweepQuery>,
+) -> Result<Json<Vec<OpenRecordSweep>>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let sweeps = attendance_sweep::list_sweeps(
+        state.read_pool(),
+        query.unresolved,
+        pagination.limit(),
+        pagination.offset(),
+    )
+    .await?;
+    Ok(Json(sweeps))
+}
+
 // Admin: force end a break
 // Admin: force end a break
 pub async fn force_end_break(
@@ -223,29 +277,24 @@ pub async fn force_end_break(
     }
     let break_record_id = BreakRecordId::from_str(&break_id)
         .map_err(|_| AppError::BadRequest("Invalid break record ID format".into()))?;
+    let break_record = BreakRecordRepository::new()
+        .find_by_id(&state.write_pool, break_record_id)
+        .await?;
+    let attendance = AttendanceRepository::new()
+        .find_optional_by_id(&state.write_pool, break_record.attendance_id)
+        .await?;
+    if let Some(attendance) = &attendance {
+        PeriodClosingService::new(state.write_pool.clone())
+            .ensure_open(attendance.user_id, attendance.date)
+            .await?;
+    }
     let now_local = time::now_in_timezone(&state.config.time_zone);
     let now_utc = now_local.with_timezone(&Utc);
     let now = now_local.naive_local();
-    let break_repo = BreakRecordRepository::new();
-    let mut rec = break_repo
-        .find_by_id(&state.write_pool, break_record_id)
+    let rec = attendance_closure::force_end_break(&state.write_pool, break_record_id, now, now_utc)
         .await?;
 
-    if rec.break_end_time.is_some() {
-        return Err(AppError::BadRequest("Break already ended".into()));
-    }
-    rec.break_end_time = Some(now);
-    let duration = now.signed_duration_since(rec.break_start_time);
-    rec.duration_minutes = Some(duration.num_minutes() as i32);
-    rec.updated_at = now_utc;
-
-    break_repo.update(&state.write_pool, &rec).await?;
-
-    let attendance_repo = AttendanceRepository::new();
-    if let Some(attendance) = attendance_repo
-        .find_optional_by_id(&state.write_pool, rec.attendance_id)
-        .await?
-    {
+    if let Some(attendance) = attendance {
         if attendance.clock_out_time.is_some() {
             recalculate_total_hours(&state.write_pool, attendance, now_utc).await?;
         }
@@ -11,6 +11,7 @@ use crate::models::user::User;
 use crate::repositories::attendance_correction_request::{
     ApproveAttendanceCorrectionRequestParams, AttendanceCorrectionRequestRepository,
 };
+use crate::services::{period_closing::PeriodClosingService, work_time::WorkTimeService};
 use crate::state::AppState;
 
 #[derive(Debug, Clone, Deserialize)]
@@ -107,6 +108,9 @@ pub async fn approve_attendance_correction_request(
     let request = repo.find_by_id(&state.write_pool, &id).await?;
     ensure_not_self_request(request.user_id, user.id)?;
     check_approval_authorization(&state.write_pool, &user, request.user_id).await?;
+    PeriodClosingService::new(state.write_pool.clone())
+        .ensure_open(request.user_id, request.date)
+        .await?;
 
     let original_snapshot = request
         .parse_original_snapshot()
@@ -128,6 +132,9 @@ pub async fn approve_attendance_correction_request(
         },
     )
     .await?;
+    WorkTimeService::new(state.write_pool.clone())
+        .refresh_week(request.user_id, request.date)
+        .await?;
 
     Ok(Json(serde_json::json!({ "message": "Request approved" })))
 }
@@ -147,6 +154,9 @@ pub async fn reject_attendance_correction_request(
     let request = repo.find_by_id(&state.write_pool, &id).await?;
     ensure_not_self_request(request.user_id, user.id)?;
     check_approval_authorization(&state.write_pool, &user, request.user_id).await?;
+    PeriodClosingService::new(state.write_pool.clone())
+        .ensure_open(request.user_id, request.date)
+        .await?;
     repo.reject(&state.write_pool, &id, user.id, &payload.comment)
         .await?;
     Ok(Json(serde_json::json!({ "message": "Request rejected" })))
@@ -0,0 +1,82 @@
+use axum::{
+    extract::{Extension, Path, Query, State},
+    response::Response,
+};
+use chrono::Datelike;
+use std::sync::Arc;
+
+use crate::{
+    error::AppError,
+    handlers::attendance::{month_range, pdf_attachment, AttendanceQuery},
+    models::user::User,
+    repositories::{attendance_report as report_repo, department},
+    services::{
+        attendance_report::{self, AttendanceReportService, ReportEmployee},
+        holiday::HolidayServiceTrait,
+    },
+    state::AppState,
+    utils::{encryption::decrypt_pii, pii::mask_name, time},
+};
+
+/// Attendance books of every member of a department for one month, as a single PDF with
+/// a page per employee. Managers may download the departments they manage.
+pub async fn export_department_attendance_report(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Extension(holiday_service): Extension<Arc<dyn HolidayServiceTrait>>,
+    Path(department_id): Path<String>,
+    Query(params): Query<AttendanceQuery>,
+) -> Result<Response, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    let department = department::find_department_by_id(state.read_pool(), &department_id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Department not found".into()))?;
+    if !user.is_system_admin()
+        && !department::can_manager_manage_department(state.read_pool(), user.id, &department_id)
+            .await?
+    {
+        return Err(AppError::Forbidden(
+            "Managers can only download reports for the departments they manage".into(),
+        ));
+    }
+
+    let now_local = time::now_in_timezone(&state.config.time_zone);
+    let year = params.year.unwrap_or_else(|| now_local.year());
+    let month = params.month.unwrap_or_else(|| now_local.month());
+    let (first_day, _) = month_range(year, month)?;
+
+    let members = report_repo::list_department_members(state.read_pool(), &department_id).await?;
+    if members.is_empty() {
+        return Err(AppError::NotFound("Department has no members".into()));
+    }
+
+    let mask_pii = !user.is_system_admin();
+    let service = AttendanceReportService::new(state.read_pool().clone(), holiday_service);
+    let mut reports = Vec::with_capacity(members.len());
+    for member in members {
+        let full_name =
+            decrypt_pii(&member.full_name, &state.config).unwrap_or_else(|_| "***".to_string());
+        let employee = ReportEmployee {
+            id: member.id,
+            username: member.username,
+            full_name: if mask_pii {
+                mask_name(&full_name)
+            } else {
+                full_name
+            },
+        };
+        reports.push(service.monthly_report(employee, first_day).await?);
+    }
+
+    let pdf = attendance_report::render_pdf(&state.config, reports).await?;
+    Ok(pdf_attachment(
+        pdf,
+        &format!(
+            "attendance_report_{}_{}.pdf",
+            department.id,
+            first_day.format("%Y%m")
+        ),
+    ))
+}
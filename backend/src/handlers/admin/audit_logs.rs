@@ -9,26 +9,27 @@ use axum::{
     response::{IntoResponse, Response},
     Json,
 };
-use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 use utoipa::{IntoParams, ToSchema};
 
 use crate::{
     handlers::admin::common::{
-        normalize_filter, parse_filter_datetime, parse_from_datetime, parse_to_datetime,
+        insert_encryption_headers, normalize_filter, parse_filter_datetime, parse_from_datetime,
+        parse_to_datetime,
+    },
+    models::{
+        audit_log::{apply_pii_policy, AuditLogResponse},
+        export_encryption::ExportEncryptionQuery,
+        user::User,
     },
-    models::{audit_log::AuditLog, user::User},
     repositories::{
         audit_log::{self, AuditLogFilters},
         permissions,
     },
+    services::export_encryption::{resolve_encryption, ENCRYPTED_CONTENT_TYPE},
     state::AppState,
     types::{AuditLogId, UserId},
-    utils::{
-        pii::{mask_ip, mask_pii_json, mask_user_agent},
-        time,
-    },
+    utils::time,
 };
 use std::str::FromStr;
 
@@ -64,43 +65,6 @@ pub struct AuditLogExportQuery {
     pub result: Option<String>,
 }
 
-#[derive(Debug, Serialize, Deserialize, ToSchema)]
-pub struct AuditLogResponse {
-    pub id: String,
-    pub occurred_at: DateTime<Utc>,
-    pub actor_id: Option<String>,
-    pub actor_type: String,
-    pub event_type: String,
-    pub target_type: Option<String>,
-    pub target_id: Option<String>,
-    pub result: String,
-    pub error_code: Option<String>,
-    pub metadata: Option<Value>,
-    pub ip: Option<String>,
-    pub user_agent: Option<String>,
-    pub request_id: Option<String>,
-}
-
-impl From<AuditLog> for AuditLogResponse {
-    fn from(log: AuditLog) -> Self {
-        Self {
-            id: log.id.to_string(),
-            occurred_at: log.occurred_at,
-            actor_id: log.actor_id.map(|id| id.to_string()),
-            actor_type: log.actor_type,
-            event_type: log.event_type,
-            target_type: log.target_type,
-            target_id: log.target_id,
-            result: log.result,
-            error_code: log.error_code,
-            metadata: log.metadata.map(|value| value.0),
-            ip: log.ip,
-            user_agent: log.user_agent,
-            request_id: log.request_id,
-        }
-    }
-}
-
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
 pub struct AuditLogListResponse {
     pub page: i64,
@@ -109,17 +73,6 @@ pub struct AuditLogListResponse {
     pub items: Vec<AuditLogResponse>,
 }
 
-fn apply_pii_policy(mut response: AuditLogResponse, mask_pii: bool) -> AuditLogResponse {
-    if !mask_pii {
-        return response;
-    }
-
-    response.metadata = response.metadata.as_ref().map(mask_pii_json);
-    response.ip = response.ip.as_deref().map(mask_ip);
-    response.user_agent = response.user_agent.as_deref().map(mask_user_agent);
-    response
-}
-
 pub async fn list_audit_logs(
     State(state): State<AppState>,
     Extension(user): Extension<User>,
@@ -182,10 +135,17 @@ pub async fn export_audit_logs(
     State(state): State<AppState>,
     Extension(user): Extension<User>,
     Query(q): Query<AuditLogExportQuery>,
+    Query(encryption): Query<ExportEncryptionQuery>,
 ) -> Result<Response, AppError> {
     ensure_audit_log_access(&state.write_pool, &user).await?;
-
-    let filters = validate_export_query(q)?;
+    let encryption = resolve_encryption(
+        &state.config,
+        &user,
+        encryption.encryption,
+        encryption.recipient.as_deref(),
+    )?;
+
+    let filters = validate_export_query(q, Some(MAX_EXPORT_DAYS))?;
     let (logs, truncated) =
         audit_log::export_audit_logs(state.read_pool(), &filters, DEFAULT_EXPORT_MAX_ROWS)
             .await
@@ -197,16 +157,27 @@ pub async fn export_audit_logs(
         .map(AuditLogResponse::from)
         .map(|response| apply_pii_policy(response, mask_pii))
         .collect();
-    let body = serde_json::to_vec(&payload).map_err(|e| AppError::InternalServerError(e.into()))?;
+    let mut body =
+        serde_json::to_vec(&payload).map_err(|e| AppError::InternalServerError(e.into()))?;
 
-    let filename = format!(
+    let mut filename = format!(
         "audit_logs_{}.json",
         time::now_in_timezone(&state.config.time_zone).format("%Y%m%d_%H%M%S")
     );
+    let mut content_type = "application/json";
+    if let Some(encryption) = &encryption {
+        let file_encryption = encryption.clone();
+        body = tokio::task::spawn_blocking(move || file_encryption.encrypt(&body))
+            .await
+            .map_err(|e| AppError::InternalServerError(e.into()))?
+            .map_err(AppError::InternalServerError)?;
+        content_type = ENCRYPTED_CONTENT_TYPE;
+        filename = encryption.filename(&filename);
+    }
     let mut response = Response::new(Body::from(body));
     response
         .headers_mut()
-        .insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        .insert(CONTENT_TYPE, HeaderValue::from_static(content_type));
     response.headers_mut().insert(
         CONTENT_DISPOSITION,
         HeaderValue::from_str(&format!("attachment; filename=\"{}\"", filename))
@@ -220,10 +191,16 @@ pub async fn export_audit_logs(
         "X-Truncated",
         HeaderValue::from_static(if truncated { "true" } else { "false" }),
     );
+    if let Some(encryption) = &encryption {
+        insert_encryption_headers(response.headers_mut(), encryption);
+    }
     Ok(response)
 }
 
-async fn ensure_audit_log_access(pool: &sqlx::PgPool, user: &User) -> Result<(), AppError> {
+pub(crate) async fn ensure_audit_log_access(
+    pool: &sqlx::PgPool,
+    user: &User,
+) -> Result<(), AppError> {
     if user.is_system_admin() {
         return Ok(());
     }
@@ -264,7 +241,11 @@ fn validate_list_query(q: AuditLogListQuery) -> Result<(i64, i64, AuditLogFilter
 
 const MAX_EXPORT_DAYS: i64 = 31;
 
-fn validate_export_query(q: AuditLogExportQuery) -> Result<AuditLogFilters, AppError> {
+/// Validates export filters; `max_days` caps the period, export jobs pass `None`.
+pub(crate) fn validate_export_query(
+    q: AuditLogExportQuery,
+    max_days: Option<i64>,
+) -> Result<AuditLogFilters, AppError> {
     let from = parse_filter_datetime(&q.from, false).ok_or_else(|| {
         AppError::BadRequest("`from` must be a valid datetime (RFC3339 or YYYY-MM-DD)".into())
     })?;
@@ -279,10 +260,10 @@ fn validate_export_query(q: AuditLogExportQuery) -> Result<AuditLogFilters, AppE
     }
 
     let calendar_days = (to.date_naive() - from.date_naive()).num_days();
-    if calendar_days > MAX_EXPORT_DAYS {
+    if let Some(max_days) = max_days.filter(|max_days| calendar_days > *max_days) {
         return Err(AppError::BadRequest(format!(
             "エクスポート期間は最大{}日です",
-            MAX_EXPORT_DAYS
+            max_days
         )));
     }
 
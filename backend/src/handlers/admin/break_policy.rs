@@ -0,0 +1,57 @@
+use axum::{
+    extract::{Extension, State},
+    Json,
+};
+use chrono::Utc;
+use validator::Validate;
+
+use crate::{
+    error::AppError,
+    models::{
+        break_policy::{BreakPolicy, BreakPolicyPayload},
+        user::User,
+    },
+    repositories::break_policy,
+    services::break_policy::BreakPolicyService,
+    state::AppState,
+};
+
+pub async fn get_break_policy(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<Json<BreakPolicy>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    let policy = BreakPolicyService::new(state.read_pool().clone())
+        .policy()
+        .await?;
+    Ok(Json(policy))
+}
+
+pub async fn update_break_policy(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Json(payload): Json<BreakPolicyPayload>,
+) -> Result<Json<BreakPolicy>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    payload.validate()?;
+    if payload.second_threshold_minutes < payload.first_threshold_minutes
+        || payload.second_break_minutes < payload.first_break_minutes
+    {
+        return Err(AppError::BadRequest(
+            "The second tier cannot be lower than the first".into(),
+        ));
+    }
+
+    let mut policy = BreakPolicyService::new(state.write_pool.clone())
+        .policy()
+        .await?;
+    policy.apply(&payload);
+    policy.updated_at = Utc::now();
+    Ok(Json(
+        break_policy::save_policy(&state.write_pool, &policy).await?,
+    ))
+}
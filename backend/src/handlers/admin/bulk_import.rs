@@ -119,30 +119,31 @@ pub async fn import_departments(
         if name.is_empty() {
             continue;
         }
-        if !parent_name.is_empty() && !csv_names.contains(parent_name.as_str()) {
-            if !resolved_parent_ids.contains_key(parent_name) {
-                let matches = dept_repo::find_departments_by_name(&state.write_pool, parent_name)
-                    .await
-                    .map_err(|e| AppError::InternalServerError(e.into()))?;
-                match matches.as_slice() {
-                    [] => errors.push(ImportRowError {
-                        row: row_num,
-                        message: format!(
-                            "Parent department '{}' not found in CSV or database",
-                            parent_name
-                        ),
-                    }),
-                    [dept] => {
-                        resolved_parent_ids.insert(parent_name.clone(), dept.id.to_string());
-                    }
-                    _ => errors.push(ImportRowError {
-                        row: row_num,
-                        message: format!(
-                            "Parent department '{}' is ambiguous; multiple departments match",
-                            parent_name
-                        ),
-                    }),
+        if !parent_name.is_empty()
+            && !csv_names.contains(parent_name.as_str())
+            && !resolved_parent_ids.contains_key(parent_name)
+        {
+            let matches = dept_repo::find_departments_by_name(&state.write_pool, parent_name)
+                .await
+                .map_err(|e| AppError::InternalServerError(e.into()))?;
+            match matches.as_slice() {
+                [] => errors.push(ImportRowError {
+                    row: row_num,
+                    message: format!(
+                        "Parent department '{}' not found in CSV or database",
+                        parent_name
+                    ),
+                }),
+                [dept] => {
+                    resolved_parent_ids.insert(parent_name.clone(), dept.id.to_string());
                 }
+                _ => errors.push(ImportRowError {
+                    row: row_num,
+                    message: format!(
+                        "Parent department '{}' is ambiguous; multiple departments match",
+                        parent_name
+                    ),
+                }),
             }
         }
     }
@@ -1,7 +1,11 @@
+use axum::http::{HeaderMap, HeaderValue};
 use chrono::{DateTime, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 use sqlx::{PgPool, Postgres, QueryBuilder};
 
-use crate::{error::AppError, models::user::User, types::UserId};
+use crate::{
+    error::AppError, models::user::User, services::export_encryption::ExportEncryption,
+    types::UserId,
+};
 
 pub fn parse_date_value(value: &str) -> Option<NaiveDate> {
     if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
@@ -22,6 +26,25 @@ pub fn parse_optional_date(raw: Option<&str>) -> Result<Option<NaiveDate>, &'sta
     }
 }
 
+/// Marks an export response as encrypted; with passphrase encryption it also carries the
+/// passphrase, which is not kept anywhere else.
+pub fn insert_encryption_headers(headers: &mut HeaderMap, encryption: &ExportEncryption) {
+    headers.insert(
+        "X-Export-Encryption",
+        HeaderValue::from_static(match encryption {
+            ExportEncryption::Age(_) => "age",
+            ExportEncryption::Passphrase(_) => "passphrase",
+        }),
+    );
+    if let Some(passphrase) = encryption
+        .passphrase()
+        .and_then(|passphrase| HeaderValue::from_str(passphrase).ok())
+    {
+        headers.insert("X-Export-Passphrase", passphrase);
+    }
+    headers.insert("Cache-Control", HeaderValue::from_static("no-store"));
+}
+
 pub fn normalize_filter(value: Option<String>) -> Option<String> {
     value
         .map(|value| value.trim().to_string())
@@ -0,0 +1,211 @@
+use axum::{
+    extract::{Extension, Query, State},
+    Json,
+};
+use chrono::{Datelike, Months, NaiveDate};
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use crate::{
+    error::AppError,
+    handlers::attendance::month_range,
+    models::{
+        break_policy::{BreakViolation, BreakViolationQuery},
+        leave_compliance::{LeaveComplianceItem, LeaveComplianceQuery},
+        overtime_limit::{OvertimeLimitLevel, OvertimeLimitReportQuery, OvertimeLimitStatus},
+        rest_day::{ConsecutiveWorkdayQuery, ConsecutiveWorkdayStreak},
+        rest_interval::{RestIntervalViolation, RestIntervalViolationQuery},
+        user::User,
+    },
+    repositories::{break_policy, department, overtime, rest_interval},
+    services::{
+        leave_compliance::LeaveComplianceService, overtime_limit::OvertimeLimitService,
+        rest_day::RestDayService,
+    },
+    state::AppState,
+    types::{DepartmentId, UserId},
+    utils::time,
+};
+
+/// Users a manager may see in compliance reports; `None` means everyone (system admins).
+pub(super) async fn visible_user_ids(
+    state: &AppState,
+    user: &User,
+) -> Result<Option<HashSet<UserId>>, AppError> {
+    if user.is_system_admin() {
+        return Ok(None);
+    }
+    let ids = department::list_subordinate_user_ids(state.read_pool(), user.id).await?;
+    Ok(Some(
+        ids.iter()
+            .filter_map(|id| UserId::from_str(id).ok())
+            .collect(),
+    ))
+}
+
+pub async fn get_annual_leave_compliance(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<LeaveComplianceQuery>,
+) -> Result<Json<Vec<LeaveComplianceItem>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let visible_users = visible_user_ids(&state, &user).await?;
+    let as_of = query
+        .as_of
+        .unwrap_or_else(|| time::today_local(&state.config.time_zone));
+    let items = LeaveComplianceService::new(state.read_pool().clone())
+        .report(as_of, visible_users.as_ref())
+        .await?;
+    Ok(Json(items))
+}
+
+pub async fn get_overtime_compliance(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<OvertimeLimitReportQuery>,
+) -> Result<Json<Vec<OvertimeLimitStatus>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let now_local = time::now_in_timezone(&state.config.time_zone);
+    let year = query.year.unwrap_or_else(|| now_local.year());
+    let month = query.month.unwrap_or_else(|| now_local.month());
+    let (first_day, last_day) = month_range(year, month)?;
+    let min_level = query.min_level.unwrap_or(OvertimeLimitLevel::Ok);
+
+    // Annual limits look back at most a year, so only employees active since then can breach.
+    let since = first_day
+        .checked_sub_months(Months::new(11))
+        .unwrap_or(NaiveDate::MIN);
+    let visible_users = visible_user_ids(&state, &user).await?;
+    let employees = overtime::list_users_with_activity(state.read_pool(), since, last_day).await?;
+
+    let limits = OvertimeLimitService::new(state.read_pool().clone());
+    let mut statuses = Vec::new();
+    for (user_id, username) in employees {
+        if visible_users
+            .as_ref()
+            .is_some_and(|visible| !visible.contains(&user_id))
+        {
+            continue;
+        }
+        let (limit, hours) = limits.limit_inputs(user_id, first_day).await?;
+        let status = OvertimeLimitStatus::evaluate(user_id, username, first_day, &limit, &hours);
+        if status.level >= min_level {
+            statuses.push(status);
+        }
+    }
+    statuses.sort_by(|a, b| b.level.cmp(&a.level));
+    Ok(Json(statuses))
+}
+
+pub async fn get_break_compliance(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<BreakViolationQuery>,
+) -> Result<Json<Vec<BreakViolation>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let today = time::today_local(&state.config.time_zone);
+    let to = query.to.unwrap_or(today);
+    let from = query.from.unwrap_or_else(|| time::month_start(to));
+    if from > to {
+        return Err(AppError::BadRequest(
+            "`from` must be before or equal to `to`".into(),
+        ));
+    }
+
+    let visible_users = visible_user_ids(&state, &user).await?;
+    let violations = break_policy::list_violations(state.read_pool(), from, to)
+        .await?
+        .into_iter()
+        .filter(|violation| {
+            visible_users
+                .as_ref()
+                .is_none_or(|visible| visible.contains(&violation.user_id))
+        })
+        .collect();
+    Ok(Json(violations))
+}
+
+pub async fn get_rest_interval_compliance(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<RestIntervalViolationQuery>,
+) -> Result<Json<Vec<RestIntervalViolation>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let today = time::today_local(&state.config.time_zone);
+    let to = query.to.unwrap_or(today);
+    let from = query.from.unwrap_or_else(|| time::month_start(to));
+    if from > to {
+        return Err(AppError::BadRequest(
+            "`from` must be before or equal to `to`".into(),
+        ));
+    }
+    let department_id = query
+        .department_id
+        .as_deref()
+        .map(parse_department_id)
+        .transpose()?;
+
+    let visible_users = visible_user_ids(&state, &user).await?;
+    let violations = rest_interval::list_violations(state.read_pool(), from, to, department_id)
+        .await?
+        .into_iter()
+        .filter(|violation| {
+            visible_users
+                .as_ref()
+                .is_none_or(|visible| visible.contains(&violation.user_id))
+        })
+        .collect();
+    Ok(Json(violations))
+}
+
+pub async fn get_consecutive_workday_compliance(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<ConsecutiveWorkdayQuery>,
+) -> Result<Json<Vec<ConsecutiveWorkdayStreak>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let today = time::today_local(&state.config.time_zone);
+    let to = query.to.unwrap_or(today);
+    let from = query.from.unwrap_or_else(|| time::month_start(to));
+    if from > to {
+        return Err(AppError::BadRequest(
+            "`from` must be before or equal to `to`".into(),
+        ));
+    }
+    let department_id = query
+        .department_id
+        .as_deref()
+        .map(parse_department_id)
+        .transpose()?;
+
+    let visible_users = visible_user_ids(&state, &user).await?;
+    let streaks = RestDayService::new(state.read_pool().clone())
+        .report(
+            from,
+            to,
+            state.config.consecutive_workday_limit,
+            department_id,
+            visible_users.as_ref(),
+        )
+        .await?;
+    Ok(Json(streaks))
+}
+
+pub(super) fn parse_department_id(raw: &str) -> Result<DepartmentId, AppError> {
+    DepartmentId::from_str(raw).map_err(|_| AppError::BadRequest("Invalid department ID".into()))
+}
@@ -1,9 +1,13 @@
 use axum::{
     extract::{Extension, Query, State},
-    http::{HeaderMap, HeaderValue},
-    response::IntoResponse,
+    http::{
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+        HeaderMap, HeaderValue,
+    },
+    response::{IntoResponse, Response},
     Json,
 };
+use chrono::NaiveDate;
 use serde::Deserialize;
 use serde_json::json;
 use sqlx::{Postgres, QueryBuilder, Row};
@@ -11,18 +15,25 @@ use utoipa::{IntoParams, ToSchema};
 
 use crate::{
     error::AppError,
-    models::user::User,
+    models::{export_encryption::ExportEncryptionQuery, user::User},
+    repositories::export_template::{self, ExportRecordFilter},
+    services::{
+        export_encryption::{resolve_encryption, ExportEncryption, ENCRYPTED_CONTENT_TYPE},
+        export_template as template_export,
+    },
     state::AppState,
     utils::{csv::append_csv_row, encryption::decrypt_pii, pii::mask_name, time},
 };
 
-use super::common::{parse_date_value, push_clause};
+use super::common::{insert_encryption_headers, parse_date_value, push_clause};
 
 #[derive(Deserialize, ToSchema, IntoParams)]
 pub struct ExportQuery {
     pub username: Option<String>,
     pub from: Option<String>, // YYYY-MM-DD
     pub to: Option<String>,   // YYYY-MM-DD
+    /// ID or name of an export template; the CSV is then returned as a file in its layout.
+    pub template: Option<String>,
 }
 
 struct ExportRow {
@@ -33,50 +44,45 @@ struct ExportRow {
     clock_out: String,
     total_hours: String,
     status: String,
+    leave_type: String,
+    work_time: [String; 5],
 }
 
 pub async fn export_data(
     State(state): State<AppState>,
     Extension(user): Extension<User>,
     Query(q): Query<ExportQuery>,
-) -> Result<impl IntoResponse, AppError> {
+    Query(encryption): Query<ExportEncryptionQuery>,
+) -> Result<Response, AppError> {
     if !(user.is_manager() || user.is_system_admin()) {
         return Err(AppError::Forbidden("Forbidden".into()));
     }
-    // Build filtered SQL
-    let parsed_from = match q.from.as_deref() {
-        Some(raw) => parse_date_value(raw)
-            .ok_or(AppError::BadRequest("`from` must be a valid date".into()))
-            .map(Some)?,
-        None => None,
-    };
-    let parsed_to = match q.to.as_deref() {
-        Some(raw) => parse_date_value(raw)
-            .ok_or(AppError::BadRequest("`to` must be a valid date".into()))
-            .map(Some)?,
-        None => None,
-    };
-    if let (Some(from), Some(to)) = (parsed_from, parsed_to) {
-        if from > to {
-            return Err(AppError::BadRequest(
-                "`from` must be on or before `to`".into(),
-            ));
-        }
-    }
+    let encryption = resolve_encryption(
+        &state.config,
+        &user,
+        encryption.encryption,
+        encryption.recipient.as_deref(),
+    )?;
+    let (parsed_from, parsed_to) = parse_export_range(q.from.as_deref(), q.to.as_deref())?;
+    let manager_user_ids = export_scope(&state, &user).await?;
 
-    let manager_user_ids: Option<Vec<String>> = if user.is_manager() && !user.is_system_admin() {
-        let ids =
-            crate::repositories::department::list_subordinate_user_ids(state.read_pool(), user.id)
-                .await
-                .map_err(|e| AppError::InternalServerError(e.into()))?;
-        Some(ids)
-    } else {
-        None
-    };
+    if let Some(reference) = q.template.as_deref() {
+        let filter = ExportRecordFilter {
+            username: q.username.clone(),
+            user_ids: manager_user_ids,
+            from: parsed_from,
+            to: parsed_to,
+        };
+        return export_with_template(&state, &user, reference, &filter, encryption).await;
+    }
 
     let mut builder: QueryBuilder<Postgres> = QueryBuilder::new(
-        "SELECT u.username, COALESCE(u.full_name_enc, '') as full_name, a.date, a.clock_in_time, a.clock_out_time, a.total_work_hours, a.status \
-         FROM attendance a JOIN users u ON a.user_id = u.id",
+        "SELECT u.username, COALESCE(u.full_name_enc, '') as full_name, a.date, a.clock_in_time, a.clock_out_time, a.total_work_hours, a.status, \
+                l.leave_type, w.regular_hours, w.statutory_overtime_hours, w.late_night_hours, \
+                w.legal_holiday_hours, w.non_statutory_holiday_hours \
+         FROM attendance a JOIN users u ON a.user_id = u.id \
+         LEFT JOIN leave_requests l ON l.id = a.leave_request_id \
+         LEFT JOIN attendance_work_time w ON w.attendance_id = a.id",
     );
     let mut has_clause = false;
     if let Some(ref u_name) = q.username {
@@ -141,6 +147,26 @@ pub async fn export_data(
                 .map(|h| format!("{:.2}", h))
                 .unwrap_or_else(|_| "0.00".to_string());
             let status = record.try_get::<String, _>("status").unwrap_or_default();
+            let leave_type = record
+                .try_get::<Option<String>, _>("leave_type")
+                .ok()
+                .flatten()
+                .unwrap_or_default();
+            let hours = |column: &str| {
+                record
+                    .try_get::<Option<f64>, _>(column)
+                    .ok()
+                    .flatten()
+                    .map(|h| format!("{:.2}", h))
+                    .unwrap_or_default()
+            };
+            let work_time = [
+                hours("regular_hours"),
+                hours("statutory_overtime_hours"),
+                hours("late_night_hours"),
+                hours("legal_holiday_hours"),
+                hours("non_statutory_holiday_hours"),
+            ];
 
             ExportRow {
                 username,
@@ -150,11 +176,18 @@ pub async fn export_data(
                 clock_out,
                 total_hours,
                 status,
+                leave_type,
+                work_time,
             }
         })
         .collect();
 
-    let csv_data = tokio::task::spawn_blocking(move || {
+    let filename = format!(
+        "attendance_export_{}.csv",
+        time::now_in_timezone(&state.config.time_zone).format("%Y%m%d_%H%M%S")
+    );
+    let file_encryption = encryption.clone();
+    let csv_data = tokio::task::spawn_blocking(move || -> anyhow::Result<String> {
         let mut csv = String::new();
         append_csv_row(
             &mut csv,
@@ -166,10 +199,18 @@ pub async fn export_data(
                 "Clock Out".to_string(),
                 "Total Hours".to_string(),
                 "Status".to_string(),
+                "Leave Type".to_string(),
+                "Regular Hours".to_string(),
+                "Statutory Overtime Hours".to_string(),
+                "Late Night Hours".to_string(),
+                "Legal Holiday Hours".to_string(),
+                "Non-statutory Holiday Hours".to_string(),
             ],
         );
 
         for row in rows {
+            let [regular, overtime, late_night, legal_holiday, non_statutory_holiday] =
+                row.work_time;
             append_csv_row(
                 &mut csv,
                 &[
@@ -180,14 +221,144 @@ pub async fn export_data(
                     row.clock_out,
                     row.total_hours,
                     row.status,
+                    row.leave_type,
+                    regular,
+                    overtime,
+                    late_night,
+                    legal_holiday,
+                    non_statutory_holiday,
                 ],
             );
         }
-        csv
+        // Armored age output is ASCII, so it still fits the JSON string.
+        match file_encryption {
+            Some(encryption) => Ok(String::from_utf8(encryption.encrypt(csv.as_bytes())?)?),
+            None => Ok(csv),
+        }
+    })
+    .await
+    .map_err(|e| AppError::InternalServerError(e.into()))?
+    .map_err(AppError::InternalServerError)?;
+
+    let mut headers = pii_masked_headers(&user);
+    let mut body = json!({
+        "csv_data": csv_data,
+        "filename": filename
+    });
+    if let Some(encryption) = &encryption {
+        insert_encryption_headers(&mut headers, encryption);
+        body["filename"] = json!(encryption.filename(&filename));
+        body["encryption"] = json!(encryption.method());
+        if let Some(passphrase) = encryption.passphrase() {
+            body["passphrase"] = json!(passphrase);
+        }
+    }
+    Ok((headers, Json(body)).into_response())
+}
+
+/// Runs an export template and returns the file it describes as an attachment.
+async fn export_with_template(
+    state: &AppState,
+    user: &User,
+    reference: &str,
+    filter: &ExportRecordFilter,
+    encryption: Option<ExportEncryption>,
+) -> Result<Response, AppError> {
+    let template = export_template::find_template_by_reference(state.read_pool(), reference)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Export template not found".into()))?;
+    let mut records = export_template::list_export_records(state.read_pool(), filter).await?;
+
+    let mask_pii = !user.is_system_admin();
+    for record in &mut records {
+        let full_name =
+            decrypt_pii(&record.full_name, &state.config).unwrap_or_else(|_| "***".to_string());
+        record.full_name = if mask_pii {
+            mask_name(&full_name)
+        } else {
+            full_name
+        };
+    }
+
+    let mut content_type = format!("text/csv; charset={}", template.encoding.charset());
+    let mut filename = format!(
+        "payroll_export_{}.csv",
+        time::now_in_timezone(&state.config.time_zone).format("%Y%m%d_%H%M%S")
+    );
+    let file_encryption = encryption.clone();
+    let file = tokio::task::spawn_blocking(move || {
+        let file = template_export::render(&template, records);
+        match file_encryption {
+            Some(encryption) => encryption.encrypt(&file),
+            None => Ok(file),
+        }
     })
     .await
-    .map_err(|e| AppError::InternalServerError(e.into()))?;
+    .map_err(|e| AppError::InternalServerError(e.into()))?
+    .map_err(AppError::InternalServerError)?;
+
+    let mut headers = pii_masked_headers(user);
+    if let Some(encryption) = &encryption {
+        insert_encryption_headers(&mut headers, encryption);
+        content_type = ENCRYPTED_CONTENT_TYPE.to_string();
+        filename = encryption.filename(&filename);
+    }
+    headers.insert(
+        CONTENT_TYPE,
+        HeaderValue::from_str(&content_type).unwrap_or(HeaderValue::from_static("text/csv")),
+    );
+    headers.insert(
+        CONTENT_DISPOSITION,
+        HeaderValue::from_str(&format!("attachment; filename=\"{}\"", filename))
+            .unwrap_or_else(|_| HeaderValue::from_static("attachment")),
+    );
+    Ok((headers, file).into_response())
+}
+
+/// Parses the optional `from`/`to` dates of an attendance export.
+pub(super) fn parse_export_range(
+    from: Option<&str>,
+    to: Option<&str>,
+) -> Result<(Option<NaiveDate>, Option<NaiveDate>), AppError> {
+    let parsed_from = match from {
+        Some(raw) => parse_date_value(raw)
+            .ok_or(AppError::BadRequest("`from` must be a valid date".into()))
+            .map(Some)?,
+        None => None,
+    };
+    let parsed_to = match to {
+        Some(raw) => parse_date_value(raw)
+            .ok_or(AppError::BadRequest("`to` must be a valid date".into()))
+            .map(Some)?,
+        None => None,
+    };
+    if let (Some(from), Some(to)) = (parsed_from, parsed_to) {
+        if from > to {
+            return Err(AppError::BadRequest(
+                "`from` must be on or before `to`".into(),
+            ));
+        }
+    }
+    Ok((parsed_from, parsed_to))
+}
+
+/// Users whose attendance `user` may export: subordinates for managers, everyone otherwise.
+pub(super) async fn export_scope(
+    state: &AppState,
+    user: &User,
+) -> Result<Option<Vec<String>>, AppError> {
+    if user.is_manager() && !user.is_system_admin() {
+        let ids =
+            crate::repositories::department::list_subordinate_user_ids(state.read_pool(), user.id)
+                .await
+                .map_err(|e| AppError::InternalServerError(e.into()))?;
+        Ok(Some(ids))
+    } else {
+        Ok(None)
+    }
+}
 
+fn pii_masked_headers(user: &User) -> HeaderMap {
     let mut headers = HeaderMap::new();
     headers.insert(
         "X-PII-Masked",
@@ -197,14 +368,5 @@ pub async fn export_data(
             "true"
         }),
     );
-    Ok((
-        headers,
-        Json(json!({
-            "csv_data": csv_data,
-            "filename": format!(
-                "attendance_export_{}.csv",
-                time::now_in_timezone(&state.config.time_zone).format("%Y%m%d_%H%M%S")
-            )
-        })),
-    ))
+    headers
 }
@@ -0,0 +1,249 @@
+use axum::{
+    body::Body,
+    extract::{Extension, Path, Query, State},
+    http::{
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+        HeaderValue, StatusCode,
+    },
+    response::Response,
+    Json,
+};
+use chrono::{Duration, Utc};
+use serde::Deserialize;
+use std::str::FromStr;
+use tokio_util::io::ReaderStream;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::{
+    error::AppError,
+    models::{
+        export_job::{
+            CreateExportJobPayload, ExportDownloadResponse, ExportJob, ExportJobKind,
+            ExportJobStatus,
+        },
+        user::User,
+        PaginatedResponse, PaginationQuery,
+    },
+    repositories::{
+        export_job,
+        export_template::{self, ExportRecordFilter},
+    },
+    services::{
+        export_encryption::resolve_encryption,
+        export_job::{new_job, ExportJobParameters},
+        export_storage::{verify_download, ExportStorage},
+    },
+    state::AppState,
+    types::ExportJobId,
+};
+
+use super::{
+    audit_logs::{ensure_audit_log_access, validate_export_query, AuditLogExportQuery},
+    export::{export_scope, parse_export_range},
+};
+
+/// Queues an export; a worker writes the file and the job reports its progress.
+///
+/// A passphrase generated for the job is in this response only.
+pub async fn create_export_job(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Json(payload): Json<CreateExportJobPayload>,
+) -> Result<(StatusCode, Json<ExportJob>), AppError> {
+    payload.validate()?;
+    let encryption = resolve_encryption(
+        &state.config,
+        &user,
+        payload.encryption,
+        payload.recipient.as_deref(),
+    )?;
+
+    let mask_pii = !user.is_system_admin();
+    let parameters = match payload.kind {
+        ExportJobKind::Attendance => {
+            if !(user.is_manager() || user.is_system_admin()) {
+                return Err(AppError::Forbidden("Forbidden".into()));
+            }
+            let (from, to) = parse_export_range(payload.from.as_deref(), payload.to.as_deref())?;
+            let template_id = match payload.template.as_deref() {
+                Some(reference) => Some(
+                    export_template::find_template_by_reference(state.read_pool(), reference)
+                        .await?
+                        .ok_or_else(|| AppError::NotFound("Export template not found".into()))?
+                        .id,
+                ),
+                None => None,
+            };
+            ExportJobParameters::Attendance {
+                filter: ExportRecordFilter {
+                    username: payload
+                        .username
+                        .map(|username| username.trim().to_string())
+                        .filter(|username| !username.is_empty()),
+                    user_ids: export_scope(&state, &user).await?,
+                    from,
+                    to,
+                },
+                template_id,
+                mask_pii,
+            }
+        }
+        ExportJobKind::AuditLogs => {
+            ensure_audit_log_access(&state.write_pool, &user).await?;
+            let (Some(from), Some(to)) = (payload.from, payload.to) else {
+                return Err(AppError::BadRequest(
+                    "`from` and `to` are required for audit log exports".into(),
+                ));
+            };
+            let filters = validate_export_query(
+                AuditLogExportQuery {
+                    from,
+                    to,
+                    actor_id: payload.actor_id,
+                    actor_type: payload.actor_type,
+                    event_type: payload.event_type,
+                    target_type: payload.target_type,
+                    target_id: payload.target_id,
+                    result: payload.result,
+                },
+                None,
+            )?;
+            ExportJobParameters::AuditLogs { filters, mask_pii }
+        }
+    };
+
+    let mut job =
+        new_job(user.id, &parameters).map_err(|e| AppError::InternalServerError(e.into()))?;
+    if let Some(encryption) = &encryption {
+        job.encryption = Some(encryption.method());
+        job.encryption_key = Some(
+            encryption
+                .seal(&state.config)
+                .map_err(AppError::InternalServerError)?,
+        );
+    }
+    let mut saved = export_job::insert_job(&state.write_pool, &job).await?;
+    saved.passphrase = encryption
+        .as_ref()
+        .and_then(|encryption| encryption.passphrase())
+        .map(str::to_string);
+    Ok((StatusCode::ACCEPTED, Json(saved)))
+}
+
+/// Export jobs, newest first: the caller's own, or everyone's for system administrators.
+pub async fn list_export_jobs(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(pagination): Query<PaginationQuery>,
+) -> Result<Json<PaginatedResponse<ExportJob>>, AppError> {
+    let requested_by = (!user.is_system_admin()).then_some(user.id);
+    let (limit, offset) = (pagination.limit(), pagination.offset());
+    let (jobs, total) =
+        export_job::list_jobs(state.read_pool(), requested_by, limit, offset).await?;
+    Ok(Json(PaginatedResponse::new(jobs, total, limit, offset)))
+}
+
+pub async fn get_export_job(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(id): Path<String>,
+) -> Result<Json<ExportJob>, AppError> {
+    let job = find_visible_job(&state, &user, &id).await?;
+    Ok(Json(job))
+}
+
+/// Issues a short-lived link to the file of a completed job.
+pub async fn get_export_job_download(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(id): Path<String>,
+) -> Result<Json<ExportDownloadResponse>, AppError> {
+    let job = find_visible_job(&state, &user, &id).await?;
+    let (ExportJobStatus::Completed, Some(key), Some(filename)) = (
+        job.status,
+        job.storage_key.as_deref(),
+        job.filename.as_deref(),
+    ) else {
+        return Err(AppError::Conflict("Export job has not completed".into()));
+    };
+
+    let expires_at =
+        Utc::now() + Duration::seconds(state.config.export_download_ttl_seconds as i64);
+    let storage = ExportStorage::from_config(&state.config).await;
+    let url = storage
+        .download_url(job.id, key, filename, expires_at, &state.config)
+        .await
+        .map_err(AppError::InternalServerError)?;
+    Ok(Json(ExportDownloadResponse { url, expires_at }))
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct ExportDownloadQuery {
+    /// Unix time after which the link stops working.
+    pub expires: i64,
+    pub signature: String,
+}
+
+/// Serves a locally stored export file to the holder of a signed link.
+pub async fn download_export_file(
+    State(state): State<AppState>,
+    Path(id): Path<String>,
+    Query(q): Query<ExportDownloadQuery>,
+) -> Result<Response, AppError> {
+    let invalid_link = || AppError::Forbidden("Download link is invalid or has expired".into());
+    let id = ExportJobId::from_str(&id).map_err(|_| invalid_link())?;
+    if !verify_download(
+        id,
+        q.expires,
+        &q.signature,
+        &state.config.export_download_secret,
+        Utc::now(),
+    ) {
+        return Err(invalid_link());
+    }
+
+    let job = export_job::find_job(state.read_pool(), id)
+        .await?
+        .filter(|job| job.status == ExportJobStatus::Completed)
+        .ok_or_else(|| AppError::NotFound("Export file not found".into()))?;
+    let storage = ExportStorage::from_config(&state.config).await;
+    let path = job
+        .storage_key
+        .as_deref()
+        .and_then(|key| storage.local_path(key))
+        .ok_or_else(|| AppError::NotFound("Export file not found".into()))?;
+    let file = tokio::fs::File::open(&path)
+        .await
+        .map_err(|_| AppError::NotFound("Export file not found".into()))?;
+
+    let mut response = Response::new(Body::from_stream(ReaderStream::new(file)));
+    let content_type = job
+        .content_type
+        .as_deref()
+        .unwrap_or("application/octet-stream");
+    response.headers_mut().insert(
+        CONTENT_TYPE,
+        HeaderValue::from_str(content_type)
+            .unwrap_or(HeaderValue::from_static("application/octet-stream")),
+    );
+    response.headers_mut().insert(
+        CONTENT_DISPOSITION,
+        HeaderValue::from_str(&format!(
+            "attachment; filename=\"{}\"",
+            job.filename.as_deref().unwrap_or("export")
+        ))
+        .unwrap_or_else(|_| HeaderValue::from_static("attachment")),
+    );
+    Ok(response)
+}
+
+/// Fetches a job the user may see; other users' jobs look absent unless they are a system admin.
+async fn find_visible_job(state: &AppState, user: &User, id: &str) -> Result<ExportJob, AppError> {
+    let id = ExportJobId::from_str(id)
+        .map_err(|_| AppError::BadRequest("Invalid export job ID".into()))?;
+    export_job::find_job(state.read_pool(), id)
+        .await?
+        .filter(|job| user.is_system_admin() || job.requested_by == user.id)
+        .ok_or_else(|| AppError::NotFound("Export job not found".into()))
+}
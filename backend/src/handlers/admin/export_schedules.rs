@@ -0,0 +1,205 @@
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::Utc;
+use serde_json::{json, Value};
+use std::str::FromStr;
+use validator::Validate;
+
+use crate::{
+    error::AppError,
+    models::{
+        export_schedule::{
+            CreateExportSchedulePayload, ExportSchedule, UpdateExportSchedulePayload,
+        },
+        user::User,
+    },
+    repositories::{export_schedule, export_template},
+    services::{export_encryption::ExportEncryption, export_schedule::next_run_after},
+    state::AppState,
+    types::{ExportScheduleId, ExportTemplateId},
+};
+
+pub async fn list_export_schedules(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<Json<Vec<ExportSchedule>>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let schedules = export_schedule::list_schedules(state.read_pool()).await?;
+    Ok(Json(schedules))
+}
+
+pub async fn create_export_schedule(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Json(payload): Json<CreateExportSchedulePayload>,
+) -> Result<(StatusCode, Json<ExportSchedule>), AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    payload.validate()?;
+    payload.destination.check().map_err(AppError::BadRequest)?;
+
+    let now = Utc::now();
+    let cron_expression = payload.cron_expression.trim().to_string();
+    let schedule = ExportSchedule {
+        id: ExportScheduleId::new(),
+        name: payload.name.trim().to_string(),
+        next_run_at: next_run_after(&cron_expression, &state.config.time_zone, now)
+            .map_err(AppError::BadRequest)?,
+        cron_expression,
+        template_id: resolve_template(&state, payload.template.as_deref()).await?,
+        username: normalize_username(payload.username),
+        period: payload.period,
+        destination: payload.destination,
+        encryption_recipient: normalize_recipient(payload.encryption_recipient)?,
+        enabled: payload.enabled.unwrap_or(true),
+        last_run_at: None,
+        last_run_status: None,
+        last_run_error: None,
+        created_by: Some(user.id),
+        created_at: now,
+        updated_at: now,
+    };
+    if export_schedule::schedule_name_taken(&state.write_pool, &schedule.name, None).await? {
+        return Err(AppError::Conflict(format!(
+            "Export schedule '{}' already exists",
+            schedule.name
+        )));
+    }
+
+    let saved = export_schedule::create_schedule(&state.write_pool, &schedule).await?;
+    Ok((StatusCode::CREATED, Json(saved)))
+}
+
+pub async fn update_export_schedule(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(id): Path<String>,
+    Json(payload): Json<UpdateExportSchedulePayload>,
+) -> Result<Json<ExportSchedule>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    payload.validate()?;
+
+    let id = parse_schedule_id(&id)?;
+    let mut schedule = export_schedule::find_schedule(&state.write_pool, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Export schedule not found".into()))?;
+
+    if let Some(name) = payload
+        .name
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+    {
+        schedule.name = name;
+    }
+    if let Some(template) = payload.template {
+        schedule.template_id = match template.trim() {
+            "" => None,
+            reference => resolve_template(&state, Some(reference)).await?,
+        };
+    }
+    if let Some(username) = payload.username {
+        schedule.username = normalize_username(Some(username));
+    }
+    if let Some(destination) = payload.destination {
+        destination.check().map_err(AppError::BadRequest)?;
+        schedule.destination = destination;
+    }
+    if let Some(recipient) = payload.encryption_recipient {
+        schedule.encryption_recipient = normalize_recipient(Some(recipient))?;
+    }
+    schedule.period = payload.period.unwrap_or(schedule.period);
+    let now = Utc::now();
+    let enabled = payload.enabled.unwrap_or(schedule.enabled);
+    let cron_changed = payload
+        .cron_expression
+        .as_deref()
+        .is_some_and(|cron| cron.trim() != schedule.cron_expression);
+    if let Some(cron_expression) = payload.cron_expression {
+        schedule.cron_expression = cron_expression.trim().to_string();
+    }
+    // A new expression, or re-enabling, starts counting from now rather than
+    // catching up on runs missed in between.
+    if cron_changed || (enabled && !schedule.enabled) {
+        schedule.next_run_at =
+            next_run_after(&schedule.cron_expression, &state.config.time_zone, now)
+                .map_err(AppError::BadRequest)?;
+    }
+    schedule.enabled = enabled;
+    schedule.updated_at = now;
+    if export_schedule::schedule_name_taken(&state.write_pool, &schedule.name, Some(id)).await? {
+        return Err(AppError::Conflict(format!(
+            "Export schedule '{}' already exists",
+            schedule.name
+        )));
+    }
+
+    let saved = export_schedule::update_schedule(&state.write_pool, &schedule).await?;
+    Ok(Json(saved))
+}
+
+pub async fn delete_export_schedule(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(id): Path<String>,
+) -> Result<Json<Value>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let id = parse_schedule_id(&id)?;
+    if export_schedule::delete_schedule(&state.write_pool, id).await? == 0 {
+        return Err(AppError::NotFound("Export schedule not found".into()));
+    }
+    Ok(Json(
+        json!({"message": "Export schedule deleted", "id": id}),
+    ))
+}
+
+async fn resolve_template(
+    state: &AppState,
+    reference: Option<&str>,
+) -> Result<Option<ExportTemplateId>, AppError> {
+    match reference {
+        Some(reference) => Ok(Some(
+            export_template::find_template_by_reference(&state.write_pool, reference)
+                .await?
+                .ok_or_else(|| AppError::NotFound("Export template not found".into()))?
+                .id,
+        )),
+        None => Ok(None),
+    }
+}
+
+fn normalize_username(username: Option<String>) -> Option<String> {
+    username
+        .map(|username| username.trim().to_string())
+        .filter(|username| !username.is_empty())
+}
+
+/// Checks an age public key; blank means no encryption.
+fn normalize_recipient(recipient: Option<String>) -> Result<Option<String>, AppError> {
+    match recipient
+        .map(|recipient| recipient.trim().to_string())
+        .filter(|recipient| !recipient.is_empty())
+    {
+        Some(recipient) => {
+            ExportEncryption::for_recipient(&recipient).map_err(AppError::BadRequest)?;
+            Ok(Some(recipient))
+        }
+        None => Ok(None),
+    }
+}
+
+fn parse_schedule_id(id: &str) -> Result<ExportScheduleId, AppError> {
+    ExportScheduleId::from_str(id)
+        .map_err(|_| AppError::BadRequest("Invalid export schedule ID".into()))
+}
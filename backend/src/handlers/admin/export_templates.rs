@@ -0,0 +1,165 @@
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::Utc;
+use serde_json::{json, Value};
+use std::str::FromStr;
+use validator::Validate;
+
+use crate::{
+    error::AppError,
+    models::{
+        export_template::{
+            CreateExportTemplatePayload, ExportField, ExportFieldInfo, ExportTemplate,
+            UpdateExportTemplatePayload,
+        },
+        user::User,
+    },
+    repositories::{export_schedule, export_template},
+    state::AppState,
+    types::ExportTemplateId,
+};
+
+const DEFAULT_DATE_FORMAT: &str = "%Y-%m-%d";
+const DEFAULT_MONTH_FORMAT: &str = "%Y-%m";
+
+pub async fn list_export_templates(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<Json<Vec<ExportTemplate>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let templates = export_template::list_templates(state.read_pool()).await?;
+    Ok(Json(templates))
+}
+
+/// Fields a template can pick, with the row granularities each one is available in.
+pub async fn list_export_fields(
+    Extension(user): Extension<User>,
+) -> Result<Json<Vec<ExportFieldInfo>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    Ok(Json(
+        ExportField::ALL
+            .into_iter()
+            .map(ExportFieldInfo::from)
+            .collect(),
+    ))
+}
+
+pub async fn create_export_template(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Json(payload): Json<CreateExportTemplatePayload>,
+) -> Result<(StatusCode, Json<ExportTemplate>), AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    payload.validate()?;
+
+    let now = Utc::now();
+    let template = ExportTemplate {
+        id: ExportTemplateId::new(),
+        name: payload.name.trim().to_string(),
+        granularity: payload.granularity,
+        encoding: payload.encoding.unwrap_or_default(),
+        delimiter: payload.delimiter.unwrap_or_default(),
+        date_format: payload
+            .date_format
+            .unwrap_or_else(|| DEFAULT_DATE_FORMAT.to_string()),
+        month_format: payload
+            .month_format
+            .unwrap_or_else(|| DEFAULT_MONTH_FORMAT.to_string()),
+        include_header: payload.include_header.unwrap_or(true),
+        columns: payload.columns,
+        created_at: now,
+        updated_at: now,
+    };
+    template.check_layout().map_err(AppError::BadRequest)?;
+    if export_template::template_name_taken(&state.write_pool, &template.name, None).await? {
+        return Err(AppError::Conflict(format!(
+            "Export template '{}' already exists",
+            template.name
+        )));
+    }
+
+    let saved = export_template::create_template(&state.write_pool, &template).await?;
+    Ok((StatusCode::CREATED, Json(saved)))
+}
+
+pub async fn update_export_template(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(id): Path<String>,
+    Json(payload): Json<UpdateExportTemplatePayload>,
+) -> Result<Json<ExportTemplate>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    payload.validate()?;
+
+    let id = parse_template_id(&id)?;
+    let mut template = export_template::find_template(&state.write_pool, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Export template not found".into()))?;
+
+    if let Some(name) = payload
+        .name
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+    {
+        template.name = name;
+    }
+    template.granularity = payload.granularity.unwrap_or(template.granularity);
+    template.encoding = payload.encoding.unwrap_or(template.encoding);
+    template.delimiter = payload.delimiter.unwrap_or(template.delimiter);
+    template.date_format = payload.date_format.unwrap_or(template.date_format);
+    template.month_format = payload.month_format.unwrap_or(template.month_format);
+    template.include_header = payload.include_header.unwrap_or(template.include_header);
+    template.columns = payload.columns.unwrap_or(template.columns);
+    template.updated_at = Utc::now();
+    template.check_layout().map_err(AppError::BadRequest)?;
+    if export_template::template_name_taken(&state.write_pool, &template.name, Some(id)).await? {
+        return Err(AppError::Conflict(format!(
+            "Export template '{}' already exists",
+            template.name
+        )));
+    }
+
+    let saved = export_template::update_template(&state.write_pool, &template).await?;
+    Ok(Json(saved))
+}
+
+pub async fn delete_export_template(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(id): Path<String>,
+) -> Result<Json<Value>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let id = parse_template_id(&id)?;
+    if export_schedule::template_in_use(&state.write_pool, id).await? {
+        return Err(AppError::Conflict(
+            "Export template is used by an export schedule".into(),
+        ));
+    }
+    if export_template::delete_template(&state.write_pool, id).await? == 0 {
+        return Err(AppError::NotFound("Export template not found".into()));
+    }
+    Ok(Json(
+        json!({"message": "Export template deleted", "id": id}),
+    ))
+}
+
+fn parse_template_id(id: &str) -> Result<ExportTemplateId, AppError> {
+    ExportTemplateId::from_str(id)
+        .map_err(|_| AppError::BadRequest("Invalid export template ID".into()))
+}
@@ -0,0 +1,125 @@
+use axum::{
+    extract::{Extension, Path, Query, State},
+    Json,
+};
+use chrono::Datelike;
+use validator::Validate;
+
+use crate::{
+    error::AppError,
+    handlers::attendance::month_range,
+    models::{
+        health_interview::{
+            HealthInterview, HealthInterviewHistoryQuery, HealthInterviewPayload,
+            LongHoursEmployee, LongHoursReportQuery,
+        },
+        user::User,
+    },
+    repositories::{health_interview, user as user_repo},
+    services::health_interview::HealthInterviewService,
+    state::AppState,
+    types::UserId,
+    utils::time,
+};
+
+use super::{
+    compliance::{parse_department_id, visible_user_ids},
+    leave_balances::parse_user_id,
+};
+
+pub async fn get_long_hours_report(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<LongHoursReportQuery>,
+) -> Result<Json<Vec<LongHoursEmployee>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let now_local = time::now_in_timezone(&state.config.time_zone);
+    let year = query.year.unwrap_or_else(|| now_local.year());
+    let month = query.month.unwrap_or_else(|| now_local.month());
+    let (first_day, last_day) = month_range(year, month)?;
+    let department_id = query
+        .department_id
+        .as_deref()
+        .map(parse_department_id)
+        .transpose()?;
+
+    let visible_users = visible_user_ids(&state, &user).await?;
+    let report = HealthInterviewService::new(state.read_pool().clone())
+        .report(first_day, last_day, department_id, visible_users.as_ref())
+        .await?;
+    Ok(Json(report))
+}
+
+pub async fn list_health_interviews(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<HealthInterviewHistoryQuery>,
+) -> Result<Json<Vec<HealthInterview>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let user_id = query.user_id.as_deref().map(parse_user_id).transpose()?;
+    let visible_users = visible_user_ids(&state, &user).await?;
+    let interviews = health_interview::list_history(state.read_pool(), user_id)
+        .await?
+        .into_iter()
+        .filter(|interview| {
+            visible_users
+                .as_ref()
+                .is_none_or(|visible| visible.contains(&interview.user_id))
+        })
+        .collect();
+    Ok(Json(interviews))
+}
+
+pub async fn record_health_interview(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path((user_id, year, month)): Path<(String, i32, u32)>,
+    Json(payload): Json<HealthInterviewPayload>,
+) -> Result<Json<HealthInterview>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    payload.validate()?;
+
+    let target_id: UserId = parse_user_id(&user_id)?;
+    let (first_day, last_day) = month_range(year, month)?;
+    if visible_user_ids(&state, &user)
+        .await?
+        .is_some_and(|visible| !visible.contains(&target_id))
+    {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    if user_repo::fetch_username(&state.write_pool, &user_id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound("User not found".into()));
+    }
+
+    let (_, overtime_hours) = HealthInterviewService::new(state.write_pool.clone())
+        .monthly_hours(target_id, first_day, last_day)
+        .await?;
+    let notes = payload
+        .notes
+        .as_deref()
+        .map(str::trim)
+        .filter(|notes| !notes.is_empty());
+    let interview = health_interview::save(
+        &state.write_pool,
+        target_id,
+        year,
+        month,
+        overtime_hours,
+        payload.status,
+        notes,
+        user.id,
+    )
+    .await?;
+    Ok(Json(interview))
+}
@@ -0,0 +1,102 @@
+use axum::{
+    extract::{Extension, Path, Query, State},
+    Json,
+};
+use validator::Validate;
+
+use crate::{
+    error::AppError,
+    models::{
+        employment_profile::{
+            AccrualPreviewItem, AccrualPreviewQuery, EmploymentProfile,
+            UpsertEmploymentProfilePayload,
+        },
+        user::User,
+    },
+    repositories::employment_profile,
+    services::leave_accrual::LeaveAccrualService,
+    state::AppState,
+    utils::time,
+};
+
+use super::common::check_approval_authorization;
+use super::compliance::visible_user_ids;
+use super::leave_balances::{ensure_user_exists, parse_user_id};
+
+pub async fn get_employment_profile(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(user_id): Path<String>,
+) -> Result<Json<EmploymentProfile>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let target_id = parse_user_id(&user_id)?;
+    check_approval_authorization(state.read_pool(), &user, target_id).await?;
+
+    employment_profile::find_by_user(state.read_pool(), target_id)
+        .await?
+        .map(Json)
+        .ok_or_else(|| AppError::NotFound("Employment profile not found".into()))
+}
+
+pub async fn upsert_employment_profile(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(user_id): Path<String>,
+    Json(payload): Json<UpsertEmploymentProfilePayload>,
+) -> Result<Json<EmploymentProfile>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    payload.validate()?;
+
+    let target_id = parse_user_id(&user_id)?;
+    ensure_user_exists(&state, target_id).await?;
+    let employee_code = payload
+        .employee_code
+        .as_deref()
+        .map(str::trim)
+        .filter(|code| !code.is_empty());
+    if let Some(code) = employee_code {
+        if employment_profile::employee_code_taken(&state.write_pool, code, target_id).await? {
+            return Err(AppError::Conflict(format!(
+                "Employee code '{}' is already in use",
+                code
+            )));
+        }
+    }
+
+    let profile = employment_profile::upsert(
+        &state.write_pool,
+        target_id,
+        payload.hire_date,
+        payload.employment_type,
+        payload.weekly_scheduled_days.unwrap_or(5),
+        payload.weekly_scheduled_hours.unwrap_or(40.0),
+        employee_code,
+    )
+    .await?;
+    Ok(Json(profile))
+}
+
+pub async fn preview_leave_accruals(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<AccrualPreviewQuery>,
+) -> Result<Json<Vec<AccrualPreviewItem>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let visible_users = visible_user_ids(&state, &user).await?;
+
+    let as_of = query
+        .as_of
+        .unwrap_or_else(|| time::today_local(&state.config.time_zone));
+    let items = LeaveAccrualService::new(state.read_pool().clone())
+        .preview(as_of, visible_users.as_ref())
+        .await?;
+    Ok(Json(items))
+}
@@ -0,0 +1,103 @@
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    Json,
+};
+use std::str::FromStr;
+use validator::Validate;
+
+use crate::{
+    error::AppError,
+    models::{
+        leave_balance::{
+            CreateLeaveBalanceEntryPayload, LeaveBalanceEntry, UserLeaveBalanceResponse,
+        },
+        user::User,
+    },
+    repositories::{leave_balance, user as user_repo},
+    services::leave_balance::LeaveBalanceService,
+    state::AppState,
+    types::UserId,
+    utils::time,
+};
+
+use super::common::check_approval_authorization;
+
+pub async fn get_user_leave_balances(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(user_id): Path<String>,
+) -> Result<Json<UserLeaveBalanceResponse>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let target_id = parse_user_id(&user_id)?;
+    check_approval_authorization(state.read_pool(), &user, target_id).await?;
+    ensure_user_exists(&state, target_id).await?;
+
+    let service = LeaveBalanceService::new(state.read_pool().clone());
+    let balances = service.balances_for_user(target_id).await?;
+    let entries = leave_balance::list_entries_for_user(state.read_pool(), target_id).await?;
+
+    Ok(Json(UserLeaveBalanceResponse {
+        user_id: target_id,
+        balances,
+        entries,
+    }))
+}
+
+pub async fn create_leave_balance_entry(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(user_id): Path<String>,
+    Json(payload): Json<CreateLeaveBalanceEntryPayload>,
+) -> Result<(StatusCode, Json<LeaveBalanceEntry>), AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    payload.validate()?;
+
+    let target_id = parse_user_id(&user_id)?;
+    ensure_user_exists(&state, target_id).await?;
+    if !LeaveBalanceService::new(state.write_pool.clone())
+        .is_balance_tracked(&payload.leave_type)
+        .await?
+    {
+        return Err(AppError::BadRequest(format!(
+            "Leave type '{}' does not track a balance",
+            payload.leave_type
+        )));
+    }
+
+    let effective_date = payload
+        .effective_date
+        .unwrap_or_else(|| time::today_local(&state.config.time_zone));
+    let mut entry = LeaveBalanceEntry::new(
+        target_id,
+        payload.leave_type,
+        payload.entry_type,
+        payload.days,
+        effective_date,
+    );
+    entry.note = payload
+        .note
+        .map(|note| note.trim().to_string())
+        .filter(|note| !note.is_empty());
+    entry.expires_on = payload.expires_on;
+    entry.created_by = Some(user.id);
+
+    let saved = leave_balance::insert_entry(&state.write_pool, &entry).await?;
+    Ok((StatusCode::CREATED, Json(saved)))
+}
+
+pub(super) fn parse_user_id(raw: &str) -> Result<UserId, AppError> {
+    UserId::from_str(raw).map_err(|_| AppError::BadRequest("Invalid user ID".into()))
+}
+
+pub(super) async fn ensure_user_exists(state: &AppState, user_id: UserId) -> Result<(), AppError> {
+    if !user_repo::user_exists(state.read_pool(), &user_id.to_string()).await? {
+        return Err(AppError::NotFound("User not found".into()));
+    }
+    Ok(())
+}
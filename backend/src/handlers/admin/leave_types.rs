@@ -0,0 +1,132 @@
+use axum::{
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::Utc;
+use serde_json::{json, Value};
+use validator::Validate;
+
+use crate::{
+    error::AppError,
+    models::{
+        leave_request::LeaveType,
+        leave_type::{CreateLeaveTypePayload, LeaveTypeDefinition, UpdateLeaveTypePayload},
+        user::User,
+    },
+    repositories::leave_type,
+    state::AppState,
+};
+
+pub async fn list_leave_types(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<Json<Vec<LeaveTypeDefinition>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let leave_types = leave_type::list_all(state.read_pool()).await?;
+    Ok(Json(leave_types))
+}
+
+pub async fn create_leave_type(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Json(payload): Json<CreateLeaveTypePayload>,
+) -> Result<(StatusCode, Json<LeaveTypeDefinition>), AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    payload.validate()?;
+
+    let name = payload.name.trim().to_string();
+    if name.is_empty() {
+        return Err(AppError::BadRequest("Leave type name is required".into()));
+    }
+    let code = LeaveType::new(payload.code);
+    if leave_type::find_by_code(&state.write_pool, &code)
+        .await?
+        .is_some()
+    {
+        return Err(AppError::Conflict(format!(
+            "Leave type '{code}' already exists"
+        )));
+    }
+
+    let now = Utc::now();
+    let definition = LeaveTypeDefinition {
+        code,
+        name,
+        paid: payload.paid.unwrap_or(true),
+        balance_tracked: payload.balance_tracked.unwrap_or(false),
+        is_active: true,
+        sort_order: payload.sort_order.unwrap_or(0),
+        created_at: now,
+        updated_at: now,
+    };
+    let saved = leave_type::create(&state.write_pool, &definition).await?;
+    Ok((StatusCode::CREATED, Json(saved)))
+}
+
+pub async fn update_leave_type(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(code): Path<String>,
+    Json(mut payload): Json<UpdateLeaveTypePayload>,
+) -> Result<Json<LeaveTypeDefinition>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    payload.validate()?;
+
+    let code = LeaveType::new(code);
+    // Statutory accrual and compliance reporting are built on annual leave.
+    if code.is_annual()
+        && (payload.is_active == Some(false) || payload.balance_tracked == Some(false))
+    {
+        return Err(AppError::BadRequest(
+            "Annual leave must stay active and balance-tracked".into(),
+        ));
+    }
+    payload.name = payload
+        .name
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty());
+
+    let updated = leave_type::update(&state.write_pool, &code, &payload)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Leave type not found".into()))?;
+    Ok(Json(updated))
+}
+
+pub async fn delete_leave_type(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(code): Path<String>,
+) -> Result<Json<Value>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let code = LeaveType::new(code);
+    if code.is_annual() {
+        return Err(AppError::BadRequest(
+            "Annual leave cannot be deleted".into(),
+        ));
+    }
+    if leave_type::find_by_code(&state.write_pool, &code)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound("Leave type not found".into()));
+    }
+    if leave_type::is_in_use(&state.write_pool, &code).await? {
+        return Err(AppError::Conflict(
+            "Leave type is referenced by requests or balance entries; deactivate it instead".into(),
+        ));
+    }
+
+    leave_type::delete(&state.write_pool, &code).await?;
+    Ok(Json(json!({"message": "Leave type deleted", "code": code})))
+}
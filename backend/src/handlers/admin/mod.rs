@@ -1,26 +1,58 @@
 pub mod attendance;
 pub mod attendance_correction_requests;
+pub mod attendance_reports;
 pub mod audit_logs;
+pub mod break_policy;
 pub mod common;
+pub mod compliance;
 pub mod departments;
 pub mod export;
+pub mod export_jobs;
+pub mod export_schedules;
+pub mod export_templates;
+pub mod health_interviews;
 pub mod holidays;
+pub mod leave_accruals;
+pub mod leave_balances;
+pub mod leave_types;
+pub mod overtime;
+pub mod overtime_limits;
+pub mod period_closings;
 pub mod requests;
+pub mod rest_interval_policy;
 pub mod sessions;
+pub mod timesheets;
 pub mod users;
+pub mod work_schedules;
 
 pub use attendance::*;
 pub use attendance_correction_requests::*;
+pub use attendance_reports::*;
 pub use audit_logs::*;
+pub use break_policy::*;
+pub use compliance::*;
 pub use departments::*;
 // common is internal helpers, usually not re-exported fully, but let's see if docs.rs needs anything from it.
 // docs.rs needs structs. The structs are in their respective modules now.
 // We should re-export everything from the new modules to maintain backward compatibility for `use crate::handlers::admin::*;` if used.
 pub use export::*;
+pub use export_jobs::*;
+pub use export_schedules::*;
+pub use export_templates::*;
+pub use health_interviews::*;
 pub use holidays::*;
+pub use leave_accruals::*;
+pub use leave_balances::*;
+pub use leave_types::*;
+pub use overtime::*;
+pub use overtime_limits::*;
+pub use period_closings::*;
 pub use requests::*;
+pub use rest_interval_policy::*;
 pub use sessions::*;
+pub use timesheets::*;
 pub use users::*;
+pub use work_schedules::*;
 
 pub mod subject_requests;
 pub use subject_requests::*;
@@ -0,0 +1,75 @@
+use axum::{
+    extract::{Extension, Query, State},
+    Json,
+};
+use chrono::Datelike;
+use std::str::FromStr;
+
+use crate::{
+    error::AppError,
+    handlers::attendance::month_range,
+    models::{
+        overtime::{OvertimeReconciliation, OvertimeReconciliationQuery},
+        user::User,
+    },
+    repositories::{overtime, user as user_repo},
+    services::overtime::OvertimeService,
+    state::AppState,
+    types::UserId,
+    utils::time,
+};
+
+use super::compliance::visible_user_ids;
+
+pub async fn get_overtime_reconciliation(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<OvertimeReconciliationQuery>,
+) -> Result<Json<Vec<OvertimeReconciliation>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let now_local = time::now_in_timezone(&state.config.time_zone);
+    let year = query.year.unwrap_or_else(|| now_local.year());
+    let month = query.month.unwrap_or_else(|| now_local.month());
+    let (first_day, last_day) = month_range(year, month)?;
+
+    let visible_users = visible_user_ids(&state, &user).await?;
+    let employees = match query.user_id.as_deref() {
+        Some(raw) => {
+            let user_id = UserId::from_str(raw)
+                .map_err(|_| AppError::BadRequest("Invalid user ID".into()))?;
+            if visible_users
+                .as_ref()
+                .is_some_and(|visible| !visible.contains(&user_id))
+            {
+                return Err(AppError::Forbidden("Forbidden".into()));
+            }
+            let username = user_repo::fetch_username(state.read_pool(), raw)
+                .await?
+                .ok_or_else(|| AppError::NotFound("User not found".into()))?;
+            vec![(user_id, username)]
+        }
+        None => overtime::list_users_with_activity(state.read_pool(), first_day, last_day)
+            .await?
+            .into_iter()
+            .filter(|(user_id, _)| {
+                visible_users
+                    .as_ref()
+                    .is_none_or(|visible| visible.contains(user_id))
+            })
+            .collect(),
+    };
+
+    let service = OvertimeService::new(state.read_pool().clone());
+    let mut reports = Vec::with_capacity(employees.len());
+    for (user_id, username) in employees {
+        reports.push(
+            service
+                .reconcile(user_id, username, first_day, last_day)
+                .await?,
+        );
+    }
+    Ok(Json(reports))
+}
@@ -0,0 +1,110 @@
+use axum::{
+    extract::{Extension, Path, State},
+    Json,
+};
+use chrono::Utc;
+use serde_json::{json, Value};
+use std::str::FromStr;
+use validator::Validate;
+
+use crate::{
+    error::AppError,
+    models::{
+        overtime_limit::{OvertimeLimit, OvertimeLimitPayload},
+        user::User,
+    },
+    repositories::{department, overtime_limit},
+    state::AppState,
+    types::DepartmentId,
+};
+
+pub async fn list_overtime_limits(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<Json<Vec<OvertimeLimit>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let mut limits = overtime_limit::list_limits(state.read_pool()).await?;
+    if limits.iter().all(|limit| limit.department_id.is_some()) {
+        limits.insert(0, OvertimeLimit::statutory(None));
+    }
+    Ok(Json(limits))
+}
+
+pub async fn update_company_overtime_limit(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Json(payload): Json<OvertimeLimitPayload>,
+) -> Result<Json<OvertimeLimit>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    save_limit(&state, None, &payload).await.map(Json)
+}
+
+pub async fn update_department_overtime_limit(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(department_id): Path<String>,
+    Json(payload): Json<OvertimeLimitPayload>,
+) -> Result<Json<OvertimeLimit>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    let department_id = parse_department_id(&department_id)?;
+    department::find_department_by_id(&state.write_pool, &department_id.to_string())
+        .await?
+        .ok_or_else(|| AppError::NotFound("Department not found".into()))?;
+    save_limit(&state, Some(department_id), &payload)
+        .await
+        .map(Json)
+}
+
+pub async fn delete_department_overtime_limit(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(department_id): Path<String>,
+) -> Result<Json<Value>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    let department_id = parse_department_id(&department_id)?;
+    if !overtime_limit::delete_department_limit(&state.write_pool, department_id).await? {
+        return Err(AppError::NotFound(
+            "Department overtime limits not found".into(),
+        ));
+    }
+    Ok(Json(json!({
+        "message": "Department overtime limits deleted",
+        "department_id": department_id
+    })))
+}
+
+async fn save_limit(
+    state: &AppState,
+    department_id: Option<DepartmentId>,
+    payload: &OvertimeLimitPayload,
+) -> Result<OvertimeLimit, AppError> {
+    payload.validate()?;
+    if payload.special_clause_enabled
+        && (payload.special_annual_limit_hours < payload.annual_limit_hours
+            || payload.special_monthly_cap_hours < payload.monthly_limit_hours)
+    {
+        return Err(AppError::BadRequest(
+            "Special clause limits cannot be lower than the standard limits".into(),
+        ));
+    }
+
+    let mut limit = overtime_limit::find_limit(&state.write_pool, department_id)
+        .await?
+        .unwrap_or_else(|| OvertimeLimit::statutory(department_id));
+    limit.apply(payload);
+    limit.updated_at = Utc::now();
+    Ok(overtime_limit::save_limit(&state.write_pool, &limit).await?)
+}
+
+fn parse_department_id(raw: &str) -> Result<DepartmentId, AppError> {
+    DepartmentId::from_str(raw).map_err(|_| AppError::BadRequest("Invalid department ID".into()))
+}
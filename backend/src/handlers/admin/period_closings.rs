@@ -0,0 +1,119 @@
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::Utc;
+use serde_json::{json, Value};
+use std::str::FromStr;
+use validator::Validate;
+
+use crate::{
+    error::AppError,
+    models::{
+        period_closing::{ClosePeriodPayload, PeriodClosing, PeriodClosingQuery},
+        user::User,
+    },
+    repositories::{department, period_closing},
+    state::AppState,
+    types::{DepartmentId, PeriodClosingId},
+};
+
+pub async fn list_period_closings(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<PeriodClosingQuery>,
+) -> Result<Json<Vec<PeriodClosing>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    let department_id = query
+        .department_id
+        .as_deref()
+        .map(parse_department_id)
+        .transpose()?;
+    let closings =
+        period_closing::list_closings(state.read_pool(), query.year, department_id).await?;
+    Ok(Json(closings))
+}
+
+/// Closes a month. Managers may close the departments they manage; closing a month
+/// company-wide requires a system administrator.
+pub async fn close_period(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Json(payload): Json<ClosePeriodPayload>,
+) -> Result<(StatusCode, Json<PeriodClosing>), AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    payload.validate()?;
+    let period_month = payload
+        .period_month()
+        .ok_or_else(|| AppError::BadRequest("Invalid period".into()))?;
+
+    let department_id = match payload.department_id.as_deref() {
+        Some(raw) => {
+            let department_id = parse_department_id(raw)?;
+            department::find_department_by_id(&state.write_pool, &department_id.to_string())
+                .await?
+                .ok_or_else(|| AppError::NotFound("Department not found".into()))?;
+            if !user.is_system_admin()
+                && !department::can_manager_manage_department(
+                    &state.write_pool,
+                    user.id,
+                    &department_id.to_string(),
+                )
+                .await?
+            {
+                return Err(AppError::Forbidden(
+                    "Managers can only close the departments they manage".into(),
+                ));
+            }
+            Some(department_id)
+        }
+        None if user.is_system_admin() => None,
+        None => {
+            return Err(AppError::Forbidden(
+                "Closing a period company-wide requires a system administrator".into(),
+            ))
+        }
+    };
+
+    let closing = PeriodClosing::new(period_month, department_id, user.id, Utc::now());
+    let saved = period_closing::insert_closing(&state.write_pool, &closing)
+        .await?
+        .ok_or_else(|| {
+            AppError::Conflict(format!(
+                "{} is already closed",
+                period_month.format("%Y-%m")
+            ))
+        })?;
+    Ok((StatusCode::CREATED, Json(saved)))
+}
+
+/// Re-opens a closed month.
+pub async fn reopen_period(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(id): Path<String>,
+) -> Result<Json<Value>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    let id = PeriodClosingId::from_str(&id)
+        .map_err(|_| AppError::BadRequest("Invalid period closing ID".into()))?;
+    let reopened = period_closing::delete_closing(&state.write_pool, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Period closing not found".into()))?;
+    Ok(Json(json!({
+        "message": "Period reopened",
+        "id": reopened.id,
+        "period_month": reopened.period_month,
+        "department_id": reopened.department_id
+    })))
+}
+
+fn parse_department_id(raw: &str) -> Result<DepartmentId, AppError> {
+    DepartmentId::from_str(raw).map_err(|_| AppError::BadRequest("Invalid department ID".into()))
+}
@@ -11,13 +11,19 @@ use crate::{
     error::AppError,
     handlers::admin::common::{check_approval_authorization, parse_filter_datetime},
     models::{
-        leave_request::LeaveRequestResponse, overtime_request::OvertimeRequestResponse, user::User,
+        leave_request::{LeaveRequest, LeaveRequestResponse},
+        overtime_request::{OvertimeRequest, OvertimeRequestResponse},
+        user::User,
     },
     repositories::{
         leave_request::{LeaveRequestRepository, LeaveRequestRepositoryTrait},
         overtime_request::{OvertimeRequestRepository, OvertimeRequestRepositoryTrait},
         request::{RequestListFilters, RequestRepository, RequestStatusUpdate},
     },
+    services::{
+        leave_balance::LeaveBalanceService, overtime_limit::OvertimeLimitService,
+        period_closing::PeriodClosingService,
+    },
     state::AppState,
     types::{LeaveRequestId, OvertimeRequestId, UserId},
     utils::time,
@@ -36,30 +42,26 @@ pub async fn approve_request(
     Path(request_id): Path<String>,
     Json(body): Json<ApprovePayload>,
 ) -> Result<Json<Value>, AppError> {
-    let applicant_id = get_request_applicant_id(&state, &request_id).await?;
-    if applicant_id == user.id {
-        return Err(AppError::Forbidden(
-            "Managers cannot approve or reject their own requests".into(),
-        ));
-    }
-    check_approval_authorization(&state.write_pool, &user, applicant_id).await?;
+    let request = find_request(&state, &request_id).await?;
+    ensure_can_decide(&state, &user, &request).await?;
     validate_decision_comment(&body.comment)?;
+    ensure_request_period_open(&state, &request).await?;
     let approver_id = user.id;
     let comment = body.comment;
     let now_utc = time::now_utc(&state.config.time_zone);
-    let request_repo = RequestRepository::new();
-    if request_repo
-        .update_request_status(
-            &state.write_pool,
-            &request_id,
-            RequestStatusUpdate::Approve {
-                approver_id,
-                comment: &comment,
-                timestamp: now_utc,
-            },
-        )
-        .await?
-    {
+    let approved = match &request {
+        DecisionRequest::Leave(leave) => {
+            LeaveBalanceService::new(state.write_pool.clone())
+                .approve_leave(leave, approver_id, &comment, now_utc)
+                .await?
+        }
+        DecisionRequest::Overtime(overtime) => {
+            OvertimeLimitService::new(state.write_pool.clone())
+                .approve_overtime(overtime, approver_id, &comment, now_utc)
+                .await?
+        }
+    };
+    if approved {
         return Ok(Json(json!({"message": "Request approved"})));
     }
 
@@ -79,14 +81,10 @@ pub async fn reject_request(
     Path(request_id): Path<String>,
     Json(body): Json<RejectPayload>,
 ) -> Result<Json<Value>, AppError> {
-    let applicant_id = get_request_applicant_id(&state, &request_id).await?;
-    if applicant_id == user.id {
-        return Err(AppError::Forbidden(
-            "Managers cannot approve or reject their own requests".into(),
-        ));
-    }
-    check_approval_authorization(&state.write_pool, &user, applicant_id).await?;
+    let request = find_request(&state, &request_id).await?;
+    ensure_can_decide(&state, &user, &request).await?;
     validate_decision_comment(&body.comment)?;
+    ensure_request_period_open(&state, &request).await?;
     let approver_id = user.id;
     let comment = body.comment;
     let now_utc = time::now_utc(&state.config.time_zone);
@@ -111,31 +109,96 @@ pub async fn reject_request(
     ))
 }
 
-/// Returns the user ID of the applicant who submitted the given request.
-async fn get_request_applicant_id(state: &AppState, request_id: &str) -> Result<UserId, AppError> {
-    if let Ok(leave_request_id) = LeaveRequestId::from_str(request_id) {
-        let leave_repo = LeaveRequestRepository::new();
-        if let Ok(request) = leave_repo
-            .find_by_id(&state.write_pool, leave_request_id)
-            .await
-        {
-            return Ok(request.user_id);
+/// A leave or overtime request awaiting a manager's decision.
+enum DecisionRequest {
+    Leave(LeaveRequest),
+    Overtime(OvertimeRequest),
+}
+
+impl DecisionRequest {
+    fn applicant_id(&self) -> UserId {
+        match self {
+            DecisionRequest::Leave(request) => request.user_id,
+            DecisionRequest::Overtime(request) => request.user_id,
         }
     }
+}
 
-    if let Ok(overtime_request_id) = OvertimeRequestId::from_str(request_id) {
-        let overtime_repo = OvertimeRequestRepository::new();
-        if let Ok(request) = overtime_repo
-            .find_by_id(&state.write_pool, overtime_request_id)
-            .await
-        {
-            return Ok(request.user_id);
-        }
+async fn find_request(state: &AppState, request_id: &str) -> Result<DecisionRequest, AppError> {
+    if let Some(request) = find_leave(state, request_id).await? {
+        return Ok(DecisionRequest::Leave(request));
+    }
+    if let Some(request) = find_overtime(state, request_id).await? {
+        return Ok(DecisionRequest::Overtime(request));
     }
 
     Err(AppError::NotFound("Request not found".into()))
 }
 
+/// Rejects decisions on the manager's own requests or on requests of users they do not manage.
+async fn ensure_can_decide(
+    state: &AppState,
+    user: &User,
+    request: &DecisionRequest,
+) -> Result<(), AppError> {
+    let applicant_id = request.applicant_id();
+    if applicant_id == user.id {
+        return Err(AppError::Forbidden(
+            "Managers cannot approve or reject their own requests".into(),
+        ));
+    }
+    check_approval_authorization(&state.write_pool, user, applicant_id).await
+}
+
+/// Rejects a decision on a request whose dates fall in a closed month.
+async fn ensure_request_period_open(
+    state: &AppState,
+    request: &DecisionRequest,
+) -> Result<(), AppError> {
+    let closings = PeriodClosingService::new(state.write_pool.clone());
+    match request {
+        DecisionRequest::Leave(request) => {
+            closings
+                .ensure_range_open(request.user_id, request.start_date, request.end_date)
+                .await
+        }
+        DecisionRequest::Overtime(request) => {
+            closings.ensure_open(request.user_id, request.date).await
+        }
+    }
+}
+
+async fn find_leave(state: &AppState, request_id: &str) -> Result<Option<LeaveRequest>, AppError> {
+    let Ok(leave_request_id) = LeaveRequestId::from_str(request_id) else {
+        return Ok(None);
+    };
+    match LeaveRequestRepository::new()
+        .find_by_id(&state.write_pool, leave_request_id)
+        .await
+    {
+        Ok(request) => Ok(Some(request)),
+        Err(AppError::NotFound(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+async fn find_overtime(
+    state: &AppState,
+    request_id: &str,
+) -> Result<Option<OvertimeRequest>, AppError> {
+    let Ok(overtime_request_id) = OvertimeRequestId::from_str(request_id) else {
+        return Ok(None);
+    };
+    match OvertimeRequestRepository::new()
+        .find_by_id(&state.write_pool, overtime_request_id)
+        .await
+    {
+        Ok(request) => Ok(Some(request)),
+        Err(AppError::NotFound(_)) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, IntoParams, ToSchema)]
 pub struct RequestListQuery {
     pub status: Option<String>,
@@ -267,8 +330,8 @@ pub async fn get_request_detail(
 
     // For managers (non-system-admin), verify read access is scoped to their departments.
     if user.is_manager() && !user.is_system_admin() {
-        if let Ok(applicant_id) = get_request_applicant_id(&state, &request_id).await {
-            check_approval_authorization(state.read_pool(), &user, applicant_id).await?;
+        if let Ok(request) = find_request(&state, &request_id).await {
+            check_approval_authorization(state.read_pool(), &user, request.applicant_id()).await?;
         }
     }
 
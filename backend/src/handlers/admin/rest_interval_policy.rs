@@ -0,0 +1,50 @@
+use axum::{
+    extract::{Extension, State},
+    Json,
+};
+use chrono::Utc;
+use validator::Validate;
+
+use crate::{
+    error::AppError,
+    models::{
+        rest_interval::{RestIntervalPolicy, RestIntervalPolicyPayload},
+        user::User,
+    },
+    repositories::rest_interval,
+    services::rest_interval::RestIntervalService,
+    state::AppState,
+};
+
+pub async fn get_rest_interval_policy(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<Json<RestIntervalPolicy>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    let policy = RestIntervalService::new(state.read_pool().clone())
+        .policy()
+        .await?;
+    Ok(Json(policy))
+}
+
+pub async fn update_rest_interval_policy(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Json(payload): Json<RestIntervalPolicyPayload>,
+) -> Result<Json<RestIntervalPolicy>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    payload.validate()?;
+
+    let mut policy = RestIntervalService::new(state.write_pool.clone())
+        .policy()
+        .await?;
+    policy.apply(&payload);
+    policy.updated_at = Utc::now();
+    Ok(Json(
+        rest_interval::save_policy(&state.write_pool, &policy).await?,
+    ))
+}
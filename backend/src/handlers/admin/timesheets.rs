@@ -0,0 +1,123 @@
+use axum::{
+    extract::{Extension, Path, Query, State},
+    Json,
+};
+use std::str::FromStr;
+use validator::Validate;
+
+use crate::{
+    error::AppError,
+    handlers::admin::common::check_approval_authorization,
+    models::{
+        timesheet::{
+            TimesheetDecisionPayload, TimesheetListQuery, TimesheetStatus, TimesheetSubmission,
+            TimesheetSubmissionListItem,
+        },
+        user::User,
+    },
+    repositories::{department, timesheet},
+    services::timesheet::TimesheetService,
+    state::AppState,
+    types::TimesheetSubmissionId,
+};
+
+/// Lists timesheet submissions; managers only see their departments' employees.
+pub async fn list_timesheets(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<TimesheetListQuery>,
+) -> Result<Json<Vec<TimesheetSubmissionListItem>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    let status = query
+        .status
+        .as_deref()
+        .map(TimesheetStatus::from_str)
+        .transpose()
+        .map_err(AppError::BadRequest)?;
+    let period_month = match (query.year, query.month) {
+        (Some(year), Some(month)) => Some(
+            chrono::NaiveDate::from_ymd_opt(year, month, 1)
+                .ok_or_else(|| AppError::BadRequest("Invalid period".into()))?,
+        ),
+        (None, Some(_)) => return Err(AppError::BadRequest("month requires year".into())),
+        _ => None,
+    };
+    let allowed_user_ids = if user.is_system_admin() {
+        None
+    } else {
+        Some(department::list_subordinate_user_ids(state.read_pool(), user.id).await?)
+    };
+    let submissions = timesheet::list_submissions(
+        state.read_pool(),
+        status,
+        period_month,
+        query.year,
+        allowed_user_ids,
+    )
+    .await?;
+    Ok(Json(submissions))
+}
+
+pub async fn approve_timesheet(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(id): Path<String>,
+    Json(payload): Json<TimesheetDecisionPayload>,
+) -> Result<Json<TimesheetSubmission>, AppError> {
+    decide(&state, &user, &id, TimesheetStatus::Approved, payload).await
+}
+
+/// Sends a submitted timesheet back to the employee; a comment is required.
+pub async fn return_timesheet(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(id): Path<String>,
+    Json(payload): Json<TimesheetDecisionPayload>,
+) -> Result<Json<TimesheetSubmission>, AppError> {
+    if payload
+        .comment
+        .as_deref()
+        .is_none_or(|comment| comment.trim().is_empty())
+    {
+        return Err(AppError::BadRequest(
+            "A comment is required when returning a timesheet".into(),
+        ));
+    }
+    decide(&state, &user, &id, TimesheetStatus::Returned, payload).await
+}
+
+async fn decide(
+    state: &AppState,
+    user: &User,
+    id: &str,
+    next: TimesheetStatus,
+    payload: TimesheetDecisionPayload,
+) -> Result<Json<TimesheetSubmission>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    payload.validate()?;
+    let id = TimesheetSubmissionId::from_str(id)
+        .map_err(|_| AppError::BadRequest("Invalid timesheet ID".into()))?;
+    let submission = timesheet::find_submission(&state.write_pool, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Timesheet not found".into()))?;
+    if submission.user_id == user.id {
+        return Err(AppError::Forbidden(
+            "Admins cannot approve or return their own timesheets".into(),
+        ));
+    }
+    check_approval_authorization(&state.write_pool, user, submission.user_id).await?;
+
+    let comment = payload
+        .comment
+        .as_deref()
+        .map(str::trim)
+        .filter(|comment| !comment.is_empty());
+    let decided = TimesheetService::new(state.write_pool.clone())
+        .decide(&submission, next, user.id, comment)
+        .await?;
+    Ok(Json(decided))
+}
@@ -0,0 +1,324 @@
+use axum::{
+    extract::{Extension, Path, Query, State},
+    http::StatusCode,
+    Json,
+};
+use chrono::Utc;
+use serde_json::{json, Value};
+use std::str::FromStr;
+use validator::Validate;
+
+use crate::{
+    error::AppError,
+    models::{
+        user::User,
+        work_schedule::{
+            normalize_weekdays, CreateWorkScheduleAssignmentPayload, CreateWorkSchedulePayload,
+            UpdateWorkSchedulePayload, WorkSchedule, WorkScheduleAssignment,
+            WorkScheduleAssignmentQuery,
+        },
+    },
+    repositories::{department, user as user_repo, work_schedule},
+    state::AppState,
+    types::{DepartmentId, UserId, WorkScheduleAssignmentId, WorkScheduleId},
+};
+
+const DEFAULT_BREAK_MINUTES: i32 = 60;
+const DEFAULT_WORKING_WEEKDAYS: [i16; 5] = [1, 2, 3, 4, 5];
+
+pub async fn list_work_schedules(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<Json<Vec<WorkSchedule>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let schedules = work_schedule::list_schedules(state.read_pool()).await?;
+    Ok(Json(schedules))
+}
+
+pub async fn create_work_schedule(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Json(payload): Json<CreateWorkSchedulePayload>,
+) -> Result<(StatusCode, Json<WorkSchedule>), AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    payload.validate()?;
+
+    let now = Utc::now();
+    let schedule = WorkSchedule {
+        id: WorkScheduleId::new(),
+        name: payload.name.trim().to_string(),
+        start_time: payload.start_time,
+        end_time: payload.end_time,
+        break_minutes: payload.break_minutes.unwrap_or(DEFAULT_BREAK_MINUTES),
+        working_weekdays: normalize_weekdays(
+            payload
+                .working_weekdays
+                .unwrap_or_else(|| DEFAULT_WORKING_WEEKDAYS.to_vec()),
+        ),
+        late_grace_minutes: payload.late_grace_minutes.unwrap_or(0),
+        kind: payload.kind.unwrap_or_default(),
+        core_start_time: payload.core_start_time,
+        core_end_time: payload.core_end_time,
+        settlement_months: payload.settlement_months.unwrap_or(1),
+        settlement_start_month: payload.settlement_start_month.unwrap_or(1),
+        created_at: now,
+        updated_at: now,
+    };
+    validate_schedule(&schedule)?;
+    if work_schedule::schedule_name_taken(&state.write_pool, &schedule.name, None).await? {
+        return Err(AppError::Conflict(format!(
+            "Work schedule '{}' already exists",
+            schedule.name
+        )));
+    }
+
+    let saved = work_schedule::create_schedule(&state.write_pool, &schedule).await?;
+    Ok((StatusCode::CREATED, Json(saved)))
+}
+
+pub async fn update_work_schedule(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(id): Path<String>,
+    Json(payload): Json<UpdateWorkSchedulePayload>,
+) -> Result<Json<WorkSchedule>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+    payload.validate()?;
+
+    let id = parse_schedule_id(&id)?;
+    let mut schedule = work_schedule::find_schedule(&state.write_pool, id)
+        .await?
+        .ok_or_else(|| AppError::NotFound("Work schedule not found".into()))?;
+
+    if let Some(name) = payload
+        .name
+        .map(|name| name.trim().to_string())
+        .filter(|name| !name.is_empty())
+    {
+        schedule.name = name;
+    }
+    schedule.start_time = payload.start_time.unwrap_or(schedule.start_time);
+    schedule.end_time = payload.end_time.unwrap_or(schedule.end_time);
+    schedule.break_minutes = payload.break_minutes.unwrap_or(schedule.break_minutes);
+    if let Some(weekdays) = payload.working_weekdays {
+        schedule.working_weekdays = normalize_weekdays(weekdays);
+    }
+    schedule.late_grace_minutes = payload
+        .late_grace_minutes
+        .unwrap_or(schedule.late_grace_minutes);
+    schedule.kind = payload.kind.unwrap_or(schedule.kind);
+    if payload.clear_core_time {
+        schedule.core_start_time = None;
+        schedule.core_end_time = None;
+    }
+    schedule.core_start_time = payload.core_start_time.or(schedule.core_start_time);
+    schedule.core_end_time = payload.core_end_time.or(schedule.core_end_time);
+    schedule.settlement_months = payload
+        .settlement_months
+        .unwrap_or(schedule.settlement_months);
+    schedule.settlement_start_month = payload
+        .settlement_start_month
+        .unwrap_or(schedule.settlement_start_month);
+    schedule.updated_at = Utc::now();
+    validate_schedule(&schedule)?;
+    if work_schedule::schedule_name_taken(&state.write_pool, &schedule.name, Some(id)).await? {
+        return Err(AppError::Conflict(format!(
+            "Work schedule '{}' already exists",
+            schedule.name
+        )));
+    }
+
+    let saved = work_schedule::update_schedule(&state.write_pool, &schedule).await?;
+    Ok(Json(saved))
+}
+
+pub async fn delete_work_schedule(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(id): Path<String>,
+) -> Result<Json<Value>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let id = parse_schedule_id(&id)?;
+    if work_schedule::find_schedule(&state.write_pool, id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound("Work schedule not found".into()));
+    }
+    if work_schedule::schedule_is_assigned(&state.write_pool, id).await? {
+        return Err(AppError::Conflict(
+            "Work schedule is still assigned; remove its assignments first".into(),
+        ));
+    }
+
+    work_schedule::delete_schedule(&state.write_pool, id).await?;
+    Ok(Json(json!({"message": "Work schedule deleted", "id": id})))
+}
+
+pub async fn list_work_schedule_assignments(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<WorkScheduleAssignmentQuery>,
+) -> Result<Json<Vec<WorkScheduleAssignment>>, AppError> {
+    if !(user.is_manager() || user.is_system_admin()) {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let user_id = query.user_id.as_deref().map(parse_user_id).transpose()?;
+    let department_id = query
+        .department_id
+        .as_deref()
+        .map(parse_department_id)
+        .transpose()?;
+    let assignments =
+        work_schedule::list_assignments(state.read_pool(), user_id, department_id).await?;
+    Ok(Json(assignments))
+}
+
+pub async fn create_work_schedule_assignment(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Json(payload): Json<CreateWorkScheduleAssignmentPayload>,
+) -> Result<(StatusCode, Json<WorkScheduleAssignment>), AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let schedule_id = parse_schedule_id(&payload.schedule_id)?;
+    let (user_id, department_id) = match (&payload.user_id, &payload.department_id) {
+        (Some(user_id), None) => (Some(parse_user_id(user_id)?), None),
+        (None, Some(department_id)) => (None, Some(parse_department_id(department_id)?)),
+        _ => {
+            return Err(AppError::BadRequest(
+                "Specify exactly one of user_id and department_id".into(),
+            ))
+        }
+    };
+    if payload
+        .effective_to
+        .is_some_and(|effective_to| effective_to < payload.effective_from)
+    {
+        return Err(AppError::BadRequest(
+            "effective_to must be on or after effective_from".into(),
+        ));
+    }
+
+    if work_schedule::find_schedule(&state.write_pool, schedule_id)
+        .await?
+        .is_none()
+    {
+        return Err(AppError::NotFound("Work schedule not found".into()));
+    }
+    if let Some(user_id) = user_id {
+        if !user_repo::user_exists(&state.write_pool, &user_id.to_string()).await? {
+            return Err(AppError::NotFound("User not found".into()));
+        }
+    }
+    if let Some(department_id) = department_id {
+        if department::find_department_by_id(&state.write_pool, &department_id.to_string())
+            .await?
+            .is_none()
+        {
+            return Err(AppError::NotFound("Department not found".into()));
+        }
+    }
+
+    let assignment = WorkScheduleAssignment {
+        id: WorkScheduleAssignmentId::new(),
+        schedule_id,
+        user_id,
+        department_id,
+        effective_from: payload.effective_from,
+        effective_to: payload.effective_to,
+        created_at: Utc::now(),
+    };
+    let saved = work_schedule::create_assignment(&state.write_pool, &assignment).await?;
+    Ok((StatusCode::CREATED, Json(saved)))
+}
+
+pub async fn delete_work_schedule_assignment(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Path(id): Path<String>,
+) -> Result<Json<Value>, AppError> {
+    if !user.is_system_admin() {
+        return Err(AppError::Forbidden("Forbidden".into()));
+    }
+
+    let id = WorkScheduleAssignmentId::from_str(&id)
+        .map_err(|_| AppError::BadRequest("Invalid assignment ID".into()))?;
+    if work_schedule::delete_assignment(&state.write_pool, id).await? == 0 {
+        return Err(AppError::NotFound(
+            "Work schedule assignment not found".into(),
+        ));
+    }
+    Ok(Json(
+        json!({"message": "Work schedule assignment deleted", "id": id}),
+    ))
+}
+
+fn validate_schedule(schedule: &WorkSchedule) -> Result<(), AppError> {
+    if schedule.name.is_empty() {
+        return Err(AppError::BadRequest(
+            "Work schedule name is required".into(),
+        ));
+    }
+    if schedule.end_time == schedule.start_time {
+        return Err(AppError::BadRequest(
+            "end_time must differ from start_time".into(),
+        ));
+    }
+    let span = schedule.span_minutes();
+    if i64::from(schedule.break_minutes) >= span {
+        return Err(AppError::BadRequest(
+            "break_minutes must be shorter than the scheduled span".into(),
+        ));
+    }
+    if schedule.working_weekdays.is_empty() {
+        return Err(AppError::BadRequest(
+            "At least one working weekday is required".into(),
+        ));
+    }
+    if i64::from(schedule.late_grace_minutes) >= span {
+        return Err(AppError::BadRequest(
+            "late_grace_minutes must be shorter than the scheduled span".into(),
+        ));
+    }
+    match (schedule.core_start_time, schedule.core_end_time) {
+        (None, None) => {}
+        (Some(start), Some(end)) if end > start => {}
+        (Some(_), Some(_)) => {
+            return Err(AppError::BadRequest(
+                "core_end_time must be after core_start_time".into(),
+            ))
+        }
+        _ => {
+            return Err(AppError::BadRequest(
+                "core_start_time and core_end_time must be set together".into(),
+            ))
+        }
+    }
+    Ok(())
+}
+
+fn parse_schedule_id(id: &str) -> Result<WorkScheduleId, AppError> {
+    WorkScheduleId::from_str(id).map_err(|_| AppError::BadRequest("Invalid schedule ID".into()))
+}
+
+fn parse_user_id(id: &str) -> Result<UserId, AppError> {
+    UserId::from_str(id).map_err(|_| AppError::BadRequest("Invalid user ID".into()))
+}
+
+fn parse_department_id(id: &str) -> Result<DepartmentId, AppError> {
+    DepartmentId::from_str(id).map_err(|_| AppError::BadRequest("Invalid department ID".into()))
+}
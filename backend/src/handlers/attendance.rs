@@ -1,5 +1,11 @@
 use axum::{
+    body::Body,
     extract::{Extension, Path, Query, State},
+    http::{
+        header::{CONTENT_DISPOSITION, CONTENT_TYPE},
+        HeaderValue,
+    },
+    response::Response,
     Json,
 };
 use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, NaiveDateTime, Utc};
@@ -14,27 +20,44 @@ use crate::error::AppError;
 use crate::handlers::attendance_utils::{
     ensure_authorized_access, ensure_clock_in_exists, ensure_clocked_in, ensure_not_clocked_in,
     ensure_not_clocked_out, fetch_attendance_by_id, fetch_attendance_by_user_date,
-    get_break_records, get_break_records_map, insert_attendance_record, update_clock_in,
-    update_clock_out,
+    fetch_open_attendance, get_break_records, get_break_records_map, insert_attendance_record,
+    update_clock_in,
 };
 use crate::repositories::{
     attendance::{AttendanceRepository, AttendanceRepositoryTrait},
     attendance_correction_request::AttendanceCorrectionRequestRepository,
     break_record::BreakRecordRepository,
+    period_closing,
     repository::Repository,
+    transaction, work_time,
 };
 use crate::state::AppState;
-use crate::types::{AttendanceId, BreakRecordId, UserId};
+use crate::types::{AttendanceId, UserId};
 use crate::{
     models::{
         attendance::{
-            Attendance, AttendanceResponse, AttendanceSummary, ClockInRequest, ClockOutRequest,
+            Attendance, AttendanceResponse, AttendanceStatus, AttendanceSummary, ClockInRequest,
+            ClockOutRequest,
         },
-        attendance_correction_request::CorrectionBreakItem,
         break_record::{BreakRecord, BreakRecordResponse},
+        overtime::OvertimeReconciliation,
+        overtime_limit::OvertimeLimitStatus,
+        period_closing::PeriodClosing,
         user::User,
     },
-    services::holiday::HolidayServiceTrait,
+    services::{
+        attendance_report::{self, AttendanceReportService, ReportEmployee},
+        break_policy::BreakPolicyService,
+        flextime::FlextimeService,
+        holiday::HolidayServiceTrait,
+        leave_attendance::LeaveAttendanceService,
+        overtime::OvertimeService,
+        overtime_limit::OvertimeLimitService,
+        period_closing::PeriodClosingService,
+        rest_interval::RestIntervalService,
+        work_schedule::WorkScheduleService,
+        work_time::{load_effective_attendance, WorkTimeService},
+    },
     utils::{csv::append_csv_row, time},
 };
 
@@ -72,30 +95,58 @@ pub async fn clock_in(
     let tz = &state.config.time_zone;
     let now_local = time::now_in_timezone(tz);
     let now_utc = now_local.with_timezone(&Utc);
-    let date = payload.date.unwrap_or_else(|| now_local.date_naive());
     let clock_in_time = now_local.naive_local();
+    let schedules = WorkScheduleService::new(state.write_pool.clone());
+    let date = match payload.date {
+        Some(date) => date,
+        None => {
+            schedules
+                .business_date(
+                    user_id,
+                    clock_in_time,
+                    state.config.attendance_day_change_hour,
+                )
+                .await?
+        }
+    };
 
     reject_if_holiday(holiday_service.as_ref(), date, user_id).await?;
+    PeriodClosingService::new(state.write_pool.clone())
+        .ensure_open(user_id, date)
+        .await?;
+    let expectation = schedules.day_expectation(user_id, date).await?;
+    let existing = fetch_attendance_by_user_date(&state.write_pool, user_id, date).await?;
+    if let Some(attendance) = &existing {
+        ensure_not_clocked_in(attendance)?;
+    }
+    let rest_shortfall = RestIntervalService::new(state.write_pool.clone())
+        .check_clock_in(user_id, date, clock_in_time)
+        .await?;
 
-    let attendance: Attendance =
-        match fetch_attendance_by_user_date(&state.write_pool, user_id, date).await? {
-            Some(mut attendance) => {
-                ensure_not_clocked_in(&attendance)?;
-                attendance.clock_in_time = Some(clock_in_time);
-                attendance.updated_at = now_utc;
-                update_clock_in(&state.write_pool, &attendance).await?;
-                attendance
-            }
-            None => {
-                let mut attendance = Attendance::new(user_id, date, now_utc);
-                attendance.clock_in_time = Some(clock_in_time);
-                insert_attendance_record(&state.write_pool, &attendance).await?;
-                attendance
+    let attendance: Attendance = match existing {
+        Some(mut attendance) => {
+            // A day marked absent or on leave before any clock-in becomes a worked day.
+            if attendance.clock_in_time.is_none() {
+                attendance.status = AttendanceStatus::Present;
             }
-        };
+            attendance.clock_in_time = Some(clock_in_time);
+            attendance.apply_day_expectation(&expectation);
+            attendance.updated_at = now_utc;
+            update_clock_in(&state.write_pool, &attendance).await?;
+            attendance
+        }
+        None => {
+            let mut attendance = Attendance::new(user_id, date, now_utc);
+            attendance.clock_in_time = Some(clock_in_time);
+            attendance.apply_day_expectation(&expectation);
+            insert_attendance_record(&state.write_pool, &attendance).await?;
+            attendance
+        }
+    };
 
     let break_records = get_break_records(&state.write_pool, attendance.id).await?;
-    let response = build_attendance_response(attendance, break_records);
+    let mut response = build_attendance_response(attendance, break_records);
+    response.rest_interval_warning = rest_shortfall;
 
     Ok(Json(response))
 }
@@ -111,10 +162,31 @@ pub async fn clock_out(
     let tz = &state.config.time_zone;
     let now_local = time::now_in_timezone(tz);
     let now_utc = now_local.with_timezone(&Utc);
-    let date = payload.date.unwrap_or_else(|| now_local.date_naive());
     let clock_out_time = now_local.naive_local();
+    let schedules = WorkScheduleService::new(state.write_pool.clone());
+
+    // Without an explicit date, close whichever shift is still open so overnight work
+    // is clocked out on the record it was clocked in on.
+    let date = match payload.date {
+        Some(date) => date,
+        None => {
+            let business_date = schedules
+                .business_date(
+                    user_id,
+                    clock_out_time,
+                    state.config.attendance_day_change_hour,
+                )
+                .await?;
+            fetch_open_attendance(&state.write_pool, user_id, business_date)
+                .await?
+                .map_or(business_date, |open| open.date)
+        }
+    };
 
     reject_if_holiday(holiday_service.as_ref(), date, user_id).await?;
+    PeriodClosingService::new(state.write_pool.clone())
+        .ensure_open(user_id, date)
+        .await?;
 
     let attendance_opt: Option<Attendance> =
         fetch_attendance_by_user_date(&state.write_pool, user_id, date).await?;
@@ -136,14 +208,26 @@ pub async fn clock_out(
     }
 
     attendance.clock_out_time = Some(clock_out_time);
-    let break_minutes = total_break_minutes(&state.write_pool, attendance.id).await?;
+    let expectation = schedules.day_expectation(user_id, date).await?;
+    let mut tx = transaction::begin_transaction(&state.write_pool).await?;
+    let break_minutes = BreakPolicyService::new(state.write_pool.clone())
+        .enforce(&mut tx, &attendance, now_utc)
+        .await?;
     attendance.calculate_work_hours(break_minutes);
+    attendance.apply_day_expectation(&expectation);
     attendance.updated_at = now_utc;
-
-    update_clock_out(&state.write_pool, &attendance).await?;
+    AttendanceRepository::new()
+        .update_in_transaction(&mut tx, &attendance)
+        .await?;
+    transaction::commit_transaction(tx).await?;
+    let work_time = WorkTimeService::new(state.write_pool.clone())
+        .refresh_week(user_id, date)
+        .await?;
 
     let break_records = get_break_records(&state.write_pool, attendance.id).await?;
-    let response = build_attendance_response(attendance, break_records);
+    let mut response = build_attendance_response(attendance, break_records);
+    response.work_time = work_time.get(&response.id).copied();
+    spawn_overtime_limit_warnings(state, user, date);
 
     Ok(Json(response))
 }
@@ -162,6 +246,9 @@ pub async fn break_start(
     let attendance = fetch_attendance_by_id(&state.write_pool, payload.attendance_id).await?;
     ensure_authorized_access(&attendance, user.id)?;
     ensure_clocked_in(&attendance)?;
+    PeriodClosingService::new(state.write_pool.clone())
+        .ensure_open(attendance.user_id, attendance.date)
+        .await?;
 
     // Check if there's already an active break
     let break_repo = BreakRecordRepository::new();
@@ -201,6 +288,9 @@ pub async fn break_end(
     }
     let att = fetch_attendance_by_id(&state.write_pool, break_record.attendance_id).await?;
     ensure_authorized_access(&att, user.id)?;
+    PeriodClosingService::new(state.write_pool.clone())
+        .ensure_open(att.user_id, att.date)
+        .await?;
 
     break_record.end_break(break_end_time, now_utc);
     break_repo.update(&state.write_pool, &break_record).await?;
@@ -259,16 +349,20 @@ pub async fn get_my_attendance(
     let correction_repo = AttendanceCorrectionRequestRepository::new();
     let correction_map =
         load_effective_correction_map(state.read_pool(), &correction_repo, &attendance_ids).await?;
+    let work_time_map =
+        work_time::find_by_attendance_ids(state.read_pool(), &attendance_ids).await?;
 
     let mut responses = Vec::new();
     for attendance in attendances {
         let attendance_id = attendance.id;
         let break_records = break_map.remove(&attendance_id).unwrap_or_default();
-        responses.push(apply_effective_correction_to_response(
+        let mut response = apply_effective_correction_to_response(
             attendance,
             break_records,
             correction_map.get(&attendance_id),
-        ));
+        );
+        response.work_time = work_time_map.get(&attendance_id).copied();
+        responses.push(response);
     }
 
     Ok(Json(responses))
@@ -280,15 +374,30 @@ pub async fn get_attendance_status(
     Query(params): Query<std::collections::HashMap<String, String>>,
 ) -> Result<Json<AttendanceStatusResponse>, AppError> {
     let user_id = user.id;
-    let date = params
+    let requested_date = params
         .get("date")
-        .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok())
-        .unwrap_or_else(|| time::today_local(&state.config.time_zone));
+        .and_then(|s| NaiveDate::parse_from_str(s, "%Y-%m-%d").ok());
 
     let repo = AttendanceRepository::new();
-    let attendance = repo
-        .find_by_user_and_date(state.read_pool(), user_id, date)
-        .await?;
+    let attendance = match requested_date {
+        Some(date) => {
+            repo.find_by_user_and_date(state.read_pool(), user_id, date)
+                .await?
+        }
+        None => {
+            let now_local = time::now_in_timezone(&state.config.time_zone).naive_local();
+            let business_date = WorkScheduleService::new(state.read_pool().clone())
+                .business_date(user_id, now_local, state.config.attendance_day_change_hour)
+                .await?;
+            match fetch_open_attendance(state.read_pool(), user_id, business_date).await? {
+                Some(open) => Some(open),
+                None => {
+                    repo.find_by_user_and_date(state.read_pool(), user_id, business_date)
+                        .await?
+                }
+            }
+        }
+    };
 
     if let Some(att) = attendance {
         // Check active break
@@ -367,36 +476,17 @@ pub async fn get_my_summary(
     let year = params.year.unwrap_or_else(|| now_local.year());
     let month = params.month.unwrap_or_else(|| now_local.month());
 
-    let Some(first_day) = NaiveDate::from_ymd_opt(year, month, 1) else {
-        return Err(AppError::BadRequest("Invalid year/month provided".into()));
-    };
-    let Some(last_day) = first_day
-        .checked_add_months(Months::new(1))
-        .and_then(|d| d.checked_sub_signed(Duration::days(1)))
-    else {
-        return Err(AppError::BadRequest("Invalid year/month provided".into()));
-    };
-
-    let repo = AttendanceRepository::new();
-    let attendances = repo
-        .find_by_user_and_range(state.read_pool(), user_id, first_day, last_day)
-        .await?;
-    let attendance_ids: Vec<AttendanceId> = attendances.iter().map(|a| a.id).collect();
-    let mut break_map = get_break_records_map(state.read_pool(), &attendance_ids).await?;
-    let correction_repo = AttendanceCorrectionRequestRepository::new();
-    let correction_map =
-        load_effective_correction_map(state.read_pool(), &correction_repo, &attendance_ids).await?;
+    let (first_day, last_day) = month_range(year, month)?;
+    let attendances =
+        load_effective_attendance(state.read_pool(), user_id, first_day, last_day).await?;
 
     let mut total_work_hours = 0.0;
     let mut total_work_days_i64 = 0i64;
-    for attendance in attendances {
-        let attendance_id = attendance.id;
-        let break_records = break_map.remove(&attendance_id).unwrap_or_default();
-        let effective = apply_effective_correction_to_response(
-            attendance,
-            break_records,
-            correction_map.get(&attendance_id),
-        );
+    let mut absent_days = 0i32;
+    for effective in attendances {
+        if matches!(effective.status, AttendanceStatus::Absent) {
+            absent_days += 1;
+        }
         if let Some(hours) = effective.total_work_hours {
             if hours > 0.0 {
                 total_work_hours += hours;
@@ -412,17 +502,135 @@ pub async fn get_my_summary(
         0.0
     };
 
+    let work_time =
+        work_time::sum_for_user(state.read_pool(), user_id, first_day, last_day).await?;
+    let leave = LeaveAttendanceService::new(state.read_pool().clone())
+        .totals(user_id, first_day, last_day)
+        .await?;
+
+    let as_of = last_day.min(now_local.date_naive());
+    let flextime = if as_of >= first_day {
+        FlextimeService::new(state.read_pool().clone())
+            .balance(user_id, as_of)
+            .await?
+    } else {
+        None
+    };
+
     let summary = AttendanceSummary {
         month,
         year,
         total_work_hours,
         total_work_days,
         average_daily_hours,
+        absent_days,
+        leave_days: leave.leave_days,
+        paid_leave_hours: leave.paid_leave_hours,
+        work_time,
+        flextime,
     };
 
     Ok(Json(summary))
 }
 
+/// Months in which the caller's attendance and requests are locked.
+pub async fn get_my_closed_periods(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<Json<Vec<PeriodClosing>>, AppError> {
+    let closings = period_closing::list_closings_for_user(state.read_pool(), user.id).await?;
+    Ok(Json(closings))
+}
+
+pub async fn get_my_overtime(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(params): Query<AttendanceQuery>,
+) -> Result<Json<OvertimeReconciliation>, AppError> {
+    let now_local = time::now_in_timezone(&state.config.time_zone);
+    let year = params.year.unwrap_or_else(|| now_local.year());
+    let month = params.month.unwrap_or_else(|| now_local.month());
+
+    let (first_day, last_day) = month_range(year, month)?;
+
+    let report = OvertimeService::new(state.read_pool().clone())
+        .reconcile(user.id, user.username, first_day, last_day)
+        .await?;
+    Ok(Json(report))
+}
+
+/// The caller's attendance book for a month (the current month by default) as PDF.
+pub async fn get_my_attendance_report(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Extension(holiday_service): Extension<Arc<dyn HolidayServiceTrait>>,
+    Query(params): Query<AttendanceQuery>,
+) -> Result<Response, AppError> {
+    let now_local = time::now_in_timezone(&state.config.time_zone);
+    let year = params.year.unwrap_or_else(|| now_local.year());
+    let month = params.month.unwrap_or_else(|| now_local.month());
+    let (first_day, _) = month_range(year, month)?;
+
+    let employee = ReportEmployee {
+        id: user.id,
+        username: user.username.clone(),
+        full_name: user.full_name.clone(),
+    };
+    let report = AttendanceReportService::new(state.read_pool().clone(), holiday_service)
+        .monthly_report(employee, first_day)
+        .await?;
+    let pdf = attendance_report::render_pdf(&state.config, vec![report]).await?;
+    Ok(pdf_attachment(
+        pdf,
+        &format!(
+            "attendance_report_{}_{}.pdf",
+            user.username,
+            first_day.format("%Y%m")
+        ),
+    ))
+}
+
+/// Wraps a rendered PDF in a download response.
+pub(crate) fn pdf_attachment(pdf: Vec<u8>, filename: &str) -> Response {
+    let mut response = Response::new(Body::from(pdf));
+    response
+        .headers_mut()
+        .insert(CONTENT_TYPE, HeaderValue::from_static("application/pdf"));
+    response.headers_mut().insert(
+        CONTENT_DISPOSITION,
+        HeaderValue::from_str(&format!("attachment; filename=\"{}\"", filename))
+            .unwrap_or_else(|_| HeaderValue::from_static("attachment")),
+    );
+    response
+}
+
+/// First and last day of a calendar month.
+pub(crate) fn month_range(year: i32, month: u32) -> Result<(NaiveDate, NaiveDate), AppError> {
+    let Some(first_day) = NaiveDate::from_ymd_opt(year, month, 1) else {
+        return Err(AppError::BadRequest("Invalid year/month provided".into()));
+    };
+    Ok((first_day, time::month_end(first_day)))
+}
+
+/// Re-checks the user's overtime limits for the month of `date` in the background and
+/// warns the user and their managers about newly reached thresholds.
+fn spawn_overtime_limit_warnings(state: AppState, user: User, date: NaiveDate) {
+    tokio::spawn(async move {
+        let month = time::month_start(date);
+        let result = async {
+            let limits = OvertimeLimitService::new(state.write_pool.clone());
+            let (limit, hours) = limits.limit_inputs(user.id, month).await?;
+            let status =
+                OvertimeLimitStatus::evaluate(user.id, user.username, month, &limit, &hours);
+            limits.send_warnings(&status, &state.config).await
+        }
+        .await;
+        if let Err(err) = result {
+            tracing::warn!(user_id = %user.id, error = ?err, "Failed to check overtime limits");
+        }
+    });
+}
+
 pub async fn export_my_attendance(
     State(state): State<AppState>,
     Extension(user): Extension<User>,
@@ -518,38 +726,11 @@ fn build_attendance_response(
         status: attendance.status,
         total_work_hours: attendance.total_work_hours,
         break_records,
+        work_time: None,
+        rest_interval_warning: None,
     }
 }
 
-fn load_break_items_from_json(value: &serde_json::Value) -> Vec<CorrectionBreakItem> {
-    serde_json::from_value(value.clone()).unwrap_or_default()
-}
-
-fn calc_total_work_hours_with_breaks(
-    clock_in_time: Option<NaiveDateTime>,
-    clock_out_time: Option<NaiveDateTime>,
-    breaks: &[CorrectionBreakItem],
-) -> Option<f64> {
-    let (Some(clock_in), Some(clock_out)) = (clock_in_time, clock_out_time) else {
-        return None;
-    };
-    let mut break_minutes = 0i64;
-    for br in breaks {
-        if let Some(end) = br.break_end_time {
-            let mins = end
-                .signed_duration_since(br.break_start_time)
-                .num_minutes()
-                .max(0);
-            break_minutes += mins;
-        }
-    }
-    let gross_minutes = clock_out
-        .signed_duration_since(clock_in)
-        .num_minutes()
-        .max(0);
-    Some((gross_minutes - break_minutes).max(0) as f64 / 60.0)
-}
-
 fn apply_effective_correction_to_response(
     attendance: Attendance,
     break_records: Vec<BreakRecordResponse>,
@@ -557,49 +738,10 @@ fn apply_effective_correction_to_response(
         &crate::models::attendance_correction_request::AttendanceCorrectionEffectiveValue,
     >,
 ) -> AttendanceResponse {
-    if let Some(effective) = effective {
-        let corrected_breaks = load_break_items_from_json(&effective.break_records_corrected_json);
-        let break_records = corrected_breaks
-            .iter()
-            .enumerate()
-            .map(|(idx, br)| BreakRecordResponse {
-                id: {
-                    let _ = idx;
-                    BreakRecordId::new()
-                },
-                attendance_id: attendance.id,
-                break_start_time: br.break_start_time,
-                break_end_time: br.break_end_time,
-                duration_minutes: br.break_end_time.map(|end| {
-                    end.signed_duration_since(br.break_start_time)
-                        .num_minutes()
-                        .max(0) as i32
-                }),
-            })
-            .collect::<Vec<_>>();
-        let clock_in_time = effective
-            .clock_in_time_corrected
-            .or(attendance.clock_in_time);
-        let clock_out_time = effective
-            .clock_out_time_corrected
-            .or(attendance.clock_out_time);
-
-        return AttendanceResponse {
-            id: attendance.id,
-            user_id: attendance.user_id,
-            date: attendance.date,
-            clock_in_time,
-            clock_out_time,
-            status: attendance.status,
-            total_work_hours: calc_total_work_hours_with_breaks(
-                clock_in_time,
-                clock_out_time,
-                &corrected_breaks,
-            ),
-            break_records,
-        };
+    match effective {
+        Some(effective) => effective.apply_to(attendance),
+        None => build_attendance_response(attendance, break_records),
     }
-    build_attendance_response(attendance, break_records)
 }
 
 async fn load_effective_correction_map(
@@ -621,14 +763,6 @@ async fn load_effective_correction_map(
     Ok(map)
 }
 
-pub(crate) async fn total_break_minutes(
-    pool: &PgPool,
-    attendance_id: AttendanceId,
-) -> Result<i64, AppError> {
-    let repo = BreakRecordRepository::new();
-    repo.get_total_duration(pool, attendance_id).await
-}
-
 pub(crate) async fn recalculate_total_hours(
     pool: &PgPool,
     mut attendance: Attendance,
@@ -638,14 +772,23 @@ pub(crate) async fn recalculate_total_hours(
         return Ok(());
     }
 
-    let break_repo = BreakRecordRepository::new();
-    let break_minutes = break_repo.get_total_duration(pool, attendance.id).await?;
-
+    let expectation = WorkScheduleService::new(pool.clone())
+        .day_expectation(attendance.user_id, attendance.date)
+        .await?;
+    let mut tx = transaction::begin_transaction(pool).await?;
+    let break_minutes = BreakPolicyService::new(pool.clone())
+        .enforce(&mut tx, &attendance, updated_at)
+        .await?;
     attendance.calculate_work_hours(break_minutes);
+    attendance.apply_day_expectation(&expectation);
     attendance.updated_at = updated_at;
-
-    let att_repo = AttendanceRepository::new();
-    att_repo.update(pool, &attendance).await?;
+    AttendanceRepository::new()
+        .update_in_transaction(&mut tx, &attendance)
+        .await?;
+    transaction::commit_transaction(tx).await?;
+    WorkTimeService::new(pool.clone())
+        .refresh_week(attendance.user_id, attendance.date)
+        .await?;
 
     Ok(())
 }
@@ -754,6 +897,11 @@ mod tests {
             total_work_hours: 160.5,
             total_work_days: 20,
             average_daily_hours: 8.0,
+            absent_days: 1,
+            leave_days: 1.5,
+            paid_leave_hours: 12.0,
+            work_time: Default::default(),
+            flextime: None,
         };
         assert_eq!(summary.month, 1);
         assert_eq!(summary.year, 2024);
@@ -832,7 +980,7 @@ mod tests {
                     .expect("clock in"),
             ),
             clock_out_time: None,
-            status: crate::models::attendance::AttendanceStatus::Present,
+            status: AttendanceStatus::Present,
             total_work_hours: None,
             created_at: now,
             updated_at: now,
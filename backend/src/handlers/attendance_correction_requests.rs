@@ -21,6 +21,7 @@ use crate::repositories::{
     },
     break_record::BreakRecordRepository,
 };
+use crate::services::period_closing::PeriodClosingService;
 use crate::state::AppState;
 
 pub async fn create_attendance_correction_request(
@@ -29,6 +30,9 @@ pub async fn create_attendance_correction_request(
     Json(payload): Json<CreateAttendanceCorrectionRequest>,
 ) -> Result<Json<AttendanceCorrectionResponse>, AppError> {
     validate_reason(&payload.reason)?;
+    PeriodClosingService::new(state.write_pool.clone())
+        .ensure_open(user.id, payload.date)
+        .await?;
 
     let attendance_repo = AttendanceRepository::new();
     let break_repo = BreakRecordRepository::new();
@@ -130,6 +134,9 @@ pub async fn update_my_attendance_correction_request(
             "Only pending requests can be updated".into(),
         ));
     }
+    PeriodClosingService::new(state.write_pool.clone())
+        .ensure_open(user.id, current.date)
+        .await?;
 
     let original_snapshot = current
         .parse_original_snapshot()
@@ -172,6 +179,12 @@ pub async fn cancel_my_attendance_correction_request(
     Path(id): Path<String>,
 ) -> Result<Json<serde_json::Value>, AppError> {
     let repo = AttendanceCorrectionRequestRepository::new();
+    let current = repo
+        .find_by_id_for_user(state.read_pool(), &id, user.id)
+        .await?;
+    PeriodClosingService::new(state.write_pool.clone())
+        .ensure_open(user.id, current.date)
+        .await?;
     repo.cancel_pending_for_user(&state.write_pool, &id, user.id)
         .await?;
     Ok(Json(serde_json::json!({ "id": id, "status": "cancelled" })))
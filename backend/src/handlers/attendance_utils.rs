@@ -60,6 +60,18 @@ pub async fn fetch_attendance_by_user_date(
     repo.find_by_user_and_date(pool, user_id, date).await
 }
 
+/// Open record the user's next clock-out or break should attach to, if it started no
+/// earlier than the day before `business_date`.
+pub async fn fetch_open_attendance(
+    pool: &PgPool,
+    user_id: UserId,
+    business_date: NaiveDate,
+) -> Result<Option<Attendance>, AppError> {
+    let since = business_date.pred_opt().unwrap_or(business_date);
+    let repo = AttendanceRepository::new();
+    repo.find_open_by_user(pool, user_id, since).await
+}
+
 pub async fn fetch_attendance_by_id(
     pool: &PgPool,
     attendance_id: AttendanceId,
@@ -81,10 +93,6 @@ pub async fn update_clock_in(pool: &PgPool, attendance: &Attendance) -> Result<(
     update_attendance_record(pool, attendance).await
 }
 
-pub async fn update_clock_out(pool: &PgPool, attendance: &Attendance) -> Result<(), AppError> {
-    update_attendance_record(pool, attendance).await
-}
-
 async fn update_attendance_record(pool: &PgPool, attendance: &Attendance) -> Result<(), AppError> {
     let repo = AttendanceRepository::new();
     repo.update(pool, attendance).await?;
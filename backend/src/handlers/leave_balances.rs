@@ -0,0 +1,18 @@
+use axum::{
+    extract::{Extension, State},
+    Json,
+};
+
+use crate::{
+    error::AppError, models::leave_balance::LeaveBalanceResponse, models::user::User,
+    services::leave_balance::LeaveBalanceService, state::AppState,
+};
+
+pub async fn get_my_leave_balances(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<Json<Vec<LeaveBalanceResponse>>, AppError> {
+    let service = LeaveBalanceService::new(state.read_pool().clone());
+    let balances = service.balances_for_user(user.id).await?;
+    Ok(Json(balances))
+}
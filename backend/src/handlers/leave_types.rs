@@ -0,0 +1,13 @@
+use axum::{extract::State, Json};
+
+use crate::{
+    error::AppError, models::leave_type::LeaveTypeDefinition, repositories::leave_type,
+    state::AppState,
+};
+
+pub async fn list_active_leave_types(
+    State(state): State<AppState>,
+) -> Result<Json<Vec<LeaveTypeDefinition>>, AppError> {
+    let leave_types = leave_type::list_active(state.read_pool()).await?;
+    Ok(Json(leave_types))
+}
@@ -7,6 +7,9 @@ pub mod config;
 pub mod consents;
 pub mod holiday_exceptions;
 pub mod holidays;
+pub mod leave_balances;
+pub mod leave_types;
 pub mod requests;
 pub mod sessions;
 pub mod subject_requests;
+pub mod timesheets;
@@ -9,17 +9,23 @@ use crate::{
     error::AppError,
     models::{
         attendance_correction_request::AttendanceCorrectionResponse,
-        leave_request::{CreateLeaveRequest, LeaveRequest, LeaveRequestResponse},
+        leave_request::{
+            validate_leave_shape, CreateLeaveRequest, LeaveRequest, LeaveRequestResponse,
+            LeaveType, LeaveUnit,
+        },
         overtime_request::{CreateOvertimeRequest, OvertimeRequest, OvertimeRequestResponse},
     },
     repositories::{
         attendance_correction_request::AttendanceCorrectionRequestRepository,
         leave_request::{LeaveRequestRepository, LeaveRequestRepositoryTrait},
+        leave_type,
         overtime_request::{OvertimeRequestRepository, OvertimeRequestRepositoryTrait},
         request::{RequestCreate, RequestRecord, RequestRepository},
     },
+    services::{leave_balance::LeaveBalanceService, period_closing::PeriodClosingService},
     state::AppState,
-    types::{LeaveRequestId, OvertimeRequestId},
+    types::{LeaveRequestId, OvertimeRequestId, UserId},
+    utils::time,
 };
 
 use chrono::Utc;
@@ -41,7 +47,15 @@ pub async fn create_leave_request(
         payload.start_date,
         payload.end_date,
         payload.reason,
-    );
+    )
+    .with_unit(payload.unit, payload.hours);
+    ensure_requestable_leave_type(&state, &leave_request.leave_type).await?;
+    PeriodClosingService::new(state.write_pool.clone())
+        .ensure_range_open(user_id, leave_request.start_date, leave_request.end_date)
+        .await?;
+    LeaveBalanceService::new(state.write_pool.clone())
+        .ensure_request_fits(&leave_request)
+        .await?;
 
     let repo = RequestRepository::new();
     let saved = repo
@@ -58,6 +72,15 @@ pub async fn create_leave_request(
     Ok(Json(response))
 }
 
+async fn ensure_requestable_leave_type(state: &AppState, code: &LeaveType) -> Result<(), AppError> {
+    match leave_type::find_by_code(&state.write_pool, code).await? {
+        Some(definition) if definition.is_active => Ok(()),
+        _ => Err(AppError::BadRequest(format!(
+            "Unknown or inactive leave type: {code}"
+        ))),
+    }
+}
+
 pub async fn create_overtime_request(
     State(state): State<AppState>,
     Extension(user): Extension<crate::models::user::User>,
@@ -67,6 +90,9 @@ pub async fn create_overtime_request(
 
     payload.validate()?;
 
+    PeriodClosingService::new(state.write_pool.clone())
+        .ensure_open(user_id, payload.date)
+        .await?;
     let overtime_request =
         OvertimeRequest::new(user_id, payload.date, payload.planned_hours, payload.reason);
 
@@ -119,6 +145,8 @@ pub struct UpdateLeavePayload {
     pub leave_type: Option<crate::models::leave_request::LeaveType>,
     pub start_date: Option<chrono::NaiveDate>,
     pub end_date: Option<chrono::NaiveDate>,
+    pub unit: Option<LeaveUnit>,
+    pub hours: Option<f64>,
     pub reason: Option<String>,
 }
 
@@ -157,18 +185,43 @@ pub async fn update_request(
         let new_type = upd.leave_type.unwrap_or_else(|| updated.leave_type.clone());
         let new_start = upd.start_date.unwrap_or(updated.start_date);
         let new_end = upd.end_date.unwrap_or(updated.end_date);
-        if new_start > new_end {
-            return Err(AppError::BadRequest(
-                "start_date must be <= end_date".into(),
-            ));
+        // Changing the unit replaces the hour count; otherwise keep it unless new hours are sent.
+        let new_hours = if upd.unit.is_some() {
+            upd.hours
+        } else {
+            upd.hours.or(updated.hours)
+        };
+        let new_unit = upd.unit.unwrap_or(updated.unit);
+        validate_leave_shape(new_start, new_end, new_unit, new_hours).map_err(|err| {
+            match err.code.as_ref() {
+                "start_date_after_end_date" => {
+                    AppError::BadRequest("start_date must be <= end_date".into())
+                }
+                code => AppError::BadRequest(format!("Invalid leave unit: {code}")),
+            }
+        })?;
+        if new_type != updated.leave_type {
+            ensure_requestable_leave_type(&state, &new_type).await?;
         }
+        let closings = PeriodClosingService::new(state.write_pool.clone());
+        closings
+            .ensure_range_open(user_id, updated.start_date, updated.end_date)
+            .await?;
+        closings
+            .ensure_range_open(user_id, new_start, new_end)
+            .await?;
         let new_reason = upd.reason.or(updated.reason.clone());
         let now = Utc::now();
         updated.leave_type = new_type;
         updated.start_date = new_start;
         updated.end_date = new_end;
+        updated.unit = new_unit;
+        updated.hours = new_hours;
         updated.reason = new_reason;
         updated.updated_at = now;
+        LeaveBalanceService::new(state.write_pool.clone())
+            .ensure_request_fits(&updated)
+            .await?;
         leave_repo.update(&state.write_pool, &updated).await?;
         return Ok(Json(json!({"message":"Leave request updated"})));
     }
@@ -194,6 +247,9 @@ pub async fn update_request(
         if new_hours <= 0.0 {
             return Err(AppError::BadRequest("planned_hours must be > 0".into()));
         }
+        let closings = PeriodClosingService::new(state.write_pool.clone());
+        closings.ensure_open(user_id, req.date).await?;
+        closings.ensure_open(user_id, new_date).await?;
         let new_reason = upd.reason.or(req.reason.clone());
         let now = Utc::now();
         let mut updated = req;
@@ -217,6 +273,9 @@ pub async fn update_request(
                     "Only pending requests can be updated".into(),
                 ));
             }
+            PeriodClosingService::new(state.write_pool.clone())
+                .ensure_open(user_id, current.date)
+                .await?;
 
             let reason = payload
                 .get("reason")
@@ -259,6 +318,7 @@ pub async fn cancel_request(
 ) -> Result<Json<Value>, AppError> {
     let user_id = user.id;
     let now = Utc::now();
+    ensure_own_request_period_open(&state, &request_id, user_id).await?;
 
     // Try leave cancellation first
     let leave_request_id = LeaveRequestId::from_str(&request_id)
@@ -270,6 +330,13 @@ pub async fn cancel_request(
     if result > 0 {
         return Ok(Json(json!({"id": request_id, "status":"cancelled"})));
     }
+    let today = time::today_local(&state.config.time_zone);
+    if LeaveBalanceService::new(state.write_pool.clone())
+        .cancel_with_reversal(leave_request_id, user_id, now, today)
+        .await?
+    {
+        return Ok(Json(json!({"id": request_id, "status":"cancelled"})));
+    }
 
     // Try overtime cancellation
     let overtime_request_id = OvertimeRequestId::from_str(&request_id)
@@ -298,6 +365,41 @@ pub async fn cancel_request(
     ))
 }
 
+/// Rejects cancelling a request whose dates fall in a closed month.
+async fn ensure_own_request_period_open(
+    state: &AppState,
+    request_id: &str,
+    user_id: UserId,
+) -> Result<(), AppError> {
+    let closings = PeriodClosingService::new(state.write_pool.clone());
+    if let Ok(leave_request_id) = LeaveRequestId::from_str(request_id) {
+        if let Some(request) = LeaveRequestRepository::new()
+            .find_by_id_for_user(&state.write_pool, leave_request_id, user_id)
+            .await?
+        {
+            return closings
+                .ensure_range_open(user_id, request.start_date, request.end_date)
+                .await;
+        }
+    }
+    if let Ok(overtime_request_id) = OvertimeRequestId::from_str(request_id) {
+        if let Some(request) = OvertimeRequestRepository::new()
+            .find_by_id_for_user(&state.write_pool, overtime_request_id, user_id)
+            .await?
+        {
+            return closings.ensure_open(user_id, request.date).await;
+        }
+    }
+    match AttendanceCorrectionRequestRepository::new()
+        .find_by_id_for_user(&state.write_pool, request_id, user_id)
+        .await
+    {
+        Ok(request) => closings.ensure_open(user_id, request.date).await,
+        Err(AppError::NotFound(_)) => Ok(()),
+        Err(err) => Err(err),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::NaiveDate;
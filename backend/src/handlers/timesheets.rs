@@ -0,0 +1,59 @@
+use axum::{
+    extract::{Extension, Query, State},
+    http::StatusCode,
+    Json,
+};
+use validator::Validate;
+
+use crate::{
+    error::AppError,
+    models::{
+        timesheet::{TimesheetMonthPayload, TimesheetMonthStatus, TimesheetSubmission},
+        user::User,
+    },
+    repositories::timesheet,
+    services::timesheet::TimesheetService,
+    state::AppState,
+    utils::time,
+};
+
+pub async fn list_my_timesheets(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+) -> Result<Json<Vec<TimesheetSubmission>>, AppError> {
+    let submissions = timesheet::list_submissions_for_user(state.read_pool(), user.id).await?;
+    Ok(Json(submissions))
+}
+
+/// Submission state of a month together with the items blocking its submission.
+pub async fn get_my_timesheet_month(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Query(query): Query<TimesheetMonthPayload>,
+) -> Result<Json<TimesheetMonthStatus>, AppError> {
+    let period_month = parse_period_month(&query)?;
+    let status = TimesheetService::new(state.read_pool().clone())
+        .month_status(user.id, period_month)
+        .await?;
+    Ok(Json(status))
+}
+
+pub async fn submit_my_timesheet(
+    State(state): State<AppState>,
+    Extension(user): Extension<User>,
+    Json(payload): Json<TimesheetMonthPayload>,
+) -> Result<(StatusCode, Json<TimesheetSubmission>), AppError> {
+    let period_month = parse_period_month(&payload)?;
+    let today = time::today_local(&state.config.time_zone);
+    let submission = TimesheetService::new(state.write_pool.clone())
+        .submit(user.id, period_month, today)
+        .await?;
+    Ok((StatusCode::CREATED, Json(submission)))
+}
+
+fn parse_period_month(payload: &TimesheetMonthPayload) -> Result<chrono::NaiveDate, AppError> {
+    payload.validate()?;
+    payload
+        .period_month()
+        .ok_or_else(|| AppError::BadRequest("Invalid period".into()))
+}
@@ -164,6 +164,11 @@ fn public_routes(state: AppState) -> Router<AppState> {
         )
         .route("/api/config/timezone", get(handlers::config::get_time_zone))
         .route_layer(rate_limiter)
+        // Signed download links are fetched without a session and not counted as auth attempts.
+        .route(
+            "/api/export-downloads/{id}",
+            get(handlers::admin::download_export_file),
+        )
         .route_layer(axum_middleware::from_fn_with_state(
             state.clone(),
             middleware::audit_log,
@@ -200,6 +205,18 @@ fn user_routes(state: AppState) -> Router<AppState> {
             "/api/attendance/me/summary",
             get(handlers::attendance::get_my_summary),
         )
+        .route(
+            "/api/attendance/me/overtime",
+            get(handlers::attendance::get_my_overtime),
+        )
+        .route(
+            "/api/attendance/me/report",
+            get(handlers::attendance::get_my_attendance_report),
+        )
+        .route(
+            "/api/attendance/me/closed-periods",
+            get(handlers::attendance::get_my_closed_periods),
+        )
         .route(
             "/api/attendance/{id}/breaks",
             get(handlers::attendance::get_breaks_by_attendance),
@@ -208,6 +225,15 @@ fn user_routes(state: AppState) -> Router<AppState> {
             "/api/attendance/export",
             get(handlers::attendance::export_my_attendance),
         )
+        .route(
+            "/api/timesheets/me",
+            get(handlers::timesheets::list_my_timesheets)
+                .post(handlers::timesheets::submit_my_timesheet),
+        )
+        .route(
+            "/api/timesheets/me/month",
+            get(handlers::timesheets::get_my_timesheet_month),
+        )
         .route(
             "/api/attendance-corrections",
             post(handlers::attendance_correction_requests::create_attendance_correction_request),
@@ -231,6 +257,14 @@ fn user_routes(state: AppState) -> Router<AppState> {
             post(handlers::requests::create_overtime_request),
         )
         .route("/api/requests/me", get(handlers::requests::get_my_requests))
+        .route(
+            "/api/leave-balances/me",
+            get(handlers::leave_balances::get_my_leave_balances),
+        )
+        .route(
+            "/api/leave-types",
+            get(handlers::leave_types::list_active_leave_types),
+        )
         .route(
             "/api/requests/{id}",
             put(handlers::requests::update_request),
@@ -375,6 +409,22 @@ fn admin_routes(state: AppState) -> Router<AppState> {
             "/api/admin/users/{id}/sessions",
             get(handlers::admin::list_user_sessions),
         )
+        .route(
+            "/api/admin/users/{id}/leave-balances",
+            get(handlers::admin::get_user_leave_balances),
+        )
+        .route(
+            "/api/admin/users/{id}/employment-profile",
+            get(handlers::admin::get_employment_profile),
+        )
+        .route(
+            "/api/admin/leave-accruals/preview",
+            get(handlers::admin::preview_leave_accruals),
+        )
+        .route(
+            "/api/admin/compliance/annual-leave",
+            get(handlers::admin::get_annual_leave_compliance),
+        )
         .route(
             "/api/admin/sessions/{id}",
             delete(handlers::admin::revoke_session),
@@ -383,6 +433,70 @@ fn admin_routes(state: AppState) -> Router<AppState> {
             "/api/admin/attendance",
             get(handlers::admin::get_all_attendance),
         )
+        .route(
+            "/api/admin/overtime/reconciliation",
+            get(handlers::admin::get_overtime_reconciliation),
+        )
+        .route(
+            "/api/admin/compliance/overtime",
+            get(handlers::admin::get_overtime_compliance),
+        )
+        .route(
+            "/api/admin/overtime-limits",
+            get(handlers::admin::list_overtime_limits),
+        )
+        .route(
+            "/api/admin/compliance/breaks",
+            get(handlers::admin::get_break_compliance),
+        )
+        .route(
+            "/api/admin/break-policy",
+            get(handlers::admin::get_break_policy),
+        )
+        .route(
+            "/api/admin/compliance/rest-intervals",
+            get(handlers::admin::get_rest_interval_compliance),
+        )
+        .route(
+            "/api/admin/compliance/consecutive-workdays",
+            get(handlers::admin::get_consecutive_workday_compliance),
+        )
+        .route(
+            "/api/admin/health/long-hours",
+            get(handlers::admin::get_long_hours_report),
+        )
+        .route(
+            "/api/admin/health/interviews",
+            get(handlers::admin::list_health_interviews),
+        )
+        .route(
+            "/api/admin/health/interviews/{user_id}/{year}/{month}",
+            put(handlers::admin::record_health_interview),
+        )
+        .route(
+            "/api/admin/rest-interval-policy",
+            get(handlers::admin::get_rest_interval_policy),
+        )
+        .route(
+            "/api/admin/period-closings",
+            get(handlers::admin::list_period_closings).post(handlers::admin::close_period),
+        )
+        .route(
+            "/api/admin/departments/{id}/attendance-report",
+            get(handlers::admin::export_department_attendance_report),
+        )
+        .route(
+            "/api/admin/timesheets",
+            get(handlers::admin::list_timesheets),
+        )
+        .route(
+            "/api/admin/timesheets/{id}/approve",
+            post(handlers::admin::approve_timesheet),
+        )
+        .route(
+            "/api/admin/timesheets/{id}/return",
+            post(handlers::admin::return_timesheet),
+        )
         .route(
             "/api/admin/holidays/{id}",
             delete(handlers::admin::delete_holiday),
@@ -401,10 +515,42 @@ fn admin_routes(state: AppState) -> Router<AppState> {
             delete(handlers::holiday_exceptions::delete_holiday_exception),
         )
         .route("/api/admin/export", get(handlers::admin::export_data))
+        .route(
+            "/api/admin/export-templates",
+            get(handlers::admin::list_export_templates),
+        )
+        .route(
+            "/api/admin/export-templates/fields",
+            get(handlers::admin::list_export_fields),
+        )
+        .route(
+            "/api/admin/export-jobs",
+            post(handlers::admin::create_export_job).get(handlers::admin::list_export_jobs),
+        )
+        .route(
+            "/api/admin/export-jobs/{id}",
+            get(handlers::admin::get_export_job),
+        )
+        .route(
+            "/api/admin/export-jobs/{id}/download",
+            get(handlers::admin::get_export_job_download),
+        )
         .route(
             "/api/admin/departments",
             get(handlers::admin::list_departments),
         )
+        .route(
+            "/api/admin/leave-types",
+            get(handlers::admin::list_leave_types),
+        )
+        .route(
+            "/api/admin/work-schedules",
+            get(handlers::admin::list_work_schedules),
+        )
+        .route(
+            "/api/admin/work-schedule-assignments",
+            get(handlers::admin::list_work_schedule_assignments),
+        )
         .route(
             "/api/admin/departments/{id}",
             get(handlers::admin::get_department),
@@ -438,6 +584,10 @@ fn system_admin_routes(state: AppState) -> Router<AppState> {
             "/api/admin/attendance",
             put(handlers::admin::upsert_attendance),
         )
+        .route(
+            "/api/admin/attendance/forgotten-clock-outs",
+            get(handlers::admin::list_forgotten_clock_outs),
+        )
         .route(
             "/api/admin/breaks/{id}/force-end",
             put(handlers::admin::force_end_break),
@@ -455,6 +605,14 @@ fn system_admin_routes(state: AppState) -> Router<AppState> {
             "/api/admin/users/{id}/unlock",
             post(handlers::admin::unlock_user_account),
         )
+        .route(
+            "/api/admin/users/{id}/leave-balances",
+            post(handlers::admin::create_leave_balance_entry),
+        )
+        .route(
+            "/api/admin/users/{id}/employment-profile",
+            put(handlers::admin::upsert_employment_profile),
+        )
         .route(
             "/api/admin/users/{id}",
             delete(handlers::admin::delete_user),
@@ -475,6 +633,71 @@ fn system_admin_routes(state: AppState) -> Router<AppState> {
             "/api/admin/departments",
             post(handlers::admin::create_department),
         )
+        .route(
+            "/api/admin/leave-types",
+            post(handlers::admin::create_leave_type),
+        )
+        .route(
+            "/api/admin/leave-types/{code}",
+            put(handlers::admin::update_leave_type).delete(handlers::admin::delete_leave_type),
+        )
+        .route(
+            "/api/admin/work-schedules",
+            post(handlers::admin::create_work_schedule),
+        )
+        .route(
+            "/api/admin/work-schedules/{id}",
+            put(handlers::admin::update_work_schedule)
+                .delete(handlers::admin::delete_work_schedule),
+        )
+        .route(
+            "/api/admin/export-templates",
+            post(handlers::admin::create_export_template),
+        )
+        .route(
+            "/api/admin/export-templates/{id}",
+            put(handlers::admin::update_export_template)
+                .delete(handlers::admin::delete_export_template),
+        )
+        .route(
+            "/api/admin/export-schedules",
+            get(handlers::admin::list_export_schedules)
+                .post(handlers::admin::create_export_schedule),
+        )
+        .route(
+            "/api/admin/export-schedules/{id}",
+            put(handlers::admin::update_export_schedule)
+                .delete(handlers::admin::delete_export_schedule),
+        )
+        .route(
+            "/api/admin/overtime-limits",
+            put(handlers::admin::update_company_overtime_limit),
+        )
+        .route(
+            "/api/admin/overtime-limits/departments/{id}",
+            put(handlers::admin::update_department_overtime_limit)
+                .delete(handlers::admin::delete_department_overtime_limit),
+        )
+        .route(
+            "/api/admin/break-policy",
+            put(handlers::admin::update_break_policy),
+        )
+        .route(
+            "/api/admin/rest-interval-policy",
+            put(handlers::admin::update_rest_interval_policy),
+        )
+        .route(
+            "/api/admin/period-closings/{id}",
+            delete(handlers::admin::reopen_period),
+        )
+        .route(
+            "/api/admin/work-schedule-assignments",
+            post(handlers::admin::create_work_schedule_assignment),
+        )
+        .route(
+            "/api/admin/work-schedule-assignments/{id}",
+            delete(handlers::admin::delete_work_schedule_assignment),
+        )
         .route(
             "/api/admin/departments/{id}",
             put(handlers::admin::update_department).delete(handlers::admin::delete_department),
@@ -676,6 +899,17 @@ mod tests {
             cookie_same_site: crate::utils::cookies::SameSite::Lax,
             cors_allow_origins,
             time_zone: UTC,
+            attendance_day_change_hour: 0,
+            forgotten_clock_out_cutoff_hours: 4,
+            forgotten_clock_out_auto_close: false,
+            consecutive_workday_limit: 6,
+            report_font_path: None,
+            export_storage_dir: "exports".into(),
+            export_s3_bucket: None,
+            export_s3_endpoint: None,
+            export_download_ttl_seconds: 300,
+            export_download_secret: "test-export-download-secret-32-chars".to_string(),
+            export_encryption_required: false,
             mfa_issuer: "Timekeeper".to_string(),
             rate_limit_ip_max_requests: 15,
             rate_limit_ip_window_seconds: 900,
@@ -531,6 +531,9 @@ fn classify_event(method: &Method, path: &str) -> Option<AuditEventDescriptor> {
         (&Method::GET, ["api", "attendance", "export"]) => {
             Some(event("attendance_export", "export", None))
         }
+        (&Method::GET, ["api", "attendance", "me", "report"]) => {
+            Some(event("attendance_report_export", "export", None))
+        }
         (&Method::POST, ["api", "requests", "leave"]) => {
             Some(event("request_leave_create", "request", None))
         }
@@ -636,6 +639,56 @@ fn classify_event(method: &Method, path: &str) -> Option<AuditEventDescriptor> {
             ))
         }
         (&Method::GET, ["api", "admin", "export"]) => Some(event("admin_export", "export", None)),
+        (&Method::POST, ["api", "admin", "export-templates"]) => Some(event(
+            "admin_export_template_create",
+            "export_template",
+            None,
+        )),
+        (&Method::PUT, ["api", "admin", "export-templates", id]) => Some(event(
+            "admin_export_template_update",
+            "export_template",
+            Some((*id).to_string()),
+        )),
+        (&Method::DELETE, ["api", "admin", "export-templates", id]) => Some(event(
+            "admin_export_template_delete",
+            "export_template",
+            Some((*id).to_string()),
+        )),
+        (&Method::POST, ["api", "admin", "export-jobs"]) => {
+            Some(event("admin_export_job_create", "export_job", None))
+        }
+        (&Method::GET, ["api", "admin", "export-jobs", id, "download"]) => Some(event(
+            "admin_export_job_download",
+            "export_job",
+            Some((*id).to_string()),
+        )),
+        (&Method::GET, ["api", "export-downloads", id]) => Some(event(
+            "export_job_file_download",
+            "export_job",
+            Some((*id).to_string()),
+        )),
+        (&Method::POST, ["api", "admin", "export-schedules"]) => Some(event(
+            "admin_export_schedule_create",
+            "export_schedule",
+            None,
+        )),
+        (&Method::PUT, ["api", "admin", "export-schedules", id]) => Some(event(
+            "admin_export_schedule_update",
+            "export_schedule",
+            Some((*id).to_string()),
+        )),
+        (&Method::DELETE, ["api", "admin", "export-schedules", id]) => Some(event(
+            "admin_export_schedule_delete",
+            "export_schedule",
+            Some((*id).to_string()),
+        )),
+        (&Method::GET, ["api", "admin", "departments", department_id, "attendance-report"]) => {
+            Some(event(
+                "admin_attendance_report_export",
+                "department",
+                Some((*department_id).to_string()),
+            ))
+        }
         (&Method::GET, ["api", "admin", "users"]) => Some(event("admin_user_list", "system", None)),
         (&Method::POST, ["api", "admin", "users"]) => {
             Some(event("admin_user_create", "user", None))
@@ -645,12 +698,149 @@ fn classify_event(method: &Method, path: &str) -> Option<AuditEventDescriptor> {
             "user",
             Some((*user_id).to_string()),
         )),
+        (&Method::GET, ["api", "admin", "users", user_id, "leave-balances"]) => Some(event(
+            "admin_leave_balance_view",
+            "user",
+            Some((*user_id).to_string()),
+        )),
+        (&Method::POST, ["api", "admin", "users", user_id, "leave-balances"]) => Some(event(
+            "admin_leave_balance_entry_create",
+            "user",
+            Some((*user_id).to_string()),
+        )),
+        (&Method::GET, ["api", "admin", "users", user_id, "employment-profile"]) => Some(event(
+            "admin_employment_profile_view",
+            "user",
+            Some((*user_id).to_string()),
+        )),
+        (&Method::PUT, ["api", "admin", "users", user_id, "employment-profile"]) => Some(event(
+            "admin_employment_profile_update",
+            "user",
+            Some((*user_id).to_string()),
+        )),
+        (&Method::POST, ["api", "admin", "leave-types"]) => {
+            Some(event("admin_leave_type_create", "leave_type", None))
+        }
+        (&Method::PUT, ["api", "admin", "leave-types", code]) => Some(event(
+            "admin_leave_type_update",
+            "leave_type",
+            Some((*code).to_string()),
+        )),
+        (&Method::DELETE, ["api", "admin", "leave-types", code]) => Some(event(
+            "admin_leave_type_delete",
+            "leave_type",
+            Some((*code).to_string()),
+        )),
+        (&Method::POST, ["api", "admin", "work-schedules"]) => {
+            Some(event("admin_work_schedule_create", "work_schedule", None))
+        }
+        (&Method::PUT, ["api", "admin", "work-schedules", id]) => Some(event(
+            "admin_work_schedule_update",
+            "work_schedule",
+            Some((*id).to_string()),
+        )),
+        (&Method::DELETE, ["api", "admin", "work-schedules", id]) => Some(event(
+            "admin_work_schedule_delete",
+            "work_schedule",
+            Some((*id).to_string()),
+        )),
+        (&Method::POST, ["api", "admin", "work-schedule-assignments"]) => Some(event(
+            "admin_work_schedule_assignment_create",
+            "work_schedule_assignment",
+            None,
+        )),
+        (&Method::DELETE, ["api", "admin", "work-schedule-assignments", id]) => Some(event(
+            "admin_work_schedule_assignment_delete",
+            "work_schedule_assignment",
+            Some((*id).to_string()),
+        )),
+        (&Method::GET, ["api", "admin", "leave-accruals", "preview"]) => {
+            Some(event("admin_leave_accrual_preview", "system", None))
+        }
+        (&Method::GET, ["api", "admin", "compliance", "annual-leave"]) => {
+            Some(event("admin_annual_leave_compliance_view", "system", None))
+        }
+        (&Method::GET, ["api", "admin", "overtime", "reconciliation"]) => {
+            Some(event("admin_overtime_reconciliation_view", "system", None))
+        }
+        (&Method::GET, ["api", "admin", "compliance", "overtime"]) => {
+            Some(event("admin_overtime_compliance_view", "system", None))
+        }
+        (&Method::PUT, ["api", "admin", "overtime-limits"]) => {
+            Some(event("admin_overtime_limit_update", "overtime_limit", None))
+        }
+        (&Method::PUT, ["api", "admin", "overtime-limits", "departments", id]) => Some(event(
+            "admin_department_overtime_limit_update",
+            "department",
+            Some((*id).to_string()),
+        )),
+        (&Method::DELETE, ["api", "admin", "overtime-limits", "departments", id]) => Some(event(
+            "admin_department_overtime_limit_delete",
+            "department",
+            Some((*id).to_string()),
+        )),
+        (&Method::GET, ["api", "admin", "compliance", "breaks"]) => {
+            Some(event("admin_break_compliance_view", "system", None))
+        }
+        (&Method::PUT, ["api", "admin", "break-policy"]) => {
+            Some(event("admin_break_policy_update", "break_policy", None))
+        }
+        (&Method::GET, ["api", "admin", "compliance", "rest-intervals"]) => {
+            Some(event("admin_rest_interval_compliance_view", "system", None))
+        }
+        (&Method::GET, ["api", "admin", "compliance", "consecutive-workdays"]) => Some(event(
+            "admin_consecutive_workday_compliance_view",
+            "system",
+            None,
+        )),
+        (&Method::GET, ["api", "admin", "health", "long-hours"]) => {
+            Some(event("admin_long_hours_report_view", "system", None))
+        }
+        (&Method::GET, ["api", "admin", "health", "interviews"]) => Some(event(
+            "admin_health_interview_history_view",
+            "health_interview",
+            None,
+        )),
+        (&Method::PUT, ["api", "admin", "health", "interviews", user_id, _, _]) => Some(event(
+            "admin_health_interview_record",
+            "health_interview",
+            Some((*user_id).to_string()),
+        )),
+        (&Method::PUT, ["api", "admin", "rest-interval-policy"]) => Some(event(
+            "admin_rest_interval_policy_update",
+            "rest_interval_policy",
+            None,
+        )),
+        (&Method::POST, ["api", "admin", "period-closings"]) => {
+            Some(event("admin_period_close", "period_closing", None))
+        }
+        (&Method::DELETE, ["api", "admin", "period-closings", id]) => Some(event(
+            "admin_period_reopen",
+            "period_closing",
+            Some((*id).to_string()),
+        )),
+        (&Method::POST, ["api", "timesheets", "me"]) => {
+            Some(event("timesheet_submit", "timesheet", None))
+        }
+        (&Method::POST, ["api", "admin", "timesheets", id, "approve"]) => Some(event(
+            "admin_timesheet_approve",
+            "timesheet",
+            Some((*id).to_string()),
+        )),
+        (&Method::POST, ["api", "admin", "timesheets", id, "return"]) => Some(event(
+            "admin_timesheet_return",
+            "timesheet",
+            Some((*id).to_string()),
+        )),
         (&Method::GET, ["api", "admin", "attendance"]) => {
             Some(event("admin_attendance_list", "system", None))
         }
         (&Method::PUT, ["api", "admin", "attendance"]) => {
             Some(event("admin_attendance_upsert", "attendance", None))
         }
+        (&Method::GET, ["api", "admin", "attendance", "forgotten-clock-outs"]) => {
+            Some(event("admin_forgotten_clock_out_list", "system", None))
+        }
         (&Method::PUT, ["api", "admin", "breaks", break_id, "force-end"]) => Some(event(
             "admin_break_force_end",
             "break_record",
@@ -693,8 +883,14 @@ fn is_excluded(method: &Method, path: &str) -> bool {
         (&Method::GET, ["api", "attendance", "status"]) => true,
         (&Method::GET, ["api", "attendance", "me"]) => true,
         (&Method::GET, ["api", "attendance", "me", "summary"]) => true,
+        (&Method::GET, ["api", "attendance", "me", "overtime"]) => true,
+        (&Method::GET, ["api", "attendance", "me", "closed-periods"]) => true,
         (&Method::GET, ["api", "attendance", _, "breaks"]) => true,
         (&Method::GET, ["api", "requests", "me"]) => true,
+        (&Method::GET, ["api", "leave-balances", "me"]) => true,
+        (&Method::GET, ["api", "leave-types"]) => true,
+        (&Method::GET, ["api", "timesheets", "me"]) => true,
+        (&Method::GET, ["api", "timesheets", "me", "month"]) => true,
         _ => path.starts_with("/api/docs") || path.starts_with("/api-doc/"),
     }
 }
@@ -781,6 +977,288 @@ mod tests {
         assert_eq!(admin_event.target_id.as_deref(), Some("user-1"));
     }
 
+    #[test]
+    fn classify_event_matches_leave_balance_paths() {
+        let view_event = classify_event(&Method::GET, "/api/admin/users/user-1/leave-balances")
+            .expect("admin view maps");
+        assert_eq!(view_event.event_type, "admin_leave_balance_view");
+        assert_eq!(view_event.target_id.as_deref(), Some("user-1"));
+
+        let entry_event = classify_event(&Method::POST, "/api/admin/users/user-1/leave-balances")
+            .expect("admin entry maps");
+        assert_eq!(entry_event.event_type, "admin_leave_balance_entry_create");
+        assert_eq!(entry_event.target_type, Some("user"));
+
+        assert!(is_excluded(&Method::GET, "/api/leave-balances/me"));
+    }
+
+    #[test]
+    fn classify_event_matches_leave_type_paths() {
+        let create_event =
+            classify_event(&Method::POST, "/api/admin/leave-types").expect("create maps");
+        assert_eq!(create_event.event_type, "admin_leave_type_create");
+        assert_eq!(create_event.target_type, Some("leave_type"));
+
+        let update_event =
+            classify_event(&Method::PUT, "/api/admin/leave-types/care").expect("update maps");
+        assert_eq!(update_event.event_type, "admin_leave_type_update");
+        assert_eq!(update_event.target_id.as_deref(), Some("care"));
+
+        let delete_event =
+            classify_event(&Method::DELETE, "/api/admin/leave-types/care").expect("delete maps");
+        assert_eq!(delete_event.event_type, "admin_leave_type_delete");
+
+        assert!(is_excluded(&Method::GET, "/api/leave-types"));
+    }
+
+    #[test]
+    fn classify_event_matches_work_schedule_paths() {
+        let create_event =
+            classify_event(&Method::POST, "/api/admin/work-schedules").expect("create maps");
+        assert_eq!(create_event.event_type, "admin_work_schedule_create");
+        assert_eq!(create_event.target_type, Some("work_schedule"));
+
+        let update_event =
+            classify_event(&Method::PUT, "/api/admin/work-schedules/ws-1").expect("update maps");
+        assert_eq!(update_event.event_type, "admin_work_schedule_update");
+        assert_eq!(update_event.target_id.as_deref(), Some("ws-1"));
+
+        let assign_event = classify_event(&Method::POST, "/api/admin/work-schedule-assignments")
+            .expect("assignment maps");
+        assert_eq!(
+            assign_event.event_type,
+            "admin_work_schedule_assignment_create"
+        );
+
+        let unassign_event = classify_event(
+            &Method::DELETE,
+            "/api/admin/work-schedule-assignments/wsa-1",
+        )
+        .expect("unassignment maps");
+        assert_eq!(
+            unassign_event.event_type,
+            "admin_work_schedule_assignment_delete"
+        );
+        assert_eq!(unassign_event.target_id.as_deref(), Some("wsa-1"));
+    }
+
+    #[test]
+    fn classify_event_matches_export_template_paths() {
+        let create_event =
+            classify_event(&Method::POST, "/api/admin/export-templates").expect("create maps");
+        assert_eq!(create_event.event_type, "admin_export_template_create");
+        assert_eq!(create_event.target_type, Some("export_template"));
+
+        let update_event =
+            classify_event(&Method::PUT, "/api/admin/export-templates/et-1").expect("update maps");
+        assert_eq!(update_event.event_type, "admin_export_template_update");
+        assert_eq!(update_event.target_id.as_deref(), Some("et-1"));
+
+        let delete_event = classify_event(&Method::DELETE, "/api/admin/export-templates/et-1")
+            .expect("delete maps");
+        assert_eq!(delete_event.event_type, "admin_export_template_delete");
+    }
+
+    #[test]
+    fn classify_event_matches_export_job_paths() {
+        let create_event =
+            classify_event(&Method::POST, "/api/admin/export-jobs").expect("create maps");
+        assert_eq!(create_event.event_type, "admin_export_job_create");
+        assert_eq!(create_event.target_type, Some("export_job"));
+
+        let link_event = classify_event(&Method::GET, "/api/admin/export-jobs/job-1/download")
+            .expect("download link maps");
+        assert_eq!(link_event.event_type, "admin_export_job_download");
+        assert_eq!(link_event.target_id.as_deref(), Some("job-1"));
+
+        let file_event = classify_event(&Method::GET, "/api/export-downloads/job-1")
+            .expect("file download maps");
+        assert_eq!(file_event.event_type, "export_job_file_download");
+        assert!(classify_event(&Method::GET, "/api/admin/export-jobs").is_none());
+    }
+
+    #[test]
+    fn classify_event_matches_export_schedule_paths() {
+        let create_event =
+            classify_event(&Method::POST, "/api/admin/export-schedules").expect("create maps");
+        assert_eq!(create_event.event_type, "admin_export_schedule_create");
+        assert_eq!(create_event.target_type, Some("export_schedule"));
+
+        let update_event =
+            classify_event(&Method::PUT, "/api/admin/export-schedules/es-1").expect("update maps");
+        assert_eq!(update_event.event_type, "admin_export_schedule_update");
+        assert_eq!(update_event.target_id.as_deref(), Some("es-1"));
+
+        let delete_event = classify_event(&Method::DELETE, "/api/admin/export-schedules/es-1")
+            .expect("delete maps");
+        assert_eq!(delete_event.event_type, "admin_export_schedule_delete");
+    }
+
+    #[test]
+    fn classify_event_matches_forgotten_clock_out_listing() {
+        let list_event = classify_event(&Method::GET, "/api/admin/attendance/forgotten-clock-outs")
+            .expect("listing maps");
+        assert_eq!(list_event.event_type, "admin_forgotten_clock_out_list");
+        assert_eq!(list_event.target_type, Some("system"));
+    }
+
+    #[test]
+    fn classify_event_matches_overtime_reconciliation() {
+        let event = classify_event(&Method::GET, "/api/admin/overtime/reconciliation")
+            .expect("reconciliation maps");
+        assert_eq!(event.event_type, "admin_overtime_reconciliation_view");
+        assert!(classify_event(&Method::GET, "/api/attendance/me/overtime").is_none());
+    }
+
+    #[test]
+    fn classify_event_matches_overtime_limit_paths() {
+        let report_event = classify_event(&Method::GET, "/api/admin/compliance/overtime")
+            .expect("compliance report maps");
+        assert_eq!(report_event.event_type, "admin_overtime_compliance_view");
+
+        let company_event =
+            classify_event(&Method::PUT, "/api/admin/overtime-limits").expect("update maps");
+        assert_eq!(company_event.event_type, "admin_overtime_limit_update");
+        assert_eq!(company_event.target_type, Some("overtime_limit"));
+
+        let department_event = classify_event(
+            &Method::DELETE,
+            "/api/admin/overtime-limits/departments/dept-1",
+        )
+        .expect("department delete maps");
+        assert_eq!(
+            department_event.event_type,
+            "admin_department_overtime_limit_delete"
+        );
+        assert_eq!(department_event.target_id.as_deref(), Some("dept-1"));
+    }
+
+    #[test]
+    fn classify_event_matches_break_policy_paths() {
+        let report_event = classify_event(&Method::GET, "/api/admin/compliance/breaks")
+            .expect("break report maps");
+        assert_eq!(report_event.event_type, "admin_break_compliance_view");
+
+        let update_event =
+            classify_event(&Method::PUT, "/api/admin/break-policy").expect("update maps");
+        assert_eq!(update_event.event_type, "admin_break_policy_update");
+        assert_eq!(update_event.target_type, Some("break_policy"));
+        assert!(classify_event(&Method::GET, "/api/admin/break-policy").is_none());
+    }
+
+    #[test]
+    fn classify_event_matches_rest_interval_paths() {
+        let report_event = classify_event(&Method::GET, "/api/admin/compliance/rest-intervals")
+            .expect("rest interval report maps");
+        assert_eq!(
+            report_event.event_type,
+            "admin_rest_interval_compliance_view"
+        );
+
+        let update_event =
+            classify_event(&Method::PUT, "/api/admin/rest-interval-policy").expect("update maps");
+        assert_eq!(update_event.event_type, "admin_rest_interval_policy_update");
+        assert_eq!(update_event.target_type, Some("rest_interval_policy"));
+    }
+
+    #[test]
+    fn classify_event_matches_consecutive_workday_report_path() {
+        let report_event =
+            classify_event(&Method::GET, "/api/admin/compliance/consecutive-workdays")
+                .expect("consecutive workday report maps");
+        assert_eq!(
+            report_event.event_type,
+            "admin_consecutive_workday_compliance_view"
+        );
+        assert_eq!(report_event.target_type, Some("system"));
+    }
+
+    #[test]
+    fn classify_event_matches_health_interview_paths() {
+        let report_event = classify_event(&Method::GET, "/api/admin/health/long-hours")
+            .expect("long hours report maps");
+        assert_eq!(report_event.event_type, "admin_long_hours_report_view");
+
+        let record_event =
+            classify_event(&Method::PUT, "/api/admin/health/interviews/user-1/2026/3")
+                .expect("interview record maps");
+        assert_eq!(record_event.event_type, "admin_health_interview_record");
+        assert_eq!(record_event.target_type, Some("health_interview"));
+        assert_eq!(record_event.target_id.as_deref(), Some("user-1"));
+    }
+
+    #[test]
+    fn classify_event_matches_period_closing_paths() {
+        let close_event =
+            classify_event(&Method::POST, "/api/admin/period-closings").expect("close maps");
+        assert_eq!(close_event.event_type, "admin_period_close");
+        assert_eq!(close_event.target_type, Some("period_closing"));
+
+        let reopen_event = classify_event(&Method::DELETE, "/api/admin/period-closings/closing-1")
+            .expect("reopen maps");
+        assert_eq!(reopen_event.event_type, "admin_period_reopen");
+        assert_eq!(reopen_event.target_id.as_deref(), Some("closing-1"));
+        assert!(classify_event(&Method::GET, "/api/admin/period-closings").is_none());
+        assert!(classify_event(&Method::GET, "/api/attendance/me/closed-periods").is_none());
+    }
+
+    #[test]
+    fn classify_event_matches_timesheet_paths() {
+        let submit_event =
+            classify_event(&Method::POST, "/api/timesheets/me").expect("submit maps");
+        assert_eq!(submit_event.event_type, "timesheet_submit");
+        assert_eq!(submit_event.target_type, Some("timesheet"));
+
+        let approve_event = classify_event(&Method::POST, "/api/admin/timesheets/ts-1/approve")
+            .expect("approve maps");
+        assert_eq!(approve_event.event_type, "admin_timesheet_approve");
+        assert_eq!(approve_event.target_id.as_deref(), Some("ts-1"));
+
+        let return_event = classify_event(&Method::POST, "/api/admin/timesheets/ts-1/return")
+            .expect("return maps");
+        assert_eq!(return_event.event_type, "admin_timesheet_return");
+        assert!(is_excluded(&Method::GET, "/api/timesheets/me/month"));
+    }
+
+    #[test]
+    fn classify_event_matches_attendance_report_paths() {
+        let my_event =
+            classify_event(&Method::GET, "/api/attendance/me/report").expect("own report maps");
+        assert_eq!(my_event.event_type, "attendance_report_export");
+        assert_eq!(my_event.target_type, Some("export"));
+
+        let department_event = classify_event(
+            &Method::GET,
+            "/api/admin/departments/dept-1/attendance-report",
+        )
+        .expect("department report maps");
+        assert_eq!(
+            department_event.event_type,
+            "admin_attendance_report_export"
+        );
+        assert_eq!(department_event.target_id.as_deref(), Some("dept-1"));
+    }
+
+    #[test]
+    fn classify_event_matches_leave_accrual_paths() {
+        let update_event =
+            classify_event(&Method::PUT, "/api/admin/users/user-1/employment-profile")
+                .expect("profile update maps");
+        assert_eq!(update_event.event_type, "admin_employment_profile_update");
+        assert_eq!(update_event.target_id.as_deref(), Some("user-1"));
+
+        let preview_event = classify_event(&Method::GET, "/api/admin/leave-accruals/preview")
+            .expect("preview maps");
+        assert_eq!(preview_event.event_type, "admin_leave_accrual_preview");
+
+        let compliance_event = classify_event(&Method::GET, "/api/admin/compliance/annual-leave")
+            .expect("compliance report maps");
+        assert_eq!(
+            compliance_event.event_type,
+            "admin_annual_leave_compliance_view"
+        );
+    }
+
     #[test]
     fn classify_event_matches_audit_log_paths() {
         let list_event = classify_event(&Method::GET, "/api/admin/audit-logs")
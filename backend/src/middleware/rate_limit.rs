@@ -703,6 +703,17 @@ mod tests {
             cookie_same_site: crate::utils::cookies::SameSite::Lax,
             cors_allow_origins: vec!["http://localhost:8000".into()],
             time_zone: chrono_tz::UTC,
+            attendance_day_change_hour: 0,
+            forgotten_clock_out_cutoff_hours: 4,
+            forgotten_clock_out_auto_close: false,
+            consecutive_workday_limit: 6,
+            report_font_path: None,
+            export_storage_dir: "exports".into(),
+            export_s3_bucket: None,
+            export_s3_endpoint: None,
+            export_download_ttl_seconds: 300,
+            export_download_secret: "test-export-download-secret-32-chars".to_string(),
+            export_encryption_required: false,
             mfa_issuer: "Timekeeper".into(),
             rate_limit_ip_max_requests: ip_max_requests,
             rate_limit_ip_window_seconds: ip_window_seconds,
@@ -0,0 +1,22 @@
+//! Models for the nightly absence detection.
+
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+/// Records created by one absence detection run.
+#[allow(dead_code)]
+pub struct AbsenceSummary {
+    /// Working days without a clock-in or approved leave.
+    pub absent: u64,
+    /// Working days covered by approved leave.
+    pub leave: u64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+/// Approved leave taken over a period.
+pub struct LeaveTotals {
+    /// Working days on leave, with half days as 0.5 and hourly leave as a share of the day.
+    pub leave_days: f64,
+    /// Hours of that leave taken as paid leave.
+    pub paid_leave_hours: f64,
+}
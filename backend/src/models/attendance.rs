@@ -1,6 +1,9 @@
 //! Models that represent employee attendance records and related requests.
 
 use crate::models::break_record::BreakRecordResponse;
+use crate::models::rest_interval::RestShortfall;
+use crate::models::work_schedule::{DayExpectation, FlextimeBalance};
+use crate::models::work_time::WorkTimeBreakdown;
 use crate::types::{AttendanceId, BreakRecordId, UserId};
 use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
@@ -44,6 +47,8 @@ pub enum AttendanceStatus {
     Late,
     /// Employee was present for only part of the day.
     HalfDay,
+    /// Employee did not work because approved leave covered the day.
+    Leave,
 }
 
 impl AttendanceStatus {
@@ -53,6 +58,7 @@ impl AttendanceStatus {
             AttendanceStatus::Absent => "absent",
             AttendanceStatus::Late => "late",
             AttendanceStatus::HalfDay => "half_day",
+            AttendanceStatus::Leave => "leave",
         }
     }
 }
@@ -92,6 +98,11 @@ pub struct AttendanceResponse {
     pub status: AttendanceStatus,
     pub total_work_hours: Option<f64>,
     pub break_records: Vec<BreakRecordResponse>,
+    /// Worked hours by premium pay category, once the day is clocked out.
+    pub work_time: Option<WorkTimeBreakdown>,
+    /// Set on clock-in when the rest since the previous clock-out was too short.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rest_interval_warning: Option<RestShortfall>,
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -102,6 +113,16 @@ pub struct AttendanceSummary {
     pub total_work_hours: f64,
     pub total_work_days: i32,
     pub average_daily_hours: f64,
+    /// Working days recorded as absent.
+    pub absent_days: i32,
+    /// Working days on approved leave, with partial days counted by their share.
+    pub leave_days: f64,
+    /// Hours of approved leave of paid leave types.
+    pub paid_leave_hours: f64,
+    /// Worked hours of the month by premium pay category.
+    pub work_time: WorkTimeBreakdown,
+    /// Settlement balance for employees on flextime, as of the month's end or today.
+    pub flextime: Option<FlextimeBalance>,
 }
 
 impl From<Attendance> for AttendanceResponse {
@@ -115,6 +136,8 @@ impl From<Attendance> for AttendanceResponse {
             status: a.status,
             total_work_hours: a.total_work_hours,
             break_records: Vec::new(),
+            work_time: None,
+            rest_interval_warning: None,
         }
     }
 }
@@ -147,6 +170,34 @@ impl Attendance {
         }
     }
 
+    /// Derives the status from what the schedule expected of the day.
+    ///
+    /// Less than half of the expected hours makes a [`AttendanceStatus::HalfDay`]; otherwise
+    /// a clock-in after the late threshold makes [`AttendanceStatus::Late`]. Flextime days
+    /// are never half days because their hours are settled over the period. Absences are
+    /// left untouched.
+    pub fn apply_day_expectation(&mut self, expectation: &DayExpectation) {
+        if matches!(self.status, AttendanceStatus::Absent) {
+            return;
+        }
+        let short = !expectation.flextime
+            && expectation.expected_hours > 0.0
+            && self
+                .total_work_hours
+                .is_some_and(|worked| worked < expectation.expected_hours / 2.0);
+        let late = match (expectation.late_after, self.clock_in_time) {
+            (Some(late_after), Some(clock_in)) => clock_in > late_after,
+            _ => false,
+        };
+        self.status = if short {
+            AttendanceStatus::HalfDay
+        } else if late {
+            AttendanceStatus::Late
+        } else {
+            AttendanceStatus::Present
+        };
+    }
+
     /// Returns `true` when the record has a clock-in but no clock-out yet.
     pub fn is_clocked_in(&self) -> bool {
         self.clock_in_time.is_some() && self.clock_out_time.is_none()
@@ -197,6 +248,7 @@ mod tests {
         assert_eq!(AttendanceStatus::Absent.db_value(), "absent");
         assert_eq!(AttendanceStatus::Late.db_value(), "late");
         assert_eq!(AttendanceStatus::HalfDay.db_value(), "half_day");
+        assert_eq!(AttendanceStatus::Leave.db_value(), "leave");
     }
 
     #[test]
@@ -217,6 +269,54 @@ mod tests {
         assert_eq!(attendance.total_work_hours, Some(0.0));
     }
 
+    #[test]
+    fn apply_day_expectation_flags_short_days_and_late_arrivals() {
+        let date = NaiveDate::from_ymd_opt(2026, 2, 4).expect("date");
+        let mut attendance = Attendance::new(UserId::new(), date, Utc::now());
+        attendance.clock_in_time = Some(date.and_hms_opt(9, 5, 0).unwrap());
+        attendance.total_work_hours = Some(3.5);
+        let late_after = Some(date.and_hms_opt(9, 0, 0).unwrap());
+
+        attendance.apply_day_expectation(&DayExpectation {
+            late_after: None,
+            expected_hours: 8.0,
+            flextime: false,
+        });
+        assert!(matches!(attendance.status, AttendanceStatus::HalfDay));
+
+        // A half day of approved leave halves the expectation.
+        attendance.apply_day_expectation(&DayExpectation {
+            late_after: None,
+            expected_hours: 4.0,
+            flextime: false,
+        });
+        assert!(matches!(attendance.status, AttendanceStatus::Present));
+
+        attendance.apply_day_expectation(&DayExpectation {
+            late_after,
+            expected_hours: 4.0,
+            flextime: false,
+        });
+        assert!(matches!(attendance.status, AttendanceStatus::Late));
+
+        attendance.status = AttendanceStatus::Absent;
+        attendance.apply_day_expectation(&DayExpectation {
+            late_after,
+            expected_hours: 8.0,
+            flextime: false,
+        });
+        assert!(matches!(attendance.status, AttendanceStatus::Absent));
+
+        // Flextime settles short days over the period instead.
+        attendance.status = AttendanceStatus::Present;
+        attendance.apply_day_expectation(&DayExpectation {
+            late_after: None,
+            expected_hours: 8.0,
+            flextime: true,
+        });
+        assert!(matches!(attendance.status, AttendanceStatus::Present));
+    }
+
     #[test]
     fn calculate_work_hours_noops_when_missing_times() {
         let date = NaiveDate::from_ymd_opt(2026, 2, 4).expect("date");
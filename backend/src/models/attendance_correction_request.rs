@@ -1,4 +1,6 @@
-use crate::types::{AttendanceId, UserId};
+use crate::models::attendance::{Attendance, AttendanceResponse};
+use crate::models::break_record::BreakRecordResponse;
+use crate::types::{AttendanceId, BreakRecordId, UserId};
 use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -95,6 +97,67 @@ pub struct AttendanceCorrectionEffectiveValue {
     pub updated_at: DateTime<Utc>,
 }
 
+impl AttendanceCorrectionEffectiveValue {
+    /// Overlays the approved correction on the stored record; `break_records` are
+    /// replaced by the corrected breaks.
+    pub fn apply_to(&self, attendance: Attendance) -> AttendanceResponse {
+        let corrected_breaks: Vec<CorrectionBreakItem> =
+            serde_json::from_value(self.break_records_corrected_json.clone()).unwrap_or_default();
+        let break_records = corrected_breaks
+            .iter()
+            .map(|br| BreakRecordResponse {
+                id: BreakRecordId::new(),
+                attendance_id: attendance.id,
+                break_start_time: br.break_start_time,
+                break_end_time: br.break_end_time,
+                duration_minutes: br.break_end_time.map(|end| {
+                    end.signed_duration_since(br.break_start_time)
+                        .num_minutes()
+                        .max(0) as i32
+                }),
+                is_automatic: false,
+            })
+            .collect::<Vec<_>>();
+        let clock_in_time = self.clock_in_time_corrected.or(attendance.clock_in_time);
+        let clock_out_time = self.clock_out_time_corrected.or(attendance.clock_out_time);
+        let total_work_hours =
+            total_work_hours_with_breaks(clock_in_time, clock_out_time, &corrected_breaks);
+
+        AttendanceResponse {
+            clock_in_time,
+            clock_out_time,
+            total_work_hours,
+            break_records,
+            ..AttendanceResponse::from(attendance)
+        }
+    }
+}
+
+fn total_work_hours_with_breaks(
+    clock_in_time: Option<NaiveDateTime>,
+    clock_out_time: Option<NaiveDateTime>,
+    breaks: &[CorrectionBreakItem],
+) -> Option<f64> {
+    let (Some(clock_in), Some(clock_out)) = (clock_in_time, clock_out_time) else {
+        return None;
+    };
+    let mut break_minutes = 0i64;
+    for br in breaks {
+        if let Some(end) = br.break_end_time {
+            let mins = end
+                .signed_duration_since(br.break_start_time)
+                .num_minutes()
+                .max(0);
+            break_minutes += mins;
+        }
+    }
+    let gross_minutes = clock_out
+        .signed_duration_since(clock_in)
+        .num_minutes()
+        .max(0);
+    Some((gross_minutes - break_minutes).max(0) as f64 / 60.0)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
 pub struct AttendanceCorrectionResponse {
     pub id: String,
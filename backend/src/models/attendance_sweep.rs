@@ -0,0 +1,53 @@
+//! Models for the forgotten clock-out sweep.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+
+use crate::types::{AttendanceId, UserId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+/// What the sweep did with a record that was still open past the cutoff.
+pub enum OpenRecordSweepAction {
+    /// Clocked out at the scheduled end of the shift.
+    AutoClosed,
+    /// Left open for the employee to fix through a correction request.
+    NeedsCorrection,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+/// A swept attendance record as listed for administrators.
+pub struct OpenRecordSweep {
+    pub attendance_id: AttendanceId,
+    pub user_id: UserId,
+    pub username: String,
+    pub date: NaiveDate,
+    pub clock_in_time: Option<NaiveDateTime>,
+    /// Current clock-out of the record, whether set by the sweep or corrected later.
+    pub clock_out_time: Option<NaiveDateTime>,
+    pub action: OpenRecordSweepAction,
+    /// Clock-out recorded by the sweep when it closed the record.
+    pub closed_at: Option<NaiveDateTime>,
+    /// Whether the employee or a manager was emailed about it.
+    pub notified: bool,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Default, Deserialize, IntoParams, ToSchema)]
+pub struct OpenRecordSweepQuery {
+    /// Only list flagged records that are still missing a clock-out.
+    #[serde(default)]
+    pub unresolved: bool,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+/// Counts reported by one sweep run.
+#[allow(dead_code)]
+pub struct SweepSummary {
+    pub auto_closed: u64,
+    pub flagged: u64,
+    pub breaks_ended: u64,
+}
@@ -3,6 +3,9 @@ use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use sqlx::{types::Json, FromRow};
+use utoipa::ToSchema;
+
+use crate::utils::pii::{mask_ip, mask_pii_json, mask_user_agent};
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct AuditLog {
@@ -20,3 +23,52 @@ pub struct AuditLog {
     pub user_agent: Option<String>,
     pub request_id: Option<String>,
 }
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct AuditLogResponse {
+    pub id: String,
+    pub occurred_at: DateTime<Utc>,
+    pub actor_id: Option<String>,
+    pub actor_type: String,
+    pub event_type: String,
+    pub target_type: Option<String>,
+    pub target_id: Option<String>,
+    pub result: String,
+    pub error_code: Option<String>,
+    pub metadata: Option<Value>,
+    pub ip: Option<String>,
+    pub user_agent: Option<String>,
+    pub request_id: Option<String>,
+}
+
+impl From<AuditLog> for AuditLogResponse {
+    fn from(log: AuditLog) -> Self {
+        Self {
+            id: log.id.to_string(),
+            occurred_at: log.occurred_at,
+            actor_id: log.actor_id.map(|id| id.to_string()),
+            actor_type: log.actor_type,
+            event_type: log.event_type,
+            target_type: log.target_type,
+            target_id: log.target_id,
+            result: log.result,
+            error_code: log.error_code,
+            metadata: log.metadata.map(|value| value.0),
+            ip: log.ip,
+            user_agent: log.user_agent,
+            request_id: log.request_id,
+        }
+    }
+}
+
+/// Masks the personal data in an entry for viewers not allowed to see it.
+pub fn apply_pii_policy(mut response: AuditLogResponse, mask_pii: bool) -> AuditLogResponse {
+    if !mask_pii {
+        return response;
+    }
+
+    response.metadata = response.metadata.as_ref().map(mask_pii_json);
+    response.ip = response.ip.as_deref().map(mask_ip);
+    response.user_agent = response.user_agent.as_deref().map(mask_user_agent);
+    response
+}
@@ -0,0 +1,161 @@
+//! Models for the statutory break policy and the days that fall short of it.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::types::{AttendanceId, UserId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+/// What happens when a day's recorded breaks fall short of the policy.
+pub enum BreakPolicyMode {
+    /// Record the day as non-compliant and leave the worked hours as recorded.
+    Flag,
+    /// Insert an automatic break for the missing minutes and record the day as well.
+    Deduct,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+/// Minimum breaks required by worked time, with what to do when they are missing.
+pub struct BreakPolicy {
+    pub mode: BreakPolicyMode,
+    /// Worked minutes beyond which `first_break_minutes` of break are required.
+    pub first_threshold_minutes: i32,
+    pub first_break_minutes: i32,
+    /// Worked minutes beyond which `second_break_minutes` of break are required.
+    pub second_threshold_minutes: i32,
+    pub second_break_minutes: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl BreakPolicy {
+    /// Statutory minimums (45 minutes beyond 6 hours, 60 beyond 8), flagged only.
+    pub fn statutory() -> Self {
+        Self {
+            mode: BreakPolicyMode::Flag,
+            first_threshold_minutes: 360,
+            first_break_minutes: 45,
+            second_threshold_minutes: 480,
+            second_break_minutes: 60,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Overwrites the policy with the payload's values.
+    pub fn apply(&mut self, payload: &BreakPolicyPayload) {
+        self.mode = payload.mode;
+        self.first_threshold_minutes = payload.first_threshold_minutes;
+        self.first_break_minutes = payload.first_break_minutes;
+        self.second_threshold_minutes = payload.second_threshold_minutes;
+        self.second_break_minutes = payload.second_break_minutes;
+    }
+
+    /// Break minutes required for `worked_minutes` of work.
+    pub fn required_break_minutes(&self, worked_minutes: i64) -> i64 {
+        if worked_minutes > i64::from(self.second_threshold_minutes) {
+            i64::from(self.second_break_minutes)
+        } else if worked_minutes > i64::from(self.first_threshold_minutes) {
+            i64::from(self.first_break_minutes)
+        } else {
+            0
+        }
+    }
+
+    /// Checks a shift of `gross_minutes` between clock-in and clock-out.
+    ///
+    /// Worked time is measured before any deduction, so a deduction never lowers the
+    /// break that is required. Returns `None` when the recorded breaks are enough.
+    pub fn check(&self, gross_minutes: i64, recorded_break_minutes: i64) -> Option<BreakShortfall> {
+        let worked_minutes = (gross_minutes - recorded_break_minutes).max(0);
+        let required_break_minutes = self.required_break_minutes(worked_minutes);
+        (recorded_break_minutes < required_break_minutes).then_some(BreakShortfall {
+            worked_minutes,
+            recorded_break_minutes,
+            required_break_minutes,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// How far a day's breaks fall short of the policy.
+pub struct BreakShortfall {
+    pub worked_minutes: i64,
+    pub recorded_break_minutes: i64,
+    pub required_break_minutes: i64,
+}
+
+impl BreakShortfall {
+    pub fn missing_minutes(&self) -> i64 {
+        self.required_break_minutes - self.recorded_break_minutes
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used by system administrators to set the break policy.
+pub struct BreakPolicyPayload {
+    pub mode: BreakPolicyMode,
+    #[validate(range(min = 1, max = 1440))]
+    pub first_threshold_minutes: i32,
+    #[validate(range(min = 0, max = 1440))]
+    pub first_break_minutes: i32,
+    #[validate(range(min = 1, max = 1440))]
+    pub second_threshold_minutes: i32,
+    #[validate(range(min = 0, max = 1440))]
+    pub second_break_minutes: i32,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+/// A day whose recorded breaks fell short of the policy, as listed for managers.
+pub struct BreakViolation {
+    pub attendance_id: AttendanceId,
+    pub user_id: UserId,
+    pub username: String,
+    pub date: NaiveDate,
+    /// Worked minutes before any automatic deduction.
+    pub worked_minutes: i32,
+    pub recorded_break_minutes: i32,
+    pub required_break_minutes: i32,
+    /// Whether an automatic break covered the missing minutes.
+    pub auto_deducted: bool,
+    pub detected_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct BreakViolationQuery {
+    /// Defaults to the first day of the current month in the configured time zone.
+    pub from: Option<NaiveDate>,
+    /// Defaults to today in the configured time zone.
+    pub to: Option<NaiveDate>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn required_break_follows_worked_time() {
+        let policy = BreakPolicy::statutory();
+        assert_eq!(policy.required_break_minutes(360), 0);
+        assert_eq!(policy.required_break_minutes(361), 45);
+        assert_eq!(policy.required_break_minutes(480), 45);
+        assert_eq!(policy.required_break_minutes(481), 60);
+    }
+
+    #[test]
+    fn check_reports_missing_minutes() {
+        let policy = BreakPolicy::statutory();
+        // Nine hours on site with a 30-minute break: 8.5 hours worked, 60 minutes required.
+        let shortfall = policy.check(540, 30).expect("short break");
+        assert_eq!(shortfall.worked_minutes, 510);
+        assert_eq!(shortfall.missing_minutes(), 30);
+
+        // Nine hours on site with an hour's break is compliant.
+        assert_eq!(policy.check(540, 60), None);
+        // Seven hours on site with 45 minutes of break is compliant.
+        assert_eq!(policy.check(420, 45), None);
+    }
+}
@@ -1,7 +1,7 @@
 //! Models that capture break sessions within an attendance record.
 
 use crate::types::{AttendanceId, BreakRecordId, UserId};
-use chrono::{DateTime, NaiveDateTime, Utc};
+use chrono::{DateTime, Duration, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 use utoipa::ToSchema;
@@ -19,6 +19,8 @@ pub struct BreakRecord {
     pub break_end_time: Option<NaiveDateTime>,
     /// Duration of the break in minutes, filled when the break ends.
     pub duration_minutes: Option<i32>,
+    /// Whether the break policy inserted the break to cover a missing statutory break.
+    pub is_automatic: bool,
     /// Creation timestamp for auditing.
     pub created_at: DateTime<Utc>,
     /// Last update timestamp for auditing.
@@ -33,6 +35,9 @@ pub struct BreakRecordResponse {
     pub break_start_time: NaiveDateTime,
     pub break_end_time: Option<NaiveDateTime>,
     pub duration_minutes: Option<i32>,
+    /// Whether the break policy inserted the break.
+    #[serde(default)]
+    pub is_automatic: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
@@ -55,6 +60,7 @@ impl From<BreakRecord> for BreakRecordResponse {
             break_start_time: record.break_start_time,
             break_end_time: record.break_end_time,
             duration_minutes: record.duration_minutes,
+            is_automatic: record.is_automatic,
         }
     }
 }
@@ -72,11 +78,28 @@ impl BreakRecord {
             break_start_time,
             break_end_time: None,
             duration_minutes: None,
+            is_automatic: false,
             created_at: now,
             updated_at: now,
         }
     }
 
+    /// Creates a completed automatic break of `minutes` centred in the shift.
+    pub fn automatic(
+        attendance_id: AttendanceId,
+        clock_in: NaiveDateTime,
+        clock_out: NaiveDateTime,
+        minutes: i64,
+        now: DateTime<Utc>,
+    ) -> Self {
+        let offset = ((clock_out - clock_in).num_minutes() - minutes).max(0) / 2;
+        let start = clock_in + Duration::minutes(offset);
+        let mut record = Self::new(attendance_id, start, now);
+        record.end_break(start + Duration::minutes(minutes), now);
+        record.is_automatic = true;
+        record
+    }
+
     /// Marks the break as completed and computes its duration.
     pub fn end_break(&mut self, break_end_time: NaiveDateTime, now: DateTime<Utc>) {
         self.break_end_time = Some(break_end_time);
@@ -110,4 +133,19 @@ mod tests {
         record.end_break(end, now);
         assert!(!record.is_active());
     }
+
+    #[test]
+    fn automatic_break_is_centred_in_the_shift() {
+        let day = NaiveDate::from_ymd_opt(2024, 1, 1).unwrap();
+        let record = BreakRecord::automatic(
+            AttendanceId::new(),
+            day.and_hms_opt(9, 0, 0).unwrap(),
+            day.and_hms_opt(19, 0, 0).unwrap(),
+            60,
+            Utc::now(),
+        );
+        assert!(record.is_automatic);
+        assert_eq!(record.break_start_time, day.and_hms_opt(13, 30, 0).unwrap());
+        assert_eq!(record.duration_minutes, Some(60));
+    }
 }
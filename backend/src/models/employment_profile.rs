@@ -0,0 +1,112 @@
+//! Models for employment profiles and the statutory leave grants derived from them.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::types::UserId;
+
+/// Working hours assumed for a day when the user has no usable employment profile.
+pub const DEFAULT_DAILY_SCHEDULED_HOURS: f64 = 8.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+/// Contract type that determines which statutory grant table applies.
+pub enum EmploymentType {
+    FullTime,
+    PartTime,
+}
+
+impl EmploymentType {
+    pub fn db_value(&self) -> &'static str {
+        match self {
+            EmploymentType::FullTime => "full_time",
+            EmploymentType::PartTime => "part_time",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+/// Database representation of a user's employment profile.
+pub struct EmploymentProfile {
+    pub user_id: UserId,
+    /// First day of employment; statutory grants are counted from here.
+    pub hire_date: NaiveDate,
+    pub employment_type: EmploymentType,
+    /// Scheduled working days per week.
+    pub weekly_scheduled_days: i16,
+    /// Scheduled working hours per week.
+    pub weekly_scheduled_hours: f64,
+    /// Code that identifies the employee in the payroll system.
+    pub employee_code: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl EmploymentProfile {
+    /// Scheduled hours of one working day, derived from the weekly schedule.
+    pub fn daily_scheduled_hours(&self) -> f64 {
+        if self.weekly_scheduled_days > 0 && self.weekly_scheduled_hours > 0.0 {
+            self.weekly_scheduled_hours / f64::from(self.weekly_scheduled_days)
+        } else {
+            DEFAULT_DAILY_SCHEDULED_HOURS
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used by system administrators to create or replace an employment profile.
+pub struct UpsertEmploymentProfilePayload {
+    pub hire_date: NaiveDate,
+    pub employment_type: EmploymentType,
+    /// Defaults to 5.
+    #[validate(range(min = 0, max = 7))]
+    pub weekly_scheduled_days: Option<i16>,
+    /// Defaults to 40.
+    #[validate(range(min = 0.0, max = 168.0))]
+    pub weekly_scheduled_hours: Option<f64>,
+    /// Code that identifies the employee in the payroll system; unique per employee.
+    #[validate(length(min = 1, max = 50))]
+    pub employee_code: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+/// Whether a previewed grant is already due or only upcoming.
+pub enum AccrualPreviewStatus {
+    /// The grant date has passed but the batch has not booked it yet.
+    Due,
+    /// The next grant after the preview date.
+    Upcoming,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+/// A statutory grant that the accrual batch would book.
+pub struct AccrualPreviewItem {
+    pub user_id: UserId,
+    pub username: String,
+    pub employment_type: EmploymentType,
+    pub grant_date: NaiveDate,
+    pub days: f64,
+    pub expires_on: NaiveDate,
+    pub status: AccrualPreviewStatus,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct AccrualPreviewQuery {
+    /// Preview date; defaults to today in the configured time zone.
+    pub as_of: Option<NaiveDate>,
+}
+
+#[allow(dead_code)]
+#[derive(Debug, Clone, Default, PartialEq, Serialize, ToSchema)]
+/// Outcome of one accrual batch run.
+pub struct AccrualRunSummary {
+    /// Number of statutory grants booked.
+    pub granted: u64,
+    /// Number of grants whose expiry was processed.
+    pub expired: u64,
+}
@@ -0,0 +1,23 @@
+//! Encryption applied to export files before they leave the system.
+
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+/// How an export file is encrypted. Encrypted files use the ASCII-armored age format.
+pub enum ExportEncryptionMethod {
+    /// For the holder of an age X25519 key; the request supplies the public key (`age1...`).
+    Age,
+    /// With a passphrase generated for the request and shown to the requester once.
+    Passphrase,
+}
+
+#[derive(Debug, Default, Deserialize, IntoParams, ToSchema)]
+/// Query parameters asking for an encrypted export.
+pub struct ExportEncryptionQuery {
+    pub encryption: Option<ExportEncryptionMethod>,
+    /// age public key (`age1...`); implies `encryption=age`.
+    pub recipient: Option<String>,
+}
@@ -0,0 +1,118 @@
+//! Models for asynchronous export jobs and the links used to download their results.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::{
+    models::export_encryption::ExportEncryptionMethod,
+    types::{ExportJobId, UserId},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+/// What an export job produces.
+pub enum ExportJobKind {
+    /// Attendance CSV, in the standard layout or an export template's.
+    Attendance,
+    /// Audit logs as a JSON array, with the same PII policy as the synchronous export.
+    AuditLogs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ExportJobStatus {
+    /// Waiting for a worker.
+    Queued,
+    /// A worker is writing the file.
+    Running,
+    /// The file is in the export store and can be downloaded.
+    Completed,
+    /// The export stopped; `error` says why.
+    Failed,
+}
+
+impl ExportJobStatus {
+    #[allow(dead_code)]
+    pub fn db_value(&self) -> &'static str {
+        match self {
+            ExportJobStatus::Queued => "queued",
+            ExportJobStatus::Running => "running",
+            ExportJobStatus::Completed => "completed",
+            ExportJobStatus::Failed => "failed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+/// An export queued by a user and its progress.
+pub struct ExportJob {
+    pub id: ExportJobId,
+    pub requested_by: UserId,
+    pub kind: ExportJobKind,
+    /// Filters resolved when the job was queued.
+    pub parameters: Value,
+    pub status: ExportJobStatus,
+    pub rows_written: i64,
+    /// Rows matching the filters when the worker started.
+    pub total_rows: Option<i64>,
+    #[serde(skip)]
+    pub storage_key: Option<String>,
+    pub filename: Option<String>,
+    pub content_type: Option<String>,
+    pub size_bytes: Option<i64>,
+    pub error: Option<String>,
+    /// How the file is encrypted; plain when absent.
+    pub encryption: Option<ExportEncryptionMethod>,
+    /// age public key, or the sealed passphrase until the worker has used it.
+    #[serde(skip)]
+    pub encryption_key: Option<String>,
+    /// Passphrase generated for the job, returned only when it is queued.
+    #[sqlx(skip)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub passphrase: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub started_at: Option<DateTime<Utc>>,
+    pub finished_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used to queue an export job.
+///
+/// `username` and `template` apply to attendance exports, the `actor_*`, `event_type`,
+/// `target_*` and `result` filters to audit log exports. Audit log exports require
+/// `from` and `to`. With `encryption` the file is encrypted for `recipient` or with a
+/// passphrase returned once in the response.
+pub struct CreateExportJobPayload {
+    pub kind: ExportJobKind,
+    pub username: Option<String>,
+    /// Start date (YYYY-MM-DD; RFC3339 is also accepted for audit logs).
+    pub from: Option<String>,
+    /// End date (YYYY-MM-DD; RFC3339 is also accepted for audit logs).
+    pub to: Option<String>,
+    /// ID or name of an export template; the standard layout is used when absent.
+    #[validate(length(min = 1, max = 100))]
+    pub template: Option<String>,
+    pub actor_id: Option<String>,
+    pub actor_type: Option<String>,
+    pub event_type: Option<String>,
+    pub target_type: Option<String>,
+    pub target_id: Option<String>,
+    pub result: Option<String>,
+    pub encryption: Option<ExportEncryptionMethod>,
+    /// age public key (`age1...`); implies `encryption: age`.
+    pub recipient: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+/// Short-lived link to a completed export file.
+pub struct ExportDownloadResponse {
+    pub url: String,
+    pub expires_at: DateTime<Utc>,
+}
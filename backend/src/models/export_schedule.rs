@@ -0,0 +1,252 @@
+//! Models for recurring exports delivered to a directory, a bucket or a mailing list.
+
+use std::path::{Component, Path};
+
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use validator::{Validate, ValidateEmail};
+
+use crate::types::{ExportScheduleId, ExportTemplateId, UserId};
+use crate::utils::time::month_start;
+
+/// Most recipients an email destination may list.
+pub const MAX_EXPORT_RECIPIENTS: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "TEXT")]
+/// Attendance period a run exports: the one before the time the run was scheduled for.
+pub enum ExportPeriod {
+    #[serde(rename = "previous_day")]
+    #[sqlx(rename = "previous_day")]
+    Day,
+    /// Monday to Sunday of the week before.
+    #[serde(rename = "previous_week")]
+    #[sqlx(rename = "previous_week")]
+    Week,
+    #[serde(rename = "previous_month")]
+    #[sqlx(rename = "previous_month")]
+    Month,
+}
+
+impl ExportPeriod {
+    /// First and last day of the period before `today`.
+    pub fn range(&self, today: NaiveDate) -> (NaiveDate, NaiveDate) {
+        match self {
+            ExportPeriod::Day => {
+                let day = today - Duration::days(1);
+                (day, day)
+            }
+            ExportPeriod::Week => {
+                let this_monday =
+                    today - Duration::days(today.weekday().num_days_from_monday() as i64);
+                (
+                    this_monday - Duration::days(7),
+                    this_monday - Duration::days(1),
+                )
+            }
+            ExportPeriod::Month => {
+                let last = month_start(today) - Duration::days(1);
+                (month_start(last), last)
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+/// Where a scheduled export is delivered.
+pub enum ExportDestination {
+    /// Directory under the export storage directory.
+    Local { directory: String },
+    /// S3-compatible bucket, addressed through the export S3 endpoint when one is configured.
+    S3 {
+        bucket: String,
+        /// Key prefix the file name is appended to.
+        prefix: Option<String>,
+    },
+    /// Sent as an attachment to each recipient.
+    Email { recipients: Vec<String> },
+}
+
+impl ExportDestination {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            ExportDestination::Local { .. } => "local",
+            ExportDestination::S3 { .. } => "s3",
+            ExportDestination::Email { .. } => "email",
+        }
+    }
+
+    /// Checks the destination can be delivered to.
+    pub fn check(&self) -> Result<(), String> {
+        match self {
+            ExportDestination::Local { directory } => {
+                let path = Path::new(directory.trim());
+                if path.as_os_str().is_empty()
+                    || !path
+                        .components()
+                        .all(|component| matches!(component, Component::Normal(_)))
+                {
+                    return Err(
+                        "Local directories must be relative paths inside the export storage directory"
+                            .into(),
+                    );
+                }
+            }
+            ExportDestination::S3 { bucket, .. } => {
+                if bucket.trim().is_empty() {
+                    return Err("S3 destinations need a bucket".into());
+                }
+            }
+            ExportDestination::Email { recipients } => {
+                if recipients.is_empty() || recipients.len() > MAX_EXPORT_RECIPIENTS {
+                    return Err(format!(
+                        "Email destinations need between 1 and {} recipients",
+                        MAX_EXPORT_RECIPIENTS
+                    ));
+                }
+                if let Some(invalid) = recipients.iter().find(|address| !address.validate_email()) {
+                    return Err(format!("Invalid recipient address: {}", invalid));
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum ExportRunStatus {
+    Success,
+    Failure,
+}
+
+impl ExportRunStatus {
+    /// `result` recorded in the audit log for the run.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ExportRunStatus::Success => "success",
+            ExportRunStatus::Failure => "failure",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+/// A recurring attendance export.
+pub struct ExportSchedule {
+    pub id: ExportScheduleId,
+    pub name: String,
+    /// Five-field cron expression evaluated in the application time zone.
+    pub cron_expression: String,
+    /// Template layout; the standard layout when absent.
+    pub template_id: Option<ExportTemplateId>,
+    pub username: Option<String>,
+    pub period: ExportPeriod,
+    #[sqlx(json)]
+    pub destination: ExportDestination,
+    /// age public key the file is encrypted for before delivery; plain when absent.
+    pub encryption_recipient: Option<String>,
+    pub enabled: bool,
+    pub next_run_at: DateTime<Utc>,
+    pub last_run_at: Option<DateTime<Utc>>,
+    pub last_run_status: Option<ExportRunStatus>,
+    pub last_run_error: Option<String>,
+    pub created_by: Option<UserId>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used to create an export schedule.
+pub struct CreateExportSchedulePayload {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    /// Five-field cron expression, e.g. `0 6 1 * *` for 06:00 on the 1st of each month.
+    #[validate(length(min = 1, max = 100))]
+    pub cron_expression: String,
+    /// ID or name of an export template; the standard layout is used when absent.
+    #[validate(length(min = 1, max = 100))]
+    pub template: Option<String>,
+    pub username: Option<String>,
+    pub period: ExportPeriod,
+    pub destination: ExportDestination,
+    /// age public key (`age1...`) to encrypt the file for.
+    pub encryption_recipient: Option<String>,
+    pub enabled: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used to update an export schedule. Omitted fields are left unchanged.
+pub struct UpdateExportSchedulePayload {
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+    #[validate(length(min = 1, max = 100))]
+    pub cron_expression: Option<String>,
+    /// ID or name of an export template; an empty string reverts to the standard layout.
+    #[validate(length(max = 100))]
+    pub template: Option<String>,
+    /// Username to export; an empty string exports everyone.
+    pub username: Option<String>,
+    pub period: Option<ExportPeriod>,
+    pub destination: Option<ExportDestination>,
+    /// age public key to encrypt the file for; an empty string delivers it unencrypted.
+    pub encryption_recipient: Option<String>,
+    pub enabled: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn periods_cover_the_day_week_and_month_before() {
+        assert_eq!(
+            ExportPeriod::Day.range(date(2026, 3, 1)),
+            (date(2026, 2, 28), date(2026, 2, 28))
+        );
+        // 2026-03-04 is a Wednesday.
+        assert_eq!(
+            ExportPeriod::Week.range(date(2026, 3, 4)),
+            (date(2026, 2, 23), date(2026, 3, 1))
+        );
+        assert_eq!(
+            ExportPeriod::Month.range(date(2026, 1, 1)),
+            (date(2025, 12, 1), date(2025, 12, 31))
+        );
+        assert_eq!(
+            ExportPeriod::Month.range(date(2026, 3, 15)),
+            (date(2026, 2, 1), date(2026, 2, 28))
+        );
+    }
+
+    #[test]
+    fn local_destinations_must_stay_inside_the_export_directory() {
+        let local = |directory: &str| ExportDestination::Local {
+            directory: directory.into(),
+        };
+        assert!(local("payroll/monthly").check().is_ok());
+        assert!(local("").check().is_err());
+        assert!(local("/srv/payroll").check().is_err());
+        assert!(local("../payroll").check().is_err());
+        assert!(local("payroll/../../etc").check().is_err());
+    }
+
+    #[test]
+    fn email_destinations_need_valid_recipients() {
+        let email = |recipients: &[&str]| ExportDestination::Email {
+            recipients: recipients.iter().map(|r| r.to_string()).collect(),
+        };
+        assert!(email(&["payroll@example.com"]).check().is_ok());
+        assert!(email(&[]).check().is_err());
+        assert!(email(&["payroll@example.com", "not-an-address"])
+            .check()
+            .is_err());
+    }
+}
@@ -0,0 +1,418 @@
+//! Models for payroll export templates and the catalog of fields they can pick from.
+
+use chrono::{
+    format::{Item, StrftimeItems},
+    DateTime, Utc,
+};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::types::ExportTemplateId;
+
+/// Most columns a template may define.
+pub const MAX_EXPORT_COLUMNS: usize = 100;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+/// Whether a template emits one row per attendance day or one per employee and month.
+pub enum ExportGranularity {
+    Daily,
+    Monthly,
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema,
+)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+/// Character encoding of the generated file.
+pub enum ExportEncoding {
+    #[default]
+    Utf8,
+    /// UTF-8 with a byte order mark, which Excel needs to detect the encoding.
+    Utf8Bom,
+    /// Shift_JIS (Windows-31J); characters it cannot represent are written as `?`.
+    ShiftJis,
+}
+
+impl ExportEncoding {
+    /// `charset` parameter of the response content type.
+    pub fn charset(&self) -> &'static str {
+        match self {
+            ExportEncoding::Utf8 | ExportEncoding::Utf8Bom => "utf-8",
+            ExportEncoding::ShiftJis => "Shift_JIS",
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema,
+)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+/// Separator between cells.
+pub enum ExportDelimiter {
+    #[default]
+    Comma,
+    Tab,
+    Semicolon,
+}
+
+impl ExportDelimiter {
+    pub fn as_char(&self) -> char {
+        match self {
+            ExportDelimiter::Comma => ',',
+            ExportDelimiter::Tab => '\t',
+            ExportDelimiter::Semicolon => ';',
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+/// A value a template column can carry.
+pub enum ExportField {
+    EmployeeCode,
+    Username,
+    FullName,
+    /// Attendance date, formatted with the template's `date_format`. Daily only.
+    Date,
+    /// Month of the row, formatted with the template's `month_format`.
+    Month,
+    /// Clock-in time as HH:MM. Daily only.
+    ClockIn,
+    /// Clock-out time as HH:MM. Daily only.
+    ClockOut,
+    /// Attendance status. Daily only.
+    Status,
+    /// Days with a clock-in. Monthly only.
+    WorkDays,
+    BreakMinutes,
+    TotalHours,
+    RegularHours,
+    StatutoryOvertimeHours,
+    LateNightHours,
+    LegalHolidayHours,
+    NonStatutoryHolidayHours,
+}
+
+impl ExportField {
+    pub const ALL: [ExportField; 16] = [
+        ExportField::EmployeeCode,
+        ExportField::Username,
+        ExportField::FullName,
+        ExportField::Date,
+        ExportField::Month,
+        ExportField::ClockIn,
+        ExportField::ClockOut,
+        ExportField::Status,
+        ExportField::WorkDays,
+        ExportField::BreakMinutes,
+        ExportField::TotalHours,
+        ExportField::RegularHours,
+        ExportField::StatutoryOvertimeHours,
+        ExportField::LateNightHours,
+        ExportField::LegalHolidayHours,
+        ExportField::NonStatutoryHolidayHours,
+    ];
+
+    /// Header written when a column does not set its own.
+    pub fn default_header(&self) -> &'static str {
+        match self {
+            ExportField::EmployeeCode => "Employee Code",
+            ExportField::Username => "Username",
+            ExportField::FullName => "Full Name",
+            ExportField::Date => "Date",
+            ExportField::Month => "Month",
+            ExportField::ClockIn => "Clock In",
+            ExportField::ClockOut => "Clock Out",
+            ExportField::Status => "Status",
+            ExportField::WorkDays => "Work Days",
+            ExportField::BreakMinutes => "Break Minutes",
+            ExportField::TotalHours => "Total Hours",
+            ExportField::RegularHours => "Regular Hours",
+            ExportField::StatutoryOvertimeHours => "Statutory Overtime Hours",
+            ExportField::LateNightHours => "Late Night Hours",
+            ExportField::LegalHolidayHours => "Legal Holiday Hours",
+            ExportField::NonStatutoryHolidayHours => "Non-statutory Holiday Hours",
+        }
+    }
+
+    /// Returns true if the field has a value in rows of `granularity`.
+    pub fn supports(&self, granularity: ExportGranularity) -> bool {
+        match self {
+            ExportField::Date
+            | ExportField::ClockIn
+            | ExportField::ClockOut
+            | ExportField::Status => granularity == ExportGranularity::Daily,
+            ExportField::WorkDays => granularity == ExportGranularity::Monthly,
+            _ => true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+/// One column of a template, in output order.
+pub struct ExportColumn {
+    pub field: ExportField,
+    /// Header text; the field's default header when absent.
+    pub header: Option<String>,
+}
+
+impl ExportColumn {
+    pub fn header(&self) -> &str {
+        self.header
+            .as_deref()
+            .unwrap_or_else(|| self.field.default_header())
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+/// Admin-defined CSV layout for a payroll system.
+pub struct ExportTemplate {
+    pub id: ExportTemplateId,
+    pub name: String,
+    pub granularity: ExportGranularity,
+    pub encoding: ExportEncoding,
+    pub delimiter: ExportDelimiter,
+    /// strftime pattern for the `date` field.
+    pub date_format: String,
+    /// strftime pattern for the `month` field.
+    pub month_format: String,
+    pub include_header: bool,
+    #[sqlx(json)]
+    pub columns: Vec<ExportColumn>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl ExportTemplate {
+    /// Layout used when an export names no template: the columns of the attendance CSV export.
+    #[allow(dead_code)]
+    pub fn standard() -> Self {
+        let now = Utc::now();
+        Self {
+            id: ExportTemplateId::new(),
+            name: "standard".into(),
+            granularity: ExportGranularity::Daily,
+            encoding: ExportEncoding::Utf8,
+            delimiter: ExportDelimiter::Comma,
+            date_format: "%Y-%m-%d".into(),
+            month_format: "%Y-%m".into(),
+            include_header: true,
+            columns: [
+                ExportField::Username,
+                ExportField::FullName,
+                ExportField::Date,
+                ExportField::ClockIn,
+                ExportField::ClockOut,
+                ExportField::TotalHours,
+                ExportField::Status,
+                ExportField::RegularHours,
+                ExportField::StatutoryOvertimeHours,
+                ExportField::LateNightHours,
+                ExportField::LegalHolidayHours,
+                ExportField::NonStatutoryHolidayHours,
+            ]
+            .into_iter()
+            .map(|field| ExportColumn {
+                field,
+                header: None,
+            })
+            .collect(),
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Checks that the columns fit the granularity and the date patterns are usable.
+    pub fn check_layout(&self) -> Result<(), String> {
+        if self.columns.is_empty() || self.columns.len() > MAX_EXPORT_COLUMNS {
+            return Err(format!(
+                "A template needs between 1 and {} columns",
+                MAX_EXPORT_COLUMNS
+            ));
+        }
+        if let Some(column) = self
+            .columns
+            .iter()
+            .find(|column| !column.field.supports(self.granularity))
+        {
+            return Err(format!(
+                "Field '{}' is not available in {} templates",
+                column.field.default_header(),
+                match self.granularity {
+                    ExportGranularity::Daily => "daily",
+                    ExportGranularity::Monthly => "monthly",
+                }
+            ));
+        }
+        if self.columns.iter().any(|column| {
+            column
+                .header
+                .as_ref()
+                .is_some_and(|h| h.chars().count() > 100)
+        }) {
+            return Err("Column headers must be at most 100 characters".into());
+        }
+        for pattern in [&self.date_format, &self.month_format] {
+            if !is_valid_date_format(pattern) {
+                return Err(format!("'{}' is not a valid date format", pattern));
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Returns true if `pattern` is a non-empty strftime pattern chrono can format dates with.
+fn is_valid_date_format(pattern: &str) -> bool {
+    !pattern.is_empty()
+        && StrftimeItems::new(pattern).all(|item| match item {
+            Item::Error => false,
+            // Dates carry no time or offset, so formatting them would fail at export time.
+            Item::Numeric(numeric, _) => {
+                use chrono::format::Numeric::*;
+                !matches!(
+                    numeric,
+                    Hour | Hour12 | Minute | Second | Nanosecond | Timestamp
+                )
+            }
+            Item::Fixed(fixed) => {
+                use chrono::format::Fixed::*;
+                !matches!(
+                    fixed,
+                    LowerAmPm
+                        | UpperAmPm
+                        | Nanosecond
+                        | Nanosecond3
+                        | Nanosecond6
+                        | Nanosecond9
+                        | TimezoneName
+                        | TimezoneOffset
+                        | TimezoneOffsetColon
+                        | TimezoneOffsetDoubleColon
+                        | TimezoneOffsetTripleColon
+                        | TimezoneOffsetColonZ
+                        | TimezoneOffsetZ
+                        | RFC2822
+                        | RFC3339
+                )
+            }
+            _ => true,
+        })
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+/// Catalog entry describing a field templates can pick.
+pub struct ExportFieldInfo {
+    pub field: ExportField,
+    pub default_header: String,
+    pub daily: bool,
+    pub monthly: bool,
+}
+
+impl From<ExportField> for ExportFieldInfo {
+    fn from(field: ExportField) -> Self {
+        Self {
+            field,
+            default_header: field.default_header().to_string(),
+            daily: field.supports(ExportGranularity::Daily),
+            monthly: field.supports(ExportGranularity::Monthly),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used by system administrators to define an export template.
+pub struct CreateExportTemplatePayload {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    pub granularity: ExportGranularity,
+    /// Defaults to `utf8`.
+    pub encoding: Option<ExportEncoding>,
+    /// Defaults to `comma`.
+    pub delimiter: Option<ExportDelimiter>,
+    /// Defaults to `%Y-%m-%d`.
+    #[validate(length(max = 50))]
+    pub date_format: Option<String>,
+    /// Defaults to `%Y-%m`.
+    #[validate(length(max = 50))]
+    pub month_format: Option<String>,
+    /// Defaults to true.
+    pub include_header: Option<bool>,
+    pub columns: Vec<ExportColumn>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used by system administrators to change an export template; omitted fields are kept.
+pub struct UpdateExportTemplatePayload {
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+    pub granularity: Option<ExportGranularity>,
+    pub encoding: Option<ExportEncoding>,
+    pub delimiter: Option<ExportDelimiter>,
+    #[validate(length(max = 50))]
+    pub date_format: Option<String>,
+    #[validate(length(max = 50))]
+    pub month_format: Option<String>,
+    pub include_header: Option<bool>,
+    pub columns: Option<Vec<ExportColumn>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn template(granularity: ExportGranularity, fields: &[ExportField]) -> ExportTemplate {
+        ExportTemplate {
+            id: ExportTemplateId::new(),
+            name: "payroll".into(),
+            granularity,
+            encoding: ExportEncoding::Utf8,
+            delimiter: ExportDelimiter::Comma,
+            date_format: "%Y/%m/%d".into(),
+            month_format: "%Y%m".into(),
+            include_header: true,
+            columns: fields
+                .iter()
+                .map(|field| ExportColumn {
+                    field: *field,
+                    header: None,
+                })
+                .collect(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn daily_only_fields_are_rejected_in_monthly_templates() {
+        let monthly = template(
+            ExportGranularity::Monthly,
+            &[ExportField::EmployeeCode, ExportField::ClockIn],
+        );
+        assert!(monthly.check_layout().is_err());
+
+        let daily = template(
+            ExportGranularity::Daily,
+            &[ExportField::EmployeeCode, ExportField::ClockIn],
+        );
+        assert!(daily.check_layout().is_ok());
+    }
+
+    #[test]
+    fn date_formats_must_only_use_date_specifiers() {
+        let mut daily = template(ExportGranularity::Daily, &[ExportField::Date]);
+        daily.date_format = "%Y年%m月%d日".into();
+        assert!(daily.check_layout().is_ok());
+
+        daily.date_format = "%Y-%m-%d %H:%M".into();
+        assert!(daily.check_layout().is_err());
+
+        daily.date_format = "%Q".into();
+        assert!(daily.check_layout().is_err());
+    }
+}
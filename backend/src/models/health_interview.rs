@@ -0,0 +1,104 @@
+//! Models for the long-working-hours report and the physician interviews offered from it.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::models::overtime::LEGAL_WEEKLY_HOURS;
+use crate::types::{DepartmentId, UserId};
+
+/// Monthly overtime above which an employee must be offered a physician interview.
+pub const INTERVIEW_THRESHOLD_HOURS: f64 = 80.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+/// Where a physician interview stands.
+pub enum HealthInterviewStatus {
+    /// The employee was offered an interview.
+    Offered,
+    /// The interview took place.
+    Completed,
+    /// The employee turned the offer down.
+    Declined,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+/// A physician interview recorded for one employee and month.
+pub struct HealthInterview {
+    pub user_id: UserId,
+    pub username: String,
+    pub year: i32,
+    pub month: i32,
+    /// Overtime of the month when the interview was last recorded.
+    pub overtime_hours: f64,
+    pub status: HealthInterviewStatus,
+    pub notes: Option<String>,
+    pub offered_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    pub updated_by: Option<UserId>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+/// An employee over the interview threshold in a month.
+pub struct LongHoursEmployee {
+    pub user_id: UserId,
+    pub username: String,
+    pub department_id: Option<DepartmentId>,
+    pub year: i32,
+    pub month: u32,
+    pub worked_hours: f64,
+    /// Hours worked beyond 40 per week over the month.
+    pub overtime_hours: f64,
+    /// The interview recorded for the month, if any.
+    pub interview: Option<HealthInterview>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used by managers to record a physician interview.
+pub struct HealthInterviewPayload {
+    pub status: HealthInterviewStatus,
+    #[validate(length(max = 2000))]
+    pub notes: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct LongHoursReportQuery {
+    /// Defaults to the current year in the configured time zone.
+    pub year: Option<i32>,
+    /// Defaults to the current month in the configured time zone.
+    pub month: Option<u32>,
+    /// Restricts the report to employees of this department.
+    pub department_id: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct HealthInterviewHistoryQuery {
+    /// Narrows the history to one employee.
+    pub user_id: Option<String>,
+}
+
+/// Hours worked in a month beyond 40 per week, as used to decide on physician interviews.
+///
+/// The weekly hours are spread over the month's calendar days, so work on holidays counts.
+pub fn health_overtime_hours(worked_hours: f64, days_in_month: i64) -> f64 {
+    let allowance = LEGAL_WEEKLY_HOURS * days_in_month as f64 / 7.0;
+    ((worked_hours - allowance).max(0.0) * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overtime_is_measured_against_forty_hours_per_calendar_week() {
+        // 31 days allow 177.14 hours.
+        assert_eq!(health_overtime_hours(260.0, 31), 82.86);
+        // 28 days allow exactly 160 hours.
+        assert_eq!(health_overtime_hours(240.0, 28), 80.0);
+        assert_eq!(health_overtime_hours(150.0, 30), 0.0);
+    }
+}
@@ -0,0 +1,276 @@
+//! Models for the leave balance ledger and the balances derived from it.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::models::leave_request::LeaveType;
+use crate::types::{LeaveBalanceEntryId, LeaveRequestId, UserId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+/// Kind of movement recorded in the leave balance ledger.
+pub enum LeaveBalanceEntryType {
+    /// Days granted to the employee.
+    Grant,
+    /// Days consumed by an approved leave request (stored as a negative amount).
+    Consumption,
+    /// Days returned when an approved leave request is cancelled.
+    Reversal,
+    /// Manual correction recorded by HR, positive or negative.
+    Adjustment,
+    /// Unused days of a grant that lapsed on its expiry date (stored as a negative amount).
+    Expiration,
+}
+
+impl LeaveBalanceEntryType {
+    pub fn db_value(&self) -> &'static str {
+        match self {
+            LeaveBalanceEntryType::Grant => "grant",
+            LeaveBalanceEntryType::Consumption => "consumption",
+            LeaveBalanceEntryType::Reversal => "reversal",
+            LeaveBalanceEntryType::Adjustment => "adjustment",
+            LeaveBalanceEntryType::Expiration => "expiration",
+        }
+    }
+
+    /// Returns true for entry types that administrators may record by hand.
+    pub fn is_manual(&self) -> bool {
+        matches!(
+            self,
+            LeaveBalanceEntryType::Grant | LeaveBalanceEntryType::Adjustment
+        )
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+/// Database representation of a single leave balance ledger entry.
+pub struct LeaveBalanceEntry {
+    /// Unique identifier for the entry.
+    pub id: LeaveBalanceEntryId,
+    /// Employee whose balance is affected.
+    pub user_id: UserId,
+    /// Leave category the entry applies to.
+    pub leave_type: LeaveType,
+    /// Kind of ledger movement.
+    pub entry_type: LeaveBalanceEntryType,
+    /// Signed number of days added to (positive) or removed from (negative) the balance.
+    pub days: f64,
+    /// Leave request that caused the entry, for consumption and reversal entries.
+    pub leave_request_id: Option<LeaveRequestId>,
+    /// Date from which the entry counts towards the balance.
+    pub effective_date: NaiveDate,
+    /// Free-form note, typically the reason for a manual adjustment.
+    pub note: Option<String>,
+    /// User who recorded the entry, if it was recorded manually.
+    pub created_by: Option<UserId>,
+    /// Creation timestamp for auditing.
+    pub created_at: DateTime<Utc>,
+    /// Date on which the unused part of a grant lapses.
+    pub expires_on: Option<NaiveDate>,
+    /// True for grants booked by the statutory accrual batch.
+    pub statutory_grant: bool,
+    /// Grant an expiration entry belongs to.
+    pub source_entry_id: Option<LeaveBalanceEntryId>,
+}
+
+impl LeaveBalanceEntry {
+    /// Creates a new ledger entry effective on the given date.
+    pub fn new(
+        user_id: UserId,
+        leave_type: LeaveType,
+        entry_type: LeaveBalanceEntryType,
+        days: f64,
+        effective_date: NaiveDate,
+    ) -> Self {
+        Self {
+            id: LeaveBalanceEntryId::new(),
+            user_id,
+            leave_type,
+            entry_type,
+            days,
+            leave_request_id: None,
+            effective_date,
+            note: None,
+            created_by: None,
+            created_at: Utc::now(),
+            expires_on: None,
+            statutory_grant: false,
+            source_entry_id: None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, FromRow)]
+/// Aggregated ledger totals for one user and leave type.
+pub struct LeaveBalanceTotals {
+    pub granted: f64,
+    pub consumed: f64,
+    pub reversed: f64,
+    pub adjusted: f64,
+    pub expired: f64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+/// Balance summary for one leave type, as returned to clients.
+pub struct LeaveBalanceResponse {
+    pub leave_type: LeaveType,
+    /// Total days granted.
+    pub granted: f64,
+    /// Net days used by approved leave (consumption minus reversals).
+    pub used: f64,
+    /// Net manual adjustments.
+    pub adjusted: f64,
+    /// Days that lapsed unused when their grant expired.
+    pub expired: f64,
+    /// Days remaining according to the ledger.
+    pub balance: f64,
+    /// Days requested by leave requests that are still pending.
+    pub pending: f64,
+    /// Days that can still be requested (`balance - pending`).
+    pub available: f64,
+}
+
+impl LeaveBalanceResponse {
+    /// Builds the summary from ledger totals and the days held by pending requests.
+    pub fn from_totals(leave_type: LeaveType, totals: &LeaveBalanceTotals, pending: f64) -> Self {
+        let balance =
+            totals.granted + totals.consumed + totals.reversed + totals.adjusted + totals.expired;
+        Self {
+            leave_type,
+            granted: totals.granted,
+            used: -(totals.consumed + totals.reversed),
+            adjusted: totals.adjusted,
+            expired: -totals.expired,
+            balance,
+            pending,
+            available: balance - pending,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, ToSchema)]
+/// Administrator view of a user's balances together with the ledger history.
+pub struct UserLeaveBalanceResponse {
+    pub user_id: UserId,
+    pub balances: Vec<LeaveBalanceResponse>,
+    pub entries: Vec<LeaveBalanceEntry>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used by administrators to record a grant or manual adjustment.
+#[validate(schema(function = "validate_manual_entry"))]
+pub struct CreateLeaveBalanceEntryPayload {
+    pub leave_type: LeaveType,
+    pub entry_type: LeaveBalanceEntryType,
+    #[validate(range(min = -366.0, max = 366.0))]
+    pub days: f64,
+    /// Defaults to today in the configured time zone.
+    pub effective_date: Option<NaiveDate>,
+    /// Optional expiry date for grants; the unused part lapses on this date.
+    pub expires_on: Option<NaiveDate>,
+    #[validate(length(max = 500))]
+    pub note: Option<String>,
+}
+
+fn validate_manual_entry(
+    payload: &CreateLeaveBalanceEntryPayload,
+) -> Result<(), validator::ValidationError> {
+    if !payload.entry_type.is_manual() {
+        return Err(validator::ValidationError::new("entry_type_not_manual"));
+    }
+    if payload.days == 0.0 || !payload.days.is_finite() {
+        return Err(validator::ValidationError::new("days_must_be_non_zero"));
+    }
+    if payload.entry_type == LeaveBalanceEntryType::Grant && payload.days < 0.0 {
+        return Err(validator::ValidationError::new(
+            "grant_days_must_be_positive",
+        ));
+    }
+    if payload.expires_on.is_some() && payload.entry_type != LeaveBalanceEntryType::Grant {
+        return Err(validator::ValidationError::new("only_grants_can_expire"));
+    }
+    if let (Some(effective), Some(expires)) = (payload.effective_date, payload.expires_on) {
+        if expires <= effective {
+            return Err(validator::ValidationError::new(
+                "expiry_must_follow_effective_date",
+            ));
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn payload(entry_type: LeaveBalanceEntryType, days: f64) -> CreateLeaveBalanceEntryPayload {
+        CreateLeaveBalanceEntryPayload {
+            leave_type: LeaveType::annual(),
+            entry_type,
+            days,
+            effective_date: None,
+            expires_on: None,
+            note: None,
+        }
+    }
+
+    #[test]
+    fn balance_response_combines_totals_and_pending_days() {
+        let totals = LeaveBalanceTotals {
+            granted: 10.0,
+            consumed: -4.0,
+            reversed: 1.0,
+            adjusted: -0.5,
+            expired: -1.0,
+        };
+        let response = LeaveBalanceResponse::from_totals(LeaveType::annual(), &totals, 2.0);
+        assert_eq!(response.used, 3.0);
+        assert_eq!(response.expired, 1.0);
+        assert_eq!(response.balance, 5.5);
+        assert_eq!(response.available, 3.5);
+    }
+
+    #[test]
+    fn manual_entry_validation_rejects_system_entry_types() {
+        assert!(payload(LeaveBalanceEntryType::Grant, 10.0)
+            .validate()
+            .is_ok());
+        assert!(payload(LeaveBalanceEntryType::Adjustment, -1.5)
+            .validate()
+            .is_ok());
+        assert!(payload(LeaveBalanceEntryType::Consumption, -1.0)
+            .validate()
+            .is_err());
+        assert!(payload(LeaveBalanceEntryType::Reversal, 1.0)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn manual_entry_validation_rejects_zero_and_negative_grants() {
+        assert!(payload(LeaveBalanceEntryType::Adjustment, 0.0)
+            .validate()
+            .is_err());
+        assert!(payload(LeaveBalanceEntryType::Grant, -3.0)
+            .validate()
+            .is_err());
+    }
+
+    #[test]
+    fn manual_entry_validation_limits_expiry_to_grants() {
+        let mut grant = payload(LeaveBalanceEntryType::Grant, 5.0);
+        grant.effective_date = NaiveDate::from_ymd_opt(2026, 4, 1);
+        grant.expires_on = NaiveDate::from_ymd_opt(2028, 4, 1);
+        assert!(grant.validate().is_ok());
+        grant.expires_on = grant.effective_date;
+        assert!(grant.validate().is_err());
+
+        let mut adjustment = payload(LeaveBalanceEntryType::Adjustment, 1.0);
+        adjustment.expires_on = NaiveDate::from_ymd_opt(2028, 4, 1);
+        assert!(adjustment.validate().is_err());
+    }
+}
@@ -0,0 +1,51 @@
+//! Models for the five-day mandatory annual leave compliance report.
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::types::{LeaveBalanceEntryId, UserId};
+
+/// Days an employee must take within a year of a qualifying grant.
+pub const MANDATORY_LEAVE_DAYS: f64 = 5.0;
+/// Smallest grant that triggers the mandatory leave obligation.
+pub const MANDATORY_LEAVE_MIN_GRANT_DAYS: f64 = 10.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+/// Progress of an employee towards the mandatory leave days of one grant period.
+pub enum LeaveComplianceStatus {
+    /// Taken and scheduled leave already cover the required days.
+    Compliant,
+    /// Days are still required but the deadline is not close yet.
+    OnTrack,
+    /// Days are still required and the deadline is within the alert window.
+    DueSoon,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+/// One employee's mandatory leave position for a grant period.
+pub struct LeaveComplianceItem {
+    pub user_id: UserId,
+    pub username: String,
+    /// Grant that opened the period.
+    pub grant_entry_id: LeaveBalanceEntryId,
+    pub grant_date: NaiveDate,
+    pub granted_days: f64,
+    /// Last day on which leave counts towards the period.
+    pub deadline: NaiveDate,
+    /// Approved annual leave already taken in the period.
+    pub days_taken: f64,
+    /// Approved annual leave booked for later in the period.
+    pub days_scheduled: f64,
+    /// Days still to be taken or scheduled before the deadline.
+    pub days_remaining: f64,
+    pub days_until_deadline: i64,
+    pub status: LeaveComplianceStatus,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct LeaveComplianceQuery {
+    /// Report date; defaults to today in the configured time zone.
+    pub as_of: Option<NaiveDate>,
+}
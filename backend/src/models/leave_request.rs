@@ -22,6 +22,10 @@ pub struct LeaveRequest {
     pub start_date: NaiveDate,
     /// Last day of the requested leave period.
     pub end_date: NaiveDate,
+    /// Portion of the day taken; partial units cover a single day.
+    pub unit: LeaveUnit,
+    /// Hours taken when `unit` is [`LeaveUnit::Hours`].
+    pub hours: Option<f64>,
     /// Optional user-provided explanation for the leave.
     pub reason: Option<String>,
     /// Current status of the leave request.
@@ -44,31 +48,73 @@ pub struct LeaveRequest {
     pub updated_at: DateTime<Utc>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(transparent)]
+#[serde(transparent)]
+/// Code of a leave category defined in the `leave_types` table.
+pub struct LeaveType(String);
+
+impl LeaveType {
+    /// Code of statutory annual paid leave, which accrual and compliance rules apply to.
+    pub const ANNUAL: &'static str = "annual";
+
+    pub fn new(code: impl Into<String>) -> Self {
+        Self(code.into())
+    }
+
+    /// Statutory annual paid leave.
+    pub fn annual() -> Self {
+        Self::new(Self::ANNUAL)
+    }
+
+    pub fn db_value(&self) -> &str {
+        &self.0
+    }
+
+    /// Returns true for statutory annual paid leave.
+    pub fn is_annual(&self) -> bool {
+        self.0 == Self::ANNUAL
+    }
+}
+
+impl std::fmt::Display for LeaveType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema,
+)]
 #[sqlx(type_name = "TEXT", rename_all = "snake_case")]
 #[serde(rename_all = "snake_case")]
-/// Supported leave categories.
-pub enum LeaveType {
-    /// Planned vacation or personal time off.
-    Annual,
-    /// Sick leave.
-    Sick,
-    /// Personal leave not covered by other categories.
-    Personal,
-    /// Custom leave type stored as free-form text.
-    Other,
+/// Portion of a working day covered by a leave request.
+pub enum LeaveUnit {
+    /// Whole days from `start_date` to `end_date`.
+    #[default]
+    Full,
+    /// Morning half of a single day.
+    HalfAm,
+    /// Afternoon half of a single day.
+    HalfPm,
+    /// A number of hours on a single day.
+    Hours,
 }
 
-impl LeaveType {
-    #[allow(dead_code)]
+impl LeaveUnit {
     pub fn db_value(&self) -> &'static str {
         match self {
-            LeaveType::Annual => "annual",
-            LeaveType::Sick => "sick",
-            LeaveType::Personal => "personal",
-            LeaveType::Other => "other",
+            LeaveUnit::Full => "full",
+            LeaveUnit::HalfAm => "half_am",
+            LeaveUnit::HalfPm => "half_pm",
+            LeaveUnit::Hours => "hours",
         }
     }
+
+    /// Returns true for half-day and hourly units, which cover part of a single day.
+    pub fn is_partial(&self) -> bool {
+        !matches!(self, LeaveUnit::Full)
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema, Validate)]
@@ -78,15 +124,43 @@ pub struct CreateLeaveRequest {
     pub leave_type: LeaveType,
     pub start_date: NaiveDate,
     pub end_date: NaiveDate,
+    /// Defaults to whole days.
+    #[serde(default)]
+    pub unit: LeaveUnit,
+    /// Required for hourly leave, rejected otherwise.
+    pub hours: Option<f64>,
     #[validate(length(max = 500))]
     pub reason: Option<String>,
 }
 
 fn validate_leave_date_range(req: &CreateLeaveRequest) -> Result<(), validator::ValidationError> {
-    if req.start_date > req.end_date {
+    validate_leave_shape(req.start_date, req.end_date, req.unit, req.hours)
+}
+
+/// Checks the date range, unit and hour count of a leave request against each other.
+pub fn validate_leave_shape(
+    start_date: NaiveDate,
+    end_date: NaiveDate,
+    unit: LeaveUnit,
+    hours: Option<f64>,
+) -> Result<(), validator::ValidationError> {
+    if start_date > end_date {
         return Err(validator::ValidationError::new("start_date_after_end_date"));
     }
-    Ok(())
+    if unit.is_partial() && start_date != end_date {
+        return Err(validator::ValidationError::new(
+            "partial_leave_spans_multiple_days",
+        ));
+    }
+    match (unit, hours) {
+        (LeaveUnit::Hours, Some(hours)) if hours > 0.0 && hours < 24.0 => Ok(()),
+        (LeaveUnit::Hours, Some(_)) => {
+            Err(validator::ValidationError::new("leave_hours_out_of_range"))
+        }
+        (LeaveUnit::Hours, None) => Err(validator::ValidationError::new("leave_hours_required")),
+        (_, Some(_)) => Err(validator::ValidationError::new("leave_hours_unexpected")),
+        (_, None) => Ok(()),
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema)]
@@ -97,6 +171,8 @@ pub struct LeaveRequestResponse {
     pub leave_type: LeaveType,
     pub start_date: NaiveDate,
     pub end_date: NaiveDate,
+    pub unit: LeaveUnit,
+    pub hours: Option<f64>,
     pub reason: Option<String>,
     pub status: RequestStatus,
     pub approved_by: Option<UserId>,
@@ -117,6 +193,8 @@ impl From<LeaveRequest> for LeaveRequestResponse {
             leave_type: request.leave_type,
             start_date: request.start_date,
             end_date: request.end_date,
+            unit: request.unit,
+            hours: request.hours,
             reason: request.reason,
             status: request.status,
             approved_by: request.approved_by,
@@ -131,7 +209,7 @@ impl From<LeaveRequest> for LeaveRequestResponse {
 }
 
 impl LeaveRequest {
-    /// Creates a new leave request pending approval.
+    /// Creates a new whole-day leave request pending approval.
     pub fn new(
         user_id: UserId,
         leave_type: LeaveType,
@@ -146,6 +224,8 @@ impl LeaveRequest {
             leave_type,
             start_date,
             end_date,
+            unit: LeaveUnit::Full,
+            hours: None,
             reason,
             status: RequestStatus::Pending,
             approved_by: None,
@@ -159,6 +239,13 @@ impl LeaveRequest {
         }
     }
 
+    /// Sets the portion of the day the request covers.
+    pub fn with_unit(mut self, unit: LeaveUnit, hours: Option<f64>) -> Self {
+        self.unit = unit;
+        self.hours = hours;
+        self
+    }
+
     /// Marks the request as approved and records reviewer details.
     #[allow(dead_code)]
     pub fn approve(&mut self, approved_by: UserId) {
@@ -191,10 +278,11 @@ mod tests {
     fn leave_type_and_status_serde_snake_case() {
         // LeaveType
         let lt: LeaveType = serde_json::from_str("\"annual\"").unwrap();
-        assert!(matches!(lt, LeaveType::Annual));
-        let vlt = serde_json::to_value(LeaveType::Personal).unwrap();
-        assert_eq!(vlt, serde_json::json!("personal"));
-        assert_eq!(LeaveType::Annual.db_value(), "annual");
+        assert_eq!(lt, LeaveType::annual());
+        assert!(lt.is_annual());
+        let vlt = serde_json::to_value(LeaveType::new("bereavement")).unwrap();
+        assert_eq!(vlt, serde_json::json!("bereavement"));
+        assert_eq!(LeaveType::annual().db_value(), "annual");
 
         // RequestStatus
         let rs: RequestStatus = serde_json::from_str("\"rejected\"").unwrap();
@@ -215,7 +303,7 @@ mod tests {
         let admin_id = UserId::new();
         let admin2_id = UserId::new();
 
-        let mut request = LeaveRequest::new(user_id, LeaveType::Annual, start, end, None);
+        let mut request = LeaveRequest::new(user_id, LeaveType::annual(), start, end, None);
         assert!(request.is_pending());
 
         request.approve(admin_id);
@@ -223,10 +311,64 @@ mod tests {
         assert_eq!(request.approved_by, Some(admin_id));
         assert!(request.approved_at.is_some());
 
-        let mut rejected = LeaveRequest::new(user_id, LeaveType::Sick, start, end, None);
+        let mut rejected = LeaveRequest::new(user_id, LeaveType::new("sick"), start, end, None);
         rejected.reject(admin2_id);
         assert!(matches!(rejected.status, RequestStatus::Rejected));
         assert_eq!(rejected.rejected_by, Some(admin2_id));
         assert!(rejected.rejected_at.is_some());
     }
+
+    #[test]
+    fn leave_shape_validation_checks_unit_and_hours() {
+        let day = NaiveDate::from_ymd_opt(2024, 4, 1).unwrap();
+        let next = NaiveDate::from_ymd_opt(2024, 4, 2).unwrap();
+        let code = |result: Result<(), validator::ValidationError>| {
+            result.err().map(|err| err.code.to_string())
+        };
+
+        assert_eq!(
+            code(validate_leave_shape(day, next, LeaveUnit::Full, None)),
+            None
+        );
+        assert_eq!(
+            code(validate_leave_shape(day, day, LeaveUnit::HalfAm, None)),
+            None
+        );
+        assert_eq!(
+            code(validate_leave_shape(day, day, LeaveUnit::Hours, Some(2.0))),
+            None
+        );
+        assert_eq!(
+            code(validate_leave_shape(next, day, LeaveUnit::Full, None)).as_deref(),
+            Some("start_date_after_end_date")
+        );
+        assert_eq!(
+            code(validate_leave_shape(day, next, LeaveUnit::HalfPm, None)).as_deref(),
+            Some("partial_leave_spans_multiple_days")
+        );
+        assert_eq!(
+            code(validate_leave_shape(day, day, LeaveUnit::Hours, None)).as_deref(),
+            Some("leave_hours_required")
+        );
+        assert_eq!(
+            code(validate_leave_shape(day, day, LeaveUnit::Hours, Some(0.0))).as_deref(),
+            Some("leave_hours_out_of_range")
+        );
+        assert_eq!(
+            code(validate_leave_shape(day, day, LeaveUnit::Full, Some(2.0))).as_deref(),
+            Some("leave_hours_unexpected")
+        );
+    }
+
+    #[test]
+    fn leave_unit_defaults_to_full_when_omitted() {
+        let payload: CreateLeaveRequest = serde_json::from_value(serde_json::json!({
+            "leave_type": "annual",
+            "start_date": "2024-04-01",
+            "end_date": "2024-04-01"
+        }))
+        .unwrap();
+        assert_eq!(payload.unit, LeaveUnit::Full);
+        assert_eq!(LeaveUnit::HalfAm.db_value(), "half_am");
+    }
 }
@@ -0,0 +1,81 @@
+//! Models for the administrator-managed catalogue of leave types.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+use validator::Validate;
+
+use crate::models::leave_request::LeaveType;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+/// A leave category and the rules that apply to requests of it.
+pub struct LeaveTypeDefinition {
+    /// Stable identifier stored on requests and ledger entries.
+    pub code: LeaveType,
+    /// Display name.
+    pub name: String,
+    /// Whether the leave is paid.
+    pub paid: bool,
+    /// Whether requests draw down the leave balance ledger.
+    pub balance_tracked: bool,
+    /// Inactive types are kept for history but cannot be requested.
+    pub is_active: bool,
+    /// Position in selection lists, ascending.
+    pub sort_order: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used by system administrators to add a leave type.
+pub struct CreateLeaveTypePayload {
+    /// Lowercase letters, digits and underscores, starting with a letter.
+    #[validate(custom(function = "validate_leave_type_code"))]
+    pub code: String,
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    /// Defaults to true.
+    pub paid: Option<bool>,
+    /// Defaults to false.
+    pub balance_tracked: Option<bool>,
+    /// Defaults to 0.
+    pub sort_order: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used by system administrators to change a leave type; omitted fields are kept.
+pub struct UpdateLeaveTypePayload {
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+    pub paid: Option<bool>,
+    pub balance_tracked: Option<bool>,
+    pub is_active: Option<bool>,
+    pub sort_order: Option<i32>,
+}
+
+fn validate_leave_type_code(code: &str) -> Result<(), validator::ValidationError> {
+    let mut chars = code.chars();
+    let starts_with_letter = chars.next().is_some_and(|c| c.is_ascii_lowercase());
+    let rest_valid = chars.all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_');
+    if !starts_with_letter || !rest_valid || code.len() > 50 {
+        return Err(validator::ValidationError::new("invalid_leave_type_code"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn leave_type_code_must_be_a_lowercase_identifier() {
+        assert!(validate_leave_type_code("summer_vacation").is_ok());
+        assert!(validate_leave_type_code("care2").is_ok());
+        assert!(validate_leave_type_code("").is_err());
+        assert!(validate_leave_type_code("2care").is_err());
+        assert!(validate_leave_type_code("Summer").is_err());
+        assert!(validate_leave_type_code("summer-vacation").is_err());
+        assert!(validate_leave_type_code(&"a".repeat(51)).is_err());
+    }
+}
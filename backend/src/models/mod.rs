@@ -63,18 +63,38 @@ impl<T: Serialize> PaginatedResponse<T> {
     }
 }
 
+pub mod absence;
 pub mod active_session;
 pub mod attendance;
 pub mod attendance_correction_request;
+pub mod attendance_sweep;
 pub mod audit_log;
+pub mod break_policy;
 pub mod break_record;
 pub mod consent_log;
 pub mod department;
+pub mod employment_profile;
+pub mod export_encryption;
+pub mod export_job;
+pub mod export_schedule;
+pub mod export_template;
+pub mod health_interview;
 pub mod holiday;
 pub mod holiday_exception;
+pub mod leave_balance;
+pub mod leave_compliance;
 pub mod leave_request;
+pub mod leave_type;
+pub mod overtime;
+pub mod overtime_limit;
 pub mod overtime_request;
 pub mod password_reset;
+pub mod period_closing;
 pub mod request;
+pub mod rest_day;
+pub mod rest_interval;
 pub mod subject_request;
+pub mod timesheet;
 pub mod user;
+pub mod work_schedule;
+pub mod work_time;
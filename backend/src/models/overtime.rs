@@ -0,0 +1,247 @@
+//! Models for actual overtime and its reconciliation against approved overtime requests.
+
+use chrono::{Datelike, Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::types::UserId;
+
+/// Statutory working hours per day; longer days are legal overtime.
+pub const LEGAL_DAILY_HOURS: f64 = 8.0;
+/// Statutory working hours per week, counted from Sunday.
+pub const LEGAL_WEEKLY_HOURS: f64 = 40.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+/// How a day's actual overtime compares with the overtime approved for it.
+pub enum OvertimeDayStatus {
+    /// No overtime was worked or approved.
+    None,
+    /// Overtime was worked and fully covered by approved requests.
+    Approved,
+    /// More overtime was worked than was approved.
+    Unapproved,
+    /// Approved overtime was not (fully) worked.
+    ApprovedUnused,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+/// Inputs for one calendar day of the overtime calculation.
+pub struct WorkedDay {
+    pub date: NaiveDate,
+    pub worked_hours: f64,
+    /// Hours the schedule expected once approved leave is taken off.
+    pub scheduled_hours: f64,
+    /// Planned hours of approved overtime requests for the day.
+    pub approved_hours: f64,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+/// Actual overtime of one day next to the overtime approved for it.
+pub struct DailyOvertime {
+    pub date: NaiveDate,
+    pub worked_hours: f64,
+    pub scheduled_hours: f64,
+    /// Hours beyond 8 per day or 40 per week.
+    pub legal_overtime_hours: f64,
+    /// Hours beyond the schedule or the legal limits, whichever is more.
+    pub overtime_hours: f64,
+    pub approved_hours: f64,
+    /// Overtime worked without an approved request covering it.
+    pub unapproved_hours: f64,
+    /// Approved overtime that was not worked.
+    pub unused_approved_hours: f64,
+    pub status: OvertimeDayStatus,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+/// One employee's actual and approved overtime for a month.
+pub struct OvertimeReconciliation {
+    pub user_id: UserId,
+    pub username: String,
+    pub year: i32,
+    pub month: u32,
+    pub worked_hours: f64,
+    pub legal_overtime_hours: f64,
+    pub overtime_hours: f64,
+    pub approved_hours: f64,
+    pub unapproved_hours: f64,
+    pub unused_approved_hours: f64,
+    /// Days with work or approved overtime.
+    pub days: Vec<DailyOvertime>,
+}
+
+impl OvertimeReconciliation {
+    /// Totals the given days into a monthly reconciliation.
+    pub fn from_days(
+        user_id: UserId,
+        username: String,
+        year: i32,
+        month: u32,
+        days: Vec<DailyOvertime>,
+    ) -> Self {
+        let total = |field: fn(&DailyOvertime) -> f64| round_hours(days.iter().map(field).sum());
+        Self {
+            user_id,
+            username,
+            year,
+            month,
+            worked_hours: total(|day| day.worked_hours),
+            legal_overtime_hours: total(|day| day.legal_overtime_hours),
+            overtime_hours: total(|day| day.overtime_hours),
+            approved_hours: total(|day| day.approved_hours),
+            unapproved_hours: total(|day| day.unapproved_hours),
+            unused_approved_hours: total(|day| day.unused_approved_hours),
+            days,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct OvertimeReconciliationQuery {
+    /// Defaults to the current year in the configured time zone.
+    pub year: Option<i32>,
+    /// Defaults to the current month in the configured time zone.
+    pub month: Option<u32>,
+    /// Narrows the report to one employee.
+    pub user_id: Option<String>,
+}
+
+/// Sunday on or before `date`, where the statutory week starts.
+pub fn week_start(date: NaiveDate) -> NaiveDate {
+    date - Duration::days(i64::from(date.weekday().num_days_from_sunday()))
+}
+
+/// Computes actual overtime for consecutive calendar days.
+///
+/// `days` must be in date order; weekly hours accumulate from each Sunday, so days before
+/// the reporting period should be included when the period starts mid-week.
+pub fn compute_daily_overtime(days: &[WorkedDay]) -> Vec<DailyOvertime> {
+    let mut current_week = None;
+    let mut weekly_regular_hours = 0.0;
+    days.iter()
+        .map(|day| {
+            let week = week_start(day.date);
+            if current_week != Some(week) {
+                current_week = Some(week);
+                weekly_regular_hours = 0.0;
+            }
+            let worked = day.worked_hours.max(0.0);
+            let regular = worked.min(LEGAL_DAILY_HOURS);
+            let weekly_excess = (weekly_regular_hours + regular - LEGAL_WEEKLY_HOURS).max(0.0)
+                - (weekly_regular_hours - LEGAL_WEEKLY_HOURS).max(0.0);
+            weekly_regular_hours += regular;
+
+            let legal_overtime = round_hours(worked - regular + weekly_excess);
+            let overtime = round_hours((worked - day.scheduled_hours).max(legal_overtime));
+            let approved = round_hours(day.approved_hours.max(0.0));
+            let unapproved = round_hours((overtime - approved).max(0.0));
+            let unused = round_hours((approved - overtime).max(0.0));
+            let status = if unapproved > 0.0 {
+                OvertimeDayStatus::Unapproved
+            } else if unused > 0.0 {
+                OvertimeDayStatus::ApprovedUnused
+            } else if overtime > 0.0 {
+                OvertimeDayStatus::Approved
+            } else {
+                OvertimeDayStatus::None
+            };
+            DailyOvertime {
+                date: day.date,
+                worked_hours: round_hours(worked),
+                scheduled_hours: round_hours(day.scheduled_hours),
+                legal_overtime_hours: legal_overtime,
+                overtime_hours: overtime,
+                approved_hours: approved,
+                unapproved_hours: unapproved,
+                unused_approved_hours: unused,
+                status,
+            }
+        })
+        .collect()
+}
+
+/// Rounds hours to two decimals so minute-based durations compare cleanly.
+fn round_hours(hours: f64) -> f64 {
+    (hours * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(date: &str, worked: f64, scheduled: f64, approved: f64) -> WorkedDay {
+        WorkedDay {
+            date: NaiveDate::parse_from_str(date, "%Y-%m-%d").unwrap(),
+            worked_hours: worked,
+            scheduled_hours: scheduled,
+            approved_hours: approved,
+        }
+    }
+
+    #[test]
+    fn daily_overtime_counts_hours_beyond_schedule_and_legal_limit() {
+        // 2026-03-02 is a Monday.
+        let result = compute_daily_overtime(&[
+            day("2026-03-02", 10.0, 8.0, 2.0),
+            day("2026-03-03", 7.5, 7.0, 0.0),
+            day("2026-03-04", 8.0, 8.0, 1.0),
+        ]);
+
+        assert_eq!(result[0].legal_overtime_hours, 2.0);
+        assert_eq!(result[0].overtime_hours, 2.0);
+        assert_eq!(result[0].status, OvertimeDayStatus::Approved);
+
+        assert_eq!(result[1].legal_overtime_hours, 0.0);
+        assert_eq!(result[1].overtime_hours, 0.5);
+        assert_eq!(result[1].unapproved_hours, 0.5);
+        assert_eq!(result[1].status, OvertimeDayStatus::Unapproved);
+
+        assert_eq!(result[2].overtime_hours, 0.0);
+        assert_eq!(result[2].unused_approved_hours, 1.0);
+        assert_eq!(result[2].status, OvertimeDayStatus::ApprovedUnused);
+    }
+
+    #[test]
+    fn weekly_limit_applies_from_sunday() {
+        // Monday 2026-03-02 through Saturday 2026-03-07, eight hours each.
+        let days: Vec<WorkedDay> = (2..=7)
+            .map(|d| day(&format!("2026-03-{d:02}"), 8.0, 8.0, 0.0))
+            .chain([day("2026-03-08", 8.0, 8.0, 0.0)])
+            .collect();
+        let result = compute_daily_overtime(&days);
+
+        assert!(result[..5]
+            .iter()
+            .all(|day| day.legal_overtime_hours == 0.0));
+        assert_eq!(result[5].legal_overtime_hours, 8.0);
+        assert_eq!(result[5].overtime_hours, 8.0);
+        // Sunday starts a new week.
+        assert_eq!(result[6].legal_overtime_hours, 0.0);
+    }
+
+    #[test]
+    fn reconciliation_totals_days() {
+        let days = compute_daily_overtime(&[
+            day("2026-03-02", 10.0, 8.0, 1.0),
+            day("2026-03-03", 8.0, 8.0, 2.0),
+        ]);
+        let report =
+            OvertimeReconciliation::from_days(UserId::new(), "alice".into(), 2026, 3, days);
+        assert_eq!(report.overtime_hours, 2.0);
+        assert_eq!(report.approved_hours, 3.0);
+        assert_eq!(report.unapproved_hours, 1.0);
+        assert_eq!(report.unused_approved_hours, 2.0);
+    }
+
+    #[test]
+    fn week_start_is_the_preceding_sunday() {
+        let wednesday = NaiveDate::from_ymd_opt(2026, 3, 4).unwrap();
+        assert_eq!(
+            week_start(wednesday),
+            NaiveDate::from_ymd_opt(2026, 3, 1).unwrap()
+        );
+        let sunday = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        assert_eq!(week_start(sunday), sunday);
+    }
+}
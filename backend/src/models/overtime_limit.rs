@@ -0,0 +1,446 @@
+//! Models for Article 36 agreement overtime limits and their monitoring.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::types::{DepartmentId, OvertimeLimitId, UserId};
+
+/// Longest window, in months, over which the multi-month average is checked.
+pub const AVERAGE_WINDOW_MONTHS: u32 = 6;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+/// Overtime limits agreed under Article 36, company-wide or for one department.
+pub struct OvertimeLimit {
+    pub id: OvertimeLimitId,
+    /// Department the limits apply to; company-wide when absent.
+    pub department_id: Option<DepartmentId>,
+    pub monthly_limit_hours: f64,
+    pub annual_limit_hours: f64,
+    /// Whether the special clause allows going beyond the monthly and annual limits.
+    pub special_clause_enabled: bool,
+    pub special_annual_limit_hours: f64,
+    /// Hours a single month must stay below under the special clause.
+    pub special_monthly_cap_hours: f64,
+    /// Highest average over any 2 to 6 consecutive months under the special clause.
+    pub special_average_limit_hours: f64,
+    /// Months per agreement year that may exceed the monthly limit under the special clause.
+    pub special_months_per_year: i32,
+    /// Share of a limit from which employees and managers are warned.
+    pub warning_ratio: f64,
+    /// First month of the agreement year.
+    pub year_start_month: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl OvertimeLimit {
+    /// Statutory limits, used until a company-wide set is saved.
+    pub fn statutory(department_id: Option<DepartmentId>) -> Self {
+        let now = Utc::now();
+        Self {
+            id: OvertimeLimitId::new(),
+            department_id,
+            monthly_limit_hours: 45.0,
+            annual_limit_hours: 360.0,
+            special_clause_enabled: false,
+            special_annual_limit_hours: 720.0,
+            special_monthly_cap_hours: 100.0,
+            special_average_limit_hours: 80.0,
+            special_months_per_year: 6,
+            warning_ratio: 0.8,
+            year_start_month: 4,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    /// Overwrites the limits with the payload's values.
+    pub fn apply(&mut self, payload: &OvertimeLimitPayload) {
+        self.monthly_limit_hours = payload.monthly_limit_hours;
+        self.annual_limit_hours = payload.annual_limit_hours;
+        self.special_clause_enabled = payload.special_clause_enabled;
+        self.special_annual_limit_hours = payload.special_annual_limit_hours;
+        self.special_monthly_cap_hours = payload.special_monthly_cap_hours;
+        self.special_average_limit_hours = payload.special_average_limit_hours;
+        self.special_months_per_year = payload.special_months_per_year;
+        self.warning_ratio = payload.warning_ratio;
+        self.year_start_month = payload.year_start_month;
+    }
+
+    /// First day of the agreement year that contains `month`.
+    pub fn agreement_year_start(&self, month: NaiveDate) -> NaiveDate {
+        let start_month = self.year_start_month.clamp(1, 12) as u32;
+        let year = if month.month() >= start_month {
+            month.year()
+        } else {
+            month.year() - 1
+        };
+        NaiveDate::from_ymd_opt(year, start_month, 1).unwrap_or(month)
+    }
+
+    /// First month whose overtime is needed to evaluate `month`.
+    pub fn window_start(&self, month: NaiveDate) -> NaiveDate {
+        let average_start = month
+            .checked_sub_months(Months::new(AVERAGE_WINDOW_MONTHS - 1))
+            .unwrap_or(month);
+        self.agreement_year_start(month).min(average_start)
+    }
+
+    /// Checks the overtime of `month` and the months before it against these limits.
+    ///
+    /// `monthly_hours` is keyed by the first day of each month; months without an entry
+    /// count as zero.
+    pub fn evaluate(
+        &self,
+        month: NaiveDate,
+        monthly_hours: &HashMap<NaiveDate, f64>,
+    ) -> Vec<OvertimeLimitCheck> {
+        let hours_in = |offset: u32| {
+            month
+                .checked_sub_months(Months::new(offset))
+                .and_then(|m| monthly_hours.get(&m).copied())
+                .unwrap_or(0.0)
+        };
+        let year_start = self.agreement_year_start(month);
+        let months_into_year = (month.year() - year_start.year()) * 12 + month.month() as i32
+            - year_start.month() as i32;
+        let year_to_date: Vec<f64> = (0..=months_into_year as u32).map(hours_in).collect();
+        let annual = round_hours(year_to_date.iter().sum());
+        let current = round_hours(hours_in(0));
+
+        if !self.special_clause_enabled {
+            return vec![
+                self.check(
+                    OvertimeLimitKind::Monthly,
+                    current,
+                    self.monthly_limit_hours,
+                ),
+                self.check(OvertimeLimitKind::Annual, annual, self.annual_limit_hours),
+            ];
+        }
+
+        let months_over = year_to_date
+            .iter()
+            .filter(|hours| **hours > self.monthly_limit_hours)
+            .count();
+        let highest_average = (2..=AVERAGE_WINDOW_MONTHS)
+            .map(|window| (0..window).map(hours_in).sum::<f64>() / f64::from(window))
+            .fold(0.0, f64::max);
+        vec![
+            self.check(
+                OvertimeLimitKind::MonthlyCap,
+                current,
+                self.special_monthly_cap_hours,
+            ),
+            self.check(
+                OvertimeLimitKind::Annual,
+                annual,
+                self.special_annual_limit_hours,
+            ),
+            self.check(
+                OvertimeLimitKind::MultiMonthAverage,
+                round_hours(highest_average),
+                self.special_average_limit_hours,
+            ),
+            self.check(
+                OvertimeLimitKind::MonthsOverMonthlyLimit,
+                months_over as f64,
+                f64::from(self.special_months_per_year),
+            ),
+        ]
+    }
+
+    fn check(&self, kind: OvertimeLimitKind, value: f64, limit: f64) -> OvertimeLimitCheck {
+        // The special-clause monthly cap must be stayed under, not merely not exceeded.
+        let exceeded = match kind {
+            OvertimeLimitKind::MonthlyCap => value >= limit,
+            _ => value > limit,
+        };
+        let level = if exceeded {
+            OvertimeLimitLevel::Exceeded
+        } else if value > 0.0 && value >= limit * self.warning_ratio {
+            OvertimeLimitLevel::Approaching
+        } else {
+            OvertimeLimitLevel::Ok
+        };
+        OvertimeLimitCheck {
+            kind,
+            value,
+            limit,
+            level,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+/// A limit of the Article 36 agreement.
+pub enum OvertimeLimitKind {
+    /// Overtime in the month against the monthly limit (no special clause).
+    Monthly,
+    /// Overtime in the agreement year so far.
+    Annual,
+    /// Overtime in the month against the special-clause cap.
+    MonthlyCap,
+    /// Highest average over the 2 to 6 months ending with the month.
+    MultiMonthAverage,
+    /// Months in the agreement year over the monthly limit.
+    MonthsOverMonthlyLimit,
+}
+
+impl OvertimeLimitKind {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Monthly => "monthly",
+            Self::Annual => "annual",
+            Self::MonthlyCap => "monthly_cap",
+            Self::MultiMonthAverage => "multi_month_average",
+            Self::MonthsOverMonthlyLimit => "months_over_monthly_limit",
+        }
+    }
+
+    /// Label used in warning emails.
+    pub fn label(self) -> &'static str {
+        match self {
+            Self::Monthly => "月間時間外労働",
+            Self::Annual => "年間時間外労働",
+            Self::MonthlyCap => "月間時間外労働(特別条項上限)",
+            Self::MultiMonthAverage => "2〜6か月平均時間外労働",
+            Self::MonthsOverMonthlyLimit => "月45時間超過回数",
+        }
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize, ToSchema,
+)]
+#[serde(rename_all = "snake_case")]
+/// How close a value is to its limit; ordered from best to worst.
+pub enum OvertimeLimitLevel {
+    Ok,
+    /// At or above the warning ratio of the limit.
+    Approaching,
+    /// Beyond the limit.
+    Exceeded,
+}
+
+impl OvertimeLimitLevel {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Ok => "ok",
+            Self::Approaching => "approaching",
+            Self::Exceeded => "exceeded",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, ToSchema)]
+/// One limit checked for one month.
+pub struct OvertimeLimitCheck {
+    pub kind: OvertimeLimitKind,
+    /// Hours, or months for `months_over_monthly_limit`.
+    pub value: f64,
+    pub limit: f64,
+    pub level: OvertimeLimitLevel,
+}
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+/// One employee's position against the overtime limits in a month.
+pub struct OvertimeLimitStatus {
+    pub user_id: UserId,
+    pub username: String,
+    pub year: i32,
+    pub month: u32,
+    pub special_clause_enabled: bool,
+    /// Legal overtime worked in the month.
+    pub monthly_hours: f64,
+    /// Legal overtime worked in the agreement year up to and including the month.
+    pub annual_hours: f64,
+    /// Worst level among the checks.
+    pub level: OvertimeLimitLevel,
+    pub checks: Vec<OvertimeLimitCheck>,
+}
+
+impl OvertimeLimitStatus {
+    /// Evaluates `limit` for one employee and month.
+    pub fn evaluate(
+        user_id: UserId,
+        username: String,
+        month: NaiveDate,
+        limit: &OvertimeLimit,
+        monthly_hours: &HashMap<NaiveDate, f64>,
+    ) -> Self {
+        let checks = limit.evaluate(month, monthly_hours);
+        let year_start = limit.agreement_year_start(month);
+        let annual_hours = monthly_hours
+            .iter()
+            .filter(|(m, _)| **m >= year_start && **m <= month)
+            .map(|(_, hours)| hours)
+            .sum();
+        Self {
+            user_id,
+            username,
+            year: month.year(),
+            month: month.month(),
+            special_clause_enabled: limit.special_clause_enabled,
+            monthly_hours: round_hours(monthly_hours.get(&month).copied().unwrap_or(0.0)),
+            annual_hours: round_hours(annual_hours),
+            level: checks
+                .iter()
+                .map(|check| check.level)
+                .max()
+                .unwrap_or(OvertimeLimitLevel::Ok),
+            checks,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used by system administrators to set overtime limits.
+pub struct OvertimeLimitPayload {
+    #[validate(range(exclusive_min = 0.0, max = 744.0))]
+    pub monthly_limit_hours: f64,
+    #[validate(range(exclusive_min = 0.0, max = 8784.0))]
+    pub annual_limit_hours: f64,
+    pub special_clause_enabled: bool,
+    #[validate(range(exclusive_min = 0.0, max = 8784.0))]
+    pub special_annual_limit_hours: f64,
+    #[validate(range(exclusive_min = 0.0, max = 744.0))]
+    pub special_monthly_cap_hours: f64,
+    #[validate(range(exclusive_min = 0.0, max = 744.0))]
+    pub special_average_limit_hours: f64,
+    #[validate(range(min = 0, max = 12))]
+    pub special_months_per_year: i32,
+    #[validate(range(exclusive_min = 0.0, max = 1.0))]
+    pub warning_ratio: f64,
+    #[validate(range(min = 1, max = 12))]
+    pub year_start_month: i32,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct OvertimeLimitReportQuery {
+    /// Defaults to the current year in the configured time zone.
+    pub year: Option<i32>,
+    /// Defaults to the current month in the configured time zone.
+    pub month: Option<u32>,
+    /// Only list employees at or above this level.
+    pub min_level: Option<OvertimeLimitLevel>,
+}
+
+fn round_hours(hours: f64) -> f64 {
+    (hours * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn month(y: i32, m: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, 1).unwrap()
+    }
+
+    fn hours(entries: &[(NaiveDate, f64)]) -> HashMap<NaiveDate, f64> {
+        entries.iter().copied().collect()
+    }
+
+    fn level_of(checks: &[OvertimeLimitCheck], kind: OvertimeLimitKind) -> OvertimeLimitLevel {
+        checks
+            .iter()
+            .find(|check| check.kind == kind)
+            .expect("check present")
+            .level
+    }
+
+    #[test]
+    fn agreement_year_starts_in_the_configured_month() {
+        let limit = OvertimeLimit::statutory(None);
+        assert_eq!(limit.agreement_year_start(month(2026, 3)), month(2025, 4));
+        assert_eq!(limit.agreement_year_start(month(2026, 4)), month(2026, 4));
+        assert_eq!(limit.window_start(month(2026, 5)), month(2025, 12));
+    }
+
+    #[test]
+    fn standard_limits_warn_then_exceed() {
+        let limit = OvertimeLimit::statutory(None);
+        let checks = limit.evaluate(month(2026, 5), &hours(&[(month(2026, 5), 36.0)]));
+        assert_eq!(
+            level_of(&checks, OvertimeLimitKind::Monthly),
+            OvertimeLimitLevel::Approaching
+        );
+        assert_eq!(
+            level_of(&checks, OvertimeLimitKind::Annual),
+            OvertimeLimitLevel::Ok
+        );
+
+        let checks = limit.evaluate(
+            month(2026, 5),
+            &hours(&[(month(2026, 3), 200.0), (month(2026, 5), 45.5)]),
+        );
+        assert_eq!(
+            level_of(&checks, OvertimeLimitKind::Monthly),
+            OvertimeLimitLevel::Exceeded
+        );
+        // March belongs to the previous agreement year.
+        assert_eq!(
+            level_of(&checks, OvertimeLimitKind::Annual),
+            OvertimeLimitLevel::Ok
+        );
+    }
+
+    #[test]
+    fn special_clause_checks_cap_average_and_month_count() {
+        let mut limit = OvertimeLimit::statutory(None);
+        limit.special_clause_enabled = true;
+
+        let checks = limit.evaluate(
+            month(2026, 6),
+            &hours(&[(month(2026, 5), 90.0), (month(2026, 6), 85.0)]),
+        );
+        assert_eq!(
+            level_of(&checks, OvertimeLimitKind::MonthlyCap),
+            OvertimeLimitLevel::Approaching
+        );
+        assert_eq!(
+            level_of(&checks, OvertimeLimitKind::MultiMonthAverage),
+            OvertimeLimitLevel::Exceeded
+        );
+        assert!(checks
+            .iter()
+            .all(|check| check.kind != OvertimeLimitKind::Monthly));
+
+        let checks = limit.evaluate(month(2026, 6), &hours(&[(month(2026, 6), 100.0)]));
+        assert_eq!(
+            level_of(&checks, OvertimeLimitKind::MonthlyCap),
+            OvertimeLimitLevel::Exceeded
+        );
+
+        let busy: Vec<(NaiveDate, f64)> = (4..=10).map(|m| (month(2026, m), 50.0)).collect();
+        let checks = limit.evaluate(month(2026, 10), &hours(&busy));
+        let count = checks
+            .iter()
+            .find(|check| check.kind == OvertimeLimitKind::MonthsOverMonthlyLimit)
+            .unwrap();
+        assert_eq!(count.value, 7.0);
+        assert_eq!(count.level, OvertimeLimitLevel::Exceeded);
+    }
+
+    #[test]
+    fn status_reports_the_worst_level() {
+        let limit = OvertimeLimit::statutory(None);
+        let status = OvertimeLimitStatus::evaluate(
+            UserId::new(),
+            "alice".into(),
+            month(2026, 5),
+            &limit,
+            &hours(&[(month(2026, 4), 20.0), (month(2026, 5), 50.0)]),
+        );
+        assert_eq!(status.monthly_hours, 50.0);
+        assert_eq!(status.annual_hours, 70.0);
+        assert_eq!(status.level, OvertimeLimitLevel::Exceeded);
+    }
+}
@@ -0,0 +1,81 @@
+//! Models for months closed for payroll, during which attendance and requests are locked.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::types::{DepartmentId, PeriodClosingId, UserId};
+use crate::utils::time::month_start;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+/// A closed month, company-wide or for one department.
+pub struct PeriodClosing {
+    pub id: PeriodClosingId,
+    /// First day of the closed month.
+    pub period_month: NaiveDate,
+    /// Department the closing applies to; company-wide when absent.
+    pub department_id: Option<DepartmentId>,
+    pub closed_by: Option<UserId>,
+    pub closed_at: DateTime<Utc>,
+}
+
+impl PeriodClosing {
+    pub fn new(
+        period_month: NaiveDate,
+        department_id: Option<DepartmentId>,
+        closed_by: UserId,
+        closed_at: DateTime<Utc>,
+    ) -> Self {
+        Self {
+            id: PeriodClosingId::new(),
+            period_month: month_start(period_month),
+            department_id,
+            closed_by: Some(closed_by),
+            closed_at,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used to close a month; omit `department_id` to close it company-wide.
+pub struct ClosePeriodPayload {
+    #[validate(range(min = 2000, max = 9999))]
+    pub year: i32,
+    #[validate(range(min = 1, max = 12))]
+    pub month: u32,
+    pub department_id: Option<String>,
+}
+
+impl ClosePeriodPayload {
+    pub fn period_month(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year, self.month, 1)
+    }
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct PeriodClosingQuery {
+    /// Restricts the list to closings of this year.
+    pub year: Option<i32>,
+    pub department_id: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn closing_is_keyed_by_the_first_of_the_month() {
+        let closing = PeriodClosing::new(
+            NaiveDate::from_ymd_opt(2024, 2, 17).unwrap(),
+            None,
+            UserId::new(),
+            Utc::now(),
+        );
+        assert_eq!(
+            closing.period_month,
+            NaiveDate::from_ymd_opt(2024, 2, 1).unwrap()
+        );
+    }
+}
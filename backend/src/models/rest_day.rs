@@ -0,0 +1,98 @@
+//! Models for weekly rest days and runs of consecutive worked days.
+
+use std::collections::BTreeSet;
+
+use chrono::{Duration, NaiveDate};
+use serde::{Deserialize, Serialize};
+use utoipa::{IntoParams, ToSchema};
+
+use crate::types::{DepartmentId, UserId};
+
+/// Length of the flexible rest day period.
+pub const REST_DAY_PERIOD_DAYS: i64 = 28;
+/// Rest days required within each flexible rest day period.
+pub const REST_DAYS_PER_PERIOD: i64 = 4;
+
+#[derive(Debug, Clone, Serialize, ToSchema)]
+/// A run of worked days longer than the configured limit.
+pub struct ConsecutiveWorkdayStreak {
+    pub user_id: UserId,
+    pub username: String,
+    pub department_id: Option<DepartmentId>,
+    pub start_date: NaiveDate,
+    /// Last worked day of the run, or the end of the report range while it continues.
+    pub end_date: NaiveDate,
+    pub days: i64,
+    /// Days of the run that were weekly or public holidays for the employee, after
+    /// holiday exceptions.
+    pub holidays_worked: i64,
+    /// Days without work in the four weeks ending on `end_date`.
+    pub rest_days_in_four_weeks: i64,
+    /// Whether those four weeks still contain the four rest days the law allows instead
+    /// of one a week.
+    pub four_week_rule_met: bool,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct ConsecutiveWorkdayQuery {
+    /// Defaults to the first day of the current month in the configured time zone.
+    pub from: Option<NaiveDate>,
+    /// Defaults to today in the configured time zone.
+    pub to: Option<NaiveDate>,
+    /// Restricts the report to employees of this department.
+    pub department_id: Option<String>,
+}
+
+/// Runs of consecutive dates in `worked` longer than `limit`, as `(first, last)` pairs.
+pub fn find_streaks(worked: &BTreeSet<NaiveDate>, limit: i64) -> Vec<(NaiveDate, NaiveDate)> {
+    let mut streaks = Vec::new();
+    let mut run: Option<(NaiveDate, NaiveDate)> = None;
+    for &date in worked {
+        run = match run {
+            Some((start, end)) if end + Duration::days(1) == date => Some((start, date)),
+            previous => {
+                streaks.extend(previous);
+                Some((date, date))
+            }
+        };
+    }
+    streaks.extend(run);
+    streaks.retain(|(start, end)| (*end - *start).num_days() + 1 > limit);
+    streaks
+}
+
+/// Days without work in the rest day period ending on `end`.
+pub fn rest_days_before(worked: &BTreeSet<NaiveDate>, end: NaiveDate) -> i64 {
+    let start = end - Duration::days(REST_DAY_PERIOD_DAYS - 1);
+    REST_DAY_PERIOD_DAYS - worked.range(start..=end).count() as i64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, day).unwrap()
+    }
+
+    #[test]
+    fn streaks_longer_than_the_limit_are_found() {
+        // 1-7 worked, 8 off, 9-12 worked, 14-20 worked.
+        let worked: BTreeSet<_> = (1..=7).chain(9..=12).chain(14..=20).map(date).collect();
+
+        assert_eq!(
+            find_streaks(&worked, 6),
+            vec![(date(1), date(7)), (date(14), date(20))]
+        );
+        assert_eq!(find_streaks(&worked, 7), vec![]);
+        assert_eq!(find_streaks(&worked, 3).len(), 3);
+    }
+
+    #[test]
+    fn rest_days_count_the_four_weeks_ending_on_the_day() {
+        let worked: BTreeSet<_> = (1..=26).map(date).collect();
+        // 28 Feb-27 Mar: 26 worked days, so 27 Mar and 28 Feb are the only rest days.
+        assert_eq!(rest_days_before(&worked, date(27)), 2);
+        assert_eq!(rest_days_before(&BTreeSet::new(), date(27)), 28);
+    }
+}
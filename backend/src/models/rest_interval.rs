@@ -0,0 +1,130 @@
+//! Models for the rest required between shifts and the clock-ins that fall short of it.
+
+use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::types::{DepartmentId, UserId};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+/// What happens when an employee clocks in before resting long enough.
+pub enum RestIntervalMode {
+    /// Let the clock-in through, warn the employee and record the violation.
+    Warn,
+    /// Refuse the clock-in and record the attempt.
+    Block,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+/// Minimum rest between one shift's clock-out and the next clock-in.
+pub struct RestIntervalPolicy {
+    pub mode: RestIntervalMode,
+    pub minimum_rest_minutes: i32,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl RestIntervalPolicy {
+    /// Company default: 11 hours of rest, warned about only.
+    pub fn standard() -> Self {
+        Self {
+            mode: RestIntervalMode::Warn,
+            minimum_rest_minutes: 660,
+            updated_at: Utc::now(),
+        }
+    }
+
+    /// Overwrites the policy with the payload's values.
+    pub fn apply(&mut self, payload: &RestIntervalPolicyPayload) {
+        self.mode = payload.mode;
+        self.minimum_rest_minutes = payload.minimum_rest_minutes;
+    }
+
+    /// Checks a clock-in at `clock_in` after the previous shift ended at `previous_clock_out`.
+    ///
+    /// Returns `None` when the rest in between is long enough.
+    pub fn check(
+        &self,
+        previous_clock_out: NaiveDateTime,
+        clock_in: NaiveDateTime,
+    ) -> Option<RestShortfall> {
+        let rest_minutes = (clock_in - previous_clock_out).num_minutes().max(0);
+        let required_rest_minutes = i64::from(self.minimum_rest_minutes);
+        (rest_minutes < required_rest_minutes).then_some(RestShortfall {
+            previous_clock_out,
+            rest_minutes,
+            required_rest_minutes,
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+/// How far the rest before a clock-in falls short of the policy.
+pub struct RestShortfall {
+    pub previous_clock_out: NaiveDateTime,
+    pub rest_minutes: i64,
+    pub required_rest_minutes: i64,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used by system administrators to set the rest interval policy.
+pub struct RestIntervalPolicyPayload {
+    pub mode: RestIntervalMode,
+    #[validate(range(min = 1, max = 2880))]
+    pub minimum_rest_minutes: i32,
+}
+
+#[derive(Debug, Clone, Serialize, FromRow, ToSchema)]
+/// A clock-in that came too soon after the previous clock-out, as listed for managers.
+pub struct RestIntervalViolation {
+    pub user_id: UserId,
+    pub username: String,
+    pub department_id: Option<DepartmentId>,
+    pub date: NaiveDate,
+    pub previous_clock_out: NaiveDateTime,
+    pub clock_in: NaiveDateTime,
+    pub rest_minutes: i32,
+    pub required_rest_minutes: i32,
+    /// Whether the clock-in was refused.
+    pub blocked: bool,
+    pub detected_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct RestIntervalViolationQuery {
+    /// Defaults to the first day of the current month in the configured time zone.
+    pub from: Option<NaiveDate>,
+    /// Defaults to today in the configured time zone.
+    pub to: Option<NaiveDate>,
+    /// Restricts the report to employees of this department.
+    pub department_id: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    #[test]
+    fn check_reports_rest_below_the_minimum() {
+        let policy = RestIntervalPolicy::standard();
+        // Clocked out at 23:00 and back at 08:00: nine hours of rest.
+        let shortfall = policy
+            .check(at("2026-03-02 23:00"), at("2026-03-03 08:00"))
+            .expect("short rest");
+        assert_eq!(shortfall.rest_minutes, 540);
+        assert_eq!(shortfall.required_rest_minutes, 660);
+
+        // Exactly eleven hours is enough.
+        assert_eq!(
+            policy.check(at("2026-03-02 21:00"), at("2026-03-03 08:00")),
+            None
+        );
+    }
+}
@@ -0,0 +1,179 @@
+//! Models for monthly timesheets submitted by employees for manager sign-off.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::types::{TimesheetSubmissionId, UserId};
+use crate::utils::time::month_start;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+pub enum TimesheetStatus {
+    /// Submitted by the employee and waiting for sign-off.
+    Submitted,
+    /// Signed off by a manager.
+    Approved,
+    /// Sent back to the employee with comments.
+    Returned,
+}
+
+impl TimesheetStatus {
+    pub fn db_value(&self) -> &'static str {
+        match self {
+            TimesheetStatus::Submitted => "submitted",
+            TimesheetStatus::Approved => "approved",
+            TimesheetStatus::Returned => "returned",
+        }
+    }
+
+    /// Whether a month in `current` state (`None` for a draft) may move to `next`.
+    pub fn can_transition(current: Option<Self>, next: Self) -> bool {
+        matches!(
+            (current, next),
+            (
+                None | Some(TimesheetStatus::Returned),
+                TimesheetStatus::Submitted
+            ) | (
+                Some(TimesheetStatus::Submitted),
+                TimesheetStatus::Approved | TimesheetStatus::Returned
+            )
+        )
+    }
+}
+
+impl std::str::FromStr for TimesheetStatus {
+    type Err = String;
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "submitted" => Ok(TimesheetStatus::Submitted),
+            "approved" => Ok(TimesheetStatus::Approved),
+            "returned" => Ok(TimesheetStatus::Returned),
+            other => Err(format!("unknown timesheet status: {other}")),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+/// An employee's timesheet for one month.
+pub struct TimesheetSubmission {
+    pub id: TimesheetSubmissionId,
+    pub user_id: UserId,
+    /// First day of the submitted month.
+    pub period_month: NaiveDate,
+    pub status: TimesheetStatus,
+    pub submitted_at: DateTime<Utc>,
+    pub decided_by: Option<UserId>,
+    pub decided_at: Option<DateTime<Utc>>,
+    /// Manager's comment; required when the timesheet is returned.
+    pub decision_comment: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl TimesheetSubmission {
+    pub fn new(user_id: UserId, period_month: NaiveDate, now: DateTime<Utc>) -> Self {
+        Self {
+            id: TimesheetSubmissionId::new(),
+            user_id,
+            period_month: month_start(period_month),
+            status: TimesheetStatus::Submitted,
+            submitted_at: now,
+            decided_by: None,
+            decided_at: None,
+            decision_comment: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+/// A submission listed for approvers, with the employee's username.
+pub struct TimesheetSubmissionListItem {
+    #[serde(flatten)]
+    #[sqlx(flatten)]
+    pub submission: TimesheetSubmission,
+    pub username: String,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+/// Open items that must be resolved before a month can be submitted.
+pub struct TimesheetBlockers {
+    /// Correction requests for the month still waiting for a decision.
+    pub pending_corrections: i64,
+    /// Days in the month clocked in without a clock-out.
+    pub open_clock_ins: i64,
+}
+
+impl TimesheetBlockers {
+    pub fn is_clear(&self) -> bool {
+        self.pending_corrections == 0 && self.open_clock_ins == 0
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+/// Submission state of one month for the signed-in employee.
+pub struct TimesheetMonthStatus {
+    pub period_month: NaiveDate,
+    /// Latest submission; absent while the month is still a draft.
+    pub submission: Option<TimesheetSubmission>,
+    pub blockers: TimesheetBlockers,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate, IntoParams)]
+/// Month to submit, or to read the submission state of.
+pub struct TimesheetMonthPayload {
+    #[validate(range(min = 2000, max = 9999))]
+    pub year: i32,
+    #[validate(range(min = 1, max = 12))]
+    pub month: u32,
+}
+
+impl TimesheetMonthPayload {
+    pub fn period_month(&self) -> Option<NaiveDate> {
+        NaiveDate::from_ymd_opt(self.year, self.month, 1)
+    }
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+pub struct TimesheetDecisionPayload {
+    #[validate(length(max = 500))]
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct TimesheetListQuery {
+    /// `submitted`, `approved` or `returned`.
+    pub status: Option<String>,
+    pub year: Option<i32>,
+    pub month: Option<u32>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn drafts_and_returned_months_can_be_submitted() {
+        use TimesheetStatus::*;
+        assert!(TimesheetStatus::can_transition(None, Submitted));
+        assert!(TimesheetStatus::can_transition(Some(Returned), Submitted));
+        assert!(!TimesheetStatus::can_transition(Some(Submitted), Submitted));
+        assert!(!TimesheetStatus::can_transition(Some(Approved), Submitted));
+    }
+
+    #[test]
+    fn only_submitted_months_can_be_decided() {
+        use TimesheetStatus::*;
+        assert!(TimesheetStatus::can_transition(Some(Submitted), Approved));
+        assert!(TimesheetStatus::can_transition(Some(Submitted), Returned));
+        assert!(!TimesheetStatus::can_transition(None, Approved));
+        assert!(!TimesheetStatus::can_transition(Some(Returned), Approved));
+        assert!(!TimesheetStatus::can_transition(Some(Approved), Returned));
+    }
+}
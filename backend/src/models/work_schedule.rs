@@ -0,0 +1,356 @@
+//! Models for work schedules (shift patterns) and their assignment to users and departments.
+
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::{IntoParams, ToSchema};
+use validator::Validate;
+
+use crate::types::{DepartmentId, UserId, WorkScheduleAssignmentId, WorkScheduleId};
+use crate::utils::time::month_start;
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, sqlx::Type, ToSchema,
+)]
+#[sqlx(type_name = "TEXT", rename_all = "snake_case")]
+#[serde(rename_all = "snake_case")]
+/// How attendance under a schedule is judged.
+pub enum WorkRuleKind {
+    /// Fixed hours: late arrivals and short days are judged per day.
+    #[default]
+    Fixed,
+    /// Flextime: hours are settled against the required total of a settlement period, and
+    /// only core-time presence is judged per day.
+    Flextime,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+/// Planned working hours that attendance is measured against.
+pub struct WorkSchedule {
+    pub id: WorkScheduleId,
+    pub name: String,
+    /// Scheduled start of the working day, local time.
+    pub start_time: NaiveTime,
+    /// Scheduled end of the working day, local time; on the following day when it is
+    /// earlier than `start_time`.
+    pub end_time: NaiveTime,
+    /// Break allowance deducted from the scheduled span.
+    pub break_minutes: i32,
+    /// Working days, 0 = Sunday through 6 = Saturday.
+    pub working_weekdays: Vec<i16>,
+    /// Minutes after `start_time` before a clock-in counts as late.
+    pub late_grace_minutes: i32,
+    pub kind: WorkRuleKind,
+    /// Start of the flextime core time, local time; no core time when absent.
+    pub core_start_time: Option<NaiveTime>,
+    /// End of the flextime core time, local time.
+    pub core_end_time: Option<NaiveTime>,
+    /// Length of the flextime settlement period, 1 to 3 months.
+    pub settlement_months: i32,
+    /// Month from which settlement periods are counted.
+    pub settlement_start_month: i32,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl WorkSchedule {
+    /// Returns true if `date` falls on one of the schedule's working weekdays.
+    pub fn works_on(&self, date: NaiveDate) -> bool {
+        let weekday = date.weekday().num_days_from_sunday() as i16;
+        self.working_weekdays.contains(&weekday)
+    }
+
+    /// Returns true for overnight shifts that end on the day after they start.
+    pub fn crosses_midnight(&self) -> bool {
+        self.end_time <= self.start_time
+    }
+
+    /// Minutes from the scheduled start to the scheduled end, breaks included.
+    pub fn span_minutes(&self) -> i64 {
+        (self.end_time - self.start_time)
+            .num_minutes()
+            .rem_euclid(MINUTES_PER_DAY)
+    }
+
+    /// Scheduled working hours of one day, net of the break allowance.
+    pub fn scheduled_hours(&self) -> f64 {
+        (self.span_minutes() - i64::from(self.break_minutes)).max(0) as f64 / 60.0
+    }
+
+    /// Scheduled end of the shift that starts on `date`.
+    pub fn shift_end(&self, date: NaiveDate) -> NaiveDateTime {
+        date.and_time(self.start_time) + Duration::minutes(self.span_minutes())
+    }
+
+    /// Latest clock-in on `date` that still counts as on time.
+    pub fn late_after(&self, date: NaiveDate) -> NaiveDateTime {
+        date.and_time(self.start_time) + Duration::minutes(i64::from(self.late_grace_minutes))
+    }
+
+    pub fn is_flextime(&self) -> bool {
+        self.kind == WorkRuleKind::Flextime
+    }
+
+    /// First and last day of the settlement period that contains `date`.
+    pub fn settlement_period(&self, date: NaiveDate) -> (NaiveDate, NaiveDate) {
+        let length = self.settlement_months.clamp(1, 3);
+        let start_month = self.settlement_start_month.clamp(1, 12);
+        let months_since_start =
+            (date.year() * 12 + date.month0() as i32 - (start_month - 1)).rem_euclid(length);
+        let first_of_month = month_start(date);
+        let start = first_of_month
+            .checked_sub_months(Months::new(months_since_start as u32))
+            .unwrap_or(first_of_month);
+        let end = start
+            .checked_add_months(Months::new(length as u32))
+            .and_then(|next| next.pred_opt())
+            .unwrap_or(date);
+        (start, end)
+    }
+
+    /// Returns true if a shift on `date` from `clock_in` to `clock_out` does not cover the
+    /// whole core time. Schedules without core time never miss it.
+    pub fn misses_core_time(
+        &self,
+        date: NaiveDate,
+        clock_in: NaiveDateTime,
+        clock_out: NaiveDateTime,
+    ) -> bool {
+        match (self.core_start_time, self.core_end_time) {
+            (Some(core_start), Some(core_end)) => {
+                clock_in > date.and_time(core_start) || clock_out < date.and_time(core_end)
+            }
+            _ => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+/// What a user's schedule and approved leave expect of one working day.
+pub struct DayExpectation {
+    /// Clock-ins after this moment are late; `None` when lateness is not judged.
+    pub late_after: Option<NaiveDateTime>,
+    /// Hours the user is expected to work once approved leave is taken off.
+    pub expected_hours: f64,
+    /// Whether the day falls under flextime, where short days are settled over the period.
+    pub flextime: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+/// Running flextime balance of a settlement period.
+pub struct FlextimeBalance {
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    /// Last day counted into the balance.
+    pub as_of: NaiveDate,
+    /// Hours required over the whole period, net of holidays and approved leave.
+    pub period_required_hours: f64,
+    /// Hours required from the start of the period through `as_of`.
+    pub required_hours_to_date: f64,
+    /// Hours worked from the start of the period through `as_of`.
+    pub worked_hours: f64,
+    /// Worked minus required hours to date; negative values are a deficit.
+    pub balance_hours: f64,
+    /// Working days through `as_of` whose shift did not cover the core time.
+    pub core_time_missed_dates: Vec<NaiveDate>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow, ToSchema)]
+/// Applies a schedule to one user or one department for a date range.
+pub struct WorkScheduleAssignment {
+    pub id: WorkScheduleAssignmentId,
+    pub schedule_id: WorkScheduleId,
+    pub user_id: Option<UserId>,
+    pub department_id: Option<DepartmentId>,
+    pub effective_from: NaiveDate,
+    /// Last day the assignment applies; open-ended when absent.
+    pub effective_to: Option<NaiveDate>,
+    pub created_at: DateTime<Utc>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used by system administrators to define a work schedule.
+pub struct CreateWorkSchedulePayload {
+    #[validate(length(min = 1, max = 100))]
+    pub name: String,
+    pub start_time: NaiveTime,
+    pub end_time: NaiveTime,
+    /// Defaults to 60.
+    #[validate(range(min = 0, max = 1440))]
+    pub break_minutes: Option<i32>,
+    /// Defaults to Monday through Friday.
+    #[validate(custom(function = "validate_weekdays"))]
+    pub working_weekdays: Option<Vec<i16>>,
+    /// Defaults to 0.
+    #[validate(range(min = 0, max = 240))]
+    pub late_grace_minutes: Option<i32>,
+    /// Defaults to `fixed`.
+    pub kind: Option<WorkRuleKind>,
+    /// Set both core-time bounds or neither.
+    pub core_start_time: Option<NaiveTime>,
+    pub core_end_time: Option<NaiveTime>,
+    /// Defaults to 1.
+    #[validate(range(min = 1, max = 3))]
+    pub settlement_months: Option<i32>,
+    /// Defaults to 1 (January).
+    #[validate(range(min = 1, max = 12))]
+    pub settlement_start_month: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema, Validate)]
+/// Payload used by system administrators to change a work schedule; omitted fields are kept.
+pub struct UpdateWorkSchedulePayload {
+    #[validate(length(min = 1, max = 100))]
+    pub name: Option<String>,
+    pub start_time: Option<NaiveTime>,
+    pub end_time: Option<NaiveTime>,
+    #[validate(range(min = 0, max = 1440))]
+    pub break_minutes: Option<i32>,
+    #[validate(custom(function = "validate_weekdays"))]
+    pub working_weekdays: Option<Vec<i16>>,
+    #[validate(range(min = 0, max = 240))]
+    pub late_grace_minutes: Option<i32>,
+    pub kind: Option<WorkRuleKind>,
+    /// Replaces the core time when set; use `clear_core_time` to remove it.
+    pub core_start_time: Option<NaiveTime>,
+    pub core_end_time: Option<NaiveTime>,
+    #[serde(default)]
+    pub clear_core_time: bool,
+    #[validate(range(min = 1, max = 3))]
+    pub settlement_months: Option<i32>,
+    #[validate(range(min = 1, max = 12))]
+    pub settlement_start_month: Option<i32>,
+}
+
+#[derive(Debug, Deserialize, Serialize, ToSchema)]
+/// Payload used by system administrators to assign a schedule to a user or a department.
+pub struct CreateWorkScheduleAssignmentPayload {
+    pub schedule_id: String,
+    /// Exactly one of `user_id` and `department_id` must be set.
+    pub user_id: Option<String>,
+    pub department_id: Option<String>,
+    pub effective_from: NaiveDate,
+    pub effective_to: Option<NaiveDate>,
+}
+
+#[derive(Debug, Deserialize, IntoParams, ToSchema)]
+pub struct WorkScheduleAssignmentQuery {
+    pub user_id: Option<String>,
+    pub department_id: Option<String>,
+}
+
+fn validate_weekdays(weekdays: &[i16]) -> Result<(), validator::ValidationError> {
+    if weekdays.iter().any(|day| !(0..=6).contains(day)) {
+        return Err(validator::ValidationError::new("invalid_weekday"));
+    }
+    Ok(())
+}
+
+/// Sorts and deduplicates weekday numbers.
+pub fn normalize_weekdays(mut weekdays: Vec<i16>) -> Vec<i16> {
+    weekdays.sort_unstable();
+    weekdays.dedup();
+    weekdays
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule() -> WorkSchedule {
+        WorkSchedule {
+            id: WorkScheduleId::new(),
+            name: "Day shift".into(),
+            start_time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+            break_minutes: 60,
+            working_weekdays: vec![1, 2, 3, 4, 5],
+            late_grace_minutes: 10,
+            kind: WorkRuleKind::Fixed,
+            core_start_time: None,
+            core_end_time: None,
+            settlement_months: 1,
+            settlement_start_month: 1,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn schedule_derives_hours_weekdays_and_lateness() {
+        let schedule = schedule();
+        assert_eq!(schedule.scheduled_hours(), 8.0);
+
+        // 2026-02-02 is a Monday, 2026-02-01 a Sunday.
+        let monday = NaiveDate::from_ymd_opt(2026, 2, 2).unwrap();
+        let sunday = NaiveDate::from_ymd_opt(2026, 2, 1).unwrap();
+        assert!(schedule.works_on(monday));
+        assert!(!schedule.works_on(sunday));
+        assert_eq!(
+            schedule.late_after(monday),
+            monday.and_hms_opt(9, 10, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn overnight_schedule_ends_on_the_next_day() {
+        let mut schedule = schedule();
+        schedule.start_time = NaiveTime::from_hms_opt(22, 0, 0).unwrap();
+        schedule.end_time = NaiveTime::from_hms_opt(6, 0, 0).unwrap();
+
+        let monday = NaiveDate::from_ymd_opt(2026, 2, 2).unwrap();
+        assert!(schedule.crosses_midnight());
+        assert_eq!(schedule.scheduled_hours(), 7.0);
+        assert_eq!(
+            schedule.shift_end(monday),
+            monday.succ_opt().unwrap().and_hms_opt(6, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn settlement_periods_follow_length_and_start_month() {
+        let mut schedule = schedule();
+        schedule.kind = WorkRuleKind::Flextime;
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(
+            schedule.settlement_period(date(2026, 2, 14)),
+            (date(2026, 2, 1), date(2026, 2, 28))
+        );
+
+        // Quarterly periods counted from April: Jan-Mar, Apr-Jun, ...
+        schedule.settlement_months = 3;
+        schedule.settlement_start_month = 4;
+        assert_eq!(
+            schedule.settlement_period(date(2026, 2, 14)),
+            (date(2026, 1, 1), date(2026, 3, 31))
+        );
+        assert_eq!(
+            schedule.settlement_period(date(2026, 6, 30)),
+            (date(2026, 4, 1), date(2026, 6, 30))
+        );
+    }
+
+    #[test]
+    fn core_time_must_be_covered() {
+        let mut schedule = schedule();
+        let monday = NaiveDate::from_ymd_opt(2026, 2, 2).unwrap();
+        let at = |h, m| monday.and_hms_opt(h, m, 0).unwrap();
+        assert!(!schedule.misses_core_time(monday, at(11, 0), at(14, 0)));
+
+        schedule.core_start_time = NaiveTime::from_hms_opt(10, 0, 0);
+        schedule.core_end_time = NaiveTime::from_hms_opt(15, 0, 0);
+        assert!(!schedule.misses_core_time(monday, at(10, 0), at(15, 0)));
+        assert!(schedule.misses_core_time(monday, at(10, 30), at(18, 0)));
+        assert!(schedule.misses_core_time(monday, at(8, 0), at(14, 0)));
+    }
+
+    #[test]
+    fn weekdays_are_validated_and_normalized() {
+        assert!(validate_weekdays(&[0, 6]).is_ok());
+        assert!(validate_weekdays(&[7]).is_err());
+        assert_eq!(normalize_weekdays(vec![5, 1, 3, 1]), vec![1, 3, 5]);
+    }
+}
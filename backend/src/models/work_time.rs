@@ -0,0 +1,243 @@
+//! Models that split worked time into the buckets premium pay is calculated from.
+
+use chrono::{Duration, NaiveDate, NaiveDateTime, NaiveTime};
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+use utoipa::ToSchema;
+
+use crate::models::overtime::{week_start, LEGAL_DAILY_HOURS, LEGAL_WEEKLY_HOURS};
+use crate::types::AttendanceId;
+
+/// Late-night work runs from 22:00 until 05:00 the next morning.
+pub const LATE_NIGHT_START_HOUR: u32 = 22;
+pub const LATE_NIGHT_END_HOUR: u32 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+/// How a calendar day counts for premium pay.
+pub enum DayKind {
+    #[default]
+    Workday,
+    /// The weekly rest day required by law.
+    LegalHoliday,
+    /// Any other holiday; work on it is paid like a working day.
+    NonStatutoryHoliday,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+/// A completed shift to classify.
+pub struct ClassifiedShift {
+    pub attendance_id: AttendanceId,
+    pub date: NaiveDate,
+    pub kind: DayKind,
+    pub clock_in: NaiveDateTime,
+    pub clock_out: NaiveDateTime,
+    /// Completed breaks as start and end.
+    pub breaks: Vec<(NaiveDateTime, NaiveDateTime)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize, FromRow, ToSchema)]
+/// Worked hours of a day, or of a period, split by premium.
+///
+/// Regular, overtime and both holiday buckets add up to the worked hours; late-night hours
+/// overlap them.
+pub struct WorkTimeBreakdown {
+    /// Hours within 8 per day and 40 per week on working days.
+    pub regular_hours: f64,
+    /// Hours beyond 8 per day or 40 per week, except on the legal holiday.
+    pub statutory_overtime_hours: f64,
+    /// Hours worked between 22:00 and 05:00.
+    pub late_night_hours: f64,
+    /// Hours worked on the legal holiday.
+    pub legal_holiday_hours: f64,
+    /// Hours within the statutory limits worked on other holidays.
+    pub non_statutory_holiday_hours: f64,
+}
+
+/// Classifies shifts in date order.
+///
+/// Weekly hours accumulate from each Sunday, so a shift is only classified correctly when
+/// the earlier shifts of its week are passed too. Legal-holiday work neither counts towards
+/// nor is limited by the statutory hours.
+pub fn classify_shifts(shifts: &[ClassifiedShift]) -> Vec<WorkTimeBreakdown> {
+    let mut current_week = None;
+    let mut weekly_regular_hours = 0.0;
+    shifts
+        .iter()
+        .map(|shift| {
+            let week = week_start(shift.date);
+            if current_week != Some(week) {
+                current_week = Some(week);
+                weekly_regular_hours = 0.0;
+            }
+            let worked = minutes_to_hours(worked_minutes(shift));
+            let late_night_hours = minutes_to_hours(late_night_minutes(shift));
+            if shift.kind == DayKind::LegalHoliday {
+                return WorkTimeBreakdown {
+                    late_night_hours,
+                    legal_holiday_hours: worked,
+                    ..WorkTimeBreakdown::default()
+                };
+            }
+
+            let within_day = worked.min(LEGAL_DAILY_HOURS);
+            let within_week = within_day.min((LEGAL_WEEKLY_HOURS - weekly_regular_hours).max(0.0));
+            weekly_regular_hours += within_day;
+            let overtime = round_hours(worked - within_week);
+            let within_week = round_hours(within_week);
+            let (regular_hours, non_statutory_holiday_hours) = match shift.kind {
+                DayKind::NonStatutoryHoliday => (0.0, within_week),
+                _ => (within_week, 0.0),
+            };
+            WorkTimeBreakdown {
+                regular_hours,
+                statutory_overtime_hours: overtime,
+                late_night_hours,
+                legal_holiday_hours: 0.0,
+                non_statutory_holiday_hours,
+            }
+        })
+        .collect()
+}
+
+/// Minutes between clock-in and clock-out less breaks, the same way total hours are derived.
+fn worked_minutes(shift: &ClassifiedShift) -> i64 {
+    let gross = (shift.clock_out - shift.clock_in).num_minutes().max(0);
+    let breaks: i64 = shift
+        .breaks
+        .iter()
+        .map(|(start, end)| (*end - *start).num_minutes().max(0))
+        .sum();
+    (gross - breaks).max(0)
+}
+
+/// Worked minutes that fall into a late-night window.
+fn late_night_minutes(shift: &ClassifiedShift) -> i64 {
+    let (Some(start), Some(end)) = (
+        NaiveTime::from_hms_opt(LATE_NIGHT_START_HOUR, 0, 0),
+        NaiveTime::from_hms_opt(LATE_NIGHT_END_HOUR, 0, 0),
+    ) else {
+        return 0;
+    };
+    let mut minutes = 0;
+    let mut day = shift.clock_in.date() - Duration::days(1);
+    while day <= shift.clock_out.date() {
+        let window_start = day.and_time(start);
+        let window_end = (day + Duration::days(1)).and_time(end);
+        minutes += overlap_minutes(shift.clock_in, shift.clock_out, window_start, window_end);
+        for (break_start, break_end) in &shift.breaks {
+            minutes -= overlap_minutes(*break_start, *break_end, window_start, window_end);
+        }
+        day += Duration::days(1);
+    }
+    minutes.max(0)
+}
+
+fn overlap_minutes(
+    start: NaiveDateTime,
+    end: NaiveDateTime,
+    window_start: NaiveDateTime,
+    window_end: NaiveDateTime,
+) -> i64 {
+    (end.min(window_end) - start.max(window_start))
+        .num_minutes()
+        .max(0)
+}
+
+fn minutes_to_hours(minutes: i64) -> f64 {
+    round_hours(minutes as f64 / 60.0)
+}
+
+fn round_hours(hours: f64) -> f64 {
+    (hours * 100.0).round() / 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(value: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").unwrap()
+    }
+
+    fn shift(clock_in: &str, clock_out: &str, kind: DayKind) -> ClassifiedShift {
+        let clock_in = at(clock_in);
+        ClassifiedShift {
+            attendance_id: AttendanceId::new(),
+            date: clock_in.date(),
+            kind,
+            clock_in,
+            clock_out: at(clock_out),
+            breaks: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn long_day_splits_into_regular_overtime_and_late_night() {
+        // Monday 2026-03-02, 13:00 to 23:30 with an hour's break.
+        let mut day = shift("2026-03-02 13:00", "2026-03-02 23:30", DayKind::Workday);
+        day.breaks
+            .push((at("2026-03-02 18:00"), at("2026-03-02 19:00")));
+
+        let result = classify_shifts(&[day]);
+        assert_eq!(result[0].regular_hours, 8.0);
+        assert_eq!(result[0].statutory_overtime_hours, 1.5);
+        assert_eq!(result[0].late_night_hours, 1.5);
+        assert_eq!(result[0].legal_holiday_hours, 0.0);
+    }
+
+    #[test]
+    fn late_night_spans_midnight_and_excludes_breaks() {
+        let mut night = shift("2026-03-02 21:00", "2026-03-03 06:00", DayKind::Workday);
+        night
+            .breaks
+            .push((at("2026-03-03 01:00"), at("2026-03-03 02:00")));
+
+        let result = classify_shifts(&[night]);
+        assert_eq!(result[0].late_night_hours, 6.0);
+        assert_eq!(result[0].regular_hours, 8.0);
+        assert_eq!(result[0].statutory_overtime_hours, 0.0);
+    }
+
+    #[test]
+    fn holidays_are_split_by_kind() {
+        // Sunday 2026-03-01 is the legal holiday, Saturday 2026-03-07 a non-statutory one.
+        let days = vec![
+            shift(
+                "2026-03-01 09:00",
+                "2026-03-01 19:00",
+                DayKind::LegalHoliday,
+            ),
+            shift(
+                "2026-03-07 09:00",
+                "2026-03-07 19:00",
+                DayKind::NonStatutoryHoliday,
+            ),
+        ];
+
+        let result = classify_shifts(&days);
+        assert_eq!(result[0].legal_holiday_hours, 10.0);
+        assert_eq!(result[0].statutory_overtime_hours, 0.0);
+        assert_eq!(result[1].non_statutory_holiday_hours, 8.0);
+        assert_eq!(result[1].statutory_overtime_hours, 2.0);
+        assert_eq!(result[1].regular_hours, 0.0);
+    }
+
+    #[test]
+    fn weekly_hours_beyond_forty_are_overtime() {
+        // Monday 2026-03-02 through Saturday 2026-03-07, eight hours each.
+        let days: Vec<ClassifiedShift> = (2..=7)
+            .map(|d| {
+                shift(
+                    &format!("2026-03-{d:02} 09:00"),
+                    &format!("2026-03-{d:02} 17:00"),
+                    DayKind::Workday,
+                )
+            })
+            .collect();
+
+        let result = classify_shifts(&days);
+        assert!(result[..5].iter().all(|day| day.regular_hours == 8.0));
+        assert_eq!(result[5].regular_hours, 0.0);
+        assert_eq!(result[5].statutory_overtime_hours, 8.0);
+    }
+}
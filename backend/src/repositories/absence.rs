@@ -0,0 +1,65 @@
+//! Repository functions for the nightly absence detection.
+
+use chrono::NaiveDate;
+use sqlx::{FromRow, PgPool};
+
+use crate::models::attendance::AttendanceStatus;
+use crate::types::{AttendanceId, LeaveRequestId, UserId};
+
+/// An employee with no attendance record on a day.
+#[derive(Debug, Clone, FromRow)]
+pub struct UnattendedUser {
+    pub user_id: UserId,
+    /// Approved leave request covering the day, if any.
+    pub leave_request_id: Option<LeaveRequestId>,
+}
+
+/// Employees already hired on `date` who have no attendance record for it.
+#[allow(dead_code)]
+pub async fn list_unattended_users(
+    pool: &PgPool,
+    date: NaiveDate,
+) -> Result<Vec<UnattendedUser>, sqlx::Error> {
+    sqlx::query_as::<_, UnattendedUser>(
+        "SELECT u.id AS user_id, \
+                (SELECT l.id FROM leave_requests l \
+                  WHERE l.user_id = u.id AND l.status = 'approved' \
+                    AND $1 BETWEEN l.start_date AND l.end_date \
+                  ORDER BY l.start_date, l.id \
+                  LIMIT 1) AS leave_request_id \
+         FROM users u \
+         LEFT JOIN user_employment_profiles p ON p.user_id = u.id \
+         WHERE COALESCE(p.hire_date, u.created_at::DATE) <= $1 \
+           AND NOT EXISTS ( \
+                 SELECT 1 FROM attendance a WHERE a.user_id = u.id AND a.date = $1 \
+             ) \
+         ORDER BY u.id",
+    )
+    .bind(date)
+    .fetch_all(pool)
+    .await
+}
+
+/// Records a day without a clock-in; returns zero if the day already has a record.
+#[allow(dead_code)]
+pub async fn insert_unattended_day(
+    pool: &PgPool,
+    user_id: UserId,
+    date: NaiveDate,
+    status: AttendanceStatus,
+    leave_request_id: Option<LeaveRequestId>,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        "INSERT INTO attendance (id, user_id, date, status, leave_request_id, created_at, updated_at) \
+         VALUES ($1, $2, $3, $4, $5, NOW(), NOW()) \
+         ON CONFLICT (user_id, date) DO NOTHING",
+    )
+    .bind(AttendanceId::new())
+    .bind(user_id)
+    .bind(date)
+    .bind(status.db_value())
+    .bind(leave_request_id)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
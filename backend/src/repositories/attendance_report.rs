@@ -0,0 +1,62 @@
+//! Queries backing the monthly attendance book.
+
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::models::leave_request::LeaveUnit;
+use crate::types::UserId;
+
+/// Approved leave overlapping a report month, with the display name of its leave type.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ApprovedLeaveRow {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub unit: LeaveUnit,
+    pub hours: Option<f64>,
+    pub leave_name: String,
+}
+
+/// Lists the user's approved leave that overlaps `from..=to`.
+pub async fn list_approved_leave(
+    pool: &PgPool,
+    user_id: UserId,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<ApprovedLeaveRow>, sqlx::Error> {
+    sqlx::query_as::<_, ApprovedLeaveRow>(
+        "SELECT lr.start_date, lr.end_date, lr.unit, lr.hours, \
+         COALESCE(lt.name, lr.leave_type) AS leave_name \
+         FROM leave_requests lr \
+         LEFT JOIN leave_types lt ON lt.code = lr.leave_type \
+         WHERE lr.user_id = $1 AND lr.status = 'approved' \
+           AND lr.start_date <= $3 AND lr.end_date >= $2 \
+         ORDER BY lr.start_date, lr.created_at",
+    )
+    .bind(user_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await
+}
+
+/// Member of a department listed in a bulk report, with the full name still encrypted.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct DepartmentMemberRow {
+    pub id: UserId,
+    pub username: String,
+    pub full_name: String,
+}
+
+/// Lists the users assigned directly to the department, ordered by username.
+pub async fn list_department_members(
+    pool: &PgPool,
+    department_id: &str,
+) -> Result<Vec<DepartmentMemberRow>, sqlx::Error> {
+    sqlx::query_as::<_, DepartmentMemberRow>(
+        "SELECT id, username, COALESCE(full_name_enc, '') AS full_name \
+         FROM users WHERE department_id = $1 ORDER BY username",
+    )
+    .bind(department_id)
+    .fetch_all(pool)
+    .await
+}
@@ -320,6 +320,30 @@ impl AttendanceRepositoryTrait for AttendanceRepository {
 }
 
 impl AttendanceRepository {
+    /// Latest record on or after `since` that has a clock-in but no clock-out yet.
+    pub async fn find_open_by_user(
+        &self,
+        db: &PgPool,
+        user_id: UserId,
+        since: NaiveDate,
+    ) -> Result<Option<Attendance>, AppError> {
+        const SELECT_COLUMNS: &str =
+            "id, user_id, date, clock_in_time, clock_out_time, status, total_work_hours, created_at, updated_at";
+        let query = format!(
+            "SELECT {} FROM attendance \
+             WHERE user_id = $1 AND date >= $2 \
+               AND clock_in_time IS NOT NULL AND clock_out_time IS NULL \
+             ORDER BY date DESC LIMIT 1",
+            SELECT_COLUMNS
+        );
+        let row = sqlx::query_as::<_, Attendance>(&query)
+            .bind(user_id)
+            .bind(since)
+            .fetch_optional(db)
+            .await?;
+        Ok(row)
+    }
+
     pub async fn delete_by_user_and_date(
         &self,
         tx: &mut PgTransaction<'_>,
@@ -334,6 +358,28 @@ impl AttendanceRepository {
         Ok(())
     }
 
+    pub async fn update_in_transaction(
+        &self,
+        tx: &mut PgTransaction<'_>,
+        item: &Attendance,
+    ) -> Result<(), AppError> {
+        sqlx::query(
+            "UPDATE attendance SET user_id = $2, date = $3, clock_in_time = $4, clock_out_time = $5, \
+             status = $6, total_work_hours = $7, updated_at = $8 WHERE id = $1",
+        )
+        .bind(item.id)
+        .bind(item.user_id)
+        .bind(item.date)
+        .bind(item.clock_in_time)
+        .bind(item.clock_out_time)
+        .bind(item.status.db_value())
+        .bind(item.total_work_hours)
+        .bind(item.updated_at)
+        .execute(tx.as_mut())
+        .await?;
+        Ok(())
+    }
+
     pub async fn create_in_transaction(
         &self,
         tx: &mut PgTransaction<'_>,
@@ -0,0 +1,88 @@
+//! Repository functions for the forgotten clock-out sweep.
+
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::PgPool;
+
+use crate::models::attendance::Attendance;
+use crate::models::attendance_sweep::{OpenRecordSweep, OpenRecordSweepAction};
+use crate::types::{AttendanceId, UserId};
+
+/// Open records dated on or before `until` that no sweep has handled yet.
+#[allow(dead_code)]
+pub async fn list_unswept_open_records(
+    pool: &PgPool,
+    until: NaiveDate,
+) -> Result<Vec<Attendance>, sqlx::Error> {
+    sqlx::query_as::<_, Attendance>(
+        "SELECT a.id, a.user_id, a.date, a.clock_in_time, a.clock_out_time, a.status, \
+                a.total_work_hours, a.created_at, a.updated_at \
+         FROM attendance a \
+         WHERE a.date <= $1 AND a.clock_in_time IS NOT NULL AND a.clock_out_time IS NULL \
+           AND NOT EXISTS ( \
+               SELECT 1 FROM attendance_open_record_sweeps s WHERE s.attendance_id = a.id \
+           ) \
+         ORDER BY a.date, a.user_id",
+    )
+    .bind(until)
+    .fetch_all(pool)
+    .await
+}
+
+/// Records what the sweep did with a record; returns zero if it was already recorded.
+#[allow(dead_code)]
+pub async fn record_sweep(
+    pool: &PgPool,
+    attendance_id: AttendanceId,
+    user_id: UserId,
+    action: OpenRecordSweepAction,
+    closed_at: Option<NaiveDateTime>,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        "INSERT INTO attendance_open_record_sweeps (attendance_id, user_id, action, closed_at) \
+         VALUES ($1, $2, $3, $4) \
+         ON CONFLICT (attendance_id) DO NOTHING",
+    )
+    .bind(attendance_id)
+    .bind(user_id)
+    .bind(action)
+    .bind(closed_at)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// Marks that the employee or a manager was told about a swept record.
+#[allow(dead_code)]
+pub async fn mark_notified(pool: &PgPool, attendance_id: AttendanceId) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE attendance_open_record_sweeps SET notified = TRUE WHERE attendance_id = $1",
+    )
+    .bind(attendance_id)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Lists swept records, newest first, optionally only those still awaiting a correction.
+pub async fn list_sweeps(
+    pool: &PgPool,
+    unresolved_only: bool,
+    limit: i64,
+    offset: i64,
+) -> Result<Vec<OpenRecordSweep>, sqlx::Error> {
+    sqlx::query_as::<_, OpenRecordSweep>(
+        "SELECT s.attendance_id, s.user_id, u.username, a.date, a.clock_in_time, \
+                a.clock_out_time, s.action, s.closed_at, s.notified, s.created_at \
+         FROM attendance_open_record_sweeps s \
+         JOIN attendance a ON a.id = s.attendance_id \
+         JOIN users u ON u.id = s.user_id \
+         WHERE NOT $1 OR (s.action = 'needs_correction' AND a.clock_out_time IS NULL) \
+         ORDER BY s.created_at DESC, a.date DESC \
+         LIMIT $2 OFFSET $3",
+    )
+    .bind(unresolved_only)
+    .bind(limit)
+    .bind(offset)
+    .fetch_all(pool)
+    .await
+}
@@ -1,11 +1,12 @@
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 use sqlx::{PgPool, Postgres, QueryBuilder};
 
 use crate::models::audit_log::AuditLog;
 use crate::repositories::common::push_clause;
 use crate::types::{AuditLogId, UserId};
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct AuditLogFilters {
     pub from: Option<DateTime<Utc>>,
     pub to: Option<DateTime<Utc>>,
@@ -63,17 +64,23 @@ pub async fn list_audit_logs(
     offset: i64,
 ) -> Result<(Vec<AuditLog>, i64), sqlx::Error> {
     let items = query_audit_logs(pool, filters, Some((per_page, offset))).await?;
+    let total = count_audit_logs(pool, filters).await?;
 
+    Ok((items, total))
+}
+
+pub async fn count_audit_logs(
+    pool: &PgPool,
+    filters: &AuditLogFilters,
+) -> Result<i64, sqlx::Error> {
     let mut count_builder: QueryBuilder<Postgres> =
         QueryBuilder::new("SELECT COUNT(*) FROM audit_logs");
     let mut count_has_clause = false;
     apply_audit_log_filters(&mut count_builder, &mut count_has_clause, filters);
-    let total = count_builder
+    count_builder
         .build_query_scalar::<i64>()
         .fetch_one(pool)
-        .await?;
-
-    Ok((items, total))
+        .await
 }
 
 pub async fn export_audit_logs(
@@ -106,14 +113,7 @@ async fn query_audit_logs(
     filters: &AuditLogFilters,
     pagination: Option<(i64, i64)>,
 ) -> Result<Vec<AuditLog>, sqlx::Error> {
-    let mut builder: QueryBuilder<Postgres> = QueryBuilder::new(
-        "SELECT id, occurred_at, actor_id, actor_type, event_type, target_type, target_id, result, \
-         error_code, metadata, ip, user_agent, request_id FROM audit_logs",
-    );
-    let mut has_clause = false;
-    apply_audit_log_filters(&mut builder, &mut has_clause, filters);
-    builder.push(" ORDER BY occurred_at DESC, id DESC");
-
+    let mut builder = export_query(filters);
     if let Some((per_page, offset)) = pagination {
         builder
             .push(" LIMIT ")
@@ -125,6 +125,18 @@ async fn query_audit_logs(
     builder.build_query_as::<AuditLog>().fetch_all(pool).await
 }
 
+/// Query selecting logs matching the filters, newest first, for callers that stream the rows.
+pub fn export_query(filters: &AuditLogFilters) -> QueryBuilder<'static, Postgres> {
+    let mut builder: QueryBuilder<Postgres> = QueryBuilder::new(
+        "SELECT id, occurred_at, actor_id, actor_type, event_type, target_type, target_id, result, \
+         error_code, metadata, ip, user_agent, request_id FROM audit_logs",
+    );
+    let mut has_clause = false;
+    apply_audit_log_filters(&mut builder, &mut has_clause, filters);
+    builder.push(" ORDER BY occurred_at DESC, id DESC");
+    builder
+}
+
 fn apply_audit_log_filters(
     builder: &mut QueryBuilder<'_, Postgres>,
     has_clause: &mut bool,
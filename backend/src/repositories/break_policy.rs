@@ -0,0 +1,140 @@
+//! Repository functions for the break policy and recorded break violations.
+
+use chrono::NaiveDate;
+use sqlx::{PgPool, Postgres};
+
+use crate::models::break_policy::{BreakPolicy, BreakShortfall, BreakViolation};
+use crate::types::AttendanceId;
+
+const POLICY_COLUMNS: &str = "mode, first_threshold_minutes, first_break_minutes, \
+     second_threshold_minutes, second_break_minutes, updated_at";
+
+/// Fetches the company-wide policy, if one was saved.
+pub async fn find_policy<'e, E>(executor: E) -> Result<Option<BreakPolicy>, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let query = format!("SELECT {POLICY_COLUMNS} FROM break_policy");
+    sqlx::query_as::<_, BreakPolicy>(&query)
+        .fetch_optional(executor)
+        .await
+}
+
+/// Inserts or overwrites the company-wide policy.
+pub async fn save_policy(pool: &PgPool, policy: &BreakPolicy) -> Result<BreakPolicy, sqlx::Error> {
+    let query = format!(
+        "INSERT INTO break_policy (id, {POLICY_COLUMNS}) \
+         VALUES (TRUE, $1, $2, $3, $4, $5, $6) \
+         ON CONFLICT (id) DO UPDATE SET \
+             mode = EXCLUDED.mode, \
+             first_threshold_minutes = EXCLUDED.first_threshold_minutes, \
+             first_break_minutes = EXCLUDED.first_break_minutes, \
+             second_threshold_minutes = EXCLUDED.second_threshold_minutes, \
+             second_break_minutes = EXCLUDED.second_break_minutes, \
+             updated_at = EXCLUDED.updated_at \
+         RETURNING {POLICY_COLUMNS}"
+    );
+    sqlx::query_as::<_, BreakPolicy>(&query)
+        .bind(policy.mode)
+        .bind(policy.first_threshold_minutes)
+        .bind(policy.first_break_minutes)
+        .bind(policy.second_threshold_minutes)
+        .bind(policy.second_break_minutes)
+        .bind(policy.updated_at)
+        .fetch_one(pool)
+        .await
+}
+
+/// Records the shortfall of a record, or removes its entry when `shortfall` is `None`.
+pub async fn save_violation<'e, E>(
+    executor: E,
+    attendance_id: AttendanceId,
+    shortfall: Option<&BreakShortfall>,
+    auto_deducted: bool,
+) -> Result<(), sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let Some(shortfall) = shortfall else {
+        sqlx::query("DELETE FROM attendance_break_violations WHERE attendance_id = $1")
+            .bind(attendance_id)
+            .execute(executor)
+            .await?;
+        return Ok(());
+    };
+    sqlx::query(
+        "INSERT INTO attendance_break_violations \
+             (attendance_id, worked_minutes, recorded_break_minutes, required_break_minutes, \
+              auto_deducted, detected_at) \
+         VALUES ($1, $2, $3, $4, $5, NOW()) \
+         ON CONFLICT (attendance_id) DO UPDATE SET \
+             worked_minutes = EXCLUDED.worked_minutes, \
+             recorded_break_minutes = EXCLUDED.recorded_break_minutes, \
+             required_break_minutes = EXCLUDED.required_break_minutes, \
+             auto_deducted = EXCLUDED.auto_deducted, \
+             detected_at = EXCLUDED.detected_at",
+    )
+    .bind(attendance_id)
+    .bind(shortfall.worked_minutes as i32)
+    .bind(shortfall.recorded_break_minutes as i32)
+    .bind(shortfall.required_break_minutes as i32)
+    .bind(auto_deducted)
+    .execute(executor)
+    .await?;
+    Ok(())
+}
+
+/// Removes the automatic breaks the policy added to a record.
+pub async fn delete_automatic_breaks<'e, E>(
+    executor: E,
+    attendance_id: AttendanceId,
+) -> Result<u64, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let result =
+        sqlx::query("DELETE FROM break_records WHERE attendance_id = $1 AND is_automatic = TRUE")
+            .bind(attendance_id)
+            .execute(executor)
+            .await?;
+    Ok(result.rows_affected())
+}
+
+/// Minutes of completed breaks recorded for a record, leaving out automatic ones.
+pub async fn recorded_break_minutes<'e, E>(
+    executor: E,
+    attendance_id: AttendanceId,
+) -> Result<i64, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    sqlx::query_scalar::<_, i64>(
+        "SELECT COALESCE(SUM(duration_minutes), 0)::BIGINT FROM break_records \
+         WHERE attendance_id = $1 AND duration_minutes IS NOT NULL AND is_automatic = FALSE",
+    )
+    .bind(attendance_id)
+    .fetch_one(executor)
+    .await
+}
+
+/// Lists violations between two dates, most recent first.
+pub async fn list_violations(
+    pool: &PgPool,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<BreakViolation>, sqlx::Error> {
+    sqlx::query_as::<_, BreakViolation>(
+        "SELECT v.attendance_id, a.user_id, u.username, a.date, v.worked_minutes, \
+                v.recorded_break_minutes, v.required_break_minutes, v.auto_deducted, \
+                v.detected_at \
+         FROM attendance_break_violations v \
+         JOIN attendance a ON a.id = v.attendance_id \
+         JOIN users u ON u.id = a.user_id \
+         WHERE a.date BETWEEN $1 AND $2 \
+         ORDER BY a.date DESC, u.username",
+    )
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await
+}
@@ -13,7 +13,8 @@ use sqlx::{PgPool, Row};
 
 const TABLE_NAME: &str = "break_records";
 const SELECT_COLUMNS: &str =
-    "id, attendance_id, break_start_time, break_end_time, duration_minutes, created_at, updated_at";
+    "id, attendance_id, break_start_time, break_end_time, duration_minutes, is_automatic, \
+     created_at, updated_at";
 const ACTIVE_BREAKS_LIST_LIMIT: i64 = 500;
 
 #[derive(Debug, Default, Clone, Copy)]
@@ -124,8 +125,8 @@ impl BreakRecordRepository {
         item: &BreakRecord,
     ) -> Result<BreakRecord, AppError> {
         let query = format!(
-            "INSERT INTO {} (id, attendance_id, break_start_time, break_end_time, duration_minutes, created_at, updated_at) \
-             VALUES ($1, $2, $3, $4, $5, $6, $7) \
+            "INSERT INTO {} (id, attendance_id, break_start_time, break_end_time, duration_minutes, is_automatic, created_at, updated_at) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8) \
              RETURNING {}",
             TABLE_NAME, SELECT_COLUMNS
         );
@@ -135,6 +136,7 @@ impl BreakRecordRepository {
             .bind(item.break_start_time)
             .bind(item.break_end_time)
             .bind(item.duration_minutes)
+            .bind(item.is_automatic)
             .bind(item.created_at)
             .bind(item.updated_at)
             .fetch_one(tx.as_mut())
@@ -174,8 +176,8 @@ impl Repository<BreakRecord> for BreakRecordRepository {
 
     async fn create(&self, db: &PgPool, item: &BreakRecord) -> Result<BreakRecord, AppError> {
         let query = format!(
-            "INSERT INTO {} (id, attendance_id, break_start_time, break_end_time, duration_minutes, created_at, updated_at) \
-             VALUES ($1, $2, $3, $4, $5, $6, $7) \
+            "INSERT INTO {} (id, attendance_id, break_start_time, break_end_time, duration_minutes, is_automatic, created_at, updated_at) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8) \
              RETURNING {}",
             TABLE_NAME, SELECT_COLUMNS
         );
@@ -185,6 +187,7 @@ impl Repository<BreakRecord> for BreakRecordRepository {
             .bind(item.break_start_time)
             .bind(item.break_end_time)
             .bind(item.duration_minutes)
+            .bind(item.is_automatic)
             .bind(item.created_at)
             .bind(item.updated_at)
             .fetch_one(db)
@@ -197,6 +197,35 @@ pub async fn can_manager_approve(
     Ok(result.0)
 }
 
+/// Checks whether the department is one the manager manages directly or through a parent.
+pub async fn can_manager_manage_department(
+    pool: &PgPool,
+    manager_id: UserId,
+    department_id: &str,
+) -> Result<bool, sqlx::Error> {
+    let result: (bool,) = sqlx::query_as(
+        r#"
+        WITH RECURSIVE subordinate_depts AS (
+            SELECT dm.department_id
+            FROM department_managers dm
+            WHERE dm.user_id = $1
+            UNION ALL
+            SELECT d.id
+            FROM departments d
+            INNER JOIN subordinate_depts sd ON d.parent_id = sd.department_id
+        )
+        SELECT EXISTS (
+            SELECT 1 FROM subordinate_depts WHERE department_id = $2
+        ) AS can_manage
+        "#,
+    )
+    .bind(manager_id.to_string())
+    .bind(department_id)
+    .fetch_one(pool)
+    .await?;
+    Ok(result.0)
+}
+
 /// Returns the user IDs of all users belonging to the manager's direct or subordinate departments.
 pub async fn list_subordinate_user_ids(
     pool: &PgPool,
@@ -263,6 +292,51 @@ pub async fn would_create_cycle(
     Ok(result.0)
 }
 
+/// Returns the encrypted email addresses of an employee and their department managers.
+pub async fn list_user_and_manager_emails(
+    pool: &PgPool,
+    user_id: UserId,
+) -> Result<Vec<String>, sqlx::Error> {
+    let rows: Vec<(String,)> = sqlx::query_as(
+        "SELECT COALESCE(u.email_enc, '') FROM users u WHERE u.id = $1 \
+         UNION \
+         SELECT COALESCE(m.email_enc, '') FROM users u \
+         JOIN department_managers dm ON dm.department_id = u.department_id \
+         JOIN users m ON m.id = dm.user_id \
+         WHERE u.id = $1",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|(email,)| email)
+        .filter(|email| !email.is_empty())
+        .collect())
+}
+
+/// Returns the encrypted email addresses of the managers of an employee's department.
+#[allow(dead_code)]
+pub async fn list_manager_emails(
+    pool: &PgPool,
+    user_id: UserId,
+) -> Result<Vec<String>, sqlx::Error> {
+    let rows: Vec<(String,)> = sqlx::query_as(
+        "SELECT DISTINCT COALESCE(m.email_enc, '') FROM users u \
+         JOIN department_managers dm ON dm.department_id = u.department_id \
+         JOIN users m ON m.id = dm.user_id \
+         WHERE u.id = $1",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|(email,)| email)
+        .filter(|email| !email.is_empty())
+        .collect())
+}
+
 #[cfg(test)]
 mod tests {
     // Unit-testable logic lives in integration tests.
@@ -0,0 +1,95 @@
+//! Repository functions for user employment profiles.
+
+use chrono::NaiveDate;
+use sqlx::{FromRow, PgPool};
+
+use crate::models::employment_profile::{EmploymentProfile, EmploymentType};
+use crate::types::UserId;
+
+const PROFILE_COLUMNS: &str = "user_id, hire_date, employment_type, weekly_scheduled_days, \
+     weekly_scheduled_hours, employee_code, created_at, updated_at";
+
+/// Employment profile joined with the owner's username.
+#[derive(Debug, Clone, FromRow)]
+pub struct EmploymentProfileWithUser {
+    #[sqlx(flatten)]
+    pub profile: EmploymentProfile,
+    pub username: String,
+}
+
+/// Fetches the employment profile for a user.
+pub async fn find_by_user(
+    pool: &PgPool,
+    user_id: UserId,
+) -> Result<Option<EmploymentProfile>, sqlx::Error> {
+    let query =
+        format!("SELECT {PROFILE_COLUMNS} FROM user_employment_profiles WHERE user_id = $1");
+    sqlx::query_as::<_, EmploymentProfile>(&query)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Lists every employment profile with its owner's username, ordered by username.
+pub async fn list_with_users(pool: &PgPool) -> Result<Vec<EmploymentProfileWithUser>, sqlx::Error> {
+    sqlx::query_as::<_, EmploymentProfileWithUser>(
+        "SELECT p.user_id, p.hire_date, p.employment_type, p.weekly_scheduled_days, \
+                p.weekly_scheduled_hours, p.employee_code, p.created_at, p.updated_at, u.username \
+         FROM user_employment_profiles p \
+         JOIN users u ON u.id = p.user_id \
+         ORDER BY u.username",
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// Returns true if another user's profile already uses `employee_code`.
+pub async fn employee_code_taken(
+    pool: &PgPool,
+    employee_code: &str,
+    user_id: UserId,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS(SELECT 1 FROM user_employment_profiles \
+         WHERE employee_code = $1 AND user_id <> $2)",
+    )
+    .bind(employee_code)
+    .bind(user_id)
+    .fetch_one(pool)
+    .await
+}
+
+/// Creates or replaces the employment profile for a user.
+pub async fn upsert(
+    pool: &PgPool,
+    user_id: UserId,
+    hire_date: NaiveDate,
+    employment_type: EmploymentType,
+    weekly_scheduled_days: i16,
+    weekly_scheduled_hours: f64,
+    employee_code: Option<&str>,
+) -> Result<EmploymentProfile, sqlx::Error> {
+    let query = format!(
+        "INSERT INTO user_employment_profiles \
+            (user_id, hire_date, employment_type, weekly_scheduled_days, weekly_scheduled_hours, \
+             employee_code) \
+         VALUES ($1, $2, $3, $4, $5, $6) \
+         ON CONFLICT (user_id) DO UPDATE SET \
+            hire_date = EXCLUDED.hire_date, \
+            employment_type = EXCLUDED.employment_type, \
+            weekly_scheduled_days = EXCLUDED.weekly_scheduled_days, \
+            weekly_scheduled_hours = EXCLUDED.weekly_scheduled_hours, \
+            employee_code = EXCLUDED.employee_code, \
+            updated_at = NOW() \
+         RETURNING {PROFILE_COLUMNS}"
+    );
+    sqlx::query_as::<_, EmploymentProfile>(&query)
+        .bind(user_id)
+        .bind(hire_date)
+        .bind(employment_type.db_value())
+        .bind(weekly_scheduled_days)
+        .bind(weekly_scheduled_hours)
+        .bind(employee_code)
+        .fetch_one(pool)
+        .await
+}
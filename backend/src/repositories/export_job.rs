@@ -0,0 +1,198 @@
+//! Repository functions for asynchronous export jobs.
+
+use chrono::{DateTime, Utc};
+use sqlx::PgPool;
+
+use crate::models::export_job::{ExportJob, ExportJobStatus};
+use crate::types::{ExportJobId, UserId};
+
+const JOB_COLUMNS: &str = "id, requested_by, kind, parameters, status, rows_written, \
+     total_rows, storage_key, filename, content_type, size_bytes, error, encryption, \
+     encryption_key, created_at, started_at, finished_at, updated_at";
+
+/// Queues a new export job.
+pub async fn insert_job(pool: &PgPool, job: &ExportJob) -> Result<ExportJob, sqlx::Error> {
+    let query = format!(
+        "INSERT INTO export_jobs (id, requested_by, kind, parameters, status, encryption, \
+         encryption_key, created_at, updated_at) VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9) \
+         RETURNING {JOB_COLUMNS}"
+    );
+    sqlx::query_as::<_, ExportJob>(&query)
+        .bind(job.id)
+        .bind(job.requested_by)
+        .bind(job.kind)
+        .bind(&job.parameters)
+        .bind(job.status)
+        .bind(job.encryption)
+        .bind(&job.encryption_key)
+        .bind(job.created_at)
+        .bind(job.updated_at)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn find_job(pool: &PgPool, id: ExportJobId) -> Result<Option<ExportJob>, sqlx::Error> {
+    let query = format!("SELECT {JOB_COLUMNS} FROM export_jobs WHERE id = $1");
+    sqlx::query_as::<_, ExportJob>(&query)
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Lists jobs newest first, optionally only those one user requested, with the total count.
+pub async fn list_jobs(
+    pool: &PgPool,
+    requested_by: Option<UserId>,
+    limit: i64,
+    offset: i64,
+) -> Result<(Vec<ExportJob>, i64), sqlx::Error> {
+    let query = format!(
+        "SELECT {JOB_COLUMNS} FROM export_jobs \
+         WHERE ($1::TEXT IS NULL OR requested_by = $1) \
+         ORDER BY created_at DESC, id DESC LIMIT $2 OFFSET $3"
+    );
+    let jobs = sqlx::query_as::<_, ExportJob>(&query)
+        .bind(requested_by)
+        .bind(limit)
+        .bind(offset)
+        .fetch_all(pool)
+        .await?;
+    let total = sqlx::query_scalar::<_, i64>(
+        "SELECT COUNT(*) FROM export_jobs WHERE ($1::TEXT IS NULL OR requested_by = $1)",
+    )
+    .bind(requested_by)
+    .fetch_one(pool)
+    .await?;
+    Ok((jobs, total))
+}
+
+/// Marks the oldest queued job as running and returns it.
+///
+/// A running job whose worker stopped refreshing it before `stale_before` is claimed again,
+/// so a crashed worker does not leave it running forever. Concurrent workers skip rows
+/// another worker has locked, so each job is claimed once.
+#[allow(dead_code)]
+pub async fn claim_next_job(
+    pool: &PgPool,
+    now: DateTime<Utc>,
+    stale_before: DateTime<Utc>,
+) -> Result<Option<ExportJob>, sqlx::Error> {
+    let query = format!(
+        "UPDATE export_jobs SET status = 'running', rows_written = 0, started_at = $1, \
+         updated_at = $1 \
+         WHERE id = (SELECT id FROM export_jobs \
+                     WHERE status = 'queued' OR (status = 'running' AND updated_at < $2) \
+                     ORDER BY created_at, id LIMIT 1 FOR UPDATE SKIP LOCKED) \
+         RETURNING {JOB_COLUMNS}"
+    );
+    sqlx::query_as::<_, ExportJob>(&query)
+        .bind(now)
+        .bind(stale_before)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Records that the worker running a job is still alive.
+#[allow(dead_code)]
+pub async fn touch_job(
+    pool: &PgPool,
+    id: ExportJobId,
+    now: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE export_jobs SET updated_at = $2 WHERE id = $1 AND status = 'running'")
+        .bind(id)
+        .bind(now)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+#[allow(dead_code)]
+pub async fn set_total_rows(
+    pool: &PgPool,
+    id: ExportJobId,
+    total_rows: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE export_jobs SET total_rows = $2, updated_at = NOW() WHERE id = $1")
+        .bind(id)
+        .bind(total_rows)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+#[allow(dead_code)]
+pub async fn update_progress(
+    pool: &PgPool,
+    id: ExportJobId,
+    rows_written: i64,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE export_jobs SET rows_written = $2, updated_at = NOW() WHERE id = $1")
+        .bind(id)
+        .bind(rows_written)
+        .execute(pool)
+        .await
+        .map(|_| ())
+}
+
+/// Drops a sealed passphrase once the job no longer needs it; age public keys are kept.
+#[allow(dead_code)]
+const FORGET_PASSPHRASE: &str = "encryption_key = CASE WHEN encryption = 'passphrase' \
+     THEN NULL ELSE encryption_key END";
+
+/// Where a finished export was stored.
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct ExportJobOutput {
+    pub rows_written: i64,
+    pub storage_key: String,
+    pub filename: String,
+    pub content_type: String,
+    pub size_bytes: i64,
+}
+
+#[allow(dead_code)]
+pub async fn complete_job(
+    pool: &PgPool,
+    id: ExportJobId,
+    output: &ExportJobOutput,
+    now: DateTime<Utc>,
+) -> Result<ExportJob, sqlx::Error> {
+    let query = format!(
+        "UPDATE export_jobs SET status = $2, rows_written = $3, storage_key = $4, \
+         filename = $5, content_type = $6, size_bytes = $7, error = NULL, \
+         {FORGET_PASSPHRASE}, finished_at = $8, updated_at = $8 \
+         WHERE id = $1 RETURNING {JOB_COLUMNS}"
+    );
+    sqlx::query_as::<_, ExportJob>(&query)
+        .bind(id)
+        .bind(ExportJobStatus::Completed.db_value())
+        .bind(output.rows_written)
+        .bind(&output.storage_key)
+        .bind(&output.filename)
+        .bind(&output.content_type)
+        .bind(output.size_bytes)
+        .bind(now)
+        .fetch_one(pool)
+        .await
+}
+
+#[allow(dead_code)]
+pub async fn fail_job(
+    pool: &PgPool,
+    id: ExportJobId,
+    error: &str,
+    now: DateTime<Utc>,
+) -> Result<ExportJob, sqlx::Error> {
+    let query = format!(
+        "UPDATE export_jobs SET status = $2, error = $3, {FORGET_PASSPHRASE}, \
+         finished_at = $4, updated_at = $4 WHERE id = $1 RETURNING {JOB_COLUMNS}"
+    );
+    sqlx::query_as::<_, ExportJob>(&query)
+        .bind(id)
+        .bind(ExportJobStatus::Failed.db_value())
+        .bind(error)
+        .bind(now)
+        .fetch_one(pool)
+        .await
+}
@@ -0,0 +1,179 @@
+//! Repository functions for recurring export schedules.
+
+use chrono::{DateTime, Utc};
+use sqlx::{types::Json, PgPool};
+
+use crate::models::export_schedule::{ExportRunStatus, ExportSchedule};
+use crate::types::{ExportScheduleId, ExportTemplateId};
+
+const SCHEDULE_COLUMNS: &str = "id, name, cron_expression, template_id, username, period, \
+     destination, encryption_recipient, enabled, next_run_at, last_run_at, last_run_status, last_run_error, \
+     created_by, created_at, updated_at";
+
+/// Lists all export schedules ordered by name.
+pub async fn list_schedules(pool: &PgPool) -> Result<Vec<ExportSchedule>, sqlx::Error> {
+    let query = format!("SELECT {SCHEDULE_COLUMNS} FROM export_schedules ORDER BY name");
+    sqlx::query_as::<_, ExportSchedule>(&query)
+        .fetch_all(pool)
+        .await
+}
+
+pub async fn find_schedule(
+    pool: &PgPool,
+    id: ExportScheduleId,
+) -> Result<Option<ExportSchedule>, sqlx::Error> {
+    let query = format!("SELECT {SCHEDULE_COLUMNS} FROM export_schedules WHERE id = $1");
+    sqlx::query_as::<_, ExportSchedule>(&query)
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Returns true if another schedule already uses `name`.
+pub async fn schedule_name_taken(
+    pool: &PgPool,
+    name: &str,
+    exclude: Option<ExportScheduleId>,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS(SELECT 1 FROM export_schedules \
+         WHERE name = $1 AND ($2::TEXT IS NULL OR id <> $2))",
+    )
+    .bind(name)
+    .bind(exclude)
+    .fetch_one(pool)
+    .await
+}
+
+/// Returns true if any schedule exports with the template.
+pub async fn template_in_use(pool: &PgPool, id: ExportTemplateId) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS(SELECT 1 FROM export_schedules WHERE template_id = $1)",
+    )
+    .bind(id)
+    .fetch_one(pool)
+    .await
+}
+
+pub async fn create_schedule(
+    pool: &PgPool,
+    schedule: &ExportSchedule,
+) -> Result<ExportSchedule, sqlx::Error> {
+    let query = format!(
+        "INSERT INTO export_schedules (id, name, cron_expression, template_id, username, \
+         period, destination, encryption_recipient, enabled, next_run_at, created_by, \
+         created_at, updated_at) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13) \
+         RETURNING {SCHEDULE_COLUMNS}"
+    );
+    sqlx::query_as::<_, ExportSchedule>(&query)
+        .bind(schedule.id)
+        .bind(&schedule.name)
+        .bind(&schedule.cron_expression)
+        .bind(schedule.template_id)
+        .bind(&schedule.username)
+        .bind(schedule.period)
+        .bind(Json(&schedule.destination))
+        .bind(&schedule.encryption_recipient)
+        .bind(schedule.enabled)
+        .bind(schedule.next_run_at)
+        .bind(schedule.created_by)
+        .bind(schedule.created_at)
+        .bind(schedule.updated_at)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn update_schedule(
+    pool: &PgPool,
+    schedule: &ExportSchedule,
+) -> Result<ExportSchedule, sqlx::Error> {
+    let query = format!(
+        "UPDATE export_schedules SET name = $2, cron_expression = $3, template_id = $4, \
+         username = $5, period = $6, destination = $7, encryption_recipient = $8, \
+         enabled = $9, next_run_at = $10, updated_at = $11 \
+         WHERE id = $1 RETURNING {SCHEDULE_COLUMNS}"
+    );
+    sqlx::query_as::<_, ExportSchedule>(&query)
+        .bind(schedule.id)
+        .bind(&schedule.name)
+        .bind(&schedule.cron_expression)
+        .bind(schedule.template_id)
+        .bind(&schedule.username)
+        .bind(schedule.period)
+        .bind(Json(&schedule.destination))
+        .bind(&schedule.encryption_recipient)
+        .bind(schedule.enabled)
+        .bind(schedule.next_run_at)
+        .bind(schedule.updated_at)
+        .fetch_one(pool)
+        .await
+}
+
+pub async fn delete_schedule(pool: &PgPool, id: ExportScheduleId) -> Result<u64, sqlx::Error> {
+    sqlx::query("DELETE FROM export_schedules WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await
+        .map(|result| result.rows_affected())
+}
+
+/// Enabled schedules whose next run is at or before `now`, oldest first.
+#[allow(dead_code)]
+pub async fn list_due_schedules(
+    pool: &PgPool,
+    now: DateTime<Utc>,
+) -> Result<Vec<ExportSchedule>, sqlx::Error> {
+    let query = format!(
+        "SELECT {SCHEDULE_COLUMNS} FROM export_schedules \
+         WHERE enabled AND next_run_at <= $1 ORDER BY next_run_at, id"
+    );
+    sqlx::query_as::<_, ExportSchedule>(&query)
+        .bind(now)
+        .fetch_all(pool)
+        .await
+}
+
+/// Moves a due schedule on to its next run.
+///
+/// Returns false when another runner already claimed this run, in which case the caller
+/// must not run it.
+#[allow(dead_code)]
+pub async fn claim_run(
+    pool: &PgPool,
+    id: ExportScheduleId,
+    scheduled_for: DateTime<Utc>,
+    next_run_at: DateTime<Utc>,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query(
+        "UPDATE export_schedules SET next_run_at = $3, updated_at = NOW() \
+         WHERE id = $1 AND enabled AND next_run_at = $2",
+    )
+    .bind(id)
+    .bind(scheduled_for)
+    .bind(next_run_at)
+    .execute(pool)
+    .await
+    .map(|result| result.rows_affected() == 1)
+}
+
+#[allow(dead_code)]
+pub async fn record_run(
+    pool: &PgPool,
+    id: ExportScheduleId,
+    status: ExportRunStatus,
+    error: Option<&str>,
+    ran_at: DateTime<Utc>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE export_schedules SET last_run_at = $2, last_run_status = $3, \
+         last_run_error = $4, updated_at = $2 WHERE id = $1",
+    )
+    .bind(id)
+    .bind(ran_at)
+    .bind(status)
+    .bind(error)
+    .execute(pool)
+    .await
+    .map(|_| ())
+}
@@ -0,0 +1,218 @@
+//! Repository functions for payroll export templates and the attendance they export.
+
+use chrono::{NaiveDate, NaiveDateTime};
+use serde::{Deserialize, Serialize};
+use sqlx::{types::Json, PgPool, Postgres, QueryBuilder};
+
+use crate::models::export_template::ExportTemplate;
+use crate::types::ExportTemplateId;
+
+use super::common::push_clause;
+
+const TEMPLATE_COLUMNS: &str = "id, name, granularity, encoding, delimiter, date_format, \
+     month_format, include_header, columns, created_at, updated_at";
+
+/// Lists all export templates ordered by name.
+pub async fn list_templates(pool: &PgPool) -> Result<Vec<ExportTemplate>, sqlx::Error> {
+    let query = format!("SELECT {TEMPLATE_COLUMNS} FROM export_templates ORDER BY name");
+    sqlx::query_as::<_, ExportTemplate>(&query)
+        .fetch_all(pool)
+        .await
+}
+
+/// Fetches an export template by ID.
+pub async fn find_template(
+    pool: &PgPool,
+    id: ExportTemplateId,
+) -> Result<Option<ExportTemplate>, sqlx::Error> {
+    let query = format!("SELECT {TEMPLATE_COLUMNS} FROM export_templates WHERE id = $1");
+    sqlx::query_as::<_, ExportTemplate>(&query)
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Fetches an export template by ID or, failing that, by name.
+pub async fn find_template_by_reference(
+    pool: &PgPool,
+    reference: &str,
+) -> Result<Option<ExportTemplate>, sqlx::Error> {
+    let query = format!(
+        "SELECT {TEMPLATE_COLUMNS} FROM export_templates WHERE id = $1 OR name = $1 \
+         ORDER BY (id = $1) DESC LIMIT 1"
+    );
+    sqlx::query_as::<_, ExportTemplate>(&query)
+        .bind(reference)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Returns true if another template already uses `name`.
+pub async fn template_name_taken(
+    pool: &PgPool,
+    name: &str,
+    exclude: Option<ExportTemplateId>,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS(SELECT 1 FROM export_templates \
+         WHERE name = $1 AND ($2::TEXT IS NULL OR id <> $2))",
+    )
+    .bind(name)
+    .bind(exclude)
+    .fetch_one(pool)
+    .await
+}
+
+/// Inserts an export template.
+pub async fn create_template(
+    pool: &PgPool,
+    template: &ExportTemplate,
+) -> Result<ExportTemplate, sqlx::Error> {
+    let query = format!(
+        "INSERT INTO export_templates ({TEMPLATE_COLUMNS}) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11) \
+         RETURNING {TEMPLATE_COLUMNS}"
+    );
+    sqlx::query_as::<_, ExportTemplate>(&query)
+        .bind(template.id)
+        .bind(&template.name)
+        .bind(template.granularity)
+        .bind(template.encoding)
+        .bind(template.delimiter)
+        .bind(&template.date_format)
+        .bind(&template.month_format)
+        .bind(template.include_header)
+        .bind(Json(&template.columns))
+        .bind(template.created_at)
+        .bind(template.updated_at)
+        .fetch_one(pool)
+        .await
+}
+
+/// Overwrites an export template.
+pub async fn update_template(
+    pool: &PgPool,
+    template: &ExportTemplate,
+) -> Result<ExportTemplate, sqlx::Error> {
+    let query = format!(
+        "UPDATE export_templates SET name = $2, granularity = $3, encoding = $4, \
+         delimiter = $5, date_format = $6, month_format = $7, include_header = $8, \
+         columns = $9, updated_at = $10 \
+         WHERE id = $1 RETURNING {TEMPLATE_COLUMNS}"
+    );
+    sqlx::query_as::<_, ExportTemplate>(&query)
+        .bind(template.id)
+        .bind(&template.name)
+        .bind(template.granularity)
+        .bind(template.encoding)
+        .bind(template.delimiter)
+        .bind(&template.date_format)
+        .bind(&template.month_format)
+        .bind(template.include_header)
+        .bind(Json(&template.columns))
+        .bind(template.updated_at)
+        .fetch_one(pool)
+        .await
+}
+
+/// Deletes an export template and returns the number of rows removed.
+pub async fn delete_template(pool: &PgPool, id: ExportTemplateId) -> Result<u64, sqlx::Error> {
+    sqlx::query("DELETE FROM export_templates WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await
+        .map(|result| result.rows_affected())
+}
+
+/// Attendance day with everything a template can export; the full name is still encrypted.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct ExportRecord {
+    pub username: String,
+    pub full_name: String,
+    pub employee_code: Option<String>,
+    pub date: NaiveDate,
+    pub clock_in_time: Option<NaiveDateTime>,
+    pub clock_out_time: Option<NaiveDateTime>,
+    pub status: String,
+    pub break_minutes: i64,
+    pub total_work_hours: Option<f64>,
+    pub regular_hours: Option<f64>,
+    pub statutory_overtime_hours: Option<f64>,
+    pub late_night_hours: Option<f64>,
+    pub legal_holiday_hours: Option<f64>,
+    pub non_statutory_holiday_hours: Option<f64>,
+}
+
+/// Which attendance a template export covers.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct ExportRecordFilter {
+    pub username: Option<String>,
+    /// Restricts the export to these users when set.
+    pub user_ids: Option<Vec<String>>,
+    pub from: Option<NaiveDate>,
+    pub to: Option<NaiveDate>,
+}
+
+/// Lists attendance matching the filter, ordered by username and date.
+pub async fn list_export_records(
+    pool: &PgPool,
+    filter: &ExportRecordFilter,
+) -> Result<Vec<ExportRecord>, sqlx::Error> {
+    export_records_query(filter)
+        .build_query_as::<ExportRecord>()
+        .fetch_all(pool)
+        .await
+}
+
+/// Counts the attendance days matching the filter.
+#[allow(dead_code)]
+pub async fn count_export_records(
+    pool: &PgPool,
+    filter: &ExportRecordFilter,
+) -> Result<i64, sqlx::Error> {
+    let mut builder: QueryBuilder<Postgres> =
+        QueryBuilder::new("SELECT COUNT(*) FROM attendance a JOIN users u ON a.user_id = u.id");
+    push_record_filter(&mut builder, filter);
+    builder.build_query_scalar::<i64>().fetch_one(pool).await
+}
+
+/// Query selecting [`ExportRecord`]s matching the filter, for callers that stream the rows.
+pub fn export_records_query(filter: &ExportRecordFilter) -> QueryBuilder<'static, Postgres> {
+    let mut builder: QueryBuilder<Postgres> = QueryBuilder::new(
+        "SELECT u.username, COALESCE(u.full_name_enc, '') AS full_name, p.employee_code, \
+                a.date, a.clock_in_time, a.clock_out_time, a.status, \
+                COALESCE((SELECT SUM(b.duration_minutes) FROM break_records b \
+                          WHERE b.attendance_id = a.id), 0)::BIGINT AS break_minutes, \
+                a.total_work_hours, w.regular_hours, w.statutory_overtime_hours, \
+                w.late_night_hours, w.legal_holiday_hours, w.non_statutory_holiday_hours \
+         FROM attendance a JOIN users u ON a.user_id = u.id \
+         LEFT JOIN user_employment_profiles p ON p.user_id = u.id \
+         LEFT JOIN attendance_work_time w ON w.attendance_id = a.id",
+    );
+    push_record_filter(&mut builder, filter);
+    builder.push(" ORDER BY u.username, a.date");
+    builder
+}
+
+fn push_record_filter(builder: &mut QueryBuilder<'static, Postgres>, filter: &ExportRecordFilter) {
+    let mut has_clause = false;
+    if let Some(username) = &filter.username {
+        push_clause(builder, &mut has_clause);
+        builder.push("u.username = ").push_bind(username.clone());
+    }
+    if let Some(user_ids) = &filter.user_ids {
+        push_clause(builder, &mut has_clause);
+        builder
+            .push("u.id = ANY(")
+            .push_bind(user_ids.clone())
+            .push(")");
+    }
+    if let Some(from) = filter.from {
+        push_clause(builder, &mut has_clause);
+        builder.push("a.date >= ").push_bind(from);
+    }
+    if let Some(to) = filter.to {
+        push_clause(builder, &mut has_clause);
+        builder.push("a.date <= ").push_bind(to);
+    }
+}
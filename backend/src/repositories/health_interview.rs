@@ -0,0 +1,111 @@
+//! Repository functions for physician interviews and the employees considered for them.
+
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::models::health_interview::{HealthInterview, HealthInterviewStatus};
+use crate::types::{DepartmentId, UserId};
+
+const INTERVIEW_COLUMNS: &str = "h.user_id, u.username, h.year, h.month, h.overtime_hours, \
+     h.status, h.notes, h.offered_at, h.completed_at, h.updated_by, h.updated_at";
+
+/// Users who clocked in between two dates, by username, optionally for one department.
+pub async fn list_users_with_work(
+    pool: &PgPool,
+    from: NaiveDate,
+    to: NaiveDate,
+    department_id: Option<DepartmentId>,
+) -> Result<Vec<(UserId, String, Option<DepartmentId>)>, sqlx::Error> {
+    sqlx::query_as::<_, (UserId, String, Option<DepartmentId>)>(
+        "SELECT u.id, u.username, u.department_id FROM users u \
+         WHERE ($3::TEXT IS NULL OR u.department_id = $3) \
+           AND EXISTS ( \
+                 SELECT 1 FROM attendance a \
+                 WHERE a.user_id = u.id AND a.date BETWEEN $1 AND $2 \
+                   AND a.clock_in_time IS NOT NULL \
+             ) \
+         ORDER BY u.username",
+    )
+    .bind(from)
+    .bind(to)
+    .bind(department_id)
+    .fetch_all(pool)
+    .await
+}
+
+/// Interviews recorded for a month.
+pub async fn list_for_month(
+    pool: &PgPool,
+    year: i32,
+    month: u32,
+) -> Result<Vec<HealthInterview>, sqlx::Error> {
+    let query = format!(
+        "SELECT {INTERVIEW_COLUMNS} FROM health_interviews h \
+         JOIN users u ON u.id = h.user_id \
+         WHERE h.year = $1 AND h.month = $2"
+    );
+    sqlx::query_as::<_, HealthInterview>(&query)
+        .bind(year)
+        .bind(month as i32)
+        .fetch_all(pool)
+        .await
+}
+
+/// Recorded interviews, newest month first, optionally for one employee.
+pub async fn list_history(
+    pool: &PgPool,
+    user_id: Option<UserId>,
+) -> Result<Vec<HealthInterview>, sqlx::Error> {
+    let query = format!(
+        "SELECT {INTERVIEW_COLUMNS} FROM health_interviews h \
+         JOIN users u ON u.id = h.user_id \
+         WHERE ($1::TEXT IS NULL OR h.user_id = $1) \
+         ORDER BY h.year DESC, h.month DESC, u.username"
+    );
+    sqlx::query_as::<_, HealthInterview>(&query)
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+}
+
+/// Records the interview of a user for a month, keeping when it was first offered and
+/// stamping when it was first completed.
+#[allow(clippy::too_many_arguments)]
+pub async fn save(
+    pool: &PgPool,
+    user_id: UserId,
+    year: i32,
+    month: u32,
+    overtime_hours: f64,
+    status: HealthInterviewStatus,
+    notes: Option<&str>,
+    updated_by: UserId,
+) -> Result<HealthInterview, sqlx::Error> {
+    let query = format!(
+        "WITH saved AS ( \
+             INSERT INTO health_interviews \
+                 (user_id, year, month, overtime_hours, status, notes, completed_at, updated_by) \
+             VALUES ($1, $2, $3, $4, $5, $6, CASE WHEN $5 = 'completed' THEN NOW() END, $7) \
+             ON CONFLICT (user_id, year, month) DO UPDATE SET \
+                 overtime_hours = EXCLUDED.overtime_hours, \
+                 status = EXCLUDED.status, \
+                 notes = EXCLUDED.notes, \
+                 completed_at = CASE WHEN EXCLUDED.status = 'completed' \
+                     THEN COALESCE(health_interviews.completed_at, NOW()) END, \
+                 updated_by = EXCLUDED.updated_by, \
+                 updated_at = NOW() \
+             RETURNING * \
+         ) \
+         SELECT {INTERVIEW_COLUMNS} FROM saved h JOIN users u ON u.id = h.user_id"
+    );
+    sqlx::query_as::<_, HealthInterview>(&query)
+        .bind(user_id)
+        .bind(year)
+        .bind(month as i32)
+        .bind(overtime_hours)
+        .bind(status)
+        .bind(notes)
+        .bind(updated_by)
+        .fetch_one(pool)
+        .await
+}
@@ -0,0 +1,133 @@
+//! Repository functions that reflect approved leave in attendance.
+
+use chrono::NaiveDate;
+use sqlx::{FromRow, PgConnection, PgPool, Postgres};
+
+use crate::models::leave_request::LeaveUnit;
+use crate::types::{AttendanceId, LeaveRequestId, UserId};
+
+/// Another approved request of the same user covering the attendance day `a`, other than `$1`.
+const OTHER_APPROVED_LEAVE: &str = "SELECT l.id FROM leave_requests l \
+     WHERE l.user_id = a.user_id AND l.status = 'approved' AND l.id <> $1 \
+       AND a.date BETWEEN l.start_date AND l.end_date";
+
+/// Dates and extent of an approved leave request, with whether its type is paid.
+#[derive(Debug, Clone, FromRow)]
+pub struct ApprovedLeave {
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+    pub unit: LeaveUnit,
+    pub hours: Option<f64>,
+    pub paid: bool,
+}
+
+/// The user's approved leave requests overlapping the dates from `from` to `to`.
+pub async fn list_approved_in_range(
+    pool: &PgPool,
+    user_id: UserId,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<ApprovedLeave>, sqlx::Error> {
+    sqlx::query_as::<_, ApprovedLeave>(
+        "SELECT l.start_date, l.end_date, l.unit, l.hours, t.paid \
+         FROM leave_requests l \
+         JOIN leave_types t ON t.code = l.leave_type \
+         WHERE l.user_id = $1 AND l.status = 'approved' \
+           AND l.start_date <= $3 AND l.end_date >= $2 \
+         ORDER BY l.start_date, l.id",
+    )
+    .bind(user_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await
+}
+
+/// Leave request the user's day is linked to, if any.
+pub async fn find_leave_link<'e, E>(
+    executor: E,
+    user_id: UserId,
+    date: NaiveDate,
+) -> Result<Option<LeaveRequestId>, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    sqlx::query_scalar::<_, Option<LeaveRequestId>>(
+        "SELECT leave_request_id FROM attendance WHERE user_id = $1 AND date = $2",
+    )
+    .bind(user_id)
+    .bind(date)
+    .fetch_optional(executor)
+    .await
+    .map(Option::flatten)
+}
+
+/// Links the user's day to a leave request, creating a leave record if the day has none.
+///
+/// An existing record without a clock-in, such as a detected absence, becomes leave; a
+/// worked record keeps its status and only gains the link. A day already linked to another
+/// approved request keeps that link.
+pub async fn mark_leave_day<'e, E>(
+    executor: E,
+    user_id: UserId,
+    date: NaiveDate,
+    leave_request_id: LeaveRequestId,
+) -> Result<u64, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let result = sqlx::query(
+        "INSERT INTO attendance (id, user_id, date, status, leave_request_id, created_at, updated_at) \
+         VALUES ($1, $2, $3, 'leave', $4, NOW(), NOW()) \
+         ON CONFLICT (user_id, date) DO UPDATE SET \
+             status = CASE WHEN attendance.clock_in_time IS NULL THEN 'leave' \
+                           ELSE attendance.status END, \
+             leave_request_id = COALESCE(attendance.leave_request_id, EXCLUDED.leave_request_id), \
+             updated_at = NOW()",
+    )
+    .bind(AttendanceId::new())
+    .bind(user_id)
+    .bind(date)
+    .bind(leave_request_id)
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// Takes the days of a cancelled leave request back out of attendance.
+///
+/// Days another approved request still covers move to that request. Of the rest, worked
+/// days only lose the link, unworked days before `today` go back to being absences and
+/// later ones are removed. Returns the number of records removed.
+pub async fn release_leave_days(
+    conn: &mut PgConnection,
+    leave_request_id: LeaveRequestId,
+    today: NaiveDate,
+) -> Result<u64, sqlx::Error> {
+    let removed = sqlx::query(&format!(
+        "DELETE FROM attendance a \
+         WHERE a.leave_request_id = $1 AND a.clock_in_time IS NULL AND a.date >= $2 \
+           AND NOT EXISTS ({OTHER_APPROVED_LEAVE})"
+    ))
+    .bind(leave_request_id)
+    .bind(today)
+    .execute(&mut *conn)
+    .await?
+    .rows_affected();
+    sqlx::query(&format!(
+        "UPDATE attendance a SET \
+             leave_request_id = c.other_id, \
+             status = CASE WHEN c.other_id IS NULL AND a.clock_in_time IS NULL \
+                           THEN 'absent' ELSE a.status END, \
+             updated_at = NOW() \
+         FROM ( \
+             SELECT a.id, ({OTHER_APPROVED_LEAVE} ORDER BY l.start_date, l.id LIMIT 1) AS other_id \
+             FROM attendance a WHERE a.leave_request_id = $1 \
+         ) c \
+         WHERE a.id = c.id"
+    ))
+    .bind(leave_request_id)
+    .execute(&mut *conn)
+    .await?;
+    Ok(removed)
+}
@@ -0,0 +1,240 @@
+//! Repository functions for the leave balance ledger.
+
+use chrono::NaiveDate;
+use sqlx::{PgPool, Postgres};
+
+use crate::models::leave_balance::{LeaveBalanceEntry, LeaveBalanceTotals};
+use crate::models::leave_request::LeaveType;
+use crate::types::{LeaveBalanceEntryId, LeaveRequestId, UserId};
+
+const ENTRY_COLUMNS: &str = "id, user_id, leave_type, entry_type, days, leave_request_id, \
+     effective_date, note, created_by, created_at, expires_on, statutory_grant, source_entry_id";
+
+/// Inserts a ledger entry.
+pub async fn insert_entry<'e, E>(
+    executor: E,
+    entry: &LeaveBalanceEntry,
+) -> Result<LeaveBalanceEntry, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let query = format!(
+        "INSERT INTO leave_balance_entries ({ENTRY_COLUMNS}) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13) \
+         RETURNING {ENTRY_COLUMNS}"
+    );
+    sqlx::query_as::<_, LeaveBalanceEntry>(&query)
+        .bind(entry.id)
+        .bind(entry.user_id)
+        .bind(entry.leave_type.db_value())
+        .bind(entry.entry_type.db_value())
+        .bind(entry.days)
+        .bind(entry.leave_request_id)
+        .bind(entry.effective_date)
+        .bind(&entry.note)
+        .bind(entry.created_by)
+        .bind(entry.created_at)
+        .bind(entry.expires_on)
+        .bind(entry.statutory_grant)
+        .bind(entry.source_entry_id)
+        .fetch_one(executor)
+        .await
+}
+
+/// Inserts a ledger entry unless a uniqueness rule (statutory grant or expiration) already covers it.
+///
+/// Returns the number of inserted rows.
+#[allow(dead_code)]
+pub async fn insert_entry_if_absent<'e, E>(
+    executor: E,
+    entry: &LeaveBalanceEntry,
+) -> Result<u64, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let query = format!(
+        "INSERT INTO leave_balance_entries ({ENTRY_COLUMNS}) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13) \
+         ON CONFLICT DO NOTHING"
+    );
+    let result = sqlx::query(&query)
+        .bind(entry.id)
+        .bind(entry.user_id)
+        .bind(entry.leave_type.db_value())
+        .bind(entry.entry_type.db_value())
+        .bind(entry.days)
+        .bind(entry.leave_request_id)
+        .bind(entry.effective_date)
+        .bind(&entry.note)
+        .bind(entry.created_by)
+        .bind(entry.created_at)
+        .bind(entry.expires_on)
+        .bind(entry.statutory_grant)
+        .bind(entry.source_entry_id)
+        .execute(executor)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+/// Lists every ledger entry for a user, newest first.
+pub async fn list_entries_for_user(
+    pool: &PgPool,
+    user_id: UserId,
+) -> Result<Vec<LeaveBalanceEntry>, sqlx::Error> {
+    let query = format!(
+        "SELECT {ENTRY_COLUMNS} FROM leave_balance_entries \
+         WHERE user_id = $1 ORDER BY effective_date DESC, created_at DESC"
+    );
+    sqlx::query_as::<_, LeaveBalanceEntry>(&query)
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+}
+
+/// Sums ledger entries for one user and leave type, grouped by entry type.
+pub async fn fetch_totals<'e, E>(
+    executor: E,
+    user_id: UserId,
+    leave_type: &LeaveType,
+) -> Result<LeaveBalanceTotals, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    sqlx::query_as::<_, LeaveBalanceTotals>(
+        "SELECT \
+            COALESCE(SUM(days) FILTER (WHERE entry_type = 'grant'), 0) AS granted, \
+            COALESCE(SUM(days) FILTER (WHERE entry_type = 'consumption'), 0) AS consumed, \
+            COALESCE(SUM(days) FILTER (WHERE entry_type = 'reversal'), 0) AS reversed, \
+            COALESCE(SUM(days) FILTER (WHERE entry_type = 'adjustment'), 0) AS adjusted, \
+            COALESCE(SUM(days) FILTER (WHERE entry_type = 'expiration'), 0) AS expired \
+         FROM leave_balance_entries WHERE user_id = $1 AND leave_type = $2",
+    )
+    .bind(user_id)
+    .bind(leave_type.db_value())
+    .fetch_one(executor)
+    .await
+}
+
+/// Effective date of the user's most recent grant of a leave type on or before `on`.
+pub async fn latest_grant_date(
+    pool: &PgPool,
+    user_id: UserId,
+    leave_type: &LeaveType,
+    on: NaiveDate,
+) -> Result<Option<NaiveDate>, sqlx::Error> {
+    let (date,): (Option<NaiveDate>,) = sqlx::query_as(
+        "SELECT MAX(effective_date) FROM leave_balance_entries \
+         WHERE user_id = $1 AND leave_type = $2 AND entry_type = 'grant' \
+           AND effective_date <= $3",
+    )
+    .bind(user_id)
+    .bind(leave_type.db_value())
+    .bind(on)
+    .fetch_one(pool)
+    .await?;
+    Ok(date)
+}
+
+/// Lists `(user_id, grant date)` for every statutory grant of a leave type.
+pub async fn list_statutory_grant_dates(
+    pool: &PgPool,
+    leave_type: &LeaveType,
+) -> Result<Vec<(UserId, NaiveDate)>, sqlx::Error> {
+    sqlx::query_as::<_, (UserId, NaiveDate)>(
+        "SELECT user_id, effective_date FROM leave_balance_entries \
+         WHERE entry_type = 'grant' AND statutory_grant AND leave_type = $1",
+    )
+    .bind(leave_type.db_value())
+    .fetch_all(pool)
+    .await
+}
+
+/// Lists grants that expired on or before `as_of` and have no expiration entry yet,
+/// oldest expiry first.
+#[allow(dead_code)]
+pub async fn list_unexpired_lapsed_grants(
+    pool: &PgPool,
+    as_of: NaiveDate,
+) -> Result<Vec<LeaveBalanceEntry>, sqlx::Error> {
+    let query = format!(
+        "SELECT {ENTRY_COLUMNS} FROM leave_balance_entries g \
+         WHERE g.entry_type = 'grant' AND g.expires_on <= $1 \
+           AND NOT EXISTS ( \
+               SELECT 1 FROM leave_balance_entries e \
+               WHERE e.entry_type = 'expiration' AND e.source_entry_id = g.id \
+           ) \
+         ORDER BY g.expires_on, g.effective_date, g.created_at"
+    );
+    sqlx::query_as::<_, LeaveBalanceEntry>(&query)
+        .bind(as_of)
+        .fetch_all(pool)
+        .await
+}
+
+/// Returns the balance on the day before a grant expires and the days of newer grants
+/// still valid on its expiry date.
+#[allow(dead_code)]
+pub async fn fetch_expiry_position<'e, E>(
+    executor: E,
+    grant: &LeaveBalanceEntry,
+) -> Result<(f64, f64), sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    sqlx::query_as::<_, (f64, f64)>(
+        "SELECT \
+            COALESCE(SUM(days) FILTER (WHERE effective_date < $3), 0), \
+            COALESCE(SUM(days) FILTER ( \
+                WHERE entry_type = 'grant' AND id <> $4 \
+                  AND effective_date > $5 AND effective_date < $3 \
+                  AND (expires_on IS NULL OR expires_on > $3) \
+            ), 0) \
+         FROM leave_balance_entries WHERE user_id = $1 AND leave_type = $2",
+    )
+    .bind(grant.user_id)
+    .bind(grant.leave_type.db_value())
+    .bind(grant.expires_on)
+    .bind(grant.id)
+    .bind(grant.effective_date)
+    .fetch_one(executor)
+    .await
+}
+
+/// Serializes concurrent balance changes for a user until the transaction ends.
+pub async fn lock_user_ledger<'e, E>(executor: E, user_id: UserId) -> Result<(), sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    sqlx::query("SELECT id FROM users WHERE id = $1 FOR UPDATE")
+        .bind(user_id)
+        .execute(executor)
+        .await
+        .map(|_| ())
+}
+
+/// Records a reversal offsetting the consumption booked for a leave request.
+///
+/// Returns the number of inserted rows; zero when the request never consumed balance.
+pub async fn insert_reversal_for_request<'e, E>(
+    executor: E,
+    leave_request_id: LeaveRequestId,
+    effective_date: NaiveDate,
+) -> Result<u64, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let result = sqlx::query(
+        "INSERT INTO leave_balance_entries \
+            (id, user_id, leave_type, entry_type, days, leave_request_id, effective_date) \
+         SELECT $1, user_id, leave_type, 'reversal', -days, leave_request_id, $2 \
+         FROM leave_balance_entries \
+         WHERE leave_request_id = $3 AND entry_type = 'consumption' \
+         ON CONFLICT DO NOTHING",
+    )
+    .bind(LeaveBalanceEntryId::new())
+    .bind(effective_date)
+    .bind(leave_request_id)
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected())
+}
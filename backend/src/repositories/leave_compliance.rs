@@ -0,0 +1,113 @@
+//! Repository functions for mandatory annual leave compliance tracking.
+
+use chrono::NaiveDate;
+use sqlx::{FromRow, PgPool};
+use uuid::Uuid;
+
+use crate::types::{LeaveBalanceEntryId, UserId};
+
+/// Annual leave grant large enough to carry the mandatory leave obligation.
+#[derive(Debug, Clone, FromRow)]
+pub struct QualifyingGrant {
+    pub grant_entry_id: LeaveBalanceEntryId,
+    pub user_id: UserId,
+    pub username: String,
+    pub grant_date: NaiveDate,
+    pub granted_days: f64,
+}
+
+/// Lists annual grants of at least `min_days` whose one-year period contains `as_of`.
+pub async fn list_qualifying_grants(
+    pool: &PgPool,
+    as_of: NaiveDate,
+    min_days: f64,
+) -> Result<Vec<QualifyingGrant>, sqlx::Error> {
+    sqlx::query_as::<_, QualifyingGrant>(
+        "SELECT g.id AS grant_entry_id, g.user_id, u.username, \
+                g.effective_date AS grant_date, g.days AS granted_days \
+         FROM leave_balance_entries g \
+         JOIN users u ON u.id = g.user_id \
+         WHERE g.entry_type = 'grant' AND g.leave_type = 'annual' AND g.days >= $2 \
+           AND g.effective_date <= $1 \
+           AND (g.effective_date + INTERVAL '1 year')::date > $1 \
+         ORDER BY u.username, g.effective_date",
+    )
+    .bind(as_of)
+    .bind(min_days)
+    .fetch_all(pool)
+    .await
+}
+
+/// Sums approved annual leave starting within `from..=to` that has not been reversed.
+///
+/// Leave taken in hours is left out, since it does not count toward the mandatory days.
+pub async fn sum_annual_leave_taken(
+    pool: &PgPool,
+    user_id: UserId,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<f64, sqlx::Error> {
+    let (days,): (f64,) = sqlx::query_as(
+        "SELECT COALESCE(SUM(-c.days), 0) \
+         FROM leave_balance_entries c \
+         LEFT JOIN leave_requests l ON l.id = c.leave_request_id \
+         WHERE c.user_id = $1 AND c.leave_type = 'annual' AND c.entry_type = 'consumption' \
+           AND c.effective_date BETWEEN $2 AND $3 \
+           AND l.unit IS DISTINCT FROM 'hours' \
+           AND NOT EXISTS ( \
+               SELECT 1 FROM leave_balance_entries r \
+               WHERE r.entry_type = 'reversal' AND r.leave_request_id = c.leave_request_id \
+           )",
+    )
+    .bind(user_id)
+    .bind(from)
+    .bind(to)
+    .fetch_one(pool)
+    .await?;
+    Ok(days)
+}
+
+/// Records that the reminder for a grant and threshold was sent.
+///
+/// Returns zero when it had already been recorded.
+#[allow(dead_code)]
+pub async fn record_notification(
+    pool: &PgPool,
+    grant_entry_id: LeaveBalanceEntryId,
+    user_id: UserId,
+    days_before_deadline: i32,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        "INSERT INTO leave_compliance_notifications \
+            (id, grant_entry_id, user_id, days_before_deadline) \
+         VALUES ($1, $2, $3, $4) \
+         ON CONFLICT (grant_entry_id, days_before_deadline) DO NOTHING",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(grant_entry_id)
+    .bind(user_id)
+    .bind(days_before_deadline)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// Returns true if the reminder for a grant and threshold has already been sent.
+#[allow(dead_code)]
+pub async fn notification_sent(
+    pool: &PgPool,
+    grant_entry_id: LeaveBalanceEntryId,
+    days_before_deadline: i32,
+) -> Result<bool, sqlx::Error> {
+    let (exists,): (bool,) = sqlx::query_as(
+        "SELECT EXISTS( \
+            SELECT 1 FROM leave_compliance_notifications \
+            WHERE grant_entry_id = $1 AND days_before_deadline = $2 \
+         )",
+    )
+    .bind(grant_entry_id)
+    .bind(days_before_deadline)
+    .fetch_one(pool)
+    .await?;
+    Ok(exists)
+}
@@ -5,10 +5,11 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDate, Utc};
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres};
 
 use crate::error::AppError;
 use crate::models::leave_request::LeaveRequest;
+use crate::models::request::RequestStatus;
 use crate::types::{LeaveRequestId, UserId};
 
 /// Repository trait for LeaveRequest operations.
@@ -103,7 +104,7 @@ impl LeaveRequestRepositoryTrait for LeaveRequestRepository {
     async fn find_all(&self, db: &PgPool) -> Result<Vec<LeaveRequest>, AppError> {
         let query = format!(
             "SELECT {} FROM {} ORDER BY start_date DESC LIMIT {}",
-            "id, user_id, leave_type, start_date, end_date, reason, status, \
+            "id, user_id, leave_type, start_date, end_date, unit, hours, reason, status, \
              approved_by, approved_at, rejected_by, rejected_at, cancelled_at, decision_comment, created_at, updated_at",
             "leave_requests",
             1_000
@@ -117,7 +118,7 @@ impl LeaveRequestRepositoryTrait for LeaveRequestRepository {
     async fn find_by_id(&self, db: &PgPool, id: LeaveRequestId) -> Result<LeaveRequest, AppError> {
         let query = format!(
             "SELECT {} FROM {} WHERE id = $1",
-            "id, user_id, leave_type, start_date, end_date, reason, status, \
+            "id, user_id, leave_type, start_date, end_date, unit, hours, reason, status, \
              approved_by, approved_at, rejected_by, rejected_at, cancelled_at, decision_comment, created_at, updated_at",
             "leave_requests"
         );
@@ -130,27 +131,20 @@ impl LeaveRequestRepositoryTrait for LeaveRequestRepository {
     }
 
     async fn create(&self, db: &PgPool, item: &LeaveRequest) -> Result<LeaveRequest, AppError> {
-        use crate::models::leave_request::LeaveType;
-        use crate::models::request::RequestStatus;
-
         let query = format!(
             "INSERT INTO {} (id, user_id, leave_type, start_date, end_date, reason, status, \
-             approved_by, approved_at, decision_comment, rejected_by, rejected_at, cancelled_at, created_at, updated_at) \
-             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15) \
+             approved_by, approved_at, decision_comment, rejected_by, rejected_at, cancelled_at, created_at, updated_at, \
+             unit, hours) \
+             VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14, $15, $16, $17) \
              RETURNING {}",
             "leave_requests",
-            "id, user_id, leave_type, start_date, end_date, reason, status, \
+            "id, user_id, leave_type, start_date, end_date, unit, hours, reason, status, \
              approved_by, approved_at, rejected_by, rejected_at, cancelled_at, decision_comment, created_at, updated_at"
         );
         let row = sqlx::query_as::<_, LeaveRequest>(&query)
             .bind(item.id)
             .bind(item.user_id)
-            .bind(match item.leave_type {
-                LeaveType::Annual => "annual",
-                LeaveType::Sick => "sick",
-                LeaveType::Personal => "personal",
-                LeaveType::Other => "other",
-            })
+            .bind(item.leave_type.db_value())
             .bind(item.start_date)
             .bind(item.end_date)
             .bind(&item.reason)
@@ -168,33 +162,27 @@ impl LeaveRequestRepositoryTrait for LeaveRequestRepository {
             .bind(item.cancelled_at)
             .bind(item.created_at)
             .bind(item.updated_at)
+            .bind(item.unit.db_value())
+            .bind(item.hours)
             .fetch_one(db)
             .await?;
         Ok(row)
     }
 
     async fn update(&self, db: &PgPool, item: &LeaveRequest) -> Result<LeaveRequest, AppError> {
-        use crate::models::leave_request::LeaveType;
-        use crate::models::request::RequestStatus;
-
         let query = format!(
             "UPDATE {} SET user_id = $2, leave_type = $3, start_date = $4, end_date = $5, reason = $6, \
              status = $7, approved_by = $8, approved_at = $9, decision_comment = $10, rejected_by = $11, \
-             rejected_at = $12, cancelled_at = $13, updated_at = $14 WHERE id = $1 \
+             rejected_at = $12, cancelled_at = $13, updated_at = $14, unit = $15, hours = $16 WHERE id = $1 \
              RETURNING {}",
             "leave_requests",
-            "id, user_id, leave_type, start_date, end_date, reason, status, \
+            "id, user_id, leave_type, start_date, end_date, unit, hours, reason, status, \
              approved_by, approved_at, rejected_by, rejected_at, cancelled_at, decision_comment, created_at, updated_at"
         );
         let row = sqlx::query_as::<_, LeaveRequest>(&query)
             .bind(item.id)
             .bind(item.user_id)
-            .bind(match item.leave_type {
-                LeaveType::Annual => "annual",
-                LeaveType::Sick => "sick",
-                LeaveType::Personal => "personal",
-                LeaveType::Other => "other",
-            })
+            .bind(item.leave_type.db_value())
             .bind(item.start_date)
             .bind(item.end_date)
             .bind(&item.reason)
@@ -211,6 +199,8 @@ impl LeaveRequestRepositoryTrait for LeaveRequestRepository {
             .bind(item.rejected_at)
             .bind(item.cancelled_at)
             .bind(item.updated_at)
+            .bind(item.unit.db_value())
+            .bind(item.hours)
             .fetch_one(db)
             .await?;
         Ok(row)
@@ -229,7 +219,7 @@ impl LeaveRequestRepositoryTrait for LeaveRequestRepository {
     ) -> Result<Vec<LeaveRequest>, AppError> {
         let query = format!(
             "SELECT {} FROM {} WHERE user_id = $1 ORDER BY created_at DESC",
-            "id, user_id, leave_type, start_date, end_date, reason, status, \
+            "id, user_id, leave_type, start_date, end_date, unit, hours, reason, status, \
              approved_by, approved_at, rejected_by, rejected_at, cancelled_at, decision_comment, created_at, updated_at",
             "leave_requests"
         );
@@ -250,7 +240,7 @@ impl LeaveRequestRepositoryTrait for LeaveRequestRepository {
         let query = format!(
             "SELECT {} FROM {} WHERE user_id = $1 AND start_date >= $2 AND end_date <= $3 \
              ORDER BY start_date DESC",
-            "id, user_id, leave_type, start_date, end_date, reason, status, \
+            "id, user_id, leave_type, start_date, end_date, unit, hours, reason, status, \
              approved_by, approved_at, rejected_by, rejected_at, cancelled_at, decision_comment, created_at, updated_at",
             "leave_requests"
         );
@@ -271,7 +261,7 @@ impl LeaveRequestRepositoryTrait for LeaveRequestRepository {
     ) -> Result<Option<LeaveRequest>, AppError> {
         let query = format!(
             "SELECT {} FROM {} WHERE id = $1 AND user_id = $2",
-            "id, user_id, leave_type, start_date, end_date, reason, status, \
+            "id, user_id, leave_type, start_date, end_date, unit, hours, reason, status, \
              approved_by, approved_at, rejected_by, rejected_at, cancelled_at, decision_comment, created_at, updated_at",
             "leave_requests"
         );
@@ -290,8 +280,6 @@ impl LeaveRequestRepositoryTrait for LeaveRequestRepository {
         user_id: UserId,
         timestamp: DateTime<Utc>,
     ) -> Result<u64, AppError> {
-        use crate::models::request::RequestStatus;
-
         let query = format!(
             "UPDATE {} SET status = $1, cancelled_at = $2, updated_at = $3 \
              WHERE id = $4 AND user_id = $5 AND status = 'pending'",
@@ -316,23 +304,7 @@ impl LeaveRequestRepositoryTrait for LeaveRequestRepository {
         comment: &str,
         timestamp: DateTime<Utc>,
     ) -> Result<u64, AppError> {
-        use crate::models::request::RequestStatus;
-
-        let query = format!(
-            "UPDATE {} SET status = $1, approved_by = $2, approved_at = $3, decision_comment = $4, \
-             updated_at = $5 WHERE id = $6 AND status = 'pending'",
-            "leave_requests"
-        );
-        let result = sqlx::query(&query)
-            .bind(RequestStatus::Approved.db_value())
-            .bind(approver_id)
-            .bind(timestamp)
-            .bind(comment)
-            .bind(timestamp)
-            .bind(id)
-            .execute(db)
-            .await?;
-        Ok(result.rows_affected())
+        approve_leave_request_with_executor(db, id, approver_id, comment, timestamp).await
     }
 
     async fn reject(
@@ -343,8 +315,6 @@ impl LeaveRequestRepositoryTrait for LeaveRequestRepository {
         comment: &str,
         timestamp: DateTime<Utc>,
     ) -> Result<u64, AppError> {
-        use crate::models::request::RequestStatus;
-
         let query = format!(
             "UPDATE {} SET status = $1, rejected_by = $2, rejected_at = $3, decision_comment = $4, \
              updated_at = $5 WHERE id = $6 AND status = 'pending'",
@@ -363,6 +333,61 @@ impl LeaveRequestRepositoryTrait for LeaveRequestRepository {
     }
 }
 
+/// Approves a pending leave request using the given executor.
+///
+/// Exposed separately from the trait so callers can approve inside a transaction.
+pub async fn approve_leave_request_with_executor<'e, E>(
+    executor: E,
+    id: LeaveRequestId,
+    approver_id: UserId,
+    comment: &str,
+    timestamp: DateTime<Utc>,
+) -> Result<u64, AppError>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let result = sqlx::query(
+        "UPDATE leave_requests SET status = $1, approved_by = $2, approved_at = $3, \
+         decision_comment = $4, updated_at = $5 WHERE id = $6 AND status = 'pending'",
+    )
+    .bind(RequestStatus::Approved.db_value())
+    .bind(approver_id)
+    .bind(timestamp)
+    .bind(comment)
+    .bind(timestamp)
+    .bind(id)
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// Cancels an already approved leave request owned by `user_id`.
+///
+/// Pending requests go through [`LeaveRequestRepositoryTrait::cancel`]; this variant exists
+/// so that the balance ledger can be reversed in the same transaction.
+pub async fn cancel_approved_leave_request_with_executor<'e, E>(
+    executor: E,
+    id: LeaveRequestId,
+    user_id: UserId,
+    timestamp: DateTime<Utc>,
+) -> Result<u64, AppError>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let result = sqlx::query(
+        "UPDATE leave_requests SET status = $1, cancelled_at = $2, updated_at = $3 \
+         WHERE id = $4 AND user_id = $5 AND status = 'approved'",
+    )
+    .bind(RequestStatus::Cancelled.db_value())
+    .bind(timestamp)
+    .bind(timestamp)
+    .bind(id)
+    .bind(user_id)
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,125 @@
+//! Repository functions for the leave type catalogue.
+
+use sqlx::{PgPool, Postgres};
+
+use crate::models::leave_request::LeaveType;
+use crate::models::leave_type::{LeaveTypeDefinition, UpdateLeaveTypePayload};
+
+const LEAVE_TYPE_COLUMNS: &str = "code, name, paid, balance_tracked, is_active, sort_order, \
+     created_at, updated_at";
+
+/// Lists every leave type, including inactive ones, in display order.
+pub async fn list_all(pool: &PgPool) -> Result<Vec<LeaveTypeDefinition>, sqlx::Error> {
+    let query = format!("SELECT {LEAVE_TYPE_COLUMNS} FROM leave_types ORDER BY sort_order, code");
+    sqlx::query_as::<_, LeaveTypeDefinition>(&query)
+        .fetch_all(pool)
+        .await
+}
+
+/// Lists the leave types employees may currently request, in display order.
+pub async fn list_active(pool: &PgPool) -> Result<Vec<LeaveTypeDefinition>, sqlx::Error> {
+    let query = format!(
+        "SELECT {LEAVE_TYPE_COLUMNS} FROM leave_types WHERE is_active \
+         ORDER BY sort_order, code"
+    );
+    sqlx::query_as::<_, LeaveTypeDefinition>(&query)
+        .fetch_all(pool)
+        .await
+}
+
+/// Lists the codes of leave types whose balance is tracked in the ledger.
+pub async fn list_balance_tracked_codes(pool: &PgPool) -> Result<Vec<LeaveType>, sqlx::Error> {
+    sqlx::query_scalar::<_, LeaveType>(
+        "SELECT code FROM leave_types WHERE balance_tracked ORDER BY sort_order, code",
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// Finds a leave type by code.
+pub async fn find_by_code<'e, E>(
+    executor: E,
+    code: &LeaveType,
+) -> Result<Option<LeaveTypeDefinition>, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let query = format!("SELECT {LEAVE_TYPE_COLUMNS} FROM leave_types WHERE code = $1");
+    sqlx::query_as::<_, LeaveTypeDefinition>(&query)
+        .bind(code.db_value())
+        .fetch_optional(executor)
+        .await
+}
+
+/// Inserts a leave type.
+pub async fn create(
+    pool: &PgPool,
+    definition: &LeaveTypeDefinition,
+) -> Result<LeaveTypeDefinition, sqlx::Error> {
+    let query = format!(
+        "INSERT INTO leave_types ({LEAVE_TYPE_COLUMNS}) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8) \
+         RETURNING {LEAVE_TYPE_COLUMNS}"
+    );
+    sqlx::query_as::<_, LeaveTypeDefinition>(&query)
+        .bind(definition.code.db_value())
+        .bind(&definition.name)
+        .bind(definition.paid)
+        .bind(definition.balance_tracked)
+        .bind(definition.is_active)
+        .bind(definition.sort_order)
+        .bind(definition.created_at)
+        .bind(definition.updated_at)
+        .fetch_one(pool)
+        .await
+}
+
+/// Applies the provided fields to a leave type; returns `None` when it does not exist.
+pub async fn update(
+    pool: &PgPool,
+    code: &LeaveType,
+    payload: &UpdateLeaveTypePayload,
+) -> Result<Option<LeaveTypeDefinition>, sqlx::Error> {
+    let query = format!(
+        "UPDATE leave_types SET \
+            name = COALESCE($2, name), \
+            paid = COALESCE($3, paid), \
+            balance_tracked = COALESCE($4, balance_tracked), \
+            is_active = COALESCE($5, is_active), \
+            sort_order = COALESCE($6, sort_order), \
+            updated_at = NOW() \
+         WHERE code = $1 \
+         RETURNING {LEAVE_TYPE_COLUMNS}"
+    );
+    sqlx::query_as::<_, LeaveTypeDefinition>(&query)
+        .bind(code.db_value())
+        .bind(payload.name.as_deref())
+        .bind(payload.paid)
+        .bind(payload.balance_tracked)
+        .bind(payload.is_active)
+        .bind(payload.sort_order)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Deletes a leave type; returns the number of deleted rows.
+pub async fn delete(pool: &PgPool, code: &LeaveType) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM leave_types WHERE code = $1")
+        .bind(code.db_value())
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+/// Returns true if any leave request or ledger entry (including archived requests)
+/// references the code.
+pub async fn is_in_use(pool: &PgPool, code: &LeaveType) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS(SELECT 1 FROM leave_requests WHERE leave_type = $1) \
+             OR EXISTS(SELECT 1 FROM archived_leave_requests WHERE leave_type = $1) \
+             OR EXISTS(SELECT 1 FROM leave_balance_entries WHERE leave_type = $1)",
+    )
+    .bind(code.db_value())
+    .fetch_one(pool)
+    .await
+}
@@ -1,31 +1,52 @@
 #![allow(unused_imports)]
 
+pub mod absence;
 pub mod active_session;
 pub mod attendance;
 pub mod attendance_correction_request;
+pub mod attendance_report;
 pub mod attendance_repository;
+pub mod attendance_sweep;
 pub mod audit_log;
 pub mod auth;
+pub mod break_policy;
 pub mod break_record;
 pub mod common;
 pub mod consent_log;
 pub mod department;
+pub mod employment_profile;
+pub mod export_job;
+pub mod export_schedule;
+pub mod export_template;
+pub mod health_interview;
 pub mod holiday;
 pub mod holiday_exception;
 pub mod holiday_repository;
+pub mod leave_attendance;
+pub mod leave_balance;
+pub mod leave_compliance;
 pub mod leave_request;
 pub mod leave_request_repository;
+pub mod leave_type;
+pub mod overtime;
+pub mod overtime_limit;
 pub mod overtime_request;
 pub mod overtime_request_repository;
 pub mod password_reset;
+pub mod period_closing;
 pub mod permissions;
 pub mod repository;
 pub mod request;
+pub mod rest_day;
+pub mod rest_interval;
 pub mod subject_request;
+pub mod timesheet;
 pub mod transaction;
 pub mod user;
 pub mod user_repository;
 pub mod weekly_holiday;
+pub mod work_schedule;
+pub mod work_time;
 
 pub use active_session::*;
 pub use audit_log::*;
@@ -0,0 +1,31 @@
+//! Repository functions for the overtime reconciliation report.
+
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::types::UserId;
+
+/// Users who clocked in or had approved overtime between two dates, by username.
+pub async fn list_users_with_activity(
+    pool: &PgPool,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<(UserId, String)>, sqlx::Error> {
+    sqlx::query_as::<_, (UserId, String)>(
+        "SELECT u.id, u.username FROM users u \
+         WHERE EXISTS ( \
+                 SELECT 1 FROM attendance a \
+                 WHERE a.user_id = u.id AND a.date BETWEEN $1 AND $2 \
+                   AND a.clock_in_time IS NOT NULL \
+             ) \
+            OR EXISTS ( \
+                 SELECT 1 FROM overtime_requests o \
+                 WHERE o.user_id = u.id AND o.date BETWEEN $1 AND $2 AND o.status = 'approved' \
+             ) \
+         ORDER BY u.username",
+    )
+    .bind(from)
+    .bind(to)
+    .fetch_all(pool)
+    .await
+}
@@ -0,0 +1,179 @@
+//! Repository functions for Article 36 overtime limits and their warning log.
+
+use chrono::NaiveDate;
+use sqlx::{PgPool, Postgres};
+use uuid::Uuid;
+
+use crate::models::overtime_limit::{OvertimeLimit, OvertimeLimitKind, OvertimeLimitLevel};
+use crate::types::{DepartmentId, UserId};
+
+const LIMIT_COLUMNS: &str = "id, department_id, monthly_limit_hours, annual_limit_hours, \
+     special_clause_enabled, special_annual_limit_hours, special_monthly_cap_hours, \
+     special_average_limit_hours, special_months_per_year, warning_ratio, year_start_month, \
+     created_at, updated_at";
+
+/// Lists the company-wide limits first, then department limits.
+pub async fn list_limits(pool: &PgPool) -> Result<Vec<OvertimeLimit>, sqlx::Error> {
+    let query = format!(
+        "SELECT {LIMIT_COLUMNS} FROM overtime_limits \
+         ORDER BY department_id IS NOT NULL, department_id"
+    );
+    sqlx::query_as::<_, OvertimeLimit>(&query)
+        .fetch_all(pool)
+        .await
+}
+
+/// Fetches the limits of a department, or the company-wide limits when `department_id` is `None`.
+pub async fn find_limit(
+    pool: &PgPool,
+    department_id: Option<DepartmentId>,
+) -> Result<Option<OvertimeLimit>, sqlx::Error> {
+    let query = format!(
+        "SELECT {LIMIT_COLUMNS} FROM overtime_limits \
+         WHERE department_id IS NOT DISTINCT FROM $1"
+    );
+    sqlx::query_as::<_, OvertimeLimit>(&query)
+        .bind(department_id)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Limits that apply to a user: their department's if set, otherwise the company-wide ones.
+pub async fn find_limit_for_user(
+    pool: &PgPool,
+    user_id: UserId,
+) -> Result<Option<OvertimeLimit>, sqlx::Error> {
+    let query = format!(
+        "SELECT {LIMIT_COLUMNS} FROM overtime_limits l \
+         WHERE l.department_id IS NULL \
+            OR l.department_id = (SELECT u.department_id FROM users u WHERE u.id = $1) \
+         ORDER BY l.department_id IS NULL \
+         LIMIT 1"
+    );
+    sqlx::query_as::<_, OvertimeLimit>(&query)
+        .bind(user_id)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Inserts the limits, or overwrites the editable fields of an existing set.
+pub async fn save_limit(
+    pool: &PgPool,
+    limit: &OvertimeLimit,
+) -> Result<OvertimeLimit, sqlx::Error> {
+    let query = format!(
+        "INSERT INTO overtime_limits ({LIMIT_COLUMNS}) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13) \
+         ON CONFLICT (id) DO UPDATE SET \
+             monthly_limit_hours = EXCLUDED.monthly_limit_hours, \
+             annual_limit_hours = EXCLUDED.annual_limit_hours, \
+             special_clause_enabled = EXCLUDED.special_clause_enabled, \
+             special_annual_limit_hours = EXCLUDED.special_annual_limit_hours, \
+             special_monthly_cap_hours = EXCLUDED.special_monthly_cap_hours, \
+             special_average_limit_hours = EXCLUDED.special_average_limit_hours, \
+             special_months_per_year = EXCLUDED.special_months_per_year, \
+             warning_ratio = EXCLUDED.warning_ratio, \
+             year_start_month = EXCLUDED.year_start_month, \
+             updated_at = EXCLUDED.updated_at \
+         RETURNING {LIMIT_COLUMNS}"
+    );
+    sqlx::query_as::<_, OvertimeLimit>(&query)
+        .bind(limit.id)
+        .bind(limit.department_id)
+        .bind(limit.monthly_limit_hours)
+        .bind(limit.annual_limit_hours)
+        .bind(limit.special_clause_enabled)
+        .bind(limit.special_annual_limit_hours)
+        .bind(limit.special_monthly_cap_hours)
+        .bind(limit.special_average_limit_hours)
+        .bind(limit.special_months_per_year)
+        .bind(limit.warning_ratio)
+        .bind(limit.year_start_month)
+        .bind(limit.created_at)
+        .bind(limit.updated_at)
+        .fetch_one(pool)
+        .await
+}
+
+/// Removes a department's limits so the company-wide ones apply again.
+pub async fn delete_department_limit(
+    pool: &PgPool,
+    department_id: DepartmentId,
+) -> Result<bool, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM overtime_limits WHERE department_id = $1")
+        .bind(department_id)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected() > 0)
+}
+
+/// Planned hours of the user's approved overtime requests between two dates, per date, for
+/// dates not yet clocked in.
+///
+/// Once a day is worked its actual overtime counts instead.
+pub async fn list_approved_unworked_hours<'e, E>(
+    executor: E,
+    user_id: UserId,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<(NaiveDate, f64)>, sqlx::Error>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    sqlx::query_as::<_, (NaiveDate, f64)>(
+        "SELECT o.date, SUM(o.planned_hours)::DOUBLE PRECISION FROM overtime_requests o \
+         WHERE o.user_id = $1 AND o.status = 'approved' AND o.date BETWEEN $2 AND $3 \
+           AND NOT EXISTS (SELECT 1 FROM attendance a \
+                           WHERE a.user_id = o.user_id AND a.date = o.date \
+                             AND a.clock_in_time IS NOT NULL) \
+         GROUP BY o.date",
+    )
+    .bind(user_id)
+    .bind(from)
+    .bind(to)
+    .fetch_all(executor)
+    .await
+}
+
+/// Returns true if the warning was already sent for the user and month.
+pub async fn notification_sent(
+    pool: &PgPool,
+    user_id: UserId,
+    month: NaiveDate,
+    kind: OvertimeLimitKind,
+    level: OvertimeLimitLevel,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS(SELECT 1 FROM overtime_limit_notifications \
+         WHERE user_id = $1 AND month = $2 AND limit_kind = $3 AND level = $4)",
+    )
+    .bind(user_id)
+    .bind(month)
+    .bind(kind.as_str())
+    .bind(level.as_str())
+    .fetch_one(pool)
+    .await
+}
+
+/// Records a sent warning; returns 0 if it had already been recorded.
+pub async fn record_notification(
+    pool: &PgPool,
+    user_id: UserId,
+    month: NaiveDate,
+    kind: OvertimeLimitKind,
+    level: OvertimeLimitLevel,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        "INSERT INTO overtime_limit_notifications (id, user_id, month, limit_kind, level) \
+         VALUES ($1, $2, $3, $4, $5) \
+         ON CONFLICT (user_id, month, limit_kind, level) DO NOTHING",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(user_id)
+    .bind(month)
+    .bind(kind.as_str())
+    .bind(level.as_str())
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
@@ -5,10 +5,11 @@
 
 use async_trait::async_trait;
 use chrono::{DateTime, NaiveDate, Utc};
-use sqlx::PgPool;
+use sqlx::{PgPool, Postgres};
 
 use crate::error::AppError;
 use crate::models::overtime_request::OvertimeRequest;
+use crate::models::request::RequestStatus;
 use crate::types::{OvertimeRequestId, UserId};
 
 /// Repository trait for OvertimeRequest operations.
@@ -325,23 +326,7 @@ impl OvertimeRequestRepositoryTrait for OvertimeRequestRepository {
         comment: &str,
         timestamp: DateTime<Utc>,
     ) -> Result<u64, AppError> {
-        use crate::models::request::RequestStatus;
-
-        let query = format!(
-            "UPDATE {} SET status = $1, approved_by = $2, approved_at = $3, decision_comment = $4, \
-             updated_at = $5 WHERE id = $6 AND status = 'pending'",
-            "overtime_requests"
-        );
-        let result = sqlx::query(&query)
-            .bind(RequestStatus::Approved.db_value())
-            .bind(approver_id)
-            .bind(timestamp)
-            .bind(comment)
-            .bind(timestamp)
-            .bind(id)
-            .execute(db)
-            .await?;
-        Ok(result.rows_affected())
+        approve_overtime_request_with_executor(db, id, approver_id, comment, timestamp).await
     }
 
     async fn reject(
@@ -372,6 +357,34 @@ impl OvertimeRequestRepositoryTrait for OvertimeRequestRepository {
     }
 }
 
+/// Approves a pending overtime request using the given executor.
+///
+/// Exposed separately from the trait so callers can approve inside a transaction.
+pub async fn approve_overtime_request_with_executor<'e, E>(
+    executor: E,
+    id: OvertimeRequestId,
+    approver_id: UserId,
+    comment: &str,
+    timestamp: DateTime<Utc>,
+) -> Result<u64, AppError>
+where
+    E: sqlx::Executor<'e, Database = Postgres>,
+{
+    let result = sqlx::query(
+        "UPDATE overtime_requests SET status = $1, approved_by = $2, approved_at = $3, \
+         decision_comment = $4, updated_at = $5 WHERE id = $6 AND status = 'pending'",
+    )
+    .bind(RequestStatus::Approved.db_value())
+    .bind(approver_id)
+    .bind(timestamp)
+    .bind(comment)
+    .bind(timestamp)
+    .bind(id)
+    .execute(executor)
+    .await?;
+    Ok(result.rows_affected())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
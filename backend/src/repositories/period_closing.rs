@@ -0,0 +1,114 @@
+//! Repository functions for months closed for payroll.
+
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::models::period_closing::PeriodClosing;
+use crate::types::{DepartmentId, PeriodClosingId, UserId};
+
+const CLOSING_COLUMNS: &str = "id, period_month, department_id, closed_by, closed_at";
+
+/// The department of user `$1` and the departments above it, since closing a department
+/// also closes its sub-departments.
+const USER_DEPARTMENTS: &str = "WITH RECURSIVE user_departments AS ( \
+         SELECT d.id, d.parent_id FROM departments d \
+         JOIN users u ON u.department_id = d.id WHERE u.id = $1 \
+         UNION ALL \
+         SELECT p.id, p.parent_id FROM departments p \
+         JOIN user_departments ud ON p.id = ud.parent_id \
+     )";
+
+/// Lists closings, most recent month first, optionally restricted to a year or department.
+pub async fn list_closings(
+    pool: &PgPool,
+    year: Option<i32>,
+    department_id: Option<DepartmentId>,
+) -> Result<Vec<PeriodClosing>, sqlx::Error> {
+    let query = format!(
+        "SELECT {CLOSING_COLUMNS} FROM period_closings \
+         WHERE ($1::INTEGER IS NULL OR EXTRACT(YEAR FROM period_month) = $1) \
+           AND ($2::TEXT IS NULL OR department_id = $2) \
+         ORDER BY period_month DESC, department_id IS NOT NULL, department_id"
+    );
+    sqlx::query_as::<_, PeriodClosing>(&query)
+        .bind(year)
+        .bind(department_id)
+        .fetch_all(pool)
+        .await
+}
+
+/// Closings that lock the user's records: company-wide ones and those of their department
+/// or a department above it.
+pub async fn list_closings_for_user(
+    pool: &PgPool,
+    user_id: UserId,
+) -> Result<Vec<PeriodClosing>, sqlx::Error> {
+    let query = format!(
+        "{USER_DEPARTMENTS} \
+         SELECT {CLOSING_COLUMNS} FROM period_closings c \
+         WHERE c.department_id IS NULL \
+            OR c.department_id IN (SELECT id FROM user_departments) \
+         ORDER BY c.period_month DESC, c.department_id IS NOT NULL"
+    );
+    sqlx::query_as::<_, PeriodClosing>(&query)
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+}
+
+/// The earliest closing that locks any of the user's days between `from` and `to`.
+pub async fn find_closing_for_user(
+    pool: &PgPool,
+    user_id: UserId,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Option<PeriodClosing>, sqlx::Error> {
+    let query = format!(
+        "{USER_DEPARTMENTS} \
+         SELECT {CLOSING_COLUMNS} FROM period_closings c \
+         WHERE c.period_month BETWEEN date_trunc('month', $2::DATE)::DATE AND $3 \
+           AND (c.department_id IS NULL \
+                OR c.department_id IN (SELECT id FROM user_departments)) \
+         ORDER BY c.period_month, c.department_id IS NOT NULL \
+         LIMIT 1"
+    );
+    sqlx::query_as::<_, PeriodClosing>(&query)
+        .bind(user_id)
+        .bind(from)
+        .bind(to)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Inserts the closing; returns `None` when the month is already closed for that scope.
+pub async fn insert_closing(
+    pool: &PgPool,
+    closing: &PeriodClosing,
+) -> Result<Option<PeriodClosing>, sqlx::Error> {
+    let query = format!(
+        "INSERT INTO period_closings ({CLOSING_COLUMNS}) \
+         VALUES ($1, $2, $3, $4, $5) \
+         ON CONFLICT DO NOTHING \
+         RETURNING {CLOSING_COLUMNS}"
+    );
+    sqlx::query_as::<_, PeriodClosing>(&query)
+        .bind(closing.id)
+        .bind(closing.period_month)
+        .bind(closing.department_id)
+        .bind(closing.closed_by)
+        .bind(closing.closed_at)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Deletes a closing, re-opening its month.
+pub async fn delete_closing(
+    pool: &PgPool,
+    id: PeriodClosingId,
+) -> Result<Option<PeriodClosing>, sqlx::Error> {
+    let query = format!("DELETE FROM period_closings WHERE id = $1 RETURNING {CLOSING_COLUMNS}");
+    sqlx::query_as::<_, PeriodClosing>(&query)
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+}
@@ -55,17 +55,11 @@ pub enum RequestRecord {
     Overtime(OvertimeRequest),
 }
 
-/// Update operation for changing request status (approve or reject).
+/// Update operation for changing request status.
+///
+/// Approvals go through `LeaveBalanceService` and `OvertimeLimitService`, which check the
+/// leave balance and overtime limits in the same transaction.
 pub enum RequestStatusUpdate<'a> {
-    /// Approve a request with approver details and comment
-    Approve {
-        /// ID of the user approving the request
-        approver_id: UserId,
-        /// Decision comment explaining the approval
-        comment: &'a str,
-        /// Timestamp when the approval occurred
-        timestamp: DateTime<Utc>,
-    },
     /// Reject a request with approver details and comment
     Reject {
         /// ID of the user rejecting the request
@@ -212,7 +206,7 @@ impl RequestRepository {
     ///
     /// * `db` - Database connection pool
     /// * `request_id` - String representation of the request ID (leave or overtime)
-    /// * `update` - The status update to apply (approve or reject with metadata)
+    /// * `update` - The status update to apply (rejection with metadata)
     ///
     /// # Returns
     ///
@@ -231,15 +225,6 @@ impl RequestRepository {
         let leave_repo = LeaveRequestRepository::new();
         if let Ok(leave_request_id) = LeaveRequestId::from_str(request_id) {
             let affected = match &update {
-                RequestStatusUpdate::Approve {
-                    approver_id,
-                    comment,
-                    timestamp,
-                } => {
-                    leave_repo
-                        .approve(db, leave_request_id, *approver_id, comment, *timestamp)
-                        .await?
-                }
                 RequestStatusUpdate::Reject {
                     approver_id,
                     comment,
@@ -259,15 +244,6 @@ impl RequestRepository {
         let overtime_repo = OvertimeRequestRepository::new();
         if let Ok(overtime_request_id) = OvertimeRequestId::from_str(request_id) {
             let affected = match &update {
-                RequestStatusUpdate::Approve {
-                    approver_id,
-                    comment,
-                    timestamp,
-                } => {
-                    overtime_repo
-                        .approve(db, overtime_request_id, *approver_id, comment, *timestamp)
-                        .await?
-                }
                 RequestStatusUpdate::Reject {
                     approver_id,
                     comment,
@@ -295,7 +271,7 @@ async fn list_leave_requests(
     offset: i64,
 ) -> Result<Vec<LeaveRequest>, AppError> {
     let mut builder: QueryBuilder<Postgres> = QueryBuilder::new(
-        "SELECT id, user_id, leave_type, start_date, end_date, reason, status, approved_by, approved_at, rejected_by, rejected_at, cancelled_at, decision_comment, created_at, updated_at FROM leave_requests",
+        "SELECT id, user_id, leave_type, start_date, end_date, unit, hours, reason, status, approved_by, approved_at, rejected_by, rejected_at, cancelled_at, decision_comment, created_at, updated_at FROM leave_requests",
     );
     apply_request_filters(&mut builder, filters);
     builder
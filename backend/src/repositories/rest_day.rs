@@ -0,0 +1,102 @@
+//! Repository functions for consecutive worked days and the alerts sent about them.
+
+use chrono::NaiveDate;
+use sqlx::{FromRow, PgPool};
+
+use crate::types::{DepartmentId, UserId};
+
+/// A day on which an employee clocked in.
+#[derive(Debug, Clone, FromRow)]
+pub struct WorkedDay {
+    pub user_id: UserId,
+    pub username: String,
+    pub department_id: Option<DepartmentId>,
+    pub date: NaiveDate,
+}
+
+/// Lists days with a clock-in between two dates, by employee and date, optionally for one
+/// department.
+pub async fn list_worked_days(
+    pool: &PgPool,
+    from: NaiveDate,
+    to: NaiveDate,
+    department_id: Option<DepartmentId>,
+) -> Result<Vec<WorkedDay>, sqlx::Error> {
+    sqlx::query_as::<_, WorkedDay>(
+        "SELECT a.user_id, u.username, u.department_id, a.date \
+         FROM attendance a \
+         JOIN users u ON u.id = a.user_id \
+         WHERE a.date BETWEEN $1 AND $2 AND a.clock_in_time IS NOT NULL \
+           AND ($3::TEXT IS NULL OR u.department_id = $3) \
+         ORDER BY u.username, a.user_id, a.date",
+    )
+    .bind(from)
+    .bind(to)
+    .bind(department_id)
+    .fetch_all(pool)
+    .await
+}
+
+/// First day of the run of consecutive worked days that includes `date`, if it was worked.
+pub async fn find_run_start(
+    pool: &PgPool,
+    user_id: UserId,
+    date: NaiveDate,
+) -> Result<Option<NaiveDate>, sqlx::Error> {
+    sqlx::query_scalar(
+        "SELECT MAX(a.date) FROM attendance a \
+         WHERE a.user_id = $1 AND a.date <= $2 AND a.clock_in_time IS NOT NULL \
+           AND NOT EXISTS ( \
+               SELECT 1 FROM attendance p \
+               WHERE p.user_id = a.user_id AND p.date = a.date - 1 \
+                 AND p.clock_in_time IS NOT NULL \
+           )",
+    )
+    .bind(user_id)
+    .bind(date)
+    .fetch_one(pool)
+    .await
+}
+
+/// Records that managers were alerted about the run starting on `start_date`.
+///
+/// Returns zero when it had already been recorded.
+#[allow(dead_code)]
+pub async fn record_notification(
+    pool: &PgPool,
+    user_id: UserId,
+    start_date: NaiveDate,
+    days: i64,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query(
+        "INSERT INTO consecutive_workday_notifications (user_id, start_date, days) \
+         VALUES ($1, $2, $3) \
+         ON CONFLICT (user_id, start_date) DO NOTHING",
+    )
+    .bind(user_id)
+    .bind(start_date)
+    .bind(days as i32)
+    .execute(pool)
+    .await?;
+    Ok(result.rows_affected())
+}
+
+/// Returns true if managers were already alerted about the run starting on `start_date`.
+#[allow(dead_code)]
+pub async fn notification_sent(
+    pool: &PgPool,
+    user_id: UserId,
+    start_date: NaiveDate,
+) -> Result<bool, sqlx::Error> {
+    let (exists,): (bool,) = sqlx::query_as(
+        "SELECT EXISTS( \
+            SELECT 1 FROM consecutive_workday_notifications \
+            WHERE user_id = $1 AND start_date = $2 \
+         )",
+    )
+    .bind(user_id)
+    .bind(start_date)
+    .fetch_one(pool)
+    .await?;
+    Ok(exists)
+}
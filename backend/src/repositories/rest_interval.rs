@@ -0,0 +1,112 @@
+//! Repository functions for the rest interval policy and recorded rest violations.
+
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::PgPool;
+
+use crate::models::rest_interval::{RestIntervalPolicy, RestIntervalViolation, RestShortfall};
+use crate::types::{DepartmentId, UserId};
+
+const POLICY_COLUMNS: &str = "mode, minimum_rest_minutes, updated_at";
+
+/// Fetches the company-wide policy, if one was saved.
+pub async fn find_policy(pool: &PgPool) -> Result<Option<RestIntervalPolicy>, sqlx::Error> {
+    let query = format!("SELECT {POLICY_COLUMNS} FROM rest_interval_policy");
+    sqlx::query_as::<_, RestIntervalPolicy>(&query)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Inserts or overwrites the company-wide policy.
+pub async fn save_policy(
+    pool: &PgPool,
+    policy: &RestIntervalPolicy,
+) -> Result<RestIntervalPolicy, sqlx::Error> {
+    let query = format!(
+        "INSERT INTO rest_interval_policy (id, {POLICY_COLUMNS}) \
+         VALUES (TRUE, $1, $2, $3) \
+         ON CONFLICT (id) DO UPDATE SET \
+             mode = EXCLUDED.mode, \
+             minimum_rest_minutes = EXCLUDED.minimum_rest_minutes, \
+             updated_at = EXCLUDED.updated_at \
+         RETURNING {POLICY_COLUMNS}"
+    );
+    sqlx::query_as::<_, RestIntervalPolicy>(&query)
+        .bind(policy.mode)
+        .bind(policy.minimum_rest_minutes)
+        .bind(policy.updated_at)
+        .fetch_one(pool)
+        .await
+}
+
+/// The latest clock-out of the user's records before `date`.
+pub async fn find_previous_clock_out(
+    pool: &PgPool,
+    user_id: UserId,
+    date: NaiveDate,
+) -> Result<Option<NaiveDateTime>, sqlx::Error> {
+    sqlx::query_scalar(
+        "SELECT MAX(clock_out_time) FROM attendance \
+         WHERE user_id = $1 AND date < $2 AND clock_out_time IS NOT NULL",
+    )
+    .bind(user_id)
+    .bind(date)
+    .fetch_one(pool)
+    .await
+}
+
+/// Records a short rest before the user's clock-in on `date`, replacing an earlier entry.
+pub async fn save_violation(
+    pool: &PgPool,
+    user_id: UserId,
+    date: NaiveDate,
+    clock_in: NaiveDateTime,
+    shortfall: &RestShortfall,
+    blocked: bool,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO rest_interval_violations \
+             (user_id, date, previous_clock_out, clock_in, rest_minutes, \
+              required_rest_minutes, blocked, detected_at) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, NOW()) \
+         ON CONFLICT (user_id, date) DO UPDATE SET \
+             previous_clock_out = EXCLUDED.previous_clock_out, \
+             clock_in = EXCLUDED.clock_in, \
+             rest_minutes = EXCLUDED.rest_minutes, \
+             required_rest_minutes = EXCLUDED.required_rest_minutes, \
+             blocked = EXCLUDED.blocked, \
+             detected_at = EXCLUDED.detected_at",
+    )
+    .bind(user_id)
+    .bind(date)
+    .bind(shortfall.previous_clock_out)
+    .bind(clock_in)
+    .bind(shortfall.rest_minutes as i32)
+    .bind(shortfall.required_rest_minutes as i32)
+    .bind(blocked)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Lists violations between two dates, most recent first, optionally for one department.
+pub async fn list_violations(
+    pool: &PgPool,
+    from: NaiveDate,
+    to: NaiveDate,
+    department_id: Option<DepartmentId>,
+) -> Result<Vec<RestIntervalViolation>, sqlx::Error> {
+    sqlx::query_as::<_, RestIntervalViolation>(
+        "SELECT v.user_id, u.username, u.department_id, v.date, v.previous_clock_out, \
+                v.clock_in, v.rest_minutes, v.required_rest_minutes, v.blocked, v.detected_at \
+         FROM rest_interval_violations v \
+         JOIN users u ON u.id = v.user_id \
+         WHERE v.date BETWEEN $1 AND $2 \
+           AND ($3::TEXT IS NULL OR u.department_id = $3) \
+         ORDER BY v.date DESC, u.username",
+    )
+    .bind(from)
+    .bind(to)
+    .bind(department_id)
+    .fetch_all(pool)
+    .await
+}
@@ -0,0 +1,160 @@
+//! Repository functions for monthly timesheet submissions.
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+
+use crate::models::timesheet::{
+    TimesheetBlockers, TimesheetStatus, TimesheetSubmission, TimesheetSubmissionListItem,
+};
+use crate::types::{TimesheetSubmissionId, UserId};
+
+const SUBMISSION_COLUMNS: &str = "id, user_id, period_month, status, submitted_at, decided_by, \
+     decided_at, decision_comment, created_at, updated_at";
+
+pub async fn find_submission(
+    pool: &PgPool,
+    id: TimesheetSubmissionId,
+) -> Result<Option<TimesheetSubmission>, sqlx::Error> {
+    let query = format!("SELECT {SUBMISSION_COLUMNS} FROM timesheet_submissions WHERE id = $1");
+    sqlx::query_as::<_, TimesheetSubmission>(&query)
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+}
+
+pub async fn find_submission_for_month(
+    pool: &PgPool,
+    user_id: UserId,
+    period_month: NaiveDate,
+) -> Result<Option<TimesheetSubmission>, sqlx::Error> {
+    let query = format!(
+        "SELECT {SUBMISSION_COLUMNS} FROM timesheet_submissions \
+         WHERE user_id = $1 AND period_month = $2"
+    );
+    sqlx::query_as::<_, TimesheetSubmission>(&query)
+        .bind(user_id)
+        .bind(period_month)
+        .fetch_optional(pool)
+        .await
+}
+
+/// The user's submissions, most recent month first.
+pub async fn list_submissions_for_user(
+    pool: &PgPool,
+    user_id: UserId,
+) -> Result<Vec<TimesheetSubmission>, sqlx::Error> {
+    let query = format!(
+        "SELECT {SUBMISSION_COLUMNS} FROM timesheet_submissions \
+         WHERE user_id = $1 ORDER BY period_month DESC"
+    );
+    sqlx::query_as::<_, TimesheetSubmission>(&query)
+        .bind(user_id)
+        .fetch_all(pool)
+        .await
+}
+
+/// Submissions for approvers, optionally limited to the given users.
+pub async fn list_submissions(
+    pool: &PgPool,
+    status: Option<TimesheetStatus>,
+    period_month: Option<NaiveDate>,
+    year: Option<i32>,
+    allowed_user_ids: Option<Vec<String>>,
+) -> Result<Vec<TimesheetSubmissionListItem>, sqlx::Error> {
+    sqlx::query_as::<_, TimesheetSubmissionListItem>(
+        "SELECT t.id, t.user_id, t.period_month, t.status, t.submitted_at, t.decided_by, \
+                t.decided_at, t.decision_comment, t.created_at, t.updated_at, \
+                u.username \
+         FROM timesheet_submissions t \
+         JOIN users u ON u.id = t.user_id \
+         WHERE ($1::TEXT IS NULL OR t.status = $1) \
+           AND ($2::DATE IS NULL OR t.period_month = $2) \
+           AND ($3::INTEGER IS NULL OR EXTRACT(YEAR FROM t.period_month) = $3) \
+           AND ($4::TEXT[] IS NULL OR t.user_id = ANY($4)) \
+         ORDER BY t.period_month DESC, t.submitted_at",
+    )
+    .bind(status.map(|status| status.db_value()))
+    .bind(period_month)
+    .bind(year)
+    .bind(allowed_user_ids)
+    .fetch_all(pool)
+    .await
+}
+
+/// Records a submission for a draft or returned month; returns `None` when the month
+/// is already submitted or approved.
+pub async fn submit(
+    pool: &PgPool,
+    submission: &TimesheetSubmission,
+) -> Result<Option<TimesheetSubmission>, sqlx::Error> {
+    let query = format!(
+        "INSERT INTO timesheet_submissions ({SUBMISSION_COLUMNS}) \
+         VALUES ($1, $2, $3, 'submitted', $4, NULL, NULL, NULL, $4, $4) \
+         ON CONFLICT (user_id, period_month) DO UPDATE \
+            SET status = 'submitted', submitted_at = EXCLUDED.submitted_at, \
+                decided_by = NULL, decided_at = NULL, decision_comment = NULL, \
+                updated_at = EXCLUDED.updated_at \
+          WHERE timesheet_submissions.status = 'returned' \
+         RETURNING {SUBMISSION_COLUMNS}"
+    );
+    sqlx::query_as::<_, TimesheetSubmission>(&query)
+        .bind(submission.id)
+        .bind(submission.user_id)
+        .bind(submission.period_month)
+        .bind(submission.submitted_at)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Approves or returns a submitted timesheet; returns `None` when it is no longer
+/// waiting for a decision.
+pub async fn decide(
+    pool: &PgPool,
+    id: TimesheetSubmissionId,
+    status: TimesheetStatus,
+    decided_by: UserId,
+    comment: Option<&str>,
+    decided_at: DateTime<Utc>,
+) -> Result<Option<TimesheetSubmission>, sqlx::Error> {
+    let query = format!(
+        "UPDATE timesheet_submissions \
+         SET status = $2, decided_by = $3, decided_at = $4, decision_comment = $5, \
+             updated_at = $4 \
+         WHERE id = $1 AND status = 'submitted' \
+         RETURNING {SUBMISSION_COLUMNS}"
+    );
+    sqlx::query_as::<_, TimesheetSubmission>(&query)
+        .bind(id)
+        .bind(status.db_value())
+        .bind(decided_by)
+        .bind(decided_at)
+        .bind(comment)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Counts the pending correction requests and open clock-ins between `from` and `to`.
+pub async fn count_blockers(
+    pool: &PgPool,
+    user_id: UserId,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<TimesheetBlockers, sqlx::Error> {
+    let (pending_corrections, open_clock_ins): (i64, i64) = sqlx::query_as(
+        "SELECT \
+            (SELECT COUNT(*) FROM attendance_correction_requests \
+              WHERE user_id = $1 AND status = 'pending' AND date BETWEEN $2 AND $3), \
+            (SELECT COUNT(*) FROM attendance \
+              WHERE user_id = $1 AND date BETWEEN $2 AND $3 \
+                AND clock_in_time IS NOT NULL AND clock_out_time IS NULL)",
+    )
+    .bind(user_id)
+    .bind(from)
+    .bind(to)
+    .fetch_one(pool)
+    .await?;
+    Ok(TimesheetBlockers {
+        pending_corrections,
+        open_clock_ins,
+    })
+}
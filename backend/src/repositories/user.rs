@@ -271,8 +271,8 @@ pub async fn soft_delete_user(
     // 1. Archive break records (via attendance)
     sqlx::query(
         r#"
-        INSERT INTO archived_break_records (id, attendance_id, break_start_time, break_end_time, duration_minutes, created_at, updated_at, archived_at)
-        SELECT br.id, br.attendance_id, br.break_start_time, br.break_end_time, br.duration_minutes, br.created_at, br.updated_at, $2
+        INSERT INTO archived_break_records (id, attendance_id, break_start_time, break_end_time, duration_minutes, is_automatic, created_at, updated_at, archived_at)
+        SELECT br.id, br.attendance_id, br.break_start_time, br.break_end_time, br.duration_minutes, br.is_automatic, br.created_at, br.updated_at, $2
         FROM break_records br
         INNER JOIN attendance a ON br.attendance_id = a.id
         WHERE a.user_id = $1
@@ -301,12 +301,12 @@ pub async fn soft_delete_user(
     sqlx::query(
         r#"
         INSERT INTO archived_leave_requests (
-            id, user_id, leave_type, start_date, end_date, reason, status,
+            id, user_id, leave_type, start_date, end_date, unit, hours, reason, status,
             approved_by, approved_at, decision_comment, rejected_by, rejected_at, cancelled_at,
             created_at, updated_at, archived_at
         )
         SELECT
-            id, user_id, leave_type, start_date, end_date, reason, status,
+            id, user_id, leave_type, start_date, end_date, unit, hours, reason, status,
             approved_by, approved_at, decision_comment, rejected_by, rejected_at, cancelled_at,
             created_at, updated_at, $2
         FROM leave_requests
@@ -480,8 +480,8 @@ pub async fn restore_user(pool: &PgPool, user_id: &str) -> Result<(), AppError>
     // 3. Restore break records
     sqlx::query(
         r#"
-        INSERT INTO break_records (id, attendance_id, break_start_time, break_end_time, duration_minutes, created_at, updated_at)
-        SELECT abr.id, abr.attendance_id, abr.break_start_time, abr.break_end_time, abr.duration_minutes, abr.created_at, abr.updated_at
+        INSERT INTO break_records (id, attendance_id, break_start_time, break_end_time, duration_minutes, is_automatic, created_at, updated_at)
+        SELECT abr.id, abr.attendance_id, abr.break_start_time, abr.break_end_time, abr.duration_minutes, abr.is_automatic, abr.created_at, abr.updated_at
         FROM archived_break_records abr
         INNER JOIN archived_attendance aa ON abr.attendance_id = aa.id
         WHERE aa.user_id = $1
@@ -495,12 +495,12 @@ pub async fn restore_user(pool: &PgPool, user_id: &str) -> Result<(), AppError>
     sqlx::query(
         r#"
         INSERT INTO leave_requests (
-            id, user_id, leave_type, start_date, end_date, reason, status,
+            id, user_id, leave_type, start_date, end_date, unit, hours, reason, status,
             approved_by, approved_at, decision_comment, rejected_by, rejected_at, cancelled_at,
             created_at, updated_at
         )
         SELECT
-            id, user_id, leave_type, start_date, end_date, reason, status,
+            id, user_id, leave_type, start_date, end_date, unit, hours, reason, status,
             approved_by, approved_at, decision_comment, rejected_by, rejected_at, cancelled_at,
             created_at, updated_at
         FROM archived_leave_requests
@@ -0,0 +1,211 @@
+//! Repository functions for work schedules and their assignments.
+
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::models::work_schedule::{WorkSchedule, WorkScheduleAssignment};
+use crate::types::{DepartmentId, UserId, WorkScheduleAssignmentId, WorkScheduleId};
+
+const SCHEDULE_COLUMNS: &str = "id, name, start_time, end_time, break_minutes, \
+     working_weekdays, late_grace_minutes, kind, core_start_time, core_end_time, \
+     settlement_months, settlement_start_month, created_at, updated_at";
+
+const ASSIGNMENT_COLUMNS: &str =
+    "id, schedule_id, user_id, department_id, effective_from, effective_to, created_at";
+
+/// Lists all work schedules ordered by name.
+pub async fn list_schedules(pool: &PgPool) -> Result<Vec<WorkSchedule>, sqlx::Error> {
+    let query = format!("SELECT {SCHEDULE_COLUMNS} FROM work_schedules ORDER BY name");
+    sqlx::query_as::<_, WorkSchedule>(&query)
+        .fetch_all(pool)
+        .await
+}
+
+/// Fetches a work schedule by ID.
+pub async fn find_schedule(
+    pool: &PgPool,
+    id: WorkScheduleId,
+) -> Result<Option<WorkSchedule>, sqlx::Error> {
+    let query = format!("SELECT {SCHEDULE_COLUMNS} FROM work_schedules WHERE id = $1");
+    sqlx::query_as::<_, WorkSchedule>(&query)
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Returns true if another schedule already uses `name`.
+pub async fn schedule_name_taken(
+    pool: &PgPool,
+    name: &str,
+    exclude: Option<WorkScheduleId>,
+) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS(SELECT 1 FROM work_schedules \
+         WHERE name = $1 AND ($2::TEXT IS NULL OR id <> $2))",
+    )
+    .bind(name)
+    .bind(exclude)
+    .fetch_one(pool)
+    .await
+}
+
+/// Inserts a work schedule.
+pub async fn create_schedule(
+    pool: &PgPool,
+    schedule: &WorkSchedule,
+) -> Result<WorkSchedule, sqlx::Error> {
+    let query = format!(
+        "INSERT INTO work_schedules ({SCHEDULE_COLUMNS}) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11, $12, $13, $14) \
+         RETURNING {SCHEDULE_COLUMNS}"
+    );
+    sqlx::query_as::<_, WorkSchedule>(&query)
+        .bind(schedule.id)
+        .bind(&schedule.name)
+        .bind(schedule.start_time)
+        .bind(schedule.end_time)
+        .bind(schedule.break_minutes)
+        .bind(&schedule.working_weekdays)
+        .bind(schedule.late_grace_minutes)
+        .bind(schedule.kind)
+        .bind(schedule.core_start_time)
+        .bind(schedule.core_end_time)
+        .bind(schedule.settlement_months)
+        .bind(schedule.settlement_start_month)
+        .bind(schedule.created_at)
+        .bind(schedule.updated_at)
+        .fetch_one(pool)
+        .await
+}
+
+/// Overwrites the editable fields of a work schedule.
+pub async fn update_schedule(
+    pool: &PgPool,
+    schedule: &WorkSchedule,
+) -> Result<WorkSchedule, sqlx::Error> {
+    let query = format!(
+        "UPDATE work_schedules SET name = $2, start_time = $3, end_time = $4, \
+            break_minutes = $5, working_weekdays = $6, late_grace_minutes = $7, \
+            kind = $8, core_start_time = $9, core_end_time = $10, settlement_months = $11, \
+            settlement_start_month = $12, updated_at = $13 \
+         WHERE id = $1 \
+         RETURNING {SCHEDULE_COLUMNS}"
+    );
+    sqlx::query_as::<_, WorkSchedule>(&query)
+        .bind(schedule.id)
+        .bind(&schedule.name)
+        .bind(schedule.start_time)
+        .bind(schedule.end_time)
+        .bind(schedule.break_minutes)
+        .bind(&schedule.working_weekdays)
+        .bind(schedule.late_grace_minutes)
+        .bind(schedule.kind)
+        .bind(schedule.core_start_time)
+        .bind(schedule.core_end_time)
+        .bind(schedule.settlement_months)
+        .bind(schedule.settlement_start_month)
+        .bind(schedule.updated_at)
+        .fetch_one(pool)
+        .await
+}
+
+/// Deletes a work schedule; returns the number of deleted rows.
+pub async fn delete_schedule(pool: &PgPool, id: WorkScheduleId) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM work_schedules WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+/// Returns true if any assignment references the schedule.
+pub async fn schedule_is_assigned(pool: &PgPool, id: WorkScheduleId) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar::<_, bool>(
+        "SELECT EXISTS(SELECT 1 FROM work_schedule_assignments WHERE schedule_id = $1)",
+    )
+    .bind(id)
+    .fetch_one(pool)
+    .await
+}
+
+/// Lists assignments, optionally narrowed to a user or a department, newest first.
+pub async fn list_assignments(
+    pool: &PgPool,
+    user_id: Option<UserId>,
+    department_id: Option<DepartmentId>,
+) -> Result<Vec<WorkScheduleAssignment>, sqlx::Error> {
+    let query = format!(
+        "SELECT {ASSIGNMENT_COLUMNS} FROM work_schedule_assignments \
+         WHERE ($1::TEXT IS NULL OR user_id = $1) \
+           AND ($2::TEXT IS NULL OR department_id = $2) \
+         ORDER BY effective_from DESC, created_at DESC"
+    );
+    sqlx::query_as::<_, WorkScheduleAssignment>(&query)
+        .bind(user_id)
+        .bind(department_id)
+        .fetch_all(pool)
+        .await
+}
+
+/// Inserts a schedule assignment.
+pub async fn create_assignment(
+    pool: &PgPool,
+    assignment: &WorkScheduleAssignment,
+) -> Result<WorkScheduleAssignment, sqlx::Error> {
+    let query = format!(
+        "INSERT INTO work_schedule_assignments ({ASSIGNMENT_COLUMNS}) \
+         VALUES ($1, $2, $3, $4, $5, $6, $7) \
+         RETURNING {ASSIGNMENT_COLUMNS}"
+    );
+    sqlx::query_as::<_, WorkScheduleAssignment>(&query)
+        .bind(assignment.id)
+        .bind(assignment.schedule_id)
+        .bind(assignment.user_id)
+        .bind(assignment.department_id)
+        .bind(assignment.effective_from)
+        .bind(assignment.effective_to)
+        .bind(assignment.created_at)
+        .fetch_one(pool)
+        .await
+}
+
+/// Deletes a schedule assignment; returns the number of deleted rows.
+pub async fn delete_assignment(
+    pool: &PgPool,
+    id: WorkScheduleAssignmentId,
+) -> Result<u64, sqlx::Error> {
+    let result = sqlx::query("DELETE FROM work_schedule_assignments WHERE id = $1")
+        .bind(id)
+        .execute(pool)
+        .await?;
+    Ok(result.rows_affected())
+}
+
+/// Resolves the schedule that applies to a user on `date`.
+///
+/// The user's own assignments take precedence over their department's; among those the
+/// latest `effective_from` wins.
+pub async fn find_effective_schedule(
+    pool: &PgPool,
+    user_id: UserId,
+    date: NaiveDate,
+) -> Result<Option<WorkSchedule>, sqlx::Error> {
+    sqlx::query_as::<_, WorkSchedule>(
+        "SELECT s.id, s.name, s.start_time, s.end_time, s.break_minutes, \
+                s.working_weekdays, s.late_grace_minutes, s.kind, s.core_start_time, \
+                s.core_end_time, s.settlement_months, s.settlement_start_month, \
+                s.created_at, s.updated_at \
+         FROM work_schedule_assignments a \
+         JOIN work_schedules s ON s.id = a.schedule_id \
+         JOIN users u ON u.id = $1 \
+         WHERE (a.user_id = u.id OR a.department_id = u.department_id) \
+           AND a.effective_from <= $2 \
+           AND (a.effective_to IS NULL OR a.effective_to >= $2) \
+         ORDER BY (a.user_id IS NOT NULL) DESC, a.effective_from DESC, a.created_at DESC \
+         LIMIT 1",
+    )
+    .bind(user_id)
+    .bind(date)
+    .fetch_optional(pool)
+    .await
+}
@@ -0,0 +1,101 @@
+//! Repository functions for the per-day work-time breakdown.
+
+use chrono::NaiveDate;
+use sqlx::PgPool;
+use std::collections::HashMap;
+
+use crate::models::work_time::WorkTimeBreakdown;
+use crate::types::{AttendanceId, UserId};
+
+#[derive(sqlx::FromRow)]
+struct WorkTimeRow {
+    attendance_id: AttendanceId,
+    #[sqlx(flatten)]
+    breakdown: WorkTimeBreakdown,
+}
+
+/// Replaces the breakdowns of the given records and removes those of `cleared` ones.
+pub async fn save_breakdowns(
+    pool: &PgPool,
+    breakdowns: &[(AttendanceId, WorkTimeBreakdown)],
+    cleared: &[AttendanceId],
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    if !cleared.is_empty() {
+        let ids: Vec<String> = cleared.iter().map(|id| id.to_string()).collect();
+        sqlx::query("DELETE FROM attendance_work_time WHERE attendance_id = ANY($1)")
+            .bind(ids)
+            .execute(tx.as_mut())
+            .await?;
+    }
+    for (attendance_id, breakdown) in breakdowns {
+        sqlx::query(
+            "INSERT INTO attendance_work_time \
+                 (attendance_id, regular_hours, statutory_overtime_hours, late_night_hours, \
+                  legal_holiday_hours, non_statutory_holiday_hours, updated_at) \
+             VALUES ($1, $2, $3, $4, $5, $6, NOW()) \
+             ON CONFLICT (attendance_id) DO UPDATE SET \
+                 regular_hours = EXCLUDED.regular_hours, \
+                 statutory_overtime_hours = EXCLUDED.statutory_overtime_hours, \
+                 late_night_hours = EXCLUDED.late_night_hours, \
+                 legal_holiday_hours = EXCLUDED.legal_holiday_hours, \
+                 non_statutory_holiday_hours = EXCLUDED.non_statutory_holiday_hours, \
+                 updated_at = EXCLUDED.updated_at",
+        )
+        .bind(attendance_id)
+        .bind(breakdown.regular_hours)
+        .bind(breakdown.statutory_overtime_hours)
+        .bind(breakdown.late_night_hours)
+        .bind(breakdown.legal_holiday_hours)
+        .bind(breakdown.non_statutory_holiday_hours)
+        .execute(tx.as_mut())
+        .await?;
+    }
+    tx.commit().await
+}
+
+/// Breakdowns of the given attendance records, keyed by record.
+pub async fn find_by_attendance_ids(
+    pool: &PgPool,
+    attendance_ids: &[AttendanceId],
+) -> Result<HashMap<AttendanceId, WorkTimeBreakdown>, sqlx::Error> {
+    if attendance_ids.is_empty() {
+        return Ok(HashMap::new());
+    }
+    let ids: Vec<String> = attendance_ids.iter().map(|id| id.to_string()).collect();
+    let rows = sqlx::query_as::<_, WorkTimeRow>(
+        "SELECT attendance_id, regular_hours, statutory_overtime_hours, late_night_hours, \
+                legal_holiday_hours, non_statutory_holiday_hours \
+         FROM attendance_work_time WHERE attendance_id = ANY($1)",
+    )
+    .bind(ids)
+    .fetch_all(pool)
+    .await?;
+    Ok(rows
+        .into_iter()
+        .map(|row| (row.attendance_id, row.breakdown))
+        .collect())
+}
+
+/// Totals a user's breakdowns between two dates.
+pub async fn sum_for_user(
+    pool: &PgPool,
+    user_id: UserId,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<WorkTimeBreakdown, sqlx::Error> {
+    sqlx::query_as::<_, WorkTimeBreakdown>(
+        "SELECT COALESCE(SUM(w.regular_hours), 0) AS regular_hours, \
+                COALESCE(SUM(w.statutory_overtime_hours), 0) AS statutory_overtime_hours, \
+                COALESCE(SUM(w.late_night_hours), 0) AS late_night_hours, \
+                COALESCE(SUM(w.legal_holiday_hours), 0) AS legal_holiday_hours, \
+                COALESCE(SUM(w.non_statutory_holiday_hours), 0) AS non_statutory_holiday_hours \
+         FROM attendance_work_time w JOIN attendance a ON a.id = w.attendance_id \
+         WHERE a.user_id = $1 AND a.date BETWEEN $2 AND $3",
+    )
+    .bind(user_id)
+    .bind(from)
+    .bind(to)
+    .fetch_one(pool)
+    .await
+}
@@ -0,0 +1,97 @@
+//! Marks scheduled working days that passed without a clock-in.
+
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::models::absence::AbsenceSummary;
+use crate::models::attendance::AttendanceStatus;
+use crate::repositories::{absence, period_closing};
+use crate::services::holiday::{HolidayService, HolidayServiceTrait};
+use crate::services::work_schedule::WorkScheduleService;
+use crate::types::UserId;
+
+/// Returns true when the user is expected to work on `date`: it is not a holiday for them
+/// after their holiday exceptions, and it is one of their work schedule's weekdays when
+/// they have a schedule.
+pub async fn is_working_day(
+    holidays: &HolidayService,
+    schedules: &WorkScheduleService,
+    user_id: UserId,
+    date: NaiveDate,
+) -> Result<bool, AppError> {
+    if holidays
+        .is_holiday(date, Some(&user_id.to_string()))
+        .await?
+        .is_holiday
+    {
+        return Ok(false);
+    }
+    Ok(schedules
+        .schedule_for(user_id, date)
+        .await?
+        .is_none_or(|schedule| schedule.works_on(date)))
+}
+
+/// Creates attendance records for working days that employees did not clock in on.
+#[derive(Clone)]
+#[allow(dead_code)]
+pub struct AbsenceDetector {
+    pool: PgPool,
+    holidays: HolidayService,
+}
+
+#[allow(dead_code)]
+impl AbsenceDetector {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            holidays: HolidayService::new(pool.clone()),
+            pool,
+        }
+    }
+
+    /// Records `date` as absence or leave for every employee without a record for it.
+    ///
+    /// Days are skipped when they are not a [working day](is_working_day) for the employee
+    /// or belong to a closed month. Approved leave covering the day makes a
+    /// [`AttendanceStatus::Leave`] record linked to the request; otherwise the day is
+    /// [`AttendanceStatus::Absent`]. Days that already have a record are never touched, so
+    /// the detection can be rerun safely.
+    pub async fn detect(&self, date: NaiveDate) -> Result<AbsenceSummary, AppError> {
+        let schedules = WorkScheduleService::new(self.pool.clone());
+        let mut summary = AbsenceSummary::default();
+
+        for candidate in absence::list_unattended_users(&self.pool, date).await? {
+            let user_id = candidate.user_id;
+            if !is_working_day(&self.holidays, &schedules, user_id, date).await? {
+                continue;
+            }
+            if period_closing::find_closing_for_user(&self.pool, user_id, date, date)
+                .await?
+                .is_some()
+            {
+                continue;
+            }
+
+            let status = if candidate.leave_request_id.is_some() {
+                AttendanceStatus::Leave
+            } else {
+                AttendanceStatus::Absent
+            };
+            let inserted = absence::insert_unattended_day(
+                &self.pool,
+                user_id,
+                date,
+                status.clone(),
+                candidate.leave_request_id,
+            )
+            .await?;
+            match status {
+                AttendanceStatus::Leave => summary.leave += inserted,
+                _ => summary.absent += inserted,
+            }
+        }
+
+        Ok(summary)
+    }
+}
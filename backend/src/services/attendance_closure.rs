@@ -0,0 +1,225 @@
+//! Closes breaks and attendance records that employees left open.
+
+use chrono::{DateTime, Duration, NaiveDateTime, NaiveTime, Utc};
+use sqlx::PgPool;
+
+use crate::config::Config;
+use crate::error::AppError;
+use crate::models::attendance::Attendance;
+use crate::models::attendance_sweep::{OpenRecordSweepAction, SweepSummary};
+use crate::models::break_record::BreakRecord;
+use crate::repositories::{
+    attendance::AttendanceRepository, attendance_sweep, break_record::BreakRecordRepository,
+    department, period_closing, repository::Repository, transaction, user as user_repo,
+};
+use crate::services::{
+    break_policy::BreakPolicyService, work_schedule::WorkScheduleService,
+    work_time::WorkTimeService,
+};
+use crate::types::BreakRecordId;
+use crate::utils::{email::EmailService, encryption::decrypt_pii};
+
+/// Ends an active break at `end_time`.
+///
+/// Ends before the break started are clamped to its start. The attendance total is left
+/// to the caller, which knows whether the record is already clocked out.
+pub async fn force_end_break(
+    pool: &PgPool,
+    break_id: BreakRecordId,
+    end_time: NaiveDateTime,
+    now_utc: DateTime<Utc>,
+) -> Result<BreakRecord, AppError> {
+    let break_repo = BreakRecordRepository::new();
+    let mut record = break_repo.find_by_id(pool, break_id).await?;
+    if !record.is_active() {
+        return Err(AppError::BadRequest("Break already ended".into()));
+    }
+    record.end_break(end_time.max(record.break_start_time), now_utc);
+    break_repo.update(pool, &record).await
+}
+
+/// Finds attendance records whose clock-out was forgotten and settles them.
+#[derive(Clone)]
+#[allow(dead_code)]
+pub struct ForgottenClockOutSweeper {
+    pool: PgPool,
+}
+
+#[allow(dead_code)]
+impl ForgottenClockOutSweeper {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Sweeps records still open `forgotten_clock_out_cutoff_hours` after their shift ended.
+    ///
+    /// With `forgotten_clock_out_auto_close` set, records with a scheduled end are clocked
+    /// out at that end and any open break is ended there too; all other records are flagged
+    /// for correction. Each record is swept once, and the employee and their managers are
+    /// emailed about it. Records in a closed month are left untouched.
+    pub async fn sweep(
+        &self,
+        now: NaiveDateTime,
+        config: &Config,
+    ) -> Result<SweepSummary, AppError> {
+        let schedules = WorkScheduleService::new(self.pool.clone());
+        let cutoff = Duration::hours(config.forgotten_clock_out_cutoff_hours);
+        let day_change = NaiveTime::from_hms_opt(config.attendance_day_change_hour, 0, 0)
+            .unwrap_or(NaiveTime::MIN);
+        let now_utc = Utc::now();
+        let mut summary = SweepSummary::default();
+
+        for attendance in
+            attendance_sweep::list_unswept_open_records(&self.pool, now.date()).await?
+        {
+            let Some(clock_in) = attendance.clock_in_time else {
+                continue;
+            };
+            if period_closing::find_closing_for_user(
+                &self.pool,
+                attendance.user_id,
+                attendance.date,
+                attendance.date,
+            )
+            .await?
+            .is_some()
+            {
+                continue;
+            }
+            let shift_end = schedules
+                .schedule_for(attendance.user_id, attendance.date)
+                .await?
+                .filter(|schedule| schedule.works_on(attendance.date))
+                .map(|schedule| schedule.shift_end(attendance.date));
+            let day_end = match shift_end {
+                Some(shift_end) => shift_end,
+                None => match attendance.date.succ_opt() {
+                    Some(next) => next.and_time(day_change),
+                    None => continue,
+                },
+            };
+            if now < day_end + cutoff {
+                continue;
+            }
+
+            let closed_at = match shift_end {
+                Some(shift_end)
+                    if config.forgotten_clock_out_auto_close && clock_in < shift_end =>
+                {
+                    summary.breaks_ended += self
+                        .close_at(attendance.clone(), shift_end, now_utc)
+                        .await?;
+                    Some(shift_end)
+                }
+                _ => None,
+            };
+            let action = if closed_at.is_some() {
+                OpenRecordSweepAction::AutoClosed
+            } else {
+                OpenRecordSweepAction::NeedsCorrection
+            };
+            if attendance_sweep::record_sweep(
+                &self.pool,
+                attendance.id,
+                attendance.user_id,
+                action,
+                closed_at,
+            )
+            .await?
+                == 0
+            {
+                continue;
+            }
+            match action {
+                OpenRecordSweepAction::AutoClosed => summary.auto_closed += 1,
+                OpenRecordSweepAction::NeedsCorrection => summary.flagged += 1,
+            }
+
+            if self.notify(&attendance, closed_at, config).await? {
+                attendance_sweep::mark_notified(&self.pool, attendance.id).await?;
+            }
+        }
+
+        Ok(summary)
+    }
+
+    /// Clocks the record out at `closed_at`; returns the number of breaks it had to end.
+    async fn close_at(
+        &self,
+        mut attendance: Attendance,
+        closed_at: NaiveDateTime,
+        now_utc: DateTime<Utc>,
+    ) -> Result<u64, AppError> {
+        let break_repo = BreakRecordRepository::new();
+        let mut breaks_ended = 0;
+        for record in break_repo
+            .find_by_attendance(&self.pool, attendance.id)
+            .await?
+        {
+            if record.is_active() {
+                force_end_break(&self.pool, record.id, closed_at, now_utc).await?;
+                breaks_ended += 1;
+            }
+        }
+
+        attendance.clock_out_time = Some(closed_at);
+        let expectation = WorkScheduleService::new(self.pool.clone())
+            .day_expectation(attendance.user_id, attendance.date)
+            .await?;
+        let mut tx = transaction::begin_transaction(&self.pool).await?;
+        let break_minutes = BreakPolicyService::new(self.pool.clone())
+            .enforce(&mut tx, &attendance, now_utc)
+            .await?;
+        attendance.calculate_work_hours(break_minutes);
+        attendance.apply_day_expectation(&expectation);
+        attendance.updated_at = now_utc;
+        AttendanceRepository::new()
+            .update_in_transaction(&mut tx, &attendance)
+            .await?;
+        transaction::commit_transaction(tx).await?;
+        WorkTimeService::new(self.pool.clone())
+            .refresh_week(attendance.user_id, attendance.date)
+            .await?;
+        Ok(breaks_ended)
+    }
+
+    /// Emails the employee and their managers; returns true if anyone was reached.
+    async fn notify(
+        &self,
+        attendance: &Attendance,
+        closed_at: Option<NaiveDateTime>,
+        config: &Config,
+    ) -> Result<bool, AppError> {
+        let username = user_repo::fetch_username(&self.pool, &attendance.user_id.to_string())
+            .await?
+            .unwrap_or_else(|| attendance.user_id.to_string());
+        let recipients =
+            department::list_user_and_manager_emails(&self.pool, attendance.user_id).await?;
+        if recipients.is_empty() {
+            return Ok(false);
+        }
+        let email_service = EmailService::new()?;
+        let mut delivered = false;
+        for encrypted in recipients {
+            let email = match decrypt_pii(&encrypted, config) {
+                Ok(email) => email,
+                Err(err) => {
+                    tracing::warn!(user_id = %attendance.user_id, error = %err, "Skipping undecryptable forgotten clock-out recipient");
+                    continue;
+                }
+            };
+            match email_service.send_forgotten_clock_out_notice(
+                &email,
+                &username,
+                attendance.date,
+                closed_at,
+            ) {
+                Ok(()) => delivered = true,
+                Err(err) => {
+                    tracing::warn!(user_id = %attendance.user_id, error = %err, "Failed to send forgotten clock-out notice")
+                }
+            }
+        }
+        Ok(delivered)
+    }
+}
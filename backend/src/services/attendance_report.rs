@@ -0,0 +1,490 @@
+//! Monthly attendance book (出勤簿) kept for each employee, rendered as PDF.
+
+use std::{collections::HashMap, sync::Arc};
+
+use anyhow::anyhow;
+use chrono::{Datelike, Months, NaiveDate, NaiveDateTime};
+use printpdf::{IndirectFontRef, Line, Mm, PdfDocument, PdfLayerReference, Point};
+use sqlx::PgPool;
+
+use crate::config::Config;
+use crate::error::AppError;
+use crate::models::{attendance::AttendanceResponse, leave_request::LeaveUnit};
+use crate::repositories::attendance_report::{self, ApprovedLeaveRow};
+use crate::services::{
+    holiday::{HolidayCalendarEntry, HolidayReason, HolidayServiceTrait},
+    work_time::load_effective_attendance,
+};
+use crate::types::UserId;
+
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 15.0;
+const TABLE_TOP: f32 = 256.0;
+const ROW_HEIGHT: f32 = 6.0;
+const COLUMNS: [(&str, f32); 8] = [
+    ("日付", MARGIN),
+    ("曜日", 30.0),
+    ("区分", 40.0),
+    ("出勤", 60.0),
+    ("退勤", 78.0),
+    ("休憩", 98.0),
+    ("労働時間", 114.0),
+    ("休暇", 136.0),
+];
+const WEEKDAYS: [&str; 7] = ["月", "火", "水", "木", "金", "土", "日"];
+
+/// Employee a report is produced for.
+#[derive(Debug, Clone)]
+pub struct ReportEmployee {
+    pub id: UserId,
+    pub username: String,
+    /// Decrypted, and masked when the reader may not see it in full.
+    pub full_name: String,
+}
+
+/// Approved leave covering (part of) one day.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ReportLeave {
+    pub name: String,
+    pub unit: LeaveUnit,
+    pub hours: Option<f64>,
+}
+
+impl ReportLeave {
+    /// Share of a day taken; hourly leave is counted in hours instead.
+    fn days(&self) -> f64 {
+        match self.unit {
+            LeaveUnit::Full => 1.0,
+            LeaveUnit::HalfAm | LeaveUnit::HalfPm => 0.5,
+            LeaveUnit::Hours => 0.0,
+        }
+    }
+
+    fn label(&self) -> String {
+        match self.unit {
+            LeaveUnit::Full => self.name.clone(),
+            LeaveUnit::HalfAm => format!("{}(午前)", self.name),
+            LeaveUnit::HalfPm => format!("{}(午後)", self.name),
+            LeaveUnit::Hours => format!("{}({:.1}h)", self.name, self.hours.unwrap_or_default()),
+        }
+    }
+}
+
+/// One calendar day of the attendance book.
+#[derive(Debug, Clone)]
+pub struct AttendanceReportDay {
+    pub date: NaiveDate,
+    /// Set when the holiday calendar marks the day as a holiday.
+    pub holiday: Option<HolidayReason>,
+    pub clock_in: Option<NaiveDateTime>,
+    pub clock_out: Option<NaiveDateTime>,
+    pub break_minutes: i64,
+    pub worked_hours: f64,
+    pub leave: Vec<ReportLeave>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct AttendanceReportTotals {
+    /// Days with a clock-in.
+    pub work_days: u32,
+    pub worked_hours: f64,
+    pub break_minutes: i64,
+    pub leave_days: f64,
+    pub leave_hours: f64,
+    pub holidays: u32,
+}
+
+/// An employee's attendance book for one month.
+#[derive(Debug, Clone)]
+pub struct AttendanceReport {
+    pub employee: ReportEmployee,
+    /// First day of the reported month.
+    pub month: NaiveDate,
+    pub days: Vec<AttendanceReportDay>,
+    pub totals: AttendanceReportTotals,
+}
+
+impl AttendanceReport {
+    /// Lays out every day of `month` with the attendance, leave and holidays falling on it.
+    pub fn build(
+        employee: ReportEmployee,
+        month: NaiveDate,
+        attendance: Vec<AttendanceResponse>,
+        leave: &[ApprovedLeaveRow],
+        holidays: &[HolidayCalendarEntry],
+    ) -> Self {
+        let mut attendance: HashMap<NaiveDate, AttendanceResponse> = attendance
+            .into_iter()
+            .map(|record| (record.date, record))
+            .collect();
+        let holidays: HashMap<NaiveDate, &HolidayReason> = holidays
+            .iter()
+            .filter(|entry| entry.is_holiday)
+            .map(|entry| (entry.date, &entry.reason))
+            .collect();
+
+        let mut days = Vec::new();
+        let mut totals = AttendanceReportTotals::default();
+        for date in month
+            .iter_days()
+            .take_while(|date| date.month() == month.month())
+        {
+            let record = attendance.remove(&date);
+            let break_minutes = record
+                .as_ref()
+                .map(|record| {
+                    record
+                        .break_records
+                        .iter()
+                        .filter_map(|item| item.duration_minutes)
+                        .map(i64::from)
+                        .sum()
+                })
+                .unwrap_or(0);
+            let leave: Vec<ReportLeave> = leave
+                .iter()
+                .filter(|row| row.start_date <= date && date <= row.end_date)
+                .map(|row| ReportLeave {
+                    name: row.leave_name.clone(),
+                    unit: row.unit,
+                    hours: row.hours,
+                })
+                .collect();
+            let day = AttendanceReportDay {
+                date,
+                holiday: holidays.get(&date).map(|reason| (*reason).clone()),
+                clock_in: record.as_ref().and_then(|record| record.clock_in_time),
+                clock_out: record.as_ref().and_then(|record| record.clock_out_time),
+                break_minutes,
+                worked_hours: record
+                    .as_ref()
+                    .and_then(|record| record.total_work_hours)
+                    .unwrap_or(0.0),
+                leave,
+            };
+
+            if day.clock_in.is_some() {
+                totals.work_days += 1;
+            }
+            if day.holiday.is_some() {
+                totals.holidays += 1;
+            }
+            totals.worked_hours += day.worked_hours;
+            totals.break_minutes += day.break_minutes;
+            for item in &day.leave {
+                totals.leave_days += item.days();
+                totals.leave_hours += item.hours.unwrap_or(0.0);
+            }
+            days.push(day);
+        }
+
+        Self {
+            employee,
+            month,
+            days,
+            totals,
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AttendanceReportService {
+    pool: PgPool,
+    holidays: Arc<dyn HolidayServiceTrait>,
+}
+
+impl AttendanceReportService {
+    pub fn new(pool: PgPool, holidays: Arc<dyn HolidayServiceTrait>) -> Self {
+        Self { pool, holidays }
+    }
+
+    /// Collects the employee's attendance book for the month starting at `month`.
+    pub async fn monthly_report(
+        &self,
+        employee: ReportEmployee,
+        month: NaiveDate,
+    ) -> Result<AttendanceReport, AppError> {
+        let last_day = month
+            .checked_add_months(Months::new(1))
+            .and_then(|next| next.pred_opt())
+            .ok_or_else(|| AppError::BadRequest("Invalid report month".into()))?;
+        let attendance =
+            load_effective_attendance(&self.pool, employee.id, month, last_day).await?;
+        let leave =
+            attendance_report::list_approved_leave(&self.pool, employee.id, month, last_day)
+                .await?;
+        let holidays = self
+            .holidays
+            .list_month(month.year(), month.month(), Some(&employee.id.to_string()))
+            .await?;
+
+        Ok(AttendanceReport::build(
+            employee, month, attendance, &leave, &holidays,
+        ))
+    }
+}
+
+/// Renders the reports as one PDF with a page per employee. The font configured in
+/// `REPORT_FONT_PATH` is embedded, subset to the glyphs in use, so Japanese text
+/// displays without fonts installed on the reader's machine.
+pub async fn render_pdf(
+    config: &Config,
+    reports: Vec<AttendanceReport>,
+) -> Result<Vec<u8>, AppError> {
+    let path = config.report_font_path.as_deref().ok_or_else(|| {
+        AppError::InternalServerError(anyhow!("REPORT_FONT_PATH is not configured"))
+    })?;
+    let font = tokio::fs::read(path).await.map_err(|err| {
+        AppError::InternalServerError(anyhow!("failed to read report font {path}: {err}"))
+    })?;
+
+    tokio::task::spawn_blocking(move || write_pdf(&reports, &font))
+        .await
+        .map_err(|err| AppError::InternalServerError(err.into()))?
+}
+
+fn write_pdf(reports: &[AttendanceReport], font: &[u8]) -> Result<Vec<u8>, AppError> {
+    let (first, rest) = reports
+        .split_first()
+        .ok_or_else(|| AppError::NotFound("No attendance to report".into()))?;
+    let (doc, page, layer) = PdfDocument::new(
+        format!("Attendance report {}", first.month.format("%Y-%m")),
+        Mm(PAGE_WIDTH),
+        Mm(PAGE_HEIGHT),
+        "attendance",
+    );
+    let font = doc.add_external_font(font).map_err(pdf_error)?;
+
+    draw_report(&doc.get_page(page).get_layer(layer), &font, first);
+    for report in rest {
+        let (page, layer) = doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "attendance");
+        draw_report(&doc.get_page(page).get_layer(layer), &font, report);
+    }
+
+    doc.save_to_bytes().map_err(pdf_error)
+}
+
+fn draw_report(layer: &PdfLayerReference, font: &IndirectFontRef, report: &AttendanceReport) {
+    let employee = &report.employee;
+    layer.use_text("出勤簿", 16.0, Mm(MARGIN), Mm(280.0), font);
+    layer.use_text(
+        format!("対象月: {}", report.month.format("%Y年%m月")),
+        10.0,
+        Mm(MARGIN),
+        Mm(271.0),
+        font,
+    );
+    let name = if employee.full_name.is_empty() {
+        employee.username.clone()
+    } else {
+        format!("{} ({})", employee.full_name, employee.username)
+    };
+    layer.use_text(format!("氏名: {name}"), 10.0, Mm(MARGIN), Mm(265.0), font);
+
+    for (label, x) in COLUMNS {
+        layer.use_text(label, 9.0, Mm(x), Mm(TABLE_TOP + 1.8), font);
+    }
+    rule(layer, TABLE_TOP + ROW_HEIGHT);
+    rule(layer, TABLE_TOP);
+
+    let mut y = TABLE_TOP;
+    for day in &report.days {
+        y -= ROW_HEIGHT;
+        let cells = [
+            day.date.format("%m/%d").to_string(),
+            WEEKDAYS[day.date.weekday().num_days_from_monday() as usize].to_string(),
+            day.holiday.as_ref().map(holiday_label).unwrap_or_default(),
+            day.clock_in
+                .map(|at| clock_label(day.date, at))
+                .unwrap_or_default(),
+            day.clock_out
+                .map(|at| clock_label(day.date, at))
+                .unwrap_or_default(),
+            if day.break_minutes > 0 {
+                minutes_label(day.break_minutes)
+            } else {
+                String::new()
+            },
+            if day.clock_in.is_some() {
+                format!("{:.2}", day.worked_hours)
+            } else {
+                String::new()
+            },
+            day.leave
+                .iter()
+                .map(ReportLeave::label)
+                .collect::<Vec<_>>()
+                .join("、"),
+        ];
+        for ((_, x), text) in COLUMNS.iter().zip(cells) {
+            if !text.is_empty() {
+                layer.use_text(text, 9.0, Mm(*x), Mm(y + 1.8), font);
+            }
+        }
+        rule(layer, y);
+    }
+
+    let totals = &report.totals;
+    y -= 10.0;
+    layer.use_text(
+        format!(
+            "出勤日数: {}日    総労働時間: {:.2}時間    休憩合計: {}",
+            totals.work_days,
+            totals.worked_hours,
+            minutes_label(totals.break_minutes)
+        ),
+        10.0,
+        Mm(MARGIN),
+        Mm(y),
+        font,
+    );
+    y -= 6.0;
+    layer.use_text(
+        format!(
+            "休暇: {:.1}日 {:.1}時間    休日: {}日",
+            totals.leave_days, totals.leave_hours, totals.holidays
+        ),
+        10.0,
+        Mm(MARGIN),
+        Mm(y),
+        font,
+    );
+}
+
+fn rule(layer: &PdfLayerReference, y: f32) {
+    layer.add_line(Line {
+        points: vec![
+            (Point::new(Mm(MARGIN), Mm(y)), false),
+            (Point::new(Mm(PAGE_WIDTH - MARGIN), Mm(y)), false),
+        ],
+        is_closed: false,
+    });
+}
+
+fn holiday_label(reason: &HolidayReason) -> String {
+    match reason {
+        HolidayReason::PublicHoliday => "祝日",
+        HolidayReason::WeeklyHoliday => "休日",
+        HolidayReason::ExceptionOverride => "指定休日",
+        HolidayReason::None => "",
+    }
+    .to_string()
+}
+
+/// Clock time on the report row; times past midnight are marked as the next day.
+fn clock_label(date: NaiveDate, at: NaiveDateTime) -> String {
+    if at.date() > date {
+        format!("翌{}", at.format("%H:%M"))
+    } else {
+        at.format("%H:%M").to_string()
+    }
+}
+
+fn minutes_label(minutes: i64) -> String {
+    format!("{}:{:02}", minutes / 60, minutes % 60)
+}
+
+fn pdf_error(err: printpdf::Error) -> AppError {
+    AppError::InternalServerError(anyhow!("failed to render attendance report: {err}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::{attendance::AttendanceStatus, break_record::BreakRecordResponse};
+    use crate::types::{AttendanceId, BreakRecordId};
+
+    fn date(day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 2, day).expect("date")
+    }
+
+    fn employee() -> ReportEmployee {
+        ReportEmployee {
+            id: UserId::new(),
+            username: "alice".into(),
+            full_name: "Alice".into(),
+        }
+    }
+
+    fn worked(day: u32, hours: f64, break_minutes: i32) -> AttendanceResponse {
+        let id = AttendanceId::new();
+        AttendanceResponse {
+            id,
+            user_id: UserId::new(),
+            date: date(day),
+            clock_in_time: date(day).and_hms_opt(9, 0, 0),
+            clock_out_time: date(day).and_hms_opt(18, 0, 0),
+            status: AttendanceStatus::Present,
+            total_work_hours: Some(hours),
+            break_records: vec![BreakRecordResponse {
+                id: BreakRecordId::new(),
+                attendance_id: id,
+                break_start_time: date(day).and_hms_opt(12, 0, 0).expect("time"),
+                break_end_time: date(day).and_hms_opt(13, 0, 0),
+                duration_minutes: Some(break_minutes),
+                is_automatic: false,
+            }],
+            work_time: None,
+            rest_interval_warning: None,
+        }
+    }
+
+    #[test]
+    fn build_lists_every_day_of_the_month_with_totals() {
+        let leave = vec![
+            ApprovedLeaveRow {
+                start_date: date(9),
+                end_date: date(10),
+                unit: LeaveUnit::Full,
+                hours: None,
+                leave_name: "年次有給休暇".into(),
+            },
+            ApprovedLeaveRow {
+                start_date: date(12),
+                end_date: date(12),
+                unit: LeaveUnit::Hours,
+                hours: Some(2.0),
+                leave_name: "年次有給休暇".into(),
+            },
+        ];
+        let holidays = vec![HolidayCalendarEntry {
+            date: date(11),
+            is_holiday: true,
+            reason: HolidayReason::PublicHoliday,
+        }];
+
+        let report = AttendanceReport::build(
+            employee(),
+            date(1),
+            vec![worked(2, 8.0, 60), worked(3, 7.5, 45)],
+            &leave,
+            &holidays,
+        );
+
+        assert_eq!(report.days.len(), 28);
+        assert_eq!(report.days[1].break_minutes, 60);
+        assert_eq!(report.days[8].leave[0].label(), "年次有給休暇");
+        assert_eq!(report.days[11].leave[0].label(), "年次有給休暇(2.0h)");
+        assert_eq!(report.days[10].holiday, Some(HolidayReason::PublicHoliday));
+        assert_eq!(
+            report.totals,
+            AttendanceReportTotals {
+                work_days: 2,
+                worked_hours: 15.5,
+                break_minutes: 105,
+                leave_days: 2.0,
+                leave_hours: 2.0,
+                holidays: 1,
+            }
+        );
+    }
+
+    #[test]
+    fn clock_label_marks_times_after_midnight() {
+        let at = date(3).and_hms_opt(1, 30, 0).expect("time");
+        assert_eq!(clock_label(date(2), at), "翌01:30");
+        assert_eq!(clock_label(date(3), at), "01:30");
+        assert_eq!(minutes_label(105), "1:45");
+    }
+}
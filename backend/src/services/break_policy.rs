@@ -0,0 +1,76 @@
+//! Enforces the break policy whenever a clocked-out shift is saved.
+
+use chrono::{DateTime, Utc};
+use sqlx::{PgPool, PgTransaction};
+
+use crate::error::AppError;
+use crate::models::{
+    attendance::Attendance,
+    break_policy::{BreakPolicy, BreakPolicyMode},
+    break_record::BreakRecord,
+};
+use crate::repositories::{break_policy, break_record::BreakRecordRepository};
+
+#[derive(Clone)]
+pub struct BreakPolicyService {
+    pool: PgPool,
+}
+
+impl BreakPolicyService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// The saved policy, falling back to the statutory one.
+    pub async fn policy(&self) -> Result<BreakPolicy, AppError> {
+        Ok(break_policy::find_policy(&self.pool)
+            .await?
+            .unwrap_or_else(BreakPolicy::statutory))
+    }
+
+    /// Checks a clocked-out record against the policy and records the outcome, as part of
+    /// the transaction that saves the record.
+    ///
+    /// Automatic breaks from an earlier check are replaced, so this runs again whenever the
+    /// shift or its breaks change. In deduct mode the missing minutes are inserted as an
+    /// automatic break. Returns the break minutes to count when computing work hours.
+    pub async fn enforce(
+        &self,
+        tx: &mut PgTransaction<'_>,
+        attendance: &Attendance,
+        now_utc: DateTime<Utc>,
+    ) -> Result<i64, AppError> {
+        break_policy::delete_automatic_breaks(tx.as_mut(), attendance.id).await?;
+        let recorded_break_minutes =
+            break_policy::recorded_break_minutes(tx.as_mut(), attendance.id).await?;
+        let (Some(clock_in), Some(clock_out)) =
+            (attendance.clock_in_time, attendance.clock_out_time)
+        else {
+            return Ok(recorded_break_minutes);
+        };
+        let policy = break_policy::find_policy(tx.as_mut())
+            .await?
+            .unwrap_or_else(BreakPolicy::statutory);
+        let gross_minutes = (clock_out - clock_in).num_minutes().max(0);
+        let shortfall = policy.check(gross_minutes, recorded_break_minutes);
+        let deducted = match (&shortfall, policy.mode) {
+            (Some(shortfall), BreakPolicyMode::Deduct) => {
+                let record = BreakRecord::automatic(
+                    attendance.id,
+                    clock_in,
+                    clock_out,
+                    shortfall.missing_minutes(),
+                    now_utc,
+                );
+                BreakRecordRepository::new()
+                    .create_in_transaction(tx, &record)
+                    .await?;
+                shortfall.missing_minutes()
+            }
+            _ => 0,
+        };
+        break_policy::save_violation(tx.as_mut(), attendance.id, shortfall.as_ref(), deducted > 0)
+            .await?;
+        Ok(recorded_break_minutes + deducted)
+    }
+}
@@ -0,0 +1,389 @@
+//! Runs queued export jobs: streams matching rows into a file and hands it to the export store.
+//!
+//! Scheduled exports write their files the same way through [`write_export_file`].
+
+use std::{path::Path, time::Duration};
+
+use anyhow::anyhow;
+use chrono::Utc;
+use futures_util::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use sqlx::PgPool;
+use tokio::{
+    fs::File,
+    io::{AsyncWrite, AsyncWriteExt, BufWriter},
+};
+
+use crate::{
+    config::Config,
+    models::{
+        audit_log::{apply_pii_policy, AuditLog, AuditLogResponse},
+        export_job::{ExportJob, ExportJobKind, ExportJobStatus},
+        export_template::ExportTemplate,
+    },
+    repositories::{
+        audit_log::{self, AuditLogFilters},
+        export_job::{self, ExportJobOutput},
+        export_template::{self, ExportRecord, ExportRecordFilter},
+    },
+    services::{
+        export_encryption::{ExportEncryption, ENCRYPTED_CONTENT_TYPE},
+        export_storage::ExportStorage,
+        export_template::TemplateWriter,
+    },
+    types::{ExportJobId, ExportTemplateId, UserId},
+    utils::{encryption::decrypt_pii, pii::mask_name, time},
+};
+
+/// Rows written between progress updates.
+#[allow(dead_code)]
+const PROGRESS_INTERVAL: i64 = 500;
+
+/// How often a worker refreshes the job it is running.
+#[allow(dead_code)]
+const HEARTBEAT_INTERVAL: Duration = Duration::from_secs(30);
+
+/// How long a running job may go without a refresh before another worker claims it again.
+#[allow(dead_code)]
+const JOB_LEASE: Duration = Duration::from_secs(5 * 60);
+
+/// Filters of an export job, resolved when it was queued and stored with it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ExportJobParameters {
+    Attendance {
+        filter: ExportRecordFilter,
+        /// Template layout; the standard layout when absent.
+        template_id: Option<ExportTemplateId>,
+        mask_pii: bool,
+    },
+    AuditLogs {
+        filters: AuditLogFilters,
+        mask_pii: bool,
+    },
+}
+
+impl ExportJobParameters {
+    pub fn kind(&self) -> ExportJobKind {
+        match self {
+            ExportJobParameters::Attendance { .. } => ExportJobKind::Attendance,
+            ExportJobParameters::AuditLogs { .. } => ExportJobKind::AuditLogs,
+        }
+    }
+}
+
+/// Builds a queued job for `requested_by`.
+pub fn new_job(
+    requested_by: UserId,
+    parameters: &ExportJobParameters,
+) -> Result<ExportJob, serde_json::Error> {
+    let now = Utc::now();
+    Ok(ExportJob {
+        id: ExportJobId::new(),
+        requested_by,
+        kind: parameters.kind(),
+        parameters: serde_json::to_value(parameters)?,
+        status: ExportJobStatus::Queued,
+        rows_written: 0,
+        total_rows: None,
+        storage_key: None,
+        filename: None,
+        content_type: None,
+        size_bytes: None,
+        error: None,
+        encryption: None,
+        encryption_key: None,
+        passphrase: None,
+        created_at: now,
+        started_at: None,
+        finished_at: None,
+        updated_at: now,
+    })
+}
+
+/// Claims the oldest queued or abandoned job and runs it to completion or failure.
+///
+/// Returns the finished job, or `None` when the queue is empty.
+#[allow(dead_code)]
+pub async fn work_once(
+    pool: &PgPool,
+    storage: &ExportStorage,
+    config: &Config,
+) -> anyhow::Result<Option<ExportJob>> {
+    let now = Utc::now();
+    let stale_before = now - chrono::Duration::from_std(JOB_LEASE)?;
+    let Some(job) = export_job::claim_next_job(pool, now, stale_before).await? else {
+        return Ok(None);
+    };
+
+    let heartbeat = tokio::spawn(keep_alive(pool.clone(), job.id));
+    let result = run_job(pool, storage, config, &job).await;
+    heartbeat.abort();
+
+    let finished = match result {
+        Ok(output) => export_job::complete_job(pool, job.id, &output, Utc::now()).await?,
+        Err(err) => {
+            tracing::warn!(job_id = %job.id, error = %err, "export job failed");
+            export_job::fail_job(pool, job.id, &err.to_string(), Utc::now()).await?
+        }
+    };
+    Ok(Some(finished))
+}
+
+/// Refreshes a running job until aborted, so other workers leave it alone.
+#[allow(dead_code)]
+async fn keep_alive(pool: PgPool, job_id: ExportJobId) {
+    let mut interval = tokio::time::interval(HEARTBEAT_INTERVAL);
+    interval.tick().await;
+    loop {
+        interval.tick().await;
+        if let Err(err) = export_job::touch_job(&pool, job_id, Utc::now()).await {
+            tracing::warn!(job_id = %job_id, error = %err, "export job heartbeat failed");
+        }
+    }
+}
+
+#[allow(dead_code)]
+async fn run_job(
+    pool: &PgPool,
+    storage: &ExportStorage,
+    config: &Config,
+    job: &ExportJob,
+) -> anyhow::Result<ExportJobOutput> {
+    let parameters: ExportJobParameters = serde_json::from_value(job.parameters.clone())?;
+    let work_path = std::env::temp_dir().join(format!("export-job-{}.part", job.id));
+    let result = write_and_store(pool, storage, config, job, parameters, &work_path).await;
+    // Only left behind when the export failed before reaching the store.
+    tokio::fs::remove_file(&work_path).await.ok();
+    result
+}
+
+#[allow(dead_code)]
+async fn write_and_store(
+    pool: &PgPool,
+    storage: &ExportStorage,
+    config: &Config,
+    job: &ExportJob,
+    parameters: ExportJobParameters,
+    work_path: &Path,
+) -> anyhow::Result<ExportJobOutput> {
+    let mut file = write_export_file(pool, config, parameters, work_path, Some(job.id)).await?;
+    if let Some(method) = job.encryption {
+        let sealed = job
+            .encryption_key
+            .as_deref()
+            .ok_or_else(|| anyhow!("Encryption key of the export job is missing"))?;
+        let encryption = ExportEncryption::unseal(method, sealed, config)?;
+        file = encrypt_export_file(encryption, work_path, file).await?;
+    }
+    let storage_key = format!("export-jobs/{}/{}", job.id, file.filename);
+    storage
+        .store(&storage_key, work_path, &file.content_type)
+        .await?;
+
+    Ok(ExportJobOutput {
+        rows_written: file.rows,
+        storage_key,
+        filename: file.filename,
+        content_type: file.content_type,
+        size_bytes: file.size_bytes,
+    })
+}
+
+/// An export written to a work file, ready to be stored or delivered.
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+pub struct ExportFile {
+    pub filename: String,
+    pub content_type: String,
+    pub rows: i64,
+    pub size_bytes: i64,
+}
+
+/// Streams the rows `parameters` select into `work_path`.
+///
+/// When the export runs as a job, its total and progress are recorded on `job_id`.
+#[allow(dead_code)]
+pub async fn write_export_file(
+    pool: &PgPool,
+    config: &Config,
+    parameters: ExportJobParameters,
+    work_path: &Path,
+    job_id: Option<ExportJobId>,
+) -> anyhow::Result<ExportFile> {
+    let timestamp = time::now_in_timezone(&config.time_zone).format("%Y%m%d_%H%M%S");
+    let mut progress = Progress::new(pool, job_id);
+    let mut out = BufWriter::new(File::create(work_path).await?);
+
+    let (filename, content_type) = match parameters {
+        ExportJobParameters::Attendance {
+            filter,
+            template_id,
+            mask_pii,
+        } => {
+            let template = match template_id {
+                Some(id) => export_template::find_template(pool, id)
+                    .await?
+                    .ok_or_else(|| anyhow!("Export template no longer exists"))?,
+                None => ExportTemplate::standard(),
+            };
+            progress
+                .set_total(export_template::count_export_records(pool, &filter).await?)
+                .await?;
+            write_attendance(
+                pool,
+                config,
+                &template,
+                &filter,
+                mask_pii,
+                &mut out,
+                &mut progress,
+            )
+            .await?;
+            let prefix = if template_id.is_some() {
+                "payroll_export"
+            } else {
+                "attendance_export"
+            };
+            (
+                format!("{prefix}_{timestamp}.csv"),
+                format!("text/csv; charset={}", template.encoding.charset()),
+            )
+        }
+        ExportJobParameters::AuditLogs { filters, mask_pii } => {
+            progress
+                .set_total(audit_log::count_audit_logs(pool, &filters).await?)
+                .await?;
+            write_audit_logs(pool, &filters, mask_pii, &mut out, &mut progress).await?;
+            (
+                format!("audit_logs_{timestamp}.json"),
+                "application/json".to_string(),
+            )
+        }
+    };
+    out.flush().await?;
+    drop(out);
+
+    Ok(ExportFile {
+        filename,
+        content_type,
+        rows: progress.rows,
+        size_bytes: tokio::fs::metadata(work_path).await?.len() as i64,
+    })
+}
+
+/// Replaces the work file with its encrypted form.
+#[allow(dead_code)]
+pub async fn encrypt_export_file(
+    encryption: ExportEncryption,
+    work_path: &Path,
+    file: ExportFile,
+) -> anyhow::Result<ExportFile> {
+    let filename = encryption.filename(&file.filename);
+    let encrypted_path = work_path.with_extension("age");
+    let (source, destination) = (work_path.to_path_buf(), encrypted_path.clone());
+    let encrypted =
+        tokio::task::spawn_blocking(move || encryption.encrypt_file(&source, &destination)).await?;
+    if let Err(err) = encrypted {
+        tokio::fs::remove_file(&encrypted_path).await.ok();
+        return Err(err);
+    }
+    tokio::fs::rename(&encrypted_path, work_path).await?;
+
+    Ok(ExportFile {
+        filename,
+        content_type: ENCRYPTED_CONTENT_TYPE.to_string(),
+        rows: file.rows,
+        size_bytes: tokio::fs::metadata(work_path).await?.len() as i64,
+    })
+}
+
+#[allow(dead_code)]
+async fn write_attendance<W: AsyncWrite + Unpin>(
+    pool: &PgPool,
+    config: &Config,
+    template: &ExportTemplate,
+    filter: &ExportRecordFilter,
+    mask_pii: bool,
+    out: &mut W,
+    progress: &mut Progress<'_>,
+) -> anyhow::Result<()> {
+    let mut writer = TemplateWriter::new(template);
+    out.write_all(&writer.start()).await?;
+
+    let mut query = export_template::export_records_query(filter);
+    let mut rows = query.build_query_as::<ExportRecord>().fetch(pool);
+    while let Some(mut record) = rows.try_next().await? {
+        let full_name =
+            decrypt_pii(&record.full_name, config).unwrap_or_else(|_| "***".to_string());
+        record.full_name = if mask_pii {
+            mask_name(&full_name)
+        } else {
+            full_name
+        };
+        out.write_all(&writer.push(record)).await?;
+        progress.advance().await?;
+    }
+    out.write_all(&writer.finish()).await?;
+    Ok(())
+}
+
+/// Writes the logs as one JSON array, the same shape the synchronous export returns.
+#[allow(dead_code)]
+async fn write_audit_logs<W: AsyncWrite + Unpin>(
+    pool: &PgPool,
+    filters: &AuditLogFilters,
+    mask_pii: bool,
+    out: &mut W,
+    progress: &mut Progress<'_>,
+) -> anyhow::Result<()> {
+    out.write_all(b"[").await?;
+    let mut query = audit_log::export_query(filters);
+    let mut rows = query.build_query_as::<AuditLog>().fetch(pool);
+    while let Some(log) = rows.try_next().await? {
+        if progress.rows > 0 {
+            out.write_all(b",").await?;
+        }
+        let entry = apply_pii_policy(AuditLogResponse::from(log), mask_pii);
+        out.write_all(&serde_json::to_vec(&entry)?).await?;
+        progress.advance().await?;
+    }
+    out.write_all(b"]").await?;
+    Ok(())
+}
+
+/// Counts rows written and, for jobs, periodically records the count on the job.
+#[allow(dead_code)]
+struct Progress<'a> {
+    pool: &'a PgPool,
+    job_id: Option<ExportJobId>,
+    rows: i64,
+}
+
+#[allow(dead_code)]
+impl<'a> Progress<'a> {
+    fn new(pool: &'a PgPool, job_id: Option<ExportJobId>) -> Self {
+        Self {
+            pool,
+            job_id,
+            rows: 0,
+        }
+    }
+
+    async fn set_total(&self, total: i64) -> Result<(), sqlx::Error> {
+        match self.job_id {
+            Some(job_id) => export_job::set_total_rows(self.pool, job_id, total).await,
+            None => Ok(()),
+        }
+    }
+
+    async fn advance(&mut self) -> Result<(), sqlx::Error> {
+        self.rows += 1;
+        if let Some(job_id) = self.job_id {
+            if self.rows % PROGRESS_INTERVAL == 0 {
+                export_job::update_progress(self.pool, job_id, self.rows).await?;
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,254 @@
+//! Runs recurring exports: works out when each schedule fires, writes the export for the
+//! period before that time and delivers the file to the schedule's destination.
+//!
+//! Every run, successful or not, is recorded in the audit log.
+
+use std::path::PathBuf;
+
+use anyhow::{anyhow, Context};
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+use croner::Cron;
+use serde_json::json;
+use sqlx::PgPool;
+
+use crate::{
+    config::Config,
+    models::export_schedule::{ExportDestination, ExportRunStatus, ExportSchedule},
+    repositories::{export_schedule, export_template::ExportRecordFilter},
+    services::{
+        audit_log::{AuditLogEntry, AuditLogServiceTrait},
+        export_encryption::ExportEncryption,
+        export_job::{encrypt_export_file, write_export_file, ExportFile, ExportJobParameters},
+        export_storage::ExportStorage,
+    },
+    utils::email::EmailService,
+};
+
+/// Parses a five-field cron expression (minute, hour, day of month, month, day of week).
+pub fn parse_cron(expression: &str) -> Result<Cron, String> {
+    Cron::new(expression.trim())
+        .parse()
+        .map_err(|err| format!("Invalid cron expression '{}': {}", expression, err))
+}
+
+/// First time after `after` that the expression matches in `time_zone`.
+pub fn next_run_after(
+    expression: &str,
+    time_zone: &Tz,
+    after: DateTime<Utc>,
+) -> Result<DateTime<Utc>, String> {
+    parse_cron(expression)?
+        .find_next_occurrence(&after.with_timezone(time_zone), false)
+        .map(|next| next.with_timezone(&Utc))
+        .map_err(|err| format!("Cron expression '{}' never matches: {}", expression, err))
+}
+
+/// Outcome of one pass over the due schedules.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ScheduleRunSummary {
+    pub succeeded: usize,
+    pub failed: usize,
+}
+
+/// What a successful run exported and delivered.
+#[derive(Debug, Clone)]
+#[allow(dead_code)]
+struct ScheduleDelivery {
+    file: ExportFile,
+    from: NaiveDate,
+    to: NaiveDate,
+}
+
+/// Runs every enabled schedule whose next run is at or before `now`.
+///
+/// A schedule that fell behind runs once and then resumes at its next time after `now`.
+#[allow(dead_code)]
+pub async fn run_due_schedules(
+    pool: &PgPool,
+    config: &Config,
+    audit: &dyn AuditLogServiceTrait,
+    now: DateTime<Utc>,
+) -> anyhow::Result<ScheduleRunSummary> {
+    let mut summary = ScheduleRunSummary::default();
+    for schedule in export_schedule::list_due_schedules(pool, now).await? {
+        let scheduled_for = schedule.next_run_at;
+        let next_run_at = match next_run_after(&schedule.cron_expression, &config.time_zone, now) {
+            Ok(next) => next,
+            Err(err) => {
+                tracing::warn!(schedule_id = %schedule.id, error = %err, "export schedule skipped");
+                continue;
+            }
+        };
+        if !export_schedule::claim_run(pool, schedule.id, scheduled_for, next_run_at).await? {
+            continue;
+        }
+
+        let result = run_schedule(pool, config, &schedule, scheduled_for).await;
+        let ran_at = Utc::now();
+        let (status, error) = match &result {
+            Ok(_) => {
+                summary.succeeded += 1;
+                (ExportRunStatus::Success, None)
+            }
+            Err(err) => {
+                summary.failed += 1;
+                tracing::warn!(schedule_id = %schedule.id, error = %err, "export schedule run failed");
+                (ExportRunStatus::Failure, Some(format!("{err:#}")))
+            }
+        };
+        export_schedule::record_run(pool, schedule.id, status, error.as_deref(), ran_at).await?;
+
+        let metadata = match &result {
+            Ok(delivery) => json!({
+                "schedule_name": schedule.name,
+                "scheduled_for": scheduled_for,
+                "destination": schedule.destination.kind(),
+                "from": delivery.from,
+                "to": delivery.to,
+                "filename": delivery.file.filename,
+                "rows": delivery.file.rows,
+                "size_bytes": delivery.file.size_bytes,
+            }),
+            Err(_) => json!({
+                "schedule_name": schedule.name,
+                "scheduled_for": scheduled_for,
+                "destination": schedule.destination.kind(),
+                "error": error,
+            }),
+        };
+        let entry = AuditLogEntry {
+            occurred_at: ran_at,
+            actor_id: None,
+            actor_type: "system".to_string(),
+            event_type: "export_schedule_run".to_string(),
+            target_type: Some("export_schedule".to_string()),
+            target_id: Some(schedule.id.to_string()),
+            result: status.as_str().to_string(),
+            error_code: error.as_ref().map(|_| "export_failed".to_string()),
+            metadata: Some(metadata),
+            ip: None,
+            user_agent: None,
+            request_id: None,
+        };
+        if let Err(err) = audit.record_event(entry).await {
+            tracing::warn!(schedule_id = %schedule.id, error = ?err, "failed to record export schedule run");
+        }
+    }
+    Ok(summary)
+}
+
+#[allow(dead_code)]
+async fn run_schedule(
+    pool: &PgPool,
+    config: &Config,
+    schedule: &ExportSchedule,
+    scheduled_for: DateTime<Utc>,
+) -> anyhow::Result<ScheduleDelivery> {
+    let today = scheduled_for.with_timezone(&config.time_zone).date_naive();
+    let (from, to) = schedule.period.range(today);
+    let parameters = ExportJobParameters::Attendance {
+        filter: ExportRecordFilter {
+            username: schedule.username.clone(),
+            user_ids: None,
+            from: Some(from),
+            to: Some(to),
+        },
+        template_id: schedule.template_id,
+        // Payroll needs real names; only system administrators can set up schedules.
+        mask_pii: false,
+    };
+
+    let work_path = std::env::temp_dir().join(format!(
+        "export-schedule-{}-{}.part",
+        schedule.id,
+        scheduled_for.timestamp()
+    ));
+    let result = async {
+        let mut file = write_export_file(pool, config, parameters, &work_path, None).await?;
+        if let Some(recipient) = schedule.encryption_recipient.as_deref() {
+            let encryption =
+                ExportEncryption::for_recipient(recipient).map_err(|err| anyhow!(err))?;
+            file = encrypt_export_file(encryption, &work_path, file).await?;
+        }
+        deliver(config, schedule, &work_path, &file).await?;
+        Ok(ScheduleDelivery { file, from, to })
+    }
+    .await;
+    tokio::fs::remove_file(&work_path).await.ok();
+    result
+}
+
+#[allow(dead_code)]
+async fn deliver(
+    config: &Config,
+    schedule: &ExportSchedule,
+    work_path: &std::path::Path,
+    file: &ExportFile,
+) -> anyhow::Result<()> {
+    match &schedule.destination {
+        ExportDestination::Local { directory } => {
+            let storage = ExportStorage::Local {
+                root: PathBuf::from(&config.export_storage_dir).join(directory.trim()),
+            };
+            storage
+                .store(&file.filename, work_path, &file.content_type)
+                .await
+        }
+        ExportDestination::S3 { bucket, prefix } => {
+            let key = match prefix.as_deref().map(|p| p.trim_matches('/')) {
+                Some(prefix) if !prefix.is_empty() => format!("{}/{}", prefix, file.filename),
+                _ => file.filename.clone(),
+            };
+            ExportStorage::s3(config, bucket.trim().to_string())
+                .await
+                .store(&key, work_path, &file.content_type)
+                .await
+        }
+        ExportDestination::Email { recipients } => {
+            let data = tokio::fs::read(work_path)
+                .await
+                .context("read export file")?;
+            EmailService::new()?.send_scheduled_export(
+                recipients,
+                &schedule.name,
+                &file.filename,
+                &file.content_type,
+                data,
+            )
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn next_run_is_evaluated_in_the_application_time_zone() {
+        let tokyo: Tz = "Asia/Tokyo".parse().unwrap();
+        let after = Utc.with_ymd_and_hms(2026, 1, 15, 0, 0, 0).unwrap();
+        // 06:00 JST on 1 February.
+        assert_eq!(
+            next_run_after("0 6 1 * *", &tokyo, after).unwrap(),
+            Utc.with_ymd_and_hms(2026, 1, 31, 21, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn next_run_is_strictly_after_the_given_time() {
+        let after = Utc.with_ymd_and_hms(2026, 2, 1, 6, 0, 0).unwrap();
+        assert_eq!(
+            next_run_after("0 6 1 * *", &Tz::UTC, after).unwrap(),
+            Utc.with_ymd_and_hms(2026, 3, 1, 6, 0, 0).unwrap()
+        );
+    }
+
+    #[test]
+    fn malformed_cron_expressions_are_rejected() {
+        assert!(parse_cron("every month").is_err());
+        assert!(parse_cron("0 25 * * *").is_err());
+        assert!(parse_cron("0 6 1 * *").is_ok());
+    }
+}
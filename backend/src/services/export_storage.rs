@@ -0,0 +1,229 @@
+//! Store for finished export files: a local directory or an S3-compatible bucket.
+//!
+//! S3 downloads use presigned URLs. Local files are served by the API itself through
+//! links carrying an expiry and an HMAC signature, so no session is needed to fetch them.
+
+use std::{
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use anyhow::Context;
+use aws_config::BehaviorVersion;
+use aws_sdk_s3::{presigning::PresigningConfig, primitives::ByteStream};
+use chrono::{DateTime, Utc};
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::{config::Config, types::ExportJobId};
+
+type HmacSha256 = Hmac<Sha256>;
+
+/// Where finished export files are kept.
+#[derive(Debug, Clone)]
+pub enum ExportStorage {
+    Local {
+        root: PathBuf,
+    },
+    S3 {
+        client: aws_sdk_s3::Client,
+        bucket: String,
+    },
+}
+
+impl ExportStorage {
+    /// Uses the S3 bucket when one is configured, otherwise the local export directory.
+    pub async fn from_config(config: &Config) -> Self {
+        match config.export_s3_bucket.clone() {
+            Some(bucket) => ExportStorage::s3(config, bucket).await,
+            None => ExportStorage::Local {
+                root: PathBuf::from(&config.export_storage_dir),
+            },
+        }
+    }
+
+    /// A bucket in the configured region, reached through the export S3 endpoint if set.
+    pub async fn s3(config: &Config, bucket: String) -> Self {
+        let shared = aws_config::defaults(BehaviorVersion::latest())
+            .region(aws_config::Region::new(config.aws_region.clone()))
+            .load()
+            .await;
+        let mut builder = aws_sdk_s3::config::Builder::from(&shared);
+        if let Some(endpoint) = &config.export_s3_endpoint {
+            // S3-compatible stores are usually addressed by path rather than bucket subdomain.
+            builder = builder.endpoint_url(endpoint).force_path_style(true);
+        }
+        ExportStorage::S3 {
+            client: aws_sdk_s3::Client::from_conf(builder.build()),
+            bucket,
+        }
+    }
+
+    /// Moves the finished file at `source` into the store under `key`.
+    #[allow(dead_code)]
+    pub async fn store(&self, key: &str, source: &Path, content_type: &str) -> anyhow::Result<()> {
+        match self {
+            ExportStorage::Local { root } => {
+                let target = root.join(key);
+                if let Some(parent) = target.parent() {
+                    tokio::fs::create_dir_all(parent)
+                        .await
+                        .with_context(|| format!("create {}", parent.display()))?;
+                }
+                if tokio::fs::rename(source, &target).await.is_err() {
+                    // The work file may live on another filesystem.
+                    tokio::fs::copy(source, &target)
+                        .await
+                        .with_context(|| format!("copy export to {}", target.display()))?;
+                    tokio::fs::remove_file(source).await.ok();
+                }
+                Ok(())
+            }
+            ExportStorage::S3 { client, bucket } => {
+                let body = ByteStream::from_path(source)
+                    .await
+                    .context("read export file")?;
+                client
+                    .put_object()
+                    .bucket(bucket)
+                    .key(key)
+                    .content_type(content_type)
+                    .body(body)
+                    .send()
+                    .await
+                    .context("upload export to S3")?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns a link to the stored file that stops working at `expires_at`.
+    ///
+    /// Local links are relative to the API origin.
+    pub async fn download_url(
+        &self,
+        job_id: ExportJobId,
+        key: &str,
+        filename: &str,
+        expires_at: DateTime<Utc>,
+        config: &Config,
+    ) -> anyhow::Result<String> {
+        match self {
+            ExportStorage::Local { .. } => {
+                let expires = expires_at.timestamp();
+                Ok(format!(
+                    "/api/export-downloads/{}?expires={}&signature={}",
+                    job_id,
+                    expires,
+                    sign_download(job_id, expires, &config.export_download_secret)
+                ))
+            }
+            ExportStorage::S3 { client, bucket } => {
+                let ttl = (expires_at - Utc::now()).num_seconds().max(1) as u64;
+                let presigning = PresigningConfig::expires_in(Duration::from_secs(ttl))?;
+                let request = client
+                    .get_object()
+                    .bucket(bucket)
+                    .key(key)
+                    .response_content_disposition(format!("attachment; filename=\"{}\"", filename))
+                    .presigned(presigning)
+                    .await
+                    .context("presign export download")?;
+                Ok(request.uri().to_string())
+            }
+        }
+    }
+
+    /// Path of a stored file, when files are kept on the local filesystem.
+    pub fn local_path(&self, key: &str) -> Option<PathBuf> {
+        match self {
+            ExportStorage::Local { root } => Some(root.join(key)),
+            ExportStorage::S3 { .. } => None,
+        }
+    }
+}
+
+/// Hex-encoded HMAC-SHA256 over the job ID and expiry of a local download link.
+pub fn sign_download(job_id: ExportJobId, expires: i64, secret: &str) -> String {
+    hex::encode(
+        download_mac(job_id, expires, secret)
+            .finalize()
+            .into_bytes(),
+    )
+}
+
+/// Returns true if `signature` was issued for this job and expiry and the link has not expired.
+pub fn verify_download(
+    job_id: ExportJobId,
+    expires: i64,
+    signature: &str,
+    secret: &str,
+    now: DateTime<Utc>,
+) -> bool {
+    if now.timestamp() > expires {
+        return false;
+    }
+    let Ok(signature) = hex::decode(signature) else {
+        return false;
+    };
+    download_mac(job_id, expires, secret)
+        .verify_slice(&signature)
+        .is_ok()
+}
+
+fn download_mac(job_id: ExportJobId, expires: i64, secret: &str) -> HmacSha256 {
+    let mut mac =
+        HmacSha256::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(format!("{}:{}", job_id, expires).as_bytes());
+    mac
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration as ChronoDuration;
+
+    const SECRET: &str = "a-test-secret-that-is-long-enough-for-jwt";
+
+    #[test]
+    fn download_signatures_are_bound_to_job_expiry_and_secret() {
+        let job_id = ExportJobId::new();
+        let now = Utc::now();
+        let expires = (now + ChronoDuration::minutes(5)).timestamp();
+        let signature = sign_download(job_id, expires, SECRET);
+
+        assert!(verify_download(job_id, expires, &signature, SECRET, now));
+        assert!(!verify_download(
+            ExportJobId::new(),
+            expires,
+            &signature,
+            SECRET,
+            now
+        ));
+        assert!(!verify_download(
+            job_id,
+            expires + 1,
+            &signature,
+            SECRET,
+            now
+        ));
+        assert!(!verify_download(
+            job_id,
+            expires,
+            &signature,
+            "other-secret",
+            now
+        ));
+        assert!(!verify_download(job_id, expires, "not-hex", SECRET, now));
+    }
+
+    #[test]
+    fn download_links_stop_working_after_expiry() {
+        let job_id = ExportJobId::new();
+        let now = Utc::now();
+        let expires = (now - ChronoDuration::seconds(1)).timestamp();
+        let signature = sign_download(job_id, expires, SECRET);
+
+        assert!(!verify_download(job_id, expires, &signature, SECRET, now));
+    }
+}
@@ -0,0 +1,335 @@
+//! Renders attendance into the layout of a payroll export template.
+
+use chrono::{NaiveDate, NaiveDateTime};
+use encoding_rs::{EncoderResult, SHIFT_JIS};
+
+use crate::{
+    models::export_template::{ExportEncoding, ExportField, ExportGranularity, ExportTemplate},
+    repositories::export_template::ExportRecord,
+    utils::{csv::append_delimited_row, time::month_start},
+};
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// One output row: an attendance day, or the sum of an employee's days in a month.
+#[derive(Debug, Clone, PartialEq)]
+struct ExportLine {
+    username: String,
+    full_name: String,
+    employee_code: Option<String>,
+    date: Option<NaiveDate>,
+    month: NaiveDate,
+    clock_in: Option<NaiveDateTime>,
+    clock_out: Option<NaiveDateTime>,
+    status: Option<String>,
+    work_days: i64,
+    break_minutes: i64,
+    total_hours: f64,
+    regular_hours: f64,
+    statutory_overtime_hours: f64,
+    late_night_hours: f64,
+    legal_holiday_hours: f64,
+    non_statutory_holiday_hours: f64,
+}
+
+impl ExportLine {
+    fn daily(record: ExportRecord) -> Self {
+        Self {
+            month: month_start(record.date),
+            date: Some(record.date),
+            work_days: i64::from(record.clock_in_time.is_some()),
+            clock_in: record.clock_in_time,
+            clock_out: record.clock_out_time,
+            status: Some(record.status),
+            break_minutes: record.break_minutes,
+            total_hours: record.total_work_hours.unwrap_or(0.0),
+            regular_hours: record.regular_hours.unwrap_or(0.0),
+            statutory_overtime_hours: record.statutory_overtime_hours.unwrap_or(0.0),
+            late_night_hours: record.late_night_hours.unwrap_or(0.0),
+            legal_holiday_hours: record.legal_holiday_hours.unwrap_or(0.0),
+            non_statutory_holiday_hours: record.non_statutory_holiday_hours.unwrap_or(0.0),
+            username: record.username,
+            full_name: record.full_name,
+            employee_code: record.employee_code,
+        }
+    }
+
+    /// Folds another day of the same employee and month into a monthly line.
+    fn absorb(&mut self, day: ExportLine) {
+        self.work_days += day.work_days;
+        self.break_minutes += day.break_minutes;
+        self.total_hours += day.total_hours;
+        self.regular_hours += day.regular_hours;
+        self.statutory_overtime_hours += day.statutory_overtime_hours;
+        self.late_night_hours += day.late_night_hours;
+        self.legal_holiday_hours += day.legal_holiday_hours;
+        self.non_statutory_holiday_hours += day.non_statutory_holiday_hours;
+    }
+
+    fn value(&self, field: ExportField, template: &ExportTemplate) -> String {
+        let hours = |value: f64| format!("{:.2}", value);
+        let clock = |value: Option<NaiveDateTime>| {
+            value
+                .map(|time| time.format("%H:%M").to_string())
+                .unwrap_or_default()
+        };
+        match field {
+            ExportField::EmployeeCode => self.employee_code.clone().unwrap_or_default(),
+            ExportField::Username => self.username.clone(),
+            ExportField::FullName => self.full_name.clone(),
+            ExportField::Date => self
+                .date
+                .map(|date| date.format(&template.date_format).to_string())
+                .unwrap_or_default(),
+            ExportField::Month => self.month.format(&template.month_format).to_string(),
+            ExportField::ClockIn => clock(self.clock_in),
+            ExportField::ClockOut => clock(self.clock_out),
+            ExportField::Status => self.status.clone().unwrap_or_default(),
+            ExportField::WorkDays => self.work_days.to_string(),
+            ExportField::BreakMinutes => self.break_minutes.to_string(),
+            ExportField::TotalHours => hours(self.total_hours),
+            ExportField::RegularHours => hours(self.regular_hours),
+            ExportField::StatutoryOvertimeHours => hours(self.statutory_overtime_hours),
+            ExportField::LateNightHours => hours(self.late_night_hours),
+            ExportField::LegalHolidayHours => hours(self.legal_holiday_hours),
+            ExportField::NonStatutoryHolidayHours => hours(self.non_statutory_holiday_hours),
+        }
+    }
+}
+
+/// Renders records ordered by username and date into the template's file contents.
+///
+/// The template must have passed [`ExportTemplate::check_layout`].
+pub fn render(template: &ExportTemplate, records: Vec<ExportRecord>) -> Vec<u8> {
+    let mut writer = TemplateWriter::new(template);
+    let mut output = writer.start();
+    for record in records {
+        output.extend(writer.push(record));
+    }
+    output.extend(writer.finish());
+    output
+}
+
+/// Renders a template one record at a time, so exports can be written out as rows arrive.
+pub struct TemplateWriter<'a> {
+    template: &'a ExportTemplate,
+    /// Monthly line still collecting days.
+    pending: Option<ExportLine>,
+}
+
+impl<'a> TemplateWriter<'a> {
+    pub fn new(template: &'a ExportTemplate) -> Self {
+        Self {
+            template,
+            pending: None,
+        }
+    }
+
+    /// Bytes preceding the first row: the byte order mark and the header row, if any.
+    pub fn start(&self) -> Vec<u8> {
+        let mut output = Vec::new();
+        if self.template.encoding == ExportEncoding::Utf8Bom {
+            output.extend_from_slice(UTF8_BOM);
+        }
+        if self.template.include_header {
+            let headers: Vec<String> = self
+                .template
+                .columns
+                .iter()
+                .map(|column| column.header().to_string())
+                .collect();
+            output.extend(self.encode_row(&headers));
+        }
+        output
+    }
+
+    /// Adds the next record, ordered by username and date, and returns the rows it completes.
+    pub fn push(&mut self, record: ExportRecord) -> Vec<u8> {
+        let day = ExportLine::daily(record);
+        if self.template.granularity == ExportGranularity::Daily {
+            return self.render_line(&day);
+        }
+        match self.pending.as_mut() {
+            Some(month) if month.username == day.username && month.month == day.month => {
+                month.absorb(day);
+                Vec::new()
+            }
+            _ => {
+                let finished = self.pending.replace(ExportLine {
+                    date: None,
+                    clock_in: None,
+                    clock_out: None,
+                    status: None,
+                    ..day
+                });
+                finished
+                    .map(|line| self.render_line(&line))
+                    .unwrap_or_default()
+            }
+        }
+    }
+
+    /// Returns the last monthly row, if one is still open.
+    pub fn finish(mut self) -> Vec<u8> {
+        self.pending
+            .take()
+            .map(|line| self.render_line(&line))
+            .unwrap_or_default()
+    }
+
+    fn render_line(&self, line: &ExportLine) -> Vec<u8> {
+        let cells: Vec<String> = self
+            .template
+            .columns
+            .iter()
+            .map(|column| line.value(column.field, self.template))
+            .collect();
+        self.encode_row(&cells)
+    }
+
+    fn encode_row(&self, cells: &[String]) -> Vec<u8> {
+        let mut text = String::new();
+        append_delimited_row(&mut text, cells, self.template.delimiter.as_char());
+        encode(&text, self.template.encoding)
+    }
+}
+
+fn encode(text: &str, encoding: ExportEncoding) -> Vec<u8> {
+    match encoding {
+        ExportEncoding::Utf8 | ExportEncoding::Utf8Bom => text.as_bytes().to_vec(),
+        ExportEncoding::ShiftJis => encode_shift_jis(text),
+    }
+}
+
+/// Encodes to Shift_JIS, writing `?` for characters it cannot represent.
+fn encode_shift_jis(text: &str) -> Vec<u8> {
+    let mut encoder = SHIFT_JIS.new_encoder();
+    let mut output = Vec::with_capacity(text.len());
+    let mut buffer = [0u8; 1024];
+    let mut input = text;
+    loop {
+        let (result, read, written) =
+            encoder.encode_from_utf8_without_replacement(input, &mut buffer, true);
+        output.extend_from_slice(&buffer[..written]);
+        input = &input[read..];
+        match result {
+            EncoderResult::InputEmpty => return output,
+            EncoderResult::OutputFull => {}
+            EncoderResult::Unmappable(_) => output.push(b'?'),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::export_template::{ExportColumn, ExportDelimiter};
+    use crate::types::ExportTemplateId;
+    use chrono::Utc;
+
+    fn record(username: &str, date: NaiveDate, hours: f64) -> ExportRecord {
+        ExportRecord {
+            username: username.into(),
+            full_name: "山田 太郎".into(),
+            employee_code: Some(format!("E-{username}")),
+            date,
+            clock_in_time: date.and_hms_opt(9, 0, 0),
+            clock_out_time: date.and_hms_opt(18, 0, 0),
+            status: "present".into(),
+            break_minutes: 60,
+            total_work_hours: Some(hours),
+            regular_hours: Some(hours.min(8.0)),
+            statutory_overtime_hours: Some((hours - 8.0).max(0.0)),
+            late_night_hours: None,
+            legal_holiday_hours: None,
+            non_statutory_holiday_hours: None,
+        }
+    }
+
+    fn template(granularity: ExportGranularity, fields: &[ExportField]) -> ExportTemplate {
+        ExportTemplate {
+            id: ExportTemplateId::new(),
+            name: "payroll".into(),
+            granularity,
+            encoding: ExportEncoding::Utf8,
+            delimiter: ExportDelimiter::Comma,
+            date_format: "%Y/%m/%d".into(),
+            month_format: "%Y%m".into(),
+            include_header: true,
+            columns: fields
+                .iter()
+                .map(|field| ExportColumn {
+                    field: *field,
+                    header: None,
+                })
+                .collect(),
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    fn date(m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, m, d).unwrap()
+    }
+
+    #[test]
+    fn monthly_templates_sum_days_per_employee_and_month() {
+        let template = template(
+            ExportGranularity::Monthly,
+            &[
+                ExportField::EmployeeCode,
+                ExportField::Month,
+                ExportField::WorkDays,
+                ExportField::TotalHours,
+                ExportField::StatutoryOvertimeHours,
+            ],
+        );
+        let records = vec![
+            record("alice", date(6, 2), 9.0),
+            record("alice", date(6, 3), 8.0),
+            record("alice", date(7, 1), 8.0),
+            record("bob", date(6, 2), 10.0),
+        ];
+
+        let output = String::from_utf8(render(&template, records)).unwrap();
+        assert_eq!(
+            output,
+            "\"Employee Code\",\"Month\",\"Work Days\",\"Total Hours\",\"Statutory Overtime Hours\"\n\
+             \"E-alice\",\"202506\",\"2\",\"17.00\",\"1.00\"\n\
+             \"E-alice\",\"202507\",\"1\",\"8.00\",\"0.00\"\n\
+             \"E-bob\",\"202506\",\"1\",\"10.00\",\"2.00\"\n"
+        );
+    }
+
+    #[test]
+    fn daily_templates_apply_headers_delimiter_and_date_format() {
+        let mut template = template(
+            ExportGranularity::Daily,
+            &[
+                ExportField::Date,
+                ExportField::ClockIn,
+                ExportField::FullName,
+            ],
+        );
+        template.delimiter = ExportDelimiter::Tab;
+        template.columns[0].header = Some("勤務日".into());
+
+        let output =
+            String::from_utf8(render(&template, vec![record("alice", date(6, 2), 8.0)])).unwrap();
+        assert_eq!(
+            output,
+            "\"勤務日\"\t\"Clock In\"\t\"Full Name\"\n\"2025/06/02\"\t\"09:00\"\t\"山田 太郎\"\n"
+        );
+    }
+
+    #[test]
+    fn shift_jis_output_replaces_unmappable_characters() {
+        assert_eq!(encode_shift_jis("山田"), vec![0x8E, 0x52, 0x93, 0x63]);
+        assert_eq!(encode_shift_jis("a😀b"), b"a?b".to_vec());
+
+        let mut template = template(ExportGranularity::Daily, &[ExportField::Username]);
+        template.encoding = ExportEncoding::Utf8Bom;
+        let output = render(&template, vec![record("alice", date(6, 2), 8.0)]);
+        assert_eq!(output, [UTF8_BOM, b"\"Username\"\n\"alice\"\n"].concat());
+    }
+}
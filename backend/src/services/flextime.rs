@@ -0,0 +1,143 @@
+//! Settles flextime hours against the required total of the settlement period.
+
+use chrono::{Datelike, Months, NaiveDate};
+use sqlx::PgPool;
+use std::collections::{HashMap, HashSet};
+
+use crate::error::AppError;
+use crate::models::work_schedule::FlextimeBalance;
+use crate::repositories::leave_request_repository::{
+    LeaveRequestRepository, LeaveRequestRepositoryTrait,
+};
+use crate::services::{
+    holiday::{HolidayService, HolidayServiceTrait},
+    leave_balance::approved_leave_hours_on,
+    work_schedule::WorkScheduleService,
+    work_time::load_effective_attendance,
+};
+use crate::types::UserId;
+use crate::utils::time::month_start;
+
+#[derive(Clone)]
+pub struct FlextimeService {
+    pool: PgPool,
+    schedules: WorkScheduleService,
+    holidays: HolidayService,
+}
+
+impl FlextimeService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            schedules: WorkScheduleService::new(pool.clone()),
+            holidays: HolidayService::new(pool.clone()),
+            pool,
+        }
+    }
+
+    /// Balance of the settlement period containing `as_of`, counted through `as_of`.
+    ///
+    /// Returns `None` unless a flextime schedule applies to the user on `as_of`.
+    pub async fn balance(
+        &self,
+        user_id: UserId,
+        as_of: NaiveDate,
+    ) -> Result<Option<FlextimeBalance>, AppError> {
+        let Some(schedule) = self
+            .schedules
+            .schedule_for(user_id, as_of)
+            .await?
+            .filter(|schedule| schedule.is_flextime())
+        else {
+            return Ok(None);
+        };
+        let (period_start, period_end) = schedule.settlement_period(as_of);
+        let holidays = self
+            .holiday_dates(user_id, period_start, period_end)
+            .await?;
+        let leave_requests = LeaveRequestRepository::new()
+            .find_by_user(&self.pool, user_id)
+            .await?;
+
+        let full_day = schedule.scheduled_hours();
+        let mut required_by_day = HashMap::new();
+        for date in period_start
+            .iter_days()
+            .take_while(|date| *date <= period_end)
+        {
+            let required = if holidays.contains(&date) || !schedule.works_on(date) {
+                0.0
+            } else {
+                (full_day - approved_leave_hours_on(&leave_requests, date, full_day)).max(0.0)
+            };
+            required_by_day.insert(date, required);
+        }
+
+        let mut worked_hours = 0.0;
+        let mut core_time_missed_dates = Vec::new();
+        for day in load_effective_attendance(&self.pool, user_id, period_start, as_of).await? {
+            worked_hours += day.total_work_hours.unwrap_or(0.0);
+            // Days shortened by approved leave may legitimately skip part of the core time.
+            let full_working_day = required_by_day
+                .get(&day.date)
+                .is_some_and(|required| *required > 0.0 && *required >= full_day);
+            if let (true, Some(clock_in), Some(clock_out)) =
+                (full_working_day, day.clock_in_time, day.clock_out_time)
+            {
+                if schedule.misses_core_time(day.date, clock_in, clock_out) {
+                    core_time_missed_dates.push(day.date);
+                }
+            }
+        }
+        core_time_missed_dates.sort_unstable();
+
+        let period_required_hours: f64 = required_by_day.values().sum();
+        let required_hours_to_date: f64 = required_by_day
+            .iter()
+            .filter(|(date, _)| **date <= as_of)
+            .map(|(_, hours)| hours)
+            .sum();
+        Ok(Some(FlextimeBalance {
+            period_start,
+            period_end,
+            as_of,
+            period_required_hours: round_hours(period_required_hours),
+            required_hours_to_date: round_hours(required_hours_to_date),
+            worked_hours: round_hours(worked_hours),
+            balance_hours: round_hours(worked_hours - required_hours_to_date),
+            core_time_missed_dates,
+        }))
+    }
+
+    /// Holidays of the user between two dates, after their holiday exceptions.
+    async fn holiday_dates(
+        &self,
+        user_id: UserId,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<HashSet<NaiveDate>, AppError> {
+        let user_id = user_id.to_string();
+        let mut dates = HashSet::new();
+        let mut month = month_start(start);
+        while month <= end {
+            let entries = self
+                .holidays
+                .list_month(month.year(), month.month(), Some(&user_id))
+                .await?;
+            dates.extend(
+                entries
+                    .iter()
+                    .filter(|entry| entry.is_holiday)
+                    .map(|entry| entry.date),
+            );
+            month = match month.checked_add_months(Months::new(1)) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+        Ok(dates)
+    }
+}
+
+fn round_hours(hours: f64) -> f64 {
+    (hours * 100.0).round() / 100.0
+}
@@ -0,0 +1,90 @@
+//! Long working hours and the physician interviews they call for.
+
+use std::collections::{HashMap, HashSet};
+
+use chrono::{Datelike, NaiveDate};
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::models::health_interview::{
+    health_overtime_hours, LongHoursEmployee, INTERVIEW_THRESHOLD_HOURS,
+};
+use crate::repositories::health_interview;
+use crate::services::work_time::load_effective_attendance;
+use crate::types::{DepartmentId, UserId};
+
+#[derive(Clone)]
+pub struct HealthInterviewService {
+    pool: PgPool,
+}
+
+impl HealthInterviewService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Hours a user worked in the month from `first_day` to `last_day` and how many of them
+    /// went beyond 40 per week.
+    pub async fn monthly_hours(
+        &self,
+        user_id: UserId,
+        first_day: NaiveDate,
+        last_day: NaiveDate,
+    ) -> Result<(f64, f64), AppError> {
+        let worked: f64 = load_effective_attendance(&self.pool, user_id, first_day, last_day)
+            .await?
+            .iter()
+            .filter_map(|attendance| attendance.total_work_hours)
+            .sum();
+        let days = (last_day - first_day).num_days() + 1;
+        Ok((
+            (worked * 100.0).round() / 100.0,
+            health_overtime_hours(worked, days),
+        ))
+    }
+
+    /// Employees whose overtime in the month is over the interview threshold, most
+    /// overtime first, with the interview recorded for them.
+    pub async fn report(
+        &self,
+        first_day: NaiveDate,
+        last_day: NaiveDate,
+        department_id: Option<DepartmentId>,
+        visible_users: Option<&HashSet<UserId>>,
+    ) -> Result<Vec<LongHoursEmployee>, AppError> {
+        let (year, month) = (first_day.year(), first_day.month());
+        let employees =
+            health_interview::list_users_with_work(&self.pool, first_day, last_day, department_id)
+                .await?;
+        let mut interviews: HashMap<UserId, _> =
+            health_interview::list_for_month(&self.pool, year, month)
+                .await?
+                .into_iter()
+                .map(|interview| (interview.user_id, interview))
+                .collect();
+
+        let mut report = Vec::new();
+        for (user_id, username, department_id) in employees {
+            if visible_users.is_some_and(|visible| !visible.contains(&user_id)) {
+                continue;
+            }
+            let (worked_hours, overtime_hours) =
+                self.monthly_hours(user_id, first_day, last_day).await?;
+            if overtime_hours <= INTERVIEW_THRESHOLD_HOURS {
+                continue;
+            }
+            report.push(LongHoursEmployee {
+                user_id,
+                username,
+                department_id,
+                year,
+                month,
+                worked_hours,
+                overtime_hours,
+                interview: interviews.remove(&user_id),
+            });
+        }
+        report.sort_by(|a, b| b.overtime_hours.total_cmp(&a.overtime_hours));
+        Ok(report)
+    }
+}
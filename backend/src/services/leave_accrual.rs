@@ -0,0 +1,288 @@
+//! Statutory paid-leave accrual based on hire date and employment type.
+//!
+//! Grants follow the Labor Standards Act schedule: the first grant is due six months after
+//! hire, later grants every twelve months, and each grant lapses two years after it is given.
+//! Part-timers scheduled for fewer than 30 hours and at most four days a week receive the
+//! proportional grant for their weekly days.
+
+use std::collections::HashSet;
+
+use chrono::{Months, NaiveDate};
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::models::employment_profile::{
+    AccrualPreviewItem, AccrualPreviewStatus, AccrualRunSummary, EmploymentProfile, EmploymentType,
+};
+use crate::models::leave_balance::{LeaveBalanceEntry, LeaveBalanceEntryType};
+use crate::models::leave_request::LeaveType;
+use crate::repositories::{employment_profile, leave_balance};
+use crate::types::UserId;
+
+/// Months between hire and the first statutory grant.
+const FIRST_GRANT_MONTHS: u32 = 6;
+/// Months a statutory grant stays usable.
+const GRANT_VALIDITY_MONTHS: u32 = 24;
+/// Weekly hours from which the full-time table applies regardless of scheduled days.
+const FULL_TABLE_WEEKLY_HOURS: f64 = 30.0;
+
+/// Days granted at 0.5, 1.5, ... 6.5+ years of service for the full-time table.
+const FULL_TIME_GRANTS: [f64; 7] = [10.0, 11.0, 12.0, 14.0, 16.0, 18.0, 20.0];
+/// Proportional grants indexed by weekly scheduled days (1 to 4), then by years of service.
+const PROPORTIONAL_GRANTS: [[f64; 7]; 4] = [
+    [1.0, 2.0, 2.0, 2.0, 3.0, 3.0, 3.0],
+    [3.0, 4.0, 4.0, 5.0, 6.0, 6.0, 7.0],
+    [5.0, 6.0, 6.0, 8.0, 9.0, 10.0, 11.0],
+    [7.0, 8.0, 9.0, 10.0, 12.0, 13.0, 15.0],
+];
+
+/// A grant owed under the statutory schedule.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ScheduledGrant {
+    pub grant_date: NaiveDate,
+    pub days: f64,
+    pub expires_on: NaiveDate,
+}
+
+/// Days granted on the `index`-th grant date (0 = six months after hire).
+pub fn statutory_grant_days(profile: &EmploymentProfile, index: usize) -> f64 {
+    let step = index.min(FULL_TIME_GRANTS.len() - 1);
+    let proportional = profile.employment_type == EmploymentType::PartTime
+        && profile.weekly_scheduled_hours < FULL_TABLE_WEEKLY_HOURS
+        && profile.weekly_scheduled_days < 5;
+    if !proportional {
+        return FULL_TIME_GRANTS[step];
+    }
+    match profile.weekly_scheduled_days {
+        days @ 1..=4 => PROPORTIONAL_GRANTS[days as usize - 1][step],
+        _ => 0.0,
+    }
+}
+
+/// The `index`-th scheduled grant for a profile.
+pub fn scheduled_grant(profile: &EmploymentProfile, index: usize) -> Option<ScheduledGrant> {
+    let months = FIRST_GRANT_MONTHS.checked_add(u32::try_from(index).ok()?.checked_mul(12)?)?;
+    let grant_date = profile.hire_date.checked_add_months(Months::new(months))?;
+    let expires_on = grant_date.checked_add_months(Months::new(GRANT_VALIDITY_MONTHS))?;
+    Some(ScheduledGrant {
+        grant_date,
+        days: statutory_grant_days(profile, index),
+        expires_on,
+    })
+}
+
+/// Grants already given by `as_of` that have not lapsed yet.
+pub fn active_grants(profile: &EmploymentProfile, as_of: NaiveDate) -> Vec<ScheduledGrant> {
+    (0..)
+        .map_while(|index| scheduled_grant(profile, index))
+        .take_while(|grant| grant.grant_date <= as_of)
+        .filter(|grant| grant.expires_on > as_of && grant.days > 0.0)
+        .collect()
+}
+
+/// The first grant falling after `as_of`.
+pub fn next_grant(profile: &EmploymentProfile, as_of: NaiveDate) -> Option<ScheduledGrant> {
+    (0..)
+        .map_while(|index| scheduled_grant(profile, index))
+        .find(|grant| grant.grant_date > as_of)
+        .filter(|grant| grant.days > 0.0)
+}
+
+/// Days of a grant that lapse unused, consuming the oldest grants first.
+///
+/// `balance_before_expiry` is the ledger balance on the day before expiry and
+/// `newer_grants` the days granted after this grant that are still valid then.
+#[allow(dead_code)]
+pub fn lapsed_days(balance_before_expiry: f64, newer_grants: f64, granted: f64) -> f64 {
+    (balance_before_expiry - newer_grants).clamp(0.0, granted.max(0.0))
+}
+
+#[derive(Clone)]
+pub struct LeaveAccrualService {
+    pool: PgPool,
+}
+
+impl LeaveAccrualService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Lists grants due by `as_of` that are not booked yet, plus each user's next grant.
+    ///
+    /// When `visible_users` is given, only those users are included.
+    pub async fn preview(
+        &self,
+        as_of: NaiveDate,
+        visible_users: Option<&HashSet<UserId>>,
+    ) -> Result<Vec<AccrualPreviewItem>, AppError> {
+        let profiles = employment_profile::list_with_users(&self.pool).await?;
+        let booked: HashSet<(UserId, NaiveDate)> =
+            leave_balance::list_statutory_grant_dates(&self.pool, &LeaveType::annual())
+                .await?
+                .into_iter()
+                .collect();
+
+        let mut items = Vec::new();
+        for row in profiles {
+            let profile = &row.profile;
+            if visible_users.is_some_and(|users| !users.contains(&profile.user_id)) {
+                continue;
+            }
+            let due = active_grants(profile, as_of)
+                .into_iter()
+                .filter(|grant| !booked.contains(&(profile.user_id, grant.grant_date)))
+                .map(|grant| (grant, AccrualPreviewStatus::Due));
+            let upcoming = next_grant(profile, as_of)
+                .into_iter()
+                .map(|grant| (grant, AccrualPreviewStatus::Upcoming));
+            items.extend(
+                due.chain(upcoming)
+                    .map(|(grant, status)| AccrualPreviewItem {
+                        user_id: profile.user_id,
+                        username: row.username.clone(),
+                        employment_type: profile.employment_type,
+                        grant_date: grant.grant_date,
+                        days: grant.days,
+                        expires_on: grant.expires_on,
+                        status,
+                    }),
+            );
+        }
+        Ok(items)
+    }
+
+    /// Books every due statutory grant and processes lapsed grants as of the given date.
+    ///
+    /// Safe to re-run: grants and expirations already booked are skipped.
+    #[allow(dead_code)]
+    pub async fn run(&self, as_of: NaiveDate) -> Result<AccrualRunSummary, AppError> {
+        let mut summary = AccrualRunSummary::default();
+        for row in employment_profile::list_with_users(&self.pool).await? {
+            for grant in active_grants(&row.profile, as_of) {
+                let mut entry = LeaveBalanceEntry::new(
+                    row.profile.user_id,
+                    LeaveType::annual(),
+                    LeaveBalanceEntryType::Grant,
+                    grant.days,
+                    grant.grant_date,
+                );
+                entry.expires_on = Some(grant.expires_on);
+                entry.statutory_grant = true;
+                entry.note = Some("Statutory annual leave grant".to_string());
+                summary.granted +=
+                    leave_balance::insert_entry_if_absent(&self.pool, &entry).await?;
+            }
+        }
+        summary.expired = self.expire_lapsed_grants(as_of).await?;
+        Ok(summary)
+    }
+
+    /// Records an expiration entry for every grant whose expiry date has been reached.
+    #[allow(dead_code)]
+    async fn expire_lapsed_grants(&self, as_of: NaiveDate) -> Result<u64, AppError> {
+        let mut processed = 0;
+        for grant in leave_balance::list_unexpired_lapsed_grants(&self.pool, as_of).await? {
+            let Some(expires_on) = grant.expires_on else {
+                continue;
+            };
+            let mut tx = self.pool.begin().await?;
+            leave_balance::lock_user_ledger(&mut *tx, grant.user_id).await?;
+            let (balance, newer_grants) =
+                leave_balance::fetch_expiry_position(&mut *tx, &grant).await?;
+            let mut entry = LeaveBalanceEntry::new(
+                grant.user_id,
+                grant.leave_type.clone(),
+                LeaveBalanceEntryType::Expiration,
+                -lapsed_days(balance, newer_grants, grant.days),
+                expires_on,
+            );
+            entry.source_entry_id = Some(grant.id);
+            entry.note = Some(format!("Grant of {} expired", grant.effective_date));
+            processed += leave_balance::insert_entry_if_absent(&mut *tx, &entry).await?;
+            tx.commit().await?;
+        }
+        Ok(processed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Utc;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).expect("valid date")
+    }
+
+    fn profile(employment_type: EmploymentType, days: i16, hours: f64) -> EmploymentProfile {
+        EmploymentProfile {
+            user_id: UserId::new(),
+            hire_date: date(2020, 4, 1),
+            employment_type,
+            weekly_scheduled_days: days,
+            weekly_scheduled_hours: hours,
+            employee_code: None,
+            created_at: Utc::now(),
+            updated_at: Utc::now(),
+        }
+    }
+
+    #[test]
+    fn full_time_schedule_rises_to_twenty_days() {
+        let full_time = profile(EmploymentType::FullTime, 5, 40.0);
+        let days: Vec<f64> = (0..8)
+            .map(|index| statutory_grant_days(&full_time, index))
+            .collect();
+        assert_eq!(days, vec![10.0, 11.0, 12.0, 14.0, 16.0, 18.0, 20.0, 20.0]);
+    }
+
+    #[test]
+    fn part_time_schedule_is_proportional_to_weekly_days() {
+        let three_days = profile(EmploymentType::PartTime, 3, 18.0);
+        assert_eq!(statutory_grant_days(&three_days, 0), 5.0);
+        assert_eq!(statutory_grant_days(&three_days, 6), 11.0);
+
+        let long_hours = profile(EmploymentType::PartTime, 4, 32.0);
+        assert_eq!(statutory_grant_days(&long_hours, 0), 10.0);
+
+        let no_schedule = profile(EmploymentType::PartTime, 0, 0.0);
+        assert_eq!(statutory_grant_days(&no_schedule, 0), 0.0);
+    }
+
+    #[test]
+    fn grants_start_six_months_after_hire_and_expire_after_two_years() {
+        let full_time = profile(EmploymentType::FullTime, 5, 40.0);
+        assert!(active_grants(&full_time, date(2020, 9, 30)).is_empty());
+
+        let grants = active_grants(&full_time, date(2022, 10, 1));
+        assert_eq!(
+            grants,
+            vec![
+                ScheduledGrant {
+                    grant_date: date(2021, 10, 1),
+                    days: 11.0,
+                    expires_on: date(2023, 10, 1),
+                },
+                ScheduledGrant {
+                    grant_date: date(2022, 10, 1),
+                    days: 12.0,
+                    expires_on: date(2024, 10, 1),
+                },
+            ]
+        );
+
+        let next = next_grant(&full_time, date(2022, 10, 1)).expect("next grant");
+        assert_eq!(next.grant_date, date(2023, 10, 1));
+        assert_eq!(next.days, 14.0);
+    }
+
+    #[test]
+    fn lapsed_days_uses_oldest_grant_first() {
+        // 10 granted, 11 newer, 4 used: balance 17, so 6 of the old grant lapse.
+        assert_eq!(lapsed_days(17.0, 11.0, 10.0), 6.0);
+        // Heavy use already drew on the newer grant; nothing of the old grant remains.
+        assert_eq!(lapsed_days(9.0, 11.0, 10.0), 0.0);
+        // Manual top-ups cannot make more than the grant itself lapse.
+        assert_eq!(lapsed_days(25.0, 11.0, 10.0), 10.0);
+    }
+}
@@ -0,0 +1,84 @@
+//! Working days of approved leave, as marked in attendance and counted in monthly totals.
+
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::models::absence::LeaveTotals;
+use crate::models::employment_profile::DEFAULT_DAILY_SCHEDULED_HOURS;
+use crate::models::leave_request::LeaveRequest;
+use crate::repositories::{employment_profile, leave_attendance};
+use crate::services::absence::is_working_day;
+use crate::services::holiday::HolidayService;
+use crate::services::leave_balance::leave_day_fraction;
+use crate::services::work_schedule::WorkScheduleService;
+use crate::types::UserId;
+
+#[derive(Clone)]
+pub struct LeaveAttendanceService {
+    pool: PgPool,
+    holidays: HolidayService,
+    schedules: WorkScheduleService,
+}
+
+impl LeaveAttendanceService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            holidays: HolidayService::new(pool.clone()),
+            schedules: WorkScheduleService::new(pool.clone()),
+            pool,
+        }
+    }
+
+    /// Working days of a leave request, which approval marks as leave in attendance.
+    pub async fn working_days(&self, request: &LeaveRequest) -> Result<Vec<NaiveDate>, AppError> {
+        let mut days = Vec::new();
+        for date in request.start_date.iter_days() {
+            if date > request.end_date {
+                break;
+            }
+            if is_working_day(&self.holidays, &self.schedules, request.user_id, date).await? {
+                days.push(date);
+            }
+        }
+        Ok(days)
+    }
+
+    /// Leave days and paid-leave hours the user takes between two dates.
+    ///
+    /// Only working days count, and each day counts by the share of it the leave covers.
+    pub async fn totals(
+        &self,
+        user_id: UserId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<LeaveTotals, AppError> {
+        let leaves =
+            leave_attendance::list_approved_in_range(&self.pool, user_id, from, to).await?;
+        if leaves.is_empty() {
+            return Ok(LeaveTotals::default());
+        }
+        let daily_hours = employment_profile::find_by_user(&self.pool, user_id)
+            .await?
+            .map(|profile| profile.daily_scheduled_hours())
+            .unwrap_or(DEFAULT_DAILY_SCHEDULED_HOURS);
+
+        let mut totals = LeaveTotals::default();
+        for leave in leaves {
+            let fraction = leave_day_fraction(leave.unit, leave.hours, daily_hours);
+            for date in leave.start_date.max(from).iter_days() {
+                if date > leave.end_date.min(to) {
+                    break;
+                }
+                if !is_working_day(&self.holidays, &self.schedules, user_id, date).await? {
+                    continue;
+                }
+                totals.leave_days += fraction;
+                if leave.paid {
+                    totals.paid_leave_hours += fraction * daily_hours;
+                }
+            }
+        }
+        Ok(totals)
+    }
+}
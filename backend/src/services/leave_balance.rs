@@ -0,0 +1,463 @@
+//! Leave balance calculations and the ledger-backed approve/cancel transitions.
+
+use std::collections::BTreeSet;
+
+use chrono::{DateTime, Datelike, Months, NaiveDate, Utc};
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::models::employment_profile::DEFAULT_DAILY_SCHEDULED_HOURS;
+use crate::models::leave_balance::{
+    LeaveBalanceEntry, LeaveBalanceEntryType, LeaveBalanceResponse,
+};
+use crate::models::leave_request::{LeaveRequest, LeaveType, LeaveUnit, RequestStatus};
+use crate::repositories::leave_request_repository::{
+    approve_leave_request_with_executor, cancel_approved_leave_request_with_executor,
+    LeaveRequestRepository, LeaveRequestRepositoryTrait,
+};
+use crate::repositories::{employment_profile, leave_attendance, leave_balance, leave_type};
+use crate::services::holiday::{HolidayService, HolidayServiceTrait};
+use crate::services::leave_attendance::LeaveAttendanceService;
+use crate::types::{LeaveRequestId, UserId};
+use crate::utils::time::month_start;
+
+pub const INSUFFICIENT_LEAVE_BALANCE: &str = "INSUFFICIENT_LEAVE_BALANCE";
+pub const HOURLY_LEAVE_LIMIT_EXCEEDED: &str = "HOURLY_LEAVE_LIMIT_EXCEEDED";
+
+/// Days' worth of annual leave that may be taken in hours per leave year.
+pub const HOURLY_ANNUAL_LEAVE_CAP_DAYS: f64 = 5.0;
+
+#[derive(Clone)]
+pub struct LeaveBalanceService {
+    pool: PgPool,
+    holidays: HolidayService,
+}
+
+impl LeaveBalanceService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            holidays: HolidayService::new(pool.clone()),
+            pool,
+        }
+    }
+
+    /// Scheduled hours of one working day for the user.
+    pub async fn daily_scheduled_hours(&self, user_id: UserId) -> Result<f64, AppError> {
+        Ok(employment_profile::find_by_user(&self.pool, user_id)
+            .await?
+            .map(|profile| profile.daily_scheduled_hours())
+            .unwrap_or(DEFAULT_DAILY_SCHEDULED_HOURS))
+    }
+
+    /// Number of leave days a request covers, excluding the requester's holidays.
+    ///
+    /// Half days count as 0.5 and hourly leave as a share of the scheduled working day.
+    pub async fn requested_days(&self, request: &LeaveRequest) -> Result<f64, AppError> {
+        let fraction = match request.unit {
+            LeaveUnit::Hours => {
+                let daily_hours = self.daily_scheduled_hours(request.user_id).await?;
+                leave_day_fraction(request.unit, request.hours, daily_hours)
+            }
+            unit => leave_day_fraction(unit, None, DEFAULT_DAILY_SCHEDULED_HOURS),
+        };
+        let user_id = request.user_id.to_string();
+        let mut holidays = BTreeSet::new();
+        let mut cursor = month_start(request.start_date);
+        while cursor <= request.end_date {
+            let entries = self
+                .holidays
+                .list_month(cursor.year(), cursor.month(), Some(&user_id))
+                .await?;
+            holidays.extend(entries.into_iter().map(|entry| entry.date));
+            cursor = next_month(cursor)?;
+        }
+        Ok(count_leave_days(request.start_date, request.end_date, &holidays) * fraction)
+    }
+
+    /// Hours of approved leave the user takes on `date`, given the length of their working day.
+    pub async fn approved_leave_hours(
+        &self,
+        user_id: UserId,
+        date: NaiveDate,
+        daily_hours: f64,
+    ) -> Result<f64, AppError> {
+        let requests = LeaveRequestRepository::new()
+            .find_by_user(&self.pool, user_id)
+            .await?;
+        Ok(approved_leave_hours_on(&requests, date, daily_hours))
+    }
+
+    /// Days held by the user's pending requests of a type, optionally skipping one request.
+    pub async fn pending_days(
+        &self,
+        user_id: UserId,
+        leave_type: &LeaveType,
+        exclude: Option<LeaveRequestId>,
+    ) -> Result<f64, AppError> {
+        let requests = LeaveRequestRepository::new()
+            .find_by_user(&self.pool, user_id)
+            .await?;
+        let mut total = 0.0;
+        for request in requests.iter().filter(|request| {
+            request.is_pending() && &request.leave_type == leave_type && Some(request.id) != exclude
+        }) {
+            total += self.requested_days(request).await?;
+        }
+        Ok(total)
+    }
+
+    /// Balance summary for one leave type.
+    pub async fn balance(
+        &self,
+        user_id: UserId,
+        leave_type: &LeaveType,
+    ) -> Result<LeaveBalanceResponse, AppError> {
+        let totals = leave_balance::fetch_totals(&self.pool, user_id, leave_type).await?;
+        let pending = self.pending_days(user_id, leave_type, None).await?;
+        Ok(LeaveBalanceResponse::from_totals(
+            leave_type.clone(),
+            &totals,
+            pending,
+        ))
+    }
+
+    /// Balance summaries for every tracked leave type.
+    pub async fn balances_for_user(
+        &self,
+        user_id: UserId,
+    ) -> Result<Vec<LeaveBalanceResponse>, AppError> {
+        let leave_types = leave_type::list_balance_tracked_codes(&self.pool).await?;
+        let mut balances = Vec::with_capacity(leave_types.len());
+        for leave_type in &leave_types {
+            balances.push(self.balance(user_id, leave_type).await?);
+        }
+        Ok(balances)
+    }
+
+    /// Returns true if requests of the type draw down the leave balance ledger.
+    ///
+    /// Unknown codes are treated as untracked.
+    pub async fn is_balance_tracked(&self, leave_type: &LeaveType) -> Result<bool, AppError> {
+        Ok(leave_type::find_by_code(&self.pool, leave_type)
+            .await?
+            .is_some_and(|definition| definition.balance_tracked))
+    }
+
+    /// Rejects a pending request that would exceed the balance left after other pending requests.
+    ///
+    /// Hourly leave must also fit within the scheduled working day and, for tracked types,
+    /// within the yearly hourly allowance.
+    pub async fn ensure_request_fits(&self, request: &LeaveRequest) -> Result<(), AppError> {
+        let tracked = self.is_balance_tracked(&request.leave_type).await?;
+        if request.unit == LeaveUnit::Hours {
+            let daily_hours = self.daily_scheduled_hours(request.user_id).await?;
+            let hours = request.hours.unwrap_or_default();
+            if hours >= daily_hours {
+                return Err(AppError::BadRequest(format!(
+                    "Hourly leave must be shorter than the scheduled working day ({daily_hours} hours)"
+                )));
+            }
+            if tracked {
+                self.ensure_hourly_allowance(request, daily_hours).await?;
+            }
+        }
+        if !tracked {
+            return Ok(());
+        }
+        let requested = self.requested_days(request).await?;
+        let totals =
+            leave_balance::fetch_totals(&self.pool, request.user_id, &request.leave_type).await?;
+        let pending = self
+            .pending_days(request.user_id, &request.leave_type, Some(request.id))
+            .await?;
+        let available =
+            LeaveBalanceResponse::from_totals(request.leave_type.clone(), &totals, pending)
+                .available;
+        ensure_covers(requested, available)
+    }
+
+    /// Rejects hourly leave that would take the leave year past the hourly allowance.
+    async fn ensure_hourly_allowance(
+        &self,
+        request: &LeaveRequest,
+        daily_hours: f64,
+    ) -> Result<(), AppError> {
+        let grant_date = leave_balance::latest_grant_date(
+            &self.pool,
+            request.user_id,
+            &request.leave_type,
+            request.start_date,
+        )
+        .await?;
+        let (from, to) = hourly_leave_year(grant_date, request.start_date);
+        let requests = LeaveRequestRepository::new()
+            .find_by_user(&self.pool, request.user_id)
+            .await?;
+        let used: f64 = requests
+            .iter()
+            .filter(|other| {
+                other.id != request.id
+                    && other.unit == LeaveUnit::Hours
+                    && other.leave_type == request.leave_type
+                    && matches!(
+                        other.status,
+                        RequestStatus::Pending | RequestStatus::Approved
+                    )
+                    && from <= other.start_date
+                    && other.start_date < to
+            })
+            .filter_map(|other| other.hours)
+            .sum();
+        let allowance = HOURLY_ANNUAL_LEAVE_CAP_DAYS * daily_hours;
+        let requested = request.hours.unwrap_or_default();
+        if used + requested > allowance {
+            return Err(AppError::BadRequestWithCode {
+                message: format!(
+                    "Hourly leave limit exceeded: requested {} hour(s), {} of {} hour(s) already used",
+                    requested, used, allowance
+                ),
+                code: HOURLY_LEAVE_LIMIT_EXCEEDED.to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Approves a leave request in one transaction: tracked types book their consumption,
+    /// and the request's working days are marked as leave in attendance.
+    ///
+    /// Returns `false` when the request was no longer pending.
+    pub async fn approve_leave(
+        &self,
+        request: &LeaveRequest,
+        approver_id: UserId,
+        comment: &str,
+        timestamp: DateTime<Utc>,
+    ) -> Result<bool, AppError> {
+        let tracked = self.is_balance_tracked(&request.leave_type).await?;
+        let requested = if tracked {
+            self.requested_days(request).await?
+        } else {
+            0.0
+        };
+        let leave_days = LeaveAttendanceService::new(self.pool.clone())
+            .working_days(request)
+            .await?;
+
+        let mut tx = self.pool.begin().await?;
+        if tracked {
+            leave_balance::lock_user_ledger(&mut *tx, request.user_id).await?;
+            let totals =
+                leave_balance::fetch_totals(&mut *tx, request.user_id, &request.leave_type).await?;
+            let balance =
+                LeaveBalanceResponse::from_totals(request.leave_type.clone(), &totals, 0.0).balance;
+            ensure_covers(requested, balance)?;
+        }
+
+        let affected = approve_leave_request_with_executor(
+            &mut *tx,
+            request.id,
+            approver_id,
+            comment,
+            timestamp,
+        )
+        .await?;
+        if affected == 0 {
+            return Ok(false);
+        }
+
+        if requested > 0.0 {
+            let mut entry = LeaveBalanceEntry::new(
+                request.user_id,
+                request.leave_type.clone(),
+                LeaveBalanceEntryType::Consumption,
+                -requested,
+                request.start_date,
+            );
+            entry.leave_request_id = Some(request.id);
+            entry.created_by = Some(approver_id);
+            leave_balance::insert_entry(&mut *tx, &entry).await?;
+        }
+        for date in leave_days {
+            leave_attendance::mark_leave_day(&mut *tx, request.user_id, date, request.id).await?;
+        }
+
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    /// Cancels an approved request that has not started yet, reverses its consumption and
+    /// takes its days back out of attendance.
+    ///
+    /// Returns `false` when no approved, future request matched.
+    pub async fn cancel_with_reversal(
+        &self,
+        request_id: LeaveRequestId,
+        user_id: UserId,
+        timestamp: DateTime<Utc>,
+        today: NaiveDate,
+    ) -> Result<bool, AppError> {
+        let request = match LeaveRequestRepository::new()
+            .find_by_id_for_user(&self.pool, request_id, user_id)
+            .await?
+        {
+            Some(request) if request.start_date > today => request,
+            _ => return Ok(false),
+        };
+
+        let mut tx = self.pool.begin().await?;
+        let affected =
+            cancel_approved_leave_request_with_executor(&mut *tx, request.id, user_id, timestamp)
+                .await?;
+        if affected == 0 {
+            return Ok(false);
+        }
+        leave_balance::insert_reversal_for_request(&mut *tx, request.id, today).await?;
+        leave_attendance::release_leave_days(&mut tx, request.id, today).await?;
+        tx.commit().await?;
+        Ok(true)
+    }
+}
+
+fn ensure_covers(requested: f64, available: f64) -> Result<(), AppError> {
+    if requested > available {
+        return Err(AppError::BadRequestWithCode {
+            message: format!(
+                "Insufficient leave balance: requested {} day(s), available {} day(s)",
+                requested,
+                available.max(0.0)
+            ),
+            code: INSUFFICIENT_LEAVE_BALANCE.to_string(),
+        });
+    }
+    Ok(())
+}
+
+/// Hours of approved leave among `requests` on `date`, given the length of the working day.
+pub fn approved_leave_hours_on(
+    requests: &[LeaveRequest],
+    date: NaiveDate,
+    daily_hours: f64,
+) -> f64 {
+    let leave_hours: f64 = requests
+        .iter()
+        .filter(|request| {
+            matches!(request.status, RequestStatus::Approved)
+                && request.start_date <= date
+                && date <= request.end_date
+        })
+        .map(|request| leave_day_fraction(request.unit, request.hours, daily_hours))
+        .sum::<f64>()
+        * daily_hours;
+    leave_hours.min(daily_hours)
+}
+
+/// Share of a working day taken by one day of leave in the given unit.
+pub fn leave_day_fraction(unit: LeaveUnit, hours: Option<f64>, daily_hours: f64) -> f64 {
+    match unit {
+        LeaveUnit::Full => 1.0,
+        LeaveUnit::HalfAm | LeaveUnit::HalfPm => 0.5,
+        LeaveUnit::Hours if daily_hours > 0.0 => {
+            (hours.unwrap_or_default() / daily_hours).clamp(0.0, 1.0)
+        }
+        LeaveUnit::Hours => 0.0,
+    }
+}
+
+/// Leave year that hourly leave on `date` counts against, as a half-open range.
+///
+/// Years run from the anniversary of the latest grant, or follow the calendar when there is none.
+fn hourly_leave_year(grant_date: Option<NaiveDate>, date: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let next_year = |day: NaiveDate| day.checked_add_months(Months::new(12));
+    let mut from = grant_date
+        .or_else(|| NaiveDate::from_ymd_opt(date.year(), 1, 1))
+        .unwrap_or(date);
+    while let Some(to) = next_year(from) {
+        if date < to {
+            return (from, to);
+        }
+        from = to;
+    }
+    (from, date)
+}
+
+/// Counts the days in `start..=end` that are not holidays.
+pub fn count_leave_days(start: NaiveDate, end: NaiveDate, holidays: &BTreeSet<NaiveDate>) -> f64 {
+    start
+        .iter_days()
+        .take_while(|day| *day <= end)
+        .filter(|day| !holidays.contains(day))
+        .count() as f64
+}
+
+fn next_month(date: NaiveDate) -> Result<NaiveDate, AppError> {
+    let (year, month) = if date.month() == 12 {
+        (date.year() + 1, 1)
+    } else {
+        (date.year(), date.month() + 1)
+    };
+    NaiveDate::from_ymd_opt(year, month, 1)
+        .ok_or_else(|| AppError::BadRequest("Leave period is out of range".into()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).expect("valid date")
+    }
+
+    #[test]
+    fn count_leave_days_skips_holidays() {
+        let holidays = BTreeSet::from([date(2026, 5, 2), date(2026, 5, 3)]);
+        assert_eq!(
+            count_leave_days(date(2026, 5, 1), date(2026, 5, 5), &holidays),
+            3.0
+        );
+        assert_eq!(
+            count_leave_days(date(2026, 5, 2), date(2026, 5, 3), &holidays),
+            0.0
+        );
+    }
+
+    #[test]
+    fn ensure_covers_reports_insufficient_balance_code() {
+        assert!(ensure_covers(2.0, 2.0).is_ok());
+        match ensure_covers(3.0, 2.0) {
+            Err(AppError::BadRequestWithCode { code, .. }) => {
+                assert_eq!(code, INSUFFICIENT_LEAVE_BALANCE)
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn leave_day_fraction_follows_unit_and_schedule() {
+        assert_eq!(leave_day_fraction(LeaveUnit::Full, None, 8.0), 1.0);
+        assert_eq!(leave_day_fraction(LeaveUnit::HalfPm, None, 8.0), 0.5);
+        assert_eq!(leave_day_fraction(LeaveUnit::Hours, Some(2.0), 8.0), 0.25);
+        assert_eq!(leave_day_fraction(LeaveUnit::Hours, Some(3.0), 6.0), 0.5);
+        assert_eq!(leave_day_fraction(LeaveUnit::Hours, Some(2.0), 0.0), 0.0);
+    }
+
+    #[test]
+    fn hourly_leave_year_starts_at_latest_grant() {
+        assert_eq!(
+            hourly_leave_year(Some(date(2026, 4, 1)), date(2026, 9, 1)),
+            (date(2026, 4, 1), date(2027, 4, 1))
+        );
+        assert_eq!(
+            hourly_leave_year(Some(date(2026, 4, 1)), date(2028, 3, 31)),
+            (date(2027, 4, 1), date(2028, 4, 1))
+        );
+        assert_eq!(
+            hourly_leave_year(None, date(2026, 9, 1)),
+            (date(2026, 1, 1), date(2027, 1, 1))
+        );
+    }
+
+    #[test]
+    fn next_month_rolls_over_year_end() {
+        assert_eq!(next_month(date(2026, 12, 1)).unwrap(), date(2027, 1, 1));
+    }
+}
@@ -0,0 +1,211 @@
+//! Five-day mandatory annual leave compliance: per-period report and deadline reminders.
+
+use std::collections::HashSet;
+
+use chrono::{Days, Months, NaiveDate};
+use sqlx::PgPool;
+
+use crate::config::Config;
+use crate::error::AppError;
+use crate::models::leave_compliance::{
+    LeaveComplianceItem, LeaveComplianceStatus, MANDATORY_LEAVE_DAYS,
+    MANDATORY_LEAVE_MIN_GRANT_DAYS,
+};
+use crate::repositories::{department, leave_compliance};
+use crate::types::UserId;
+use crate::utils::{email::EmailService, encryption::decrypt_pii};
+
+/// Days before the deadline from which an unmet period is reported as due soon.
+pub const DUE_SOON_DAYS: i64 = 60;
+/// Days before the deadline at which reminders go out, largest first.
+const REMINDER_THRESHOLDS: [i64; 3] = [60, 30, 7];
+
+/// Last day of the one-year period opened by a grant.
+pub fn mandatory_leave_deadline(grant_date: NaiveDate) -> NaiveDate {
+    grant_date
+        .checked_add_months(Months::new(12))
+        .and_then(|date| date.checked_sub_days(Days::new(1)))
+        .unwrap_or(grant_date)
+}
+
+/// Classifies a period from the days still required and the time left.
+pub fn compliance_status(days_remaining: f64, days_until_deadline: i64) -> LeaveComplianceStatus {
+    if days_remaining <= 0.0 {
+        LeaveComplianceStatus::Compliant
+    } else if days_until_deadline <= DUE_SOON_DAYS {
+        LeaveComplianceStatus::DueSoon
+    } else {
+        LeaveComplianceStatus::OnTrack
+    }
+}
+
+/// The reminder threshold a period has reached, if any.
+pub fn reminder_threshold(days_until_deadline: i64) -> Option<i64> {
+    REMINDER_THRESHOLDS
+        .iter()
+        .rev()
+        .copied()
+        .find(|threshold| (0..=*threshold).contains(&days_until_deadline))
+}
+
+#[derive(Clone)]
+pub struct LeaveComplianceService {
+    pool: PgPool,
+}
+
+impl LeaveComplianceService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Reports every grant period open on `as_of`, optionally limited to `visible_users`.
+    pub async fn report(
+        &self,
+        as_of: NaiveDate,
+        visible_users: Option<&HashSet<UserId>>,
+    ) -> Result<Vec<LeaveComplianceItem>, AppError> {
+        let grants = leave_compliance::list_qualifying_grants(
+            &self.pool,
+            as_of,
+            MANDATORY_LEAVE_MIN_GRANT_DAYS,
+        )
+        .await?;
+
+        let mut items = Vec::with_capacity(grants.len());
+        for grant in grants {
+            if visible_users.is_some_and(|users| !users.contains(&grant.user_id)) {
+                continue;
+            }
+            let deadline = mandatory_leave_deadline(grant.grant_date);
+            let days_taken = leave_compliance::sum_annual_leave_taken(
+                &self.pool,
+                grant.user_id,
+                grant.grant_date,
+                as_of,
+            )
+            .await?;
+            let days_scheduled = match as_of.succ_opt() {
+                Some(tomorrow) if tomorrow <= deadline => {
+                    leave_compliance::sum_annual_leave_taken(
+                        &self.pool,
+                        grant.user_id,
+                        tomorrow,
+                        deadline,
+                    )
+                    .await?
+                }
+                _ => 0.0,
+            };
+            let days_remaining = (MANDATORY_LEAVE_DAYS - days_taken - days_scheduled).max(0.0);
+            let days_until_deadline = (deadline - as_of).num_days();
+            items.push(LeaveComplianceItem {
+                user_id: grant.user_id,
+                username: grant.username,
+                grant_entry_id: grant.grant_entry_id,
+                grant_date: grant.grant_date,
+                granted_days: grant.granted_days,
+                deadline,
+                days_taken,
+                days_scheduled,
+                days_remaining,
+                days_until_deadline,
+                status: compliance_status(days_remaining, days_until_deadline),
+            });
+        }
+        Ok(items)
+    }
+
+    /// Emails the employee and their department managers once per reminder threshold.
+    ///
+    /// Returns the number of periods for which a reminder was sent.
+    #[allow(dead_code)]
+    pub async fn send_deadline_reminders(
+        &self,
+        as_of: NaiveDate,
+        config: &Config,
+    ) -> Result<u64, AppError> {
+        let email_service = EmailService::new()?;
+        let mut sent = 0;
+        for item in self.report(as_of, None).await? {
+            if item.status == LeaveComplianceStatus::Compliant {
+                continue;
+            }
+            let Some(threshold) = reminder_threshold(item.days_until_deadline) else {
+                continue;
+            };
+            let threshold = threshold as i32;
+            if leave_compliance::notification_sent(&self.pool, item.grant_entry_id, threshold)
+                .await?
+            {
+                continue;
+            }
+
+            let recipients =
+                department::list_user_and_manager_emails(&self.pool, item.user_id).await?;
+            let mut delivered = false;
+            for encrypted in recipients {
+                let email = match decrypt_pii(&encrypted, config) {
+                    Ok(email) => email,
+                    Err(err) => {
+                        tracing::warn!(user_id = %item.user_id, error = %err, "Skipping undecryptable reminder recipient");
+                        continue;
+                    }
+                };
+                match email_service.send_mandatory_leave_reminder(
+                    &email,
+                    &item.username,
+                    item.days_remaining,
+                    item.deadline,
+                ) {
+                    Ok(()) => delivered = true,
+                    Err(err) => {
+                        tracing::warn!(user_id = %item.user_id, error = %err, "Failed to send mandatory leave reminder")
+                    }
+                }
+            }
+            if delivered {
+                sent += leave_compliance::record_notification(
+                    &self.pool,
+                    item.grant_entry_id,
+                    item.user_id,
+                    threshold,
+                )
+                .await?;
+            }
+        }
+        Ok(sent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).expect("valid date")
+    }
+
+    #[test]
+    fn deadline_is_the_day_before_the_grant_anniversary() {
+        assert_eq!(
+            mandatory_leave_deadline(date(2026, 4, 1)),
+            date(2027, 3, 31)
+        );
+    }
+
+    #[test]
+    fn status_depends_on_remaining_days_and_deadline() {
+        assert_eq!(compliance_status(0.0, 10), LeaveComplianceStatus::Compliant);
+        assert_eq!(compliance_status(2.0, 61), LeaveComplianceStatus::OnTrack);
+        assert_eq!(compliance_status(2.0, 60), LeaveComplianceStatus::DueSoon);
+    }
+
+    #[test]
+    fn reminder_threshold_picks_the_tightest_window() {
+        assert_eq!(reminder_threshold(90), None);
+        assert_eq!(reminder_threshold(45), Some(60));
+        assert_eq!(reminder_threshold(30), Some(30));
+        assert_eq!(reminder_threshold(3), Some(7));
+        assert_eq!(reminder_threshold(-1), None);
+    }
+}
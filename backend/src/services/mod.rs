@@ -1,7 +1,30 @@
+pub mod absence;
+pub mod attendance_closure;
+pub mod attendance_report;
 pub mod audit_log;
+pub mod break_policy;
 pub mod consent_log;
+pub mod export_encryption;
+pub mod export_job;
+pub mod export_schedule;
+pub mod export_storage;
+pub mod export_template;
+pub mod flextime;
+pub mod health_interview;
 pub mod holiday;
 pub mod holiday_exception;
+pub mod leave_accrual;
+pub mod leave_attendance;
+pub mod leave_balance;
+pub mod leave_compliance;
 pub mod lockout_notification_queue;
 pub mod lockout_notification_worker;
+pub mod overtime;
+pub mod overtime_limit;
+pub mod period_closing;
+pub mod rest_day;
+pub mod rest_interval;
+pub mod timesheet;
 pub mod token_cache;
+pub mod work_schedule;
+pub mod work_time;
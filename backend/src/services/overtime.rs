@@ -0,0 +1,120 @@
+//! Compares actual overtime with approved overtime requests.
+
+use std::collections::HashMap;
+
+use chrono::{Datelike, NaiveDate};
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::models::overtime::{
+    compute_daily_overtime, week_start, OvertimeReconciliation, WorkedDay,
+};
+use crate::models::request::RequestStatus;
+use crate::repositories::overtime_request::{
+    OvertimeRequestRepository, OvertimeRequestRepositoryTrait,
+};
+use crate::services::{work_schedule::WorkScheduleService, work_time::load_effective_attendance};
+use crate::types::UserId;
+
+#[derive(Clone)]
+pub struct OvertimeService {
+    pool: PgPool,
+}
+
+impl OvertimeService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Approved overtime hours per day between two dates.
+    pub async fn approved_hours(
+        &self,
+        user_id: UserId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<HashMap<NaiveDate, f64>, AppError> {
+        let requests = OvertimeRequestRepository::new()
+            .find_by_user_and_date_range(&self.pool, user_id, from, to)
+            .await?;
+        let mut approved = HashMap::new();
+        for request in requests
+            .into_iter()
+            .filter(|request| matches!(request.status, RequestStatus::Approved))
+        {
+            *approved.entry(request.date).or_insert(0.0) += request.planned_hours;
+        }
+        Ok(approved)
+    }
+
+    /// Worked hours per day between two dates, based on corrected attendance.
+    pub async fn worked_hours(
+        &self,
+        user_id: UserId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<HashMap<NaiveDate, f64>, AppError> {
+        let mut worked_hours = HashMap::new();
+        for attendance in load_effective_attendance(&self.pool, user_id, from, to).await? {
+            *worked_hours.entry(attendance.date).or_insert(0.0) +=
+                attendance.total_work_hours.unwrap_or(0.0);
+        }
+        Ok(worked_hours)
+    }
+
+    /// Reconciles actual overtime between `first_day` and `last_day` with approved requests.
+    ///
+    /// Days from the Sunday before `first_day` count towards the first week's 40 hours
+    /// without being reported.
+    pub async fn reconcile(
+        &self,
+        user_id: UserId,
+        username: String,
+        first_day: NaiveDate,
+        last_day: NaiveDate,
+    ) -> Result<OvertimeReconciliation, AppError> {
+        let worked_hours = self
+            .worked_hours(user_id, week_start(first_day), last_day)
+            .await?;
+        let approved = self.approved_hours(user_id, first_day, last_day).await?;
+        let schedules = WorkScheduleService::new(self.pool.clone());
+
+        let mut days = Vec::new();
+        for date in week_start(first_day)
+            .iter_days()
+            .take_while(|date| *date <= last_day)
+        {
+            let worked = worked_hours.get(&date).copied().unwrap_or(0.0);
+            // Flextime hours are settled over the period, so only legal overtime counts daily.
+            let scheduled_hours = if date >= first_day && worked > 0.0 {
+                let expectation = schedules.day_expectation(user_id, date).await?;
+                if expectation.flextime {
+                    worked
+                } else {
+                    expectation.expected_hours
+                }
+            } else {
+                0.0
+            };
+            days.push(WorkedDay {
+                date,
+                worked_hours: worked,
+                scheduled_hours,
+                approved_hours: approved.get(&date).copied().unwrap_or(0.0),
+            });
+        }
+
+        let reported = compute_daily_overtime(&days)
+            .into_iter()
+            .filter(|day| {
+                day.date >= first_day && (day.worked_hours > 0.0 || day.approved_hours > 0.0)
+            })
+            .collect();
+        Ok(OvertimeReconciliation::from_days(
+            user_id,
+            username,
+            first_day.year(),
+            first_day.month(),
+            reported,
+        ))
+    }
+}
@@ -0,0 +1,250 @@
+//! Article 36 agreement limits: which limits apply, approval checks and threshold warnings.
+
+use std::collections::HashMap;
+
+use chrono::{DateTime, NaiveDate, Utc};
+use sqlx::PgPool;
+
+use crate::config::Config;
+use crate::error::AppError;
+use crate::models::overtime::{compute_daily_overtime, week_start, WorkedDay};
+use crate::models::overtime_limit::{
+    OvertimeLimit, OvertimeLimitCheck, OvertimeLimitLevel, OvertimeLimitStatus,
+};
+use crate::models::overtime_request::OvertimeRequest;
+use crate::repositories::{
+    department, leave_balance, overtime_limit,
+    overtime_request_repository::approve_overtime_request_with_executor,
+};
+use crate::services::overtime::OvertimeService;
+use crate::types::UserId;
+use crate::utils::{
+    email::EmailService,
+    encryption::decrypt_pii,
+    time::{month_end, month_start},
+};
+
+pub const OVERTIME_LIMIT_EXCEEDED: &str = "OVERTIME_LIMIT_EXCEEDED";
+
+/// Rejects overtime whose projected checks breach a limit.
+pub fn ensure_within_limits(checks: &[OvertimeLimitCheck]) -> Result<(), AppError> {
+    let breaches: Vec<String> = checks
+        .iter()
+        .filter(|check| check.level == OvertimeLimitLevel::Exceeded)
+        .map(|check| format!("{} {} > {}", check.kind.as_str(), check.value, check.limit))
+        .collect();
+    if breaches.is_empty() {
+        return Ok(());
+    }
+    Err(AppError::BadRequestWithCode {
+        message: format!(
+            "Overtime would exceed the Article 36 agreement limits: {}",
+            breaches.join(", ")
+        ),
+        code: OVERTIME_LIMIT_EXCEEDED.to_string(),
+    })
+}
+
+#[derive(Clone)]
+pub struct OvertimeLimitService {
+    pool: PgPool,
+}
+
+impl OvertimeLimitService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Limits that apply to the user, falling back to the statutory ones.
+    pub async fn limit_for(&self, user_id: UserId) -> Result<OvertimeLimit, AppError> {
+        Ok(overtime_limit::find_limit_for_user(&self.pool, user_id)
+            .await?
+            .unwrap_or_else(|| OvertimeLimit::statutory(None)))
+    }
+
+    /// Limits that apply to a user and their legal overtime in every month needed to check
+    /// `month`.
+    pub async fn limit_inputs(
+        &self,
+        user_id: UserId,
+        month: NaiveDate,
+    ) -> Result<(OvertimeLimit, HashMap<NaiveDate, f64>), AppError> {
+        let limit = self.limit_for(user_id).await?;
+        let hours = self
+            .monthly_legal_overtime(user_id, limit.window_start(month), month_end(month))
+            .await?;
+        Ok((limit, hours))
+    }
+
+    /// Approves an overtime request unless its planned hours, on top of the overtime already
+    /// worked and approved, would breach a limit.
+    ///
+    /// Approvals for the same user are serialized, so concurrent ones cannot each pass on
+    /// their own. Returns `false` when the request was no longer pending.
+    pub async fn approve_overtime(
+        &self,
+        request: &OvertimeRequest,
+        approver_id: UserId,
+        comment: &str,
+        timestamp: DateTime<Utc>,
+    ) -> Result<bool, AppError> {
+        let month = month_start(request.date);
+        let (limit, mut hours) = self.limit_inputs(request.user_id, month).await?;
+
+        let mut tx = self.pool.begin().await?;
+        leave_balance::lock_user_ledger(&mut *tx, request.user_id).await?;
+        for (date, planned_hours) in overtime_limit::list_approved_unworked_hours(
+            &mut *tx,
+            request.user_id,
+            limit.window_start(month),
+            month_end(month),
+        )
+        .await?
+        {
+            *hours.entry(month_start(date)).or_insert(0.0) += planned_hours;
+        }
+        *hours.entry(month).or_insert(0.0) += request.planned_hours;
+        ensure_within_limits(&limit.evaluate(month, &hours))?;
+
+        let affected = approve_overtime_request_with_executor(
+            &mut *tx,
+            request.id,
+            approver_id,
+            comment,
+            timestamp,
+        )
+        .await?;
+        if affected == 0 {
+            return Ok(false);
+        }
+        tx.commit().await?;
+        Ok(true)
+    }
+
+    /// Legal overtime of a user per month from `from` to `to`, keyed by the first day of
+    /// the month.
+    pub async fn monthly_legal_overtime(
+        &self,
+        user_id: UserId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<HashMap<NaiveDate, f64>, AppError> {
+        let worked_hours = OvertimeService::new(self.pool.clone())
+            .worked_hours(user_id, week_start(from), to)
+            .await?;
+        let days: Vec<WorkedDay> = week_start(from)
+            .iter_days()
+            .take_while(|date| *date <= to)
+            .map(|date| WorkedDay {
+                date,
+                worked_hours: worked_hours.get(&date).copied().unwrap_or(0.0),
+                ..WorkedDay::default()
+            })
+            .collect();
+
+        let mut monthly = HashMap::new();
+        for day in compute_daily_overtime(&days)
+            .into_iter()
+            .filter(|day| day.date >= from)
+        {
+            *monthly.entry(month_start(day.date)).or_insert(0.0) += day.legal_overtime_hours;
+        }
+        Ok(monthly)
+    }
+
+    /// Emails the employee and their department managers about limits that are approaching
+    /// or exceeded, once per month, limit and level.
+    ///
+    /// Returns the number of warnings sent.
+    pub async fn send_warnings(
+        &self,
+        status: &OvertimeLimitStatus,
+        config: &Config,
+    ) -> Result<u64, AppError> {
+        let Some(month) = NaiveDate::from_ymd_opt(status.year, status.month, 1) else {
+            return Ok(0);
+        };
+        let mut pending = Vec::new();
+        for check in &status.checks {
+            if check.level == OvertimeLimitLevel::Ok
+                || overtime_limit::notification_sent(
+                    &self.pool,
+                    status.user_id,
+                    month,
+                    check.kind,
+                    check.level,
+                )
+                .await?
+            {
+                continue;
+            }
+            pending.push(check);
+        }
+        if pending.is_empty() {
+            return Ok(0);
+        }
+
+        let email_service = EmailService::new()?;
+        let recipients =
+            department::list_user_and_manager_emails(&self.pool, status.user_id).await?;
+        let mut sent = 0;
+        for check in pending {
+            let mut delivered = false;
+            for encrypted in &recipients {
+                let email = match decrypt_pii(encrypted, config) {
+                    Ok(email) => email,
+                    Err(err) => {
+                        tracing::warn!(user_id = %status.user_id, error = %err, "Skipping undecryptable overtime warning recipient");
+                        continue;
+                    }
+                };
+                match email_service.send_overtime_limit_warning(
+                    &email,
+                    &status.username,
+                    month,
+                    check.kind.label(),
+                    check.value,
+                    check.limit,
+                    check.level == OvertimeLimitLevel::Exceeded,
+                ) {
+                    Ok(()) => delivered = true,
+                    Err(err) => {
+                        tracing::warn!(user_id = %status.user_id, error = %err, "Failed to send overtime limit warning")
+                    }
+                }
+            }
+            if delivered {
+                sent += overtime_limit::record_notification(
+                    &self.pool,
+                    status.user_id,
+                    month,
+                    check.kind,
+                    check.level,
+                )
+                .await?;
+            }
+        }
+        Ok(sent)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn breached_limits_are_rejected_with_a_code() {
+        let limit = OvertimeLimit::statutory(None);
+        let month = NaiveDate::from_ymd_opt(2026, 5, 1).unwrap();
+        let within = limit.evaluate(month, &HashMap::from([(month, 44.0)]));
+        assert!(ensure_within_limits(&within).is_ok());
+
+        let over = limit.evaluate(month, &HashMap::from([(month, 46.0)]));
+        match ensure_within_limits(&over) {
+            Err(AppError::BadRequestWithCode { code, .. }) => {
+                assert_eq!(code, OVERTIME_LIMIT_EXCEEDED)
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+//! Locks attendance and requests in months closed for payroll.
+
+use chrono::NaiveDate;
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::repositories::period_closing;
+use crate::types::UserId;
+
+pub const PERIOD_CLOSED: &str = "PERIOD_CLOSED";
+
+#[derive(Clone)]
+pub struct PeriodClosingService {
+    pool: PgPool,
+}
+
+impl PeriodClosingService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Rejects a write to the user's records on `date` when its month is closed.
+    pub async fn ensure_open(&self, user_id: UserId, date: NaiveDate) -> Result<(), AppError> {
+        self.ensure_range_open(user_id, date, date).await
+    }
+
+    /// Rejects a write to the user's records between `from` and `to` when any of those
+    /// months is closed.
+    pub async fn ensure_range_open(
+        &self,
+        user_id: UserId,
+        from: NaiveDate,
+        to: NaiveDate,
+    ) -> Result<(), AppError> {
+        let Some(closing) =
+            period_closing::find_closing_for_user(&self.pool, user_id, from, to).await?
+        else {
+            return Ok(());
+        };
+        let scope = if closing.department_id.is_some() {
+            "for the department"
+        } else {
+            "company-wide"
+        };
+        Err(AppError::BadRequestWithCode {
+            message: format!(
+                "{} is closed {scope}; a system administrator must reopen it before changes",
+                closing.period_month.format("%Y-%m")
+            ),
+            code: PERIOD_CLOSED.to_string(),
+        })
+    }
+}
@@ -0,0 +1,188 @@
+//! Weekly rest day compliance: runs of consecutive worked days and manager alerts.
+
+use std::collections::{BTreeSet, HashSet};
+
+use chrono::{Datelike, Duration, NaiveDate};
+use sqlx::PgPool;
+
+use crate::config::Config;
+use crate::error::AppError;
+use crate::models::rest_day::{
+    find_streaks, rest_days_before, ConsecutiveWorkdayStreak, REST_DAYS_PER_PERIOD,
+    REST_DAY_PERIOD_DAYS,
+};
+use crate::repositories::{department, rest_day};
+use crate::services::holiday::{HolidayService, HolidayServiceTrait};
+use crate::types::{DepartmentId, UserId};
+use crate::utils::{email::EmailService, encryption::decrypt_pii, time::month_start};
+
+/// Days back from the nightly check's date in which runs are still alerted.
+const ALERT_LOOKBACK_DAYS: i64 = 6;
+
+#[derive(Clone)]
+pub struct RestDayService {
+    pool: PgPool,
+    holidays: HolidayService,
+}
+
+impl RestDayService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            holidays: HolidayService::new(pool.clone()),
+            pool,
+        }
+    }
+
+    /// Runs of more than `limit` worked days that end between `from` and `to`, optionally
+    /// limited to a department and to `visible_users`.
+    ///
+    /// Work is read from four weeks before `from`, so a run that began earlier than that is
+    /// reported as starting there.
+    pub async fn report(
+        &self,
+        from: NaiveDate,
+        to: NaiveDate,
+        limit: i64,
+        department_id: Option<DepartmentId>,
+        visible_users: Option<&HashSet<UserId>>,
+    ) -> Result<Vec<ConsecutiveWorkdayStreak>, AppError> {
+        let window_start = report_window_start(from);
+        let days = rest_day::list_worked_days(&self.pool, window_start, to, department_id).await?;
+
+        let mut streaks = Vec::new();
+        let mut rows = days.into_iter().peekable();
+        while let Some(first) = rows.next() {
+            let mut worked = BTreeSet::from([first.date]);
+            while let Some(next) = rows.next_if(|row| row.user_id == first.user_id) {
+                worked.insert(next.date);
+            }
+            if visible_users.is_some_and(|users| !users.contains(&first.user_id)) {
+                continue;
+            }
+            for (start_date, end_date) in find_streaks(&worked, limit) {
+                if end_date < from {
+                    continue;
+                }
+                let rest_days_in_four_weeks = rest_days_before(&worked, end_date);
+                streaks.push(ConsecutiveWorkdayStreak {
+                    user_id: first.user_id,
+                    username: first.username.clone(),
+                    department_id: first.department_id,
+                    start_date,
+                    end_date,
+                    days: (end_date - start_date).num_days() + 1,
+                    holidays_worked: self
+                        .count_holidays(first.user_id, start_date, end_date)
+                        .await?,
+                    rest_days_in_four_weeks,
+                    four_week_rule_met: rest_days_in_four_weeks >= REST_DAYS_PER_PERIOD,
+                });
+            }
+        }
+        streaks.sort_by(|a, b| b.days.cmp(&a.days).then(a.username.cmp(&b.username)));
+        Ok(streaks)
+    }
+
+    /// Designated holidays of the user between two dates, after their holiday exceptions.
+    async fn count_holidays(
+        &self,
+        user_id: UserId,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<i64, AppError> {
+        let user_id = user_id.to_string();
+        let mut count = 0;
+        let mut month = month_start(start);
+        while month <= end {
+            let entries = self
+                .holidays
+                .list_month(month.year(), month.month(), Some(&user_id))
+                .await?;
+            count += entries
+                .iter()
+                .filter(|entry| entry.is_holiday && (start..=end).contains(&entry.date))
+                .count() as i64;
+            month = match month.checked_add_months(chrono::Months::new(1)) {
+                Some(next) => next,
+                None => break,
+            };
+        }
+        Ok(count)
+    }
+
+    /// Emails department managers once about each run over the limit that ended in the
+    /// week up to `as_of`.
+    ///
+    /// Runs are keyed by their real first day, even when they began before the report
+    /// window, so a long run is not alerted again every night.
+    ///
+    /// Returns the number of runs alerted.
+    #[allow(dead_code)]
+    pub async fn send_alerts(&self, as_of: NaiveDate, config: &Config) -> Result<u64, AppError> {
+        let from = as_of - Duration::days(ALERT_LOOKBACK_DAYS);
+        let streaks = self
+            .report(from, as_of, config.consecutive_workday_limit, None, None)
+            .await?;
+        if streaks.is_empty() {
+            return Ok(0);
+        }
+
+        let email_service = EmailService::new()?;
+        let mut sent = 0;
+        for mut streak in streaks {
+            if streak.start_date == report_window_start(from) {
+                if let Some(start_date) =
+                    rest_day::find_run_start(&self.pool, streak.user_id, streak.start_date).await?
+                {
+                    streak.days += (streak.start_date - start_date).num_days();
+                    streak.start_date = start_date;
+                }
+            }
+            if rest_day::notification_sent(&self.pool, streak.user_id, streak.start_date).await? {
+                continue;
+            }
+            let recipients = department::list_manager_emails(&self.pool, streak.user_id).await?;
+            if recipients.is_empty() {
+                tracing::warn!(user_id = %streak.user_id, "No department manager to alert about consecutive workdays");
+                continue;
+            }
+            let mut delivered = false;
+            for encrypted in recipients {
+                let email = match decrypt_pii(&encrypted, config) {
+                    Ok(email) => email,
+                    Err(err) => {
+                        tracing::warn!(user_id = %streak.user_id, error = %err, "Skipping undecryptable consecutive workday alert recipient");
+                        continue;
+                    }
+                };
+                match email_service.send_consecutive_workday_alert(
+                    &email,
+                    &streak.username,
+                    streak.start_date,
+                    streak.days,
+                    streak.rest_days_in_four_weeks,
+                ) {
+                    Ok(()) => delivered = true,
+                    Err(err) => {
+                        tracing::warn!(user_id = %streak.user_id, error = %err, "Failed to send consecutive workday alert")
+                    }
+                }
+            }
+            if delivered {
+                sent += rest_day::record_notification(
+                    &self.pool,
+                    streak.user_id,
+                    streak.start_date,
+                    streak.days,
+                )
+                .await?;
+            }
+        }
+        Ok(sent)
+    }
+}
+
+/// First day of work read for a report starting on `from`.
+fn report_window_start(from: NaiveDate) -> NaiveDate {
+    from - Duration::days(REST_DAY_PERIOD_DAYS - 1)
+}
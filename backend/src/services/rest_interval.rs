@@ -0,0 +1,66 @@
+//! Enforces the rest interval policy when a shift is clocked in.
+
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::models::rest_interval::{RestIntervalMode, RestIntervalPolicy, RestShortfall};
+use crate::repositories::rest_interval;
+use crate::types::UserId;
+
+pub const REST_INTERVAL_TOO_SHORT: &str = "REST_INTERVAL_TOO_SHORT";
+
+#[derive(Clone)]
+pub struct RestIntervalService {
+    pool: PgPool,
+}
+
+impl RestIntervalService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// The saved policy, falling back to the standard one.
+    pub async fn policy(&self) -> Result<RestIntervalPolicy, AppError> {
+        Ok(rest_interval::find_policy(&self.pool)
+            .await?
+            .unwrap_or_else(RestIntervalPolicy::standard))
+    }
+
+    /// Checks the rest before the user's clock-in on `date` and records any shortfall.
+    ///
+    /// In block mode a shortfall refuses the clock-in; otherwise it is returned so the
+    /// employee can be warned.
+    pub async fn check_clock_in(
+        &self,
+        user_id: UserId,
+        date: NaiveDate,
+        clock_in: NaiveDateTime,
+    ) -> Result<Option<RestShortfall>, AppError> {
+        let Some(previous_clock_out) =
+            rest_interval::find_previous_clock_out(&self.pool, user_id, date).await?
+        else {
+            return Ok(None);
+        };
+        let policy = self.policy().await?;
+        let Some(shortfall) = policy.check(previous_clock_out, clock_in) else {
+            return Ok(None);
+        };
+        let blocked = policy.mode == RestIntervalMode::Block;
+        rest_interval::save_violation(&self.pool, user_id, date, clock_in, &shortfall, blocked)
+            .await?;
+        if blocked {
+            return Err(AppError::BadRequestWithCode {
+                message: format!(
+                    "Only {} minutes have passed since the last clock-out at {}; {} minutes of \
+                     rest are required before clocking in.",
+                    shortfall.rest_minutes,
+                    shortfall.previous_clock_out.format("%Y-%m-%d %H:%M"),
+                    shortfall.required_rest_minutes
+                ),
+                code: REST_INTERVAL_TOO_SHORT.to_string(),
+            });
+        }
+        Ok(Some(shortfall))
+    }
+}
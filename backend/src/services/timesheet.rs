@@ -0,0 +1,134 @@
+//! Monthly timesheet submission and sign-off.
+
+use chrono::{NaiveDate, Utc};
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::models::timesheet::{
+    TimesheetBlockers, TimesheetMonthStatus, TimesheetStatus, TimesheetSubmission,
+};
+use crate::repositories::timesheet;
+use crate::types::UserId;
+use crate::utils::time::{month_end, month_start};
+
+pub const TIMESHEET_NOT_READY: &str = "TIMESHEET_NOT_READY";
+
+#[derive(Clone)]
+pub struct TimesheetService {
+    pool: PgPool,
+}
+
+impl TimesheetService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    pub async fn month_status(
+        &self,
+        user_id: UserId,
+        period_month: NaiveDate,
+    ) -> Result<TimesheetMonthStatus, AppError> {
+        let submission =
+            timesheet::find_submission_for_month(&self.pool, user_id, period_month).await?;
+        let blockers = self.blockers(user_id, period_month).await?;
+        Ok(TimesheetMonthStatus {
+            period_month,
+            submission,
+            blockers,
+        })
+    }
+
+    /// Submits the month as final. Months that have not started yet, months that are
+    /// already submitted or approved, and months with open items are rejected.
+    pub async fn submit(
+        &self,
+        user_id: UserId,
+        period_month: NaiveDate,
+        today: NaiveDate,
+    ) -> Result<TimesheetSubmission, AppError> {
+        if period_month > month_start(today) {
+            return Err(AppError::BadRequest(
+                "Timesheets can only be submitted for the current or past months".into(),
+            ));
+        }
+        let current = timesheet::find_submission_for_month(&self.pool, user_id, period_month)
+            .await?
+            .map(|submission| submission.status);
+        if !TimesheetStatus::can_transition(current, TimesheetStatus::Submitted) {
+            return Err(already_submitted(period_month));
+        }
+        self.ensure_ready(user_id, period_month).await?;
+
+        let submission = TimesheetSubmission::new(user_id, period_month, Utc::now());
+        timesheet::submit(&self.pool, &submission)
+            .await?
+            .ok_or_else(|| already_submitted(period_month))
+    }
+
+    /// Approves or returns a submitted timesheet. Approval re-checks the month, since
+    /// corrections can still be requested after submission.
+    pub async fn decide(
+        &self,
+        submission: &TimesheetSubmission,
+        next: TimesheetStatus,
+        decided_by: UserId,
+        comment: Option<&str>,
+    ) -> Result<TimesheetSubmission, AppError> {
+        if !TimesheetStatus::can_transition(Some(submission.status), next)
+            || next == TimesheetStatus::Submitted
+        {
+            return Err(AppError::Conflict(
+                "Only submitted timesheets can be approved or returned".into(),
+            ));
+        }
+        if next == TimesheetStatus::Approved {
+            self.ensure_ready(submission.user_id, submission.period_month)
+                .await?;
+        }
+        timesheet::decide(
+            &self.pool,
+            submission.id,
+            next,
+            decided_by,
+            comment,
+            Utc::now(),
+        )
+        .await?
+        .ok_or_else(|| {
+            AppError::Conflict("Only submitted timesheets can be approved or returned".into())
+        })
+    }
+
+    async fn blockers(
+        &self,
+        user_id: UserId,
+        period_month: NaiveDate,
+    ) -> Result<TimesheetBlockers, AppError> {
+        let to = month_end(period_month);
+        Ok(timesheet::count_blockers(&self.pool, user_id, period_month, to).await?)
+    }
+
+    async fn ensure_ready(&self, user_id: UserId, period_month: NaiveDate) -> Result<(), AppError> {
+        let blockers = self.blockers(user_id, period_month).await?;
+        if blockers.is_clear() {
+            return Ok(());
+        }
+        Err(AppError::BadRequestWithCode {
+            message: format!(
+                "{} has {} pending correction request(s) and {} open clock-in(s); \
+                 resolve them before submitting",
+                period_month.format("%Y-%m"),
+                blockers.pending_corrections,
+                blockers.open_clock_ins
+            ),
+            code: TIMESHEET_NOT_READY.to_string(),
+        })
+    }
+}
+
+fn already_submitted(period_month: NaiveDate) -> AppError {
+    AppError::Conflict(format!(
+        "The timesheet for {} is already submitted",
+        period_month.format("%Y-%m")
+    ))
+}
@@ -0,0 +1,86 @@
+//! Resolves which work schedule applies to a user and what it expects of a day.
+
+use chrono::{NaiveDate, NaiveDateTime};
+use sqlx::PgPool;
+
+use crate::error::AppError;
+use crate::models::work_schedule::{DayExpectation, WorkSchedule};
+use crate::repositories::work_schedule;
+use crate::services::leave_balance::LeaveBalanceService;
+use crate::types::UserId;
+use crate::utils::time;
+
+#[derive(Clone)]
+pub struct WorkScheduleService {
+    pool: PgPool,
+}
+
+impl WorkScheduleService {
+    pub fn new(pool: PgPool) -> Self {
+        Self { pool }
+    }
+
+    /// Schedule assigned to the user on `date`, directly or through their department.
+    pub async fn schedule_for(
+        &self,
+        user_id: UserId,
+        date: NaiveDate,
+    ) -> Result<Option<WorkSchedule>, AppError> {
+        Ok(work_schedule::find_effective_schedule(&self.pool, user_id, date).await?)
+    }
+
+    /// Business date that a punch at local time `now` belongs to.
+    ///
+    /// While an overnight shift that started yesterday is still scheduled to run, punches
+    /// belong to yesterday; otherwise the day rolls over at `day_change_hour`.
+    pub async fn business_date(
+        &self,
+        user_id: UserId,
+        now: NaiveDateTime,
+        day_change_hour: u32,
+    ) -> Result<NaiveDate, AppError> {
+        if let Some(yesterday) = now.date().pred_opt() {
+            let overnight = self
+                .schedule_for(user_id, yesterday)
+                .await?
+                .filter(|schedule| schedule.crosses_midnight() && schedule.works_on(yesterday));
+            if overnight.is_some_and(|schedule| now < schedule.shift_end(yesterday)) {
+                return Ok(yesterday);
+            }
+        }
+        Ok(time::business_date(now, day_change_hour))
+    }
+
+    /// Expected hours and late threshold for the user on `date`.
+    ///
+    /// Without a schedule the employment profile's daily hours apply and lateness is not
+    /// judged. Days the schedule does not work expect nothing, and a day with approved leave
+    /// is never late because the leave may cover the morning. Flextime days are never late.
+    pub async fn day_expectation(
+        &self,
+        user_id: UserId,
+        date: NaiveDate,
+    ) -> Result<DayExpectation, AppError> {
+        let leave_balances = LeaveBalanceService::new(self.pool.clone());
+        let schedule = self.schedule_for(user_id, date).await?;
+        let daily_hours = match &schedule {
+            Some(schedule) if schedule.works_on(date) => schedule.scheduled_hours(),
+            Some(_) => 0.0,
+            None => leave_balances.daily_scheduled_hours(user_id).await?,
+        };
+        let leave_hours = leave_balances
+            .approved_leave_hours(user_id, date, daily_hours)
+            .await?;
+        let flextime = schedule
+            .as_ref()
+            .is_some_and(|schedule| schedule.is_flextime());
+        let late_after = schedule
+            .filter(|schedule| schedule.works_on(date) && leave_hours == 0.0 && !flextime)
+            .map(|schedule| schedule.late_after(date));
+        Ok(DayExpectation {
+            late_after,
+            expected_hours: (daily_hours - leave_hours).max(0.0),
+            flextime,
+        })
+    }
+}
@@ -0,0 +1,228 @@
+//! Splits effective attendance into the work-time buckets premium pay is calculated from.
+
+use chrono::{Datelike, Duration, NaiveDate, Weekday};
+use sqlx::PgPool;
+use std::collections::HashMap;
+
+use crate::error::AppError;
+use crate::models::{
+    attendance::AttendanceResponse,
+    break_record::BreakRecordResponse,
+    overtime::week_start,
+    work_time::{classify_shifts, ClassifiedShift, DayKind, WorkTimeBreakdown},
+};
+use crate::repositories::{
+    attendance::{AttendanceRepository, AttendanceRepositoryTrait},
+    attendance_correction_request::AttendanceCorrectionRequestRepository,
+    break_record::BreakRecordRepository,
+    work_time,
+};
+use crate::services::holiday::{HolidayDecision, HolidayService, HolidayServiceTrait};
+use crate::types::{AttendanceId, UserId};
+
+/// Attendance of a user between two dates with approved corrections applied.
+pub async fn load_effective_attendance(
+    pool: &PgPool,
+    user_id: UserId,
+    from: NaiveDate,
+    to: NaiveDate,
+) -> Result<Vec<AttendanceResponse>, AppError> {
+    let attendances = AttendanceRepository::new()
+        .find_by_user_and_range(pool, user_id, from, to)
+        .await?;
+    let attendance_ids: Vec<AttendanceId> = attendances.iter().map(|a| a.id).collect();
+    let mut break_map: HashMap<AttendanceId, Vec<BreakRecordResponse>> = HashMap::new();
+    for record in BreakRecordRepository::new()
+        .find_by_attendance_ids(pool, &attendance_ids)
+        .await?
+    {
+        break_map
+            .entry(record.attendance_id)
+            .or_default()
+            .push(BreakRecordResponse::from(record));
+    }
+    let corrections: HashMap<AttendanceId, _> = AttendanceCorrectionRequestRepository::new()
+        .get_effective_values(pool, &attendance_ids)
+        .await?
+        .into_iter()
+        .map(|effective| (effective.attendance_id, effective))
+        .collect();
+
+    Ok(attendances
+        .into_iter()
+        .map(|attendance| match corrections.get(&attendance.id) {
+            Some(effective) => effective.apply_to(attendance),
+            None => {
+                let break_records = break_map.remove(&attendance.id).unwrap_or_default();
+                AttendanceResponse {
+                    break_records,
+                    ..AttendanceResponse::from(attendance)
+                }
+            }
+        })
+        .collect())
+}
+
+#[derive(Clone)]
+pub struct WorkTimeService {
+    pool: PgPool,
+    holidays: HolidayService,
+}
+
+impl WorkTimeService {
+    pub fn new(pool: PgPool) -> Self {
+        Self {
+            holidays: HolidayService::new(pool.clone()),
+            pool,
+        }
+    }
+
+    /// Reclassifies the statutory week (Sunday to Saturday) around `date` and stores it.
+    ///
+    /// The whole week is rebuilt because a change to one day moves the 40-hour line for
+    /// the days after it. Returns the stored breakdowns.
+    pub async fn refresh_week(
+        &self,
+        user_id: UserId,
+        date: NaiveDate,
+    ) -> Result<HashMap<AttendanceId, WorkTimeBreakdown>, AppError> {
+        let from = week_start(date);
+        let to = from + Duration::days(6);
+        let mut days = load_effective_attendance(&self.pool, user_id, from, to).await?;
+        days.sort_by_key(|day| day.date);
+
+        let mut shifts = Vec::with_capacity(days.len());
+        let mut cleared = Vec::new();
+        for day in days {
+            let (Some(clock_in), Some(clock_out)) = (day.clock_in_time, day.clock_out_time) else {
+                cleared.push(day.id);
+                continue;
+            };
+            let decision = self
+                .holidays
+                .is_holiday(day.date, Some(&user_id.to_string()))
+                .await?;
+            let calendar = self.holidays.is_holiday(day.date, None).await?;
+            shifts.push(ClassifiedShift {
+                attendance_id: day.id,
+                date: day.date,
+                kind: day_kind(day.date, &decision, &calendar),
+                clock_in,
+                clock_out,
+                breaks: day
+                    .break_records
+                    .iter()
+                    .filter_map(|br| br.break_end_time.map(|end| (br.break_start_time, end)))
+                    .collect(),
+            });
+        }
+
+        let breakdowns: Vec<(AttendanceId, WorkTimeBreakdown)> = shifts
+            .iter()
+            .map(|shift| shift.attendance_id)
+            .zip(classify_shifts(&shifts))
+            .collect();
+        work_time::save_breakdowns(&self.pool, &breakdowns, &cleared).await?;
+        Ok(breakdowns.into_iter().collect())
+    }
+}
+
+/// A holiday on Sunday, where the statutory week starts, is the legal holiday; holidays on
+/// other days are non-statutory.
+///
+/// `decision` is the user's day and `calendar` the company calendar's. Clocking in on a
+/// calendar holiday takes a workday exception, which clears the user's flag, so a day the
+/// calendar marks as a holiday stays holiday work either way.
+fn day_kind(date: NaiveDate, decision: &HolidayDecision, calendar: &HolidayDecision) -> DayKind {
+    if !decision.is_holiday && !calendar.is_holiday {
+        DayKind::Workday
+    } else if date.weekday() == Weekday::Sun {
+        DayKind::LegalHoliday
+    } else {
+        DayKind::NonStatutoryHoliday
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::holiday::HolidayReason;
+
+    fn decision(reason: HolidayReason) -> HolidayDecision {
+        HolidayDecision {
+            is_holiday: reason != HolidayReason::None,
+            reason,
+        }
+    }
+
+    #[test]
+    fn only_sunday_holidays_are_legal_holidays() {
+        let sunday = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let saturday = NaiveDate::from_ymd_opt(2026, 3, 7).unwrap();
+
+        let workday = decision(HolidayReason::None);
+
+        assert_eq!(
+            day_kind(
+                sunday,
+                &decision(HolidayReason::WeeklyHoliday),
+                &decision(HolidayReason::WeeklyHoliday)
+            ),
+            DayKind::LegalHoliday
+        );
+        assert_eq!(
+            day_kind(
+                saturday,
+                &decision(HolidayReason::WeeklyHoliday),
+                &decision(HolidayReason::WeeklyHoliday)
+            ),
+            DayKind::NonStatutoryHoliday
+        );
+        assert_eq!(
+            day_kind(
+                sunday,
+                &decision(HolidayReason::PublicHoliday),
+                &decision(HolidayReason::PublicHoliday)
+            ),
+            DayKind::LegalHoliday
+        );
+        assert_eq!(day_kind(saturday, &workday, &workday), DayKind::Workday);
+    }
+
+    #[test]
+    fn workday_exceptions_on_calendar_holidays_stay_holiday_work() {
+        let sunday = NaiveDate::from_ymd_opt(2026, 3, 1).unwrap();
+        let saturday = NaiveDate::from_ymd_opt(2026, 3, 7).unwrap();
+        let worked = HolidayDecision {
+            is_holiday: false,
+            reason: HolidayReason::ExceptionOverride,
+        };
+
+        assert_eq!(
+            day_kind(sunday, &worked, &decision(HolidayReason::PublicHoliday)),
+            DayKind::LegalHoliday
+        );
+        assert_eq!(
+            day_kind(saturday, &worked, &decision(HolidayReason::WeeklyHoliday)),
+            DayKind::NonStatutoryHoliday
+        );
+        assert_eq!(
+            day_kind(saturday, &worked, &decision(HolidayReason::None)),
+            DayKind::Workday
+        );
+    }
+
+    #[test]
+    fn forced_holidays_count_as_holiday_work() {
+        let monday = NaiveDate::from_ymd_opt(2026, 3, 2).unwrap();
+        let forced = HolidayDecision {
+            is_holiday: true,
+            reason: HolidayReason::ExceptionOverride,
+        };
+
+        assert_eq!(
+            day_kind(monday, &forced, &decision(HolidayReason::None)),
+            DayKind::NonStatutoryHoliday
+        );
+    }
+}
@@ -166,6 +166,36 @@ typed_id!(
 );
 typed_id!(AuditLogId, "Unique identifier for an audit log entry.");
 typed_id!(DepartmentId, "Unique identifier for a department.");
+typed_id!(
+    LeaveBalanceEntryId,
+    "Unique identifier for a leave balance ledger entry."
+);
+typed_id!(WorkScheduleId, "Unique identifier for a work schedule.");
+typed_id!(
+    WorkScheduleAssignmentId,
+    "Unique identifier for a work schedule assignment."
+);
+typed_id!(
+    OvertimeLimitId,
+    "Unique identifier for an overtime limit set."
+);
+typed_id!(PeriodClosingId, "Unique identifier for a closed period.");
+typed_id!(
+    TimesheetSubmissionId,
+    "Unique identifier for a monthly timesheet submission."
+);
+typed_id!(
+    ExportTemplateId,
+    "Unique identifier for a payroll export template."
+);
+typed_id!(
+    ExportJobId,
+    "Unique identifier for an asynchronous export job."
+);
+typed_id!(
+    ExportScheduleId,
+    "Unique identifier for a recurring export schedule."
+);
 
 #[cfg(test)]
 mod tests {
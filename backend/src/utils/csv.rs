@@ -14,9 +14,14 @@ fn escape_cell(value: &str) -> String {
 }
 
 pub fn append_csv_row(buffer: &mut String, fields: &[String]) {
+    append_delimited_row(buffer, fields, ',');
+}
+
+/// Appends a row whose cells are separated by `delimiter` instead of a comma.
+pub fn append_delimited_row(buffer: &mut String, fields: &[String], delimiter: char) {
     for (idx, field) in fields.iter().enumerate() {
         if idx > 0 {
-            buffer.push(',');
+            buffer.push(delimiter);
         }
         buffer.push_str(&escape_cell(field));
     }
@@ -45,4 +50,12 @@ mod tests {
 
         assert_eq!(buffer, "\"'  -1\"\n");
     }
+
+    #[test]
+    fn separates_cells_with_the_given_delimiter() {
+        let mut buffer = String::new();
+        append_delimited_row(&mut buffer, &["a".to_string(), "b\tc".to_string()], '\t');
+
+        assert_eq!(buffer, "\"a\"\t\"b\tc\"\n");
+    }
 }
@@ -1,5 +1,6 @@
 use anyhow::Result;
 use lettre::message::header::ContentType;
+use lettre::message::{Attachment, MultiPart, SinglePart};
 use lettre::transport::smtp::authentication::Credentials;
 use lettre::{Message, SmtpTransport, Transport};
 use std::env;
@@ -143,6 +144,234 @@ Timekeeper 勤怠管理システム
         self.mailer.send(&email)?;
         Ok(())
     }
+
+    #[allow(dead_code)]
+    pub fn send_mandatory_leave_reminder(
+        &self,
+        to_email: &str,
+        username: &str,
+        days_remaining: f64,
+        deadline: chrono::NaiveDate,
+    ) -> Result<()> {
+        if env::var("SMTP_SKIP_SEND").unwrap_or_default() == "true" {
+            return Ok(());
+        }
+        let body = format!(
+            r#"
+{}さんの年次有給休暇の年5日取得義務について、取得期限が近づいています。
+
+残り必要日数: {}日
+取得期限: {}
+
+期限までに休暇を取得・申請してください。
+
+---
+Timekeeper 勤怠管理システム
+"#,
+            username,
+            days_remaining,
+            deadline.format("%Y-%m-%d")
+        );
+
+        let email = Message::builder()
+            .from(self.from_address.parse()?)
+            .to(to_email.parse()?)
+            .subject("年次有給休暇 取得期限のお知らせ - Timekeeper")
+            .header(ContentType::TEXT_PLAIN)
+            .body(body)?;
+
+        self.mailer.send(&email)?;
+        Ok(())
+    }
+
+    /// Tells an employee (or their manager) that a clock-out was missing.
+    ///
+    /// `closed_at` is the clock-out the system recorded; without it the record is left
+    /// open for a correction request.
+    #[allow(dead_code)]
+    pub fn send_forgotten_clock_out_notice(
+        &self,
+        to_email: &str,
+        username: &str,
+        date: chrono::NaiveDate,
+        closed_at: Option<chrono::NaiveDateTime>,
+    ) -> Result<()> {
+        if env::var("SMTP_SKIP_SEND").unwrap_or_default() == "true" {
+            return Ok(());
+        }
+        let outcome = match closed_at {
+            Some(closed_at) => format!(
+                "所定終業時刻 {} で自動的に退勤処理しました。実際の退勤時刻と異なる場合は修正申請を行ってください。",
+                closed_at.format("%Y-%m-%d %H:%M")
+            ),
+            None => "勤怠記録は未退勤のままです。修正申請で退勤時刻を登録してください。".to_string(),
+        };
+        let body = format!(
+            r#"
+{}さんの{}の勤怠に退勤打刻がありません。
+
+{}
+
+---
+Timekeeper 勤怠管理システム
+"#,
+            username,
+            date.format("%Y-%m-%d"),
+            outcome
+        );
+
+        let email = Message::builder()
+            .from(self.from_address.parse()?)
+            .to(to_email.parse()?)
+            .subject("退勤打刻漏れのお知らせ - Timekeeper")
+            .header(ContentType::TEXT_PLAIN)
+            .body(body)?;
+
+        self.mailer.send(&email)?;
+        Ok(())
+    }
+
+    /// Warns an employee (or their manager) that an Article 36 limit is near or exceeded.
+    #[allow(clippy::too_many_arguments)]
+    pub fn send_overtime_limit_warning(
+        &self,
+        to_email: &str,
+        username: &str,
+        month: chrono::NaiveDate,
+        limit_label: &str,
+        value: f64,
+        limit: f64,
+        exceeded: bool,
+    ) -> Result<()> {
+        if env::var("SMTP_SKIP_SEND").unwrap_or_default() == "true" {
+            return Ok(());
+        }
+        let (state, subject) = if exceeded {
+            (
+                "上限を超過しました",
+                "36協定 上限超過のお知らせ - Timekeeper",
+            )
+        } else {
+            (
+                "上限に近づいています",
+                "36協定 上限接近のお知らせ - Timekeeper",
+            )
+        };
+        let body = format!(
+            r#"
+{}さんの{}の{}が36協定の{}。
+
+実績: {}
+上限: {}
+
+業務量の調整や時間外労働の抑制を検討してください。
+
+---
+Timekeeper 勤怠管理システム
+"#,
+            username,
+            month.format("%Y年%m月"),
+            limit_label,
+            state,
+            value,
+            limit
+        );
+
+        let email = Message::builder()
+            .from(self.from_address.parse()?)
+            .to(to_email.parse()?)
+            .subject(subject)
+            .header(ContentType::TEXT_PLAIN)
+            .body(body)?;
+
+        self.mailer.send(&email)?;
+        Ok(())
+    }
+
+    /// Alerts a manager that an employee has worked more days in a row than allowed.
+    #[allow(dead_code)]
+    pub fn send_consecutive_workday_alert(
+        &self,
+        to_email: &str,
+        username: &str,
+        start_date: chrono::NaiveDate,
+        days: i64,
+        rest_days_in_four_weeks: i64,
+    ) -> Result<()> {
+        if env::var("SMTP_SKIP_SEND").unwrap_or_default() == "true" {
+            return Ok(());
+        }
+        let body = format!(
+            r#"
+{}さんが{}から{}日連続で勤務しています。
+
+直近4週間の休日数: {}日
+
+法定休日（週1日または4週4日）を確保できるよう、勤務予定を見直してください。
+
+---
+Timekeeper 勤怠管理システム
+"#,
+            username,
+            start_date.format("%Y-%m-%d"),
+            days,
+            rest_days_in_four_weeks
+        );
+
+        let email = Message::builder()
+            .from(self.from_address.parse()?)
+            .to(to_email.parse()?)
+            .subject("連続勤務日数超過のお知らせ - Timekeeper")
+            .header(ContentType::TEXT_PLAIN)
+            .body(body)?;
+
+        self.mailer.send(&email)?;
+        Ok(())
+    }
+
+    /// Delivers a scheduled export to its distribution list as an attachment.
+    #[allow(dead_code)]
+    pub fn send_scheduled_export(
+        &self,
+        recipients: &[String],
+        schedule_name: &str,
+        filename: &str,
+        content_type: &str,
+        data: Vec<u8>,
+    ) -> Result<()> {
+        if env::var("SMTP_SKIP_SEND").unwrap_or_default() == "true" {
+            return Ok(());
+        }
+        let body = format!(
+            r#"
+定期エクスポート「{}」のファイルを添付します。
+
+ファイル名: {}
+
+---
+Timekeeper 勤怠管理システム
+"#,
+            schedule_name, filename
+        );
+
+        let mut builder = Message::builder()
+            .from(self.from_address.parse()?)
+            .subject(format!("定期エクスポート: {} - Timekeeper", schedule_name));
+        for recipient in recipients {
+            builder = builder.to(recipient.parse()?);
+        }
+        let email = builder.multipart(
+            MultiPart::mixed()
+                .singlepart(SinglePart::plain(body))
+                .singlepart(
+                    Attachment::new(filename.to_string())
+                        .body(data, ContentType::parse(content_type)?),
+                ),
+        )?;
+
+        self.mailer.send(&email)?;
+        Ok(())
+    }
 }
 
 impl Default for EmailService {
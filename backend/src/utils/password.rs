@@ -129,6 +129,17 @@ mod tests {
             cookie_same_site: SameSite::Lax,
             cors_allow_origins: vec![],
             time_zone: UTC,
+            attendance_day_change_hour: 0,
+            forgotten_clock_out_cutoff_hours: 4,
+            forgotten_clock_out_auto_close: false,
+            consecutive_workday_limit: 6,
+            report_font_path: None,
+            export_storage_dir: "exports".into(),
+            export_s3_bucket: None,
+            export_s3_endpoint: None,
+            export_download_ttl_seconds: 300,
+            export_download_secret: "test-export-download-secret-32-chars".to_string(),
+            export_encryption_required: false,
             mfa_issuer: "".to_string(),
             rate_limit_ip_max_requests: 0,
             rate_limit_ip_window_seconds: 0,
@@ -1,4 +1,4 @@
-use chrono::{DateTime, NaiveDate, Utc};
+use chrono::{DateTime, Datelike, Duration, Months, NaiveDate, NaiveDateTime, Utc};
 use chrono_tz::Tz;
 
 /// Returns the current time in the configured timezone.
@@ -16,6 +16,25 @@ pub fn today_local(tz: &Tz) -> NaiveDate {
     now_in_timezone(tz).date_naive()
 }
 
+/// Business date a local timestamp belongs to when days roll over at `day_change_hour`.
+pub fn business_date(local: NaiveDateTime, day_change_hour: u32) -> NaiveDate {
+    (local - Duration::hours(i64::from(day_change_hour))).date()
+}
+
+/// First day of the month containing `date`.
+pub fn month_start(date: NaiveDate) -> NaiveDate {
+    date.with_day(1).unwrap_or(date)
+}
+
+/// Last day of the month containing `date`.
+pub fn month_end(date: NaiveDate) -> NaiveDate {
+    let first = month_start(date);
+    first
+        .checked_add_months(Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .unwrap_or(first)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -52,4 +71,29 @@ mod tests {
         let diff = (result - utc_now).num_seconds().abs();
         assert!(diff < 2, "Difference should be less than 2 seconds");
     }
+
+    #[test]
+    fn business_date_rolls_over_at_the_day_change_hour() {
+        let date = NaiveDate::from_ymd_opt(2026, 2, 4).unwrap();
+        let early = date.and_hms_opt(4, 59, 0).unwrap();
+        let later = date.and_hms_opt(5, 0, 0).unwrap();
+        assert_eq!(business_date(early, 0), date);
+        assert_eq!(business_date(early, 5), date.pred_opt().unwrap());
+        assert_eq!(business_date(later, 5), date);
+    }
+
+    #[test]
+    fn month_bounds_cover_the_calendar_month() {
+        let date = NaiveDate::from_ymd_opt(2028, 2, 17).unwrap();
+        assert_eq!(
+            month_start(date),
+            NaiveDate::from_ymd_opt(2028, 2, 1).unwrap()
+        );
+        assert_eq!(
+            month_end(date),
+            NaiveDate::from_ymd_opt(2028, 2, 29).unwrap()
+        );
+        let december = NaiveDate::from_ymd_opt(2026, 12, 31).unwrap();
+        assert_eq!(month_end(december), december);
+    }
 }
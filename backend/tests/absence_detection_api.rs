@@ -0,0 +1,140 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Extension, Router,
+};
+use chrono::NaiveDate;
+use sqlx::PgPool;
+use timekeeper_backend::{
+    handlers::attendance,
+    models::{
+        absence::AbsenceSummary,
+        leave_request::LeaveType,
+        user::{User, UserRole},
+    },
+    services::absence::AbsenceDetector,
+    state::AppState,
+    types::UserId,
+};
+use tower::ServiceExt;
+
+mod support;
+
+use support::{
+    response_json, seed_attendance, seed_holiday_exception, seed_leave_request,
+    seed_public_holiday, seed_user, test_config, test_pool,
+};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn summary_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/attendance/me/summary",
+            axum::routing::get(attendance::get_my_summary),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+fn date(value: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").expect("valid date")
+}
+
+async fn status_on(pool: &PgPool, user_id: UserId, day: NaiveDate) -> Option<(String, bool)> {
+    sqlx::query_as::<_, (String, bool)>(
+        "SELECT status, leave_request_id IS NOT NULL FROM attendance \
+         WHERE user_id = $1 AND date = $2",
+    )
+    .bind(user_id)
+    .bind(day)
+    .fetch_optional(pool)
+    .await
+    .expect("load attendance")
+}
+
+#[tokio::test]
+async fn detection_marks_absence_and_leave_on_working_days_only() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let workday = date("2032-03-09");
+    let holiday = date("2032-03-10");
+    let absentee = seed_user(&pool, UserRole::Employee, false).await;
+    let on_leave = seed_user(&pool, UserRole::Employee, false).await;
+    let worker = seed_user(&pool, UserRole::Employee, false).await;
+    // Other suites leave weekly holidays behind; keep the day a working day for these users.
+    for user in [&absentee, &on_leave, &worker] {
+        seed_holiday_exception(&pool, user.id, workday, false, "working day").await;
+    }
+    let leave = seed_leave_request(&pool, on_leave.id, LeaveType::annual(), workday, workday).await;
+    sqlx::query("UPDATE leave_requests SET status = 'approved', approved_at = NOW() WHERE id = $1")
+        .bind(leave.id.to_string())
+        .execute(&pool)
+        .await
+        .expect("approve leave");
+    seed_attendance(
+        &pool,
+        worker.id,
+        workday,
+        Some(workday.and_hms_opt(9, 0, 0).unwrap()),
+        Some(workday.and_hms_opt(18, 0, 0).unwrap()),
+    )
+    .await;
+
+    let detector = AbsenceDetector::new(pool.clone());
+    let summary = detector.detect(workday).await.expect("detect absences");
+    assert!(summary.absent >= 1);
+    assert!(summary.leave >= 1);
+    assert_eq!(
+        status_on(&pool, absentee.id, workday).await,
+        Some(("absent".to_string(), false))
+    );
+    assert_eq!(
+        status_on(&pool, on_leave.id, workday).await,
+        Some(("leave".to_string(), true))
+    );
+    assert_eq!(
+        status_on(&pool, worker.id, workday).await,
+        Some(("present".to_string(), false))
+    );
+
+    // Days that already have a record are left alone on a rerun.
+    let rerun = detector.detect(workday).await.expect("rerun detection");
+    assert_eq!(rerun, AbsenceSummary::default());
+
+    sqlx::query("DELETE FROM holidays WHERE holiday_date = $1")
+        .bind(holiday)
+        .execute(&pool)
+        .await
+        .expect("clear holiday");
+    seed_public_holiday(&pool, holiday, "Absence detection holiday").await;
+    detector.detect(holiday).await.expect("detect on holiday");
+    assert_eq!(status_on(&pool, absentee.id, holiday).await, None);
+
+    let app = summary_router(pool.clone(), absentee);
+    let request = Request::builder()
+        .uri("/api/attendance/me/summary?year=2032&month=3")
+        .body(Body::empty())
+        .expect("build request");
+    let response = app.oneshot(request).await.expect("call summary");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response_json(response).await;
+    assert_eq!(body["absent_days"], 1);
+    assert_eq!(body["total_work_days"], 0);
+}
@@ -15,7 +15,7 @@ use tower::ServiceExt;
 
 mod support;
 
-use support::{create_test_token, seed_user, test_config, test_pool};
+use support::{create_test_token, seed_leave_grant, seed_user, test_config, test_pool};
 
 async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
     static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
@@ -66,6 +66,7 @@ async fn test_admin_can_list_all_requests() {
 
     let admin = seed_user(&pool, UserRole::Manager, false).await;
     let employee = seed_user(&pool, UserRole::Employee, false).await;
+    seed_leave_grant(&pool, employee.id, 20.0).await;
 
     let employee_token = create_test_token(employee.id, employee.role.clone());
     let user_app = test_router_user(pool.clone(), employee.clone());
@@ -133,6 +134,7 @@ async fn test_admin_can_approve_leave_request() {
 
     let admin = seed_user(&pool, UserRole::Manager, false).await;
     let employee = seed_user(&pool, UserRole::Employee, false).await;
+    seed_leave_grant(&pool, employee.id, 20.0).await;
 
     let employee_token = create_test_token(employee.id, employee.role.clone());
     let user_app = test_router_user(pool.clone(), employee.clone());
@@ -187,6 +189,7 @@ async fn test_admin_can_reject_leave_request() {
 
     let admin = seed_user(&pool, UserRole::Manager, false).await;
     let employee = seed_user(&pool, UserRole::Employee, false).await;
+    seed_leave_grant(&pool, employee.id, 20.0).await;
 
     let employee_token = create_test_token(employee.id, employee.role.clone());
     let user_app = test_router_user(pool.clone(), employee.clone());
@@ -240,6 +243,7 @@ async fn test_admin_cannot_approve_own_request() {
         .expect("run migrations");
 
     let admin = seed_user(&pool, UserRole::Manager, false).await;
+    seed_leave_grant(&pool, admin.id, 20.0).await;
     let admin_token = create_test_token(admin.id, admin.role.clone());
     let user_app = test_router_user(pool.clone(), admin.clone());
     let admin_app = test_router_admin(pool.clone(), admin.clone());
@@ -289,6 +293,7 @@ async fn test_admin_cannot_reject_own_request() {
         .expect("run migrations");
 
     let admin = seed_user(&pool, UserRole::Manager, false).await;
+    seed_leave_grant(&pool, admin.id, 20.0).await;
     let admin_token = create_test_token(admin.id, admin.role.clone());
     let user_app = test_router_user(pool.clone(), admin.clone());
     let admin_app = test_router_admin(pool.clone(), admin.clone());
@@ -339,6 +344,7 @@ async fn test_approve_already_processed_request_fails() {
 
     let admin = seed_user(&pool, UserRole::Manager, false).await;
     let employee = seed_user(&pool, UserRole::Employee, false).await;
+    seed_leave_grant(&pool, employee.id, 20.0).await;
 
     let employee_token = create_test_token(employee.id, employee.role.clone());
     let user_app = test_router_user(pool.clone(), employee.clone());
@@ -401,6 +407,7 @@ async fn test_employee_cannot_approve_request() {
 
     let employee1 = seed_user(&pool, UserRole::Employee, false).await;
     let employee2 = seed_user(&pool, UserRole::Employee, false).await;
+    seed_leave_grant(&pool, employee2.id, 20.0).await;
 
     let employee2_token = create_test_token(employee2.id, employee2.role.clone());
     let user_app = test_router_user(pool.clone(), employee2.clone());
@@ -21,8 +21,8 @@ use tower::ServiceExt;
 mod support;
 
 use support::{
-    create_test_token, seed_attendance, seed_break_record, seed_public_holiday, seed_user,
-    test_config, test_pool,
+    create_test_token, response_json, seed_attendance, seed_break_record, seed_holiday_exception,
+    seed_public_holiday, seed_user, test_config, test_pool,
 };
 
 async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
@@ -775,3 +775,58 @@ async fn test_break_start_twice_and_break_end_twice_return_bad_request() {
         StatusCode::BAD_REQUEST
     );
 }
+
+#[tokio::test]
+async fn test_clock_out_closes_overnight_record_from_previous_day() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    sqlx::migrate!("./migrations")
+        .run(&pool)
+        .await
+        .expect("run migrations");
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let config = test_config();
+    let yesterday = timekeeper_backend::utils::time::today_local(&config.time_zone)
+        .pred_opt()
+        .expect("yesterday");
+    seed_holiday_exception(&pool, employee.id, yesterday, false, "night shift").await;
+    let night_shift = seed_attendance(
+        &pool,
+        employee.id,
+        yesterday,
+        Some(yesterday.and_hms_opt(22, 0, 0).unwrap()),
+        None,
+    )
+    .await;
+
+    let token = create_test_token(employee.id, employee.role.clone());
+    let app = test_router_with_state(pool.clone(), employee.clone());
+
+    let request = Request::builder()
+        .uri("/api/attendance/status")
+        .header("Authorization", format!("Bearer {}", token))
+        .body(Body::empty())
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let status = response_json(response).await;
+    assert_eq!(status["status"], "clocked_in");
+    assert_eq!(status["attendance_id"], night_shift.id.to_string());
+
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/attendance/clock-out")
+        .header("Authorization", format!("Bearer {}", token))
+        .header("Content-Type", "application/json")
+        .body(Body::from(
+            json!(ClockOutRequest { date: None }).to_string(),
+        ))
+        .unwrap();
+    let response = app.oneshot(request).await.unwrap();
+    assert_eq!(response.status(), StatusCode::OK);
+    let closed = response_json(response).await;
+    assert_eq!(closed["id"], night_shift.id.to_string());
+    assert_eq!(closed["date"], yesterday.to_string());
+    assert!(closed["total_work_hours"].as_f64().expect("hours") > 0.0);
+}
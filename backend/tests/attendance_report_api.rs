@@ -0,0 +1,161 @@
+use axum::{
+    body::Body,
+    http::{header::CONTENT_TYPE, Request, StatusCode},
+    Extension, Router,
+};
+use chrono::NaiveDate;
+use sqlx::PgPool;
+use std::sync::Arc;
+use timekeeper_backend::{
+    handlers::{admin, attendance},
+    models::{
+        leave_request::LeaveType,
+        user::{User, UserRole},
+    },
+    services::holiday::{HolidayService, HolidayServiceTrait},
+    state::AppState,
+};
+use tower::ServiceExt;
+
+mod support;
+
+use support::{
+    seed_attendance, seed_department, seed_leave_request, seed_user, test_config, test_pool,
+};
+
+const TEST_FONT: &str = "tests/support/fonts/demo.ttf";
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn report_router(pool: PgPool, user: User, font: Option<&str>) -> Router {
+    let mut config = test_config();
+    config.report_font_path = font.map(str::to_string);
+    let state = AppState::new(pool.clone(), None, None, None, config);
+    let holiday_service: Arc<dyn HolidayServiceTrait> = Arc::new(HolidayService::new(pool));
+    Router::new()
+        .route(
+            "/api/attendance/me/report",
+            axum::routing::get(attendance::get_my_attendance_report),
+        )
+        .route(
+            "/api/admin/departments/{id}/attendance-report",
+            axum::routing::get(admin::export_department_attendance_report),
+        )
+        .layer(Extension(user))
+        .layer(Extension(holiday_service))
+        .with_state(state)
+}
+
+async fn get_pdf(app: &Router, uri: &str) -> (StatusCode, Option<String>, Vec<u8>) {
+    let request = Request::builder()
+        .method("GET")
+        .uri(uri)
+        .body(Body::empty())
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("call endpoint");
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("read body bytes");
+    (status, content_type, body.to_vec())
+}
+
+fn count_pages(pdf: &[u8]) -> usize {
+    let text = String::from_utf8_lossy(pdf);
+    text.matches("/Type/Page/").count() + text.matches("/Type /Page\n").count()
+}
+
+#[tokio::test]
+async fn employee_downloads_own_monthly_report_as_pdf() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let mut employee = seed_user(&pool, UserRole::Employee, false).await;
+    // The test font only has a glyph for "A"; unused fonts are left out of the PDF.
+    employee.full_name = "Alice".into();
+    let day = |d: u32| NaiveDate::from_ymd_opt(2025, 6, d).unwrap();
+    seed_attendance(
+        &pool,
+        employee.id,
+        day(2),
+        Some(day(2).and_hms_opt(9, 0, 0).unwrap()),
+        Some(day(2).and_hms_opt(18, 0, 0).unwrap()),
+    )
+    .await;
+    let leave = seed_leave_request(&pool, employee.id, LeaveType::annual(), day(3), day(4)).await;
+    sqlx::query("UPDATE leave_requests SET status = 'approved' WHERE id = $1")
+        .bind(leave.id.to_string())
+        .execute(&pool)
+        .await
+        .expect("approve leave");
+
+    let app = report_router(pool.clone(), employee.clone(), Some(TEST_FONT));
+    let (status, content_type, body) =
+        get_pdf(&app, "/api/attendance/me/report?year=2025&month=6").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type.as_deref(), Some("application/pdf"));
+    assert!(body.starts_with(b"%PDF"));
+    assert!(String::from_utf8_lossy(&body).contains("/FontFile2"));
+
+    let (status, _, _) = get_pdf(&app, "/api/attendance/me/report?year=2025&month=13").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let unconfigured = report_router(pool.clone(), employee, None);
+    let (status, _, _) =
+        get_pdf(&unconfigured, "/api/attendance/me/report?year=2025&month=6").await;
+    assert_eq!(status, StatusCode::INTERNAL_SERVER_ERROR);
+}
+
+#[tokio::test]
+async fn manager_downloads_department_reports_with_a_page_per_employee() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let first = seed_user(&pool, UserRole::Employee, false).await;
+    let second = seed_user(&pool, UserRole::Employee, false).await;
+    let manager = seed_user(&pool, UserRole::Manager, false).await;
+    let other_manager = seed_user(&pool, UserRole::Manager, false).await;
+    let department_id = seed_department(&pool, &[first.id, second.id], manager.id).await;
+    let uri = format!("/api/admin/departments/{department_id}/attendance-report?year=2025&month=6");
+
+    let app = report_router(pool.clone(), manager, Some(TEST_FONT));
+    let (status, content_type, body) = get_pdf(&app, &uri).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type.as_deref(), Some("application/pdf"));
+    assert_eq!(count_pages(&body), 2);
+
+    let outsider = report_router(pool.clone(), other_manager, Some(TEST_FONT));
+    let (status, _, _) = get_pdf(&outsider, &uri).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let employee_app = report_router(pool.clone(), first, Some(TEST_FONT));
+    let (status, _, _) = get_pdf(&employee_app, &uri).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _, _) = get_pdf(
+        &app,
+        "/api/admin/departments/missing/attendance-report?year=2025&month=6",
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
@@ -0,0 +1,276 @@
+use axum::{http::StatusCode, Extension, Router};
+use serde_json::json;
+use sqlx::PgPool;
+use std::str::FromStr;
+use timekeeper_backend::{
+    handlers::admin,
+    models::user::{User, UserRole},
+    repositories::attendance::{AttendanceRepository, AttendanceRepositoryTrait},
+    services::break_policy::BreakPolicyService,
+    state::AppState,
+    types::{AttendanceId, UserId},
+};
+
+mod support;
+
+use support::{seed_user, send, test_config, test_pool};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+/// Drops any saved policy so the statutory, flag-only one applies.
+async fn reset_policy(pool: &PgPool) {
+    sqlx::query("DELETE FROM break_policy")
+        .execute(pool)
+        .await
+        .expect("reset break policy");
+}
+
+fn break_policy_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/admin/attendance",
+            axum::routing::put(admin::upsert_attendance),
+        )
+        .route(
+            "/api/admin/break-policy",
+            axum::routing::get(admin::get_break_policy).put(admin::update_break_policy),
+        )
+        .route(
+            "/api/admin/compliance/breaks",
+            axum::routing::get(admin::get_break_compliance),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+fn violation_on<'a>(
+    body: &'a serde_json::Value,
+    user_id: UserId,
+    date: &str,
+) -> Option<&'a serde_json::Value> {
+    body.as_array()
+        .expect("violation array")
+        .iter()
+        .find(|v| v["user_id"] == user_id.to_string() && v["date"] == date)
+}
+
+#[tokio::test]
+async fn short_breaks_are_flagged_or_deducted_by_policy() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+    reset_policy(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    let app = break_policy_router(pool.clone(), system_admin);
+
+    let (status, policy) = send(&app, "GET", "/api/admin/break-policy", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(policy["mode"], "flag");
+
+    // Nine hours without a break: flagged, hours left as recorded.
+    let (status, flagged) = send(
+        &app,
+        "PUT",
+        "/api/admin/attendance",
+        Some(json!({
+            "user_id": employee.id.to_string(),
+            "date": "2031-10-06",
+            "clock_in_time": "2031-10-06T09:00:00",
+            "clock_out_time": "2031-10-06T18:00:00"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(flagged["total_work_hours"], 9.0);
+
+    let (status, _) = send(
+        &app,
+        "PUT",
+        "/api/admin/break-policy",
+        Some(json!({
+            "mode": "deduct",
+            "first_threshold_minutes": 360,
+            "first_break_minutes": 45,
+            "second_threshold_minutes": 480,
+            "second_break_minutes": 60
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Seven hours with a ten-minute break: 35 minutes are deducted automatically.
+    let (status, deducted) = send(
+        &app,
+        "PUT",
+        "/api/admin/attendance",
+        Some(json!({
+            "user_id": employee.id.to_string(),
+            "date": "2031-10-07",
+            "clock_in_time": "2031-10-07T09:00:00",
+            "clock_out_time": "2031-10-07T16:00:00",
+            "breaks": [
+                {
+                    "break_start_time": "2031-10-07T12:00:00",
+                    "break_end_time": "2031-10-07T12:10:00"
+                }
+            ]
+        })),
+    )
+    .await;
+    reset_policy(&pool).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(deducted["total_work_hours"], 6.25);
+    let automatic: Vec<_> = deducted["break_records"]
+        .as_array()
+        .expect("break records")
+        .iter()
+        .filter(|br| br["is_automatic"] == true)
+        .collect();
+    assert_eq!(automatic.len(), 1);
+    assert_eq!(automatic[0]["duration_minutes"], 35);
+
+    let (status, report) = send(
+        &app,
+        "GET",
+        "/api/admin/compliance/breaks?from=2031-10-01&to=2031-10-31",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let first = violation_on(&report, employee.id, "2031-10-06").expect("flagged day listed");
+    assert_eq!(first["required_break_minutes"], 60);
+    assert_eq!(first["auto_deducted"], false);
+    let second = violation_on(&report, employee.id, "2031-10-07").expect("deducted day listed");
+    assert_eq!(second["recorded_break_minutes"], 10);
+    assert_eq!(second["auto_deducted"], true);
+
+    let employee_app = break_policy_router(pool.clone(), employee);
+    let (status, _) = send(&employee_app, "GET", "/api/admin/compliance/breaks", None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn rechecking_a_shift_replaces_its_automatic_break() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+    reset_policy(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    let app = break_policy_router(pool.clone(), system_admin);
+    let (status, _) = send(
+        &app,
+        "PUT",
+        "/api/admin/break-policy",
+        Some(json!({
+            "mode": "deduct",
+            "first_threshold_minutes": 360,
+            "first_break_minutes": 45,
+            "second_threshold_minutes": 480,
+            "second_break_minutes": 60
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, body) = send(
+        &app,
+        "PUT",
+        "/api/admin/attendance",
+        Some(json!({
+            "user_id": employee.id.to_string(),
+            "date": "2031-10-08",
+            "clock_in_time": "2031-10-08T09:00:00",
+            "clock_out_time": "2031-10-08T16:00:00"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["total_work_hours"], 6.25);
+    let attendance_id =
+        AttendanceId::from_str(body["id"].as_str().expect("attendance id")).expect("valid id");
+
+    // A break added afterwards covers the requirement, so the automatic one goes away.
+    sqlx::query(
+        "INSERT INTO break_records \
+             (id, attendance_id, break_start_time, break_end_time, duration_minutes, \
+              is_automatic, created_at, updated_at) \
+         VALUES ($1, $2, '2031-10-08 12:00:00', '2031-10-08 12:50:00', 50, FALSE, NOW(), NOW())",
+    )
+    .bind(uuid::Uuid::new_v4().to_string())
+    .bind(attendance_id)
+    .execute(&pool)
+    .await
+    .expect("insert manual break");
+    let attendance = AttendanceRepository::new()
+        .find_by_id(&pool, attendance_id)
+        .await
+        .expect("load attendance");
+    let mut tx = pool.begin().await.expect("begin transaction");
+    let break_minutes = BreakPolicyService::new(pool.clone())
+        .enforce(&mut tx, &attendance, chrono::Utc::now())
+        .await
+        .expect("enforce policy");
+    tx.commit().await.expect("commit");
+    reset_policy(&pool).await;
+    assert_eq!(break_minutes, 50);
+
+    let (automatic,): (i64,) = sqlx::query_as(
+        "SELECT COUNT(*) FROM break_records WHERE attendance_id = $1 AND is_automatic",
+    )
+    .bind(attendance_id)
+    .fetch_one(&pool)
+    .await
+    .expect("count automatic breaks");
+    assert_eq!(automatic, 0);
+    let (status, report) = send(
+        &app,
+        "GET",
+        "/api/admin/compliance/breaks?from=2031-10-08&to=2031-10-08",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(violation_on(&report, employee.id, "2031-10-08").is_none());
+}
+
+#[tokio::test]
+async fn break_policy_rejects_inverted_tiers() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    let app = break_policy_router(pool.clone(), system_admin);
+    let (status, _) = send(
+        &app,
+        "PUT",
+        "/api/admin/break-policy",
+        Some(json!({
+            "mode": "flag",
+            "first_threshold_minutes": 480,
+            "first_break_minutes": 60,
+            "second_threshold_minutes": 360,
+            "second_break_minutes": 45
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -0,0 +1,209 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Extension, Router,
+};
+use chrono::{Duration, NaiveDate};
+use sqlx::PgPool;
+use std::env;
+use timekeeper_backend::{
+    handlers::admin,
+    models::user::{User, UserRole},
+    services::rest_day::RestDayService,
+    state::AppState,
+    types::UserId,
+};
+use tower::ServiceExt;
+
+mod support;
+
+use support::{
+    response_json, seed_attendance, seed_department, seed_public_holiday, seed_user, test_config,
+    test_pool,
+};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn compliance_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/admin/compliance/consecutive-workdays",
+            axum::routing::get(admin::get_consecutive_workday_compliance),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+async fn report(app: &Router, query: &str) -> (StatusCode, serde_json::Value) {
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!(
+            "/api/admin/compliance/consecutive-workdays?{query}"
+        ))
+        .body(Body::empty())
+        .expect("build report request");
+    let response = app.clone().oneshot(request).await.expect("call report");
+    let status = response.status();
+    (status, response_json(response).await)
+}
+
+/// Seeds a clocked-in day for each date from `start` for `days` days.
+async fn seed_work(pool: &PgPool, user_id: UserId, start: NaiveDate, days: i64) {
+    for offset in 0..days {
+        let date = start + Duration::days(offset);
+        seed_attendance(
+            pool,
+            user_id,
+            date,
+            date.and_hms_opt(9, 0, 0),
+            date.and_hms_opt(18, 0, 0),
+        )
+        .await;
+    }
+}
+
+fn date(raw: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(raw, "%Y-%m-%d").expect("valid date")
+}
+
+fn listed(body: &serde_json::Value, user_id: UserId) -> Vec<&serde_json::Value> {
+    body.as_array()
+        .expect("streak array")
+        .iter()
+        .filter(|s| s["user_id"] == user_id.to_string())
+        .collect()
+}
+
+#[tokio::test]
+async fn runs_over_the_limit_are_reported_to_department_managers() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let manager = seed_user(&pool, UserRole::Manager, false).await;
+    let outsider = seed_user(&pool, UserRole::Manager, false).await;
+    let department_id = seed_department(&pool, &[employee.id], manager.id).await;
+
+    // Eight days in a row, one of them a holiday for the employee, then six days after a
+    // day off, which stays within the default limit of six.
+    seed_work(&pool, employee.id, date("2037-03-02"), 8).await;
+    seed_work(&pool, employee.id, date("2037-03-11"), 6).await;
+    sqlx::query("DELETE FROM holidays WHERE holiday_date = $1")
+        .bind(date("2037-03-05"))
+        .execute(&pool)
+        .await
+        .expect("clear holiday");
+    seed_public_holiday(&pool, date("2037-03-05"), "Rest day test").await;
+
+    let app = compliance_router(pool.clone(), manager.clone());
+    let (status, body) = report(&app, "from=2037-03-01&to=2037-03-31").await;
+    assert_eq!(status, StatusCode::OK);
+    let streaks = listed(&body, employee.id);
+    assert_eq!(streaks.len(), 1);
+    assert_eq!(streaks[0]["start_date"], "2037-03-02");
+    assert_eq!(streaks[0]["end_date"], "2037-03-09");
+    assert_eq!(streaks[0]["days"], 8);
+    assert!(streaks[0]["holidays_worked"].as_i64().unwrap() >= 1);
+    assert_eq!(streaks[0]["department_id"], department_id);
+
+    // Runs ending before the range are left out.
+    let (_, body) = report(&app, "from=2037-03-10&to=2037-03-31").await;
+    assert!(listed(&body, employee.id).is_empty());
+
+    let (_, body) = report(
+        &app,
+        &format!("from=2037-03-01&to=2037-03-31&department_id={department_id}"),
+    )
+    .await;
+    assert_eq!(listed(&body, employee.id).len(), 1);
+    let (status, _) = report(&app, "department_id=not-an-id").await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Managers of other departments do not see the employee.
+    let app = compliance_router(pool.clone(), outsider);
+    let (_, body) = report(&app, "from=2037-03-01&to=2037-03-31").await;
+    assert!(listed(&body, employee.id).is_empty());
+
+    let app = compliance_router(pool.clone(), employee);
+    let (status, _) = report(&app, "").await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn nightly_check_alerts_managers_once_per_run() {
+    let _guard = integration_guard().await;
+    env::set_var("SMTP_SKIP_SEND", "true");
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let manager = seed_user(&pool, UserRole::Manager, false).await;
+    seed_department(&pool, &[employee.id], manager.id).await;
+    seed_work(&pool, employee.id, date("2038-05-03"), 7).await;
+
+    let service = RestDayService::new(pool.clone());
+    let config = test_config();
+    for as_of in ["2038-05-08", "2038-05-09", "2038-05-10"] {
+        service
+            .send_alerts(date(as_of), &config)
+            .await
+            .expect("consecutive workday check");
+    }
+
+    let notified: Vec<(NaiveDate, i32)> = sqlx::query_as(
+        "SELECT start_date, days FROM consecutive_workday_notifications WHERE user_id = $1",
+    )
+    .bind(employee.id.to_string())
+    .fetch_all(&pool)
+    .await
+    .expect("load notifications");
+    // The run only exceeds the limit on its seventh day; it is alerted once after that.
+    assert_eq!(notified, vec![(date("2038-05-03"), 7)]);
+}
+
+#[tokio::test]
+async fn nightly_check_alerts_a_run_longer_than_the_window_once() {
+    let _guard = integration_guard().await;
+    env::set_var("SMTP_SKIP_SEND", "true");
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let manager = seed_user(&pool, UserRole::Manager, false).await;
+    seed_department(&pool, &[employee.id], manager.id).await;
+    seed_work(&pool, employee.id, date("2038-07-01"), 41).await;
+
+    let service = RestDayService::new(pool.clone());
+    let config = test_config();
+    for as_of in ["2038-08-08", "2038-08-09", "2038-08-10"] {
+        service
+            .send_alerts(date(as_of), &config)
+            .await
+            .expect("consecutive workday check");
+    }
+
+    let notified: Vec<(NaiveDate, i32)> = sqlx::query_as(
+        "SELECT start_date, days FROM consecutive_workday_notifications WHERE user_id = $1",
+    )
+    .bind(employee.id.to_string())
+    .fetch_all(&pool)
+    .await
+    .expect("load notifications");
+    // Each night reads only four weeks back, but the run keeps its real first day.
+    assert_eq!(notified, vec![(date("2038-07-01"), 39)]);
+}
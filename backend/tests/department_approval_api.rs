@@ -26,7 +26,9 @@ use tower::ServiceExt;
 
 mod support;
 
-use support::{create_test_token, seed_leave_request, seed_user, test_config, test_pool};
+use support::{
+    create_test_token, seed_leave_grant, seed_leave_request, seed_user, test_config, test_pool,
+};
 
 async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
     static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
@@ -100,6 +102,7 @@ async fn assign_manager_to_dept(pool: &PgPool, user_id: &str, dept_id: &str) {
 }
 
 async fn submit_leave_request(pool: PgPool, employee: User) -> String {
+    seed_leave_grant(&pool, employee.id, 20.0).await;
     let token = create_test_token(employee.id, employee.role.clone());
     let app = leave_request_router(pool, employee);
 
@@ -388,7 +391,7 @@ async fn test_employee_cannot_approve_request() {
     let leave = seed_leave_request(
         &pool,
         other.id,
-        timekeeper_backend::models::leave_request::LeaveType::Annual,
+        timekeeper_backend::models::leave_request::LeaveType::annual(),
         chrono::NaiveDate::from_ymd_opt(2025, 7, 1).unwrap(),
         chrono::NaiveDate::from_ymd_opt(2025, 7, 3).unwrap(),
     )
@@ -0,0 +1,272 @@
+use std::io::Read;
+
+use age::{armor::ArmoredReader, secrecy::SecretString, x25519};
+use axum::{
+    body::Body,
+    http::{HeaderMap, Request, StatusCode},
+    routing::{get, post},
+    Extension, Router,
+};
+use chrono::{Duration, NaiveDate, Utc};
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use timekeeper_backend::{
+    config::Config,
+    handlers::admin,
+    models::user::{User, UserRole},
+    services::{export_job::work_once, export_storage::ExportStorage},
+    state::AppState,
+};
+use tower::ServiceExt;
+
+mod support;
+
+use support::{seed_attendance, seed_audit_log, seed_user, test_config, test_pool};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn export_config(encryption_required: bool) -> Config {
+    let mut config = test_config();
+    config.export_storage_dir = std::env::temp_dir()
+        .join(format!("timekeeper-encrypted-{}", uuid::Uuid::new_v4()))
+        .to_string_lossy()
+        .into_owned();
+    config.export_encryption_required = encryption_required;
+    config
+}
+
+fn export_router(pool: PgPool, config: Config, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, config);
+    Router::new()
+        .route("/api/admin/export", get(admin::export_data))
+        .route(
+            "/api/admin/audit-logs/export",
+            get(admin::export_audit_logs),
+        )
+        .route("/api/admin/export-jobs", post(admin::create_export_job))
+        .route("/api/admin/export-jobs/{id}", get(admin::get_export_job))
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+async fn call(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, HeaderMap, Vec<u8>) {
+    let builder = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => builder
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("call endpoint");
+    let status = response.status();
+    let headers = response.headers().clone();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("read body bytes");
+    (status, headers, body.to_vec())
+}
+
+fn decrypt(encrypted: &[u8], identity: &dyn age::Identity) -> String {
+    let decryptor = age::Decryptor::new(ArmoredReader::new(encrypted)).expect("age file");
+    let mut reader = decryptor
+        .decrypt(std::iter::once(identity))
+        .expect("decrypt export");
+    let mut plaintext = String::new();
+    reader.read_to_string(&mut plaintext).expect("read export");
+    plaintext
+}
+
+fn passphrase_identity(headers: &HeaderMap) -> age::scrypt::Identity {
+    let passphrase = headers["X-Export-Passphrase"].to_str().unwrap().to_string();
+    age::scrypt::Identity::new(SecretString::from(passphrase))
+}
+
+#[tokio::test]
+async fn policy_makes_managers_encrypt_attendance_exports() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let manager = seed_user(&pool, UserRole::Manager, false).await;
+    let sysadmin = seed_user(&pool, UserRole::Manager, true).await;
+    let config = export_config(true);
+    let manager_app = export_router(pool.clone(), config.clone(), manager);
+
+    let (status, _, _) = call(&manager_app, "GET", "/api/admin/export", None).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _, _) = call(
+        &manager_app,
+        "GET",
+        "/api/admin/export?encryption=age&recipient=age1invalid",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, headers, body) = call(
+        &manager_app,
+        "GET",
+        "/api/admin/export?encryption=passphrase",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["X-Export-Encryption"], "passphrase");
+    assert_eq!(headers["Cache-Control"], "no-store");
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert!(body["filename"].as_str().unwrap().ends_with(".csv.age"));
+    assert_eq!(
+        body["passphrase"],
+        headers["X-Export-Passphrase"].to_str().unwrap()
+    );
+    let csv = decrypt(
+        body["csv_data"].as_str().unwrap().as_bytes(),
+        &passphrase_identity(&headers),
+    );
+    assert!(csv.starts_with("\"Username\",\"Full Name\",\"Date\""));
+
+    // System administrators are exempt from the policy.
+    let sysadmin_app = export_router(pool.clone(), config, sysadmin);
+    let (status, headers, body) = call(&sysadmin_app, "GET", "/api/admin/export", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(headers.get("X-Export-Encryption").is_none());
+    let body: Value = serde_json::from_slice(&body).unwrap();
+    assert!(body["csv_data"]
+        .as_str()
+        .unwrap()
+        .starts_with("\"Username\",\"Full Name\",\"Date\""));
+}
+
+#[tokio::test]
+async fn audit_log_exports_are_encrypted_for_the_requested_recipient() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let sysadmin = seed_user(&pool, UserRole::Manager, true).await;
+    seed_audit_log(&pool, sysadmin.id, "encrypted_export_test", "export").await;
+    let identity = x25519::Identity::generate();
+    let app = export_router(pool.clone(), export_config(false), sysadmin);
+
+    let today = Utc::now().date_naive();
+    let (status, headers, body) = call(
+        &app,
+        "GET",
+        &format!(
+            "/api/admin/audit-logs/export?from={}&to={}&recipient={}",
+            today - Duration::days(1),
+            today + Duration::days(1),
+            identity.to_public()
+        ),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(headers["X-Export-Encryption"], "age");
+    assert!(headers.get("X-Export-Passphrase").is_none());
+    assert_eq!(headers["content-type"], "application/octet-stream");
+    assert!(headers["content-disposition"]
+        .to_str()
+        .unwrap()
+        .ends_with(".json.age\""));
+    let logs: Value = serde_json::from_str(&decrypt(&body, &identity)).unwrap();
+    assert!(logs.as_array().is_some_and(|logs| !logs.is_empty()));
+}
+
+#[tokio::test]
+async fn export_jobs_encrypt_the_stored_file_and_forget_the_passphrase() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let sysadmin = seed_user(&pool, UserRole::Manager, true).await;
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let date = NaiveDate::from_ymd_opt(2025, 6, 2).unwrap();
+    seed_attendance(
+        &pool,
+        employee.id,
+        date,
+        date.and_hms_opt(9, 0, 0),
+        date.and_hms_opt(18, 0, 0),
+    )
+    .await;
+    let config = export_config(false);
+    let app = export_router(pool.clone(), config.clone(), sysadmin);
+
+    let (status, _, body) = call(
+        &app,
+        "POST",
+        "/api/admin/export-jobs",
+        Some(json!({
+            "kind": "attendance",
+            "username": employee.username,
+            "encryption": "passphrase"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    let job: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(job["encryption"], "passphrase");
+    let passphrase = job["passphrase"].as_str().unwrap().to_string();
+    let job_id = job["id"].as_str().unwrap().to_string();
+
+    let storage = ExportStorage::from_config(&config).await;
+    while work_once(&pool, &storage, &config)
+        .await
+        .expect("run export job")
+        .is_some()
+    {}
+
+    let (status, _, body) = call(
+        &app,
+        "GET",
+        &format!("/api/admin/export-jobs/{job_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let job: Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(job["status"], "completed");
+    assert_eq!(job["content_type"], "application/octet-stream");
+    assert!(job.get("passphrase").is_none());
+    let filename = job["filename"].as_str().unwrap();
+    assert!(filename.ends_with(".csv.age"));
+
+    let key: Option<String> =
+        sqlx::query_scalar("SELECT encryption_key FROM export_jobs WHERE id = $1")
+            .bind(&job_id)
+            .fetch_one(&pool)
+            .await
+            .unwrap();
+    assert!(key.is_none());
+
+    let path = storage
+        .local_path(&format!("export-jobs/{job_id}/{filename}"))
+        .unwrap();
+    let csv = decrypt(
+        &std::fs::read(path).unwrap(),
+        &age::scrypt::Identity::new(SecretString::from(passphrase)),
+    );
+    assert_eq!(csv.lines().count(), 2);
+    assert!(csv.contains("2025-06-02"));
+    std::fs::remove_dir_all(&config.export_storage_dir).ok();
+}
@@ -0,0 +1,324 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::{get, post},
+    Extension, Router,
+};
+use chrono::NaiveDate;
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use timekeeper_backend::{
+    config::Config,
+    handlers::admin,
+    models::user::{User, UserRole},
+    services::{export_job::work_once, export_storage::ExportStorage},
+    state::AppState,
+};
+use tower::ServiceExt;
+
+mod support;
+
+use support::{seed_attendance, seed_audit_log, seed_user, test_config, test_pool};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn export_config() -> Config {
+    let mut config = test_config();
+    config.export_storage_dir = std::env::temp_dir()
+        .join(format!("timekeeper-exports-{}", uuid::Uuid::new_v4()))
+        .to_string_lossy()
+        .into_owned();
+    config
+}
+
+fn export_router(pool: PgPool, config: Config, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, config);
+    let admin_routes = Router::new()
+        .route(
+            "/api/admin/export-jobs",
+            post(admin::create_export_job).get(admin::list_export_jobs),
+        )
+        .route("/api/admin/export-jobs/{id}", get(admin::get_export_job))
+        .route(
+            "/api/admin/export-jobs/{id}/download",
+            get(admin::get_export_job_download),
+        )
+        .layer(Extension(user));
+    Router::new()
+        .merge(admin_routes)
+        .route(
+            "/api/export-downloads/{id}",
+            get(admin::download_export_file),
+        )
+        .with_state(state)
+}
+
+async fn call(app: &Router, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, Vec<u8>) {
+    let builder = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => builder
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("call endpoint");
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("read body bytes");
+    (status, body.to_vec())
+}
+
+fn json_body(body: &[u8]) -> Value {
+    serde_json::from_slice(body).expect("json body")
+}
+
+/// Runs queued jobs until the queue is empty, as the worker binary would.
+async fn drain_queue(pool: &PgPool, config: &Config) {
+    let storage = ExportStorage::from_config(config).await;
+    while work_once(pool, &storage, config)
+        .await
+        .expect("run export job")
+        .is_some()
+    {}
+}
+
+#[tokio::test]
+async fn attendance_job_streams_csv_to_storage_and_serves_it_through_a_signed_link() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    for day in [2, 3] {
+        let date = NaiveDate::from_ymd_opt(2025, 6, day).unwrap();
+        seed_attendance(
+            &pool,
+            employee.id,
+            date,
+            date.and_hms_opt(9, 0, 0),
+            date.and_hms_opt(18, 0, 0),
+        )
+        .await;
+    }
+    let config = export_config();
+    let app = export_router(pool.clone(), config.clone(), admin);
+
+    let (status, body) = call(
+        &app,
+        "POST",
+        "/api/admin/export-jobs",
+        Some(json!({
+            "kind": "attendance",
+            "username": employee.username,
+            "from": "2025-01-01",
+            "to": "2025-12-31"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    let job = json_body(&body);
+    assert_eq!(job["status"], "queued");
+    let job_id = job["id"].as_str().unwrap().to_string();
+
+    let (status, _) = call(
+        &app,
+        "GET",
+        &format!("/api/admin/export-jobs/{job_id}/download"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    drain_queue(&pool, &config).await;
+
+    let (status, body) = call(
+        &app,
+        "GET",
+        &format!("/api/admin/export-jobs/{job_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let job = json_body(&body);
+    assert_eq!(job["status"], "completed");
+    assert_eq!(job["rows_written"], 2);
+    assert_eq!(job["total_rows"], 2);
+    assert!(job.get("storage_key").is_none());
+
+    let (status, body) = call(
+        &app,
+        "GET",
+        &format!("/api/admin/export-jobs/{job_id}/download"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let url = json_body(&body)["url"].as_str().unwrap().to_string();
+
+    let (status, body) = call(&app, "GET", &url, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let csv = String::from_utf8(body).expect("utf-8 csv");
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 3);
+    assert!(lines[0].starts_with("\"Username\",\"Full Name\",\"Date\""));
+    assert!(lines[1].contains("\"2025-06-02\",\"09:00\",\"18:00\""));
+
+    let tampered = url.replace("signature=", "signature=00");
+    let (status, _) = call(&app, "GET", &tampered, None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let other_manager = seed_user(&pool, UserRole::Manager, false).await;
+    let manager_app = export_router(pool.clone(), config.clone(), other_manager);
+    let (status, _) = call(
+        &manager_app,
+        "GET",
+        &format!("/api/admin/export-jobs/{job_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, body) = call(&manager_app, "GET", "/api/admin/export-jobs", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(json_body(&body)["total"], 0);
+
+    std::fs::remove_dir_all(&config.export_storage_dir).ok();
+}
+
+#[tokio::test]
+async fn audit_log_jobs_cover_long_periods_without_truncation() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    let event_type = format!("export_job_test_{}", uuid::Uuid::new_v4().simple());
+    for _ in 0..3 {
+        seed_audit_log(&pool, admin.id, &event_type, "export_job_test").await;
+    }
+    let config = export_config();
+    let app = export_router(pool.clone(), config.clone(), admin);
+
+    let (status, _) = call(
+        &app,
+        "POST",
+        "/api/admin/export-jobs",
+        Some(json!({"kind": "audit_logs", "event_type": event_type})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Longer than the 31 days the synchronous export allows.
+    let today = chrono::Utc::now().date_naive();
+    let (status, body) = call(
+        &app,
+        "POST",
+        "/api/admin/export-jobs",
+        Some(json!({
+            "kind": "audit_logs",
+            "from": (today - chrono::Duration::days(90)).to_string(),
+            "to": today.to_string(),
+            "event_type": event_type
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::ACCEPTED);
+    let job_id = json_body(&body)["id"].as_str().unwrap().to_string();
+
+    drain_queue(&pool, &config).await;
+
+    let (status, body) = call(
+        &app,
+        "GET",
+        &format!("/api/admin/export-jobs/{job_id}/download"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let url = json_body(&body)["url"].as_str().unwrap().to_string();
+    let (status, body) = call(&app, "GET", &url, None).await;
+    assert_eq!(status, StatusCode::OK);
+    let logs = json_body(&body);
+    assert_eq!(logs.as_array().map(Vec::len), Some(3));
+    assert_eq!(logs[0]["event_type"], event_type.as_str());
+    assert_eq!(logs[0]["ip"], "127.0.0.1");
+
+    std::fs::remove_dir_all(&config.export_storage_dir).ok();
+}
+
+#[tokio::test]
+async fn jobs_abandoned_by_a_crashed_worker_are_claimed_again() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    let event_type = format!("export_job_test_{}", uuid::Uuid::new_v4().simple());
+    seed_audit_log(&pool, admin.id, &event_type, "export_job_test").await;
+    let config = export_config();
+    let app = export_router(pool.clone(), config.clone(), admin);
+
+    let today = chrono::Utc::now().date_naive();
+    let mut job_ids = Vec::new();
+    for _ in 0..2 {
+        let (status, body) = call(
+            &app,
+            "POST",
+            "/api/admin/export-jobs",
+            Some(json!({
+                "kind": "audit_logs",
+                "from": (today - chrono::Duration::days(1)).to_string(),
+                "to": today.to_string(),
+                "event_type": event_type
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::ACCEPTED);
+        job_ids.push(json_body(&body)["id"].as_str().unwrap().to_string());
+    }
+    // One worker died an hour ago; the other is still busy with its job.
+    for (job_id, last_seen) in job_ids.iter().zip(["1 hour", "0 seconds"]) {
+        sqlx::query(
+            "UPDATE export_jobs SET status = 'running', \
+             updated_at = NOW() - $2::INTERVAL WHERE id = $1",
+        )
+        .bind(job_id)
+        .bind(last_seen)
+        .execute(&pool)
+        .await
+        .expect("mark job running");
+    }
+
+    drain_queue(&pool, &config).await;
+
+    let mut statuses = Vec::new();
+    for job_id in &job_ids {
+        let (status, body) = call(
+            &app,
+            "GET",
+            &format!("/api/admin/export-jobs/{job_id}"),
+            None,
+        )
+        .await;
+        assert_eq!(status, StatusCode::OK);
+        statuses.push(json_body(&body)["status"].clone());
+    }
+    assert_eq!(statuses, [json!("completed"), json!("running")]);
+
+    std::fs::remove_dir_all(&config.export_storage_dir).ok();
+}
@@ -0,0 +1,380 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    routing::{delete, get, put},
+    Extension, Router,
+};
+use chrono::{DateTime, NaiveDate, TimeZone, Utc};
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use timekeeper_backend::{
+    config::Config,
+    handlers::admin,
+    models::user::{User, UserRole},
+    services::{audit_log::AuditLogService, export_schedule::run_due_schedules},
+    state::AppState,
+};
+use tower::ServiceExt;
+
+mod support;
+
+use support::{seed_attendance, seed_user, test_config, test_pool};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn export_config() -> Config {
+    let mut config = test_config();
+    config.export_storage_dir = std::env::temp_dir()
+        .join(format!("timekeeper-schedules-{}", uuid::Uuid::new_v4()))
+        .to_string_lossy()
+        .into_owned();
+    config
+}
+
+fn schedule_router(pool: PgPool, config: Config, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, config);
+    Router::new()
+        .route(
+            "/api/admin/export-schedules",
+            get(admin::list_export_schedules).post(admin::create_export_schedule),
+        )
+        .route(
+            "/api/admin/export-schedules/{id}",
+            put(admin::update_export_schedule).delete(admin::delete_export_schedule),
+        )
+        .route(
+            "/api/admin/export-templates",
+            axum::routing::post(admin::create_export_template),
+        )
+        .route(
+            "/api/admin/export-templates/{id}",
+            delete(admin::delete_export_template),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+async fn call(app: &Router, method: &str, uri: &str, body: Option<Value>) -> (StatusCode, Value) {
+    let builder = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => builder
+            .header("content-type", "application/json")
+            .body(Body::from(body.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("call endpoint");
+    let status = response.status();
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("read body bytes");
+    (status, serde_json::from_slice(&body).unwrap_or(Value::Null))
+}
+
+fn unique(prefix: &str) -> String {
+    format!("{}-{}", prefix, uuid::Uuid::new_v4().simple())
+}
+
+async fn make_due(pool: &PgPool, id: &str, at: DateTime<Utc>) {
+    sqlx::query("UPDATE export_schedules SET next_run_at = $2 WHERE id = $1")
+        .bind(id)
+        .bind(at)
+        .execute(pool)
+        .await
+        .expect("make schedule due");
+}
+
+async fn run_audit_entries(pool: &PgPool, id: &str) -> Vec<(String, Value)> {
+    sqlx::query_as::<_, (String, sqlx::types::Json<Value>)>(
+        "SELECT result, metadata FROM audit_logs \
+         WHERE event_type = 'export_schedule_run' AND target_id = $1 ORDER BY occurred_at",
+    )
+    .bind(id)
+    .fetch_all(pool)
+    .await
+    .expect("fetch audit entries")
+    .into_iter()
+    .map(|(result, metadata)| (result, metadata.0))
+    .collect()
+}
+
+#[tokio::test]
+async fn schedules_validate_cron_and_destination_and_keep_templates_in_use() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let sysadmin = seed_user(&pool, UserRole::Manager, true).await;
+    let manager = seed_user(&pool, UserRole::Manager, false).await;
+    let config = export_config();
+    let app = schedule_router(pool.clone(), config.clone(), sysadmin);
+
+    let schedule = |name: &str, cron: &str, destination: Value| {
+        json!({
+            "name": name,
+            "cron_expression": cron,
+            "period": "previous_month",
+            "destination": destination
+        })
+    };
+    let local = json!({"type": "local", "directory": "payroll"});
+
+    let (status, _) = call(
+        &app,
+        "POST",
+        "/api/admin/export-schedules",
+        Some(schedule(&unique("bad-cron"), "monthly", local.clone())),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = call(
+        &app,
+        "POST",
+        "/api/admin/export-schedules",
+        Some(schedule(
+            &unique("escape"),
+            "0 6 1 * *",
+            json!({"type": "local", "directory": "../etc"}),
+        )),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, _) = call(
+        &app,
+        "POST",
+        "/api/admin/export-schedules",
+        Some(schedule(
+            &unique("bad-mail"),
+            "0 6 1 * *",
+            json!({"type": "email", "recipients": ["payroll"]}),
+        )),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let mut payload = schedule(&unique("bad-key"), "0 6 1 * *", local.clone());
+    payload["encryption_recipient"] = json!("age1notakey");
+    let (status, _) = call(&app, "POST", "/api/admin/export-schedules", Some(payload)).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let manager_app = schedule_router(pool.clone(), config.clone(), manager);
+    let (status, _) = call(
+        &manager_app,
+        "POST",
+        "/api/admin/export-schedules",
+        Some(schedule(&unique("manager"), "0 6 1 * *", local.clone())),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, template) = call(
+        &app,
+        "POST",
+        "/api/admin/export-templates",
+        Some(json!({
+            "name": unique("payroll"),
+            "granularity": "daily",
+            "columns": [{"field": "username"}, {"field": "date"}]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let template_id = template["id"].as_str().unwrap().to_string();
+
+    let name = unique("monthly");
+    let mut payload = schedule(&name, "0 6 1 * *", local.clone());
+    payload["template"] = json!(template_id);
+    let (status, created) = call(&app, "POST", "/api/admin/export-schedules", Some(payload)).await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(created["enabled"], true);
+    assert_eq!(created["template_id"], template_id.as_str());
+    let next_run_at: DateTime<Utc> = created["next_run_at"].as_str().unwrap().parse().unwrap();
+    assert!(next_run_at > Utc::now());
+    let id = created["id"].as_str().unwrap().to_string();
+
+    let (status, _) = call(
+        &app,
+        "POST",
+        "/api/admin/export-schedules",
+        Some(schedule(&name, "0 6 1 * *", local.clone())),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _) = call(
+        &app,
+        "DELETE",
+        &format!("/api/admin/export-templates/{template_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, updated) = call(
+        &app,
+        "PUT",
+        &format!("/api/admin/export-schedules/{id}"),
+        Some(json!({"cron_expression": "30 7 * * 1", "template": ""})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(updated["cron_expression"], "30 7 * * 1");
+    assert!(updated["template_id"].is_null());
+    assert_ne!(updated["next_run_at"], created["next_run_at"]);
+
+    let (status, _) = call(
+        &app,
+        "DELETE",
+        &format!("/api/admin/export-schedules/{id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = call(
+        &app,
+        "DELETE",
+        &format!("/api/admin/export-schedules/{id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    let (status, _) = call(
+        &app,
+        "DELETE",
+        &format!("/api/admin/export-templates/{template_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn due_schedules_deliver_the_previous_month_and_audit_every_run() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let sysadmin = seed_user(&pool, UserRole::Manager, true).await;
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    for (month, day) in [(6, 2), (6, 30), (7, 1)] {
+        let date = NaiveDate::from_ymd_opt(2025, month, day).unwrap();
+        seed_attendance(
+            &pool,
+            employee.id,
+            date,
+            date.and_hms_opt(9, 0, 0),
+            date.and_hms_opt(18, 0, 0),
+        )
+        .await;
+    }
+    let config = export_config();
+    let app = schedule_router(pool.clone(), config.clone(), sysadmin);
+
+    let mut ids = Vec::new();
+    for directory in ["payroll", "blocked/monthly"] {
+        let (status, created) = call(
+            &app,
+            "POST",
+            "/api/admin/export-schedules",
+            Some(json!({
+                "name": unique("monthly"),
+                "cron_expression": "0 6 1 * *",
+                "username": employee.username,
+                "period": "previous_month",
+                "destination": {"type": "local", "directory": directory}
+            })),
+        )
+        .await;
+        assert_eq!(status, StatusCode::CREATED);
+        ids.push(created["id"].as_str().unwrap().to_string());
+    }
+    // A file where the second destination needs a directory makes its delivery fail.
+    std::fs::create_dir_all(&config.export_storage_dir).unwrap();
+    std::fs::write(
+        std::path::Path::new(&config.export_storage_dir).join("blocked"),
+        b"",
+    )
+    .unwrap();
+
+    // 06:00 JST on 1 July 2025.
+    let scheduled_for = Utc.with_ymd_and_hms(2025, 6, 30, 21, 0, 0).unwrap();
+    for id in &ids {
+        make_due(&pool, id, scheduled_for).await;
+    }
+    let audit = AuditLogService::new(pool.clone());
+    let now = Utc::now();
+    let summary = run_due_schedules(&pool, &config, &audit, now)
+        .await
+        .expect("run schedules");
+    assert!(summary.succeeded >= 1);
+    assert!(summary.failed >= 1);
+
+    let files: Vec<_> =
+        std::fs::read_dir(std::path::Path::new(&config.export_storage_dir).join("payroll"))
+            .expect("payroll directory")
+            .map(|entry| entry.unwrap().path())
+            .collect();
+    assert_eq!(files.len(), 1);
+    let csv = std::fs::read_to_string(&files[0]).unwrap();
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines.len(), 3, "header and the two June days: {csv}");
+    assert!(lines[1].contains("2025-06-02"));
+    assert!(lines[2].contains("2025-06-30"));
+
+    let (_, schedules) = call(&app, "GET", "/api/admin/export-schedules", None).await;
+    let find = |id: &str| {
+        schedules
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|schedule| schedule["id"] == id)
+            .cloned()
+            .unwrap()
+    };
+    let delivered = find(&ids[0]);
+    assert_eq!(delivered["last_run_status"], "success");
+    let next_run_at: DateTime<Utc> = delivered["next_run_at"].as_str().unwrap().parse().unwrap();
+    assert!(next_run_at > now);
+    let failed = find(&ids[1]);
+    assert_eq!(failed["last_run_status"], "failure");
+    assert!(failed["last_run_error"].as_str().is_some());
+
+    let entries = run_audit_entries(&pool, &ids[0]).await;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].0, "success");
+    assert_eq!(entries[0].1["rows"], 2);
+    assert_eq!(entries[0].1["from"], "2025-06-01");
+    assert_eq!(entries[0].1["to"], "2025-06-30");
+    let entries = run_audit_entries(&pool, &ids[1]).await;
+    assert_eq!(entries.len(), 1);
+    assert_eq!(entries[0].0, "failure");
+
+    // Runs are claimed once; nothing is due again until the next occurrence.
+    run_due_schedules(&pool, &config, &audit, Utc::now())
+        .await
+        .expect("run schedules again");
+    assert_eq!(run_audit_entries(&pool, &ids[0]).await.len(), 1);
+
+    for id in &ids {
+        call(
+            &app,
+            "DELETE",
+            &format!("/api/admin/export-schedules/{id}"),
+            None,
+        )
+        .await;
+    }
+    std::fs::remove_dir_all(&config.export_storage_dir).ok();
+}
@@ -0,0 +1,275 @@
+use axum::{
+    body::Body,
+    http::{header::CONTENT_TYPE, Request, StatusCode},
+    routing::{get, post},
+    Extension, Router,
+};
+use chrono::NaiveDate;
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use timekeeper_backend::{
+    handlers::admin,
+    models::{
+        employment_profile::EmploymentType,
+        user::{User, UserRole},
+    },
+    repositories::employment_profile,
+    state::AppState,
+    utils::encryption::encrypt_pii,
+};
+use tower::ServiceExt;
+
+mod support;
+
+use support::{seed_attendance, seed_user, test_config, test_pool};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn export_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route("/api/admin/export", get(admin::export_data))
+        .route(
+            "/api/admin/export-templates",
+            post(admin::create_export_template).get(admin::list_export_templates),
+        )
+        .route(
+            "/api/admin/export-templates/fields",
+            get(admin::list_export_fields),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+async fn call(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    body: Option<Value>,
+) -> (StatusCode, Option<String>, Vec<u8>) {
+    let builder = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => builder
+            .header(CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("call endpoint");
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .map(str::to_string);
+    let body = axum::body::to_bytes(response.into_body(), usize::MAX)
+        .await
+        .expect("read body bytes");
+    (status, content_type, body.to_vec())
+}
+
+async fn seed_payroll_employee(pool: &PgPool, employee_code: &str) -> User {
+    let employee = seed_user(pool, UserRole::Employee, false).await;
+    let full_name_enc = encrypt_pii("山田 太郎", &test_config()).expect("encrypt full name");
+    sqlx::query("UPDATE users SET full_name_enc = $1 WHERE id = $2")
+        .bind(full_name_enc)
+        .bind(employee.id.to_string())
+        .execute(pool)
+        .await
+        .expect("set full name");
+    employment_profile::upsert(
+        pool,
+        employee.id,
+        NaiveDate::from_ymd_opt(2024, 4, 1).unwrap(),
+        EmploymentType::FullTime,
+        5,
+        40.0,
+        Some(employee_code),
+    )
+    .await
+    .expect("create profile");
+
+    for (day, hours) in [(2, 8.0), (3, 9.5)] {
+        let date = NaiveDate::from_ymd_opt(2025, 6, day).unwrap();
+        let attendance = seed_attendance(
+            pool,
+            employee.id,
+            date,
+            date.and_hms_opt(9, 0, 0),
+            date.and_hms_opt(18, 0, 0),
+        )
+        .await;
+        sqlx::query("UPDATE attendance SET total_work_hours = $1 WHERE id = $2")
+            .bind(hours)
+            .bind(attendance.id.to_string())
+            .execute(pool)
+            .await
+            .expect("set worked hours");
+    }
+    employee
+}
+
+#[tokio::test]
+async fn monthly_shift_jis_template_exports_one_row_per_employee_and_month() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    let employee_code = format!("E{}", &uuid::Uuid::new_v4().simple().to_string()[..8]);
+    let employee = seed_payroll_employee(&pool, &employee_code).await;
+    let app = export_router(pool.clone(), admin);
+
+    let name = format!("payroll-{}", uuid::Uuid::new_v4());
+    let (status, _, body) = call(
+        &app,
+        "POST",
+        "/api/admin/export-templates",
+        Some(json!({
+            "name": name,
+            "granularity": "monthly",
+            "encoding": "shift_jis",
+            "delimiter": "tab",
+            "month_format": "%Y%m",
+            "columns": [
+                {"field": "employee_code", "header": "社員番号"},
+                {"field": "full_name", "header": "氏名"},
+                {"field": "month"},
+                {"field": "work_days"},
+                {"field": "total_hours"}
+            ]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let template: Value = serde_json::from_slice(&body).expect("template json");
+    assert_eq!(template["encoding"], "shift_jis");
+
+    let uri = format!(
+        "/api/admin/export?template={}&username={}&from=2025-06-01&to=2025-06-30",
+        template["id"].as_str().unwrap(),
+        employee.username
+    );
+    let (status, content_type, body) = call(&app, "GET", &uri, None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(content_type.as_deref(), Some("text/csv; charset=Shift_JIS"));
+    let (text, _, had_errors) = encoding_rs::SHIFT_JIS.decode(&body);
+    assert!(!had_errors);
+    assert_eq!(
+        text,
+        format!(
+            "\"社員番号\"\t\"氏名\"\t\"Month\"\t\"Work Days\"\t\"Total Hours\"\n\
+             \"{employee_code}\"\t\"山田 太郎\"\t\"202506\"\t\"2\"\t\"17.50\"\n"
+        )
+    );
+
+    let (status, _, _) = call(
+        &app,
+        "GET",
+        "/api/admin/export?template=missing-template",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn template_definitions_are_validated_and_limited_to_system_admins() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    let manager = seed_user(&pool, UserRole::Manager, false).await;
+    let app = export_router(pool.clone(), admin);
+    let name = format!("daily-{}", uuid::Uuid::new_v4());
+    let daily = json!({
+        "name": name,
+        "granularity": "daily",
+        "date_format": "%Y/%m/%d",
+        "columns": [{"field": "username"}, {"field": "date"}, {"field": "clock_in"}]
+    });
+
+    let (status, _, _) = call(
+        &app,
+        "POST",
+        "/api/admin/export-templates",
+        Some(daily.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    let (status, _, _) = call(&app, "POST", "/api/admin/export-templates", Some(daily)).await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, _, _) = call(
+        &app,
+        "POST",
+        "/api/admin/export-templates",
+        Some(json!({
+            "name": format!("monthly-{}", uuid::Uuid::new_v4()),
+            "granularity": "monthly",
+            "columns": [{"field": "clock_in"}]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, _, _) = call(
+        &app,
+        "POST",
+        "/api/admin/export-templates",
+        Some(json!({
+            "name": format!("bad-date-{}", uuid::Uuid::new_v4()),
+            "granularity": "daily",
+            "date_format": "%Y-%m-%d %H:%M",
+            "columns": [{"field": "date"}]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let manager_app = export_router(pool.clone(), manager);
+    let (status, _, _) = call(
+        &manager_app,
+        "POST",
+        "/api/admin/export-templates",
+        Some(json!({
+            "name": format!("manager-{}", uuid::Uuid::new_v4()),
+            "granularity": "daily",
+            "columns": [{"field": "username"}]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _, body) = call(
+        &manager_app,
+        "GET",
+        "/api/admin/export-templates/fields",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let fields: Value = serde_json::from_slice(&body).expect("fields json");
+    let work_days = fields
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|field| field["field"] == "work_days")
+        .expect("work_days in catalog");
+    assert_eq!(work_days["daily"], false);
+    assert_eq!(work_days["monthly"], true);
+}
@@ -0,0 +1,181 @@
+use axum::{http::StatusCode, Extension, Router};
+use chrono::NaiveDate;
+use serde_json::json;
+use sqlx::PgPool;
+use timekeeper_backend::{
+    handlers::{admin, attendance},
+    models::user::{User, UserRole},
+    state::AppState,
+};
+
+mod support;
+
+use support::{seed_holiday_exception, seed_user, send, test_config, test_pool};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn flextime_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/admin/work-schedules",
+            axum::routing::post(admin::create_work_schedule),
+        )
+        .route(
+            "/api/admin/work-schedule-assignments",
+            axum::routing::post(admin::create_work_schedule_assignment),
+        )
+        .route(
+            "/api/admin/attendance",
+            axum::routing::put(admin::upsert_attendance),
+        )
+        .route(
+            "/api/attendance/me/summary",
+            axum::routing::get(attendance::get_my_summary),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+fn date(value: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").expect("valid date")
+}
+
+/// Schedule names are global, so each test run gets its own.
+fn unique_name(prefix: &str) -> String {
+    format!(
+        "{prefix} {}",
+        &uuid::Uuid::new_v4().simple().to_string()[..8]
+    )
+}
+
+#[tokio::test]
+async fn flextime_summary_reports_balance_and_core_time_misses() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    // Pin both days as workdays regardless of shared holiday fixtures.
+    seed_holiday_exception(&pool, employee.id, date("2025-03-03"), false, "workday").await;
+    seed_holiday_exception(&pool, employee.id, date("2025-03-04"), false, "workday").await;
+    let admin_app = flextime_router(pool.clone(), system_admin);
+
+    let (status, _) = send(
+        &admin_app,
+        "POST",
+        "/api/admin/work-schedules",
+        Some(json!({
+            "name": unique_name("Unbalanced core"),
+            "start_time": "09:00:00",
+            "end_time": "18:00:00",
+            "kind": "flextime",
+            "core_start_time": "10:00:00"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let (status, schedule) = send(
+        &admin_app,
+        "POST",
+        "/api/admin/work-schedules",
+        Some(json!({
+            "name": unique_name("Flextime"),
+            "start_time": "09:00:00",
+            "end_time": "18:00:00",
+            "kind": "flextime",
+            "core_start_time": "10:00:00",
+            "core_end_time": "15:00:00"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(schedule["kind"], "flextime");
+    assert_eq!(schedule["settlement_months"], 1);
+
+    let (status, _) = send(
+        &admin_app,
+        "POST",
+        "/api/admin/work-schedule-assignments",
+        Some(json!({
+            "schedule_id": schedule["id"],
+            "user_id": employee.id.to_string(),
+            "effective_from": "2025-03-01"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    // Six hours that leave before the end of core time.
+    let (status, early) = send(
+        &admin_app,
+        "PUT",
+        "/api/admin/attendance",
+        Some(json!({
+            "user_id": employee.id.to_string(),
+            "date": "2025-03-03",
+            "clock_in_time": "2025-03-03T07:00:00",
+            "clock_out_time": "2025-03-03T13:00:00"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(early["status"], "present");
+
+    // A late start is not lateness on flextime, and nine hours is not schedule overtime.
+    let (status, late) = send(
+        &admin_app,
+        "PUT",
+        "/api/admin/attendance",
+        Some(json!({
+            "user_id": employee.id.to_string(),
+            "date": "2025-03-04",
+            "clock_in_time": "2025-03-04T10:00:00",
+            "clock_out_time": "2025-03-04T20:00:00",
+            "breaks": [
+                {
+                    "break_start_time": "2025-03-04T13:00:00",
+                    "break_end_time": "2025-03-04T14:00:00"
+                }
+            ]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(late["status"], "present");
+
+    let employee_app = flextime_router(pool.clone(), employee);
+    let (status, summary) = send(
+        &employee_app,
+        "GET",
+        "/api/attendance/me/summary?year=2025&month=3",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let flextime = &summary["flextime"];
+    assert_eq!(flextime["period_start"], "2025-03-01");
+    assert_eq!(flextime["period_end"], "2025-03-31");
+    assert_eq!(flextime["worked_hours"], 15.0);
+    let required = flextime["required_hours_to_date"]
+        .as_f64()
+        .expect("required hours");
+    assert!(required >= 16.0);
+    assert_eq!(flextime["balance_hours"].as_f64(), Some(15.0 - required));
+    assert_eq!(flextime["core_time_missed_dates"], json!(["2025-03-03"]));
+}
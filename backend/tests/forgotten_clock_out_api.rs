@@ -0,0 +1,280 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Extension, Router,
+};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, Utc};
+use sqlx::PgPool;
+use std::env;
+use timekeeper_backend::{
+    config::Config,
+    handlers::admin,
+    models::{
+        attendance::Attendance,
+        break_record::BreakRecord,
+        user::{User, UserRole},
+        work_schedule::{WorkSchedule, WorkScheduleAssignment},
+    },
+    repositories::{
+        attendance::{AttendanceRepository, AttendanceRepositoryTrait},
+        break_record::BreakRecordRepository,
+        repository::Repository,
+        work_schedule,
+    },
+    services::attendance_closure::ForgottenClockOutSweeper,
+    state::AppState,
+    types::{AttendanceId, UserId, WorkScheduleAssignmentId, WorkScheduleId},
+};
+use tower::ServiceExt;
+use uuid::Uuid;
+
+mod support;
+
+use support::{response_json, seed_user, test_config, test_pool};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn sweep_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/admin/attendance/forgotten-clock-outs",
+            axum::routing::get(admin::list_forgotten_clock_outs),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+fn at(value: &str) -> NaiveDateTime {
+    NaiveDateTime::parse_from_str(value, "%Y-%m-%d %H:%M").expect("valid timestamp")
+}
+
+fn sweep_config(auto_close: bool) -> Config {
+    let mut config = test_config();
+    config.forgotten_clock_out_cutoff_hours = 4;
+    config.forgotten_clock_out_auto_close = auto_close;
+    config.attendance_day_change_hour = 0;
+    config
+}
+
+/// Assigns a 09:00-18:00, every-day schedule to the user.
+async fn assign_day_shift(pool: &PgPool, user_id: UserId) {
+    let now = Utc::now();
+    let schedule = work_schedule::create_schedule(
+        pool,
+        &WorkSchedule {
+            id: WorkScheduleId::new(),
+            name: format!("Sweep shift {}", Uuid::new_v4()),
+            start_time: NaiveTime::from_hms_opt(9, 0, 0).unwrap(),
+            end_time: NaiveTime::from_hms_opt(18, 0, 0).unwrap(),
+            break_minutes: 60,
+            working_weekdays: vec![0, 1, 2, 3, 4, 5, 6],
+            late_grace_minutes: 0,
+            kind: Default::default(),
+            core_start_time: None,
+            core_end_time: None,
+            settlement_months: 1,
+            settlement_start_month: 1,
+            created_at: now,
+            updated_at: now,
+        },
+    )
+    .await
+    .expect("create schedule");
+    work_schedule::create_assignment(
+        pool,
+        &WorkScheduleAssignment {
+            id: WorkScheduleAssignmentId::new(),
+            schedule_id: schedule.id,
+            user_id: Some(user_id),
+            department_id: None,
+            effective_from: NaiveDate::from_ymd_opt(2000, 1, 1).unwrap(),
+            effective_to: None,
+            created_at: now,
+        },
+    )
+    .await
+    .expect("assign schedule");
+}
+
+async fn seed_open_record(pool: &PgPool, user_id: UserId, clock_in: &str) -> Attendance {
+    let clock_in = at(clock_in);
+    let mut attendance = Attendance::new(user_id, clock_in.date(), Utc::now());
+    attendance.clock_in_time = Some(clock_in);
+    attendance.clock_out_time = None;
+    AttendanceRepository::new()
+        .create(pool, &attendance)
+        .await
+        .expect("create attendance")
+}
+
+async fn reload(pool: &PgPool, id: AttendanceId) -> Attendance {
+    AttendanceRepository::new()
+        .find_by_id(pool, id)
+        .await
+        .expect("reload attendance")
+}
+
+async fn sweep_action(pool: &PgPool, id: AttendanceId) -> Option<String> {
+    sqlx::query_scalar("SELECT action FROM attendance_open_record_sweeps WHERE attendance_id = $1")
+        .bind(id)
+        .fetch_optional(pool)
+        .await
+        .expect("read sweep row")
+}
+
+#[tokio::test]
+async fn sweep_auto_closes_at_the_scheduled_end_and_ends_open_breaks() {
+    let _guard = integration_guard().await;
+    env::set_var("SMTP_SKIP_SEND", "true");
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    assign_day_shift(&pool, employee.id).await;
+    let attendance = seed_open_record(&pool, employee.id, "2001-01-10 09:00").await;
+    let open_break = BreakRecordRepository::new()
+        .create(
+            &pool,
+            &BreakRecord::new(attendance.id, at("2001-01-10 17:30"), Utc::now()),
+        )
+        .await
+        .expect("create break");
+
+    let sweeper = ForgottenClockOutSweeper::new(pool.clone());
+    let config = sweep_config(true);
+
+    // 18:00 plus the four-hour cutoff has not passed yet.
+    sweeper
+        .sweep(at("2001-01-10 21:00"), &config)
+        .await
+        .expect("early sweep");
+    assert!(reload(&pool, attendance.id).await.clock_out_time.is_none());
+    assert_eq!(sweep_action(&pool, attendance.id).await, None);
+
+    let summary = sweeper
+        .sweep(at("2001-01-11 00:00"), &config)
+        .await
+        .expect("sweep");
+    assert!(summary.auto_closed >= 1);
+    assert!(summary.breaks_ended >= 1);
+
+    let closed = reload(&pool, attendance.id).await;
+    assert_eq!(closed.clock_out_time, Some(at("2001-01-10 18:00")));
+    assert_eq!(closed.total_work_hours, Some(8.5));
+    assert_eq!(
+        sweep_action(&pool, attendance.id).await.as_deref(),
+        Some("auto_closed")
+    );
+
+    let ended = BreakRecordRepository::new()
+        .find_by_id(&pool, open_break.id)
+        .await
+        .expect("reload break");
+    assert_eq!(ended.break_end_time, Some(at("2001-01-10 18:00")));
+    assert_eq!(ended.duration_minutes, Some(30));
+
+    let notified: bool = sqlx::query_scalar(
+        "SELECT notified FROM attendance_open_record_sweeps WHERE attendance_id = $1",
+    )
+    .bind(attendance.id)
+    .fetch_one(&pool)
+    .await
+    .expect("read notified flag");
+    assert!(notified);
+
+    // A second run leaves the swept record alone.
+    sweeper
+        .sweep(at("2001-01-12 00:00"), &config)
+        .await
+        .expect("repeat sweep");
+    let again = reload(&pool, attendance.id).await;
+    assert_eq!(again.updated_at, closed.updated_at);
+}
+
+#[tokio::test]
+async fn sweep_flags_records_for_correction_when_not_auto_closing() {
+    let _guard = integration_guard().await;
+    env::set_var("SMTP_SKIP_SEND", "true");
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let admin_user = seed_user(&pool, UserRole::Manager, true).await;
+    let scheduled = seed_user(&pool, UserRole::Employee, false).await;
+    assign_day_shift(&pool, scheduled.id).await;
+    let flagged = seed_open_record(&pool, scheduled.id, "2001-02-10 09:00").await;
+    let unscheduled = seed_user(&pool, UserRole::Employee, false).await;
+    let no_schedule = seed_open_record(&pool, unscheduled.id, "2001-02-10 10:00").await;
+
+    let sweeper = ForgottenClockOutSweeper::new(pool.clone());
+    sweeper
+        .sweep(at("2001-02-11 00:00"), &sweep_config(false))
+        .await
+        .expect("sweep");
+    assert!(reload(&pool, flagged.id).await.clock_out_time.is_none());
+    assert_eq!(
+        sweep_action(&pool, flagged.id).await.as_deref(),
+        Some("needs_correction")
+    );
+    // Without a schedule the record waits for the day change plus the cutoff.
+    assert_eq!(sweep_action(&pool, no_schedule.id).await, None);
+
+    // Auto-close needs a scheduled end, so an unscheduled record is only flagged.
+    sweeper
+        .sweep(at("2001-02-11 04:00"), &sweep_config(true))
+        .await
+        .expect("later sweep");
+    assert!(reload(&pool, no_schedule.id).await.clock_out_time.is_none());
+    assert_eq!(
+        sweep_action(&pool, no_schedule.id).await.as_deref(),
+        Some("needs_correction")
+    );
+
+    let app = sweep_router(pool.clone(), admin_user);
+    let request = Request::builder()
+        .method("GET")
+        .uri("/api/admin/attendance/forgotten-clock-outs?unresolved=true&limit=500")
+        .body(Body::empty())
+        .expect("build list request");
+    let response = app.oneshot(request).await.expect("call list");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response_json(response).await;
+    let item = body
+        .as_array()
+        .expect("array")
+        .iter()
+        .find(|item| item["attendance_id"] == flagged.id.to_string())
+        .expect("flagged record listed");
+    assert_eq!(item["action"], "needs_correction");
+    assert_eq!(item["username"], scheduled.username);
+}
+
+#[tokio::test]
+async fn forgotten_clock_out_listing_requires_system_admin() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let manager = seed_user(&pool, UserRole::Manager, false).await;
+    let app = sweep_router(pool, manager);
+    let request = Request::builder()
+        .method("GET")
+        .uri("/api/admin/attendance/forgotten-clock-outs")
+        .body(Body::empty())
+        .expect("build list request");
+    let response = app.oneshot(request).await.expect("call list");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
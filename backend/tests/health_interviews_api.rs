@@ -0,0 +1,181 @@
+use axum::{http::StatusCode, Extension, Router};
+use chrono::NaiveDate;
+use serde_json::json;
+use sqlx::PgPool;
+use timekeeper_backend::{
+    handlers::admin,
+    models::user::{User, UserRole},
+    state::AppState,
+    types::UserId,
+};
+
+mod support;
+
+use support::{seed_department, seed_long_days, seed_user, send, test_config, test_pool};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn health_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/admin/health/long-hours",
+            axum::routing::get(admin::get_long_hours_report),
+        )
+        .route(
+            "/api/admin/health/interviews",
+            axum::routing::get(admin::list_health_interviews),
+        )
+        .route(
+            "/api/admin/health/interviews/{user_id}/{year}/{month}",
+            axum::routing::put(admin::record_health_interview),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+fn date(value: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").expect("valid date")
+}
+
+fn listed(body: &serde_json::Value, user_id: UserId) -> Option<&serde_json::Value> {
+    body.as_array()
+        .expect("array body")
+        .iter()
+        .find(|item| item["user_id"] == user_id.to_string())
+}
+
+#[tokio::test]
+async fn employees_over_eighty_hours_are_listed_and_interviews_tracked() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let over = seed_user(&pool, UserRole::Employee, false).await;
+    let at_threshold = seed_user(&pool, UserRole::Employee, false).await;
+    let manager = seed_user(&pool, UserRole::Manager, false).await;
+    let outsider = seed_user(&pool, UserRole::Manager, false).await;
+    seed_department(&pool, &[over.id, at_threshold.id], manager.id).await;
+
+    // February 2039 has 28 days, i.e. 160 hours at 40 per week: 252 hours leave 92 over,
+    // 240 hours exactly 80.
+    seed_long_days(
+        &pool,
+        over.id,
+        &date("2039-02-01").iter_days().take(21).collect::<Vec<_>>(),
+    )
+    .await;
+    seed_long_days(
+        &pool,
+        at_threshold.id,
+        &date("2039-02-01").iter_days().take(20).collect::<Vec<_>>(),
+    )
+    .await;
+
+    let app = health_router(pool.clone(), manager.clone());
+    let (status, body) = send(
+        &app,
+        "GET",
+        "/api/admin/health/long-hours?year=2039&month=2",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let entry = listed(&body, over.id).expect("employee over the threshold listed");
+    assert_eq!(entry["worked_hours"], 252.0);
+    assert_eq!(entry["overtime_hours"], 92.0);
+    assert!(entry["interview"].is_null());
+    assert!(listed(&body, at_threshold.id).is_none());
+
+    let interview_uri = format!("/api/admin/health/interviews/{}/2039/2", over.id);
+    let (status, offered) = send(
+        &app,
+        "PUT",
+        &interview_uri,
+        Some(json!({ "status": "offered", "notes": "  Offered by email  " })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(offered["status"], "offered");
+    assert_eq!(offered["notes"], "Offered by email");
+    assert_eq!(offered["overtime_hours"], 92.0);
+    assert!(offered["completed_at"].is_null());
+
+    let (status, completed) = send(
+        &app,
+        "PUT",
+        &interview_uri,
+        Some(json!({ "status": "completed" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(completed["status"], "completed");
+    assert_eq!(completed["offered_at"], offered["offered_at"]);
+    assert!(completed["completed_at"].is_string());
+
+    let (_, body) = send(
+        &app,
+        "GET",
+        "/api/admin/health/long-hours?year=2039&month=2",
+        None,
+    )
+    .await;
+    let entry = listed(&body, over.id).expect("still listed");
+    assert_eq!(entry["interview"]["status"], "completed");
+
+    let (status, history) = send(
+        &app,
+        "GET",
+        &format!("/api/admin/health/interviews?user_id={}", over.id),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(history.as_array().expect("history").len(), 1);
+    assert_eq!(history[0]["month"], 2);
+
+    let (status, _) = send(
+        &app,
+        "PUT",
+        &format!("/api/admin/health/interviews/{}/2039/13", over.id),
+        Some(json!({ "status": "offered" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    // Managers of other departments neither see nor record the employee's interviews.
+    let app = health_router(pool.clone(), outsider);
+    let (_, history) = send(
+        &app,
+        "GET",
+        &format!("/api/admin/health/interviews?user_id={}", over.id),
+        None,
+    )
+    .await;
+    assert!(history.as_array().expect("history").is_empty());
+    let (status, _) = send(
+        &app,
+        "PUT",
+        &interview_uri,
+        Some(json!({ "status": "declined" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let app = health_router(pool.clone(), over);
+    let (status, _) = send(&app, "GET", "/api/admin/health/long-hours", None).await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
@@ -0,0 +1,245 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Extension, Router,
+};
+use chrono::NaiveDate;
+use serde_json::json;
+use sqlx::PgPool;
+use timekeeper_backend::{
+    handlers::admin,
+    models::{
+        employment_profile::EmploymentType,
+        leave_balance::{LeaveBalanceEntry, LeaveBalanceEntryType},
+        leave_request::LeaveType,
+        user::{User, UserRole},
+    },
+    repositories::{employment_profile, leave_balance},
+    services::{leave_accrual::LeaveAccrualService, leave_balance::LeaveBalanceService},
+    state::AppState,
+    types::UserId,
+};
+use tower::ServiceExt;
+
+mod support;
+
+use support::{response_json, seed_user, test_config, test_pool};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn accrual_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/admin/users/{id}/employment-profile",
+            axum::routing::get(admin::get_employment_profile).put(admin::upsert_employment_profile),
+        )
+        .route(
+            "/api/admin/leave-accruals/preview",
+            axum::routing::get(admin::preview_leave_accruals),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+fn date(value: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").expect("valid date")
+}
+
+async fn statutory_grants(pool: &PgPool, user_id: UserId) -> Vec<LeaveBalanceEntry> {
+    let mut grants: Vec<LeaveBalanceEntry> = leave_balance::list_entries_for_user(pool, user_id)
+        .await
+        .expect("list entries")
+        .into_iter()
+        .filter(|entry| entry.statutory_grant)
+        .collect();
+    grants.sort_by_key(|entry| entry.effective_date);
+    grants
+}
+
+#[tokio::test]
+async fn accrual_run_books_due_grants_once() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    employment_profile::upsert(
+        &pool,
+        employee.id,
+        date("2028-01-15"),
+        EmploymentType::FullTime,
+        5,
+        40.0,
+        None,
+    )
+    .await
+    .expect("create profile");
+
+    let service = LeaveAccrualService::new(pool.clone());
+    service.run(date("2029-08-01")).await.expect("first run");
+    service.run(date("2029-08-01")).await.expect("second run");
+
+    let grants = statutory_grants(&pool, employee.id).await;
+    let booked: Vec<(NaiveDate, f64, Option<NaiveDate>)> = grants
+        .iter()
+        .map(|grant| (grant.effective_date, grant.days, grant.expires_on))
+        .collect();
+    assert_eq!(
+        booked,
+        vec![
+            (date("2028-07-15"), 10.0, Some(date("2030-07-15"))),
+            (date("2029-07-15"), 11.0, Some(date("2031-07-15"))),
+        ]
+    );
+}
+
+#[tokio::test]
+async fn expired_grant_lapses_only_unused_days() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    employment_profile::upsert(
+        &pool,
+        employee.id,
+        date("2025-01-01"),
+        EmploymentType::FullTime,
+        5,
+        40.0,
+        None,
+    )
+    .await
+    .expect("create profile");
+
+    let service = LeaveAccrualService::new(pool.clone());
+    service.run(date("2025-07-01")).await.expect("first grant");
+    let consumption = LeaveBalanceEntry::new(
+        employee.id,
+        LeaveType::annual(),
+        LeaveBalanceEntryType::Consumption,
+        -4.0,
+        date("2026-03-02"),
+    );
+    leave_balance::insert_entry(&pool, &consumption)
+        .await
+        .expect("book consumption");
+    service.run(date("2026-07-01")).await.expect("second grant");
+    service.run(date("2027-07-01")).await.expect("expiry");
+    service.run(date("2027-07-01")).await.expect("expiry rerun");
+
+    let balance = LeaveBalanceService::new(pool.clone())
+        .balance(employee.id, &LeaveType::annual())
+        .await
+        .expect("balance");
+    // Grants of 10, 11 and 12 days; 4 days used before the first grant lapsed.
+    assert_eq!(balance.granted, 33.0);
+    assert_eq!(balance.used, 4.0);
+    assert_eq!(balance.expired, 6.0);
+    assert_eq!(balance.balance, 23.0);
+}
+
+#[tokio::test]
+async fn preview_lists_due_and_upcoming_grants_for_visible_users() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    let manager = seed_user(&pool, UserRole::Manager, false).await;
+    let admin_app = accrual_router(pool.clone(), system_admin);
+
+    let upsert = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/api/admin/users/{}/employment-profile",
+            employee.id
+        ))
+        .header("Content-Type", "application/json")
+        .body(Body::from(
+            json!({
+                "hire_date": "2032-04-01",
+                "employment_type": "part_time",
+                "weekly_scheduled_days": 3,
+                "weekly_scheduled_hours": 18.0
+            })
+            .to_string(),
+        ))
+        .expect("build upsert request");
+    let response = admin_app.clone().oneshot(upsert).await.expect("upsert");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let preview = Request::builder()
+        .method("GET")
+        .uri("/api/admin/leave-accruals/preview?as_of=2033-10-02")
+        .body(Body::empty())
+        .expect("build preview request");
+    let response = admin_app.oneshot(preview).await.expect("preview");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response_json(response).await;
+    let items: Vec<(String, String, f64)> = body
+        .as_array()
+        .expect("preview array")
+        .iter()
+        .filter(|item| item["user_id"] == employee.id.to_string())
+        .map(|item| {
+            (
+                item["grant_date"].as_str().unwrap_or_default().to_string(),
+                item["status"].as_str().unwrap_or_default().to_string(),
+                item["days"].as_f64().unwrap_or_default(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        items,
+        vec![
+            ("2032-10-01".to_string(), "due".to_string(), 5.0),
+            ("2033-10-01".to_string(), "due".to_string(), 6.0),
+            ("2034-10-01".to_string(), "upcoming".to_string(), 6.0),
+        ]
+    );
+
+    let manager_app = accrual_router(pool.clone(), manager);
+    let preview = Request::builder()
+        .method("GET")
+        .uri("/api/admin/leave-accruals/preview?as_of=2033-10-02")
+        .body(Body::empty())
+        .expect("build preview request");
+    let response = manager_app.clone().oneshot(preview).await.expect("preview");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response_json(response).await;
+    assert!(body
+        .as_array()
+        .expect("preview array")
+        .iter()
+        .all(|item| item["user_id"] != employee.id.to_string()));
+
+    let upsert = Request::builder()
+        .method("PUT")
+        .uri(format!(
+            "/api/admin/users/{}/employment-profile",
+            employee.id
+        ))
+        .header("Content-Type", "application/json")
+        .body(Body::from(
+            json!({"hire_date": "2032-04-01", "employment_type": "full_time"}).to_string(),
+        ))
+        .expect("build upsert request");
+    let response = manager_app.oneshot(upsert).await.expect("upsert");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
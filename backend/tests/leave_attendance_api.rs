@@ -0,0 +1,321 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Extension, Router,
+};
+use chrono::NaiveDate;
+use serde_json::json;
+use sqlx::PgPool;
+use std::str::FromStr;
+use timekeeper_backend::{
+    handlers::{admin, attendance, requests},
+    models::user::{User, UserRole},
+    repositories::leave_attendance,
+    state::AppState,
+    types::{LeaveRequestId, UserId},
+};
+use tower::ServiceExt;
+
+mod support;
+
+use support::{
+    response_json, seed_holiday_exception, seed_leave_grant, seed_user, test_config, test_pool,
+};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn leave_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/requests/leave",
+            axum::routing::post(requests::create_leave_request),
+        )
+        .route(
+            "/api/requests/{id}",
+            axum::routing::delete(requests::cancel_request),
+        )
+        .route(
+            "/api/admin/requests/{id}/approve",
+            axum::routing::put(admin::approve_request),
+        )
+        .route(
+            "/api/admin/attendance",
+            axum::routing::put(admin::upsert_attendance),
+        )
+        .route(
+            "/api/attendance/me/summary",
+            axum::routing::get(attendance::get_my_summary),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+fn date(value: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").expect("valid date")
+}
+
+async fn leave_rows(pool: &PgPool, user_id: UserId) -> Vec<(NaiveDate, String, bool)> {
+    sqlx::query_as::<_, (NaiveDate, String, bool)>(
+        "SELECT date, status, leave_request_id IS NOT NULL FROM attendance \
+         WHERE user_id = $1 AND date BETWEEN '2033-05-01' AND '2033-05-31' \
+         ORDER BY date",
+    )
+    .bind(user_id)
+    .fetch_all(pool)
+    .await
+    .expect("load attendance")
+}
+
+async fn day_record(
+    pool: &PgPool,
+    user_id: UserId,
+    day: NaiveDate,
+) -> Option<(String, Option<String>)> {
+    sqlx::query_as::<_, (String, Option<String>)>(
+        "SELECT status, leave_request_id FROM attendance WHERE user_id = $1 AND date = $2",
+    )
+    .bind(user_id)
+    .bind(day)
+    .fetch_optional(pool)
+    .await
+    .expect("load attendance")
+}
+
+async fn submit_leave(app: &Router, payload: serde_json::Value) -> String {
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/requests/leave")
+        .header("Content-Type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .expect("build leave request");
+    let response = app.clone().oneshot(request).await.expect("call create");
+    assert_eq!(response.status(), StatusCode::OK);
+    response_json(response).await["id"]
+        .as_str()
+        .expect("request id")
+        .to_string()
+}
+
+async fn approve(app: &Router, request_id: &str) {
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/api/admin/requests/{}/approve", request_id))
+        .header("Content-Type", "application/json")
+        .body(Body::from(json!({"comment": "ok"}).to_string()))
+        .expect("build approve request");
+    let response = app.clone().oneshot(request).await.expect("approve");
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+async fn cancel(app: &Router, request_id: &str) {
+    let request = Request::builder()
+        .method("DELETE")
+        .uri(format!("/api/requests/{}", request_id))
+        .body(Body::empty())
+        .expect("build cancel request");
+    let response = app.clone().oneshot(request).await.expect("call cancel");
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+async fn may_summary(app: &Router) -> serde_json::Value {
+    let request = Request::builder()
+        .uri("/api/attendance/me/summary?year=2033&month=5")
+        .body(Body::empty())
+        .expect("build summary request");
+    let response = app.clone().oneshot(request).await.expect("call summary");
+    assert_eq!(response.status(), StatusCode::OK);
+    response_json(response).await
+}
+
+#[tokio::test]
+async fn approved_leave_is_recorded_in_attendance_until_cancelled() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    for day in ["2033-05-10", "2033-05-11", "2033-05-12"] {
+        seed_holiday_exception(&pool, employee.id, date(day), false, "workday").await;
+    }
+    seed_leave_grant(&pool, employee.id, 5.0).await;
+    let employee_app = leave_router(pool.clone(), employee.clone());
+    let admin_app = leave_router(pool.clone(), admin);
+
+    let request_id = submit_leave(
+        &employee_app,
+        json!({
+            "leave_type": "annual",
+            "start_date": "2033-05-10",
+            "end_date": "2033-05-12",
+        }),
+    )
+    .await;
+    approve(&admin_app, &request_id).await;
+
+    let rows = leave_rows(&pool, employee.id).await;
+    assert_eq!(
+        rows,
+        ["2033-05-10", "2033-05-11", "2033-05-12"]
+            .into_iter()
+            .map(|day| (date(day), "leave".to_string(), true))
+            .collect::<Vec<_>>()
+    );
+
+    let summary = may_summary(&employee_app).await;
+    assert_eq!(summary["leave_days"], 3.0);
+    assert_eq!(summary["paid_leave_hours"], 24.0);
+    assert_eq!(summary["total_work_days"], 0);
+    assert_eq!(summary["absent_days"], 0);
+
+    cancel(&employee_app, &request_id).await;
+
+    assert!(leave_rows(&pool, employee.id).await.is_empty());
+    let summary = may_summary(&employee_app).await;
+    assert_eq!(summary["leave_days"], 0.0);
+    assert_eq!(summary["paid_leave_hours"], 0.0);
+}
+
+#[tokio::test]
+async fn overlapping_half_days_keep_their_day_until_both_are_cancelled() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let day = date("2033-06-07");
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    seed_holiday_exception(&pool, employee.id, day, false, "workday").await;
+    let employee_app = leave_router(pool.clone(), employee.clone());
+    let admin_app = leave_router(pool.clone(), admin);
+
+    let mut ids = Vec::new();
+    for unit in ["half_am", "half_pm"] {
+        let id = submit_leave(
+            &employee_app,
+            json!({
+                "leave_type": "personal",
+                "start_date": "2033-06-07",
+                "end_date": "2033-06-07",
+                "unit": unit,
+            }),
+        )
+        .await;
+        approve(&admin_app, &id).await;
+        ids.push(id);
+    }
+    assert_eq!(
+        day_record(&pool, employee.id, day).await,
+        Some(("leave".to_string(), Some(ids[0].clone())))
+    );
+
+    cancel(&employee_app, &ids[0]).await;
+    assert_eq!(
+        day_record(&pool, employee.id, day).await,
+        Some(("leave".to_string(), Some(ids[1].clone())))
+    );
+
+    cancel(&employee_app, &ids[1]).await;
+    assert_eq!(day_record(&pool, employee.id, day).await, None);
+}
+
+#[tokio::test]
+async fn released_past_leave_days_become_absences_again() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let day = date("2033-07-05");
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    seed_holiday_exception(&pool, employee.id, day, false, "workday").await;
+    let employee_app = leave_router(pool.clone(), employee.clone());
+    let admin_app = leave_router(pool.clone(), admin);
+
+    let id = submit_leave(
+        &employee_app,
+        json!({
+            "leave_type": "personal",
+            "start_date": "2033-07-05",
+            "end_date": "2033-07-05",
+        }),
+    )
+    .await;
+    approve(&admin_app, &id).await;
+    let request_id = LeaveRequestId::from_str(&id).expect("leave request id");
+
+    // Released once the day has passed, the day is a detected absence again.
+    let mut conn = pool.acquire().await.expect("acquire connection");
+    let removed = leave_attendance::release_leave_days(&mut conn, request_id, date("2033-07-06"))
+        .await
+        .expect("release leave days");
+    assert_eq!(removed, 0);
+    drop(conn);
+    assert_eq!(
+        day_record(&pool, employee.id, day).await,
+        Some(("absent".to_string(), None))
+    );
+}
+
+#[tokio::test]
+async fn admin_correction_keeps_the_leave_link() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let day = date("2033-08-09");
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    seed_holiday_exception(&pool, employee.id, day, false, "workday").await;
+    let employee_app = leave_router(pool.clone(), employee.clone());
+    let admin_app = leave_router(pool.clone(), admin);
+
+    let id = submit_leave(
+        &employee_app,
+        json!({
+            "leave_type": "personal",
+            "start_date": "2033-08-09",
+            "end_date": "2033-08-09",
+            "unit": "half_pm",
+        }),
+    )
+    .await;
+    approve(&admin_app, &id).await;
+
+    let request = Request::builder()
+        .method("PUT")
+        .uri("/api/admin/attendance")
+        .header("Content-Type", "application/json")
+        .body(Body::from(
+            json!({
+                "user_id": employee.id.to_string(),
+                "date": "2033-08-09",
+                "clock_in_time": "2033-08-09T09:00:00",
+                "clock_out_time": "2033-08-09T12:00:00",
+            })
+            .to_string(),
+        ))
+        .expect("build upsert request");
+    let response = admin_app.oneshot(request).await.expect("call upsert");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let (status, link) = day_record(&pool, employee.id, day)
+        .await
+        .expect("attendance record");
+    assert_ne!(status, "leave");
+    assert_eq!(link, Some(id));
+}
@@ -0,0 +1,465 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Extension, Router,
+};
+use chrono::NaiveDate;
+use serde_json::json;
+use sqlx::PgPool;
+use timekeeper_backend::{
+    handlers::{admin, leave_balances, requests},
+    models::{
+        employment_profile::EmploymentType,
+        user::{User, UserRole},
+    },
+    repositories::employment_profile,
+    state::AppState,
+    types::UserId,
+};
+use tower::ServiceExt;
+
+mod support;
+
+use support::{
+    response_json, seed_holiday_exception, seed_leave_grant, seed_user, test_config, test_pool,
+};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn leave_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/requests/leave",
+            axum::routing::post(requests::create_leave_request),
+        )
+        .route(
+            "/api/requests/{id}",
+            axum::routing::delete(requests::cancel_request),
+        )
+        .route(
+            "/api/leave-balances/me",
+            axum::routing::get(leave_balances::get_my_leave_balances),
+        )
+        .route(
+            "/api/admin/requests/{id}/approve",
+            axum::routing::put(admin::approve_request),
+        )
+        .route(
+            "/api/admin/users/{id}/leave-balances",
+            axum::routing::get(admin::get_user_leave_balances)
+                .post(admin::create_leave_balance_entry),
+        )
+        .route(
+            "/api/admin/attendance",
+            axum::routing::put(admin::upsert_attendance),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+fn date(value: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").expect("valid date")
+}
+
+/// Pins the given days as working days so shared holiday fixtures cannot change day counts.
+async fn seed_workdays(pool: &PgPool, user_id: UserId, days: &[&str]) {
+    for day in days {
+        seed_holiday_exception(pool, user_id, date(day), false, "workday").await;
+    }
+}
+
+async fn submit_leave(app: &Router, start: &str, end: &str) -> axum::response::Response {
+    let payload = json!({
+        "leave_type": "annual",
+        "start_date": start,
+        "end_date": end,
+    });
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/requests/leave")
+        .header("Content-Type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .expect("build leave request");
+    app.clone().oneshot(request).await.expect("call create")
+}
+
+async fn submit_partial_leave(
+    app: &Router,
+    day: &str,
+    unit: &str,
+    hours: Option<f64>,
+) -> axum::response::Response {
+    let payload = json!({
+        "leave_type": "annual",
+        "start_date": day,
+        "end_date": day,
+        "unit": unit,
+        "hours": hours,
+    });
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/requests/leave")
+        .header("Content-Type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .expect("build partial leave request");
+    app.clone().oneshot(request).await.expect("call create")
+}
+
+async fn approve(app: &Router, request_id: &str) {
+    let request = Request::builder()
+        .method("PUT")
+        .uri(format!("/api/admin/requests/{}/approve", request_id))
+        .header("Content-Type", "application/json")
+        .body(Body::from(json!({"comment": "ok"}).to_string()))
+        .expect("build approve request");
+    let response = app.clone().oneshot(request).await.expect("approve");
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+async fn my_annual_balance(app: &Router) -> serde_json::Value {
+    let request = Request::builder()
+        .method("GET")
+        .uri("/api/leave-balances/me")
+        .body(Body::empty())
+        .expect("build balance request");
+    let response = app.clone().oneshot(request).await.expect("call balance");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response_json(response).await;
+    body.as_array()
+        .and_then(|items| items.iter().find(|item| item["leave_type"] == "annual"))
+        .cloned()
+        .expect("annual balance present")
+}
+
+#[tokio::test]
+async fn create_leave_request_rejects_overdraw() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    seed_workdays(
+        &pool,
+        employee.id,
+        &["2031-03-03", "2031-03-04", "2031-03-05"],
+    )
+    .await;
+    seed_leave_grant(&pool, employee.id, 2.0).await;
+    let app = leave_router(pool.clone(), employee.clone());
+
+    let response = submit_leave(&app, "2031-03-03", "2031-03-05").await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = response_json(response).await;
+    assert_eq!(body["code"], "INSUFFICIENT_LEAVE_BALANCE");
+
+    let response = submit_leave(&app, "2031-03-03", "2031-03-04").await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let balance = my_annual_balance(&app).await;
+    assert_eq!(balance["balance"], 2.0);
+    assert_eq!(balance["pending"], 2.0);
+    assert_eq!(balance["available"], 0.0);
+
+    let response = submit_leave(&app, "2031-03-05", "2031-03-05").await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn approval_consumes_balance_and_cancellation_reverses_it() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    seed_workdays(&pool, employee.id, &["2031-04-07", "2031-04-08"]).await;
+    seed_leave_grant(&pool, employee.id, 5.0).await;
+    let employee_app = leave_router(pool.clone(), employee.clone());
+    let admin_app = leave_router(pool.clone(), admin.clone());
+
+    let response = submit_leave(&employee_app, "2031-04-07", "2031-04-08").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let request_id = response_json(response).await["id"]
+        .as_str()
+        .expect("request id")
+        .to_string();
+
+    let approve = Request::builder()
+        .method("PUT")
+        .uri(format!("/api/admin/requests/{}/approve", request_id))
+        .header("Content-Type", "application/json")
+        .body(Body::from(json!({"comment": "ok"}).to_string()))
+        .expect("build approve request");
+    let response = admin_app.clone().oneshot(approve).await.expect("approve");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let balance = my_annual_balance(&employee_app).await;
+    assert_eq!(balance["used"], 2.0);
+    assert_eq!(balance["balance"], 3.0);
+    assert_eq!(balance["pending"], 0.0);
+
+    let cancel = Request::builder()
+        .method("DELETE")
+        .uri(format!("/api/requests/{}", request_id))
+        .body(Body::empty())
+        .expect("build cancel request");
+    let response = employee_app
+        .clone()
+        .oneshot(cancel)
+        .await
+        .expect("cancel approved leave");
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let balance = my_annual_balance(&employee_app).await;
+    assert_eq!(balance["used"], 0.0);
+    assert_eq!(balance["balance"], 5.0);
+
+    let detail = Request::builder()
+        .method("GET")
+        .uri(format!("/api/admin/users/{}/leave-balances", employee.id))
+        .body(Body::empty())
+        .expect("build admin view request");
+    let response = admin_app.oneshot(detail).await.expect("admin view");
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response_json(response).await;
+    let entry_types: Vec<&str> = body["entries"]
+        .as_array()
+        .expect("entries array")
+        .iter()
+        .filter_map(|entry| entry["entry_type"].as_str())
+        .collect();
+    assert!(entry_types.contains(&"grant"));
+    assert!(entry_types.contains(&"consumption"));
+    assert!(entry_types.contains(&"reversal"));
+}
+
+#[tokio::test]
+async fn approval_rejects_request_exceeding_balance() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    seed_workdays(&pool, employee.id, &["2031-05-06"]).await;
+    seed_leave_grant(&pool, employee.id, 1.0).await;
+    let employee_app = leave_router(pool.clone(), employee.clone());
+    let admin_app = leave_router(pool.clone(), admin.clone());
+
+    let response = submit_leave(&employee_app, "2031-05-06", "2031-05-06").await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let request_id = response_json(response).await["id"]
+        .as_str()
+        .expect("request id")
+        .to_string();
+
+    // HR corrects the balance after submission, leaving nothing to consume.
+    let adjust = Request::builder()
+        .method("POST")
+        .uri(format!("/api/admin/users/{}/leave-balances", employee.id))
+        .header("Content-Type", "application/json")
+        .body(Body::from(
+            json!({
+                "leave_type": "annual",
+                "entry_type": "adjustment",
+                "days": -1.0,
+                "note": "granted in error"
+            })
+            .to_string(),
+        ))
+        .expect("build adjustment request");
+    let response = admin_app.clone().oneshot(adjust).await.expect("adjust");
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let approve = Request::builder()
+        .method("PUT")
+        .uri(format!("/api/admin/requests/{}/approve", request_id))
+        .header("Content-Type", "application/json")
+        .body(Body::from(json!({"comment": "ok"}).to_string()))
+        .expect("build approve request");
+    let response = admin_app.oneshot(approve).await.expect("approve");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let body = response_json(response).await;
+    assert_eq!(body["code"], "INSUFFICIENT_LEAVE_BALANCE");
+}
+
+#[tokio::test]
+async fn manual_entries_require_system_admin() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let manager = seed_user(&pool, UserRole::Manager, false).await;
+    let app = leave_router(pool.clone(), manager);
+
+    let grant = Request::builder()
+        .method("POST")
+        .uri(format!("/api/admin/users/{}/leave-balances", employee.id))
+        .header("Content-Type", "application/json")
+        .body(Body::from(
+            json!({"leave_type": "annual", "entry_type": "grant", "days": 10.0}).to_string(),
+        ))
+        .expect("build grant request");
+    let response = app.oneshot(grant).await.expect("grant");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn partial_units_consume_a_share_of_the_scheduled_day() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    // Four six-hour days a week.
+    employment_profile::upsert(
+        &pool,
+        employee.id,
+        date("2030-04-01"),
+        EmploymentType::PartTime,
+        4,
+        24.0,
+        None,
+    )
+    .await
+    .expect("upsert profile");
+    seed_workdays(
+        &pool,
+        employee.id,
+        &["2031-05-06", "2031-05-07", "2031-05-08"],
+    )
+    .await;
+    seed_leave_grant(&pool, employee.id, 3.0).await;
+    let employee_app = leave_router(pool.clone(), employee.clone());
+    let admin_app = leave_router(pool.clone(), admin.clone());
+
+    let response = submit_partial_leave(&employee_app, "2031-05-06", "half_am", None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response_json(response).await;
+    assert_eq!(body["unit"], "half_am");
+
+    let response = submit_partial_leave(&employee_app, "2031-05-07", "hours", Some(3.0)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let hourly_id = response_json(response).await["id"]
+        .as_str()
+        .expect("request id")
+        .to_string();
+
+    let balance = my_annual_balance(&employee_app).await;
+    assert_eq!(balance["pending"], 1.0);
+
+    let response = submit_partial_leave(&employee_app, "2031-05-08", "hours", Some(6.0)).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    let response = submit_partial_leave(&employee_app, "2031-05-08", "hours", None).await;
+    assert_eq!(response_json(response).await["code"], "VALIDATION_ERROR");
+
+    let payload = json!({
+        "leave_type": "annual",
+        "start_date": "2031-05-07",
+        "end_date": "2031-05-08",
+        "unit": "half_pm",
+    });
+    let request = Request::builder()
+        .method("POST")
+        .uri("/api/requests/leave")
+        .header("Content-Type", "application/json")
+        .body(Body::from(payload.to_string()))
+        .expect("build multi-day half request");
+    let response = employee_app.clone().oneshot(request).await.expect("call");
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    approve(&admin_app, &hourly_id).await;
+    let balance = my_annual_balance(&employee_app).await;
+    assert_eq!(balance["used"], 0.5);
+    assert_eq!(balance["balance"], 2.5);
+}
+
+#[tokio::test]
+async fn hourly_leave_is_capped_at_five_days_per_leave_year() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    seed_leave_grant(&pool, employee.id, 10.0).await;
+    let app = leave_router(pool.clone(), employee.clone());
+
+    for day in [
+        "2031-06-02",
+        "2031-06-03",
+        "2031-06-04",
+        "2031-06-05",
+        "2031-06-06",
+    ] {
+        let response = submit_partial_leave(&app, day, "hours", Some(7.0)).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+
+    let response = submit_partial_leave(&app, "2031-06-09", "hours", Some(6.0)).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response_json(response).await["code"],
+        "HOURLY_LEAVE_LIMIT_EXCEEDED"
+    );
+
+    let response = submit_partial_leave(&app, "2031-06-09", "hours", Some(5.0)).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn approved_half_day_reduces_expected_attendance_hours() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    seed_workdays(&pool, employee.id, &["2031-07-07", "2031-07-08"]).await;
+    seed_leave_grant(&pool, employee.id, 5.0).await;
+    let employee_app = leave_router(pool.clone(), employee.clone());
+    let admin_app = leave_router(pool.clone(), admin.clone());
+
+    let response = submit_partial_leave(&employee_app, "2031-07-07", "half_am", None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let request_id = response_json(response).await["id"]
+        .as_str()
+        .expect("request id")
+        .to_string();
+    approve(&admin_app, &request_id).await;
+
+    for (day, expected_status) in [("2031-07-07", "present"), ("2031-07-08", "half_day")] {
+        let payload = json!({
+            "user_id": employee.id.to_string(),
+            "date": day,
+            "clock_in_time": format!("{day}T13:00:00"),
+            "clock_out_time": format!("{day}T16:30:00"),
+        });
+        let request = Request::builder()
+            .method("PUT")
+            .uri("/api/admin/attendance")
+            .header("Content-Type", "application/json")
+            .body(Body::from(payload.to_string()))
+            .expect("build attendance upsert");
+        let response = admin_app.clone().oneshot(request).await.expect("upsert");
+        assert_eq!(response.status(), StatusCode::OK);
+        let body = response_json(response).await;
+        assert_eq!(body["total_work_hours"], 3.5);
+        assert_eq!(body["status"], expected_status, "status on {day}");
+    }
+}
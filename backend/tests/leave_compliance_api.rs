@@ -0,0 +1,280 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Extension, Router,
+};
+use chrono::NaiveDate;
+use sqlx::PgPool;
+use std::env;
+use timekeeper_backend::{
+    handlers::admin,
+    models::{
+        leave_balance::{LeaveBalanceEntry, LeaveBalanceEntryType},
+        leave_request::LeaveType,
+        user::{User, UserRole},
+    },
+    repositories::leave_balance,
+    services::leave_compliance::LeaveComplianceService,
+    state::AppState,
+    types::{LeaveBalanceEntryId, UserId},
+};
+use tower::ServiceExt;
+
+mod support;
+
+use support::{response_json, seed_leave_request, seed_user, test_config, test_pool};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn compliance_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/admin/compliance/annual-leave",
+            axum::routing::get(admin::get_annual_leave_compliance),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+fn date(value: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").expect("valid date")
+}
+
+async fn book(
+    pool: &PgPool,
+    user_id: UserId,
+    entry_type: LeaveBalanceEntryType,
+    days: f64,
+    on: &str,
+) -> LeaveBalanceEntryId {
+    let entry = LeaveBalanceEntry::new(user_id, LeaveType::annual(), entry_type, days, date(on));
+    leave_balance::insert_entry(pool, &entry)
+        .await
+        .expect("insert ledger entry")
+        .id
+}
+
+async fn report_for(app: &Router, as_of: &str, user_id: UserId) -> Vec<serde_json::Value> {
+    let request = Request::builder()
+        .method("GET")
+        .uri(format!("/api/admin/compliance/annual-leave?as_of={as_of}"))
+        .body(Body::empty())
+        .expect("build report request");
+    let response = app.clone().oneshot(request).await.expect("call report");
+    assert_eq!(response.status(), StatusCode::OK);
+    response_json(response)
+        .await
+        .as_array()
+        .expect("report array")
+        .iter()
+        .filter(|item| item["user_id"] == user_id.to_string())
+        .cloned()
+        .collect()
+}
+
+#[tokio::test]
+async fn report_tracks_taken_and_scheduled_days_against_deadline() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let small_grant = seed_user(&pool, UserRole::Employee, false).await;
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    book(
+        &pool,
+        employee.id,
+        LeaveBalanceEntryType::Grant,
+        10.0,
+        "2030-04-01",
+    )
+    .await;
+    book(
+        &pool,
+        employee.id,
+        LeaveBalanceEntryType::Consumption,
+        -2.0,
+        "2030-05-10",
+    )
+    .await;
+    book(
+        &pool,
+        employee.id,
+        LeaveBalanceEntryType::Consumption,
+        -1.0,
+        "2031-02-01",
+    )
+    .await;
+    book(
+        &pool,
+        small_grant.id,
+        LeaveBalanceEntryType::Grant,
+        7.0,
+        "2030-04-01",
+    )
+    .await;
+    let app = compliance_router(pool.clone(), system_admin);
+
+    let items = report_for(&app, "2031-01-15", employee.id).await;
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["deadline"], "2031-03-31");
+    assert_eq!(items[0]["days_taken"], 2.0);
+    assert_eq!(items[0]["days_scheduled"], 1.0);
+    assert_eq!(items[0]["days_remaining"], 2.0);
+    assert_eq!(items[0]["status"], "on_track");
+
+    let items = report_for(&app, "2031-02-15", employee.id).await;
+    assert_eq!(items[0]["days_taken"], 3.0);
+    assert_eq!(items[0]["days_scheduled"], 0.0);
+    assert_eq!(items[0]["status"], "due_soon");
+
+    assert!(report_for(&app, "2031-01-15", small_grant.id)
+        .await
+        .is_empty());
+    assert!(report_for(&app, "2031-04-01", employee.id).await.is_empty());
+}
+
+#[tokio::test]
+async fn hourly_leave_does_not_count_toward_mandatory_days() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    book(
+        &pool,
+        employee.id,
+        LeaveBalanceEntryType::Grant,
+        10.0,
+        "2030-04-01",
+    )
+    .await;
+    let hourly = seed_leave_request(
+        &pool,
+        employee.id,
+        LeaveType::annual(),
+        date("2030-06-03"),
+        date("2030-06-03"),
+    )
+    .await;
+    sqlx::query(
+        "UPDATE leave_requests SET status = 'approved', unit = 'hours', hours = 4 WHERE id = $1",
+    )
+    .bind(hourly.id)
+    .execute(&pool)
+    .await
+    .expect("approve hourly leave");
+    let mut entry = LeaveBalanceEntry::new(
+        employee.id,
+        LeaveType::annual(),
+        LeaveBalanceEntryType::Consumption,
+        -0.5,
+        date("2030-06-03"),
+    );
+    entry.leave_request_id = Some(hourly.id);
+    leave_balance::insert_entry(&pool, &entry)
+        .await
+        .expect("insert hourly consumption");
+    book(
+        &pool,
+        employee.id,
+        LeaveBalanceEntryType::Consumption,
+        -1.0,
+        "2030-06-10",
+    )
+    .await;
+    let app = compliance_router(pool.clone(), system_admin);
+
+    let items = report_for(&app, "2031-01-15", employee.id).await;
+    assert_eq!(items.len(), 1);
+    assert_eq!(items[0]["days_taken"], 1.0);
+    assert_eq!(items[0]["days_remaining"], 4.0);
+}
+
+#[tokio::test]
+async fn report_is_scoped_to_manager_subordinates() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let manager = seed_user(&pool, UserRole::Manager, false).await;
+    let other = seed_user(&pool, UserRole::Employee, false).await;
+    book(
+        &pool,
+        employee.id,
+        LeaveBalanceEntryType::Grant,
+        10.0,
+        "2030-06-01",
+    )
+    .await;
+
+    let app = compliance_router(pool.clone(), manager);
+    assert!(report_for(&app, "2030-07-01", employee.id).await.is_empty());
+
+    let app = compliance_router(pool.clone(), other);
+    let request = Request::builder()
+        .method("GET")
+        .uri("/api/admin/compliance/annual-leave")
+        .body(Body::empty())
+        .expect("build report request");
+    let response = app.oneshot(request).await.expect("call report");
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn deadline_reminders_are_sent_once_per_threshold() {
+    let _guard = integration_guard().await;
+    env::set_var("SMTP_SKIP_SEND", "true");
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let grant_id = book(
+        &pool,
+        employee.id,
+        LeaveBalanceEntryType::Grant,
+        10.0,
+        "2035-01-01",
+    )
+    .await;
+
+    let service = LeaveComplianceService::new(pool.clone());
+    let config = test_config();
+    // 91 days before the 2035-12-31 deadline: no threshold reached yet.
+    service
+        .send_deadline_reminders(date("2035-10-01"), &config)
+        .await
+        .expect("early run");
+    for as_of in ["2035-11-15", "2035-11-20", "2035-12-26"] {
+        service
+            .send_deadline_reminders(date(as_of), &config)
+            .await
+            .expect("reminder run");
+    }
+
+    let thresholds: Vec<(i32,)> = sqlx::query_as(
+        "SELECT days_before_deadline FROM leave_compliance_notifications \
+         WHERE grant_entry_id = $1 ORDER BY days_before_deadline DESC",
+    )
+    .bind(grant_id)
+    .fetch_all(&pool)
+    .await
+    .expect("load notifications");
+    assert_eq!(thresholds, vec![(60,), (7,)]);
+}
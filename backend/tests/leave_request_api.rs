@@ -14,7 +14,7 @@ use tower::ServiceExt;
 
 mod support;
 
-use support::{create_test_token, seed_user, test_config, test_pool};
+use support::{create_test_token, seed_leave_grant, seed_user, test_config, test_pool};
 
 async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
     static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
@@ -53,6 +53,7 @@ async fn test_create_leave_request_succeeds() {
         .expect("run migrations");
 
     let employee = seed_user(&pool, UserRole::Employee, false).await;
+    seed_leave_grant(&pool, employee.id, 20.0).await;
 
     let token = create_test_token(employee.id, employee.role.clone());
     let app = test_router_with_state(pool.clone(), employee.clone());
@@ -148,6 +149,7 @@ async fn test_get_my_requests_returns_list() {
         .expect("run migrations");
 
     let employee = seed_user(&pool, UserRole::Employee, false).await;
+    seed_leave_grant(&pool, employee.id, 20.0).await;
 
     let token = create_test_token(employee.id, employee.role.clone());
     let app = test_router_with_state(pool.clone(), employee.clone());
@@ -192,6 +194,7 @@ async fn test_cancel_leave_request_succeeds() {
         .expect("run migrations");
 
     let employee = seed_user(&pool, UserRole::Employee, false).await;
+    seed_leave_grant(&pool, employee.id, 20.0).await;
 
     let token = create_test_token(employee.id, employee.role.clone());
     let app = test_router_with_state(pool.clone(), employee.clone());
@@ -0,0 +1,318 @@
+use axum::{http::StatusCode, Extension, Router};
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use timekeeper_backend::{
+    handlers::{admin, leave_balances, leave_types, requests},
+    models::user::{User, UserRole},
+    state::AppState,
+};
+
+mod support;
+
+use support::{
+    response_json, seed_holiday_exception, seed_user, send_request, test_config, test_pool,
+};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn leave_type_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/leave-types",
+            axum::routing::get(leave_types::list_active_leave_types),
+        )
+        .route(
+            "/api/admin/leave-types",
+            axum::routing::get(admin::list_leave_types).post(admin::create_leave_type),
+        )
+        .route(
+            "/api/admin/leave-types/{code}",
+            axum::routing::put(admin::update_leave_type).delete(admin::delete_leave_type),
+        )
+        .route(
+            "/api/requests/leave",
+            axum::routing::post(requests::create_leave_request),
+        )
+        .route(
+            "/api/leave-balances/me",
+            axum::routing::get(leave_balances::get_my_leave_balances),
+        )
+        .route(
+            "/api/admin/users/{id}/leave-balances",
+            axum::routing::post(admin::create_leave_balance_entry),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+/// Leave type codes are global, so each test run gets its own.
+fn unique_code(prefix: &str) -> String {
+    format!(
+        "{prefix}_{}",
+        &uuid::Uuid::new_v4().simple().to_string()[..8]
+    )
+}
+
+fn codes(body: &Value) -> Vec<String> {
+    body.as_array()
+        .expect("leave type array")
+        .iter()
+        .filter_map(|item| item["code"].as_str().map(str::to_string))
+        .collect()
+}
+
+#[tokio::test]
+async fn migration_seeds_the_existing_leave_types() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let app = leave_type_router(pool.clone(), employee);
+
+    let response = send_request(&app, "GET", "/api/leave-types", None).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response_json(response).await;
+    let codes = codes(&body);
+    for code in ["annual", "sick", "personal", "other"] {
+        assert!(codes.contains(&code.to_string()), "missing {code}");
+    }
+    let annual = body
+        .as_array()
+        .and_then(|items| items.iter().find(|item| item["code"] == "annual"))
+        .expect("annual present");
+    assert_eq!(annual["paid"], true);
+    assert_eq!(annual["balance_tracked"], true);
+}
+
+#[tokio::test]
+async fn system_admin_manages_leave_types() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let admin_app = leave_type_router(pool.clone(), admin);
+    let employee_app = leave_type_router(pool.clone(), employee);
+    let code = unique_code("care");
+
+    let payload = json!({
+        "code": code,
+        "name": "Family care leave",
+        "paid": false,
+        "sort_order": 50
+    });
+    let response = send_request(
+        &employee_app,
+        "POST",
+        "/api/admin/leave-types",
+        Some(payload.clone()),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = send_request(
+        &admin_app,
+        "POST",
+        "/api/admin/leave-types",
+        Some(payload.clone()),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let created = response_json(response).await;
+    assert_eq!(created["code"], code.as_str());
+    assert_eq!(created["paid"], false);
+    assert_eq!(created["balance_tracked"], false);
+    assert_eq!(created["is_active"], true);
+
+    let response = send_request(&admin_app, "POST", "/api/admin/leave-types", Some(payload)).await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = send_request(&employee_app, "GET", "/api/leave-types", None).await;
+    assert!(codes(&response_json(response).await).contains(&code));
+
+    let response = send_request(
+        &admin_app,
+        "PUT",
+        &format!("/api/admin/leave-types/{code}"),
+        Some(json!({"name": "Care leave", "is_active": false})),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let updated = response_json(response).await;
+    assert_eq!(updated["name"], "Care leave");
+    assert_eq!(updated["is_active"], false);
+    assert_eq!(updated["paid"], false);
+
+    let response = send_request(&employee_app, "GET", "/api/leave-types", None).await;
+    assert!(!codes(&response_json(response).await).contains(&code));
+    let response = send_request(&admin_app, "GET", "/api/admin/leave-types", None).await;
+    assert!(codes(&response_json(response).await).contains(&code));
+
+    let response = send_request(
+        &admin_app,
+        "DELETE",
+        &format!("/api/admin/leave-types/{code}"),
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = send_request(
+        &admin_app,
+        "DELETE",
+        &format!("/api/admin/leave-types/{code}"),
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::NOT_FOUND);
+}
+
+#[tokio::test]
+async fn leave_type_rules_are_enforced() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    let app = leave_type_router(pool.clone(), admin);
+
+    let response = send_request(
+        &app,
+        "POST",
+        "/api/admin/leave-types",
+        Some(json!({"code": "Bad-Code", "name": "Bad"})),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = send_request(
+        &app,
+        "PUT",
+        "/api/admin/leave-types/annual",
+        Some(json!({"is_active": false})),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = send_request(&app, "DELETE", "/api/admin/leave-types/annual", None).await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
+
+#[tokio::test]
+async fn leave_requests_follow_the_configured_types() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let admin_app = leave_type_router(pool.clone(), admin);
+    let employee_app = leave_type_router(pool.clone(), employee.clone());
+    let code = unique_code("summer");
+    let day = chrono::NaiveDate::from_ymd_opt(2032, 8, 2).expect("valid date");
+    seed_holiday_exception(&pool, employee.id, day, false, "workday").await;
+
+    let leave = |leave_type: &str| {
+        json!({
+            "leave_type": leave_type,
+            "start_date": "2032-08-02",
+            "end_date": "2032-08-02",
+        })
+    };
+
+    let response = send_request(
+        &employee_app,
+        "POST",
+        "/api/requests/leave",
+        Some(leave(&code)),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = send_request(
+        &admin_app,
+        "POST",
+        "/api/admin/leave-types",
+        Some(json!({"code": code, "name": "Summer vacation", "balance_tracked": true})),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    // Tracked types start with no balance.
+    let response = send_request(
+        &employee_app,
+        "POST",
+        "/api/requests/leave",
+        Some(leave(&code)),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+    assert_eq!(
+        response_json(response).await["code"],
+        "INSUFFICIENT_LEAVE_BALANCE"
+    );
+
+    let response = send_request(
+        &admin_app,
+        "POST",
+        &format!("/api/admin/users/{}/leave-balances", employee.id),
+        Some(json!({"leave_type": code, "entry_type": "grant", "days": 3.0})),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+
+    let response = send_request(
+        &employee_app,
+        "POST",
+        "/api/requests/leave",
+        Some(leave(&code)),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+
+    let response = send_request(&employee_app, "GET", "/api/leave-balances/me", None).await;
+    let balances = response_json(response).await;
+    let balance = balances
+        .as_array()
+        .and_then(|items| {
+            items
+                .iter()
+                .find(|item| item["leave_type"] == code.as_str())
+        })
+        .expect("custom balance listed");
+    assert_eq!(balance["balance"], 3.0);
+
+    let response = send_request(
+        &admin_app,
+        "DELETE",
+        &format!("/api/admin/leave-types/{code}"),
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    // Ledger entries are only accepted for tracked types.
+    let response = send_request(
+        &admin_app,
+        "POST",
+        &format!("/api/admin/users/{}/leave-balances", employee.id),
+        Some(json!({"leave_type": "sick", "entry_type": "grant", "days": 1.0})),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+}
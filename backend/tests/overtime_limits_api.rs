@@ -0,0 +1,235 @@
+use axum::{http::StatusCode, Extension, Router};
+use chrono::NaiveDate;
+use serde_json::json;
+use sqlx::PgPool;
+use timekeeper_backend::{
+    handlers::admin,
+    models::user::{User, UserRole},
+    state::AppState,
+    types::UserId,
+};
+
+mod support;
+
+use support::{
+    seed_department_member, seed_long_days, seed_overtime_request, seed_user, send, test_config,
+    test_pool,
+};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn overtime_limit_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/admin/compliance/overtime",
+            axum::routing::get(admin::get_overtime_compliance),
+        )
+        .route(
+            "/api/admin/overtime-limits",
+            axum::routing::get(admin::list_overtime_limits)
+                .put(admin::update_company_overtime_limit),
+        )
+        .route(
+            "/api/admin/overtime-limits/departments/{id}",
+            axum::routing::put(admin::update_department_overtime_limit)
+                .delete(admin::delete_department_overtime_limit),
+        )
+        .route(
+            "/api/admin/requests/{id}/approve",
+            axum::routing::put(admin::approve_request),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+fn date(value: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").expect("valid date")
+}
+
+fn limit_payload(monthly_limit_hours: f64) -> serde_json::Value {
+    json!({
+        "monthly_limit_hours": monthly_limit_hours,
+        "annual_limit_hours": 360.0,
+        "special_clause_enabled": false,
+        "special_annual_limit_hours": 720.0,
+        "special_monthly_cap_hours": 100.0,
+        "special_average_limit_hours": 80.0,
+        "special_months_per_year": 6,
+        "warning_ratio": 0.8,
+        "year_start_month": 4
+    })
+}
+
+fn status_for(body: &serde_json::Value, user_id: UserId) -> Option<&serde_json::Value> {
+    body.as_array()
+        .expect("report array")
+        .iter()
+        .find(|status| status["user_id"] == user_id.to_string())
+}
+
+#[tokio::test]
+async fn compliance_report_applies_department_overrides() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    let department_id = seed_department_member(&pool, employee.id).await;
+    seed_long_days(
+        &pool,
+        employee.id,
+        &[
+            "2031-05-05",
+            "2031-05-06",
+            "2031-05-07",
+            "2031-05-08",
+            "2031-05-09",
+            "2031-05-12",
+            "2031-05-13",
+            "2031-05-14",
+            "2031-05-15",
+            "2031-05-16",
+            "2031-05-19",
+            "2031-05-20",
+        ]
+        .map(date),
+    )
+    .await;
+
+    let app = overtime_limit_router(pool.clone(), system_admin);
+    let (status, body) = send(
+        &app,
+        "GET",
+        "/api/admin/compliance/overtime?year=2031&month=5&min_level=exceeded",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let report = status_for(&body, employee.id).expect("employee over the statutory limit");
+    assert_eq!(report["monthly_hours"], 48.0);
+    assert_eq!(report["level"], "exceeded");
+
+    let (status, saved) = send(
+        &app,
+        "PUT",
+        &format!("/api/admin/overtime-limits/departments/{department_id}"),
+        Some(limit_payload(60.0)),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(saved["department_id"], department_id);
+
+    let (_, body) = send(
+        &app,
+        "GET",
+        "/api/admin/compliance/overtime?year=2031&month=5",
+        None,
+    )
+    .await;
+    let report = status_for(&body, employee.id).expect("employee listed");
+    assert_eq!(report["level"], "approaching");
+
+    let (status, _) = send(
+        &app,
+        "DELETE",
+        &format!("/api/admin/overtime-limits/departments/{department_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, _) = send(
+        &app,
+        "DELETE",
+        &format!("/api/admin/overtime-limits/departments/{department_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+
+    let employee_app = overtime_limit_router(pool.clone(), employee);
+    let (status, _) = send(
+        &employee_app,
+        "GET",
+        "/api/admin/compliance/overtime?year=2031&month=5",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn approval_is_blocked_when_overtime_would_breach_a_limit() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    seed_long_days(
+        &pool,
+        employee.id,
+        &[
+            "2031-07-07",
+            "2031-07-08",
+            "2031-07-09",
+            "2031-07-10",
+            "2031-07-11",
+            "2031-07-14",
+            "2031-07-15",
+            "2031-07-16",
+            "2031-07-17",
+            "2031-07-18",
+        ]
+        .map(date),
+    )
+    .await;
+    let too_much = seed_overtime_request(&pool, employee.id, date("2031-07-25"), 6.0).await;
+    let within = seed_overtime_request(&pool, employee.id, date("2031-07-25"), 4.0).await;
+
+    let app = overtime_limit_router(pool.clone(), system_admin);
+    let (status, body) = send(
+        &app,
+        "PUT",
+        &format!("/api/admin/requests/{}/approve", too_much.id),
+        Some(json!({"comment": "ok"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "OVERTIME_LIMIT_EXCEEDED");
+
+    let (status, _) = send(
+        &app,
+        "PUT",
+        &format!("/api/admin/requests/{}/approve", within.id),
+        Some(json!({"comment": "ok"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    // Approved hours not worked yet count towards the limit of later approvals.
+    let another_day = seed_overtime_request(&pool, employee.id, date("2031-07-28"), 2.0).await;
+    let (status, body) = send(
+        &app,
+        "PUT",
+        &format!("/api/admin/requests/{}/approve", another_day.id),
+        Some(json!({"comment": "ok"})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "OVERTIME_LIMIT_EXCEEDED");
+}
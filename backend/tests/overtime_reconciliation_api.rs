@@ -0,0 +1,186 @@
+use axum::{
+    body::Body,
+    http::{Request, StatusCode},
+    Extension, Router,
+};
+use chrono::{NaiveDate, Utc};
+use sqlx::PgPool;
+use timekeeper_backend::{
+    handlers::{admin, attendance},
+    models::{
+        attendance::{Attendance, AttendanceStatus},
+        request::RequestStatus,
+        user::{User, UserRole},
+    },
+    repositories::attendance::{AttendanceRepository, AttendanceRepositoryTrait},
+    state::AppState,
+    types::{AttendanceId, UserId},
+};
+use tower::ServiceExt;
+
+mod support;
+
+use support::{response_json, seed_overtime_request, seed_user, test_config, test_pool};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn overtime_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/admin/overtime/reconciliation",
+            axum::routing::get(admin::get_overtime_reconciliation),
+        )
+        .route(
+            "/api/attendance/me/overtime",
+            axum::routing::get(attendance::get_my_overtime),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+fn date(value: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").expect("valid date")
+}
+
+async fn seed_worked_day(pool: &PgPool, user_id: UserId, on: &str, hours: f64) {
+    let on = date(on);
+    let now = Utc::now();
+    let attendance = Attendance {
+        id: AttendanceId::new(),
+        user_id,
+        date: on,
+        clock_in_time: on.and_hms_opt(9, 0, 0),
+        clock_out_time: on
+            .and_hms_opt(9, 0, 0)
+            .map(|start| start + chrono::Duration::minutes((hours * 60.0).round() as i64 + 60)),
+        status: AttendanceStatus::Present,
+        total_work_hours: Some(hours),
+        created_at: now,
+        updated_at: now,
+    };
+    AttendanceRepository::new()
+        .create(pool, &attendance)
+        .await
+        .expect("create attendance");
+}
+
+async fn seed_approved_overtime(pool: &PgPool, user_id: UserId, on: &str, hours: f64) {
+    let request = seed_overtime_request(pool, user_id, date(on), hours).await;
+    sqlx::query("UPDATE overtime_requests SET status = $1 WHERE id = $2")
+        .bind(RequestStatus::Approved.db_value())
+        .bind(request.id.to_string())
+        .execute(pool)
+        .await
+        .expect("approve overtime request");
+}
+
+async fn get(app: &Router, uri: &str) -> (StatusCode, serde_json::Value) {
+    let request = Request::builder()
+        .method("GET")
+        .uri(uri)
+        .body(Body::empty())
+        .expect("build request");
+    let response = app.clone().oneshot(request).await.expect("call endpoint");
+    let status = response.status();
+    (status, response_json(response).await)
+}
+
+#[tokio::test]
+async fn reconciliation_flags_unapproved_and_unused_overtime() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    // 2030-03-04 is a Monday; without a schedule the profile's eight hours apply.
+    seed_worked_day(&pool, employee.id, "2030-03-04", 10.0).await;
+    seed_approved_overtime(&pool, employee.id, "2030-03-04", 2.0).await;
+    seed_worked_day(&pool, employee.id, "2030-03-05", 9.5).await;
+    seed_worked_day(&pool, employee.id, "2030-03-06", 8.0).await;
+    seed_approved_overtime(&pool, employee.id, "2030-03-06", 1.0).await;
+
+    let app = overtime_router(pool.clone(), system_admin);
+    let (status, body) = get(
+        &app,
+        &format!(
+            "/api/admin/overtime/reconciliation?year=2030&month=3&user_id={}",
+            employee.id
+        ),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let reports = body.as_array().expect("report array");
+    assert_eq!(reports.len(), 1);
+    let report = &reports[0];
+    assert_eq!(report["user_id"], employee.id.to_string());
+    assert_eq!(report["overtime_hours"], 3.5);
+    assert_eq!(report["approved_hours"], 3.0);
+    assert_eq!(report["unapproved_hours"], 1.5);
+    assert_eq!(report["unused_approved_hours"], 1.0);
+
+    let statuses: Vec<&str> = report["days"]
+        .as_array()
+        .expect("days array")
+        .iter()
+        .map(|day| day["status"].as_str().expect("status"))
+        .collect();
+    assert_eq!(statuses, ["approved", "unapproved", "approved_unused"]);
+
+    let own_app = overtime_router(pool.clone(), employee.clone());
+    let (status, own) = get(&own_app, "/api/attendance/me/overtime?year=2030&month=3").await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(own["unapproved_hours"], 1.5);
+}
+
+#[tokio::test]
+async fn reconciliation_is_limited_to_visible_subordinates() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let unrelated_manager = seed_user(&pool, UserRole::Manager, false).await;
+    seed_worked_day(&pool, employee.id, "2030-04-01", 9.0).await;
+
+    let app = overtime_router(pool.clone(), unrelated_manager);
+    let (status, _) = get(
+        &app,
+        &format!(
+            "/api/admin/overtime/reconciliation?year=2030&month=4&user_id={}",
+            employee.id
+        ),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, body) = get(&app, "/api/admin/overtime/reconciliation?year=2030&month=4").await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body
+        .as_array()
+        .expect("report array")
+        .iter()
+        .all(|report| report["user_id"] != employee.id.to_string()));
+
+    let employee_app = overtime_router(pool.clone(), employee);
+    let (status, _) = get(
+        &employee_app,
+        "/api/admin/overtime/reconciliation?year=2030&month=4",
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
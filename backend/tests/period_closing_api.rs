@@ -0,0 +1,322 @@
+use axum::{http::StatusCode, Extension, Router};
+use chrono::{NaiveDate, Utc};
+use serde_json::json;
+use sqlx::PgPool;
+use timekeeper_backend::{
+    handlers::{admin, attendance, requests},
+    models::{
+        attendance::Attendance,
+        break_record::BreakRecord,
+        user::{User, UserRole},
+    },
+    repositories::{
+        attendance::{AttendanceRepository, AttendanceRepositoryTrait},
+        break_record::BreakRecordRepository,
+        repository::Repository,
+    },
+    services::attendance_closure::ForgottenClockOutSweeper,
+    state::AppState,
+    types::DepartmentId,
+};
+
+mod support;
+
+use support::{seed_department_member, seed_user, send, test_config, test_pool};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn period_closing_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/admin/period-closings",
+            axum::routing::get(admin::list_period_closings).post(admin::close_period),
+        )
+        .route(
+            "/api/admin/period-closings/{id}",
+            axum::routing::delete(admin::reopen_period),
+        )
+        .route(
+            "/api/admin/attendance",
+            axum::routing::put(admin::upsert_attendance),
+        )
+        .route(
+            "/api/admin/breaks/{id}/force-end",
+            axum::routing::put(admin::force_end_break),
+        )
+        .route(
+            "/api/attendance/me/closed-periods",
+            axum::routing::get(attendance::get_my_closed_periods),
+        )
+        .route(
+            "/api/requests/leave",
+            axum::routing::post(requests::create_leave_request),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+fn upsert_payload(user: &User, date: &str) -> serde_json::Value {
+    json!({
+        "user_id": user.id.to_string(),
+        "date": date,
+        "clock_in_time": format!("{date}T09:00:00"),
+        "clock_out_time": format!("{date}T17:00:00")
+    })
+}
+
+#[tokio::test]
+async fn closed_department_month_rejects_writes_until_reopened() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let outsider = seed_user(&pool, UserRole::Employee, false).await;
+    let manager = seed_user(&pool, UserRole::Manager, false).await;
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    let department_id = seed_department_member(&pool, employee.id).await;
+
+    let manager_app = period_closing_router(pool.clone(), manager);
+    let (status, _) = send(
+        &manager_app,
+        "POST",
+        "/api/admin/period-closings",
+        Some(json!({"year": 2039, "month": 5, "department_id": department_id})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send(
+        &manager_app,
+        "POST",
+        "/api/admin/period-closings",
+        Some(json!({"year": 2039, "month": 5})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let admin_app = period_closing_router(pool.clone(), system_admin);
+    let (status, closing) = send(
+        &admin_app,
+        "POST",
+        "/api/admin/period-closings",
+        Some(json!({"year": 2039, "month": 5, "department_id": department_id})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(closing["period_month"], "2039-05-01");
+    let closing_id = closing["id"].as_str().expect("closing id").to_string();
+    let (status, _) = send(
+        &admin_app,
+        "POST",
+        "/api/admin/period-closings",
+        Some(json!({"year": 2039, "month": 5, "department_id": department_id})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, body) = send(
+        &admin_app,
+        "PUT",
+        "/api/admin/attendance",
+        Some(upsert_payload(&employee, "2039-05-10")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "PERIOD_CLOSED");
+    let (status, _) = send(
+        &admin_app,
+        "PUT",
+        "/api/admin/attendance",
+        Some(upsert_payload(&outsider, "2039-05-10")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let employee_app = period_closing_router(pool.clone(), employee.clone());
+    let (status, body) = send(
+        &employee_app,
+        "POST",
+        "/api/requests/leave",
+        Some(json!({
+            "leave_type": "sick",
+            "start_date": "2039-04-28",
+            "end_date": "2039-05-02"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "PERIOD_CLOSED");
+    let (status, closed) = send(
+        &employee_app,
+        "GET",
+        "/api/attendance/me/closed-periods",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(closed
+        .as_array()
+        .expect("closing array")
+        .iter()
+        .any(|item| item["id"] == closing_id.as_str()));
+
+    let (status, _) = send(
+        &manager_app,
+        "DELETE",
+        &format!("/api/admin/period-closings/{closing_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+    let (status, _) = send(
+        &admin_app,
+        "DELETE",
+        &format!("/api/admin/period-closings/{closing_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+
+    let (status, _) = send(
+        &admin_app,
+        "PUT",
+        "/api/admin/attendance",
+        Some(upsert_payload(&employee, "2039-05-10")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+}
+
+#[tokio::test]
+async fn closing_a_department_locks_its_sub_departments() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    let team_id = seed_department_member(&pool, employee.id).await;
+    let parent_id = DepartmentId::new().to_string();
+    sqlx::query("INSERT INTO departments (id, name) VALUES ($1, $2)")
+        .bind(&parent_id)
+        .bind(format!("Division {}", &parent_id[..8]))
+        .execute(&pool)
+        .await
+        .expect("insert parent department");
+    sqlx::query("UPDATE departments SET parent_id = $1 WHERE id = $2")
+        .bind(&parent_id)
+        .bind(&team_id)
+        .execute(&pool)
+        .await
+        .expect("nest department");
+
+    let admin_app = period_closing_router(pool.clone(), system_admin);
+    let (status, _) = send(
+        &admin_app,
+        "POST",
+        "/api/admin/period-closings",
+        Some(json!({"year": 2039, "month": 7, "department_id": parent_id})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, body) = send(
+        &admin_app,
+        "PUT",
+        "/api/admin/attendance",
+        Some(upsert_payload(&employee, "2039-07-12")),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "PERIOD_CLOSED");
+}
+
+#[tokio::test]
+async fn closed_month_blocks_forced_break_ends_and_the_clock_out_sweep() {
+    let _guard = integration_guard().await;
+    std::env::set_var("SMTP_SKIP_SEND", "true");
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    let department_id = seed_department_member(&pool, employee.id).await;
+
+    let date = NaiveDate::from_ymd_opt(2039, 9, 14).unwrap();
+    let mut open_record = Attendance::new(employee.id, date, Utc::now());
+    open_record.clock_in_time = date.and_hms_opt(9, 0, 0);
+    open_record.clock_out_time = None;
+    let open_record = AttendanceRepository::new()
+        .create(&pool, &open_record)
+        .await
+        .expect("create attendance");
+    let open_break = BreakRecordRepository::new()
+        .create(
+            &pool,
+            &BreakRecord::new(
+                open_record.id,
+                date.and_hms_opt(12, 0, 0).unwrap(),
+                Utc::now(),
+            ),
+        )
+        .await
+        .expect("create break");
+
+    let admin_app = period_closing_router(pool.clone(), system_admin);
+    let (status, _) = send(
+        &admin_app,
+        "POST",
+        "/api/admin/period-closings",
+        Some(json!({"year": 2039, "month": 9, "department_id": department_id})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+
+    let (status, body) = send(
+        &admin_app,
+        "PUT",
+        &format!("/api/admin/breaks/{}/force-end", open_break.id),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "PERIOD_CLOSED");
+
+    ForgottenClockOutSweeper::new(pool.clone())
+        .sweep(
+            NaiveDate::from_ymd_opt(2039, 10, 1)
+                .unwrap()
+                .and_hms_opt(0, 0, 0)
+                .unwrap(),
+            &test_config(),
+        )
+        .await
+        .expect("sweep");
+    let swept: Option<String> = sqlx::query_scalar(
+        "SELECT action FROM attendance_open_record_sweeps WHERE attendance_id = $1",
+    )
+    .bind(open_record.id)
+    .fetch_optional(&pool)
+    .await
+    .expect("read sweep row");
+    assert_eq!(swept, None);
+    let break_record = BreakRecordRepository::new()
+        .find_by_id(&pool, open_break.id)
+        .await
+        .expect("reload break");
+    assert!(break_record.is_active());
+}
@@ -29,7 +29,7 @@ async fn leave_request_repository_approve_roundtrip() {
         .run(&pool)
         .await
         .expect("run migrations");
-    sqlx::query("TRUNCATE leave_requests")
+    sqlx::query("TRUNCATE leave_requests CASCADE")
         .execute(&pool)
         .await
         .expect("truncate leave_requests");
@@ -38,7 +38,13 @@ async fn leave_request_repository_approve_roundtrip() {
     let admin = support::seed_user(&pool, UserRole::Manager, false).await;
     let start = NaiveDate::from_ymd_opt(2024, 5, 1).unwrap();
     let end = NaiveDate::from_ymd_opt(2024, 5, 2).unwrap();
-    let request = LeaveRequest::new(user.id, LeaveType::Annual, start, end, Some("trip".into()));
+    let request = LeaveRequest::new(
+        user.id,
+        LeaveType::annual(),
+        start,
+        end,
+        Some("trip".into()),
+    );
 
     let repo = LeaveRequestRepository::new();
     let saved = repo
@@ -48,7 +48,7 @@ async fn leave_request_repository_impl_covers_crud_and_state_changes() {
     let first_end = NaiveDate::from_ymd_opt(2026, 3, 2).expect("valid date");
     let first = LeaveRequest::new(
         user.id,
-        LeaveType::Annual,
+        LeaveType::annual(),
         first_start,
         first_end,
         Some("annual leave".to_string()),
@@ -118,7 +118,7 @@ async fn leave_request_repository_impl_covers_crud_and_state_changes() {
 
     let second = LeaveRequest::new(
         user.id,
-        LeaveType::Other,
+        LeaveType::new("other"),
         NaiveDate::from_ymd_opt(2026, 4, 1).expect("valid date"),
         NaiveDate::from_ymd_opt(2026, 4, 1).expect("valid date"),
         Some("other leave".to_string()),
@@ -128,7 +128,7 @@ async fn leave_request_repository_impl_covers_crud_and_state_changes() {
         .await
         .expect("create second leave");
     let mut second_update = second.clone();
-    second_update.leave_type = LeaveType::Sick;
+    second_update.leave_type = LeaveType::new("sick");
     second_update.reason = Some("updated sick leave".to_string());
     second_update.status = RequestStatus::Pending;
     second_update.updated_at = Utc::now();
@@ -136,7 +136,7 @@ async fn leave_request_repository_impl_covers_crud_and_state_changes() {
         .update(&pool, &second_update)
         .await
         .expect("update second leave");
-    assert!(matches!(updated.leave_type, LeaveType::Sick));
+    assert_eq!(updated.leave_type, LeaveType::new("sick"));
     assert_eq!(updated.reason.as_deref(), Some("updated sick leave"));
 
     assert_eq!(
@@ -148,7 +148,7 @@ async fn leave_request_repository_impl_covers_crud_and_state_changes() {
 
     let third = LeaveRequest::new(
         user.id,
-        LeaveType::Personal,
+        LeaveType::new("personal"),
         NaiveDate::from_ymd_opt(2026, 5, 1).expect("valid date"),
         NaiveDate::from_ymd_opt(2026, 5, 2).expect("valid date"),
         Some("personal leave".to_string()),
@@ -201,7 +201,7 @@ async fn leave_request_repository_find_all_is_capped() {
             .checked_add_signed(chrono::Duration::days(day_offset))
             .expect("date offset");
         let end = start;
-        let request = LeaveRequest::new(user.id, LeaveType::Annual, start, end, None);
+        let request = LeaveRequest::new(user.id, LeaveType::annual(), start, end, None);
         repo.create(&pool, &request).await.expect("create leave");
     }
 
@@ -82,7 +82,7 @@ async fn update_leave_request_validates_pending_and_date_window() {
     let leave = seed_leave_request(
         &pool,
         employee.id,
-        timekeeper_backend::models::leave_request::LeaveType::Annual,
+        timekeeper_backend::models::leave_request::LeaveType::annual(),
         NaiveDate::from_ymd_opt(2026, 2, 10).expect("valid date"),
         NaiveDate::from_ymd_opt(2026, 2, 11).expect("valid date"),
     )
@@ -142,7 +142,7 @@ async fn update_leave_request_succeeds_for_pending_request() {
     let leave = seed_leave_request(
         &pool,
         employee.id,
-        timekeeper_backend::models::leave_request::LeaveType::Personal,
+        timekeeper_backend::models::leave_request::LeaveType::new("personal"),
         NaiveDate::from_ymd_opt(2026, 2, 20).expect("valid date"),
         NaiveDate::from_ymd_opt(2026, 2, 20).expect("valid date"),
     )
@@ -255,7 +255,7 @@ async fn cancel_request_handles_invalid_and_not_cancellable_states() {
     let leave = seed_leave_request(
         &pool,
         employee.id,
-        timekeeper_backend::models::leave_request::LeaveType::Annual,
+        timekeeper_backend::models::leave_request::LeaveType::annual(),
         NaiveDate::from_ymd_opt(2026, 4, 10).expect("valid date"),
         NaiveDate::from_ymd_opt(2026, 4, 10).expect("valid date"),
     )
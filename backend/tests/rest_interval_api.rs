@@ -0,0 +1,217 @@
+use axum::{http::StatusCode, Extension, Router};
+use chrono::Duration;
+use serde_json::json;
+use sqlx::PgPool;
+use std::sync::Arc;
+use timekeeper_backend::{
+    handlers::{admin, attendance},
+    models::user::{User, UserRole},
+    services::holiday::{HolidayService, HolidayServiceTrait},
+    state::AppState,
+    types::UserId,
+    utils::time,
+};
+
+mod support;
+
+use support::{seed_attendance, seed_department_member, seed_user, send, test_config, test_pool};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+/// Drops any saved policy so the standard, warn-only one applies.
+async fn reset_policy(pool: &PgPool) {
+    sqlx::query("DELETE FROM rest_interval_policy")
+        .execute(pool)
+        .await
+        .expect("reset rest interval policy");
+}
+
+fn rest_interval_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool.clone(), None, None, None, test_config());
+    let holiday_service: Arc<dyn HolidayServiceTrait> = Arc::new(HolidayService::new(pool));
+    Router::new()
+        .route(
+            "/api/attendance/clock-in",
+            axum::routing::post(attendance::clock_in),
+        )
+        .route(
+            "/api/admin/rest-interval-policy",
+            axum::routing::get(admin::get_rest_interval_policy)
+                .put(admin::update_rest_interval_policy),
+        )
+        .route(
+            "/api/admin/compliance/rest-intervals",
+            axum::routing::get(admin::get_rest_interval_compliance),
+        )
+        .layer(Extension(user))
+        .layer(Extension(holiday_service))
+        .with_state(state)
+}
+
+fn listed(body: &serde_json::Value, user_id: UserId) -> Option<&serde_json::Value> {
+    body.as_array()
+        .expect("violation array")
+        .iter()
+        .find(|v| v["user_id"] == user_id.to_string())
+}
+
+#[tokio::test]
+async fn short_rest_is_warned_about_or_blocked_and_reported() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+    reset_policy(&pool).await;
+
+    let warned = seed_user(&pool, UserRole::Employee, false).await;
+    let blocked = seed_user(&pool, UserRole::Employee, false).await;
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    let department_id = seed_department_member(&pool, warned.id).await;
+
+    // Both finished yesterday's shift three hours ago.
+    let now = time::now_in_timezone(&test_config().time_zone).naive_local();
+    let today = now.date();
+    let yesterday = today - Duration::days(1);
+    for user in [&warned, &blocked] {
+        seed_attendance(
+            &pool,
+            user.id,
+            yesterday,
+            Some(now - Duration::hours(11)),
+            Some(now - Duration::hours(3)),
+        )
+        .await;
+    }
+
+    let admin_app = rest_interval_router(pool.clone(), system_admin);
+    let (status, policy) = send(&admin_app, "GET", "/api/admin/rest-interval-policy", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(policy["mode"], "warn");
+    assert_eq!(policy["minimum_rest_minutes"], 660);
+
+    let clock_in = json!({ "date": today });
+    let (status, body) = send(
+        &rest_interval_router(pool.clone(), warned.clone()),
+        "POST",
+        "/api/attendance/clock-in",
+        Some(clock_in.clone()),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let warning = &body["rest_interval_warning"];
+    assert_eq!(warning["required_rest_minutes"], 660);
+    assert!(warning["rest_minutes"].as_i64().unwrap() < 660);
+
+    let (status, _) = send(
+        &admin_app,
+        "PUT",
+        "/api/admin/rest-interval-policy",
+        Some(json!({ "mode": "block", "minimum_rest_minutes": 660 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let (status, body) = send(
+        &rest_interval_router(pool.clone(), blocked.clone()),
+        "POST",
+        "/api/attendance/clock-in",
+        Some(clock_in),
+    )
+    .await;
+    reset_policy(&pool).await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "REST_INTERVAL_TOO_SHORT");
+    let clocked_in: i64 =
+        sqlx::query_scalar("SELECT COUNT(*) FROM attendance WHERE user_id = $1 AND date = $2")
+            .bind(blocked.id)
+            .bind(today)
+            .fetch_one(&pool)
+            .await
+            .expect("count attendance");
+    assert_eq!(clocked_in, 0);
+
+    let range = format!("from={yesterday}&to={today}");
+    let (status, report) = send(
+        &admin_app,
+        "GET",
+        &format!("/api/admin/compliance/rest-intervals?{range}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let first = listed(&report, warned.id).expect("warned clock-in listed");
+    assert_eq!(first["blocked"], false);
+    assert_eq!(first["department_id"], department_id.as_str());
+    let second = listed(&report, blocked.id).expect("blocked clock-in listed");
+    assert_eq!(second["blocked"], true);
+
+    let (status, report) = send(
+        &admin_app,
+        "GET",
+        &format!("/api/admin/compliance/rest-intervals?{range}&department_id={department_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(report.as_array().unwrap().len(), 1);
+    assert!(listed(&report, warned.id).is_some());
+
+    let (status, _) = send(
+        &admin_app,
+        "GET",
+        "/api/admin/compliance/rest-intervals?department_id=not-a-uuid",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    let employee_app = rest_interval_router(pool.clone(), warned);
+    let (status, _) = send(
+        &employee_app,
+        "GET",
+        "/api/admin/compliance/rest-intervals",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+}
+
+#[tokio::test]
+async fn rest_interval_policy_is_set_by_system_admins_only() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let manager = seed_user(&pool, UserRole::Manager, false).await;
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    let payload = json!({ "mode": "block", "minimum_rest_minutes": 600 });
+
+    let (status, _) = send(
+        &rest_interval_router(pool.clone(), manager),
+        "PUT",
+        "/api/admin/rest-interval-policy",
+        Some(payload),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let (status, _) = send(
+        &rest_interval_router(pool.clone(), system_admin),
+        "PUT",
+        "/api/admin/rest-interval-policy",
+        Some(json!({ "mode": "warn", "minimum_rest_minutes": 0 })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+}
@@ -179,6 +179,17 @@ pub fn test_config() -> Config {
         cookie_same_site: SameSite::Lax,
         cors_allow_origins: vec!["http://localhost:8000".into()],
         time_zone: Tokyo,
+        attendance_day_change_hour: 0,
+        forgotten_clock_out_cutoff_hours: 4,
+        forgotten_clock_out_auto_close: false,
+        consecutive_workday_limit: 6,
+        report_font_path: None,
+        export_storage_dir: "exports".into(),
+        export_s3_bucket: None,
+        export_s3_endpoint: None,
+        export_download_ttl_seconds: 300,
+        export_download_secret: "test-export-download-secret-32-chars".to_string(),
+        export_encryption_required: false,
         mfa_issuer: "Timekeeper".into(),
         rate_limit_ip_max_requests: 15,
         rate_limit_ip_window_seconds: 900,
@@ -378,12 +389,7 @@ pub async fn seed_leave_request(
     )
     .bind(request.id.to_string())
     .bind(request.user_id.to_string())
-    .bind(match request.leave_type {
-        LeaveType::Annual => "annual",
-        LeaveType::Sick => "sick",
-        LeaveType::Personal => "personal",
-        LeaveType::Other => "other",
-    })
+    .bind(request.leave_type.db_value())
     .bind(request.start_date)
     .bind(request.end_date)
     .bind(&request.reason)
@@ -407,6 +413,19 @@ pub async fn seed_leave_request(
     request
 }
 
+pub async fn seed_leave_grant(pool: &PgPool, user_id: UserId, days: f64) {
+    sqlx::query(
+        "INSERT INTO leave_balance_entries (id, user_id, leave_type, entry_type, days, effective_date) \
+         VALUES ($1, $2, 'annual', 'grant', $3, CURRENT_DATE)",
+    )
+    .bind(Uuid::new_v4().to_string())
+    .bind(user_id.to_string())
+    .bind(days)
+    .execute(pool)
+    .await
+    .expect("insert leave grant");
+}
+
 pub async fn seed_overtime_request(
     pool: &PgPool,
     user_id: UserId,
@@ -515,6 +534,33 @@ pub async fn seed_attendance(
         .expect("create attendance")
 }
 
+/// Seeds a clocked-out 08:00-21:00 day worth 12 hours for each date.
+pub async fn seed_long_days(pool: &PgPool, user_id: UserId, days: &[NaiveDate]) {
+    use chrono::Utc;
+    use timekeeper_backend::models::attendance::{Attendance, AttendanceStatus};
+    use timekeeper_backend::repositories::attendance::AttendanceRepository;
+    use timekeeper_backend::repositories::attendance::AttendanceRepositoryTrait;
+
+    let now = Utc::now();
+    for day in days {
+        let attendance = Attendance {
+            id: timekeeper_backend::types::AttendanceId::new(),
+            user_id,
+            date: *day,
+            clock_in_time: day.and_hms_opt(8, 0, 0),
+            clock_out_time: day.and_hms_opt(21, 0, 0),
+            status: AttendanceStatus::Present,
+            total_work_hours: Some(12.0),
+            created_at: now,
+            updated_at: now,
+        };
+        AttendanceRepository::new()
+            .create(pool, &attendance)
+            .await
+            .expect("create attendance");
+    }
+}
+
 pub async fn seed_break_record(
     pool: &PgPool,
     attendance_id: timekeeper_backend::types::AttendanceId,
@@ -534,6 +580,7 @@ pub async fn seed_break_record(
         break_start_time: start_time,
         break_end_time: end_time,
         duration_minutes,
+        is_automatic: false,
         created_at: now,
         updated_at: now,
     };
@@ -643,6 +690,48 @@ pub async fn seed_active_session(
     id
 }
 
+async fn seed_empty_department(pool: &PgPool) -> String {
+    let department_id = Uuid::new_v4().to_string();
+    sqlx::query("INSERT INTO departments (id, name) VALUES ($1, $2)")
+        .bind(&department_id)
+        .bind(format!("Dept {}", &department_id[..8]))
+        .execute(pool)
+        .await
+        .expect("insert department");
+    department_id
+}
+
+async fn assign_department(pool: &PgPool, department_id: &str, user_id: UserId) {
+    sqlx::query("UPDATE users SET department_id = $1 WHERE id = $2")
+        .bind(department_id)
+        .bind(user_id.to_string())
+        .execute(pool)
+        .await
+        .expect("assign department");
+}
+
+/// Puts the user in a new department without managers; returns the department ID.
+pub async fn seed_department_member(pool: &PgPool, user_id: UserId) -> String {
+    let department_id = seed_empty_department(pool).await;
+    assign_department(pool, &department_id, user_id).await;
+    department_id
+}
+
+/// Creates a department with the given members and manager; returns its ID.
+pub async fn seed_department(pool: &PgPool, members: &[UserId], manager: UserId) -> String {
+    let department_id = seed_empty_department(pool).await;
+    for member in members {
+        assign_department(pool, &department_id, *member).await;
+    }
+    sqlx::query("INSERT INTO department_managers (department_id, user_id) VALUES ($1, $2)")
+        .bind(&department_id)
+        .bind(manager.to_string())
+        .execute(pool)
+        .await
+        .expect("assign manager");
+    department_id
+}
+
 pub async fn seed_subject_request(
     pool: &PgPool,
     user_id: UserId,
@@ -690,6 +779,7 @@ use axum::{
     response::Response,
     Extension, Router,
 };
+use tower::ServiceExt;
 
 pub fn test_router_with_user<F>(
     handler: F,
@@ -751,6 +841,36 @@ pub fn build_json_request(
         .expect("build json request")
 }
 
+/// Calls the router with an optional JSON body.
+pub async fn send_request(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    body: Option<serde_json::Value>,
+) -> Response {
+    let builder = Request::builder().method(method).uri(uri);
+    let request = match body {
+        Some(body) => builder
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string())),
+        None => builder.body(Body::empty()),
+    }
+    .expect("build request");
+    app.clone().oneshot(request).await.expect("call endpoint")
+}
+
+/// Calls the router with an optional JSON body and returns the status and JSON response.
+pub async fn send(
+    app: &Router,
+    method: &str,
+    uri: &str,
+    body: Option<serde_json::Value>,
+) -> (StatusCode, serde_json::Value) {
+    let response = send_request(app, method, uri, body).await;
+    let status = response.status();
+    (status, response_json(response).await)
+}
+
 pub async fn response_json(response: Response) -> serde_json::Value {
     let body = axum::body::to_bytes(response.into_body(), usize::MAX)
         .await
@@ -0,0 +1,242 @@
+use axum::{http::StatusCode, Extension, Router};
+use chrono::NaiveDate;
+use serde_json::json;
+use sqlx::PgPool;
+use timekeeper_backend::{
+    handlers::{admin, timesheets},
+    models::user::{User, UserRole},
+    state::AppState,
+    types::DepartmentId,
+};
+
+mod support;
+
+use support::{seed_attendance, seed_user, send, test_config, test_pool};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn timesheet_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/timesheets/me",
+            axum::routing::get(timesheets::list_my_timesheets)
+                .post(timesheets::submit_my_timesheet),
+        )
+        .route(
+            "/api/timesheets/me/month",
+            axum::routing::get(timesheets::get_my_timesheet_month),
+        )
+        .route(
+            "/api/admin/timesheets",
+            axum::routing::get(admin::list_timesheets),
+        )
+        .route(
+            "/api/admin/timesheets/{id}/approve",
+            axum::routing::post(admin::approve_timesheet),
+        )
+        .route(
+            "/api/admin/timesheets/{id}/return",
+            axum::routing::post(admin::return_timesheet),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+async fn seed_managed_department(pool: &PgPool, employee: &User, manager: &User) {
+    let id = DepartmentId::new().to_string();
+    sqlx::query("INSERT INTO departments (id, name) VALUES ($1, $2)")
+        .bind(&id)
+        .bind(format!("Timesheets {}", &id[..8]))
+        .execute(pool)
+        .await
+        .expect("insert department");
+    sqlx::query("UPDATE users SET department_id = $1 WHERE id = $2")
+        .bind(&id)
+        .bind(employee.id.to_string())
+        .execute(pool)
+        .await
+        .expect("assign department");
+    sqlx::query("INSERT INTO department_managers (department_id, user_id) VALUES ($1, $2)")
+        .bind(&id)
+        .bind(manager.id.to_string())
+        .execute(pool)
+        .await
+        .expect("assign manager");
+}
+
+fn month(year: i32, month: u32) -> serde_json::Value {
+    json!({ "year": year, "month": month })
+}
+
+#[tokio::test]
+async fn timesheet_moves_through_submission_return_and_approval() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let manager = seed_user(&pool, UserRole::Manager, false).await;
+    let other_manager = seed_user(&pool, UserRole::Manager, false).await;
+    seed_managed_department(&pool, &employee, &manager).await;
+
+    let date = NaiveDate::from_ymd_opt(2024, 3, 12).unwrap();
+    let attendance = seed_attendance(
+        &pool,
+        employee.id,
+        date,
+        Some(date.and_hms_opt(9, 0, 0).unwrap()),
+        None,
+    )
+    .await;
+
+    let employee_app = timesheet_router(pool.clone(), employee.clone());
+    let (status, body) = send(
+        &employee_app,
+        "GET",
+        "/api/timesheets/me/month?year=2024&month=3",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(body["submission"].is_null());
+    assert_eq!(body["blockers"]["open_clock_ins"], 1);
+
+    let (status, body) = send(
+        &employee_app,
+        "POST",
+        "/api/timesheets/me",
+        Some(month(2024, 3)),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    assert_eq!(body["code"], "TIMESHEET_NOT_READY");
+    let (status, _) = send(
+        &employee_app,
+        "POST",
+        "/api/timesheets/me",
+        Some(month(2999, 1)),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+
+    sqlx::query("UPDATE attendance SET clock_out_time = $1 WHERE id = $2")
+        .bind(date.and_hms_opt(18, 0, 0).unwrap())
+        .bind(attendance.id.to_string())
+        .execute(&pool)
+        .await
+        .expect("clock out");
+    let (status, submission) = send(
+        &employee_app,
+        "POST",
+        "/api/timesheets/me",
+        Some(month(2024, 3)),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(submission["status"], "submitted");
+    assert_eq!(submission["period_month"], "2024-03-01");
+    let id = submission["id"]
+        .as_str()
+        .expect("submission id")
+        .to_string();
+    let (status, _) = send(
+        &employee_app,
+        "POST",
+        "/api/timesheets/me",
+        Some(month(2024, 3)),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let other_app = timesheet_router(pool.clone(), other_manager);
+    let (status, _) = send(
+        &other_app,
+        "POST",
+        &format!("/api/admin/timesheets/{id}/approve"),
+        Some(json!({})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::FORBIDDEN);
+
+    let manager_app = timesheet_router(pool.clone(), manager);
+    let (status, listed) = send(
+        &manager_app,
+        "GET",
+        "/api/admin/timesheets?status=submitted&year=2024&month=3",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    let listed = listed.as_array().expect("submission list");
+    assert_eq!(listed.len(), 1);
+    assert_eq!(listed[0]["username"], employee.username.as_str());
+
+    let (status, _) = send(
+        &manager_app,
+        "POST",
+        &format!("/api/admin/timesheets/{id}/return"),
+        Some(json!({})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::BAD_REQUEST);
+    let (status, returned) = send(
+        &manager_app,
+        "POST",
+        &format!("/api/admin/timesheets/{id}/return"),
+        Some(json!({ "comment": "Add the client visit on the 12th" })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(returned["status"], "returned");
+    assert_eq!(
+        returned["decision_comment"],
+        "Add the client visit on the 12th"
+    );
+
+    let (status, resubmitted) = send(
+        &employee_app,
+        "POST",
+        "/api/timesheets/me",
+        Some(month(2024, 3)),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CREATED);
+    assert_eq!(resubmitted["id"], id.as_str());
+    assert!(resubmitted["decision_comment"].is_null());
+
+    let (status, approved) = send(
+        &manager_app,
+        "POST",
+        &format!("/api/admin/timesheets/{id}/approve"),
+        Some(json!({})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(approved["status"], "approved");
+    let (status, _) = send(
+        &manager_app,
+        "POST",
+        &format!("/api/admin/timesheets/{id}/approve"),
+        Some(json!({})),
+    )
+    .await;
+    assert_eq!(status, StatusCode::CONFLICT);
+
+    let (status, history) = send(&employee_app, "GET", "/api/timesheets/me", None).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(history[0]["status"], "approved");
+}
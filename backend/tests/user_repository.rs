@@ -225,7 +225,7 @@ async fn user_repository_soft_delete_archives_records_and_hard_delete_archived_r
         Some(clock_in + ChronoDuration::hours(3)),
     )
     .await;
-    support::seed_leave_request(&pool, user.id, LeaveType::Personal, date, date).await;
+    support::seed_leave_request(&pool, user.id, LeaveType::new("personal"), date, date).await;
     support::seed_overtime_request(&pool, user.id, date, 1.5).await;
     support::seed_holiday_exception(&pool, user.id, date, false, "coverage test").await;
     let correction_request_id = Uuid::new_v4().to_string();
@@ -468,7 +468,7 @@ async fn user_repository_soft_delete_restore_and_archive_cleanup() {
     )
     .await;
     let leave_request =
-        support::seed_leave_request(&pool, user.id, LeaveType::Annual, date, date).await;
+        support::seed_leave_request(&pool, user.id, LeaveType::annual(), date, date).await;
     let overtime_request = support::seed_overtime_request(&pool, user.id, date, 2.0).await;
     support::seed_holiday_exception(&pool, user.id, date, false, "manual override").await;
     let now = Utc::now();
@@ -0,0 +1,458 @@
+use axum::{http::StatusCode, Extension, Router};
+use chrono::NaiveDate;
+use serde_json::{json, Value};
+use sqlx::PgPool;
+use timekeeper_backend::{
+    handlers::admin,
+    models::user::{User, UserRole},
+    services::work_schedule::WorkScheduleService,
+    state::AppState,
+    types::DepartmentId,
+};
+
+mod support;
+
+use support::{
+    response_json, seed_department_member, seed_user, send_request, test_config, test_pool,
+};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn schedule_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool, None, None, None, test_config());
+    Router::new()
+        .route(
+            "/api/admin/work-schedules",
+            axum::routing::get(admin::list_work_schedules).post(admin::create_work_schedule),
+        )
+        .route(
+            "/api/admin/work-schedules/{id}",
+            axum::routing::put(admin::update_work_schedule).delete(admin::delete_work_schedule),
+        )
+        .route(
+            "/api/admin/work-schedule-assignments",
+            axum::routing::get(admin::list_work_schedule_assignments)
+                .post(admin::create_work_schedule_assignment),
+        )
+        .route(
+            "/api/admin/work-schedule-assignments/{id}",
+            axum::routing::delete(admin::delete_work_schedule_assignment),
+        )
+        .route(
+            "/api/admin/attendance",
+            axum::routing::put(admin::upsert_attendance),
+        )
+        .layer(Extension(user))
+        .with_state(state)
+}
+
+/// Schedule names are global, so each test run gets its own.
+fn unique_name(prefix: &str) -> String {
+    format!(
+        "{prefix} {}",
+        &uuid::Uuid::new_v4().simple().to_string()[..8]
+    )
+}
+
+async fn create_schedule(app: &Router, payload: Value) -> String {
+    let response = send_request(app, "POST", "/api/admin/work-schedules", Some(payload)).await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    response_json(response).await["id"]
+        .as_str()
+        .expect("schedule id")
+        .to_string()
+}
+
+async fn assign(app: &Router, payload: Value) -> String {
+    let response = send_request(
+        app,
+        "POST",
+        "/api/admin/work-schedule-assignments",
+        Some(payload),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    response_json(response).await["id"]
+        .as_str()
+        .expect("assignment id")
+        .to_string()
+}
+
+async fn upsert_status(app: &Router, user: &User, clock_in: &str, clock_out: &str) -> String {
+    let date = &clock_in[..10];
+    let response = send_request(
+        app,
+        "PUT",
+        "/api/admin/attendance",
+        Some(json!({
+            "user_id": user.id.to_string(),
+            "date": date,
+            "clock_in_time": clock_in,
+            "clock_out_time": clock_out,
+        })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    response_json(response).await["status"]
+        .as_str()
+        .expect("status")
+        .to_string()
+}
+
+#[tokio::test]
+async fn system_admin_manages_work_schedules() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let admin_app = schedule_router(pool.clone(), admin);
+    let employee_app = schedule_router(pool.clone(), employee.clone());
+    let name = unique_name("Day shift");
+    let payload = json!({
+        "name": name,
+        "start_time": "09:00:00",
+        "end_time": "18:00:00",
+        "working_weekdays": [5, 1, 2, 3, 4, 1],
+        "late_grace_minutes": 10
+    });
+
+    let response = send_request(
+        &employee_app,
+        "POST",
+        "/api/admin/work-schedules",
+        Some(payload.clone()),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::FORBIDDEN);
+
+    let response = send_request(
+        &admin_app,
+        "POST",
+        "/api/admin/work-schedules",
+        Some(payload.clone()),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CREATED);
+    let created = response_json(response).await;
+    assert_eq!(created["break_minutes"], 60);
+    assert_eq!(created["working_weekdays"], json!([1, 2, 3, 4, 5]));
+    let id = created["id"].as_str().expect("schedule id").to_string();
+
+    let response = send_request(
+        &admin_app,
+        "POST",
+        "/api/admin/work-schedules",
+        Some(payload),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = send_request(
+        &admin_app,
+        "POST",
+        "/api/admin/work-schedules",
+        Some(json!({
+            "name": unique_name("Zero length"),
+            "start_time": "09:00:00",
+            "end_time": "09:00:00"
+        })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let response = send_request(
+        &admin_app,
+        "PUT",
+        &format!("/api/admin/work-schedules/{id}"),
+        Some(json!({"start_time": "08:30:00", "break_minutes": 45})),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let updated = response_json(response).await;
+    assert_eq!(updated["start_time"], "08:30:00");
+    assert_eq!(updated["break_minutes"], 45);
+    assert_eq!(updated["late_grace_minutes"], 10);
+
+    let response = send_request(
+        &admin_app,
+        "POST",
+        "/api/admin/work-schedule-assignments",
+        Some(json!({
+            "schedule_id": id,
+            "user_id": employee.id.to_string(),
+            "department_id": DepartmentId::new().to_string(),
+            "effective_from": "2033-01-01"
+        })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::BAD_REQUEST);
+
+    let assignment_id = assign(
+        &admin_app,
+        json!({
+            "schedule_id": id,
+            "user_id": employee.id.to_string(),
+            "effective_from": "2033-01-01"
+        }),
+    )
+    .await;
+    let response = send_request(
+        &admin_app,
+        "GET",
+        &format!(
+            "/api/admin/work-schedule-assignments?user_id={}",
+            employee.id
+        ),
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let assignments = response_json(response).await;
+    assert_eq!(assignments.as_array().map(Vec::len), Some(1));
+
+    let response = send_request(
+        &admin_app,
+        "DELETE",
+        &format!("/api/admin/work-schedules/{id}"),
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::CONFLICT);
+
+    let response = send_request(
+        &admin_app,
+        "DELETE",
+        &format!("/api/admin/work-schedule-assignments/{assignment_id}"),
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let response = send_request(
+        &admin_app,
+        "DELETE",
+        &format!("/api/admin/work-schedules/{id}"),
+        None,
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn assigned_schedule_drives_late_and_half_day_status() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let department_id = seed_department_member(&pool, employee.id).await;
+    let app = schedule_router(pool.clone(), admin);
+
+    // 2033-03-07 is a Monday.
+    let department_schedule = create_schedule(
+        &app,
+        json!({
+            "name": unique_name("Office"),
+            "start_time": "09:00:00",
+            "end_time": "18:00:00",
+            "late_grace_minutes": 10
+        }),
+    )
+    .await;
+    assign(
+        &app,
+        json!({
+            "schedule_id": department_schedule,
+            "department_id": department_id,
+            "effective_from": "2033-03-01"
+        }),
+    )
+    .await;
+
+    let status = upsert_status(
+        &app,
+        &employee,
+        "2033-03-07T09:08:00",
+        "2033-03-07T18:00:00",
+    )
+    .await;
+    assert_eq!(status, "present");
+    let status = upsert_status(
+        &app,
+        &employee,
+        "2033-03-07T09:11:00",
+        "2033-03-07T18:00:00",
+    )
+    .await;
+    assert_eq!(status, "late");
+    let status = upsert_status(
+        &app,
+        &employee,
+        "2033-03-07T09:00:00",
+        "2033-03-07T12:00:00",
+    )
+    .await;
+    assert_eq!(status, "half_day");
+    // Saturdays are off in the default pattern, so nothing is expected of them.
+    let status = upsert_status(
+        &app,
+        &employee,
+        "2033-03-12T11:00:00",
+        "2033-03-12T12:00:00",
+    )
+    .await;
+    assert_eq!(status, "present");
+
+    // A personal assignment overrides the department's from its effective date.
+    let personal_schedule = create_schedule(
+        &app,
+        json!({
+            "name": unique_name("Afternoon"),
+            "start_time": "13:00:00",
+            "end_time": "17:00:00",
+            "break_minutes": 0,
+            "working_weekdays": [0, 1, 2, 3, 4, 5, 6]
+        }),
+    )
+    .await;
+    assign(
+        &app,
+        json!({
+            "schedule_id": personal_schedule,
+            "user_id": employee.id.to_string(),
+            "effective_from": "2033-03-10",
+            "effective_to": "2033-03-31"
+        }),
+    )
+    .await;
+
+    let status = upsert_status(
+        &app,
+        &employee,
+        "2033-03-09T09:11:00",
+        "2033-03-09T18:00:00",
+    )
+    .await;
+    assert_eq!(status, "late");
+    let status = upsert_status(
+        &app,
+        &employee,
+        "2033-03-10T13:00:00",
+        "2033-03-10T17:00:00",
+    )
+    .await;
+    assert_eq!(status, "present");
+    let status = upsert_status(
+        &app,
+        &employee,
+        "2033-03-12T13:01:00",
+        "2033-03-12T17:00:00",
+    )
+    .await;
+    assert_eq!(status, "late");
+    let status = upsert_status(
+        &app,
+        &employee,
+        "2033-04-01T09:11:00",
+        "2033-04-01T18:00:00",
+    )
+    .await;
+    assert_eq!(status, "late");
+}
+
+#[tokio::test]
+async fn overnight_schedule_keeps_the_shift_on_its_start_date() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let admin = seed_user(&pool, UserRole::Manager, true).await;
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let app = schedule_router(pool.clone(), admin);
+
+    // 2033-05-02 is a Monday.
+    let night_shift = create_schedule(
+        &app,
+        json!({
+            "name": unique_name("Night"),
+            "start_time": "22:00:00",
+            "end_time": "06:00:00",
+            "late_grace_minutes": 5
+        }),
+    )
+    .await;
+    assign(
+        &app,
+        json!({
+            "schedule_id": night_shift,
+            "user_id": employee.id.to_string(),
+            "effective_from": "2033-05-01"
+        }),
+    )
+    .await;
+
+    let service = WorkScheduleService::new(pool.clone());
+    let monday = NaiveDate::from_ymd_opt(2033, 5, 2).expect("monday");
+    let tuesday = monday.succ_opt().expect("tuesday");
+    let before_shift_end = tuesday.and_hms_opt(5, 30, 0).expect("time");
+    let after_shift_end = tuesday.and_hms_opt(7, 0, 0).expect("time");
+    assert_eq!(
+        service
+            .business_date(employee.id, before_shift_end, 0)
+            .await
+            .expect("business date"),
+        monday
+    );
+    assert_eq!(
+        service
+            .business_date(employee.id, after_shift_end, 0)
+            .await
+            .expect("business date"),
+        tuesday
+    );
+
+    let response = send_request(
+        &app,
+        "PUT",
+        "/api/admin/attendance",
+        Some(json!({
+            "user_id": employee.id.to_string(),
+            "date": "2033-05-02",
+            "clock_in_time": "2033-05-02T21:58:00",
+            "clock_out_time": "2033-05-03T06:04:00",
+            "breaks": [{
+                "break_start_time": "2033-05-03T02:00:00",
+                "break_end_time": "2033-05-03T03:00:00"
+            }]
+        })),
+    )
+    .await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let body = response_json(response).await;
+    assert_eq!(body["status"], "present");
+    assert_eq!(body["total_work_hours"], 7.1);
+
+    let status = upsert_status(
+        &app,
+        &employee,
+        "2033-05-03T22:06:00",
+        "2033-05-04T06:00:00",
+    )
+    .await;
+    assert_eq!(status, "late");
+}
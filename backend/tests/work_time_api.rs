@@ -0,0 +1,186 @@
+use axum::{http::StatusCode, Extension, Router};
+use chrono::NaiveDate;
+use serde_json::json;
+use sqlx::PgPool;
+use std::sync::Arc;
+use timekeeper_backend::{
+    handlers::{admin, attendance},
+    models::user::{User, UserRole},
+    services::{
+        holiday::{HolidayService, HolidayServiceTrait},
+        work_time::WorkTimeService,
+    },
+    state::AppState,
+};
+
+mod support;
+
+use support::{
+    seed_holiday_exception, seed_public_holiday, seed_user, send, test_config, test_pool,
+};
+
+async fn integration_guard() -> tokio::sync::MutexGuard<'static, ()> {
+    static GUARD: std::sync::OnceLock<tokio::sync::Mutex<()>> = std::sync::OnceLock::new();
+    GUARD
+        .get_or_init(|| tokio::sync::Mutex::new(()))
+        .lock()
+        .await
+}
+
+async fn migrate(pool: &PgPool) {
+    sqlx::migrate!("./migrations")
+        .run(pool)
+        .await
+        .expect("run migrations");
+}
+
+fn work_time_router(pool: PgPool, user: User) -> Router {
+    let state = AppState::new(pool.clone(), None, None, None, test_config());
+    let holiday_service: Arc<dyn HolidayServiceTrait> = Arc::new(HolidayService::new(pool));
+    Router::new()
+        .route(
+            "/api/admin/attendance",
+            axum::routing::put(admin::upsert_attendance),
+        )
+        .route(
+            "/api/attendance/clock-in",
+            axum::routing::post(attendance::clock_in),
+        )
+        .route(
+            "/api/attendance/me/summary",
+            axum::routing::get(attendance::get_my_summary),
+        )
+        .layer(Extension(user))
+        .layer(Extension(holiday_service))
+        .with_state(state)
+}
+
+fn date(value: &str) -> NaiveDate {
+    NaiveDate::parse_from_str(value, "%Y-%m-%d").expect("valid date")
+}
+
+#[tokio::test]
+async fn worked_hours_are_split_into_premium_buckets() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    let system_admin = seed_user(&pool, UserRole::Manager, true).await;
+    // Pin Monday as a workday and Sunday as a holiday regardless of shared holiday fixtures.
+    seed_holiday_exception(&pool, employee.id, date("2031-09-01"), false, "workday").await;
+    sqlx::query("DELETE FROM holidays WHERE holiday_date = $1")
+        .bind(date("2031-09-07"))
+        .execute(&pool)
+        .await
+        .expect("clear holiday");
+    seed_public_holiday(&pool, date("2031-09-07"), "Rest day").await;
+
+    let admin_app = work_time_router(pool.clone(), system_admin);
+    let (status, body) = send(
+        &admin_app,
+        "PUT",
+        "/api/admin/attendance",
+        Some(json!({
+            "user_id": employee.id.to_string(),
+            "date": "2031-09-01",
+            "clock_in_time": "2031-09-01T13:00:00",
+            "clock_out_time": "2031-09-01T23:30:00",
+            "breaks": [
+                {
+                    "break_start_time": "2031-09-01T18:00:00",
+                    "break_end_time": "2031-09-01T19:00:00"
+                }
+            ]
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["work_time"]["regular_hours"], 8.0);
+    assert_eq!(body["work_time"]["statutory_overtime_hours"], 1.5);
+    assert_eq!(body["work_time"]["late_night_hours"], 1.5);
+
+    let (status, body) = send(
+        &admin_app,
+        "PUT",
+        "/api/admin/attendance",
+        Some(json!({
+            "user_id": employee.id.to_string(),
+            "date": "2031-09-07",
+            "clock_in_time": "2031-09-07T09:00:00",
+            "clock_out_time": "2031-09-07T19:00:00"
+        })),
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(body["work_time"]["legal_holiday_hours"], 10.0);
+    assert_eq!(body["work_time"]["statutory_overtime_hours"], 0.0);
+
+    let employee_app = work_time_router(pool.clone(), employee);
+    let (status, summary) = send(
+        &employee_app,
+        "GET",
+        "/api/attendance/me/summary?year=2031&month=9",
+        None,
+    )
+    .await;
+    assert_eq!(status, StatusCode::OK);
+    assert_eq!(summary["work_time"]["regular_hours"], 8.0);
+    assert_eq!(summary["work_time"]["statutory_overtime_hours"], 1.5);
+    assert_eq!(summary["work_time"]["late_night_hours"], 1.5);
+    assert_eq!(summary["work_time"]["legal_holiday_hours"], 10.0);
+}
+
+#[tokio::test]
+async fn clock_in_on_a_holiday_is_classified_as_holiday_work() {
+    let _guard = integration_guard().await;
+    let pool = test_pool().await;
+    migrate(&pool).await;
+
+    let sunday = date("2031-09-14");
+    let employee = seed_user(&pool, UserRole::Employee, false).await;
+    sqlx::query("DELETE FROM holidays WHERE holiday_date = $1")
+        .bind(sunday)
+        .execute(&pool)
+        .await
+        .expect("clear holiday");
+    seed_public_holiday(&pool, sunday, "Holiday shift").await;
+
+    let app = work_time_router(pool.clone(), employee.clone());
+    let clock_in = json!({ "date": "2031-09-14" });
+    let (status, _) = send(
+        &app,
+        "POST",
+        "/api/attendance/clock-in",
+        Some(clock_in.clone()),
+    )
+    .await;
+    assert_ne!(status, StatusCode::OK);
+
+    // Working the holiday takes a workday exception.
+    seed_holiday_exception(&pool, employee.id, sunday, false, "holiday shift").await;
+    let (status, body) = send(&app, "POST", "/api/attendance/clock-in", Some(clock_in)).await;
+    assert_eq!(status, StatusCode::OK);
+    let attendance_id = body["id"].as_str().expect("attendance id").to_string();
+
+    sqlx::query(
+        "UPDATE attendance SET clock_in_time = '2031-09-14 09:00:00', \
+             clock_out_time = '2031-09-14 17:00:00' \
+         WHERE id = $1",
+    )
+    .bind(&attendance_id)
+    .execute(&pool)
+    .await
+    .expect("set shift times");
+    let breakdowns = WorkTimeService::new(pool.clone())
+        .refresh_week(employee.id, sunday)
+        .await
+        .expect("refresh week");
+    let breakdown = breakdowns
+        .iter()
+        .find(|(id, _)| id.to_string() == attendance_id)
+        .map(|(_, breakdown)| *breakdown)
+        .expect("breakdown for the holiday shift");
+    assert_eq!(breakdown.legal_holiday_hours, 8.0);
+    assert_eq!(breakdown.regular_hours, 0.0);
+}
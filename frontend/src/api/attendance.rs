@@ -5,7 +5,7 @@ use super::{
     client::{encode_path_segment, ApiClient},
     types::{
         ActiveBreakResponse, AdminAttendanceUpsert, ApiError, AttendanceResponse,
-        AttendanceStatusResponse, AttendanceSummary, BreakRecordResponse,
+        AttendanceStatusResponse, AttendanceSummary, BreakRecordResponse, PeriodClosing,
     },
 };
 
@@ -358,6 +358,31 @@ impl ApiClient {
         }
     }
 
+    pub async fn get_my_closed_periods(&self) -> Result<Vec<PeriodClosing>, ApiError> {
+        let base_url = self.resolved_base_url().await;
+        let response = self
+            .send_with_refresh(|| {
+                Ok(self
+                    .http_client()
+                    .get(format!("{}/attendance/me/closed-periods", base_url)))
+            })
+            .await?;
+        let status = response.status();
+        Self::handle_unauthorized_status(status);
+        if status.is_success() {
+            response
+                .json()
+                .await
+                .map_err(|e| ApiError::unknown(format!("Failed to parse response: {}", e)))
+        } else {
+            let error: ApiError = response
+                .json()
+                .await
+                .map_err(ApiClient::map_error_payload_parse_failure)?;
+            Err(error)
+        }
+    }
+
     pub async fn export_my_attendance_filtered(
         &self,
         from: Option<&str>,
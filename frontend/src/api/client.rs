@@ -766,6 +766,8 @@ impl ApiClient {
         username: Option<&str>,
         from: Option<&str>,
         to: Option<&str>,
+        encryption: Option<ExportEncryptionMethod>,
+        recipient: Option<&str>,
     ) -> Result<PiiProtectedResponse<serde_json::Value>, ApiError> {
         let base_url = self.resolved_base_url().await;
         let mut params: Vec<(&str, String)> = Vec::new();
@@ -784,6 +786,18 @@ impl ApiClient {
                 params.push(("to", t.to_string()));
             }
         }
+        match encryption {
+            Some(ExportEncryptionMethod::Age) => params.push(("encryption", "age".to_string())),
+            Some(ExportEncryptionMethod::Passphrase) => {
+                params.push(("encryption", "passphrase".to_string()))
+            }
+            None => {}
+        }
+        if let Some(r) = recipient {
+            if !r.is_empty() {
+                params.push(("recipient", r.to_string()));
+            }
+        }
 
         let response = self
             .send_with_refresh(|| {
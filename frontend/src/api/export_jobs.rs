@@ -0,0 +1,115 @@
+use serde::de::DeserializeOwned;
+
+use super::{
+    client::{encode_path_segment, ApiClient},
+    types::{
+        ApiError, CreateExportJobRequest, ExportDownloadResponse, ExportJob, ExportJobListResponse,
+    },
+};
+
+impl ApiClient {
+    pub async fn create_export_job(
+        &self,
+        request: &CreateExportJobRequest,
+    ) -> Result<ExportJob, ApiError> {
+        let base_url = self.resolved_base_url().await;
+        let response = self
+            .send_with_refresh(|| {
+                Ok(self
+                    .http_client()
+                    .post(format!("{}/admin/export-jobs", base_url))
+                    .json(request))
+            })
+            .await?;
+        Self::parse_export_job_response(response).await
+    }
+
+    pub async fn list_export_jobs(
+        &self,
+        limit: i64,
+        offset: i64,
+    ) -> Result<ExportJobListResponse, ApiError> {
+        let base_url = self.resolved_base_url().await;
+        let response = self
+            .send_with_refresh(|| {
+                Ok(self
+                    .http_client()
+                    .get(format!("{}/admin/export-jobs", base_url))
+                    .query(&[("limit", limit.to_string()), ("offset", offset.to_string())]))
+            })
+            .await?;
+        Self::parse_export_job_response(response).await
+    }
+
+    /// Fetches a short-lived link to a completed job's file.
+    ///
+    /// Links served by the API itself come back relative to its origin and are
+    /// resolved against the configured base URL.
+    pub async fn get_export_job_download(
+        &self,
+        id: &str,
+    ) -> Result<ExportDownloadResponse, ApiError> {
+        let base_url = self.resolved_base_url().await;
+        let encoded_id = encode_path_segment(id);
+        let response = self
+            .send_with_refresh(|| {
+                Ok(self.http_client().get(format!(
+                    "{}/admin/export-jobs/{}/download",
+                    base_url, encoded_id
+                )))
+            })
+            .await?;
+        let mut download: ExportDownloadResponse =
+            Self::parse_export_job_response(response).await?;
+        download.url = resolve_download_url(&base_url, &download.url);
+        Ok(download)
+    }
+
+    async fn parse_export_job_response<R: DeserializeOwned>(
+        response: reqwest::Response,
+    ) -> Result<R, ApiError> {
+        let status = response.status();
+        Self::handle_unauthorized_status(status);
+        if status.is_success() {
+            response
+                .json()
+                .await
+                .map_err(|e| ApiError::unknown(format!("Failed to parse response: {}", e)))
+        } else {
+            let error: ApiError = response
+                .json()
+                .await
+                .map_err(ApiClient::map_error_payload_parse_failure)?;
+            Err(error)
+        }
+    }
+}
+
+/// `base_url` already ends in `/api`, as does the path of a relative link.
+fn resolve_download_url(base_url: &str, url: &str) -> String {
+    match url.strip_prefix("/api") {
+        Some(path) => format!("{}{}", base_url.trim_end_matches('/'), path),
+        None => url.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::resolve_download_url;
+
+    #[test]
+    fn relative_download_links_resolve_against_the_api_base() {
+        assert_eq!(
+            resolve_download_url("https://tk.example.com/api", "/api/export-downloads/j1?x=1"),
+            "https://tk.example.com/api/export-downloads/j1?x=1"
+        );
+        assert_eq!(
+            resolve_download_url("/api/", "/api/export-downloads/j1"),
+            "/api/export-downloads/j1"
+        );
+        assert_eq!(
+            resolve_download_url("/api", "https://bucket.s3.amazonaws.com/k?sig=1"),
+            "https://bucket.s3.amazonaws.com/k?sig=1"
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use serde::de::DeserializeOwned;
+
+use super::{
+    client::{encode_path_segment, ApiClient},
+    types::{ApiError, HealthInterview, LongHoursEmployee, RecordHealthInterviewRequest},
+};
+
+impl ApiClient {
+    pub async fn admin_get_long_hours_report(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<Vec<LongHoursEmployee>, ApiError> {
+        let base_url = self.resolved_base_url().await;
+        let response = self
+            .send_with_refresh(|| {
+                Ok(self
+                    .http_client()
+                    .get(format!("{}/admin/health/long-hours", base_url))
+                    .query(&[("year", year.to_string()), ("month", month.to_string())]))
+            })
+            .await?;
+        Self::parse_health_response(response).await
+    }
+
+    pub async fn admin_list_health_interviews(&self) -> Result<Vec<HealthInterview>, ApiError> {
+        let base_url = self.resolved_base_url().await;
+        let response = self
+            .send_with_refresh(|| {
+                Ok(self
+                    .http_client()
+                    .get(format!("{}/admin/health/interviews", base_url)))
+            })
+            .await?;
+        Self::parse_health_response(response).await
+    }
+
+    pub async fn admin_record_health_interview(
+        &self,
+        user_id: &str,
+        year: i32,
+        month: u32,
+        request: &RecordHealthInterviewRequest,
+    ) -> Result<HealthInterview, ApiError> {
+        let base_url = self.resolved_base_url().await;
+        let encoded_id = encode_path_segment(user_id);
+        let response = self
+            .send_with_refresh(|| {
+                Ok(self
+                    .http_client()
+                    .put(format!(
+                        "{}/admin/health/interviews/{}/{}/{}",
+                        base_url, encoded_id, year, month
+                    ))
+                    .json(request))
+            })
+            .await?;
+        Self::parse_health_response(response).await
+    }
+
+    async fn parse_health_response<R: DeserializeOwned>(
+        response: reqwest::Response,
+    ) -> Result<R, ApiError> {
+        let status = response.status();
+        Self::handle_unauthorized_status(status);
+        if status.is_success() {
+            response
+                .json()
+                .await
+                .map_err(|e| ApiError::unknown(format!("Failed to parse response: {}", e)))
+        } else {
+            let error: ApiError = response
+                .json()
+                .await
+                .map_err(ApiClient::map_error_payload_parse_failure)?;
+            Err(error)
+        }
+    }
+}
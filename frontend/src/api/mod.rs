@@ -2,8 +2,12 @@ mod attendance;
 mod audit_log;
 mod auth;
 pub mod client;
+mod export_jobs;
+mod health;
+mod leave;
 mod requests;
 mod subject_requests;
+mod timesheets;
 pub mod types;
 
 pub use audit_log::AuditLogQuery;
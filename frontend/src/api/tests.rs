@@ -376,7 +376,13 @@ async fn api_client_admin_and_auth_endpoints_succeed() {
     );
 
     let export = client
-        .export_data_filtered_with_policy(Some("alice"), Some("2025-01-01"), Some("2025-01-31"))
+        .export_data_filtered_with_policy(
+            Some("alice"),
+            Some("2025-01-01"),
+            Some("2025-01-31"),
+            None,
+            None,
+        )
         .await
         .unwrap();
     assert_eq!(export.data["filename"], "export.csv");
@@ -450,6 +456,16 @@ async fn api_client_attendance_and_requests_endpoints_succeed() {
         when.method(GET).path("/api/attendance/me/summary");
         then.status(200).json_body(attendance_summary_json());
     });
+    server.mock(|when, then| {
+        when.method(GET).path("/api/attendance/me/closed-periods");
+        then.status(200).json_body(json!([{
+            "id": "pc-1",
+            "period_month": "2025-01-01",
+            "department_id": null,
+            "closed_by": "admin",
+            "closed_at": "2025-02-05T09:00:00Z"
+        }]));
+    });
     server.mock(|when, then| {
         when.method(GET).path("/api/attendance/export");
         then.status(200)
@@ -531,6 +547,9 @@ async fn api_client_attendance_and_requests_endpoints_succeed() {
     assert_eq!(client.admin_list_active_breaks().await.unwrap().len(), 1);
     let summary = client.get_my_summary(Some(2025), Some(1)).await.unwrap();
     assert_eq!(summary.total_work_days, 20);
+    let closed = client.get_my_closed_periods().await.unwrap();
+    assert!(closed[0].covers(chrono::NaiveDate::from_ymd_opt(2025, 1, 31).unwrap()));
+    assert!(!closed[0].covers(chrono::NaiveDate::from_ymd_opt(2025, 2, 1).unwrap()));
     let export = client
         .export_my_attendance_filtered(Some("2025-01-01"), Some("2025-01-31"))
         .await
@@ -550,6 +569,8 @@ async fn api_client_attendance_and_requests_endpoints_succeed() {
                 leave_type: "annual".into(),
                 start_date: chrono::NaiveDate::from_ymd_opt(2025, 1, 10).unwrap(),
                 end_date: chrono::NaiveDate::from_ymd_opt(2025, 1, 12).unwrap(),
+                unit: "full".into(),
+                hours: None,
                 reason: Some("updated".into()),
             },
         )
@@ -572,6 +593,8 @@ async fn api_client_attendance_and_requests_endpoints_succeed() {
             leave_type: "annual".into(),
             start_date: chrono::NaiveDate::from_ymd_opt(2025, 1, 10).unwrap(),
             end_date: chrono::NaiveDate::from_ymd_opt(2025, 1, 12).unwrap(),
+            unit: "full".into(),
+            hours: None,
             reason: None,
         })
         .await
@@ -688,6 +711,87 @@ async fn api_client_subject_request_and_audit_log_endpoints_succeed() {
         .unwrap();
 }
 
+fn timesheet_json(id: &str, status: &str) -> serde_json::Value {
+    json!({
+        "id": id,
+        "user_id": "u1",
+        "period_month": "2025-03-01",
+        "status": status,
+        "submitted_at": "2025-04-01T09:00:00Z",
+        "decided_by": null,
+        "decided_at": null,
+        "decision_comment": null,
+        "created_at": "2025-04-01T09:00:00Z",
+        "updated_at": "2025-04-01T09:00:00Z"
+    })
+}
+
+#[tokio::test]
+async fn api_client_timesheet_endpoints_succeed() {
+    let server = MockServer::start_async().await;
+    server.mock(|when, then| {
+        when.method(GET).path("/api/timesheets/me");
+        then.status(200)
+            .json_body(json!([timesheet_json("ts-1", "submitted")]));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/api/timesheets/me/month");
+        then.status(200).json_body(json!({
+            "period_month": "2025-03-01",
+            "submission": null,
+            "blockers": { "pending_corrections": 1, "open_clock_ins": 0 }
+        }));
+    });
+    server.mock(|when, then| {
+        when.method(POST).path("/api/timesheets/me");
+        then.status(201)
+            .json_body(timesheet_json("ts-1", "submitted"));
+    });
+    server.mock(|when, then| {
+        when.method(GET).path("/api/admin/timesheets");
+        then.status(200).json_body(json!([{
+            "username": "alice",
+            "id": "ts-1",
+            "user_id": "u1",
+            "period_month": "2025-03-01",
+            "status": "submitted",
+            "submitted_at": "2025-04-01T09:00:00Z"
+        }]));
+    });
+    server.mock(|when, then| {
+        when.method(POST).path("/api/admin/timesheets/ts-1/approve");
+        then.status(200)
+            .json_body(timesheet_json("ts-1", "approved"));
+    });
+    server.mock(|when, then| {
+        when.method(POST).path("/api/admin/timesheets/ts-1/return");
+        then.status(200)
+            .json_body(timesheet_json("ts-1", "returned"));
+    });
+
+    let client = api_client(&server);
+    let history = client.get_my_timesheets().await.unwrap();
+    assert_eq!(history[0].status, TimesheetStatus::Submitted);
+    let month = client.get_my_timesheet_month(2025, 3).await.unwrap();
+    assert!(month.submission.is_none());
+    assert!(!month.blockers.is_clear());
+    let submitted = client.submit_my_timesheet(2025, 3).await.unwrap();
+    assert_eq!(submitted.id, "ts-1");
+    let listed = client
+        .admin_list_timesheets(Some("submitted"))
+        .await
+        .unwrap();
+    assert_eq!(listed[0].username, "alice");
+    assert_eq!(listed[0].submission.id, "ts-1");
+    let approved = client.admin_approve_timesheet("ts-1", None).await.unwrap();
+    assert_eq!(approved.status, TimesheetStatus::Approved);
+    let returned = client
+        .admin_return_timesheet("ts-1", "missing visit")
+        .await
+        .unwrap();
+    assert_eq!(returned.status, TimesheetStatus::Returned);
+}
+
 #[tokio::test]
 async fn api_client_encodes_dynamic_path_segments_for_breaks_and_subject_requests() {
     let server = MockServer::start_async().await;
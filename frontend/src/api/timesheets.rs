@@ -0,0 +1,132 @@
+use serde::de::DeserializeOwned;
+use serde_json::json;
+
+use super::{
+    client::{encode_path_segment, ApiClient},
+    types::{ApiError, TimesheetMonthStatus, TimesheetSubmission, TimesheetSubmissionListItem},
+};
+
+impl ApiClient {
+    pub async fn get_my_timesheets(&self) -> Result<Vec<TimesheetSubmission>, ApiError> {
+        let base_url = self.resolved_base_url().await;
+        let response = self
+            .send_with_refresh(|| {
+                Ok(self
+                    .http_client()
+                    .get(format!("{}/timesheets/me", base_url)))
+            })
+            .await?;
+        Self::parse_timesheet_response(response).await
+    }
+
+    pub async fn get_my_timesheet_month(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<TimesheetMonthStatus, ApiError> {
+        let base_url = self.resolved_base_url().await;
+        let response = self
+            .send_with_refresh(|| {
+                Ok(self
+                    .http_client()
+                    .get(format!("{}/timesheets/me/month", base_url))
+                    .query(&[("year", year.to_string()), ("month", month.to_string())]))
+            })
+            .await?;
+        Self::parse_timesheet_response(response).await
+    }
+
+    pub async fn submit_my_timesheet(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<TimesheetSubmission, ApiError> {
+        let base_url = self.resolved_base_url().await;
+        let response = self
+            .send_with_refresh(|| {
+                Ok(self
+                    .http_client()
+                    .post(format!("{}/timesheets/me", base_url))
+                    .json(&json!({ "year": year, "month": month })))
+            })
+            .await?;
+        Self::parse_timesheet_response(response).await
+    }
+
+    pub async fn admin_list_timesheets(
+        &self,
+        status: Option<&str>,
+    ) -> Result<Vec<TimesheetSubmissionListItem>, ApiError> {
+        let base_url = self.resolved_base_url().await;
+        let response = self
+            .send_with_refresh(|| {
+                let mut request = self
+                    .http_client()
+                    .get(format!("{}/admin/timesheets", base_url));
+                if let Some(status) = status {
+                    request = request.query(&[("status", status)]);
+                }
+                Ok(request)
+            })
+            .await?;
+        Self::parse_timesheet_response(response).await
+    }
+
+    pub async fn admin_approve_timesheet(
+        &self,
+        id: &str,
+        comment: Option<&str>,
+    ) -> Result<TimesheetSubmission, ApiError> {
+        self.admin_decide_timesheet(id, "approve", comment).await
+    }
+
+    pub async fn admin_return_timesheet(
+        &self,
+        id: &str,
+        comment: &str,
+    ) -> Result<TimesheetSubmission, ApiError> {
+        self.admin_decide_timesheet(id, "return", Some(comment))
+            .await
+    }
+
+    async fn admin_decide_timesheet(
+        &self,
+        id: &str,
+        action: &str,
+        comment: Option<&str>,
+    ) -> Result<TimesheetSubmission, ApiError> {
+        let base_url = self.resolved_base_url().await;
+        let encoded_id = encode_path_segment(id);
+        let response = self
+            .send_with_refresh(|| {
+                Ok(self
+                    .http_client()
+                    .post(format!(
+                        "{}/admin/timesheets/{}/{}",
+                        base_url, encoded_id, action
+                    ))
+                    .json(&json!({ "comment": comment })))
+            })
+            .await?;
+        Self::parse_timesheet_response(response).await
+    }
+
+    async fn parse_timesheet_response<R: DeserializeOwned>(
+        response: reqwest::Response,
+    ) -> Result<R, ApiError> {
+        let status = response.status();
+        Self::handle_unauthorized_status(status);
+        if status.is_success() {
+            response
+                .json()
+                .await
+                .map_err(|e| ApiError::unknown(format!("Failed to parse response: {}", e)))
+        } else {
+            let error: ApiError = response
+                .json()
+                .await
+                .map_err(ApiClient::map_error_payload_parse_failure)?;
+            Err(error)
+        }
+    }
+}
@@ -1,4 +1,4 @@
-use chrono::{DateTime, NaiveDate, NaiveDateTime, Utc};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, Utc};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
 
@@ -228,6 +228,206 @@ pub struct BreakRecordResponse {
     pub duration_minutes: Option<i32>,
 }
 
+/// A month closed for payroll; the user's attendance and requests in it are read-only.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PeriodClosing {
+    pub id: String,
+    /// First day of the closed month.
+    pub period_month: NaiveDate,
+    #[serde(default)]
+    pub department_id: Option<String>,
+    pub closed_at: DateTime<Utc>,
+}
+
+impl PeriodClosing {
+    pub fn covers(&self, date: NaiveDate) -> bool {
+        date.year() == self.period_month.year() && date.month() == self.period_month.month()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TimesheetStatus {
+    Submitted,
+    Approved,
+    Returned,
+}
+
+/// An employee's timesheet for one month, submitted for manager sign-off.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimesheetSubmission {
+    pub id: String,
+    pub user_id: String,
+    /// First day of the submitted month.
+    pub period_month: NaiveDate,
+    pub status: TimesheetStatus,
+    pub submitted_at: DateTime<Utc>,
+    #[serde(default)]
+    pub decided_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub decision_comment: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimesheetSubmissionListItem {
+    #[serde(flatten)]
+    pub submission: TimesheetSubmission,
+    pub username: String,
+}
+
+/// Open items that must be resolved before a month can be submitted.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TimesheetBlockers {
+    pub pending_corrections: i64,
+    pub open_clock_ins: i64,
+}
+
+impl TimesheetBlockers {
+    pub fn is_clear(&self) -> bool {
+        self.pending_corrections == 0 && self.open_clock_ins == 0
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TimesheetMonthStatus {
+    pub period_month: NaiveDate,
+    #[serde(default)]
+    pub submission: Option<TimesheetSubmission>,
+    #[serde(default)]
+    pub blockers: TimesheetBlockers,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportJobKind {
+    Attendance,
+    AuditLogs,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportJobStatus {
+    Queued,
+    Running,
+    Completed,
+    Failed,
+}
+
+/// How an export file is encrypted with age.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ExportEncryptionMethod {
+    /// For the holder of an age key, given its public key.
+    Age,
+    /// With a one-time passphrase the server generates.
+    Passphrase,
+}
+
+/// An export the server writes in the background.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExportJob {
+    pub id: String,
+    pub kind: ExportJobKind,
+    pub status: ExportJobStatus,
+    pub rows_written: i64,
+    /// Known once the worker has counted the matching rows.
+    #[serde(default)]
+    pub total_rows: Option<i64>,
+    #[serde(default)]
+    pub filename: Option<String>,
+    #[serde(default)]
+    pub size_bytes: Option<i64>,
+    #[serde(default)]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub encryption: Option<ExportEncryptionMethod>,
+    /// Only returned when a passphrase-encrypted job is queued.
+    #[serde(default)]
+    pub passphrase: Option<String>,
+    pub created_at: DateTime<Utc>,
+    #[serde(default)]
+    pub finished_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CreateExportJobRequest {
+    pub kind: ExportJobKind,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encryption: Option<ExportEncryptionMethod>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub recipient: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportJobListResponse {
+    pub data: Vec<ExportJob>,
+    pub total: i64,
+    pub limit: i64,
+    pub offset: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportDownloadResponse {
+    pub url: String,
+    pub expires_at: DateTime<Utc>,
+}
+
+/// Where a physician interview for long working hours stands.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum HealthInterviewStatus {
+    Offered,
+    Completed,
+    Declined,
+}
+
+/// A physician interview recorded for one employee and month.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HealthInterview {
+    pub user_id: String,
+    pub username: String,
+    pub year: i32,
+    pub month: u32,
+    /// Overtime of the month when the interview was last recorded.
+    pub overtime_hours: f64,
+    pub status: HealthInterviewStatus,
+    #[serde(default)]
+    pub notes: Option<String>,
+    pub offered_at: DateTime<Utc>,
+    #[serde(default)]
+    pub completed_at: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// An employee over the physician interview threshold in a month.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LongHoursEmployee {
+    pub user_id: String,
+    pub username: String,
+    #[serde(default)]
+    pub department_id: Option<String>,
+    pub year: i32,
+    pub month: u32,
+    pub worked_hours: f64,
+    /// Hours worked beyond 40 per week over the month.
+    pub overtime_hours: f64,
+    #[serde(default)]
+    pub interview: Option<HealthInterview>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RecordHealthInterviewRequest {
+    pub status: HealthInterviewStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub notes: Option<String>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AttendanceSummary {
     pub month: u32,
@@ -235,6 +435,31 @@ pub struct AttendanceSummary {
     pub total_work_hours: f64,
     pub total_work_days: i32,
     pub average_daily_hours: f64,
+    /// Working days recorded as absent.
+    #[serde(default)]
+    pub absent_days: i32,
+    /// Working days on approved leave; half days count as 0.5.
+    #[serde(default)]
+    pub leave_days: f64,
+    /// Hours of the leave days taken as paid leave.
+    #[serde(default)]
+    pub paid_leave_hours: f64,
+    /// Present only for employees on flextime.
+    #[serde(default)]
+    pub flextime: Option<FlextimeBalance>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FlextimeBalance {
+    pub period_start: NaiveDate,
+    pub period_end: NaiveDate,
+    pub as_of: NaiveDate,
+    pub period_required_hours: f64,
+    pub required_hours_to_date: f64,
+    pub worked_hours: f64,
+    pub balance_hours: f64,
+    #[serde(default)]
+    pub core_time_missed_dates: Vec<NaiveDate>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -242,6 +467,8 @@ pub struct CreateLeaveRequest {
     pub leave_type: String,
     pub start_date: NaiveDate,
     pub end_date: NaiveDate,
+    pub unit: String,
+    pub hours: Option<f64>,
     pub reason: Option<String>,
 }
 
@@ -250,6 +477,8 @@ pub struct UpdateLeaveRequest {
     pub leave_type: String,
     pub start_date: NaiveDate,
     pub end_date: NaiveDate,
+    pub unit: String,
+    pub hours: Option<f64>,
     pub reason: Option<String>,
 }
 
@@ -260,6 +489,10 @@ pub struct LeaveRequestResponse {
     pub leave_type: String,
     pub start_date: NaiveDate,
     pub end_date: NaiveDate,
+    #[serde(default = "default_leave_unit")]
+    pub unit: String,
+    #[serde(default)]
+    pub hours: Option<f64>,
     pub reason: Option<String>,
     pub status: String,
     pub approved_by: Option<String>,
@@ -271,6 +504,35 @@ pub struct LeaveRequestResponse {
     pub created_at: String,
 }
 
+fn default_leave_unit() -> String {
+    "full".to_string()
+}
+
+/// Leave type offered on the leave request form.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LeaveTypeOption {
+    pub code: String,
+    pub name: String,
+    #[serde(default)]
+    pub paid: bool,
+    #[serde(default)]
+    pub balance_tracked: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AnnualLeaveComplianceItem {
+    pub user_id: String,
+    pub username: String,
+    pub grant_date: NaiveDate,
+    pub granted_days: f64,
+    pub deadline: NaiveDate,
+    pub days_taken: f64,
+    pub days_scheduled: f64,
+    pub days_remaining: f64,
+    pub days_until_deadline: i64,
+    pub status: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CreateOvertimeRequest {
     pub date: NaiveDate,
@@ -546,6 +808,8 @@ mod tests {
             leave_type: "annual".into(),
             start_date: chrono::NaiveDate::from_ymd_opt(2025, 1, 2).unwrap(),
             end_date: chrono::NaiveDate::from_ymd_opt(2025, 1, 3).unwrap(),
+            unit: "full".into(),
+            hours: None,
             reason: None,
         };
         let v = serde_json::to_value(&req).unwrap();
@@ -554,6 +818,8 @@ mod tests {
         assert_eq!(v["end_date"], serde_json::json!("2025-01-03"));
         assert!(v.get("reason").is_some());
         assert!(v["reason"].is_null());
+        assert_eq!(v["unit"], serde_json::json!("full"));
+        assert!(v["hours"].is_null());
     }
 
     #[wasm_bindgen_test]
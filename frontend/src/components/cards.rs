@@ -266,6 +266,10 @@ mod host_tests {
                         total_work_hours: Some(160.0),
                         total_work_days: Some(20),
                         average_daily_hours: Some(8.0),
+                        absent_days: None,
+                        leave_days: None,
+                        paid_leave_hours: None,
+                        flextime_balance_hours: None,
                     })
                 },
             );
@@ -273,6 +277,10 @@ mod host_tests {
                 total_work_hours: Some(160.0),
                 total_work_days: Some(20),
                 average_daily_hours: Some(8.0),
+                absent_days: None,
+                leave_days: None,
+                paid_leave_hours: None,
+                flextime_balance_hours: None,
             }));
             view! { <SummaryCard summary=summary /> }
         });
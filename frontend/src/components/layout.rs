@@ -149,6 +149,9 @@ pub fn Header() -> impl IntoView {
                             <A href="/requests" exact=true class="text-fg-muted hover:text-fg px-3 py-2 rounded-md text-sm font-medium hover:bg-action-ghost-bg-hover" active_class="text-fg bg-action-ghost-bg-hover">
                                 {rust_i18n::t!("common.navigation.requests")}
                             </A>
+                            <A href="/timesheets" exact=true class="text-fg-muted hover:text-fg px-3 py-2 rounded-md text-sm font-medium hover:bg-action-ghost-bg-hover" active_class="text-fg bg-action-ghost-bg-hover">
+                                {rust_i18n::t!("common.navigation.timesheets")}
+                            </A>
                             <A href="/settings" exact=true class="text-fg-muted hover:text-fg px-3 py-2 rounded-md text-sm font-medium hover:bg-action-ghost-bg-hover" active_class="text-fg bg-action-ghost-bg-hover">
                                 {rust_i18n::t!("common.navigation.settings")}
                             </A>
@@ -162,6 +165,9 @@ pub fn Header() -> impl IntoView {
                                 <A href="/admin/audit-logs" exact=true class="text-fg-muted hover:text-fg px-3 py-2 rounded-md text-sm font-medium hover:bg-action-ghost-bg-hover" active_class="text-fg bg-action-ghost-bg-hover">
                                     {rust_i18n::t!("common.navigation.admin_audit_logs")}
                                 </A>
+                                <A href="/admin/health" exact=true class="text-fg-muted hover:text-fg px-3 py-2 rounded-md text-sm font-medium hover:bg-action-ghost-bg-hover" active_class="text-fg bg-action-ghost-bg-hover">
+                                    {rust_i18n::t!("common.navigation.admin_health")}
+                                </A>
                             </Show>
                             <Show when=move || can_access_settings()>
                                 <A href="/admin/settings" exact=true class="text-fg-muted hover:text-fg px-3 py-2 rounded-md text-sm font-medium hover:bg-action-ghost-bg-hover" active_class="text-fg bg-action-ghost-bg-hover">
@@ -258,6 +264,15 @@ pub fn Header() -> impl IntoView {
                             >
                                 {rust_i18n::t!("common.navigation.requests")}
                             </A>
+                            <A
+                                href="/timesheets"
+                                exact=true
+                                class="block text-fg-muted hover:text-fg px-3 py-2 rounded-md text-sm font-medium hover:bg-action-ghost-bg-hover"
+                                active_class="text-fg bg-action-ghost-bg-hover"
+                                on:click=move |_| set_menu_open.set(false)
+                            >
+                                {rust_i18n::t!("common.navigation.timesheets")}
+                            </A>
                             <A
                                 href="/settings"
                                 exact=true
@@ -295,6 +310,15 @@ pub fn Header() -> impl IntoView {
                                 >
                                     {rust_i18n::t!("common.navigation.admin_audit_logs")}
                                 </A>
+                                <A
+                                    href="/admin/health"
+                                    exact=true
+                                    class="block text-fg-muted hover:text-fg px-3 py-2 rounded-md text-sm font-medium hover:bg-action-ghost-bg-hover"
+                                    active_class="text-fg bg-action-ghost-bg-hover"
+                                    on:click=move |_| set_menu_open.set(false)
+                                >
+                                    {rust_i18n::t!("common.navigation.admin_health")}
+                                </A>
                             </Show>
                             <Show when=move || can_access_settings()>
                                 <A
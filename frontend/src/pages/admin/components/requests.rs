@@ -101,6 +101,8 @@ fn field_label(key: &str) -> &str {
         "leave_type" => "休暇種別",
         "start_date" => "開始日",
         "end_date" => "終了日",
+        "unit" => "取得単位",
+        "hours" => "時間数",
         "date" => "対象日",
         "planned_hours" => "予定時間",
         "reason" => "理由",
@@ -193,6 +195,8 @@ const FIELD_ORDER: &[&str] = &[
     "leave_type",
     "start_date",
     "end_date",
+    "unit",
+    "hours",
     "date",
     "planned_hours",
     "reason",
@@ -214,9 +218,7 @@ fn request_detail_rows(data: &Value, users: &[UserResponse]) -> Vec<(String, Str
         .iter()
         .filter_map(|&key| {
             let value = obj.get(key)?;
-            let formatted = if key == "user_id" {
-                format_user_field_value(users, value)?
-            } else if key == "approved_by" || key == "rejected_by" {
+            let formatted = if matches!(key, "user_id" | "approved_by" | "rejected_by") {
                 format_user_field_value(users, value)?
             } else {
                 format_field_value(key, value)?
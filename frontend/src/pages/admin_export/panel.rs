@@ -1,12 +1,14 @@
 use super::view_model::{
-    needs_specific_user_selection, use_admin_export_view_model, ExportFilters,
+    job_is_downloadable, job_progress_label, needs_specific_user_selection,
+    use_admin_export_view_model, ExportFilters,
 };
-use crate::api::ApiError;
+use crate::api::{ApiError, ExportEncryptionMethod, ExportJobKind, ExportJobStatus};
 use crate::components::error::InlineErrorMessage;
 use crate::components::forms::DatePicker;
 use crate::components::layout::*;
 use crate::pages::admin::components::user_select::{AdminUserSelect, UserSelectValue};
 use crate::state::auth::use_auth;
+use crate::utils::time::format_in_app_tz;
 use leptos::*;
 
 #[component]
@@ -56,39 +58,59 @@ fn AdminExportPanel() -> impl IntoView {
         needs_specific_user_selection(vm.use_specific_user.get(), &vm.username.get())
     });
 
-    let on_export = {
-        move |_| {
-            let filters = ExportFilters {
-                username: if vm.use_specific_user.get_untracked() {
-                    vm.username.get_untracked()
-                } else {
-                    String::new()
-                },
-                from_date: vm.from_date.get_untracked(),
-                to_date: vm.to_date.get_untracked(),
-            };
-            if needs_specific_user_selection(
-                vm.use_specific_user.get_untracked(),
-                &filters.username,
-            ) {
-                vm.error.set(Some(ApiError::validation(rust_i18n::t!(
-                    "pages.admin_export.validation.user_required"
-                ))));
-                vm.preview.set(None);
-                return;
-            }
-            if let Err(msg) = filters.validate() {
-                vm.error.set(Some(msg));
-                vm.preview.set(None);
-                return;
-            }
-            vm.error.set(None);
+    // Checks the form and returns the filters to export, reporting problems inline.
+    let validated_filters = move || {
+        let filters = ExportFilters {
+            username: if vm.use_specific_user.get_untracked() {
+                vm.username.get_untracked()
+            } else {
+                String::new()
+            },
+            from_date: vm.from_date.get_untracked(),
+            to_date: vm.to_date.get_untracked(),
+            encryption: vm.encryption.get_untracked(),
+            recipient: vm.recipient.get_untracked(),
+        };
+        if needs_specific_user_selection(vm.use_specific_user.get_untracked(), &filters.username) {
+            vm.error.set(Some(ApiError::validation(rust_i18n::t!(
+                "pages.admin_export.validation.user_required"
+            ))));
+            vm.preview.set(None);
+            return None;
+        }
+        if let Err(msg) = filters.validate() {
+            vm.error.set(Some(msg));
+            vm.preview.set(None);
+            return None;
+        }
+        vm.error.set(None);
+        vm.passphrase.set(None);
+        Some(filters)
+    };
+
+    let on_export = move |_| {
+        if let Some(filters) = validated_filters() {
             vm.preview.set(None);
             vm.export_action.dispatch(filters);
         }
     };
 
+    let on_queue = move |_| {
+        if let Some(filters) = validated_filters() {
+            vm.queue_action.dispatch(filters);
+        }
+    };
+
     let downloading = vm.export_action.pending();
+    let queueing = vm.queue_action.pending();
+    let jobs_loading = vm.jobs_resource.loading();
+    let jobs_error = Signal::derive(move || vm.jobs_resource.get().and_then(|res| res.err()));
+    let jobs = Signal::derive(move || {
+        vm.jobs_resource
+            .get()
+            .and_then(|res| res.ok())
+            .unwrap_or_default()
+    });
 
     view! {
         <div class="space-y-6">
@@ -172,21 +194,70 @@ fn AdminExportPanel() -> impl IntoView {
                         value=vm.to_date
                     />
                 </div>
+                <div class="grid grid-cols-1 lg:grid-cols-3 gap-3 mb-4">
+                    <div>
+                        <label class="block text-sm text-fg-muted">{rust_i18n::t!("pages.admin_export.fields.encryption")}</label>
+                        <select
+                            class="mt-1 w-full border border-form-control-border bg-form-control-bg text-form-control-text rounded px-2 py-1"
+                            on:change=move |ev| {
+                                vm.encryption.set(encryption_from_value(&event_target_value(&ev)));
+                                vm.error.set(None);
+                            }
+                        >
+                            <option value="" selected=move || vm.encryption.get().is_none()>
+                                {rust_i18n::t!("pages.admin_export.encryption.none")}
+                            </option>
+                            <option value="age" selected=move || vm.encryption.get() == Some(ExportEncryptionMethod::Age)>
+                                {rust_i18n::t!("pages.admin_export.encryption.age")}
+                            </option>
+                            <option value="passphrase" selected=move || vm.encryption.get() == Some(ExportEncryptionMethod::Passphrase)>
+                                {rust_i18n::t!("pages.admin_export.encryption.passphrase")}
+                            </option>
+                        </select>
+                    </div>
+                    <Show when=move || vm.encryption.get() == Some(ExportEncryptionMethod::Age)>
+                        <div class="lg:col-span-2">
+                            <label class="block text-sm text-fg-muted">{rust_i18n::t!("pages.admin_export.fields.recipient")}</label>
+                            <input
+                                class="mt-1 w-full border border-form-control-border bg-form-control-bg text-form-control-text rounded px-2 py-1 font-mono"
+                                placeholder={rust_i18n::t!("pages.admin_export.fields.recipient_placeholder")}
+                                prop:value={move || vm.recipient.get()}
+                                on:input=move |ev| vm.recipient.set(event_target_value(&ev))
+                            />
+                        </div>
+                    </Show>
+                </div>
                 <Show when=move || vm.error.get().is_some()>
                     <InlineErrorMessage error={vm.error.into()} />
                 </Show>
-                <button
-                    class="px-4 py-2 bg-action-primary-bg text-action-primary-text rounded disabled:opacity-50"
-                    on:click=on_export
-                    disabled=move || downloading.get() || specific_user_required.get()
-                >
-                    <span class="inline-flex items-center gap-2">
-                        <Show when=move || downloading.get()>
-                            <span class="h-4 w-4 animate-spin rounded-full border-2 border-action-primary-text/70 border-t-transparent"></span>
-                        </Show>
-                        {move || if downloading.get() { rust_i18n::t!("pages.admin_export.actions.exporting").into_owned() } else { rust_i18n::t!("pages.admin_export.actions.export").into_owned() }}
-                    </span>
-                </button>
+                <div class="flex flex-wrap items-center gap-3">
+                    <button
+                        class="px-4 py-2 bg-action-primary-bg text-action-primary-text rounded disabled:opacity-50"
+                        on:click=on_export
+                        disabled=move || downloading.get() || specific_user_required.get()
+                    >
+                        <span class="inline-flex items-center gap-2">
+                            <Show when=move || downloading.get()>
+                                <span class="h-4 w-4 animate-spin rounded-full border-2 border-action-primary-text/70 border-t-transparent"></span>
+                            </Show>
+                            {move || if downloading.get() { rust_i18n::t!("pages.admin_export.actions.exporting").into_owned() } else { rust_i18n::t!("pages.admin_export.actions.export").into_owned() }}
+                        </span>
+                    </button>
+                    <button
+                        class="px-4 py-2 border border-border text-fg rounded disabled:opacity-50"
+                        on:click=on_queue
+                        disabled=move || queueing.get() || specific_user_required.get()
+                    >
+                        {move || if queueing.get() { rust_i18n::t!("pages.admin_export.actions.queueing").into_owned() } else { rust_i18n::t!("pages.admin_export.actions.queue").into_owned() }}
+                    </button>
+                </div>
+                <Show when=move || vm.passphrase.get().is_some()>
+                    <div class="mt-4 rounded-lg border border-status-warning-border bg-status-warning-bg px-3 py-2 text-sm text-status-warning-text">
+                        {rust_i18n::t!("pages.admin_export.passphrase_notice")}
+                        " "
+                        <code class="font-mono select-all">{move || vm.passphrase.get().unwrap_or_default()}</code>
+                    </div>
+                </Show>
                 <Show when=move || vm.preview.get().is_some()>
                     <div class="mt-4">
                         <h3 class="text-sm text-fg">
@@ -199,10 +270,106 @@ fn AdminExportPanel() -> impl IntoView {
                     </div>
                 </Show>
             </div>
+            <div class="bg-surface-elevated shadow rounded-lg p-6">
+                <div class="flex items-center justify-between mb-4">
+                    <h2 class="text-lg font-medium text-fg">{rust_i18n::t!("pages.admin_export.jobs.title")}</h2>
+                    <button
+                        class="px-3 py-1 text-sm border border-border text-fg rounded disabled:opacity-50"
+                        on:click=move |_| vm.jobs_reload.update(|value| *value = value.wrapping_add(1))
+                        disabled=move || jobs_loading.get()
+                    >
+                        {rust_i18n::t!("pages.admin_export.jobs.refresh")}
+                    </button>
+                </div>
+                <Show when=move || jobs_error.get().is_some()>
+                    <InlineErrorMessage error={jobs_error} />
+                </Show>
+                <Show when=move || !jobs_loading.get() && jobs_error.get().is_none() && jobs.get().is_empty()>
+                    <p class="text-sm text-fg-muted">{rust_i18n::t!("pages.admin_export.jobs.empty")}</p>
+                </Show>
+                <Show when=move || !jobs.get().is_empty()>
+                    <div class="overflow-x-auto">
+                        <table class="min-w-full divide-y divide-border text-sm">
+                            <thead class="bg-surface-muted">
+                                <tr>
+                                    <th class="px-4 py-2 text-left text-fg-muted">{rust_i18n::t!("pages.admin_export.jobs.columns.created_at")}</th>
+                                    <th class="px-4 py-2 text-left text-fg-muted">{rust_i18n::t!("pages.admin_export.jobs.columns.kind")}</th>
+                                    <th class="px-4 py-2 text-left text-fg-muted">{rust_i18n::t!("pages.admin_export.jobs.columns.status")}</th>
+                                    <th class="px-4 py-2 text-left text-fg-muted">{rust_i18n::t!("pages.admin_export.jobs.columns.rows")}</th>
+                                    <th class="px-4 py-2 text-right text-fg-muted">{rust_i18n::t!("pages.admin_export.jobs.columns.file")}</th>
+                                </tr>
+                            </thead>
+                            <tbody class="divide-y divide-border">
+                                <For
+                                    each=move || jobs.get()
+                                    key=|job| (job.id.clone(), job.status, job.rows_written)
+                                    children=move |job| {
+                                        let downloadable = job_is_downloadable(&job);
+                                        let id = job.id.clone();
+                                        view! {
+                                            <tr>
+                                                <td class="px-4 py-2 text-fg">{format_in_app_tz(job.created_at)}</td>
+                                                <td class="px-4 py-2 text-fg">{job_kind_label(job.kind)}</td>
+                                                <td class="px-4 py-2 text-fg">
+                                                    {job_status_label(job.status)}
+                                                    {job.error.clone().map(|error| view! {
+                                                        <p class="text-xs text-status-error-text">{error}</p>
+                                                    })}
+                                                </td>
+                                                <td class="px-4 py-2 text-fg-muted">{job_progress_label(&job)}</td>
+                                                <td class="px-4 py-2 text-right">
+                                                    <Show when=move || downloadable>
+                                                        <button
+                                                            class="text-action-primary-bg hover:underline disabled:opacity-50"
+                                                            disabled=move || vm.download_job_action.pending().get()
+                                                            on:click={
+                                                                let id = id.clone();
+                                                                move |_| vm.download_job_action.dispatch(id.clone())
+                                                            }
+                                                        >
+                                                            {rust_i18n::t!("pages.admin_export.jobs.download")}
+                                                        </button>
+                                                    </Show>
+                                                </td>
+                                            </tr>
+                                        }
+                                    }
+                                />
+                            </tbody>
+                        </table>
+                    </div>
+                </Show>
+            </div>
         </div>
     }
 }
 
+fn encryption_from_value(value: &str) -> Option<ExportEncryptionMethod> {
+    match value {
+        "age" => Some(ExportEncryptionMethod::Age),
+        "passphrase" => Some(ExportEncryptionMethod::Passphrase),
+        _ => None,
+    }
+}
+
+fn job_kind_label(kind: ExportJobKind) -> String {
+    match kind {
+        ExportJobKind::Attendance => rust_i18n::t!("pages.admin_export.jobs.kind.attendance"),
+        ExportJobKind::AuditLogs => rust_i18n::t!("pages.admin_export.jobs.kind.audit_logs"),
+    }
+    .into_owned()
+}
+
+fn job_status_label(status: ExportJobStatus) -> String {
+    match status {
+        ExportJobStatus::Queued => rust_i18n::t!("pages.admin_export.jobs.status.queued"),
+        ExportJobStatus::Running => rust_i18n::t!("pages.admin_export.jobs.status.running"),
+        ExportJobStatus::Completed => rust_i18n::t!("pages.admin_export.jobs.status.completed"),
+        ExportJobStatus::Failed => rust_i18n::t!("pages.admin_export.jobs.status.failed"),
+    }
+    .into_owned()
+}
+
 #[cfg(all(test, not(target_arch = "wasm32")))]
 mod host_tests {
     use super::*;
@@ -228,6 +395,12 @@ mod host_tests {
             when.method(GET).path("/api/admin/users");
             then.status(200).json_body(serde_json::json!([]));
         });
+        server.mock(|when, then| {
+            when.method(GET).path("/api/admin/export-jobs");
+            then.status(200).json_body(serde_json::json!({
+                "data": [], "total": 0, "limit": 20, "offset": 0
+            }));
+        });
 
         let server = server.clone();
         let html = render_with_router_to_string("http://localhost/", move || {
@@ -241,5 +414,7 @@ mod host_tests {
         assert!(html.contains("Export CSV"));
         assert!(html.contains("Start Date"));
         assert!(html.contains("End Date"));
+        assert!(html.contains("Export in Background"));
+        assert!(html.contains("Export History"));
     }
 }
@@ -1,6 +1,13 @@
-use crate::api::{ApiClient, ApiError, PiiProtectedResponse};
+use super::view_model::ExportFilters;
+use crate::api::{
+    ApiClient, ApiError, CreateExportJobRequest, ExportDownloadResponse, ExportJob, ExportJobKind,
+    PiiProtectedResponse,
+};
 use std::rc::Rc;
 
+/// Most recent jobs shown in the export history.
+pub const EXPORT_JOB_HISTORY_LIMIT: i64 = 20;
+
 #[derive(Clone)]
 pub struct AdminExportRepository {
     client: Rc<ApiClient>,
@@ -25,12 +32,16 @@ impl AdminExportRepository {
 
     pub async fn export_data_filtered(
         &self,
-        username: Option<&str>,
-        from: Option<&str>,
-        to: Option<&str>,
+        filters: &ExportFilters,
     ) -> Result<PiiProtectedResponse<serde_json::Value>, ApiError> {
         self.client
-            .export_data_filtered_with_policy(username, from, to)
+            .export_data_filtered_with_policy(
+                filters.username_param(),
+                filters.start_date_param(),
+                filters.end_date_param(),
+                filters.encryption,
+                filters.recipient_param(),
+            )
             .await
     }
 
@@ -39,6 +50,31 @@ impl AdminExportRepository {
     ) -> Result<PiiProtectedResponse<Vec<crate::api::UserResponse>>, ApiError> {
         self.client.get_users_with_policy().await
     }
+
+    /// Queues an attendance export the server writes in the background.
+    pub async fn queue_export_job(&self, filters: &ExportFilters) -> Result<ExportJob, ApiError> {
+        let request = CreateExportJobRequest {
+            kind: ExportJobKind::Attendance,
+            username: filters.username_param().map(str::to_string),
+            from: filters.start_date_param().map(str::to_string),
+            to: filters.end_date_param().map(str::to_string),
+            encryption: filters.encryption,
+            recipient: filters.recipient_param().map(str::to_string),
+        };
+        self.client.create_export_job(&request).await
+    }
+
+    pub async fn fetch_export_jobs(&self) -> Result<Vec<ExportJob>, ApiError> {
+        Ok(self
+            .client
+            .list_export_jobs(EXPORT_JOB_HISTORY_LIMIT, 0)
+            .await?
+            .data)
+    }
+
+    pub async fn export_job_download(&self, id: &str) -> Result<ExportDownloadResponse, ApiError> {
+        self.client.get_export_job_download(id).await
+    }
 }
 
 #[cfg(all(test, not(target_arch = "wasm32")))]
@@ -73,10 +109,13 @@ mod host_tests {
         });
 
         let repo = repository(&server);
-        let export = repo
-            .export_data_filtered(Some("alice"), Some("2026-01-01"), Some("2026-01-31"))
-            .await
-            .unwrap();
+        let filters = ExportFilters {
+            username: "alice".into(),
+            from_date: "2026-01-01".into(),
+            to_date: "2026-01-31".into(),
+            ..Default::default()
+        };
+        let export = repo.export_data_filtered(&filters).await.unwrap();
         assert!(!export.pii_masked);
         assert_eq!(export.data["filename"], "export.csv");
 
@@ -99,9 +138,75 @@ mod host_tests {
 
         let repo = repository(&server);
         let error = repo
-            .export_data_filtered(None, None, None)
+            .export_data_filtered(&ExportFilters::default())
             .await
             .expect_err("should return API error");
         assert_eq!(error.code, "INTERNAL_SERVER_ERROR");
     }
+
+    fn job_json(id: &str, status: &str) -> serde_json::Value {
+        serde_json::json!({
+            "id": id,
+            "requested_by": "u1",
+            "kind": "attendance",
+            "parameters": {},
+            "status": status,
+            "rows_written": 2,
+            "total_rows": 2,
+            "filename": "attendance_export.csv",
+            "content_type": "text/csv; charset=utf-8",
+            "size_bytes": 120,
+            "error": null,
+            "created_at": "2026-01-31T09:00:00Z",
+            "started_at": null,
+            "finished_at": null,
+            "updated_at": "2026-01-31T09:00:00Z"
+        })
+    }
+
+    #[tokio::test]
+    async fn export_jobs_are_queued_listed_and_downloaded_through_the_api_base() {
+        let server = MockServer::start_async().await;
+        server.mock(|when, then| {
+            when.method(POST).path("/api/admin/export-jobs");
+            then.status(202).json_body(job_json("job-1", "queued"));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/api/admin/export-jobs");
+            then.status(200).json_body(serde_json::json!({
+                "data": [job_json("job-1", "completed")],
+                "total": 1,
+                "limit": 20,
+                "offset": 0
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/admin/export-jobs/job-1/download");
+            then.status(200).json_body(serde_json::json!({
+                "url": "/api/export-downloads/job-1?expires=1&signature=ab",
+                "expires_at": "2026-01-31T09:05:00Z"
+            }));
+        });
+
+        let repo = repository(&server);
+        let filters = ExportFilters {
+            username: " alice ".into(),
+            from_date: "2026-01-01".into(),
+            to_date: " ".into(),
+            ..Default::default()
+        };
+        let job = repo.queue_export_job(&filters).await.unwrap();
+        assert_eq!(job.status, crate::api::ExportJobStatus::Queued);
+
+        let jobs = repo.fetch_export_jobs().await.unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].status, crate::api::ExportJobStatus::Completed);
+
+        let download = repo.export_job_download("job-1").await.unwrap();
+        assert_eq!(
+            download.url,
+            server.url("/api/export-downloads/job-1?expires=1&signature=ab")
+        );
+    }
 }
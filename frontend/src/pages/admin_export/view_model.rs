@@ -1,6 +1,9 @@
 use super::repository::AdminExportRepository;
-use crate::api::{ApiClient, ApiError, PiiProtectedResponse};
-use crate::utils::trigger_csv_download;
+use crate::api::{
+    ApiClient, ApiError, ExportDownloadResponse, ExportEncryptionMethod, ExportJob,
+    ExportJobStatus, PiiProtectedResponse,
+};
+use crate::utils::{open_download_url, trigger_csv_download};
 use chrono::NaiveDate;
 use leptos::*;
 use std::rc::Rc;
@@ -10,6 +13,9 @@ pub struct ExportFilters {
     pub username: String,
     pub from_date: String,
     pub to_date: String,
+    pub encryption: Option<ExportEncryptionMethod>,
+    /// Public key (`age1...`) the file is encrypted for with age encryption.
+    pub recipient: String,
 }
 
 impl ExportFilters {
@@ -34,6 +40,13 @@ impl ExportFilters {
         Self::normalized_str(&self.to_date)
     }
 
+    pub fn recipient_param(&self) -> Option<&str> {
+        match self.encryption {
+            Some(ExportEncryptionMethod::Age) => Self::normalized_str(&self.recipient),
+            _ => None,
+        }
+    }
+
     pub fn validate(&self) -> Result<(), ApiError> {
         let from_str = self.from_date.trim();
         let to_str = self.to_date.trim();
@@ -63,6 +76,13 @@ impl ExportFilters {
             }
         }
 
+        if self.encryption == Some(ExportEncryptionMethod::Age) && self.recipient_param().is_none()
+        {
+            return Err(ApiError::validation(rust_i18n::t!(
+                "pages.admin_export.validation.recipient_required"
+            )));
+        }
+
         Ok(())
     }
 }
@@ -76,10 +96,18 @@ pub struct AdminExportViewModel {
     pub from_date: RwSignal<String>,
     pub to_date: RwSignal<String>,
     pub use_specific_user: RwSignal<bool>,
+    pub encryption: RwSignal<Option<ExportEncryptionMethod>>,
+    pub recipient: RwSignal<String>,
+    /// One-time passphrase of the latest passphrase-encrypted export.
+    pub passphrase: RwSignal<Option<String>>,
     pub pii_masked: RwSignal<bool>,
     pub users_resource: Resource<bool, Result<Vec<crate::api::UserResponse>, ApiError>>,
     pub export_action:
         Action<ExportFilters, Result<PiiProtectedResponse<serde_json::Value>, ApiError>>,
+    pub jobs_reload: RwSignal<u32>,
+    pub jobs_resource: Resource<u32, Result<Vec<ExportJob>, ApiError>>,
+    pub queue_action: Action<ExportFilters, Result<ExportJob, ApiError>>,
+    pub download_job_action: Action<String, Result<ExportDownloadResponse, ApiError>>,
 }
 
 pub fn use_admin_export_view_model() -> AdminExportViewModel {
@@ -93,6 +121,9 @@ pub fn use_admin_export_view_model() -> AdminExportViewModel {
     let from_date = create_rw_signal(String::new());
     let to_date = create_rw_signal(String::new());
     let use_specific_user = create_rw_signal(false);
+    let encryption = create_rw_signal(None::<ExportEncryptionMethod>);
+    let recipient = create_rw_signal(String::new());
+    let passphrase = create_rw_signal(None::<String>);
     let pii_masked = create_rw_signal(false);
 
     let repo_users = repo.clone();
@@ -113,14 +144,7 @@ pub fn use_admin_export_view_model() -> AdminExportViewModel {
     let export_action = create_action(move |filters: &ExportFilters| {
         let repo = repo_export.clone();
         let filters = filters.clone();
-        async move {
-            repo.export_data_filtered(
-                filters.username_param(),
-                filters.start_date_param(),
-                filters.end_date_param(),
-            )
-            .await
-        }
+        async move { repo.export_data_filtered(&filters).await }
     });
 
     create_effect(move |_| {
@@ -139,6 +163,13 @@ pub fn use_admin_export_view_model() -> AdminExportViewModel {
                         .and_then(|c| c.as_str())
                         .unwrap_or("");
                     filename.set(fname.to_string());
+                    passphrase.set(
+                        payload
+                            .data
+                            .get("passphrase")
+                            .and_then(|p| p.as_str())
+                            .map(str::to_string),
+                    );
                     preview.set(Some(csv.chars().take(2000).collect()));
                     let _ = trigger_csv_download(fname, csv);
                     error.set(None);
@@ -151,6 +182,55 @@ pub fn use_admin_export_view_model() -> AdminExportViewModel {
         }
     });
 
+    let jobs_reload = create_rw_signal(0u32);
+    let repo_jobs = repo.clone();
+    let jobs_resource = create_resource(
+        move || jobs_reload.get(),
+        move |_| {
+            let repo = repo_jobs.clone();
+            async move { repo.fetch_export_jobs().await }
+        },
+    );
+
+    let repo_queue = repo.clone();
+    let queue_action = create_action(move |filters: &ExportFilters| {
+        let repo = repo_queue.clone();
+        let filters = filters.clone();
+        async move { repo.queue_export_job(&filters).await }
+    });
+
+    create_effect(move |_| {
+        if let Some(result) = queue_action.value().get() {
+            match result {
+                Ok(job) => {
+                    error.set(None);
+                    passphrase.set(job.passphrase);
+                    jobs_reload.update(|value| *value = value.wrapping_add(1));
+                }
+                Err(message) => error.set(Some(message)),
+            }
+        }
+    });
+
+    let repo_download = repo.clone();
+    let download_job_action = create_action(move |id: &String| {
+        let repo = repo_download.clone();
+        let id = id.clone();
+        async move { repo.export_job_download(&id).await }
+    });
+
+    create_effect(move |_| {
+        if let Some(result) = download_job_action.value().get() {
+            match result {
+                Ok(download) => {
+                    let _ = open_download_url(&download.url);
+                    error.set(None);
+                }
+                Err(message) => error.set(Some(message)),
+            }
+        }
+    });
+
     AdminExportViewModel {
         error,
         preview,
@@ -159,21 +239,91 @@ pub fn use_admin_export_view_model() -> AdminExportViewModel {
         from_date,
         to_date,
         use_specific_user,
+        encryption,
+        recipient,
+        passphrase,
         pii_masked,
         users_resource,
         export_action,
+        jobs_reload,
+        jobs_resource,
+        queue_action,
+        download_job_action,
+    }
+}
+
+/// Rows written so far, out of the total once the worker has counted it.
+pub fn job_progress_label(job: &ExportJob) -> String {
+    match job.total_rows {
+        Some(total) => format!("{} / {}", job.rows_written, total),
+        None => job.rows_written.to_string(),
     }
 }
 
+pub fn job_is_downloadable(job: &ExportJob) -> bool {
+    job.status == ExportJobStatus::Completed
+}
+
 pub fn needs_specific_user_selection(use_specific_user: bool, username: &str) -> bool {
     use_specific_user && username.trim().is_empty()
 }
 
 #[cfg(test)]
 mod tests {
-    use super::{needs_specific_user_selection, ExportFilters};
+    use super::{
+        job_is_downloadable, job_progress_label, needs_specific_user_selection, ExportFilters,
+    };
+    use crate::api::{ExportEncryptionMethod, ExportJob, ExportJobKind, ExportJobStatus};
     use crate::test_support::helpers::set_test_locale;
 
+    fn job(status: ExportJobStatus, rows_written: i64, total_rows: Option<i64>) -> ExportJob {
+        ExportJob {
+            id: "job-1".into(),
+            kind: ExportJobKind::Attendance,
+            status,
+            rows_written,
+            total_rows,
+            filename: None,
+            size_bytes: None,
+            error: None,
+            encryption: None,
+            passphrase: None,
+            created_at: chrono::Utc::now(),
+            finished_at: None,
+        }
+    }
+
+    #[test]
+    fn job_progress_shows_total_once_counted() {
+        assert_eq!(
+            job_progress_label(&job(ExportJobStatus::Queued, 0, None)),
+            "0"
+        );
+        assert_eq!(
+            job_progress_label(&job(ExportJobStatus::Running, 500, Some(1200))),
+            "500 / 1200"
+        );
+    }
+
+    #[test]
+    fn only_completed_jobs_are_downloadable() {
+        assert!(job_is_downloadable(&job(
+            ExportJobStatus::Completed,
+            2,
+            Some(2)
+        )));
+        assert!(!job_is_downloadable(&job(
+            ExportJobStatus::Running,
+            1,
+            Some(2)
+        )));
+        assert!(!job_is_downloadable(&job(
+            ExportJobStatus::Failed,
+            0,
+            Some(2)
+        )));
+    }
+
     #[test]
     fn specific_user_requires_selection() {
         assert!(needs_specific_user_selection(true, ""));
@@ -188,6 +338,7 @@ mod tests {
             username: "   ".into(),
             from_date: "".into(),
             to_date: "  ".into(),
+            ..Default::default()
         };
         assert_eq!(filters.username_param(), None);
         assert_eq!(filters.start_date_param(), None);
@@ -200,6 +351,7 @@ mod tests {
             username: " alice ".into(),
             from_date: " 2026-01-01 ".into(),
             to_date: " 2026-01-31 ".into(),
+            ..Default::default()
         };
         assert_eq!(filters.username_param(), Some("alice"));
         assert_eq!(filters.start_date_param(), Some("2026-01-01"));
@@ -213,6 +365,7 @@ mod tests {
             username: "alice".into(),
             from_date: "2026/01/01".into(),
             to_date: "2026-01-31".into(),
+            ..Default::default()
         };
         let error = filters.validate().expect_err("invalid format must fail");
         assert_eq!(error.code, "VALIDATION_ERROR");
@@ -225,6 +378,7 @@ mod tests {
             username: "alice".into(),
             from_date: "2026-02-01".into(),
             to_date: "2026-01-01".into(),
+            ..Default::default()
         };
         let error = filters
             .validate()
@@ -238,6 +392,7 @@ mod tests {
             username: "".into(),
             from_date: "2026-01-01".into(),
             to_date: "".into(),
+            ..Default::default()
         };
         only_from.validate().expect("from only should be valid");
 
@@ -245,10 +400,31 @@ mod tests {
             username: "".into(),
             from_date: "".into(),
             to_date: "2026-01-31".into(),
+            ..Default::default()
         };
         only_to.validate().expect("to only should be valid");
 
         let empty = ExportFilters::default();
         empty.validate().expect("empty range should be valid");
     }
+
+    #[test]
+    fn export_filters_require_a_recipient_for_age_encryption() {
+        let _locale = set_test_locale("ja");
+        let mut filters = ExportFilters {
+            encryption: Some(ExportEncryptionMethod::Age),
+            recipient: "  ".into(),
+            ..Default::default()
+        };
+        let error = filters.validate().expect_err("age needs a recipient");
+        assert_eq!(error.code, "VALIDATION_ERROR");
+
+        filters.recipient = " age1example ".into();
+        filters.validate().expect("recipient given");
+        assert_eq!(filters.recipient_param(), Some("age1example"));
+
+        filters.encryption = Some(ExportEncryptionMethod::Passphrase);
+        filters.validate().expect("passphrase needs no recipient");
+        assert_eq!(filters.recipient_param(), None);
+    }
 }
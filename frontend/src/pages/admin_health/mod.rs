@@ -0,0 +1,5 @@
+pub mod panel;
+pub mod repository;
+pub mod view_model;
+
+pub use panel::AdminHealthPage;
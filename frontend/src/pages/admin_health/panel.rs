@@ -0,0 +1,256 @@
+use super::view_model::{hours_label, use_admin_health_view_model, InterviewUpdate};
+use crate::api::HealthInterviewStatus;
+use crate::components::error::InlineErrorMessage;
+use crate::components::layout::*;
+use crate::state::auth::use_auth;
+use crate::utils::time::format_in_app_tz;
+use leptos::*;
+
+#[component]
+pub fn AdminHealthPage() -> impl IntoView {
+    let (auth, _set_auth) = use_auth();
+    let is_admin = create_memo(move |_| {
+        auth.get()
+            .user
+            .as_ref()
+            .map(|user| {
+                user.is_system_admin
+                    || user.role.eq_ignore_ascii_case("manager")
+                    || user.role.eq_ignore_ascii_case("admin")
+            })
+            .unwrap_or(false)
+    });
+
+    view! {
+        <Layout>
+            <Show
+                when=move || is_admin.get()
+                fallback=move || {
+                    view! {
+                        <div class="space-y-6">
+                            <div class="bg-surface-elevated shadow rounded-lg p-6">
+                                <p class="text-sm text-fg">
+                                    {rust_i18n::t!("pages.admin_health.unauthorized")}
+                                </p>
+                            </div>
+                        </div>
+                    }
+                }
+            >
+                <AdminHealthPanel />
+            </Show>
+        </Layout>
+    }
+}
+
+#[component]
+fn AdminHealthPanel() -> impl IntoView {
+    let vm = use_admin_health_view_model();
+
+    let report_loading = vm.report_resource.loading();
+    let report_error = Signal::derive(move || vm.report_resource.get().and_then(|res| res.err()));
+    let report = Signal::derive(move || {
+        vm.report_resource
+            .get()
+            .and_then(|res| res.ok())
+            .unwrap_or_default()
+    });
+    let history_error = Signal::derive(move || vm.history_resource.get().and_then(|res| res.err()));
+    let history = Signal::derive(move || {
+        vm.history_resource
+            .get()
+            .and_then(|res| res.ok())
+            .unwrap_or_default()
+    });
+    let saving = vm.record_action.pending();
+
+    view! {
+        <div class="space-y-6">
+            <div class="bg-surface-elevated shadow rounded-lg p-6">
+                <h2 class="text-lg font-medium text-fg">{rust_i18n::t!("pages.admin_health.title")}</h2>
+                <p class="mt-1 mb-4 text-sm text-fg-muted">{rust_i18n::t!("pages.admin_health.description")}</p>
+                <div class="mb-4 max-w-xs">
+                    <label class="block text-sm text-fg-muted">{rust_i18n::t!("pages.admin_health.fields.month")}</label>
+                    <input
+                        type="month"
+                        class="mt-1 w-full border border-form-control-border bg-form-control-bg text-form-control-text rounded px-2 py-1"
+                        prop:value={move || vm.month.get()}
+                        on:change=move |ev| vm.month.set(event_target_value(&ev))
+                    />
+                </div>
+                <Show when=move || vm.error.get().is_some()>
+                    <InlineErrorMessage error={vm.error.into()} />
+                </Show>
+                <Show when=move || report_error.get().is_some()>
+                    <InlineErrorMessage error={report_error} />
+                </Show>
+                <Show when=move || report_loading.get()>
+                    <LoadingSpinner />
+                </Show>
+                <Show when=move || !report_loading.get() && report_error.get().is_none() && report.get().is_empty()>
+                    <p class="text-sm text-fg-muted">{rust_i18n::t!("pages.admin_health.report.empty")}</p>
+                </Show>
+                <Show when=move || !report.get().is_empty()>
+                    <div class="overflow-x-auto">
+                        <table class="min-w-full divide-y divide-border text-sm">
+                            <thead class="bg-surface-muted">
+                                <tr>
+                                    <th class="px-4 py-2 text-left text-fg-muted">{rust_i18n::t!("pages.admin_health.columns.employee")}</th>
+                                    <th class="px-4 py-2 text-right text-fg-muted">{rust_i18n::t!("pages.admin_health.columns.worked_hours")}</th>
+                                    <th class="px-4 py-2 text-right text-fg-muted">{rust_i18n::t!("pages.admin_health.columns.overtime_hours")}</th>
+                                    <th class="px-4 py-2 text-left text-fg-muted">{rust_i18n::t!("pages.admin_health.columns.interview")}</th>
+                                    <th class="px-4 py-2 text-right text-fg-muted">{rust_i18n::t!("pages.admin_health.columns.actions")}</th>
+                                </tr>
+                            </thead>
+                            <tbody class="divide-y divide-border">
+                                <For
+                                    each=move || report.get()
+                                    key=|entry| (entry.user_id.clone(), entry.interview.as_ref().map(|i| i.status))
+                                    children=move |entry| {
+                                        let status = entry.interview.as_ref().map(|interview| interview.status);
+                                        let actions = [
+                                            HealthInterviewStatus::Offered,
+                                            HealthInterviewStatus::Completed,
+                                            HealthInterviewStatus::Declined,
+                                        ]
+                                        .into_iter()
+                                        .filter(|action| Some(*action) != status)
+                                        .map(|action| {
+                                            let update = InterviewUpdate {
+                                                user_id: entry.user_id.clone(),
+                                                year: entry.year,
+                                                month: entry.month,
+                                                status: action,
+                                            };
+                                            view! {
+                                                <button
+                                                    class="px-2 py-1 text-xs border border-border text-fg rounded disabled:opacity-50"
+                                                    disabled=move || saving.get()
+                                                    on:click=move |_| vm.record_action.dispatch(update.clone())
+                                                >
+                                                    {action_label(action)}
+                                                </button>
+                                            }
+                                        })
+                                        .collect_view();
+                                        view! {
+                                            <tr>
+                                                <td class="px-4 py-2 text-fg">{entry.username.clone()}</td>
+                                                <td class="px-4 py-2 text-right text-fg-muted">{hours_label(entry.worked_hours)}</td>
+                                                <td class="px-4 py-2 text-right font-medium text-status-error-text">{hours_label(entry.overtime_hours)}</td>
+                                                <td class="px-4 py-2 text-fg">
+                                                    {status.map(status_label).unwrap_or_else(|| {
+                                                        rust_i18n::t!("pages.admin_health.status.not_offered").into_owned()
+                                                    })}
+                                                </td>
+                                                <td class="px-4 py-2">
+                                                    <div class="flex justify-end gap-2">{actions}</div>
+                                                </td>
+                                            </tr>
+                                        }
+                                    }
+                                />
+                            </tbody>
+                        </table>
+                    </div>
+                </Show>
+            </div>
+            <div class="bg-surface-elevated shadow rounded-lg p-6">
+                <h2 class="text-lg font-medium text-fg mb-4">{rust_i18n::t!("pages.admin_health.history.title")}</h2>
+                <Show when=move || history_error.get().is_some()>
+                    <InlineErrorMessage error={history_error} />
+                </Show>
+                <Show when=move || history_error.get().is_none() && history.get().is_empty()>
+                    <p class="text-sm text-fg-muted">{rust_i18n::t!("pages.admin_health.history.empty")}</p>
+                </Show>
+                <Show when=move || !history.get().is_empty()>
+                    <div class="overflow-x-auto">
+                        <table class="min-w-full divide-y divide-border text-sm">
+                            <thead class="bg-surface-muted">
+                                <tr>
+                                    <th class="px-4 py-2 text-left text-fg-muted">{rust_i18n::t!("pages.admin_health.columns.month")}</th>
+                                    <th class="px-4 py-2 text-left text-fg-muted">{rust_i18n::t!("pages.admin_health.columns.employee")}</th>
+                                    <th class="px-4 py-2 text-right text-fg-muted">{rust_i18n::t!("pages.admin_health.columns.overtime_hours")}</th>
+                                    <th class="px-4 py-2 text-left text-fg-muted">{rust_i18n::t!("pages.admin_health.columns.interview")}</th>
+                                    <th class="px-4 py-2 text-left text-fg-muted">{rust_i18n::t!("pages.admin_health.columns.offered_at")}</th>
+                                    <th class="px-4 py-2 text-left text-fg-muted">{rust_i18n::t!("pages.admin_health.columns.completed_at")}</th>
+                                </tr>
+                            </thead>
+                            <tbody class="divide-y divide-border">
+                                <For
+                                    each=move || history.get()
+                                    key=|interview| (interview.user_id.clone(), interview.year, interview.month, interview.status)
+                                    children=move |interview| {
+                                        view! {
+                                            <tr>
+                                                <td class="px-4 py-2 text-fg">{format!("{:04}-{:02}", interview.year, interview.month)}</td>
+                                                <td class="px-4 py-2 text-fg">{interview.username.clone()}</td>
+                                                <td class="px-4 py-2 text-right text-fg-muted">{hours_label(interview.overtime_hours)}</td>
+                                                <td class="px-4 py-2 text-fg">
+                                                    {status_label(interview.status)}
+                                                    {interview.notes.clone().map(|notes| view! {
+                                                        <p class="text-xs text-fg-muted">{notes}</p>
+                                                    })}
+                                                </td>
+                                                <td class="px-4 py-2 text-fg-muted">{format_in_app_tz(interview.offered_at)}</td>
+                                                <td class="px-4 py-2 text-fg-muted">
+                                                    {interview.completed_at.map(format_in_app_tz).unwrap_or_default()}
+                                                </td>
+                                            </tr>
+                                        }
+                                    }
+                                />
+                            </tbody>
+                        </table>
+                    </div>
+                </Show>
+            </div>
+        </div>
+    }
+}
+
+fn status_label(status: HealthInterviewStatus) -> String {
+    match status {
+        HealthInterviewStatus::Offered => rust_i18n::t!("pages.admin_health.status.offered"),
+        HealthInterviewStatus::Completed => rust_i18n::t!("pages.admin_health.status.completed"),
+        HealthInterviewStatus::Declined => rust_i18n::t!("pages.admin_health.status.declined"),
+    }
+    .into_owned()
+}
+
+fn action_label(status: HealthInterviewStatus) -> String {
+    match status {
+        HealthInterviewStatus::Offered => rust_i18n::t!("pages.admin_health.actions.offer"),
+        HealthInterviewStatus::Completed => rust_i18n::t!("pages.admin_health.actions.complete"),
+        HealthInterviewStatus::Declined => rust_i18n::t!("pages.admin_health.actions.decline"),
+    }
+    .into_owned()
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod host_tests {
+    use super::*;
+    use crate::test_support::helpers::{admin_user, provide_auth, regular_user, set_test_locale};
+    use crate::test_support::ssr::render_with_router_to_string;
+
+    #[test]
+    fn admin_health_page_denied_for_non_admin() {
+        let _locale = set_test_locale("en");
+        let html = render_with_router_to_string("http://localhost/", move || {
+            provide_auth(Some(regular_user()));
+            view! { <AdminHealthPage /> }
+        });
+        assert!(html.contains("administrator privileges"));
+    }
+
+    #[test]
+    fn admin_health_page_renders_for_admin() {
+        let _locale = set_test_locale("ja");
+        let html = render_with_router_to_string("http://localhost/admin/health", move || {
+            provide_auth(Some(admin_user(true)));
+            view! { <AdminHealthPage /> }
+        });
+        assert!(html.contains("長時間労働者の面接指導"));
+        assert!(html.contains("面接指導の履歴"));
+    }
+}
@@ -0,0 +1,127 @@
+use crate::api::{
+    ApiClient, ApiError, HealthInterview, HealthInterviewStatus, LongHoursEmployee,
+    RecordHealthInterviewRequest,
+};
+use std::rc::Rc;
+
+#[derive(Clone)]
+pub struct AdminHealthRepository {
+    client: Rc<ApiClient>,
+}
+
+impl Default for AdminHealthRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl AdminHealthRepository {
+    pub fn new() -> Self {
+        Self {
+            client: Rc::new(ApiClient::new()),
+        }
+    }
+
+    pub fn new_with_client(client: Rc<ApiClient>) -> Self {
+        Self { client }
+    }
+
+    pub async fn fetch_report(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<Vec<LongHoursEmployee>, ApiError> {
+        self.client.admin_get_long_hours_report(year, month).await
+    }
+
+    pub async fn fetch_history(&self) -> Result<Vec<HealthInterview>, ApiError> {
+        self.client.admin_list_health_interviews().await
+    }
+
+    pub async fn record_interview(
+        &self,
+        user_id: &str,
+        year: i32,
+        month: u32,
+        status: HealthInterviewStatus,
+    ) -> Result<HealthInterview, ApiError> {
+        let request = RecordHealthInterviewRequest {
+            status,
+            notes: None,
+        };
+        self.client
+            .admin_record_health_interview(user_id, year, month, &request)
+            .await
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod host_tests {
+    use super::*;
+    use crate::api::test_support::mock::*;
+
+    fn repository(server: &MockServer) -> AdminHealthRepository {
+        AdminHealthRepository::new_with_client(Rc::new(ApiClient::new_with_base_url(
+            &server.url("/api"),
+        )))
+    }
+
+    fn interview_json(status: &str) -> serde_json::Value {
+        serde_json::json!({
+            "user_id": "u1",
+            "username": "alice",
+            "year": 2026,
+            "month": 3,
+            "overtime_hours": 92.5,
+            "status": status,
+            "notes": null,
+            "offered_at": "2026-04-02T09:00:00Z",
+            "completed_at": null,
+            "updated_by": "m1",
+            "updated_at": "2026-04-02T09:00:00Z"
+        })
+    }
+
+    #[tokio::test]
+    async fn admin_health_repository_calls_endpoints() {
+        let server = MockServer::start_async().await;
+        server.mock(|when, then| {
+            when.method(GET).path("/api/admin/health/long-hours");
+            then.status(200).json_body(serde_json::json!([{
+                "user_id": "u1",
+                "username": "alice",
+                "department_id": null,
+                "year": 2026,
+                "month": 3,
+                "worked_hours": 269.64,
+                "overtime_hours": 92.5,
+                "interview": null
+            }]));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/api/admin/health/interviews");
+            then.status(200)
+                .json_body(serde_json::json!([interview_json("offered")]));
+        });
+        server.mock(|when, then| {
+            when.method(PUT)
+                .path("/api/admin/health/interviews/u1/2026/3");
+            then.status(200).json_body(interview_json("completed"));
+        });
+
+        let repo = repository(&server);
+        let report = repo.fetch_report(2026, 3).await.unwrap();
+        assert_eq!(report.len(), 1);
+        assert_eq!(report[0].overtime_hours, 92.5);
+        assert!(report[0].interview.is_none());
+
+        let history = repo.fetch_history().await.unwrap();
+        assert_eq!(history[0].status, HealthInterviewStatus::Offered);
+
+        let saved = repo
+            .record_interview("u1", 2026, 3, HealthInterviewStatus::Completed)
+            .await
+            .unwrap();
+        assert_eq!(saved.status, HealthInterviewStatus::Completed);
+    }
+}
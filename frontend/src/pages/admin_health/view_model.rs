@@ -0,0 +1,121 @@
+use super::repository::AdminHealthRepository;
+use crate::api::{ApiClient, ApiError, HealthInterview, HealthInterviewStatus, LongHoursEmployee};
+use crate::utils::time::today_in_app_tz;
+use chrono::Datelike;
+use leptos::*;
+use std::rc::Rc;
+
+/// Interview to record for an employee's month.
+#[derive(Clone, Debug, PartialEq)]
+pub struct InterviewUpdate {
+    pub user_id: String,
+    pub year: i32,
+    pub month: u32,
+    pub status: HealthInterviewStatus,
+}
+
+#[derive(Clone, Copy)]
+pub struct AdminHealthViewModel {
+    pub error: RwSignal<Option<ApiError>>,
+    /// Reported month as `YYYY-MM`, the value of a month input.
+    pub month: RwSignal<String>,
+    pub report_resource: Resource<(String, u32), Result<Vec<LongHoursEmployee>, ApiError>>,
+    pub history_resource: Resource<u32, Result<Vec<HealthInterview>, ApiError>>,
+    pub record_action: Action<InterviewUpdate, Result<HealthInterview, ApiError>>,
+}
+
+pub fn use_admin_health_view_model() -> AdminHealthViewModel {
+    let api = use_context::<ApiClient>().unwrap_or_else(ApiClient::new);
+    let repo = AdminHealthRepository::new_with_client(Rc::new(api));
+
+    let error = create_rw_signal(None::<ApiError>);
+    let today = today_in_app_tz();
+    let month = create_rw_signal(format!("{:04}-{:02}", today.year(), today.month()));
+    let reload = create_rw_signal(0u32);
+
+    let repo_report = repo.clone();
+    let report_resource = create_resource(
+        move || (month.get(), reload.get()),
+        move |(value, _)| {
+            let repo = repo_report.clone();
+            async move {
+                let (year, month) = parse_month(&value).ok_or_else(|| {
+                    ApiError::validation(rust_i18n::t!("pages.admin_health.validation.month"))
+                })?;
+                repo.fetch_report(year, month).await
+            }
+        },
+    );
+
+    let repo_history = repo.clone();
+    let history_resource = create_resource(
+        move || reload.get(),
+        move |_| {
+            let repo = repo_history.clone();
+            async move { repo.fetch_history().await }
+        },
+    );
+
+    let repo_record = repo.clone();
+    let record_action = create_action(move |update: &InterviewUpdate| {
+        let repo = repo_record.clone();
+        let update = update.clone();
+        async move {
+            repo.record_interview(&update.user_id, update.year, update.month, update.status)
+                .await
+        }
+    });
+
+    create_effect(move |_| {
+        if let Some(result) = record_action.value().get() {
+            match result {
+                Ok(_) => {
+                    error.set(None);
+                    reload.update(|value| *value = value.wrapping_add(1));
+                }
+                Err(message) => error.set(Some(message)),
+            }
+        }
+    });
+
+    AdminHealthViewModel {
+        error,
+        month,
+        report_resource,
+        history_resource,
+        record_action,
+    }
+}
+
+/// Year and month of a `YYYY-MM` value.
+pub fn parse_month(value: &str) -> Option<(i32, u32)> {
+    let (year, month) = value.trim().split_once('-')?;
+    let year = year.parse().ok()?;
+    let month = month.parse().ok()?;
+    (1..=12).contains(&month).then_some((year, month))
+}
+
+/// Hours formatted with one decimal, as shown in the report.
+pub fn hours_label(hours: f64) -> String {
+    format!("{:.1}", hours)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{hours_label, parse_month};
+
+    #[test]
+    fn month_values_parse_into_year_and_month() {
+        assert_eq!(parse_month("2026-03"), Some((2026, 3)));
+        assert_eq!(parse_month(" 2026-12 "), Some((2026, 12)));
+        assert_eq!(parse_month("2026-13"), None);
+        assert_eq!(parse_month("2026"), None);
+        assert_eq!(parse_month(""), None);
+    }
+
+    #[test]
+    fn hours_are_shown_with_one_decimal() {
+        assert_eq!(hours_label(92.0), "92.0");
+        assert_eq!(hours_label(80.04), "80.0");
+    }
+}
@@ -1,5 +1,5 @@
 use crate::{
-    api::{AttendanceResponse, BreakRecordResponse, HolidayCalendarEntry},
+    api::{AttendanceResponse, BreakRecordResponse, HolidayCalendarEntry, PeriodClosing},
     components::{error::InlineErrorMessage, layout::LoadingSpinner},
     pages::attendance::repository,
     state::attendance::describe_holiday_reason,
@@ -15,7 +15,11 @@ pub fn HistorySection(
     holiday_entries: Signal<Vec<HolidayCalendarEntry>>,
     loading: Signal<bool>,
     error: Signal<Option<crate::api::ApiError>>,
+    /// Months closed for payroll; their rows are shown as read-only.
+    #[prop(optional)]
+    closed_periods: MaybeSignal<Vec<PeriodClosing>>,
 ) -> impl IntoView {
+    let closed_periods = Signal::derive(move || closed_periods.get());
     view! {
         <div class="bg-surface-elevated rounded-2xl shadow-sm overflow-hidden border border-border">
             <div class="px-6 py-4 border-b border-border flex items-center justify-between">
@@ -46,7 +50,7 @@ pub fn HistorySection(
                         <For
                             each=move || history.get()
                             key=|item| item.id.clone()
-                            children=move |item| view! { <HistoryRow item=item holiday_entries=holiday_entries /> }
+                            children=move |item| view! { <HistoryRow item=item holiday_entries=holiday_entries closed_periods=closed_periods /> }
                         />
                     </ul>
                 </Show>
@@ -59,6 +63,7 @@ pub fn HistorySection(
 fn HistoryRow(
     item: AttendanceResponse,
     holiday_entries: Signal<Vec<HolidayCalendarEntry>>,
+    closed_periods: Signal<Vec<PeriodClosing>>,
 ) -> impl IntoView {
     let expanded = create_rw_signal(false);
     let breaks = create_rw_signal(Vec::<BreakRecordResponse>::new());
@@ -72,6 +77,10 @@ fn HistoryRow(
             .map(|entry| entry.reason.clone())
     });
 
+    let closed = create_memo(move |_| {
+        closed_periods.with(|closings| closings.iter().any(|closing| closing.covers(item.date)))
+    });
+
     let toggle: Rc<dyn Fn()> = Rc::new({
         let attendance_id = item.id.clone();
         move || {
@@ -146,6 +155,15 @@ fn HistoryRow(
                                         }}
                                     </span>
                                 </Show>
+                                <Show when=move || closed.get()>
+                                    <span
+                                        class="inline-flex items-center gap-1 px-2 py-0.5 rounded-md text-[10px] font-bold bg-surface-muted text-fg-muted uppercase"
+                                        title={rust_i18n::t!("pages.attendance.history.closed_hint").into_owned()}
+                                    >
+                                        <i class="fas fa-lock"></i>
+                                        {rust_i18n::t!("pages.attendance.history.closed")}
+                                    </span>
+                                </Show>
                                 <span class="text-sm font-bold text-fg">
                                     {match (item.clock_in_time, item.clock_out_time) {
                                         (Some(start), Some(end)) => format!("{} - {}", start.format("%H:%M"), end.format("%H:%M")),
@@ -310,4 +328,32 @@ mod host_tests {
         assert!(html.contains("role=\"button\""));
         assert!(html.contains("aria-expanded=\"false\""));
     }
+
+    #[test]
+    fn history_rows_in_closed_months_show_lock() {
+        let html = render_to_string(move || {
+            let attendance = sample_attendance();
+            let closing = PeriodClosing {
+                id: "pc-1".into(),
+                period_month: attendance.date,
+                department_id: None,
+                closed_at: chrono::Utc::now(),
+            };
+            let (history, _) = create_signal(vec![attendance]);
+            let history_signal = Signal::derive(move || history.get());
+            let holiday_signal = Signal::derive(Vec::new);
+            let loading = Signal::derive(|| false);
+            let error = Signal::derive(|| None::<crate::api::ApiError>);
+            view! {
+                <HistorySection
+                    history=history_signal
+                    holiday_entries=holiday_signal
+                    loading=loading
+                    error=error
+                    closed_periods=MaybeSignal::Static(vec![closing])
+                />
+            }
+        });
+        assert!(html.contains("fa-lock"));
+    }
 }
@@ -41,6 +41,12 @@ pub fn AttendancePanel() -> impl IntoView {
     let _context_loading = vm.context_resource.loading();
     let last_refresh_error = Signal::derive(move || state.with(|s| s.last_refresh_error.clone()));
     let history_signal = Signal::derive(move || state.with(|s| s.attendance_history.clone()));
+    let closed_periods = Signal::derive(move || {
+        vm.closed_periods_resource
+            .get()
+            .and_then(|result| result.ok())
+            .unwrap_or_default()
+    });
 
     view! {
         <AttendanceFrame>
@@ -80,6 +86,7 @@ pub fn AttendancePanel() -> impl IntoView {
                     holiday_entries={holiday_entries}
                     loading=history_loading
                     error={history_error}
+                    closed_periods={closed_periods.into()}
                 />
             </div>
         </AttendanceFrame>
@@ -1,4 +1,4 @@
-use crate::api::{ApiClient, ApiError, BreakRecordResponse, HolidayCalendarEntry};
+use crate::api::{ApiClient, ApiError, BreakRecordResponse, HolidayCalendarEntry, PeriodClosing};
 
 pub trait AttendanceApi {
     async fn get_monthly_holidays(
@@ -10,6 +10,7 @@ pub trait AttendanceApi {
         &self,
         attendance_id: &str,
     ) -> Result<Vec<BreakRecordResponse>, ApiError>;
+    async fn get_my_closed_periods(&self) -> Result<Vec<PeriodClosing>, ApiError>;
 }
 
 impl AttendanceApi for ApiClient {
@@ -27,6 +28,10 @@ impl AttendanceApi for ApiClient {
     ) -> Result<Vec<BreakRecordResponse>, ApiError> {
         self.get_breaks_by_attendance(attendance_id).await
     }
+
+    async fn get_my_closed_periods(&self) -> Result<Vec<PeriodClosing>, ApiError> {
+        self.get_my_closed_periods().await
+    }
 }
 
 pub async fn fetch_monthly_holidays(
@@ -44,6 +49,12 @@ pub async fn fetch_breaks_by_attendance(
     api.get_breaks_by_attendance(attendance_id).await
 }
 
+pub async fn fetch_closed_periods(
+    api: &impl AttendanceApi,
+) -> Result<Vec<PeriodClosing>, ApiError> {
+    api.get_my_closed_periods().await
+}
+
 #[cfg(all(test, not(target_arch = "wasm32")))]
 mod host_tests {
     use super::*;
@@ -102,6 +113,10 @@ mod host_tests {
                 .push(attendance_id.to_string());
             Ok(self.breaks.clone())
         }
+
+        async fn get_my_closed_periods(&self) -> Result<Vec<PeriodClosing>, ApiError> {
+            Ok(Vec::new())
+        }
     }
 
     #[tokio::test]
@@ -114,6 +129,7 @@ mod host_tests {
         let breaks = fetch_breaks_by_attendance(&api, "att-1").await.unwrap();
         assert_eq!(breaks.len(), 1);
         assert_eq!(breaks[0].attendance_id, "att-1");
+        assert!(fetch_closed_periods(&api).await.unwrap().is_empty());
 
         assert_eq!(api.holiday_calls.borrow().as_slice(), &[(2025, 1)]);
         assert_eq!(api.breaks_calls.borrow().as_slice(), &["att-1".to_string()]);
@@ -89,6 +89,7 @@ pub struct AttendanceViewModel {
     pub holiday_resource:
         Resource<HolidayQuery, Result<Vec<crate::api::HolidayCalendarEntry>, ApiError>>,
     pub context_resource: Resource<(), Result<(), ApiError>>,
+    pub closed_periods_resource: Resource<(), Result<Vec<crate::api::PeriodClosing>, ApiError>>,
     pub export_action: Action<ExportPayload, Result<Value, ApiError>>,
     pub clock_action: Action<ClockEventPayload, Result<(), ApiError>>,
     pub clock_message: RwSignal<Option<ClockMessage>>,
@@ -359,6 +360,15 @@ impl AttendanceViewModel {
             },
         );
 
+        let api_for_closings = api.clone();
+        let closed_periods_resource = create_resource(
+            || (),
+            move |_| {
+                let api = api_for_closings.clone();
+                async move { repository::fetch_closed_periods(&api).await }
+            },
+        );
+
         let api_for_clock = api.clone();
         let clock_action = create_action(move |payload: &ClockEventPayload| {
             let api = api_for_clock.clone();
@@ -427,6 +437,7 @@ impl AttendanceViewModel {
             holiday_query,
             holiday_resource,
             context_resource,
+            closed_periods_resource,
             export_action,
             clock_action,
             clock_message,
@@ -6,7 +6,7 @@ use crate::{
 use leptos::*;
 
 type AlertsResource = Resource<
-    Option<Result<DashboardSummary, crate::api::ApiError>>,
+    (Option<Result<DashboardSummary, crate::api::ApiError>>, bool),
     Result<Vec<DashboardAlert>, crate::api::ApiError>,
 >;
 
@@ -85,11 +85,18 @@ mod host_tests {
         let html = render_to_string(move || {
             let resource = Resource::new(
                 || {
-                    Some(Ok(DashboardSummary {
-                        total_work_hours: None,
-                        total_work_days: None,
-                        average_daily_hours: None,
-                    }))
+                    (
+                        Some(Ok(DashboardSummary {
+                            total_work_hours: None,
+                            total_work_days: None,
+                            average_daily_hours: None,
+                            absent_days: None,
+                            leave_days: None,
+                            paid_leave_hours: None,
+                            flextime_balance_hours: None,
+                        })),
+                        false,
+                    )
                 },
                 |_| async move { Ok::<Vec<DashboardAlert>, crate::api::ApiError>(Vec::new()) },
             );
@@ -108,11 +115,18 @@ mod host_tests {
         let html = render_to_string(move || {
             let resource = Resource::new(
                 || {
-                    Some(Ok(DashboardSummary {
-                        total_work_hours: None,
-                        total_work_days: None,
-                        average_daily_hours: None,
-                    }))
+                    (
+                        Some(Ok(DashboardSummary {
+                            total_work_hours: None,
+                            total_work_days: None,
+                            average_daily_hours: None,
+                            absent_days: None,
+                            leave_days: None,
+                            paid_leave_hours: None,
+                            flextime_balance_hours: None,
+                        })),
+                        false,
+                    )
                 },
                 |_| async move { Ok::<Vec<DashboardAlert>, crate::api::ApiError>(Vec::new()) },
             );
@@ -3,7 +3,7 @@ use crate::{
     components::layout::LoadingSpinner,
     pages::dashboard::{
         repository::DashboardSummary,
-        utils::{format_days, format_hours},
+        utils::{format_balance_hours, format_days, format_hours, format_leave_days},
     },
 };
 use leptos::*;
@@ -35,6 +35,12 @@ pub fn SummarySection(
                             <Metric label=rust_i18n::t!("pages.dashboard.summary.metrics.total_hours").into_owned() value={format_hours(data.total_work_hours)} />
                             <Metric label=rust_i18n::t!("pages.dashboard.summary.metrics.total_days").into_owned() value={format_days(data.total_work_days)} />
                             <Metric label=rust_i18n::t!("pages.dashboard.summary.metrics.average_hours").into_owned() value={format_hours(data.average_daily_hours)} />
+                            <Metric label=rust_i18n::t!("pages.dashboard.summary.metrics.absent_days").into_owned() value={format_days(data.absent_days)} />
+                            <Metric label=rust_i18n::t!("pages.dashboard.summary.metrics.leave_days").into_owned() value={format_leave_days(data.leave_days)} />
+                            <Metric label=rust_i18n::t!("pages.dashboard.summary.metrics.paid_leave_hours").into_owned() value={format_hours(data.paid_leave_hours)} />
+                            {data.flextime_balance_hours.map(|balance| view! {
+                                <Metric label=rust_i18n::t!("pages.dashboard.summary.metrics.flextime_balance").into_owned() value={format_balance_hours(balance)} />
+                            })}
                         </div>
                     }.into_view(),
                 }}
@@ -70,6 +76,10 @@ mod host_tests {
                         total_work_hours: Some(160.0),
                         total_work_days: Some(20),
                         average_daily_hours: Some(8.0),
+                        absent_days: None,
+                        leave_days: None,
+                        paid_leave_hours: None,
+                        flextime_balance_hours: None,
                     })
                 },
             );
@@ -77,12 +87,45 @@ mod host_tests {
                 total_work_hours: Some(160.0),
                 total_work_days: Some(20),
                 average_daily_hours: Some(8.0),
+                absent_days: None,
+                leave_days: None,
+                paid_leave_hours: None,
+                flextime_balance_hours: None,
             }));
             view! { <SummarySection summary=resource /> }
         });
         assert!(html.contains("160.00"));
     }
 
+    #[test]
+    fn summary_section_renders_flextime_balance() {
+        let data = DashboardSummary {
+            total_work_hours: Some(100.0),
+            total_work_days: Some(12),
+            average_daily_hours: Some(8.3),
+            absent_days: Some(3),
+            leave_days: Some(1.5),
+            paid_leave_hours: Some(12.0),
+            flextime_balance_hours: Some(-3.5),
+        };
+        let html = render_to_string(move || {
+            let resource = Resource::new(
+                || (),
+                |_| async move {
+                    Err::<DashboardSummary, crate::api::ApiError>(crate::api::ApiError::unknown(
+                        "pending",
+                    ))
+                },
+            );
+            resource.set(Ok(data.clone()));
+            view! { <SummarySection summary=resource /> }
+        });
+        assert!(html.contains("-3.50"));
+        assert!(html.contains("3 日"));
+        assert!(html.contains("1.5 日"));
+        assert!(html.contains("12.00時間"));
+    }
+
     #[test]
     fn summary_section_renders_error() {
         let html = render_to_string(move || {
@@ -93,6 +136,10 @@ mod host_tests {
                         total_work_hours: None,
                         total_work_days: None,
                         average_daily_hours: None,
+                        absent_days: None,
+                        leave_days: None,
+                        paid_leave_hours: None,
+                        flextime_balance_hours: None,
                     })
                 },
             );
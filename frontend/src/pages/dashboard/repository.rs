@@ -1,4 +1,4 @@
-use crate::api::{ApiClient, ApiError, AttendanceSummary};
+use crate::api::{AnnualLeaveComplianceItem, ApiClient, ApiError, AttendanceSummary, UserResponse};
 use crate::pages::dashboard::utils::{current_year_month, ActivityStatusFilter};
 use crate::pages::requests::repository::RequestsRepository;
 use crate::pages::requests::types::{flatten_requests, RequestKind, RequestSummary};
@@ -9,6 +9,14 @@ pub struct DashboardSummary {
     pub total_work_hours: Option<f64>,
     pub total_work_days: Option<i32>,
     pub average_daily_hours: Option<f64>,
+    /// Working days of the month recorded as absent.
+    pub absent_days: Option<i32>,
+    /// Working days of the month on approved leave.
+    pub leave_days: Option<f64>,
+    /// Hours of the month's leave taken as paid leave.
+    pub paid_leave_hours: Option<f64>,
+    /// Flextime surplus (positive) or deficit (negative) of the settlement period so far.
+    pub flextime_balance_hours: Option<f64>,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
@@ -37,10 +45,28 @@ pub async fn fetch_summary(api: &ApiClient) -> Result<DashboardSummary, ApiError
         total_work_hours: Some(summary.total_work_hours),
         total_work_days: Some(summary.total_work_days),
         average_daily_hours: Some(summary.average_daily_hours),
+        absent_days: Some(summary.absent_days),
+        leave_days: Some(summary.leave_days),
+        paid_leave_hours: Some(summary.paid_leave_hours),
+        flextime_balance_hours: summary.flextime.map(|flextime| flextime.balance_hours),
     })
 }
 
-pub fn build_alerts(summary: &DashboardSummary) -> Vec<DashboardAlert> {
+/// Managers and system admins are responsible for the five-day mandatory leave rule.
+pub fn reviews_mandatory_leave(user: &UserResponse) -> bool {
+    user.is_system_admin || user.role.eq_ignore_ascii_case("manager")
+}
+
+pub async fn fetch_leave_compliance(
+    api: &ApiClient,
+) -> Result<Vec<AnnualLeaveComplianceItem>, ApiError> {
+    api.admin_get_annual_leave_compliance().await
+}
+
+pub fn build_alerts(
+    summary: &DashboardSummary,
+    compliance: &[AnnualLeaveComplianceItem],
+) -> Vec<DashboardAlert> {
     let mut alerts = Vec::new();
 
     if summary.total_work_days.unwrap_or_default() == 0 {
@@ -50,6 +76,21 @@ pub fn build_alerts(summary: &DashboardSummary) -> Vec<DashboardAlert> {
         });
     }
 
+    let due_soon = compliance
+        .iter()
+        .filter(|item| item.status == "due_soon")
+        .count();
+    if due_soon > 0 {
+        alerts.push(DashboardAlert {
+            level: DashboardAlertLevel::Error,
+            message: rust_i18n::t!(
+                "pages.dashboard.alerts.messages.mandatory_leave_due_soon",
+                count = due_soon
+            )
+            .into_owned(),
+        });
+    }
+
     if alerts.is_empty() {
         alerts.push(DashboardAlert {
             level: DashboardAlertLevel::Info,
@@ -119,13 +160,49 @@ mod tests {
             total_work_hours: Some(0.0),
             total_work_days: Some(0),
             average_daily_hours: None,
+            absent_days: None,
+            leave_days: None,
+            paid_leave_hours: None,
+            flextime_balance_hours: None,
         };
-        let alerts = build_alerts(&summary);
+        let alerts = build_alerts(&summary, &[]);
         assert!(alerts
             .iter()
             .any(|a| matches!(a.level, DashboardAlertLevel::Warning)));
     }
 
+    #[test]
+    fn alerts_flag_mandatory_leave_due_soon() {
+        let summary = DashboardSummary {
+            total_work_hours: Some(80.0),
+            total_work_days: Some(10),
+            average_daily_hours: Some(8.0),
+            absent_days: None,
+            leave_days: None,
+            paid_leave_hours: None,
+            flextime_balance_hours: None,
+        };
+        let item = |status: &str| AnnualLeaveComplianceItem {
+            user_id: "u1".into(),
+            username: "alice".into(),
+            grant_date: chrono::NaiveDate::from_ymd_opt(2025, 4, 1).unwrap(),
+            granted_days: 10.0,
+            deadline: chrono::NaiveDate::from_ymd_opt(2026, 3, 31).unwrap(),
+            days_taken: 1.0,
+            days_scheduled: 0.0,
+            days_remaining: 4.0,
+            days_until_deadline: 20,
+            status: status.into(),
+        };
+
+        let alerts = build_alerts(&summary, &[item("due_soon"), item("on_track")]);
+        assert_eq!(alerts.len(), 1);
+        assert!(matches!(alerts[0].level, DashboardAlertLevel::Error));
+
+        let alerts = build_alerts(&summary, &[item("on_track")]);
+        assert!(matches!(alerts[0].level, DashboardAlertLevel::Info));
+    }
+
     #[test]
     fn count_handles_missing_kind() {
         let empty: crate::pages::requests::types::MyRequestsResponse = Default::default();
@@ -12,11 +12,22 @@ pub fn format_hours(hours: Option<f64>) -> String {
         .unwrap_or_else(|| "-".into())
 }
 
+/// Signed hours, so a flextime surplus reads `+2.50時間` and a deficit `-2.50時間`.
+pub fn format_balance_hours(hours: f64) -> String {
+    format!("{:+.2}時間", hours)
+}
+
 pub fn format_days(days: Option<i32>) -> String {
     days.map(|d| format!("{d} 日"))
         .unwrap_or_else(|| "-".into())
 }
 
+/// Leave days with one decimal, since half days count as 0.5.
+pub fn format_leave_days(days: Option<f64>) -> String {
+    days.map(|d| format!("{d:.1} 日"))
+        .unwrap_or_else(|| "-".into())
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum ActivityStatusFilter {
     All,
@@ -58,4 +69,11 @@ mod tests {
         assert_eq!(format_days(Some(5)), "5 日");
         assert_eq!(format_days(None), "-");
     }
+
+    #[test]
+    fn formats_leave_days_with_one_decimal() {
+        assert_eq!(format_leave_days(Some(1.5)), "1.5 日");
+        assert_eq!(format_leave_days(Some(2.0)), "2.0 日");
+        assert_eq!(format_leave_days(None), "-");
+    }
 }
@@ -4,10 +4,11 @@ use crate::state::attendance::{
     self as attendance_state, refresh_today_context, use_attendance, AttendanceState,
     ClockEventKind, ClockEventPayload, ClockMessage,
 };
+use crate::state::auth::use_auth;
 use leptos::{ev::MouseEvent, *};
 
 type DashboardAlertsResource = Resource<
-    Option<Result<repository::DashboardSummary, ApiError>>,
+    (Option<Result<repository::DashboardSummary, ApiError>>, bool),
     Result<Vec<repository::DashboardAlert>, ApiError>,
 >;
 
@@ -59,13 +60,33 @@ impl DashboardViewModel {
             },
         );
 
+        let (auth, _) = use_auth();
+        let api_clone = api.clone();
         let alerts_resource = create_resource(
-            move || summary_resource.get(),
-            move |summary_opt| async move {
-                if let Some(Ok(summary)) = summary_opt {
-                    Ok(repository::build_alerts(&summary))
-                } else {
-                    Ok(Vec::new())
+            move || {
+                let reviews_leave = auth.with(|state| {
+                    state
+                        .user
+                        .as_ref()
+                        .is_some_and(repository::reviews_mandatory_leave)
+                });
+                (summary_resource.get(), reviews_leave)
+            },
+            move |(summary_opt, reviews_leave)| {
+                let api = api_clone.clone();
+                async move {
+                    let Some(Ok(summary)) = summary_opt else {
+                        return Ok(Vec::new());
+                    };
+                    // The compliance report is supplementary; its failure must not hide other alerts.
+                    let compliance = if reviews_leave {
+                        repository::fetch_leave_compliance(&api)
+                            .await
+                            .unwrap_or_default()
+                    } else {
+                        Vec::new()
+                    };
+                    Ok(repository::build_alerts(&summary, &compliance))
                 }
             },
         );
@@ -2,6 +2,7 @@ pub mod admin;
 pub mod admin_audit_logs;
 pub mod admin_departments;
 pub mod admin_export;
+pub mod admin_health;
 pub mod admin_settings;
 pub mod admin_users;
 pub mod attendance;
@@ -13,3 +14,4 @@ pub mod mfa;
 pub mod requests;
 pub mod reset_password;
 pub mod settings;
+pub mod timesheets;
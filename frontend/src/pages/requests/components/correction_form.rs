@@ -1,4 +1,4 @@
-use crate::api::{ApiError, CreateAttendanceCorrectionRequest};
+use crate::api::{ApiError, CreateAttendanceCorrectionRequest, PeriodClosing};
 use crate::components::error::InlineErrorMessage;
 use crate::components::forms::DatePicker;
 use crate::components::layout::SuccessMessage;
@@ -7,6 +7,7 @@ use crate::pages::requests::{
     utils::{AttendanceCorrectionFormState, EditTarget, MessageState},
     view_model::AttendanceCorrectionEditPayload,
 };
+use chrono::NaiveDate;
 use leptos::*;
 
 #[component]
@@ -17,6 +18,7 @@ pub fn AttendanceCorrectionRequestForm(
     update_action: Action<AttendanceCorrectionEditPayload, Result<(), ApiError>>,
     editing: RwSignal<Option<EditTarget>>,
     on_cancel_edit: Callback<()>,
+    #[prop(optional)] closed_periods: MaybeSignal<Vec<PeriodClosing>>,
 ) -> impl IntoView {
     let pending = action.pending();
     let updating = update_action.pending();
@@ -49,6 +51,12 @@ pub fn AttendanceCorrectionRequestForm(
     };
 
     let date_signal = state.date_signal();
+    let date_closed = create_memo(move |_| {
+        let Ok(date) = NaiveDate::parse_from_str(&date_signal.get(), "%Y-%m-%d") else {
+            return false;
+        };
+        closed_periods.with(|closings| closings.iter().any(|closing| closing.covers(date)))
+    });
     let clock_in_signal = state.clock_in_signal();
     let clock_out_signal = state.clock_out_signal();
     let break_rows_signal = state.break_rows_signal();
@@ -82,6 +90,11 @@ pub fn AttendanceCorrectionRequestForm(
                     value=date_signal
                     disabled=MaybeSignal::derive(editing_correction)
                 />
+                <Show when=move || date_closed.get()>
+                    <p class="text-sm text-status-warning-text bg-status-warning-bg border border-status-warning-border rounded px-3 py-2">
+                        {rust_i18n::t!("pages.requests.correction_form.closed_notice")}
+                    </p>
+                </Show>
                 <div class="grid grid-cols-1 gap-4 lg:grid-cols-2">
                     <div>
                         <label class="block text-sm font-medium text-fg-muted">{rust_i18n::t!("pages.requests.correction_form.clock_in_label")}</label>
@@ -126,7 +139,7 @@ pub fn AttendanceCorrectionRequestForm(
                 <button
                     type="submit"
                     class="px-4 py-2 rounded bg-action-primary-bg text-action-primary-text disabled:opacity-50"
-                    disabled=move || pending.get() || updating.get()
+                    disabled=move || pending.get() || updating.get() || date_closed.get()
                 >
                     {move || {
                         if pending.get() || updating.get() {
@@ -178,5 +191,38 @@ mod host_tests {
         assert!(html.contains("Attendance Correction Request"));
         assert!(html.contains("Editing"));
         assert!(html.contains("Update Attendance Correction Request"));
+        assert!(!html.contains("closed for payroll"));
+    }
+
+    #[test]
+    fn correction_form_blocks_dates_in_closed_months() {
+        let _locale = set_test_locale("en");
+        let html = render_to_string(move || {
+            let state = AttendanceCorrectionFormState::default();
+            state.date_signal().set("2025-03-14".into());
+            let message = create_rw_signal(MessageState::default());
+            let action = create_action(|_| async move { Ok::<(), ApiError>(()) });
+            let update_action = create_action(|_| async move { Ok::<(), ApiError>(()) });
+            let closing: PeriodClosing = serde_json::from_value(serde_json::json!({
+                "id": "closing-1",
+                "period_month": "2025-03-01",
+                "department_id": null,
+                "closed_at": "2025-04-01T00:00:00Z"
+            }))
+            .unwrap();
+            view! {
+                <AttendanceCorrectionRequestForm
+                    state=state
+                    message=message
+                    action=action
+                    update_action=update_action
+                    editing=create_rw_signal(None)
+                    on_cancel_edit=Callback::new(|_| {})
+                    closed_periods=MaybeSignal::Static(vec![closing])
+                />
+            }
+        });
+        assert!(html.contains("closed for payroll"));
+        assert!(html.contains("disabled"));
     }
 }
@@ -1,4 +1,4 @@
-use crate::api::{ApiError, CreateLeaveRequest, UpdateLeaveRequest};
+use crate::api::{ApiError, CreateLeaveRequest, LeaveTypeOption, UpdateLeaveRequest};
 use crate::components::error::InlineErrorMessage;
 use crate::components::forms::DatePicker;
 use crate::components::layout::SuccessMessage;
@@ -12,6 +12,7 @@ use leptos::*;
 #[component]
 pub fn LeaveRequestForm(
     state: LeaveFormState,
+    #[prop(into)] leave_types: Signal<Vec<LeaveTypeOption>>,
     message: RwSignal<MessageState>,
     action: Action<CreateLeaveRequest, Result<(), ApiError>>,
     update_action: Action<EditPayload, Result<(), ApiError>>,
@@ -35,6 +36,8 @@ pub fn LeaveRequestForm(
                                     leave_type: payload.leave_type,
                                     start_date: payload.start_date,
                                     end_date: payload.end_date,
+                                    unit: payload.unit,
+                                    hours: payload.hours,
                                     reason: payload.reason,
                                 },
                             )
@@ -54,6 +57,8 @@ pub fn LeaveRequestForm(
     let leave_type = state.leave_type_signal();
     let start_signal = state.start_signal();
     let end_signal = state.end_signal();
+    let unit_signal = state.unit_signal();
+    let hours_signal = state.hours_signal();
     let reason_signal = state.reason_signal();
     view! {
         <div class="bg-surface-elevated rounded-2xl shadow-sm border border-border p-6 space-y-4">
@@ -90,10 +95,18 @@ pub fn LeaveRequestForm(
                         prop:value=move || leave_type.get()
                         on:change=move |ev| leave_type.set(event_target_value(&ev))
                     >
-                        <option value="annual">{rust_i18n::t!("pages.requests.leave_form.types.annual")}</option>
-                        <option value="sick">{rust_i18n::t!("pages.requests.leave_form.types.sick")}</option>
-                        <option value="personal">{rust_i18n::t!("pages.requests.leave_form.types.personal")}</option>
-                        <option value="other">{rust_i18n::t!("pages.requests.leave_form.types.other")}</option>
+                        <For
+                            each=move || leave_types.get()
+                            key=|option| option.code.clone()
+                            children=move |option| {
+                                let code = option.code.clone();
+                                view! {
+                                    <option value=option.code selected=move || leave_type.get() == code>
+                                        {option.name}
+                                    </option>
+                                }
+                            }
+                        />
                     </select>
                 </div>
                 <div class="grid grid-cols-1 gap-4 lg:grid-cols-2">
@@ -106,6 +119,40 @@ pub fn LeaveRequestForm(
                         value=end_signal
                     />
                 </div>
+                <div class="grid grid-cols-1 gap-4 lg:grid-cols-2">
+                    <div>
+                        <label class="block text-sm font-medium text-fg-muted">{rust_i18n::t!("pages.requests.leave_form.unit_label")}</label>
+                        <select
+                            class="mt-1 block w-full border border-form-control-border bg-form-control-bg text-form-control-text rounded px-2 py-1"
+                            prop:value=move || unit_signal.get()
+                            on:change=move |ev| {
+                                let unit = event_target_value(&ev);
+                                if unit != "full" {
+                                    end_signal.set(start_signal.get_untracked());
+                                }
+                                unit_signal.set(unit);
+                            }
+                        >
+                            <option value="full">{rust_i18n::t!("pages.requests.leave_form.units.full")}</option>
+                            <option value="half_am">{rust_i18n::t!("pages.requests.leave_form.units.half_am")}</option>
+                            <option value="half_pm">{rust_i18n::t!("pages.requests.leave_form.units.half_pm")}</option>
+                            <option value="hours">{rust_i18n::t!("pages.requests.leave_form.units.hours")}</option>
+                        </select>
+                    </div>
+                    <Show when=move || unit_signal.get() == "hours">
+                        <div>
+                            <label class="block text-sm font-medium text-fg-muted">{rust_i18n::t!("pages.requests.leave_form.hours_label")}</label>
+                            <input
+                                type="number"
+                                step="0.25"
+                                min="0.25"
+                                class="mt-1 block w-full border border-form-control-border bg-form-control-bg text-form-control-text rounded px-2 py-1"
+                                prop:value=move || hours_signal.get()
+                                on:input=move |ev| hours_signal.set(event_target_value(&ev))
+                            />
+                        </div>
+                    </Show>
+                </div>
                 <div>
                     <label class="block text-sm font-medium text-fg-muted">{rust_i18n::t!("pages.requests.leave_form.reason_label")}</label>
                     <textarea
@@ -149,6 +196,20 @@ mod host_tests {
         let _locale = set_test_locale("en");
         let html = render_to_string(move || {
             let state = LeaveFormState::default();
+            let leave_types = vec![
+                LeaveTypeOption {
+                    code: "annual".into(),
+                    name: "Annual Leave".into(),
+                    paid: true,
+                    balance_tracked: true,
+                },
+                LeaveTypeOption {
+                    code: "care".into(),
+                    name: "Family Care Leave".into(),
+                    paid: false,
+                    balance_tracked: false,
+                },
+            ];
             let message = create_rw_signal(MessageState::default());
             message.update(|msg| msg.set_success("ok".to_string()));
             let action = create_action(|_| async move { Ok::<(), ApiError>(()) });
@@ -160,6 +221,7 @@ mod host_tests {
             view! {
                 <LeaveRequestForm
                     state=state
+                    leave_types=Signal::derive(move || leave_types.clone())
                     message=message
                     action=action
                     update_action=update_action
@@ -171,5 +233,7 @@ mod host_tests {
         assert!(html.contains("Leave Request"));
         assert!(html.contains("Editing"));
         assert!(html.contains("Update Leave Request"));
+        assert!(html.contains("Half Day (AM)"));
+        assert!(html.contains("Family Care Leave"));
     }
 }
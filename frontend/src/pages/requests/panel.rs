@@ -13,6 +13,8 @@ use leptos::*;
 pub fn RequestsPage() -> impl IntoView {
     let vm = use_requests_view_model();
     let leave_state = vm.leave_state;
+    let leave_type_options = vm.leave_type_options();
+    let closed_periods = vm.closed_periods();
     let overtime_state = vm.overtime_state;
     let correction_state = vm.correction_state;
     let active_form = vm.active_form;
@@ -106,6 +108,7 @@ pub fn RequestsPage() -> impl IntoView {
                     <Show when=move || matches!(active_form.get(), RequestFormKind::Leave)>
                         <LeaveRequestForm
                             state=leave_state
+                            leave_types=leave_type_options
                             message=leave_message
                             action=leave_action
                             update_action=update_action
@@ -131,12 +134,14 @@ pub fn RequestsPage() -> impl IntoView {
                             update_action=correction_update_action
                             editing=editing_request
                             on_cancel_edit=on_cancel_correction
+                            closed_periods=closed_periods.into()
                         />
                     </Show>
                 </div>
                 <div class="hidden lg:grid grid-cols-1 gap-6 lg:grid-cols-3">
                     <LeaveRequestForm
                         state=leave_state
+                        leave_types=leave_type_options
                         message=leave_message
                         action=leave_action
                         update_action=update_action
@@ -158,6 +163,7 @@ pub fn RequestsPage() -> impl IntoView {
                         update_action=correction_update_action
                         editing=editing_request
                         on_cancel_edit=on_cancel_correction
+                        closed_periods=closed_periods.into()
                     />
                 </div>
                 <RequestsFilter filter_state=filter_state />
@@ -1,7 +1,7 @@
 use crate::api::{
     ApiClient, ApiError, CreateAttendanceCorrectionRequest, CreateLeaveRequest,
-    CreateOvertimeRequest, LeaveRequestResponse, OvertimeRequestResponse,
-    UpdateAttendanceCorrectionRequest, UpdateLeaveRequest, UpdateOvertimeRequest,
+    CreateOvertimeRequest, LeaveRequestResponse, LeaveTypeOption, OvertimeRequestResponse,
+    PeriodClosing, UpdateAttendanceCorrectionRequest, UpdateLeaveRequest, UpdateOvertimeRequest,
 };
 use serde_json::Value;
 use std::rc::Rc;
@@ -88,6 +88,14 @@ impl RequestsRepository {
             .map(|_| ())
     }
 
+    pub async fn list_leave_types(&self) -> Result<Vec<LeaveTypeOption>, ApiError> {
+        self.client.get_leave_types().await
+    }
+
+    pub async fn list_closed_periods(&self) -> Result<Vec<PeriodClosing>, ApiError> {
+        self.client.get_my_closed_periods().await
+    }
+
     pub async fn list_my_requests(&self) -> Result<MyRequestsResponse, ApiError> {
         let value: Value = self.client.get_my_requests().await?;
         serde_json::from_value(value)
@@ -154,6 +162,17 @@ mod host_tests {
             then.status(200)
                 .json_body(serde_json::json!({ "status": "cancelled" }));
         });
+        server.mock(|when, then| {
+            when.method(GET).path("/api/leave-types");
+            then.status(200).json_body(serde_json::json!([
+                {
+                    "code": "annual",
+                    "name": "年次有給休暇",
+                    "paid": true,
+                    "balance_tracked": true
+                }
+            ]));
+        });
         server.mock(|when, then| {
             when.method(GET).path("/api/requests/me");
             then.status(200).json_body(serde_json::json!({
@@ -168,6 +187,8 @@ mod host_tests {
             leave_type: "annual".into(),
             start_date: chrono::NaiveDate::from_ymd_opt(2025, 1, 10).unwrap(),
             end_date: chrono::NaiveDate::from_ymd_opt(2025, 1, 12).unwrap(),
+            unit: "full".into(),
+            hours: None,
             reason: None,
         })
         .await
@@ -185,6 +206,8 @@ mod host_tests {
                 leave_type: "annual".into(),
                 start_date: chrono::NaiveDate::from_ymd_opt(2025, 1, 10).unwrap(),
                 end_date: chrono::NaiveDate::from_ymd_opt(2025, 1, 12).unwrap(),
+                unit: "full".into(),
+                hours: None,
                 reason: Some("updated".into()),
             },
         )
@@ -202,5 +225,8 @@ mod host_tests {
         .unwrap();
         repo.cancel_request("req-1").await.unwrap();
         repo.list_my_requests().await.unwrap();
+        let leave_types = repo.list_leave_types().await.unwrap();
+        assert_eq!(leave_types.len(), 1);
+        assert!(leave_types[0].balance_tracked);
     }
 }
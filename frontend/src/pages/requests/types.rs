@@ -40,9 +40,30 @@ impl RequestSummary {
             .get("end_date")
             .and_then(|v| v.as_str())
             .map(|s| s.to_string());
+        let unit = value.get("unit").and_then(|v| v.as_str()).unwrap_or("full");
         let primary_label = match (start, end) {
             (Some(s), Some(e)) if s != e => Some(format!("{} 〜 {}", s, e)),
-            (Some(s), _) => Some(s),
+            (Some(s), _) => Some(match unit {
+                "half_am" => {
+                    rust_i18n::t!("pages.requests.summary.leave_half_am", date = s).into_owned()
+                }
+                "half_pm" => {
+                    rust_i18n::t!("pages.requests.summary.leave_half_pm", date = s).into_owned()
+                }
+                "hours" => {
+                    let hours = value
+                        .get("hours")
+                        .and_then(|v| v.as_f64())
+                        .unwrap_or_default();
+                    rust_i18n::t!(
+                        "pages.requests.summary.leave_hours",
+                        date = s,
+                        hours = hours
+                    )
+                    .into_owned()
+                }
+                _ => s,
+            }),
             _ => None,
         };
         Self {
@@ -187,6 +208,32 @@ mod tests {
         assert_eq!(summary.secondary_label.as_deref(), Some("annual"));
     }
 
+    #[test]
+    fn summarizes_partial_leave_with_unit() {
+        let _locale = crate::test_support::helpers::set_test_locale("ja");
+        let half = RequestSummary::from_leave(&json!({
+            "id": "req-2",
+            "start_date": "2025-01-10",
+            "end_date": "2025-01-10",
+            "unit": "half_pm"
+        }));
+        assert_eq!(
+            half.primary_label.as_deref(),
+            Some("2025-01-10（午後半休）")
+        );
+        let hourly = RequestSummary::from_leave(&json!({
+            "id": "req-3",
+            "start_date": "2025-01-10",
+            "end_date": "2025-01-10",
+            "unit": "hours",
+            "hours": 2.5
+        }));
+        assert_eq!(
+            hourly.primary_label.as_deref(),
+            Some("2025-01-10（2.5 時間）")
+        );
+    }
+
     #[test]
     fn summarizes_overtime_request() {
         let _locale = crate::test_support::helpers::set_test_locale("ja");
@@ -19,6 +19,8 @@ pub struct LeaveFormState {
     leave_type: RwSignal<String>,
     start_date: RwSignal<String>,
     end_date: RwSignal<String>,
+    unit: RwSignal<String>,
+    hours: RwSignal<String>,
     reason: RwSignal<String>,
 }
 
@@ -28,6 +30,8 @@ impl Default for LeaveFormState {
             leave_type: create_rw_signal("annual".to_string()),
             start_date: create_rw_signal(String::new()),
             end_date: create_rw_signal(String::new()),
+            unit: create_rw_signal("full".to_string()),
+            hours: create_rw_signal(String::new()),
             reason: create_rw_signal(String::new()),
         }
     }
@@ -67,6 +71,14 @@ impl LeaveFormState {
         self.end_date
     }
 
+    pub fn unit_signal(&self) -> RwSignal<String> {
+        self.unit
+    }
+
+    pub fn hours_signal(&self) -> RwSignal<String> {
+        self.hours
+    }
+
     pub fn reason_signal(&self) -> RwSignal<String> {
         self.reason
     }
@@ -75,6 +87,8 @@ impl LeaveFormState {
         self.leave_type.set("annual".into());
         self.start_date.set(String::new());
         self.end_date.set(String::new());
+        self.unit.set("full".into());
+        self.hours.set(String::new());
         self.reason.set(String::new());
     }
 
@@ -88,6 +102,16 @@ impl LeaveFormState {
         if let Some(end) = value.get("end_date").and_then(|v| v.as_str()) {
             self.end_date.set(end.to_string());
         }
+        if let Some(unit) = value.get("unit").and_then(|v| v.as_str()) {
+            self.unit.set(unit.to_string());
+        }
+        self.hours.set(
+            value
+                .get("hours")
+                .and_then(|v| v.as_f64())
+                .map(|hours| format!("{:.2}", hours))
+                .unwrap_or_default(),
+        );
         if let Some(reason) = value.get("reason").and_then(|v| v.as_str()) {
             self.reason.set(reason.to_string());
         }
@@ -107,10 +131,33 @@ impl LeaveFormState {
                 "pages.requests.validation.leave_date_order"
             )));
         }
+        let unit = self.unit.get();
+        if unit != "full" && end != start {
+            return Err(ApiError::validation(rust_i18n::t!(
+                "pages.requests.validation.leave_partial_single_day"
+            )));
+        }
+        let hours = if unit == "hours" {
+            let hours = self.hours.get().trim().parse::<f64>().map_err(|_| {
+                ApiError::validation(rust_i18n::t!(
+                    "pages.requests.validation.leave_hours_number"
+                ))
+            })?;
+            if hours <= 0.0 || hours >= 24.0 {
+                return Err(ApiError::validation(rust_i18n::t!(
+                    "pages.requests.validation.leave_hours_range"
+                )));
+            }
+            Some(hours)
+        } else {
+            None
+        };
         Ok(CreateLeaveRequest {
             leave_type: self.leave_type.get(),
             start_date: start,
             end_date: end,
+            unit,
+            hours,
             reason: optional_string(self.reason.get()),
         })
     }
@@ -433,6 +480,32 @@ mod tests {
         });
     }
 
+    #[test]
+    fn leave_form_validates_partial_units() {
+        with_runtime(|| {
+            let state = LeaveFormState::default();
+            state.start_signal().set("2025-01-10".into());
+            state.end_signal().set("2025-01-11".into());
+            state.unit_signal().set("half_am".into());
+            assert!(state.to_payload().is_err());
+
+            state.end_signal().set("2025-01-10".into());
+            let payload = state.to_payload().expect("half-day payload");
+            assert_eq!(payload.unit, "half_am");
+            assert_eq!(payload.hours, None);
+
+            state.unit_signal().set("hours".into());
+            state.hours_signal().set("abc".into());
+            assert!(state.to_payload().is_err());
+            state.hours_signal().set("24".into());
+            assert!(state.to_payload().is_err());
+            state.hours_signal().set("2.5".into());
+            let payload = state.to_payload().expect("hourly payload");
+            assert_eq!(payload.unit, "hours");
+            assert_eq!(payload.hours, Some(2.5));
+        });
+    }
+
     #[test]
     fn overtime_form_validates_hours() {
         with_runtime(|| {
@@ -1,7 +1,7 @@
 use crate::api::{
     ApiClient, ApiError, CreateAttendanceCorrectionRequest, CreateLeaveRequest,
-    CreateOvertimeRequest, UpdateAttendanceCorrectionRequest, UpdateLeaveRequest,
-    UpdateOvertimeRequest,
+    CreateOvertimeRequest, LeaveTypeOption, PeriodClosing, UpdateAttendanceCorrectionRequest,
+    UpdateLeaveRequest, UpdateOvertimeRequest,
 };
 use crate::pages::requests::types::MyRequestsResponse;
 use crate::pages::requests::{
@@ -29,6 +29,8 @@ pub struct RequestsViewModel {
     pub active_form: ReadSignal<RequestFormKind>,
     pub set_active_form: WriteSignal<RequestFormKind>,
     pub requests_resource: Resource<u32, Result<MyRequestsResponse, ApiError>>,
+    pub leave_types_resource: Resource<(), Result<Vec<LeaveTypeOption>, ApiError>>,
+    pub closed_periods_resource: Resource<u32, Result<Vec<PeriodClosing>, ApiError>>,
     pub leave_action: Action<CreateLeaveRequest, Result<(), ApiError>>,
     pub overtime_action: Action<CreateOvertimeRequest, Result<(), ApiError>>,
     pub correction_action: Action<CreateAttendanceCorrectionRequest, Result<(), ApiError>>,
@@ -242,6 +244,35 @@ fn apply_cancel_selection<F>(
     dispatch_cancel(summary.id);
 }
 
+fn default_leave_type_options() -> Vec<LeaveTypeOption> {
+    [
+        (
+            "annual",
+            rust_i18n::t!("pages.requests.leave_form.types.annual"),
+        ),
+        (
+            "sick",
+            rust_i18n::t!("pages.requests.leave_form.types.sick"),
+        ),
+        (
+            "personal",
+            rust_i18n::t!("pages.requests.leave_form.types.personal"),
+        ),
+        (
+            "other",
+            rust_i18n::t!("pages.requests.leave_form.types.other"),
+        ),
+    ]
+    .into_iter()
+    .map(|(code, name)| LeaveTypeOption {
+        code: code.to_string(),
+        name: name.into_owned(),
+        paid: code == "annual",
+        balance_tracked: code == "annual",
+    })
+    .collect()
+}
+
 impl RequestsViewModel {
     pub fn new() -> Self {
         let api = use_context::<ApiClient>().unwrap_or_else(ApiClient::new);
@@ -268,6 +299,22 @@ impl RequestsViewModel {
             },
         );
 
+        let leave_types_resource = create_resource(
+            || (),
+            move |_| {
+                let repo = repository.get_value();
+                async move { repo.list_leave_types().await }
+            },
+        );
+
+        let closed_periods_resource = create_resource(
+            move || reload.get(),
+            move |_| {
+                let repo = repository.get_value();
+                async move { repo.list_closed_periods().await }
+            },
+        );
+
         let leave_action = create_action(move |payload: &CreateLeaveRequest| {
             let repo = repository.get_value();
             let payload = payload.clone();
@@ -415,6 +462,8 @@ impl RequestsViewModel {
             active_form,
             set_active_form,
             requests_resource,
+            leave_types_resource,
+            closed_periods_resource,
             leave_action,
             overtime_action,
             correction_action,
@@ -450,6 +499,29 @@ impl RequestsViewModel {
         })
     }
 
+    /// Leave types offered on the form; the built-in types until the list has loaded.
+    pub fn leave_type_options(&self) -> Signal<Vec<LeaveTypeOption>> {
+        let leave_types_resource = self.leave_types_resource;
+        Signal::derive(move || {
+            leave_types_resource
+                .get()
+                .and_then(|result| result.ok())
+                .filter(|options| !options.is_empty())
+                .unwrap_or_else(default_leave_type_options)
+        })
+    }
+
+    /// Months closed for payroll; empty until loaded so the forms stay usable.
+    pub fn closed_periods(&self) -> Signal<Vec<PeriodClosing>> {
+        let closed_periods_resource = self.closed_periods_resource;
+        Signal::derive(move || {
+            closed_periods_resource
+                .get()
+                .and_then(|result| result.ok())
+                .unwrap_or_default()
+        })
+    }
+
     pub fn on_edit(&self) -> Callback<RequestSummary> {
         let leave_state = self.leave_state;
         let overtime_state = self.overtime_state;
@@ -653,6 +725,8 @@ mod host_tests {
                 leave_type: "annual".into(),
                 start_date: NaiveDate::from_ymd_opt(2025, 1, 10).unwrap(),
                 end_date: NaiveDate::from_ymd_opt(2025, 1, 12).unwrap(),
+                unit: "full".into(),
+                hours: None,
                 reason: None,
             });
             assert!(
@@ -699,6 +773,8 @@ mod host_tests {
                     leave_type: "annual".into(),
                     start_date: NaiveDate::from_ymd_opt(2025, 1, 10).unwrap(),
                     end_date: NaiveDate::from_ymd_opt(2025, 1, 12).unwrap(),
+                    unit: "full".into(),
+                    hours: None,
                     reason: Some("updated".into()),
                 },
             )));
@@ -0,0 +1,330 @@
+use super::view_model::{
+    can_submit, parse_month_input, timesheet_status_key, use_timesheets_view_model,
+    TimesheetDecision, TimesheetsViewModel,
+};
+use crate::api::{ApiError, TimesheetMonthStatus, TimesheetStatus, TimesheetSubmissionListItem};
+use crate::components::error::InlineErrorMessage;
+use crate::components::layout::{Layout, LoadingSpinner, SuccessMessage};
+use crate::utils::time::format_in_app_tz;
+use leptos::*;
+
+#[component]
+pub fn TimesheetsPage() -> impl IntoView {
+    let vm = use_timesheets_view_model();
+    let error = vm.error;
+    let success = vm.success;
+    let can_approve = vm.can_approve;
+
+    view! {
+        <Layout>
+            <div class="space-y-6">
+                <div>
+                    <h1 class="text-2xl font-bold text-fg">{rust_i18n::t!("pages.timesheets.title")}</h1>
+                    <p class="mt-1 text-sm text-fg-muted">{rust_i18n::t!("pages.timesheets.description")}</p>
+                </div>
+                <InlineErrorMessage error={error.into()} />
+                <Show when=move || success.get().is_some()>
+                    <SuccessMessage message={success.get().unwrap_or_default()} />
+                </Show>
+                <div class="grid grid-cols-1 gap-6 lg:grid-cols-2">
+                    <MonthSubmissionCard vm=vm />
+                    <SubmissionHistory vm=vm />
+                </div>
+                <Show when=move || can_approve.get()>
+                    <PendingApprovals vm=vm />
+                </Show>
+            </div>
+        </Layout>
+    }
+}
+
+#[component]
+fn MonthSubmissionCard(vm: TimesheetsViewModel) -> impl IntoView {
+    let selected_month = vm.selected_month;
+    let month_resource = vm.month_resource;
+    let submit_action = vm.submit_action;
+    let pending = submit_action.pending();
+
+    let on_submit = move |_| match parse_month_input(&selected_month.get_untracked()) {
+        Some(period) => submit_action.dispatch(period),
+        None => vm.error.set(Some(ApiError::validation(rust_i18n::t!(
+            "pages.timesheets.validation.invalid_month"
+        )))),
+    };
+
+    view! {
+        <div class="bg-surface-elevated shadow rounded-lg p-6 space-y-4">
+            <div>
+                <h2 class="text-lg font-medium text-fg">{rust_i18n::t!("pages.timesheets.month.title")}</h2>
+                <p class="text-sm text-fg-muted">{rust_i18n::t!("pages.timesheets.month.description")}</p>
+            </div>
+            <label class="block text-sm font-medium text-fg-muted">
+                {rust_i18n::t!("pages.timesheets.month.label")}
+                <input
+                    type="month"
+                    class="mt-1 block w-full border border-form-control-border bg-form-control-bg text-form-control-text rounded px-2 py-1"
+                    prop:value=move || selected_month.get()
+                    on:input=move |ev| selected_month.set(event_target_value(&ev))
+                />
+            </label>
+            {move || match month_resource.get() {
+                None => view! { <LoadingSpinner /> }.into_view(),
+                Some(Err(err)) => view! {
+                    <p class="text-sm text-status-error-text">{err.error}</p>
+                }
+                .into_view(),
+                Some(Ok(None)) => view! {
+                    <p class="text-sm text-fg-muted">
+                        {rust_i18n::t!("pages.timesheets.validation.invalid_month")}
+                    </p>
+                }
+                .into_view(),
+                Some(Ok(Some(status))) => view! {
+                    <MonthStatusDetails status=status.clone() />
+                    <button
+                        class="px-4 py-2 rounded bg-action-primary-bg text-action-primary-text disabled:opacity-50"
+                        disabled=move || pending.get() || !can_submit(&status)
+                        on:click=on_submit
+                    >
+                        {move || if pending.get() {
+                            rust_i18n::t!("pages.timesheets.actions.submitting")
+                        } else {
+                            rust_i18n::t!("pages.timesheets.actions.submit")
+                        }}
+                    </button>
+                }
+                .into_view(),
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn MonthStatusDetails(status: TimesheetMonthStatus) -> impl IntoView {
+    let status_label = status
+        .submission
+        .as_ref()
+        .map(|submission| rust_i18n::t!(timesheet_status_key(submission.status)))
+        .unwrap_or_else(|| rust_i18n::t!("pages.timesheets.status.draft"));
+    let returned_comment = status
+        .submission
+        .as_ref()
+        .filter(|submission| submission.status == TimesheetStatus::Returned)
+        .and_then(|submission| submission.decision_comment.clone());
+    let blockers = status.blockers;
+
+    view! {
+        <div class="space-y-2 text-sm">
+            <p class="text-fg">
+                {rust_i18n::t!("pages.timesheets.month.status")}": "
+                <span class="font-semibold">{status_label}</span>
+            </p>
+            {returned_comment.map(|comment| view! {
+                <p class="text-status-warning-text bg-status-warning-bg border border-status-warning-border rounded px-3 py-2">
+                    {rust_i18n::t!("pages.timesheets.month.returned_comment", comment = comment)}
+                </p>
+            })}
+            <Show
+                when=move || !blockers.is_clear()
+                fallback=|| view! {
+                    <p class="text-fg-muted">{rust_i18n::t!("pages.timesheets.blockers.none")}</p>
+                }
+            >
+                <ul class="list-disc pl-5 text-status-error-text">
+                    <Show when=move || { blockers.pending_corrections > 0 }>
+                        <li>
+                            {rust_i18n::t!(
+                                "pages.timesheets.blockers.pending_corrections",
+                                count = blockers.pending_corrections
+                            )}
+                        </li>
+                    </Show>
+                    <Show when=move || { blockers.open_clock_ins > 0 }>
+                        <li>
+                            {rust_i18n::t!(
+                                "pages.timesheets.blockers.open_clock_ins",
+                                count = blockers.open_clock_ins
+                            )}
+                        </li>
+                    </Show>
+                </ul>
+            </Show>
+        </div>
+    }
+}
+
+#[component]
+fn SubmissionHistory(vm: TimesheetsViewModel) -> impl IntoView {
+    let history_resource = vm.history_resource;
+    view! {
+        <div class="bg-surface-elevated shadow rounded-lg p-6 space-y-4">
+            <h2 class="text-lg font-medium text-fg">{rust_i18n::t!("pages.timesheets.history.title")}</h2>
+            {move || match history_resource.get() {
+                None => view! { <LoadingSpinner /> }.into_view(),
+                Some(Err(err)) => view! {
+                    <p class="text-sm text-status-error-text">{err.error}</p>
+                }
+                .into_view(),
+                Some(Ok(items)) if items.is_empty() => view! {
+                    <p class="text-sm text-fg-muted">{rust_i18n::t!("pages.timesheets.history.empty")}</p>
+                }
+                .into_view(),
+                Some(Ok(items)) => view! {
+                    <ul class="divide-y divide-border text-sm">
+                        {items
+                            .into_iter()
+                            .map(|item| view! {
+                                <li class="py-2 flex items-center justify-between gap-4">
+                                    <span class="text-fg">{item.period_month.format("%Y-%m").to_string()}</span>
+                                    <span class="text-fg-muted">{rust_i18n::t!(timesheet_status_key(item.status))}</span>
+                                </li>
+                            })
+                            .collect_view()}
+                    </ul>
+                }
+                .into_view(),
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn PendingApprovals(vm: TimesheetsViewModel) -> impl IntoView {
+    let approvals_resource = vm.approvals_resource;
+    view! {
+        <div class="bg-surface-elevated shadow rounded-lg p-6 space-y-4">
+            <div>
+                <h2 class="text-lg font-medium text-fg">{rust_i18n::t!("pages.timesheets.approvals.title")}</h2>
+                <p class="text-sm text-fg-muted">{rust_i18n::t!("pages.timesheets.approvals.description")}</p>
+            </div>
+            {move || match approvals_resource.get() {
+                None => view! { <LoadingSpinner /> }.into_view(),
+                Some(Err(err)) => view! {
+                    <p class="text-sm text-status-error-text">{err.error}</p>
+                }
+                .into_view(),
+                Some(Ok(items)) if items.is_empty() => view! {
+                    <p class="text-sm text-fg-muted">{rust_i18n::t!("pages.timesheets.approvals.empty")}</p>
+                }
+                .into_view(),
+                Some(Ok(items)) => view! {
+                    <ul class="divide-y divide-border">
+                        {items
+                            .into_iter()
+                            .map(|item| view! { <ApprovalRow vm=vm item=item /> })
+                            .collect_view()}
+                    </ul>
+                }
+                .into_view(),
+            }}
+        </div>
+    }
+}
+
+#[component]
+fn ApprovalRow(vm: TimesheetsViewModel, item: TimesheetSubmissionListItem) -> impl IntoView {
+    let comment = create_rw_signal(String::new());
+    let pending = vm.decision_action.pending();
+    let id = store_value(item.submission.id.clone());
+    let decide = move |approve: bool| {
+        let text = comment.get_untracked();
+        if !approve && text.trim().is_empty() {
+            vm.error.set(Some(ApiError::validation(rust_i18n::t!(
+                "pages.timesheets.validation.return_comment_required"
+            ))));
+            return;
+        }
+        vm.decision_action.dispatch(TimesheetDecision {
+            id: id.get_value(),
+            approve,
+            comment: text,
+        });
+    };
+
+    view! {
+        <li class="py-3 space-y-2">
+            <div class="flex flex-wrap items-center justify-between gap-2 text-sm">
+                <span class="font-medium text-fg">{item.username.clone()}</span>
+                <span class="text-fg-muted">
+                    {item.submission.period_month.format("%Y-%m").to_string()}
+                    " · "
+                    {rust_i18n::t!(
+                        "pages.timesheets.approvals.submitted_at",
+                        at = format_in_app_tz(item.submission.submitted_at)
+                    )}
+                </span>
+            </div>
+            <input
+                type="text"
+                class="block w-full border border-form-control-border bg-form-control-bg text-form-control-text rounded px-2 py-1 text-sm"
+                placeholder={rust_i18n::t!("pages.timesheets.approvals.comment_placeholder").into_owned()}
+                prop:value=move || comment.get()
+                on:input=move |ev| comment.set(event_target_value(&ev))
+            />
+            <div class="flex gap-2">
+                <button
+                    class="px-3 py-1 rounded bg-action-primary-bg text-action-primary-text text-sm disabled:opacity-50"
+                    disabled=move || pending.get()
+                    on:click=move |_| decide(true)
+                >
+                    {rust_i18n::t!("pages.timesheets.actions.approve")}
+                </button>
+                <button
+                    class="px-3 py-1 rounded border border-border text-fg text-sm disabled:opacity-50"
+                    disabled=move || pending.get()
+                    on:click=move |_| decide(false)
+                >
+                    {rust_i18n::t!("pages.timesheets.actions.return")}
+                </button>
+            </div>
+        </li>
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod host_tests {
+    use super::*;
+    use crate::test_support::helpers::{manager_user, provide_auth, regular_user, set_test_locale};
+    use crate::test_support::ssr::{render_to_string, render_with_router_to_string};
+
+    #[test]
+    fn month_details_list_blockers_and_return_comment() {
+        let _locale = set_test_locale("en");
+        let status: TimesheetMonthStatus = serde_json::from_value(serde_json::json!({
+            "period_month": "2025-03-01",
+            "submission": {
+                "id": "ts-1",
+                "user_id": "u1",
+                "period_month": "2025-03-01",
+                "status": "returned",
+                "submitted_at": "2025-04-01T09:00:00Z",
+                "decision_comment": "Add the client visit"
+            },
+            "blockers": { "pending_corrections": 2, "open_clock_ins": 0 }
+        }))
+        .unwrap();
+        let html = render_to_string(move || view! { <MonthStatusDetails status=status /> });
+        assert!(html.contains("Returned"));
+        assert!(html.contains("Add the client visit"));
+        assert!(html.contains("2 pending correction request(s)"));
+        assert!(!html.contains("without a clock-out"));
+    }
+
+    #[test]
+    fn approvals_section_is_shown_to_managers_only() {
+        let _locale = set_test_locale("en");
+        let employee_html =
+            render_with_router_to_string("http://localhost/timesheets", move || {
+                provide_auth(Some(regular_user()));
+                view! { <TimesheetsPage /> }
+            });
+        assert!(employee_html.contains("Monthly Timesheets"));
+        assert!(!employee_html.contains("Awaiting Sign-off"));
+
+        let manager_html = render_with_router_to_string("http://localhost/timesheets", move || {
+            provide_auth(Some(manager_user()));
+            view! { <TimesheetsPage /> }
+        });
+        assert!(manager_html.contains("Awaiting Sign-off"));
+    }
+}
@@ -0,0 +1,90 @@
+use crate::api::{
+    ApiClient, ApiError, TimesheetMonthStatus, TimesheetSubmission, TimesheetSubmissionListItem,
+};
+use std::rc::Rc;
+
+#[derive(Clone)]
+pub struct TimesheetsRepository {
+    client: Rc<ApiClient>,
+}
+
+impl Default for TimesheetsRepository {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl TimesheetsRepository {
+    pub fn new() -> Self {
+        Self {
+            client: Rc::new(ApiClient::new()),
+        }
+    }
+
+    pub fn new_with_client(client: Rc<ApiClient>) -> Self {
+        Self { client }
+    }
+
+    pub async fn fetch_month(
+        &self,
+        year: i32,
+        month: u32,
+    ) -> Result<TimesheetMonthStatus, ApiError> {
+        self.client.get_my_timesheet_month(year, month).await
+    }
+
+    pub async fn fetch_history(&self) -> Result<Vec<TimesheetSubmission>, ApiError> {
+        self.client.get_my_timesheets().await
+    }
+
+    pub async fn submit(&self, year: i32, month: u32) -> Result<TimesheetSubmission, ApiError> {
+        self.client.submit_my_timesheet(year, month).await
+    }
+
+    pub async fn fetch_pending_approvals(
+        &self,
+    ) -> Result<Vec<TimesheetSubmissionListItem>, ApiError> {
+        self.client.admin_list_timesheets(Some("submitted")).await
+    }
+
+    pub async fn approve(
+        &self,
+        id: &str,
+        comment: Option<&str>,
+    ) -> Result<TimesheetSubmission, ApiError> {
+        self.client.admin_approve_timesheet(id, comment).await
+    }
+
+    pub async fn return_to_employee(
+        &self,
+        id: &str,
+        comment: &str,
+    ) -> Result<TimesheetSubmission, ApiError> {
+        self.client.admin_return_timesheet(id, comment).await
+    }
+}
+
+#[cfg(all(test, not(target_arch = "wasm32")))]
+mod host_tests {
+    use super::*;
+    use crate::api::test_support::mock::*;
+
+    #[tokio::test]
+    async fn timesheets_repository_calls_endpoints() {
+        let server = MockServer::start_async().await;
+        server.mock(|when, then| {
+            when.method(GET).path("/api/admin/timesheets");
+            then.status(200).json_body(serde_json::json!([]));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/api/timesheets/me");
+            then.status(200).json_body(serde_json::json!([]));
+        });
+
+        let repo = TimesheetsRepository::new_with_client(Rc::new(ApiClient::new_with_base_url(
+            &server.url("/api"),
+        )));
+        assert!(repo.fetch_pending_approvals().await.unwrap().is_empty());
+        assert!(repo.fetch_history().await.unwrap().is_empty());
+    }
+}
@@ -0,0 +1,246 @@
+use super::repository::TimesheetsRepository;
+use crate::api::{
+    ApiClient, ApiError, TimesheetMonthStatus, TimesheetSubmission, TimesheetSubmissionListItem,
+};
+use crate::state::auth::use_auth;
+use crate::utils::time::today_in_app_tz;
+use chrono::{Datelike, Months, NaiveDate};
+use leptos::*;
+use std::rc::Rc;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TimesheetDecision {
+    pub id: String,
+    pub approve: bool,
+    pub comment: String,
+}
+
+/// Month preselected on the page: the previous one, which is the month usually due.
+pub fn default_month_input(today: NaiveDate) -> String {
+    today
+        .with_day(1)
+        .and_then(|first| first.checked_sub_months(Months::new(1)))
+        .unwrap_or(today)
+        .format("%Y-%m")
+        .to_string()
+}
+
+/// Parses the `YYYY-MM` value of a month input.
+pub fn parse_month_input(value: &str) -> Option<(i32, u32)> {
+    let date = NaiveDate::parse_from_str(&format!("{}-01", value.trim()), "%Y-%m-%d").ok()?;
+    Some((date.year(), date.month()))
+}
+
+pub fn timesheet_status_key(status: crate::api::TimesheetStatus) -> &'static str {
+    match status {
+        crate::api::TimesheetStatus::Submitted => "pages.timesheets.status.submitted",
+        crate::api::TimesheetStatus::Approved => "pages.timesheets.status.approved",
+        crate::api::TimesheetStatus::Returned => "pages.timesheets.status.returned",
+    }
+}
+
+/// Whether the month can be submitted: no open items, and still a draft or returned.
+pub fn can_submit(status: &TimesheetMonthStatus) -> bool {
+    status.blockers.is_clear()
+        && status
+            .submission
+            .as_ref()
+            .is_none_or(|submission| submission.status == crate::api::TimesheetStatus::Returned)
+}
+
+#[derive(Clone, Copy)]
+pub struct TimesheetsViewModel {
+    pub selected_month: RwSignal<String>,
+    pub error: RwSignal<Option<ApiError>>,
+    pub success: RwSignal<Option<String>>,
+    pub can_approve: Memo<bool>,
+    pub month_resource: Resource<(String, u32), Result<Option<TimesheetMonthStatus>, ApiError>>,
+    pub history_resource: Resource<u32, Result<Vec<TimesheetSubmission>, ApiError>>,
+    pub approvals_resource:
+        Resource<(bool, u32), Result<Vec<TimesheetSubmissionListItem>, ApiError>>,
+    pub submit_action: Action<(i32, u32), Result<TimesheetSubmission, ApiError>>,
+    pub decision_action: Action<TimesheetDecision, Result<TimesheetSubmission, ApiError>>,
+}
+
+pub fn use_timesheets_view_model() -> TimesheetsViewModel {
+    let (auth, _) = use_auth();
+    let api = use_context::<ApiClient>().unwrap_or_else(ApiClient::new);
+    let repo = TimesheetsRepository::new_with_client(Rc::new(api));
+
+    let selected_month = create_rw_signal(default_month_input(today_in_app_tz()));
+    let error = create_rw_signal(None::<ApiError>);
+    let success = create_rw_signal(None::<String>);
+    let reload = create_rw_signal(0u32);
+    let can_approve = create_memo(move |_| {
+        auth.get()
+            .user
+            .as_ref()
+            .map(|user| user.is_system_admin || user.role.eq_ignore_ascii_case("manager"))
+            .unwrap_or(false)
+    });
+
+    let repo_month = repo.clone();
+    let month_resource = create_resource(
+        move || (selected_month.get(), reload.get()),
+        move |(value, _)| {
+            let repo = repo_month.clone();
+            async move {
+                match parse_month_input(&value) {
+                    Some((year, month)) => repo.fetch_month(year, month).await.map(Some),
+                    None => Ok(None),
+                }
+            }
+        },
+    );
+
+    let repo_history = repo.clone();
+    let history_resource = create_resource(
+        move || reload.get(),
+        move |_| {
+            let repo = repo_history.clone();
+            async move { repo.fetch_history().await }
+        },
+    );
+
+    let repo_approvals = repo.clone();
+    let approvals_resource = create_resource(
+        move || (can_approve.get(), reload.get()),
+        move |(allowed, _)| {
+            let repo = repo_approvals.clone();
+            async move {
+                if allowed {
+                    repo.fetch_pending_approvals().await
+                } else {
+                    Ok(Vec::new())
+                }
+            }
+        },
+    );
+
+    let repo_submit = repo.clone();
+    let submit_action = create_action(move |(year, month): &(i32, u32)| {
+        let repo = repo_submit.clone();
+        let (year, month) = (*year, *month);
+        async move { repo.submit(year, month).await }
+    });
+
+    let repo_decision = repo.clone();
+    let decision_action = create_action(move |decision: &TimesheetDecision| {
+        let repo = repo_decision.clone();
+        let decision = decision.clone();
+        async move {
+            let comment = decision.comment.trim();
+            if decision.approve {
+                let comment = (!comment.is_empty()).then_some(comment);
+                repo.approve(&decision.id, comment).await
+            } else {
+                repo.return_to_employee(&decision.id, comment).await
+            }
+        }
+    });
+
+    create_effect(move |_| {
+        if let Some(result) = submit_action.value().get() {
+            apply_result(
+                result,
+                "pages.timesheets.messages.submitted",
+                error,
+                success,
+                reload,
+            );
+        }
+    });
+    create_effect(move |_| {
+        if let Some(result) = decision_action.value().get() {
+            apply_result(
+                result,
+                "pages.timesheets.messages.decided",
+                error,
+                success,
+                reload,
+            );
+        }
+    });
+
+    TimesheetsViewModel {
+        selected_month,
+        error,
+        success,
+        can_approve,
+        month_resource,
+        history_resource,
+        approvals_resource,
+        submit_action,
+        decision_action,
+    }
+}
+
+fn apply_result(
+    result: Result<TimesheetSubmission, ApiError>,
+    success_key: &str,
+    error: RwSignal<Option<ApiError>>,
+    success: RwSignal<Option<String>>,
+    reload: RwSignal<u32>,
+) {
+    match result {
+        Ok(_) => {
+            error.set(None);
+            success.set(Some(rust_i18n::t!(success_key).into_owned()));
+            reload.update(|value| *value = value.wrapping_add(1));
+        }
+        Err(err) => {
+            success.set(None);
+            error.set(Some(err));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_month_is_the_previous_month() {
+        let today = NaiveDate::from_ymd_opt(2025, 1, 15).unwrap();
+        assert_eq!(default_month_input(today), "2024-12");
+        let today = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+        assert_eq!(default_month_input(today), "2025-02");
+    }
+
+    #[test]
+    fn month_input_parses_year_and_month() {
+        assert_eq!(parse_month_input("2025-03"), Some((2025, 3)));
+        assert_eq!(parse_month_input(" 2025-12 "), Some((2025, 12)));
+        assert_eq!(parse_month_input("2025-13"), None);
+        assert_eq!(parse_month_input(""), None);
+    }
+
+    #[test]
+    fn only_clear_draft_or_returned_months_can_be_submitted() {
+        let mut status: TimesheetMonthStatus = serde_json::from_value(serde_json::json!({
+            "period_month": "2025-03-01",
+            "submission": null,
+            "blockers": { "pending_corrections": 0, "open_clock_ins": 1 }
+        }))
+        .unwrap();
+        assert!(!can_submit(&status));
+        status.blockers.open_clock_ins = 0;
+        assert!(can_submit(&status));
+
+        status.submission = Some(
+            serde_json::from_value(serde_json::json!({
+                "id": "ts-1",
+                "user_id": "u1",
+                "period_month": "2025-03-01",
+                "status": "submitted",
+                "submitted_at": "2025-04-01T09:00:00Z"
+            }))
+            .unwrap(),
+        );
+        assert!(!can_submit(&status));
+        if let Some(submission) = status.submission.as_mut() {
+            submission.status = crate::api::TimesheetStatus::Returned;
+        }
+        assert!(can_submit(&status));
+    }
+}
@@ -6,10 +6,11 @@ use crate::{
     pages::{
         admin::AdminPage, admin_audit_logs::AdminAuditLogsPage,
         admin_departments::AdminDepartmentsPage, admin_export::AdminExportPage,
-        admin_settings::AdminSettingsPage, admin_users::AdminUsersPage, attendance::AttendancePage,
-        dashboard::DashboardPage, forgot_password::ForgotPasswordPage, home::HomePage,
-        login::LoginPage, mfa::MfaRegisterPage, requests::RequestsPage,
-        reset_password::ResetPasswordPage, settings::SettingsPage,
+        admin_health::AdminHealthPage, admin_settings::AdminSettingsPage,
+        admin_users::AdminUsersPage, attendance::AttendancePage, dashboard::DashboardPage,
+        forgot_password::ForgotPasswordPage, home::HomePage, login::LoginPage,
+        mfa::MfaRegisterPage, requests::RequestsPage, reset_password::ResetPasswordPage,
+        settings::SettingsPage, timesheets::TimesheetsPage,
     },
     state::{auth::AuthProvider, locale::LocaleProvider},
 };
@@ -22,6 +23,7 @@ pub const ROUTE_PATHS: &[&str] = &[
     "/dashboard",
     "/attendance",
     "/requests",
+    "/timesheets",
     "/mfa/register",
     "/settings",
     "/admin",
@@ -30,12 +32,14 @@ pub const ROUTE_PATHS: &[&str] = &[
     "/admin/export",
     "/admin/audit-logs",
     "/admin/departments",
+    "/admin/health",
 ];
 
 pub const PROTECTED_ROUTE_PATHS: &[&str] = &[
     "/dashboard",
     "/attendance",
     "/requests",
+    "/timesheets",
     "/mfa/register",
     "/settings",
     "/admin",
@@ -44,6 +48,7 @@ pub const PROTECTED_ROUTE_PATHS: &[&str] = &[
     "/admin/export",
     "/admin/audit-logs",
     "/admin/departments",
+    "/admin/health",
 ];
 
 pub const PUBLIC_ROUTE_PATHS: &[&str] = &["/", "/login", "/forgot-password", "/reset-password"];
@@ -66,6 +71,7 @@ pub fn app_root() -> impl IntoView {
                         <Route path="/dashboard" view=ProtectedDashboard/>
                         <Route path="/attendance" view=ProtectedAttendance/>
                         <Route path="/requests" view=ProtectedRequests/>
+                        <Route path="/timesheets" view=ProtectedTimesheets/>
                         <Route path="/mfa/register" view=ProtectedMfaRegister/>
                         <Route path="/settings" view=ProtectedSettings/>
                         <Route path="/admin" view=ProtectedAdmin/>
@@ -74,6 +80,7 @@ pub fn app_root() -> impl IntoView {
                         <Route path="/admin/export" view=ProtectedAdminExport/>
                         <Route path="/admin/audit-logs" view=ProtectedAdminAuditLogs/>
                         <Route path="/admin/departments" view=ProtectedAdminDepartments/>
+                        <Route path="/admin/health" view=ProtectedAdminHealth/>
                     </Routes>
                 </Router>
             </AuthProvider>
@@ -96,6 +103,11 @@ fn ProtectedRequests() -> impl IntoView {
     view! { <RequireAuth><RequestsPage/></RequireAuth> }
 }
 
+#[component]
+fn ProtectedTimesheets() -> impl IntoView {
+    view! { <RequireAuth><TimesheetsPage/></RequireAuth> }
+}
+
 #[component]
 fn ProtectedMfaRegister() -> impl IntoView {
     view! { <RequireAuth><MfaRegisterPage/></RequireAuth> }
@@ -136,6 +148,11 @@ fn ProtectedAdminDepartments() -> impl IntoView {
     view! { <RequireAdmin><AdminDepartmentsPage/></RequireAdmin> }
 }
 
+#[component]
+fn ProtectedAdminHealth() -> impl IntoView {
+    view! { <RequireAdmin><AdminHealthPage/></RequireAdmin> }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -227,6 +244,7 @@ mod host_tests {
                         <ProtectedDashboard />
                         <ProtectedAttendance />
                         <ProtectedRequests />
+                        <ProtectedTimesheets />
                         <ProtectedMfaRegister />
                         <ProtectedSettings />
                         <ProtectedAdmin />
@@ -235,6 +253,7 @@ mod host_tests {
                         <ProtectedAdminExport />
                         <ProtectedAdminAuditLogs />
                         <ProtectedAdminDepartments />
+                        <ProtectedAdminHealth />
                     </div>
                 </Router>
             }
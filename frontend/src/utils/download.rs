@@ -39,6 +39,21 @@ pub fn trigger_csv_download(_filename: &str, _csv_data: &str) -> Result<(), Stri
     Err("CSV download is only available in wasm".to_string())
 }
 
+/// Sends the browser to a file link; the server's `Content-Disposition` makes it a download.
+#[cfg(target_arch = "wasm32")]
+pub fn open_download_url(url: &str) -> Result<(), String> {
+    web_sys::window()
+        .ok_or("No window")?
+        .location()
+        .set_href(url)
+        .map_err(|_| "Failed to open download link".to_string())
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn open_download_url(_url: &str) -> Result<(), String> {
+    Err("Link download is only available in wasm".to_string())
+}
+
 #[cfg(all(test, target_arch = "wasm32"))]
 mod tests {
     use super::*;